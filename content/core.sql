@@ -0,0 +1,1514 @@
+-- The DC20 core rules, loaded by `mellon_book::db::load_core_content`.
+
+INSERT INTO `sources`
+VALUES (X'019a1d3e5f607a81b2c3d4e5f6071829', 'DC20 Core Rules')
+ON CONFLICT (`source_id`) DO NOTHING
+;
+
+INSERT INTO `ancestries`
+VALUES (X'0199182824927164b25d368464947b6a', 'Human')
+, (X'019961a4e5427aeaa845989fe8f884c8', 'Elf')
+, (X'019a44c1a47b7ccba6ce5a99388f540a', 'Dwarf')
+, (X'019a44c1a5d675d9b0966201eb218005', 'Halfling')
+, (X'019a44c1a749720b88bf09041931e769', 'Gnome')
+, (X'019a44c1a8c37873a4121cfb741e8ae1', 'Orc')
+, (X'019a44c1aa4479e7bf565186dbb9a387', 'Dragonborn')
+, (X'019a44c1abd37c96b4b6ee0c742a08f7', 'Giantborn')
+, (X'019a44c1ad737a35abe7b2868b7fe81b', 'Angelborn')
+, (X'019a44c1af117e568d1c965ee5f72a7e', 'Fiendborn')
+, (X'019a44c1b02275c49662d0b40cdcdb0a', 'Beastborn')
+ON CONFLICT (`ancestry_id`) DO NOTHING
+;
+
+INSERT INTO `ancestry_traits`
+    (`ancestry_trait_id`, `name`, `description`, `cost`, `source_id`)
+VALUES (X'01991828aa3c7fa9a24bc2afacaa349d', 'Attribute Increase', 'Choose an Attribute. The chosen Attribute increases by 1 (up to the Attribute Limit).', 2, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a0307100a36ed6ca6e20af8e', 'Darkvision', 'You can see in Dim Light within 10 Spaces as if it were Bright Light, and in Darkness as if it were Dim Light.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a06d79219079b2c157eda214', 'Climb Speed', 'You gain a Climb Speed equal to your Ground Speed.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a0b1762fa7655b51647555ef', 'Quick Reactions', 'You gain +1 PD.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a0b97cb8aa26f1116b24f3ed', 'Small-Sized', 'Your Size is Small instead of Medium.', 0, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a0bf7f51800a43c41a3a8bee', 'Tough', 'Your HP maximum increases by 2.', 2, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a0e37f15b2b422fbf72fcc47', 'Thick-Skinned', 'While you aren''t wearing Armor, you gain +2 AD.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a1237b948095ae228bf3baeb', 'Glide Speed', 'You have a set of wings that allow you to fall at a rate of 1 Space per Round and move horizontally while doing so.', 2, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a14e79148eb916dc6d7db5ab', 'Sunlight Sensitivity', 'While you or your target is in Sunlight, you have DisADV on Attacks and Awareness Checks that rely on sight.', -1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a16f785f96fb33888392680f', 'Short-Legged', 'Your Speed decreases by 1.', -1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a1aa7f528220ffc93df6e978', 'Trade Expertise', 'Choose a Trade. Your Mastery Cap and Mastery Level in the chosen Trade both increase by 1.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a1f475fcac01a91fb77bc329', 'Attribute Decrease', 'Choose an Attribute. The chosen Attribute decreases by 1 (to a minimum of -2).', -1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a224799da3b2d6aa5787a913', 'Skill Expertise', 'Choose a Skill. Your Mastery Cap and Mastery Level in the chosen Skill both increase by 1.', 2, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a2707c308f458969322bf678', 'Resolve', 'You gain ADV on Death Saves.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a27772028644c3c8557c4f42', 'Undying', 'You have ADV on Saves against the Doomed Condition.', 0, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a2a87940a08814ee62323684', 'Determination', 'While you are Bloodied, you gain ADV on Attacks and Spell Checks.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a2f07d018636dd4840b9c361', 'Unbreakable', 'When you become Deaths Door, you regain 1 HP. You can''t use this Trait again until you finish a Long Rest.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a3467df38e8679ffe190892f', 'Elven Will', 'You have ADV on Checks and Saves against being Charmed and Put to Sleep.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a373737fafa0a0e178956fc7', 'Nimble', 'When you take the Dodge Action, you instead gain the benefits of the Full Dodge Action.', 2, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a3847c5ea6b2ab986aaa3f2b', 'Agile Explorer', 'You are not affected by Difficult Terrain.', 2, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a3b47acc92530a0edf5266fc', 'Discerning Sight', 'You have ADV on Checks made to see through Illusions.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a3d57cf2bb022dcc8e25ea34', 'Peerless Sight', 'You don''t have DisADV on Attacks made at Long Range.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a3f975b6a42f3f10885e010f', 'Plant Knowledge', 'While in forests, jungles and swamps, you have ADV on Survival and Nature Checks.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a40177bea403e6e74fecc650', 'Brittle', 'Your AD decreases by 1.', -1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a4367429aebbc723f1d371b3', 'Frail', 'Your HP maximum decreases by 2.', -1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a48c7a098d40e6d6d7681ff5', 'Toxic Fortitude', 'You have Poison Resistance (Half) and ADV on Saves against being Poisoned.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a4bf77c4bd97096c40cdb2c6', 'Physically Sturdy', 'You have ADV on Saves against being Impaired, Deafened or Petrified.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a50f7fc4a3b6fa35ecfec3d1', 'Iron Stomach', 'You have ADV on Saves against consuming bad food and drink.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a525771abecacb1f03e3817e', 'Natural Combatant', 'You gain Combat Training with Heavy Armor and Heavy Shields.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a53d78c0b37a2d6da08d5a9c', 'Stone Blood', 'You can spend 1 AP to end the Bleeding Condition on yourself.', 2, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a54a7131a0b8125f44082fef', 'Minecraft', 'You have ADV on Checks to know about stonework, and can sense the direction of the surface while underground.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a59c78bfb211caaf79fc91fe', 'Earthen Knowledge', 'While underground, you have ADV on Survival and Nature Checks.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a5dd79b9b98c625143d7eba3', 'Elusive', 'When you take the Disengage Action, you instead gain the benefits of the Full Disengage Action.', 2, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a63279bcbc63d8b28457e970', 'Bravery', 'You have ADV on Saves against being Intimidated, Rattled or Frightened.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a68b7e8dba85e43078c88ab0', 'Endurance', 'You gain 1 Rest Point.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a6df7cc69428d8a2f3a743a5', 'Deft Footwork', 'You can move through the space of a creature one Size larger than you, and it doesn''t count as Difficult Terrain.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a6e4793b8d86ffec3e368785', 'Beast Whisperer', 'You can speak to Beasts that are Small or smaller.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a71376e7bef2fef3fe697c1d', 'Critter Knowledge', 'You have ADV on Animal and Nature Checks concerning Small or smaller creatures.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a72d72d3baf1168f784459c0', 'Halfling Luck', 'When you roll a 1 on a d20, you can reroll it. You can''t use this Trait again until you finish a Long Rest.', 2, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a7947581991c6fd08703c2e9', 'Escape Artist', 'You have ADV on Checks and Saves to avoid or escape being Grappled or Restrained.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a7a474458007676771a4c8f3', 'Magnified Vision', 'You have ADV on Investigation Checks made on objects within 1 Space.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a7fa71f5844c6c6d6a007e9b', 'Mental Defense', 'You have Psychic Resistance (Half) and ADV on Saves against Psychic effects.', 2, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a82c705482ab4c5743a5be2c', 'Strong Minded', 'You gain +1 MD.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a84072e6a0bf0165bd389eca', 'Predict Weather', 'You know what the weather will be in your area for the next 24 hours.', 0, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a8817337b176c16cc8782bff', 'Trapper', 'You have ADV on Checks to spot, disarm and build traps.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a90e702884b9e21eb1edd11d', 'Orcish Resolve', 'You gain 1 additional AP while you are on Deaths Door.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a9277d5fbaffb45b268b9296', 'Brutal Strikes', 'Your Heavy and Brutal Hits deal +1 damage.', 2, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a96f7569b77e65c4a2b1c2e3', 'Relentless', 'When you would be reduced to 0 HP, you can drop to 1 HP instead. You can''t use this Trait again until you finish a Long Rest.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a9777e0a8f0211959f3e8e4b', 'Intimidating Shout', 'You can spend 1 AP to shout. Creatures of your choice within 5 Spaces make a Charisma Save or are Intimidated by you until the end of your next turn.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a981760f816bf4561f86a07f', 'Dash', 'When you take the Move Action, your Speed increases by 1.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a98f74d7b845a0760dcbde15', 'Finishing Blow', 'You deal +1 damage to creatures that are Well-Bloodied.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1a9d874eca735af423d36d5bb', 'Imposing Presence', 'You have ADV on Intimidation Checks.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1aa1f726ba51ac2c28cc8192b', 'Reckless', 'Your PD decreases by 1.', -1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1aa90714a9f70b487901e160c', 'Draconic Resistance', 'You have Resistance (Half) to the damage type of your Draconic Origin.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1aabe78e7bd01d3b20d5bd48f', 'Draconic Breath Weapon', 'You can spend 2 AP to exhale a Cone or Line of energy of your Draconic Origin. Creatures in the area make an Agility Save, taking 2 damage on a failure.', 2, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1aacf769d96a3b8406aead5d9', 'Reptilian Superiority', 'You have ADV on Intimidation Checks against reptiles.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1aaf87907b09f5546d823800c', 'Mana Increase', 'Your MP maximum increases by 1.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1ab3979908410fa7523c03d18', 'Draconic Ward', 'When you become Bloodied, you gain 2 Temp HP. You can''t use this Trait again until you finish a Long Rest.', 2, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1ab7d71df9d855c3be73e5d1b', 'Guardian''s Bond', 'You can spend 1 AP to grant a creature within 1 Space Resistance (1) to the damage type of your Draconic Origin until the start of your next turn.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1abd876f4a4176d13cf46caab', 'Powerful Build', 'You count as one Size larger when determining your carrying capacity and what you can push, drag or lift.', 2, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1ac1d7c36843c7b4bf760a712', 'Unstoppable', 'You have ADV on Saves against being Slowed or Stunned.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1ac447c8a9f5f794fb46aeb0b', 'Giant''s Fortitude', 'While you are Bloodied, you gain 1 damage reduction.', 2, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1ac8771d38fb00a81c144896b', 'Strong Body', 'You have ADV on Might Saves against being moved.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1acb77cbd8aaec74a54c39a26', 'Mighty Leap', 'You can use your Might instead of your Agility to determine your jump distance.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1acf5705c8648ad24967504fa', 'Heavy Riser', 'Standing up from Prone costs you no movement.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1ad4d77599d794c6ff0900287', 'Clumsiness', 'You have DisADV on Agility Checks.', -1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1ad797f688c1d40758f692f11', 'Radiant Resistance', 'You have Radiant Resistance (Half).', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1add27497b1653eeb0a77844f', 'Celestial Clarity', 'You have ADV on Saves against being Blinded or Deafened.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1adf678ad988e98ab7b911d89', 'Angelic Insight', 'You have ADV on Insight Checks to tell whether a creature is lying.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1ae017b8f86fa6cb9c9370d66', 'Gift of the Angels', 'You can spend 1 AP to touch a creature and restore 2 HP to it. You can''t use this Trait again until you finish a Long Rest.', 2, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1ae467b58a654a86f970a0004', 'Healing Touch', 'When you restore HP to a creature, it regains 1 additional HP.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1ae867898b29c3ee0be8c9510', 'Divine Glow', 'You can make your body shed Bright Light in a 5 Space radius.', 0, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1aeb779419a5fc7094fcb6032', 'Pacifist', 'You have DisADV on the first Attack you make against a creature that hasn''t attacked you.', -1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1af647176bc1be9f9fa244b3a', 'Fiendish Resistance', 'You have Resistance (Half) to the damage type of your Fiendish Origin.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1af74783293851393cf22b504', 'Fiendish Aura', 'You can spend 1 AP to cause creatures within 1 Space of you to take 1 damage of the type of your Fiendish Origin.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1afaa7f40a27ed6d7552f79fc', 'Lies and Deceit', 'You have ADV on Trickery Checks to lie.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1afdb7d9eb43c99711bda40a5', 'Charming Gaze', 'You can spend 1 AP to make a creature that can see you make a Charisma Save or become Charmed by you until the end of your next turn.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1afe97dadaf9d49ae9f574580', 'Infernal Bravery', 'You have ADV on Saves against being Intimidated.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1b06f7dd7bb3edf890e753a22', 'Echolocation', 'While you aren''t Deafened, you can sense creatures within 5 Spaces of you.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1b0a47a2491257f0fce9e430f', 'Keen Sense', 'You have ADV on Awareness Checks that rely on one sense of your choice.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1b0c7735e8d63a73d4a2b77e3', 'Natural Weapon', 'You have claws, horns or fangs you can make Unarmed Strikes with that deal 1 Slashing, Piercing or Bludgeoning damage.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1b1057d9db48ad648a94c7ca6', 'Fur Coat', 'You have Cold Resistance (Half).', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1b12e771fb1a51da2ef5f1504', 'Prowling', 'You have ADV on Stealth Checks while in Darkness.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1b1697514ab2ae892dd63b3c8', 'Hard Shell', 'You gain +1 PD while you aren''t wearing Armor.', 1, X'019a1d3e5f607a81b2c3d4e5f6071829')
+ON CONFLICT (`ancestry_trait_id`) DO NOTHING
+;
+
+INSERT INTO `ancestries_ancestry_traits`
+VALUES (X'0199182824927164b25d368464947b6a', X'01991828aa3c7fa9a24bc2afacaa349d', FALSE)
+, (X'0199182824927164b25d368464947b6a', X'019a44c1a224799da3b2d6aa5787a913', FALSE) -- Human :: Skill Expertise
+, (X'0199182824927164b25d368464947b6a', X'019a44c1a2707c308f458969322bf678', FALSE) -- Human :: Resolve
+, (X'0199182824927164b25d368464947b6a', X'019a44c1a27772028644c3c8557c4f42', FALSE) -- Human :: Undying
+, (X'0199182824927164b25d368464947b6a', X'019a44c1a1aa7f528220ffc93df6e978', FALSE) -- Human :: Trade Expertise
+, (X'0199182824927164b25d368464947b6a', X'019a44c1a2a87940a08814ee62323684', TRUE) -- Human :: Determination
+, (X'0199182824927164b25d368464947b6a', X'019a44c1a2f07d018636dd4840b9c361', TRUE) -- Human :: Unbreakable
+, (X'0199182824927164b25d368464947b6a', X'019a44c1a1f475fcac01a91fb77bc329', TRUE) -- Human :: Attribute Decrease
+, (X'019961a4e5427aeaa845989fe8f884c8', X'019a44c1a3467df38e8679ffe190892f', FALSE) -- Elf :: Elven Will
+, (X'019961a4e5427aeaa845989fe8f884c8', X'019a44c1a373737fafa0a0e178956fc7', FALSE) -- Elf :: Nimble
+, (X'019961a4e5427aeaa845989fe8f884c8', X'019a44c1a3847c5ea6b2ab986aaa3f2b', FALSE) -- Elf :: Agile Explorer
+, (X'019961a4e5427aeaa845989fe8f884c8', X'019a44c1a3b47acc92530a0edf5266fc', FALSE) -- Elf :: Discerning Sight
+, (X'019961a4e5427aeaa845989fe8f884c8', X'019a44c1a0b1762fa7655b51647555ef', FALSE) -- Elf :: Quick Reactions
+, (X'019961a4e5427aeaa845989fe8f884c8', X'019a44c1a3d57cf2bb022dcc8e25ea34', FALSE) -- Elf :: Peerless Sight
+, (X'019961a4e5427aeaa845989fe8f884c8', X'019a44c1a06d79219079b2c157eda214', FALSE) -- Elf :: Climb Speed
+, (X'019961a4e5427aeaa845989fe8f884c8', X'019a44c1a1aa7f528220ffc93df6e978', FALSE) -- Elf :: Trade Expertise
+, (X'019961a4e5427aeaa845989fe8f884c8', X'019a44c1a3f975b6a42f3f10885e010f', TRUE) -- Elf :: Plant Knowledge
+, (X'019961a4e5427aeaa845989fe8f884c8', X'019a44c1a40177bea403e6e74fecc650', TRUE) -- Elf :: Brittle
+, (X'019961a4e5427aeaa845989fe8f884c8', X'019a44c1a4367429aebbc723f1d371b3', TRUE) -- Elf :: Frail
+, (X'019a44c1a47b7ccba6ce5a99388f540a', X'019a44c1a0bf7f51800a43c41a3a8bee', FALSE) -- Dwarf :: Tough
+, (X'019a44c1a47b7ccba6ce5a99388f540a', X'019a44c1a48c7a098d40e6d6d7681ff5', FALSE) -- Dwarf :: Toxic Fortitude
+, (X'019a44c1a47b7ccba6ce5a99388f540a', X'019a44c1a4bf77c4bd97096c40cdb2c6', FALSE) -- Dwarf :: Physically Sturdy
+, (X'019a44c1a47b7ccba6ce5a99388f540a', X'019a44c1a50f7fc4a3b6fa35ecfec3d1', FALSE) -- Dwarf :: Iron Stomach
+, (X'019a44c1a47b7ccba6ce5a99388f540a', X'019a44c1a0e37f15b2b422fbf72fcc47', FALSE) -- Dwarf :: Thick-Skinned
+, (X'019a44c1a47b7ccba6ce5a99388f540a', X'019a44c1a525771abecacb1f03e3817e', FALSE) -- Dwarf :: Natural Combatant
+, (X'019a44c1a47b7ccba6ce5a99388f540a', X'019a44c1a53d78c0b37a2d6da08d5a9c', FALSE) -- Dwarf :: Stone Blood
+, (X'019a44c1a47b7ccba6ce5a99388f540a', X'019a44c1a54a7131a0b8125f44082fef', FALSE) -- Dwarf :: Minecraft
+, (X'019a44c1a47b7ccba6ce5a99388f540a', X'019a44c1a0307100a36ed6ca6e20af8e', FALSE) -- Dwarf :: Darkvision
+, (X'019a44c1a47b7ccba6ce5a99388f540a', X'019a44c1a59c78bfb211caaf79fc91fe', TRUE) -- Dwarf :: Earthen Knowledge
+, (X'019a44c1a47b7ccba6ce5a99388f540a', X'019a44c1a16f785f96fb33888392680f', TRUE) -- Dwarf :: Short-Legged
+, (X'019a44c1a5d675d9b0966201eb218005', X'019a44c1a0b97cb8aa26f1116b24f3ed', FALSE) -- Halfling :: Small-Sized
+, (X'019a44c1a5d675d9b0966201eb218005', X'019a44c1a5dd79b9b98c625143d7eba3', FALSE) -- Halfling :: Elusive
+, (X'019a44c1a5d675d9b0966201eb218005', X'019a44c1a63279bcbc63d8b28457e970', FALSE) -- Halfling :: Bravery
+, (X'019a44c1a5d675d9b0966201eb218005', X'019a44c1a68b7e8dba85e43078c88ab0', FALSE) -- Halfling :: Endurance
+, (X'019a44c1a5d675d9b0966201eb218005', X'019a44c1a6df7cc69428d8a2f3a743a5', FALSE) -- Halfling :: Deft Footwork
+, (X'019a44c1a5d675d9b0966201eb218005', X'019a44c1a6e4793b8d86ffec3e368785', FALSE) -- Halfling :: Beast Whisperer
+, (X'019a44c1a5d675d9b0966201eb218005', X'019a44c1a1aa7f528220ffc93df6e978', FALSE) -- Halfling :: Trade Expertise
+, (X'019a44c1a5d675d9b0966201eb218005', X'019a44c1a71376e7bef2fef3fe697c1d', TRUE) -- Halfling :: Critter Knowledge
+, (X'019a44c1a5d675d9b0966201eb218005', X'019a44c1a72d72d3baf1168f784459c0', TRUE) -- Halfling :: Halfling Luck
+, (X'019a44c1a5d675d9b0966201eb218005', X'019a44c1a16f785f96fb33888392680f', TRUE) -- Halfling :: Short-Legged
+, (X'019a44c1a749720b88bf09041931e769', X'019a44c1a0b97cb8aa26f1116b24f3ed', FALSE) -- Gnome :: Small-Sized
+, (X'019a44c1a749720b88bf09041931e769', X'019a44c1a7947581991c6fd08703c2e9', FALSE) -- Gnome :: Escape Artist
+, (X'019a44c1a749720b88bf09041931e769', X'019a44c1a7a474458007676771a4c8f3', FALSE) -- Gnome :: Magnified Vision
+, (X'019a44c1a749720b88bf09041931e769', X'019a44c1a7fa71f5844c6c6d6a007e9b', FALSE) -- Gnome :: Mental Defense
+, (X'019a44c1a749720b88bf09041931e769', X'019a44c1a82c705482ab4c5743a5be2c', FALSE) -- Gnome :: Strong Minded
+, (X'019a44c1a749720b88bf09041931e769', X'019a44c1a84072e6a0bf0165bd389eca', FALSE) -- Gnome :: Predict Weather
+, (X'019a44c1a749720b88bf09041931e769', X'019a44c1a0307100a36ed6ca6e20af8e', FALSE) -- Gnome :: Darkvision
+, (X'019a44c1a749720b88bf09041931e769', X'019a44c1a8817337b176c16cc8782bff', TRUE) -- Gnome :: Trapper
+, (X'019a44c1a749720b88bf09041931e769', X'019a44c1a1f475fcac01a91fb77bc329', TRUE) -- Gnome :: Attribute Decrease
+, (X'019a44c1a8c37873a4121cfb741e8ae1', X'019a44c1a90e702884b9e21eb1edd11d', FALSE) -- Orc :: Orcish Resolve
+, (X'019a44c1a8c37873a4121cfb741e8ae1', X'019a44c1a9277d5fbaffb45b268b9296', FALSE) -- Orc :: Brutal Strikes
+, (X'019a44c1a8c37873a4121cfb741e8ae1', X'019a44c1a0bf7f51800a43c41a3a8bee', FALSE) -- Orc :: Tough
+, (X'019a44c1a8c37873a4121cfb741e8ae1', X'019a44c1a96f7569b77e65c4a2b1c2e3', FALSE) -- Orc :: Relentless
+, (X'019a44c1a8c37873a4121cfb741e8ae1', X'019a44c1a9777e0a8f0211959f3e8e4b', FALSE) -- Orc :: Intimidating Shout
+, (X'019a44c1a8c37873a4121cfb741e8ae1', X'019a44c1a981760f816bf4561f86a07f', FALSE) -- Orc :: Dash
+, (X'019a44c1a8c37873a4121cfb741e8ae1', X'019a44c1a98f74d7b845a0760dcbde15', FALSE) -- Orc :: Finishing Blow
+, (X'019a44c1a8c37873a4121cfb741e8ae1', X'019a44c1a0307100a36ed6ca6e20af8e', FALSE) -- Orc :: Darkvision
+, (X'019a44c1a8c37873a4121cfb741e8ae1', X'019a44c1a9d874eca735af423d36d5bb', TRUE) -- Orc :: Imposing Presence
+, (X'019a44c1a8c37873a4121cfb741e8ae1', X'019a44c1aa1f726ba51ac2c28cc8192b', TRUE) -- Orc :: Reckless
+, (X'019a44c1aa4479e7bf565186dbb9a387', X'019a44c1a0307100a36ed6ca6e20af8e', FALSE) -- Dragonborn :: Darkvision
+, (X'019a44c1aa4479e7bf565186dbb9a387', X'019a44c1aa90714a9f70b487901e160c', FALSE) -- Dragonborn :: Draconic Resistance
+, (X'019a44c1aa4479e7bf565186dbb9a387', X'019a44c1aabe78e7bd01d3b20d5bd48f', FALSE) -- Dragonborn :: Draconic Breath Weapon
+, (X'019a44c1aa4479e7bf565186dbb9a387', X'019a44c1aacf769d96a3b8406aead5d9', FALSE) -- Dragonborn :: Reptilian Superiority
+, (X'019a44c1aa4479e7bf565186dbb9a387', X'019a44c1aaf87907b09f5546d823800c', FALSE) -- Dragonborn :: Mana Increase
+, (X'019a44c1aa4479e7bf565186dbb9a387', X'019a44c1a0e37f15b2b422fbf72fcc47', FALSE) -- Dragonborn :: Thick-Skinned
+, (X'019a44c1aa4479e7bf565186dbb9a387', X'019a44c1ab3979908410fa7523c03d18', TRUE) -- Dragonborn :: Draconic Ward
+, (X'019a44c1aa4479e7bf565186dbb9a387', X'019a44c1ab7d71df9d855c3be73e5d1b', TRUE) -- Dragonborn :: Guardian's Bond
+, (X'019a44c1abd37c96b4b6ee0c742a08f7', X'019a44c1a0bf7f51800a43c41a3a8bee', FALSE) -- Giantborn :: Tough
+, (X'019a44c1abd37c96b4b6ee0c742a08f7', X'019a44c1abd876f4a4176d13cf46caab', FALSE) -- Giantborn :: Powerful Build
+, (X'019a44c1abd37c96b4b6ee0c742a08f7', X'019a44c1ac1d7c36843c7b4bf760a712', FALSE) -- Giantborn :: Unstoppable
+, (X'019a44c1abd37c96b4b6ee0c742a08f7', X'019a44c1ac447c8a9f5f794fb46aeb0b', FALSE) -- Giantborn :: Giant's Fortitude
+, (X'019a44c1abd37c96b4b6ee0c742a08f7', X'019a44c1ac8771d38fb00a81c144896b', FALSE) -- Giantborn :: Strong Body
+, (X'019a44c1abd37c96b4b6ee0c742a08f7', X'019a44c1acb77cbd8aaec74a54c39a26', FALSE) -- Giantborn :: Mighty Leap
+, (X'019a44c1abd37c96b4b6ee0c742a08f7', X'019a44c1acf5705c8648ad24967504fa', TRUE) -- Giantborn :: Heavy Riser
+, (X'019a44c1abd37c96b4b6ee0c742a08f7', X'019a44c1ad4d77599d794c6ff0900287', TRUE) -- Giantborn :: Clumsiness
+, (X'019a44c1ad737a35abe7b2868b7fe81b', X'019a44c1ad797f688c1d40758f692f11', FALSE) -- Angelborn :: Radiant Resistance
+, (X'019a44c1ad737a35abe7b2868b7fe81b', X'019a44c1add27497b1653eeb0a77844f', FALSE) -- Angelborn :: Celestial Clarity
+, (X'019a44c1ad737a35abe7b2868b7fe81b', X'019a44c1adf678ad988e98ab7b911d89', FALSE) -- Angelborn :: Angelic Insight
+, (X'019a44c1ad737a35abe7b2868b7fe81b', X'019a44c1ae017b8f86fa6cb9c9370d66', FALSE) -- Angelborn :: Gift of the Angels
+, (X'019a44c1ad737a35abe7b2868b7fe81b', X'019a44c1ae467b58a654a86f970a0004', FALSE) -- Angelborn :: Healing Touch
+, (X'019a44c1ad737a35abe7b2868b7fe81b', X'019a44c1ae867898b29c3ee0be8c9510', FALSE) -- Angelborn :: Divine Glow
+, (X'019a44c1ad737a35abe7b2868b7fe81b', X'019a44c1a1237b948095ae228bf3baeb', TRUE) -- Angelborn :: Glide Speed
+, (X'019a44c1ad737a35abe7b2868b7fe81b', X'019a44c1aeb779419a5fc7094fcb6032', TRUE) -- Angelborn :: Pacifist
+, (X'019a44c1af117e568d1c965ee5f72a7e', X'019a44c1af647176bc1be9f9fa244b3a', FALSE) -- Fiendborn :: Fiendish Resistance
+, (X'019a44c1af117e568d1c965ee5f72a7e', X'019a44c1af74783293851393cf22b504', FALSE) -- Fiendborn :: Fiendish Aura
+, (X'019a44c1af117e568d1c965ee5f72a7e', X'019a44c1a0307100a36ed6ca6e20af8e', FALSE) -- Fiendborn :: Darkvision
+, (X'019a44c1af117e568d1c965ee5f72a7e', X'019a44c1afaa7f40a27ed6d7552f79fc', FALSE) -- Fiendborn :: Lies and Deceit
+, (X'019a44c1af117e568d1c965ee5f72a7e', X'019a44c1afdb7d9eb43c99711bda40a5', FALSE) -- Fiendborn :: Charming Gaze
+, (X'019a44c1af117e568d1c965ee5f72a7e', X'019a44c1a1237b948095ae228bf3baeb', TRUE) -- Fiendborn :: Glide Speed
+, (X'019a44c1af117e568d1c965ee5f72a7e', X'019a44c1afe97dadaf9d49ae9f574580', TRUE) -- Fiendborn :: Infernal Bravery
+, (X'019a44c1af117e568d1c965ee5f72a7e', X'019a44c1a14e79148eb916dc6d7db5ab', TRUE) -- Fiendborn :: Sunlight Sensitivity
+, (X'019a44c1b02275c49662d0b40cdcdb0a', X'019a44c1a0307100a36ed6ca6e20af8e', FALSE) -- Beastborn :: Darkvision
+, (X'019a44c1b02275c49662d0b40cdcdb0a', X'019a44c1b06f7dd7bb3edf890e753a22', FALSE) -- Beastborn :: Echolocation
+, (X'019a44c1b02275c49662d0b40cdcdb0a', X'019a44c1b0a47a2491257f0fce9e430f', FALSE) -- Beastborn :: Keen Sense
+, (X'019a44c1b02275c49662d0b40cdcdb0a', X'019a44c1a0b1762fa7655b51647555ef', FALSE) -- Beastborn :: Quick Reactions
+, (X'019a44c1b02275c49662d0b40cdcdb0a', X'019a44c1a06d79219079b2c157eda214', FALSE) -- Beastborn :: Climb Speed
+, (X'019a44c1b02275c49662d0b40cdcdb0a', X'019a44c1b0c7735e8d63a73d4a2b77e3', FALSE) -- Beastborn :: Natural Weapon
+, (X'019a44c1b02275c49662d0b40cdcdb0a', X'019a44c1b1057d9db48ad648a94c7ca6', FALSE) -- Beastborn :: Fur Coat
+, (X'019a44c1b02275c49662d0b40cdcdb0a', X'019a44c1b12e771fb1a51da2ef5f1504', TRUE) -- Beastborn :: Prowling
+, (X'019a44c1b02275c49662d0b40cdcdb0a', X'019a44c1b1697514ab2ae892dd63b3c8', TRUE) -- Beastborn :: Hard Shell
+, (X'019a44c1b02275c49662d0b40cdcdb0a', X'019a44c1a14e79148eb916dc6d7db5ab', TRUE) -- Beastborn :: Sunlight Sensitivity
+ON CONFLICT (`ancestry_id`, `ancestry_trait_id`) DO NOTHING
+;
+
+INSERT INTO `attributes`
+VALUES (X'01993b832d6c7e7882b2063d613880b9', 'Prime')
+, (X'01993b83e9f978d4a5ae97c2011f49c6', 'Might')
+, (X'01993b8460827289a9e9cc105341940e', 'Agility')
+, (X'01993b84fcf17fcbb1fed093bfd9853d', 'Charisma')
+, (X'01993b8556b4774aa4a333bd7f76469e', 'Intelligence')
+ON CONFLICT (`attribute_id`) DO NOTHING
+;
+
+INSERT INTO `classes`
+    (`class_id`, `name`, `source_id`)
+VALUES (X'019964edee4b746ea5a85006d034d3ba', 'Artificer', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', 'Barbarian', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019964ee6f237b65b83cf0cd85bf985f', 'Bard', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019964ee77db78869c6783619eee210c', 'Champion', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019964ee7ef475a68360cc0a3b12cffc', 'Cleric', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019964ee864d7c15b46cbd28180f1f27', 'Commander', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019964ee8cf6787e91d0f48e5c194f7b', 'Druid', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019964eea1ad7225a1692680524594a3', 'Hunter', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019964eeaac57516a28f2fcfd17fdfb3', 'Monk', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019964eeb1ff7b288f9443d7cf59a4e5', 'Psion', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019964eeb92879d19159713bf7c8cdbd', 'Rogue', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019964eebf03730a95530ec528bb68ce', 'Sorcerer', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019964eec5df783bbf5365a4ba231213', 'Spellblade', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019964eecd22732fa0ab349338786aed', 'Warlock', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019964eed31576dea71821db96213fcd', 'Wizard', X'019a1d3e5f607a81b2c3d4e5f6071829')
+ON CONFLICT (`class_id`) DO NOTHING
+;
+
+INSERT INTO `subclasses`
+VALUES (X'019964f27af17574a82346e464d01aa7', 'Oracle')
+, (X'019964f2835d7929bcdc10d1d9d93a5e', 'Psi-Knight')
+, (X'019a44c1b173727c9eb3672e948da8ad', 'Alchemist')
+, (X'019a44c1b1767070b76f5f336c38e0db', 'Inventor')
+, (X'019a44c1b39672dfacebe822aeba4f78', 'Elemental Fury')
+, (X'019a44c1b3c97d088b9fe5030af4b17a', 'Spirit Guardian')
+, (X'019a44c1b59b7e759fe88007aa4539a0', 'Eloquence')
+, (X'019a44c1b5da7707b508a4b898a93308', 'Jester')
+, (X'019a44c1b73c781fada62c4741f743b9', 'Hero')
+, (X'019a44c1b766780087477d679b0562f5', 'Sentinel')
+, (X'019a44c1b9107b8084f166432a584bb8', 'Inquisitor')
+, (X'019a44c1b91e72039933c5fb96cff2ff', 'Priest')
+, (X'019a44c1baef7e69b30a28095a26b0da', 'Crusader')
+, (X'019a44c1bb34784fa8d28f826ced8d90', 'Warlord')
+, (X'019a44c1bd2f7799a10d008eb158b490', 'Phoenix')
+, (X'019a44c1bd417eeb8eaf22d0ec1a4fda', 'Rampant Growth')
+, (X'019a44c1bf8179dbb2f8f7ab08d78320', 'Monster Slayer')
+, (X'019a44c1bfaa79a4932c6722ab65d1ca', 'Trapper')
+, (X'019a44c1c1737fe3843069f6e193bfa6', 'Astral Self')
+, (X'019a44c1c1b27444bf9c4ccb1079addd', 'Shifting Tide')
+, (X'019a44c1c54a709a842d5507e2fc7fe1', 'Long Death')
+, (X'019a44c1c573799f93ea84b1b6dd44be', 'Swashbuckler')
+, (X'019a44c1c7127b50a50313196c4c1de4', 'Angelic')
+, (X'019a44c1c7257276ae2f8a78c5ed8fa7', 'Draconic')
+, (X'019a44c1c93f79cca52e33beda96d986', 'Paladin')
+, (X'019a44c1c94572adb3835941c82a29a8', 'Rune Knight')
+, (X'019a44c1cbb975ff9352284a8e43ff29', 'Eldritch')
+, (X'019a44c1cbe3764fb4fa1839d50a67bf', 'Fey')
+, (X'019a44c1cdc074409064d81bf16d8801', 'Portal Mage')
+, (X'019a44c1cded768a87236cdea83efd3d', 'Witch')
+ON CONFLICT (`subclass_id`) DO NOTHING
+;
+
+INSERT INTO `classes_subclasses`
+VALUES (X'019964eeb1ff7b288f9443d7cf59a4e5', X'019964f27af17574a82346e464d01aa7') -- Psion :: Oracle
+, (X'019964eeb1ff7b288f9443d7cf59a4e5', X'019964f2835d7929bcdc10d1d9d93a5e') -- Psion :: Psi-Knight
+, (X'019964edee4b746ea5a85006d034d3ba', X'019a44c1b173727c9eb3672e948da8ad') -- Artificer :: Alchemist
+, (X'019964edee4b746ea5a85006d034d3ba', X'019a44c1b1767070b76f5f336c38e0db') -- Artificer :: Inventor
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', X'019a44c1b39672dfacebe822aeba4f78') -- Barbarian :: Elemental Fury
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', X'019a44c1b3c97d088b9fe5030af4b17a') -- Barbarian :: Spirit Guardian
+, (X'019964ee6f237b65b83cf0cd85bf985f', X'019a44c1b59b7e759fe88007aa4539a0') -- Bard :: Eloquence
+, (X'019964ee6f237b65b83cf0cd85bf985f', X'019a44c1b5da7707b508a4b898a93308') -- Bard :: Jester
+, (X'019964ee77db78869c6783619eee210c', X'019a44c1b73c781fada62c4741f743b9') -- Champion :: Hero
+, (X'019964ee77db78869c6783619eee210c', X'019a44c1b766780087477d679b0562f5') -- Champion :: Sentinel
+, (X'019964ee7ef475a68360cc0a3b12cffc', X'019a44c1b9107b8084f166432a584bb8') -- Cleric :: Inquisitor
+, (X'019964ee7ef475a68360cc0a3b12cffc', X'019a44c1b91e72039933c5fb96cff2ff') -- Cleric :: Priest
+, (X'019964ee864d7c15b46cbd28180f1f27', X'019a44c1baef7e69b30a28095a26b0da') -- Commander :: Crusader
+, (X'019964ee864d7c15b46cbd28180f1f27', X'019a44c1bb34784fa8d28f826ced8d90') -- Commander :: Warlord
+, (X'019964ee8cf6787e91d0f48e5c194f7b', X'019a44c1bd2f7799a10d008eb158b490') -- Druid :: Phoenix
+, (X'019964ee8cf6787e91d0f48e5c194f7b', X'019a44c1bd417eeb8eaf22d0ec1a4fda') -- Druid :: Rampant Growth
+, (X'019964eea1ad7225a1692680524594a3', X'019a44c1bf8179dbb2f8f7ab08d78320') -- Hunter :: Monster Slayer
+, (X'019964eea1ad7225a1692680524594a3', X'019a44c1bfaa79a4932c6722ab65d1ca') -- Hunter :: Trapper
+, (X'019964eeaac57516a28f2fcfd17fdfb3', X'019a44c1c1737fe3843069f6e193bfa6') -- Monk :: Astral Self
+, (X'019964eeaac57516a28f2fcfd17fdfb3', X'019a44c1c1b27444bf9c4ccb1079addd') -- Monk :: Shifting Tide
+, (X'019964eeb92879d19159713bf7c8cdbd', X'019a44c1c54a709a842d5507e2fc7fe1') -- Rogue :: Long Death
+, (X'019964eeb92879d19159713bf7c8cdbd', X'019a44c1c573799f93ea84b1b6dd44be') -- Rogue :: Swashbuckler
+, (X'019964eebf03730a95530ec528bb68ce', X'019a44c1c7127b50a50313196c4c1de4') -- Sorcerer :: Angelic
+, (X'019964eebf03730a95530ec528bb68ce', X'019a44c1c7257276ae2f8a78c5ed8fa7') -- Sorcerer :: Draconic
+, (X'019964eec5df783bbf5365a4ba231213', X'019a44c1c93f79cca52e33beda96d986') -- Spellblade :: Paladin
+, (X'019964eec5df783bbf5365a4ba231213', X'019a44c1c94572adb3835941c82a29a8') -- Spellblade :: Rune Knight
+, (X'019964eecd22732fa0ab349338786aed', X'019a44c1cbb975ff9352284a8e43ff29') -- Warlock :: Eldritch
+, (X'019964eecd22732fa0ab349338786aed', X'019a44c1cbe3764fb4fa1839d50a67bf') -- Warlock :: Fey
+, (X'019964eed31576dea71821db96213fcd', X'019a44c1cdc074409064d81bf16d8801') -- Wizard :: Portal Mage
+, (X'019964eed31576dea71821db96213fcd', X'019a44c1cded768a87236cdea83efd3d') -- Wizard :: Witch
+ON CONFLICT (`class_id`, `subclass_id`) DO NOTHING
+;
+
+INSERT INTO `class_features`
+    (`class_feature_id`, `class_id`, `level`, `name`, `description`)
+VALUES (X'019a44c1b1b37852a74cbf1a7018c580', X'019964edee4b746ea5a85006d034d3ba', 1, 'Infusions', 'You know 2 Infusions. During a Long Rest you can infuse that many nonmagical Items, which stay infused until your next Long Rest.')
+, (X'019a44c1b1d674b4936594dd1a3deb6c', X'019964edee4b746ea5a85006d034d3ba', 1, 'Tinkerer', 'You gain Combat Training with Tools, and your Mastery Cap in Tinkering and Engineering increases by 1.')
+, (X'019a44c1b1e87eef9b13de662b7498f8', X'019964edee4b746ea5a85006d034d3ba', 2, 'Flash of Genius', 'When a creature within 5 Spaces fails a Check or Save, you can spend 1 AP to grant it a d4 bonus to the roll.')
+, (X'019a44c1b21677f5bd2575de6d0e2153', X'019964edee4b746ea5a85006d034d3ba', 3, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1b237780da0d8d85591fd13c0', X'019964edee4b746ea5a85006d034d3ba', 4, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1b25b75028179efd28e06c5ff', X'019964edee4b746ea5a85006d034d3ba', 6, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1b2ab7ac49846ca3ddf9714f5', X'019964edee4b746ea5a85006d034d3ba', 7, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1b2fa796da611e0dbb2607053', X'019964edee4b746ea5a85006d034d3ba', 9, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1b34c7eaea77c9f9f763a3bf5', X'019964edee4b746ea5a85006d034d3ba', 10, 'Capstone', 'Your Class reaches its peak, and you gain the Capstone Feature of your Class.')
+, (X'019a44c1b41a7edda7edaae12e744807', X'019964ee4cef7ec3a2ce3ea8844aadc9', 1, 'Rage', 'You can spend 1 AP and 1 SP to enter a Rage for 1 minute. While Raging you deal +1 damage with Martial Attacks, gain Resistance (Half) to Elemental and Physical damage, and have ADV on Might Saves.')
+, (X'019a44c1b42677c283db796569388fe3', X'019964ee4cef7ec3a2ce3ea8844aadc9', 1, 'Berserker', 'Your Speed increases by 1, and you can move through Difficult Terrain on your turn without extra cost while Bloodied.')
+, (X'019a44c1b4457763bb8f9ce12d087adf', X'019964ee4cef7ec3a2ce3ea8844aadc9', 1, 'Shattering Force', 'When you Hit a structure or object with a Melee Attack, it counts as a Heavy Hit.')
+, (X'019a44c1b494765da7f6e9b8012490fe', X'019964ee4cef7ec3a2ce3ea8844aadc9', 2, 'Battlecry', 'You can spend 1 AP and 1 SP to shout. Allies within 10 Spaces that can hear you gain +1 damage on their next Attack.')
+, (X'019a44c1b4a87be4a3da2e5712a21e14', X'019964ee4cef7ec3a2ce3ea8844aadc9', 3, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1b4dc70039ff4fbcd62660cae', X'019964ee4cef7ec3a2ce3ea8844aadc9', 4, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1b5197a13aec28c185f8a0eaa', X'019964ee4cef7ec3a2ce3ea8844aadc9', 6, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1b52b782e8688ed8e66860897', X'019964ee4cef7ec3a2ce3ea8844aadc9', 7, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1b5397ca19a5d6cb86b98286b', X'019964ee4cef7ec3a2ce3ea8844aadc9', 9, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1b58271aba781a304da1718ca', X'019964ee4cef7ec3a2ce3ea8844aadc9', 10, 'Capstone', 'Your Class reaches its peak, and you gain the Capstone Feature of your Class.')
+, (X'019a44c1b5e87653a05c5ddc8646c5d1', X'019964ee6f237b65b83cf0cd85bf985f', 1, 'Font of Inspiration', 'You can grant a Help Die to an ally within 10 Spaces as a Reaction, without spending AP, once per Round.')
+, (X'019a44c1b61476ebba75123ebfb9f691', X'019964ee6f237b65b83cf0cd85bf985f', 1, 'Remarkable Repertoire', 'You learn 1 additional Spell from any Spell List, and gain Mastery in Musician or Theatre.')
+, (X'019a44c1b630750fb641b14565a972d2', X'019964ee6f237b65b83cf0cd85bf985f', 2, 'Crowd Pleaser', 'When you succeed on a Charisma Check in front of an audience, you can grant up to 3 allies who can see you 1 Temp HP.')
+, (X'019a44c1b63c721ead9d6bb5f2cd7ffd', X'019964ee6f237b65b83cf0cd85bf985f', 3, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1b67d7554a98e2ff7a6625209', X'019964ee6f237b65b83cf0cd85bf985f', 4, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1b68d740ab29fd39c7c4730d6', X'019964ee6f237b65b83cf0cd85bf985f', 6, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1b69b75b681e725a4f84ef9f6', X'019964ee6f237b65b83cf0cd85bf985f', 7, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1b6d079fe8534648dff00ece0', X'019964ee6f237b65b83cf0cd85bf985f', 9, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1b71f7396a9386b64840b4f1f', X'019964ee6f237b65b83cf0cd85bf985f', 10, 'Capstone', 'Your Class reaches its peak, and you gain the Capstone Feature of your Class.')
+, (X'019a44c1b7b070ffae0e805b497282f7', X'019964ee77db78869c6783619eee210c', 1, 'Master-at-Arms', 'You gain Combat Training with all Weapons, Armor and Shields. Once per Round, you can reduce the SP cost of a Maneuver by 1.')
+, (X'019a44c1b7c977cf8f71a5e9b210ca50', X'019964ee77db78869c6783619eee210c', 1, 'Fighting Spirit', 'At the start of each of your turns in combat, you gain 1 Temp HP while you are Bloodied.')
+, (X'019a44c1b7ff79c58073f902795c3ffc', X'019964ee77db78869c6783619eee210c', 2, 'Adaptive Tactics', 'When you roll for Initiative, you gain 1 SP.')
+, (X'019a44c1b84d7df7a6fabb59664ae507', X'019964ee77db78869c6783619eee210c', 3, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1b86e7a01a37afa48f5cf3ba5', X'019964ee77db78869c6783619eee210c', 4, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1b8a07cd186d46ad7c9983228', X'019964ee77db78869c6783619eee210c', 6, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1b8ce7107a5c09ae3283610bd', X'019964ee77db78869c6783619eee210c', 7, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1b8de73d2b7638a6fd836470d', X'019964ee77db78869c6783619eee210c', 9, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1b8f87147a0f56b6a24476b0c', X'019964ee77db78869c6783619eee210c', 10, 'Capstone', 'Your Class reaches its peak, and you gain the Capstone Feature of your Class.')
+, (X'019a44c1b94d71f29a89e670f636e9be', X'019964ee7ef475a68360cc0a3b12cffc', 1, 'Cleric Order', 'Choose 2 Divine Domains. You gain the benefits of both.')
+, (X'019a44c1b97578c98e0d04d6a3fa35fc', X'019964ee7ef475a68360cc0a3b12cffc', 1, 'Divine Blessing', 'You can spend 1 AP to grant a creature you can see within 5 Spaces a d4 bonus to its next Check or Save.')
+, (X'019a44c1b9ce7eafb13146f758cf85f0', X'019964ee7ef475a68360cc0a3b12cffc', 2, 'Channel Divinity', 'You can spend 2 AP and 1 MP to restore HP to creatures of your choice within 5 Spaces, or to force Undead to make a Charisma Save or become Frightened.')
+, (X'019a44c1b9dc7a438685c679506c786e', X'019964ee7ef475a68360cc0a3b12cffc', 3, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1b9e3733aa3c8116bcfa1603d', X'019964ee7ef475a68360cc0a3b12cffc', 4, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1b9f77f68bbeee42a54887a0b', X'019964ee7ef475a68360cc0a3b12cffc', 6, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1ba057d56b133104051bf8855', X'019964ee7ef475a68360cc0a3b12cffc', 7, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1ba5c7f04baa001f0f1f0b71c', X'019964ee7ef475a68360cc0a3b12cffc', 9, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1ba957819a4a522b68bc41ec3', X'019964ee7ef475a68360cc0a3b12cffc', 10, 'Capstone', 'Your Class reaches its peak, and you gain the Capstone Feature of your Class.')
+, (X'019a44c1bb6f76fa9a707430ad1af068', X'019964ee864d7c15b46cbd28180f1f27', 1, 'Inspiring Presence', 'Allies within 5 Spaces that can see you gain +1 to Saves against Fear.')
+, (X'019a44c1bbb0705cbdfe862e31df5579', X'019964ee864d7c15b46cbd28180f1f27', 1, 'Commander''s Call', 'You can spend 1 AP to command an ally that can hear you to take an Action of their choice without spending their AP.')
+, (X'019a44c1bbdc70c8bc0e3f9c9983dade', X'019964ee864d7c15b46cbd28180f1f27', 2, 'Natural Leader', 'You have ADV on Influence Checks made to lead or rally others.')
+, (X'019a44c1bc157e6b9c1a1ca619e275e1', X'019964ee864d7c15b46cbd28180f1f27', 3, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1bc6271fcb62ef03218a6aeea', X'019964ee864d7c15b46cbd28180f1f27', 4, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1bc8f76feba51fdf8bd2082c6', X'019964ee864d7c15b46cbd28180f1f27', 6, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1bc997556b26af56df9b23be2', X'019964ee864d7c15b46cbd28180f1f27', 7, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1bcb37e9faacef3fed02f90a2', X'019964ee864d7c15b46cbd28180f1f27', 9, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1bcea7780ba86cb52bdcec523', X'019964ee864d7c15b46cbd28180f1f27', 10, 'Capstone', 'Your Class reaches its peak, and you gain the Capstone Feature of your Class.')
+, (X'019a44c1bd5d7a09a28e113906c0d9ee', X'019964ee8cf6787e91d0f48e5c194f7b', 1, 'Druid Domain', 'You can spend 1 AP and 1 MP to create a Druid Domain of natural terrain that fills a 3 Space radius and lasts for 1 minute.')
+, (X'019a44c1bd9f75348916a779eb90236e', X'019964ee8cf6787e91d0f48e5c194f7b', 1, 'Wild Form', 'You can spend 1 AP and 1 MP to transform into a Beast Form until you choose to end it or drop to 0 HP.')
+, (X'019a44c1bdf8729f92803317c62b8ce1', X'019964ee8cf6787e91d0f48e5c194f7b', 2, 'Nature''s Torrent', 'When you cast a Spell with a damage type of Cold, Fire or Lightning, you can move your Druid Domain up to 2 Spaces.')
+, (X'019a44c1be0470c0b59cfb78bf2005ff', X'019964ee8cf6787e91d0f48e5c194f7b', 3, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1be5474c7a91567b30b51aa99', X'019964ee8cf6787e91d0f48e5c194f7b', 4, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1bea67da480674127ab58e11e', X'019964ee8cf6787e91d0f48e5c194f7b', 6, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1bec975838c4d69a8e73d99a8', X'019964ee8cf6787e91d0f48e5c194f7b', 7, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1bf137370b9bb6e1d5e8f5e3a', X'019964ee8cf6787e91d0f48e5c194f7b', 9, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1bf457223bdb7b0dc33d05098', X'019964ee8cf6787e91d0f48e5c194f7b', 10, 'Capstone', 'Your Class reaches its peak, and you gain the Capstone Feature of your Class.')
+, (X'019a44c1bfe271c5b5fd4ce86c315fc2', X'019964eea1ad7225a1692680524594a3', 1, 'Hunter''s Mark', 'You can spend 1 AP and 1 SP to mark a creature you can see. You have ADV on Awareness and Survival Checks to find it and your Attacks against it ignore 1 PDR.')
+, (X'019a44c1c01a758f9fdb9f49c1bcc107', X'019964eea1ad7225a1692680524594a3', 1, 'Favored Terrain', 'Choose a terrain type. While in it, you have ADV on Stealth and Survival Checks and your Speed increases by 1.')
+, (X'019a44c1c03d70b9a428195ab95ba9ba', X'019964eea1ad7225a1692680524594a3', 2, 'Bestiary', 'You have ADV on Checks to recall information about creatures you have fought before.')
+, (X'019a44c1c07e73ac99f433f445444186', X'019964eea1ad7225a1692680524594a3', 3, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1c0887df69928cd210624f06e', X'019964eea1ad7225a1692680524594a3', 4, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1c0a37c40977a41f46ad48618', X'019964eea1ad7225a1692680524594a3', 6, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1c0e8716bae3e8529585bd262', X'019964eea1ad7225a1692680524594a3', 7, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1c13a7ffca82a7c5be2d7dc8a', X'019964eea1ad7225a1692680524594a3', 9, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1c168770fb5874cfaddb119ea', X'019964eea1ad7225a1692680524594a3', 10, 'Capstone', 'Your Class reaches its peak, and you gain the Capstone Feature of your Class.')
+, (X'019a44c1c1c97346bb72e45d3a38eaee', X'019964eeaac57516a28f2fcfd17fdfb3', 1, 'Monk Training', 'Your Unarmed Strikes deal 1 extra damage and your Speed increases by 1 while you aren''t wearing Armor.')
+, (X'019a44c1c202778f8ca31061cb16e0b1', X'019964eeaac57516a28f2fcfd17fdfb3', 1, 'Monk Stance', 'You learn 2 Monk Stances. You can enter or swap a Stance at the start of each of your turns.')
+, (X'019a44c1c22a7018a6b058191d3b57c2', X'019964eeaac57516a28f2fcfd17fdfb3', 2, 'Spiritual Balance', 'When you take the Dodge Action, you can spend 1 SP to also regain 1 HP.')
+, (X'019a44c1c2757d0890121fb7030978ca', X'019964eeaac57516a28f2fcfd17fdfb3', 3, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1c2bc736897d9d38d88450f24', X'019964eeaac57516a28f2fcfd17fdfb3', 4, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1c2fb7ea98db668d6f6940a87', X'019964eeaac57516a28f2fcfd17fdfb3', 6, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1c328766882741495e94b4a8c', X'019964eeaac57516a28f2fcfd17fdfb3', 7, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1c3817a579a8ac71f96234174', X'019964eeaac57516a28f2fcfd17fdfb3', 9, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1c3ad7fb1b37ab5c3cf99fddf', X'019964eeaac57516a28f2fcfd17fdfb3', 10, 'Capstone', 'Your Class reaches its peak, and you gain the Capstone Feature of your Class.')
+, (X'019a44c1c3f47583807a94f2e526f0a9', X'019964eeb1ff7b288f9443d7cf59a4e5', 1, 'Psionic Mind', 'You can communicate telepathically with a creature you can see within 10 Spaces.')
+, (X'019a44c1c4047054b2ac104b4f9bd5f6', X'019964eeb1ff7b288f9443d7cf59a4e5', 1, 'Psionic Recovery', 'When you finish a Short Rest, you regain 1 MP or 1 SP.')
+, (X'019a44c1c40d79beb4ab0d0f37fb038d', X'019964eeb1ff7b288f9443d7cf59a4e5', 2, 'Mind Over Matter', 'You can use your Intelligence instead of your Might for Might Checks and Saves.')
+, (X'019a44c1c4537dd480370ff99ca65d9b', X'019964eeb1ff7b288f9443d7cf59a4e5', 3, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1c45a7df3a5a41e5a8dfde234', X'019964eeb1ff7b288f9443d7cf59a4e5', 4, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1c4637622b0d62c29e49fdd0c', X'019964eeb1ff7b288f9443d7cf59a4e5', 6, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1c4a1730ebddbc06616fb5f61', X'019964eeb1ff7b288f9443d7cf59a4e5', 7, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1c4c77f0ebb8aff57acd469c7', X'019964eeb1ff7b288f9443d7cf59a4e5', 9, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1c51b7e31a0a75bd4653ae1b4', X'019964eeb1ff7b288f9443d7cf59a4e5', 10, 'Capstone', 'Your Class reaches its peak, and you gain the Capstone Feature of your Class.')
+, (X'019a44c1c57c79c39c459d11eab37fc8', X'019964eeb92879d19159713bf7c8cdbd', 1, 'Debilitating Strike', 'When you Hit with a Martial Attack, you can spend 1 SP to make the target Slowed or Impaired until the end of your next turn.')
+, (X'019a44c1c5ba7682ae19ea81ba807f90', X'019964eeb92879d19159713bf7c8cdbd', 1, 'Roguish Finesse', 'You gain Mastery in Trickery and Stealth, and your Cunning Action costs 1 less AP.')
+, (X'019a44c1c5e77b83903494671705c3a0', X'019964eeb92879d19159713bf7c8cdbd', 2, 'Cheap Shot', 'You have ADV on Attacks against creatures that are Prone, Grappled or Dazed.')
+, (X'019a44c1c61f75f986494d9ff0339daa', X'019964eeb92879d19159713bf7c8cdbd', 3, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1c652760a92ec9ec42d29ed01', X'019964eeb92879d19159713bf7c8cdbd', 4, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1c658707cbe6afba5933a845b', X'019964eeb92879d19159713bf7c8cdbd', 6, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1c6b1793ab8ba55d7cfe3874c', X'019964eeb92879d19159713bf7c8cdbd', 7, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1c6cc72048e426a9e8fa7fe27', X'019964eeb92879d19159713bf7c8cdbd', 9, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1c6da701f9b5e59e06c45cefb', X'019964eeb92879d19159713bf7c8cdbd', 10, 'Capstone', 'Your Class reaches its peak, and you gain the Capstone Feature of your Class.')
+, (X'019a44c1c758789fba062c4fa431f81e', X'019964eebf03730a95530ec528bb68ce', 1, 'Innate Power', 'Your MP maximum increases by 1. Once per Long Rest, you can cast a Spell you know without spending MP.')
+, (X'019a44c1c7957433b4cfcbb3a3eea848', X'019964eebf03730a95530ec528bb68ce', 1, 'Overload Magic', 'You can spend 2 AP to gain +5 to Spell Checks until the end of your next turn, then make a Might Save or take 1 damage.')
+, (X'019a44c1c7ea725c828928821338a3cb', X'019964eebf03730a95530ec528bb68ce', 2, 'Meta Magic', 'You learn 2 Meta Magic options to alter the Spells you cast.')
+, (X'019a44c1c83873ddaec891c7d0297aaa', X'019964eebf03730a95530ec528bb68ce', 3, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1c886720dba180d67120c9598', X'019964eebf03730a95530ec528bb68ce', 4, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1c88b7231a12f200c1f655290', X'019964eebf03730a95530ec528bb68ce', 6, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1c89e79cbbb9b08fedab0b400', X'019964eebf03730a95530ec528bb68ce', 7, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1c8c6791eb858f33d9a49aa2c', X'019964eebf03730a95530ec528bb68ce', 9, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1c8fe78c2bdac5fb5bed54418', X'019964eebf03730a95530ec528bb68ce', 10, 'Capstone', 'Your Class reaches its peak, and you gain the Capstone Feature of your Class.')
+, (X'019a44c1c99478abb4b7af9f9409031b', X'019964eec5df783bbf5365a4ba231213', 1, 'Bound Weapon', 'You bind a Weapon to yourself during a Short Rest. You can summon it to your hand for free and use it as a Spell Focus.')
+, (X'019a44c1c9e87b96bb84ada925570466', X'019964eec5df783bbf5365a4ba231213', 1, 'Spellstrike', 'When you Hit with your Bound Weapon, you can spend 1 MP to deal 1 extra damage of a type you choose.')
+, (X'019a44c1ca087d9389b2663a1e0645b1', X'019964eec5df783bbf5365a4ba231213', 2, 'Spellblade Disciplines', 'You learn 1 Discipline that blends Maneuvers and Spells.')
+, (X'019a44c1ca2f79deb97055ab7bfcf34d', X'019964eec5df783bbf5365a4ba231213', 3, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1ca8577d7b4be046675eb71a6', X'019964eec5df783bbf5365a4ba231213', 4, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1cad574f2b03ee4a068e36542', X'019964eec5df783bbf5365a4ba231213', 6, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1cb1179b080d19b0a2ef8a691', X'019964eec5df783bbf5365a4ba231213', 7, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1cb4a7cefbbcb8934c9be53e5', X'019964eec5df783bbf5365a4ba231213', 9, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1cb8b77d59a1d4d2fbb6766df', X'019964eec5df783bbf5365a4ba231213', 10, 'Capstone', 'Your Class reaches its peak, and you gain the Capstone Feature of your Class.')
+, (X'019a44c1cc167091b0d519c09fad5afa', X'019964eecd22732fa0ab349338786aed', 1, 'Warlock Contract', 'You make a contract with a patron. You can spend HP instead of MP to cast Spells, at 2 HP per MP.')
+, (X'019a44c1cc5c744c9de957432442b2da', X'019964eecd22732fa0ab349338786aed', 1, 'Hasty Bargain', 'Once per Round, when you make a Check you can spend 1 HP to gain ADV on it.')
+, (X'019a44c1cc91719fb872a169d5f9c966', X'019964eecd22732fa0ab349338786aed', 2, 'Beseech the Patron', 'During a Long Rest you can ask your patron for guidance, gaining ADV on one Check of your choice before your next Long Rest.')
+, (X'019a44c1cce871b8822c30bd97c2a2e6', X'019964eecd22732fa0ab349338786aed', 3, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1cd1a7f01b486b91ea374fab7', X'019964eecd22732fa0ab349338786aed', 4, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1cd487a9fa4970adead7f963a', X'019964eecd22732fa0ab349338786aed', 6, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1cd5d7004967ac0c742a87dbf', X'019964eecd22732fa0ab349338786aed', 7, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1cd7479ee828bad86dbf76dd2', X'019964eecd22732fa0ab349338786aed', 9, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1cdab77b194b3e3dab79a37f1', X'019964eecd22732fa0ab349338786aed', 10, 'Capstone', 'Your Class reaches its peak, and you gain the Capstone Feature of your Class.')
+, (X'019a44c1ce0a7e70bd5fde37f800899d', X'019964eed31576dea71821db96213fcd', 1, 'Spell School Initiate', 'Choose a School of Magic. You learn 1 additional Spell from it and its Spells cost 1 less AP to Sustain.')
+, (X'019a44c1ce627ef4a7c5a9382d35c6c3', X'019964eed31576dea71821db96213fcd', 1, 'Arcane Sigil', 'You can spend 1 AP and 1 MP to create a 1 Space Arcane Sigil. Creatures within it gain a benefit tied to your chosen School.')
+, (X'019a44c1ce827ebfaa8377b4483b6995', X'019964eed31576dea71821db96213fcd', 2, 'Prepared Spell', 'When you finish a Long Rest, choose a Spell you know. You can cast it once without spending MP before your next Long Rest.')
+, (X'019a44c1cea4775eb1069460b20a9284', X'019964eed31576dea71821db96213fcd', 3, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1ceea757489fd184fec890e19', X'019964eed31576dea71821db96213fcd', 4, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1cf3075c6b964051f1c8c807c', X'019964eed31576dea71821db96213fcd', 6, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1cf457ebca422ae0e2d7edead', X'019964eed31576dea71821db96213fcd', 7, 'Talent', 'You gain a Talent of your choice.')
+, (X'019a44c1cf6578a3a49211d1d8502d85', X'019964eed31576dea71821db96213fcd', 9, 'Subclass Feature', 'You gain a Feature from your Subclass.')
+, (X'019a44c1cfab7d4d9481543fc6a01eed', X'019964eed31576dea71821db96213fcd', 10, 'Capstone', 'Your Class reaches its peak, and you gain the Capstone Feature of your Class.')
+ON CONFLICT (`class_feature_id`) DO NOTHING
+;
+
+INSERT INTO `class_table_rows`
+    ( `class_id`
+    , `level`
+    , `health_points`
+    , `attribute_points`
+    , `skill_points`
+    , `trade_points`
+    , `stamina_points`
+    , `maneuvers_known`
+    , `techniques_known`
+    , `mana_points`
+    , `cantrips_known`
+    , `spells_known`
+    )
+VALUES (X'019964edee4b746ea5a85006d034d3ba', 1, 8, 0, 0, 0, 1, 2, 0, 3, 1, 2) -- Artificer 1
+, (X'019964edee4b746ea5a85006d034d3ba', 2, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964edee4b746ea5a85006d034d3ba', 3, 2, 0, 0, 1, 0, 0, 1, 1, 0, 1)
+, (X'019964edee4b746ea5a85006d034d3ba', 4, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964edee4b746ea5a85006d034d3ba', 5, 2, 0, 0, 1, 1, 1, 0, 1, 1, 0)
+, (X'019964edee4b746ea5a85006d034d3ba', 6, 2, 1, 1, 0, 0, 0, 0, 1, 0, 0)
+, (X'019964edee4b746ea5a85006d034d3ba', 7, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964edee4b746ea5a85006d034d3ba', 8, 2, 1, 1, 1, 0, 0, 1, 1, 0, 1)
+, (X'019964edee4b746ea5a85006d034d3ba', 9, 2, 0, 0, 0, 1, 0, 0, 1, 0, 0)
+, (X'019964edee4b746ea5a85006d034d3ba', 10, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0)
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', 1, 9, 0, 0, 0, 1, 4, 0, 0, 0, 0) -- Barbarian 1
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', 2, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', 3, 2, 0, 0, 1, 1, 0, 1, 0, 0, 0)
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', 4, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', 5, 2, 0, 0, 1, 0, 1, 1, 0, 0, 0)
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', 6, 2, 1, 1, 0, 1, 0, 0, 0, 0, 0)
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', 7, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', 8, 2, 1, 1, 1, 0, 1, 1, 0, 0, 0)
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', 9, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0)
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', 10, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0)
+, (X'019964ee6f237b65b83cf0cd85bf985f', 1, 8, 0, 0, 0, 0, 0, 0, 6, 2, 3) -- Bard 1
+, (X'019964ee6f237b65b83cf0cd85bf985f', 2, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964ee6f237b65b83cf0cd85bf985f', 3, 2, 0, 0, 1, 0, 0, 0, 2, 0, 1)
+, (X'019964ee6f237b65b83cf0cd85bf985f', 4, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964ee6f237b65b83cf0cd85bf985f', 5, 2, 0, 0, 1, 0, 0, 0, 2, 1, 0)
+, (X'019964ee6f237b65b83cf0cd85bf985f', 6, 2, 1, 1, 0, 0, 0, 0, 2, 0, 1)
+, (X'019964ee6f237b65b83cf0cd85bf985f', 7, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964ee6f237b65b83cf0cd85bf985f', 8, 2, 1, 1, 1, 0, 0, 0, 2, 1, 0)
+, (X'019964ee6f237b65b83cf0cd85bf985f', 9, 2, 0, 0, 0, 0, 0, 0, 2, 0, 1)
+, (X'019964ee6f237b65b83cf0cd85bf985f', 10, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0)
+, (X'019964ee77db78869c6783619eee210c', 1, 9, 0, 0, 0, 1, 4, 0, 0, 0, 0) -- Champion 1
+, (X'019964ee77db78869c6783619eee210c', 2, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964ee77db78869c6783619eee210c', 3, 2, 0, 0, 1, 1, 0, 1, 0, 0, 0)
+, (X'019964ee77db78869c6783619eee210c', 4, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964ee77db78869c6783619eee210c', 5, 2, 0, 0, 1, 0, 1, 1, 0, 0, 0)
+, (X'019964ee77db78869c6783619eee210c', 6, 2, 1, 1, 0, 1, 0, 0, 0, 0, 0)
+, (X'019964ee77db78869c6783619eee210c', 7, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964ee77db78869c6783619eee210c', 8, 2, 1, 1, 1, 0, 1, 1, 0, 0, 0)
+, (X'019964ee77db78869c6783619eee210c', 9, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0)
+, (X'019964ee77db78869c6783619eee210c', 10, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0)
+, (X'019964ee7ef475a68360cc0a3b12cffc', 1, 8, 0, 0, 0, 0, 0, 0, 6, 2, 3) -- Cleric 1
+, (X'019964ee7ef475a68360cc0a3b12cffc', 2, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964ee7ef475a68360cc0a3b12cffc', 3, 2, 0, 0, 1, 0, 0, 0, 2, 0, 1)
+, (X'019964ee7ef475a68360cc0a3b12cffc', 4, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964ee7ef475a68360cc0a3b12cffc', 5, 2, 0, 0, 1, 0, 0, 0, 2, 1, 0)
+, (X'019964ee7ef475a68360cc0a3b12cffc', 6, 2, 1, 1, 0, 0, 0, 0, 2, 0, 1)
+, (X'019964ee7ef475a68360cc0a3b12cffc', 7, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964ee7ef475a68360cc0a3b12cffc', 8, 2, 1, 1, 1, 0, 0, 0, 2, 1, 0)
+, (X'019964ee7ef475a68360cc0a3b12cffc', 9, 2, 0, 0, 0, 0, 0, 0, 2, 0, 1)
+, (X'019964ee7ef475a68360cc0a3b12cffc', 10, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0)
+, (X'019964ee864d7c15b46cbd28180f1f27', 1, 9, 0, 0, 0, 1, 4, 0, 0, 0, 0) -- Commander 1
+, (X'019964ee864d7c15b46cbd28180f1f27', 2, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964ee864d7c15b46cbd28180f1f27', 3, 2, 0, 0, 1, 1, 0, 1, 0, 0, 0)
+, (X'019964ee864d7c15b46cbd28180f1f27', 4, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964ee864d7c15b46cbd28180f1f27', 5, 2, 0, 0, 1, 0, 1, 1, 0, 0, 0)
+, (X'019964ee864d7c15b46cbd28180f1f27', 6, 2, 1, 1, 0, 1, 0, 0, 0, 0, 0)
+, (X'019964ee864d7c15b46cbd28180f1f27', 7, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964ee864d7c15b46cbd28180f1f27', 8, 2, 1, 1, 1, 0, 1, 1, 0, 0, 0)
+, (X'019964ee864d7c15b46cbd28180f1f27', 9, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0)
+, (X'019964ee864d7c15b46cbd28180f1f27', 10, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0)
+, (X'019964ee8cf6787e91d0f48e5c194f7b', 1, 8, 0, 0, 0, 0, 0, 0, 6, 2, 3) -- Druid 1
+, (X'019964ee8cf6787e91d0f48e5c194f7b', 2, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964ee8cf6787e91d0f48e5c194f7b', 3, 2, 0, 0, 1, 0, 0, 0, 2, 0, 1)
+, (X'019964ee8cf6787e91d0f48e5c194f7b', 4, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964ee8cf6787e91d0f48e5c194f7b', 5, 2, 0, 0, 1, 0, 0, 0, 2, 1, 0)
+, (X'019964ee8cf6787e91d0f48e5c194f7b', 6, 2, 1, 1, 0, 0, 0, 0, 2, 0, 1)
+, (X'019964ee8cf6787e91d0f48e5c194f7b', 7, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964ee8cf6787e91d0f48e5c194f7b', 8, 2, 1, 1, 1, 0, 0, 0, 2, 1, 0)
+, (X'019964ee8cf6787e91d0f48e5c194f7b', 9, 2, 0, 0, 0, 0, 0, 0, 2, 0, 1)
+, (X'019964ee8cf6787e91d0f48e5c194f7b', 10, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0)
+, (X'019964eea1ad7225a1692680524594a3', 1, 9, 0, 0, 0, 1, 4, 0, 0, 0, 0) -- Hunter 1
+, (X'019964eea1ad7225a1692680524594a3', 2, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eea1ad7225a1692680524594a3', 3, 2, 0, 0, 1, 1, 0, 1, 0, 0, 0)
+, (X'019964eea1ad7225a1692680524594a3', 4, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eea1ad7225a1692680524594a3', 5, 2, 0, 0, 1, 0, 1, 1, 0, 0, 0)
+, (X'019964eea1ad7225a1692680524594a3', 6, 2, 1, 1, 0, 1, 0, 0, 0, 0, 0)
+, (X'019964eea1ad7225a1692680524594a3', 7, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eea1ad7225a1692680524594a3', 8, 2, 1, 1, 1, 0, 1, 1, 0, 0, 0)
+, (X'019964eea1ad7225a1692680524594a3', 9, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0)
+, (X'019964eea1ad7225a1692680524594a3', 10, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0)
+, (X'019964eeaac57516a28f2fcfd17fdfb3', 1, 9, 0, 0, 0, 1, 4, 0, 0, 0, 0) -- Monk 1
+, (X'019964eeaac57516a28f2fcfd17fdfb3', 2, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eeaac57516a28f2fcfd17fdfb3', 3, 2, 0, 0, 1, 1, 0, 1, 0, 0, 0)
+, (X'019964eeaac57516a28f2fcfd17fdfb3', 4, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eeaac57516a28f2fcfd17fdfb3', 5, 2, 0, 0, 1, 0, 1, 1, 0, 0, 0)
+, (X'019964eeaac57516a28f2fcfd17fdfb3', 6, 2, 1, 1, 0, 1, 0, 0, 0, 0, 0)
+, (X'019964eeaac57516a28f2fcfd17fdfb3', 7, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eeaac57516a28f2fcfd17fdfb3', 8, 2, 1, 1, 1, 0, 1, 1, 0, 0, 0)
+, (X'019964eeaac57516a28f2fcfd17fdfb3', 9, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0)
+, (X'019964eeaac57516a28f2fcfd17fdfb3', 10, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0)
+, (X'019964eeb1ff7b288f9443d7cf59a4e5', 1, 8, 0, 0, 0, 1, 2, 0, 3, 1, 2) -- Psion 1
+, (X'019964eeb1ff7b288f9443d7cf59a4e5', 2, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eeb1ff7b288f9443d7cf59a4e5', 3, 2, 0, 0, 1, 0, 0, 1, 1, 0, 1)
+, (X'019964eeb1ff7b288f9443d7cf59a4e5', 4, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eeb1ff7b288f9443d7cf59a4e5', 5, 2, 0, 0, 1, 1, 1, 0, 1, 1, 0)
+, (X'019964eeb1ff7b288f9443d7cf59a4e5', 6, 2, 1, 1, 0, 0, 0, 0, 1, 0, 0)
+, (X'019964eeb1ff7b288f9443d7cf59a4e5', 7, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eeb1ff7b288f9443d7cf59a4e5', 8, 2, 1, 1, 1, 0, 0, 1, 1, 0, 1)
+, (X'019964eeb1ff7b288f9443d7cf59a4e5', 9, 2, 0, 0, 0, 1, 0, 0, 1, 0, 0)
+, (X'019964eeb1ff7b288f9443d7cf59a4e5', 10, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0)
+, (X'019964eeb92879d19159713bf7c8cdbd', 1, 9, 0, 0, 0, 1, 4, 0, 0, 0, 0) -- Rogue 1
+, (X'019964eeb92879d19159713bf7c8cdbd', 2, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eeb92879d19159713bf7c8cdbd', 3, 2, 0, 0, 1, 1, 0, 1, 0, 0, 0)
+, (X'019964eeb92879d19159713bf7c8cdbd', 4, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eeb92879d19159713bf7c8cdbd', 5, 2, 0, 0, 1, 0, 1, 1, 0, 0, 0)
+, (X'019964eeb92879d19159713bf7c8cdbd', 6, 2, 1, 1, 0, 1, 0, 0, 0, 0, 0)
+, (X'019964eeb92879d19159713bf7c8cdbd', 7, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eeb92879d19159713bf7c8cdbd', 8, 2, 1, 1, 1, 0, 1, 1, 0, 0, 0)
+, (X'019964eeb92879d19159713bf7c8cdbd', 9, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0)
+, (X'019964eeb92879d19159713bf7c8cdbd', 10, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0)
+, (X'019964eebf03730a95530ec528bb68ce', 1, 8, 0, 0, 0, 0, 0, 0, 6, 2, 3) -- Sorcerer 1
+, (X'019964eebf03730a95530ec528bb68ce', 2, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eebf03730a95530ec528bb68ce', 3, 2, 0, 0, 1, 0, 0, 0, 2, 0, 1)
+, (X'019964eebf03730a95530ec528bb68ce', 4, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eebf03730a95530ec528bb68ce', 5, 2, 0, 0, 1, 0, 0, 0, 2, 1, 0)
+, (X'019964eebf03730a95530ec528bb68ce', 6, 2, 1, 1, 0, 0, 0, 0, 2, 0, 1)
+, (X'019964eebf03730a95530ec528bb68ce', 7, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eebf03730a95530ec528bb68ce', 8, 2, 1, 1, 1, 0, 0, 0, 2, 1, 0)
+, (X'019964eebf03730a95530ec528bb68ce', 9, 2, 0, 0, 0, 0, 0, 0, 2, 0, 1)
+, (X'019964eebf03730a95530ec528bb68ce', 10, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0)
+, (X'019964eec5df783bbf5365a4ba231213', 1, 8, 0, 0, 0, 1, 2, 0, 3, 1, 2) -- Spellblade 1
+, (X'019964eec5df783bbf5365a4ba231213', 2, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eec5df783bbf5365a4ba231213', 3, 2, 0, 0, 1, 0, 0, 1, 1, 0, 1)
+, (X'019964eec5df783bbf5365a4ba231213', 4, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eec5df783bbf5365a4ba231213', 5, 2, 0, 0, 1, 1, 1, 0, 1, 1, 0)
+, (X'019964eec5df783bbf5365a4ba231213', 6, 2, 1, 1, 0, 0, 0, 0, 1, 0, 0)
+, (X'019964eec5df783bbf5365a4ba231213', 7, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eec5df783bbf5365a4ba231213', 8, 2, 1, 1, 1, 0, 0, 1, 1, 0, 1)
+, (X'019964eec5df783bbf5365a4ba231213', 9, 2, 0, 0, 0, 1, 0, 0, 1, 0, 0)
+, (X'019964eec5df783bbf5365a4ba231213', 10, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0)
+, (X'019964eecd22732fa0ab349338786aed', 1, 8, 0, 0, 0, 0, 0, 0, 6, 2, 3) -- Warlock 1
+, (X'019964eecd22732fa0ab349338786aed', 2, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eecd22732fa0ab349338786aed', 3, 2, 0, 0, 1, 0, 0, 0, 2, 0, 1)
+, (X'019964eecd22732fa0ab349338786aed', 4, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eecd22732fa0ab349338786aed', 5, 2, 0, 0, 1, 0, 0, 0, 2, 1, 0)
+, (X'019964eecd22732fa0ab349338786aed', 6, 2, 1, 1, 0, 0, 0, 0, 2, 0, 1)
+, (X'019964eecd22732fa0ab349338786aed', 7, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eecd22732fa0ab349338786aed', 8, 2, 1, 1, 1, 0, 0, 0, 2, 1, 0)
+, (X'019964eecd22732fa0ab349338786aed', 9, 2, 0, 0, 0, 0, 0, 0, 2, 0, 1)
+, (X'019964eecd22732fa0ab349338786aed', 10, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0)
+, (X'019964eed31576dea71821db96213fcd', 1, 8, 0, 0, 0, 0, 0, 0, 6, 2, 3) -- Wizard 1
+, (X'019964eed31576dea71821db96213fcd', 2, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eed31576dea71821db96213fcd', 3, 2, 0, 0, 1, 0, 0, 0, 2, 0, 1)
+, (X'019964eed31576dea71821db96213fcd', 4, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eed31576dea71821db96213fcd', 5, 2, 0, 0, 1, 0, 0, 0, 2, 1, 0)
+, (X'019964eed31576dea71821db96213fcd', 6, 2, 1, 1, 0, 0, 0, 0, 2, 0, 1)
+, (X'019964eed31576dea71821db96213fcd', 7, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+, (X'019964eed31576dea71821db96213fcd', 8, 2, 1, 1, 1, 0, 0, 0, 2, 1, 0)
+, (X'019964eed31576dea71821db96213fcd', 9, 2, 0, 0, 0, 0, 0, 0, 2, 0, 1)
+, (X'019964eed31576dea71821db96213fcd', 10, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0)
+ON CONFLICT (`class_id`, `level`) DO NOTHING
+;
+
+INSERT INTO `skills`
+VALUES (X'01993a736a8577e183451a57d7c324de', 'Awareness', X'01993b832d6c7e7882b2063d613880b9')
+, (X'01993b89eb9d7d71a9481f5dd0e6dd82', 'Athletics', X'01993b83e9f978d4a5ae97c2011f49c6')
+, (X'01993b8ce1b37b1da8d18ff3c1f3d58e', 'Intimidation', X'01993b83e9f978d4a5ae97c2011f49c6')
+, (X'01993b8e7add7d34a04620c11a889327', 'Acrobatics', X'01993b8460827289a9e9cc105341940e')
+, (X'01993b8ec8397c0fa20d9b17738aaf63', 'Trickery', X'01993b8460827289a9e9cc105341940e')
+, (X'01993b8efd737f49a1c955f3e11f885c', 'Stealth', X'01993b8460827289a9e9cc105341940e')
+, (X'01993ea183e17b82b3092a188fb6afda', 'Animal', X'01993b8460827289a9e9cc105341940e')
+, (X'01993ea1a9457087b8e568454a0bdadb', 'Influence', X'01993b8460827289a9e9cc105341940e')
+, (X'01993ea22e1a7d29952eedc454fa9f95', 'Insight', X'01993b8460827289a9e9cc105341940e')
+, (X'01993ea24cee74f9a1e9c9d10269ac62', 'Investigation', X'01993b8460827289a9e9cc105341940e')
+, (X'01993ea27be07dbbbb8c2a79be9df862', 'Medicine', X'01993b8460827289a9e9cc105341940e')
+, (X'01993ea29d2c70f9bc47df7378496cd0', 'Survival', X'01993b8460827289a9e9cc105341940e')
+ON CONFLICT (`skill_id`) DO NOTHING
+;
+
+INSERT INTO `trades`
+VALUES (X'01993c6279d477f690568205594a194f', 'Alchemy')
+, (X'01993c24fd977213be9b22cf1d9abba6', 'Arcana')
+, (X'01993c60a4d47cf4b28b04a2cab76f31', 'Blacksmithing')
+, (X'01993c6263387012b539dae12bce21f3', 'Brewing')
+, (X'01993c625d70790a88440d0dba805941', 'Carpentry')
+, (X'01993c624f007611b33b6c5bfc97872c', 'Cartography')
+, (X'01993c65b4297edeb05e6bbc974a5c58', 'Cooking')
+, (X'01993c61dfba7a028fdf00240ed1ea7c', 'Cryptography')
+, (X'01993c63065175abb414c08d8a76222c', 'Disguise')
+, (X'01993c63288f7ab08bf0bf6dfb02d1ea', 'Engineering')
+, (X'01993c634b6c7719ba3f26ded46cf7a3', 'Gaming')
+, (X'01993c635f5b784f80ccb306c5d1e165', 'Glassblowing')
+, (X'01993c6375607c3aa0ea8f15f2331254', 'Herbalism')
+, (X'01993c2692b37405ac3c43ce36d4e499', 'History')
+, (X'01993c3419ae7bd39c1b9dbc51822077', 'Illustration')
+, (X'01993c638c9e7306b3069151eab22080', 'Jeweler')
+, (X'01993c63d606793fa1fd6af329f7a33b', 'Leatherworking')
+, (X'01993c63f87c7e109e3bec9f469be611', 'Lockpicking')
+, (X'01993c640aac788d9d142249ca54d12e', 'Masonry')
+, (X'01993c35c6e77510b08ce43982d13b13', 'Musician')
+, (X'01993c26c3b277ea9e479a1ca7c697bf', 'Nature')
+, (X'01993c26e3fe743d80e99c7956d25f3a', 'Occultism')
+, (X'01993c271111702884ae000055dff749', 'Religion')
+, (X'01993c642fde7df2adae5fa0a7f0b381', 'Sculpting')
+, (X'01993c64630f78ee93da7ac5e029d4d5', 'Theatre')
+, (X'01993c6482ea75b8894f17be262687bd', 'Tinkering')
+, (X'01993c648fd470b48d6105d6b8f167af', 'Weaving')
+, (X'01993c64a33878f9a402856d3704c9da', 'Vehicles')
+ON CONFLICT (`trade_id`) DO NOTHING
+;
+
+INSERT INTO `attributes_trades`
+VALUES (X'01993b8556b4774aa4a333bd7f76469e', X'01993c24fd977213be9b22cf1d9abba6')
+, (X'01993b8556b4774aa4a333bd7f76469e', X'01993c2692b37405ac3c43ce36d4e499')
+, (X'01993b8556b4774aa4a333bd7f76469e', X'01993c26c3b277ea9e479a1ca7c697bf')
+, (X'01993b8556b4774aa4a333bd7f76469e', X'01993c26e3fe743d80e99c7956d25f3a')
+, (X'01993b8556b4774aa4a333bd7f76469e', X'01993c271111702884ae000055dff749')
+, (X'01993b8460827289a9e9cc105341940e', X'01993c3419ae7bd39c1b9dbc51822077')
+, (X'01993b8460827289a9e9cc105341940e', X'01993c35c6e77510b08ce43982d13b13')
+, (X'01993b84fcf17fcbb1fed093bfd9853d', X'01993c35c6e77510b08ce43982d13b13')
+ON CONFLICT (`attribute_id`, `trade_id`) DO NOTHING
+;
+
+INSERT INTO `masteries`
+VALUES (X'01993c50621f720b98cc90eed9ddddea', 'Novice', 2)
+, (X'01993c50879c7d63848474593e4e0c70', 'Adept', 4)
+, (X'01993c50b7e8763da96d47673a2e12c6', 'Expert', 6)
+, (X'01993c50d7297fed893796dc5e928c04', 'Master', 8)
+, (X'01993c50ed66774d913ae96abef0b500', 'Grandmaster', 10)
+ON CONFLICT (`mastery_id`) DO NOTHING
+;
+
+INSERT INTO `languages`
+VALUES (X'01993e35ae3470bfb26e86f39c6b8d85', 'Common')
+, (X'01993e35e4347c668b34a759162219d0', 'Common Sign')
+, (X'01993e7fe3ef751ea7ebd2208a30435b', 'Human')
+, (X'01993e80e761757c812cfec2e5ddedf1', 'Dwarven')
+, (X'01993e8203ef7d83861bf41cca36819e', 'Elvish')
+, (X'01993e9bde6070a6ab380a2c3f9f023f', 'Gnomish')
+, (X'01993e9c3c32767bb5cd8d552a436565', 'Halfling')
+, (X'01993e9cec66705c85350544dc0878d4', 'Orcish')
+, (X'01993e9d061b74958eb960e2d3f9caa0', 'Giant')
+, (X'01993e9d1cba7e3b9eeb1ee7b3688c5f', 'Draconic')
+, (X'01993e9d37657a6f9adab7074e9f48b1', 'Fey')
+, (X'01993e9d44c8716f95bdb8cecb2b0098', 'Elemental')
+, (X'01993e9d5249721a93806cb086147cd9', 'Celestial')
+, (X'01993e9d6d2d7b6dbff04da13988d0fe', 'Fiend')
+, (X'01993e9d811f7c78b349e1b19561c220', 'Deep Speech')
+ON CONFLICT (`language_id`) DO NOTHING
+;
+
+INSERT INTO `backgrounds`
+    (`background_id`, `name`, `source_id`)
+VALUES (X'01993ea09d21764d9a0b98bb22b619ca', 'Human Mercenary', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1cfff7caaa2ad420ca3a14214', 'Acolyte', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d01c7ca78ff2722ba30a1f02', 'Artisan', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d02475d998582d0af013057c', 'Criminal', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d03d77d7bbbb2ea99bd9603e', 'Entertainer', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d0557ecf8c5f1560df8cd644', 'Farmer', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d08b7cdfaada2864ba69fdb8', 'Guard', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d0947780a31bb34453a8461d', 'Hermit', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d0dc713186185f324244af46', 'Noble', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d0f07d99b49529b4ab456541', 'Sage', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d1347fc3a516c3f7bf6b6d0a', 'Sailor', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d18c7f1f9184345635504971', 'Scout', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d1db763b871a7399dea2f769', 'Urchin', X'019a1d3e5f607a81b2c3d4e5f6071829')
+ON CONFLICT (`background_id`) DO NOTHING
+;
+
+INSERT INTO `backgrounds_languages`
+VALUES (X'01993ea09d21764d9a0b98bb22b619ca', X'01993e35ae3470bfb26e86f39c6b8d85', 2)
+, (X'01993ea09d21764d9a0b98bb22b619ca', X'01993e7fe3ef751ea7ebd2208a30435b', 2)
+, (X'019a44c1cfff7caaa2ad420ca3a14214', X'01993e35ae3470bfb26e86f39c6b8d85', 2) -- Acolyte :: Common
+, (X'019a44c1cfff7caaa2ad420ca3a14214', X'01993e9d5249721a93806cb086147cd9', 1) -- Acolyte :: Celestial
+, (X'019a44c1d01c7ca78ff2722ba30a1f02', X'01993e35ae3470bfb26e86f39c6b8d85', 2) -- Artisan :: Common
+, (X'019a44c1d01c7ca78ff2722ba30a1f02', X'01993e80e761757c812cfec2e5ddedf1', 1) -- Artisan :: Dwarven
+, (X'019a44c1d02475d998582d0af013057c', X'01993e35ae3470bfb26e86f39c6b8d85', 2) -- Criminal :: Common
+, (X'019a44c1d02475d998582d0af013057c', X'01993e35e4347c668b34a759162219d0', 1) -- Criminal :: Common Sign
+, (X'019a44c1d03d77d7bbbb2ea99bd9603e', X'01993e35ae3470bfb26e86f39c6b8d85', 2) -- Entertainer :: Common
+, (X'019a44c1d03d77d7bbbb2ea99bd9603e', X'01993e8203ef7d83861bf41cca36819e', 1) -- Entertainer :: Elvish
+, (X'019a44c1d0557ecf8c5f1560df8cd644', X'01993e35ae3470bfb26e86f39c6b8d85', 2) -- Farmer :: Common
+, (X'019a44c1d0557ecf8c5f1560df8cd644', X'01993e9c3c32767bb5cd8d552a436565', 1) -- Farmer :: Halfling
+, (X'019a44c1d08b7cdfaada2864ba69fdb8', X'01993e35ae3470bfb26e86f39c6b8d85', 2) -- Guard :: Common
+, (X'019a44c1d08b7cdfaada2864ba69fdb8', X'01993e9cec66705c85350544dc0878d4', 1) -- Guard :: Orcish
+, (X'019a44c1d0947780a31bb34453a8461d', X'01993e35ae3470bfb26e86f39c6b8d85', 2) -- Hermit :: Common
+, (X'019a44c1d0947780a31bb34453a8461d', X'01993e9d37657a6f9adab7074e9f48b1', 1) -- Hermit :: Fey
+, (X'019a44c1d0dc713186185f324244af46', X'01993e35ae3470bfb26e86f39c6b8d85', 2) -- Noble :: Common
+, (X'019a44c1d0dc713186185f324244af46', X'01993e9d1cba7e3b9eeb1ee7b3688c5f', 1) -- Noble :: Draconic
+, (X'019a44c1d0f07d99b49529b4ab456541', X'01993e35ae3470bfb26e86f39c6b8d85', 2) -- Sage :: Common
+, (X'019a44c1d0f07d99b49529b4ab456541', X'01993e9d44c8716f95bdb8cecb2b0098', 1) -- Sage :: Elemental
+, (X'019a44c1d1347fc3a516c3f7bf6b6d0a', X'01993e35ae3470bfb26e86f39c6b8d85', 2) -- Sailor :: Common
+, (X'019a44c1d1347fc3a516c3f7bf6b6d0a', X'01993e9d061b74958eb960e2d3f9caa0', 1) -- Sailor :: Giant
+, (X'019a44c1d18c7f1f9184345635504971', X'01993e35ae3470bfb26e86f39c6b8d85', 2) -- Scout :: Common
+, (X'019a44c1d18c7f1f9184345635504971', X'01993e9bde6070a6ab380a2c3f9f023f', 1) -- Scout :: Gnomish
+, (X'019a44c1d1db763b871a7399dea2f769', X'01993e35ae3470bfb26e86f39c6b8d85', 2) -- Urchin :: Common
+, (X'019a44c1d1db763b871a7399dea2f769', X'01993e9d811f7c78b349e1b19561c220', 1) -- Urchin :: Deep Speech
+ON CONFLICT (`background_id`, `language_id`) DO NOTHING
+;
+
+INSERT INTO `backgrounds_skills`
+VALUES (X'01993ea09d21764d9a0b98bb22b619ca', X'01993a736a8577e183451a57d7c324de')
+, (X'01993ea09d21764d9a0b98bb22b619ca', X'01993b89eb9d7d71a9481f5dd0e6dd82')
+, (X'019a44c1cfff7caaa2ad420ca3a14214', X'01993ea22e1a7d29952eedc454fa9f95') -- Acolyte :: Insight
+, (X'019a44c1cfff7caaa2ad420ca3a14214', X'01993ea27be07dbbbb8c2a79be9df862') -- Acolyte :: Medicine
+, (X'019a44c1d01c7ca78ff2722ba30a1f02', X'01993ea24cee74f9a1e9c9d10269ac62') -- Artisan :: Investigation
+, (X'019a44c1d01c7ca78ff2722ba30a1f02', X'01993ea1a9457087b8e568454a0bdadb') -- Artisan :: Influence
+, (X'019a44c1d02475d998582d0af013057c', X'01993b8efd737f49a1c955f3e11f885c') -- Criminal :: Stealth
+, (X'019a44c1d02475d998582d0af013057c', X'01993b8ec8397c0fa20d9b17738aaf63') -- Criminal :: Trickery
+, (X'019a44c1d03d77d7bbbb2ea99bd9603e', X'01993b8e7add7d34a04620c11a889327') -- Entertainer :: Acrobatics
+, (X'019a44c1d03d77d7bbbb2ea99bd9603e', X'01993ea1a9457087b8e568454a0bdadb') -- Entertainer :: Influence
+, (X'019a44c1d0557ecf8c5f1560df8cd644', X'01993ea183e17b82b3092a188fb6afda') -- Farmer :: Animal
+, (X'019a44c1d0557ecf8c5f1560df8cd644', X'01993b89eb9d7d71a9481f5dd0e6dd82') -- Farmer :: Athletics
+, (X'019a44c1d08b7cdfaada2864ba69fdb8', X'01993a736a8577e183451a57d7c324de') -- Guard :: Awareness
+, (X'019a44c1d08b7cdfaada2864ba69fdb8', X'01993b8ce1b37b1da8d18ff3c1f3d58e') -- Guard :: Intimidation
+, (X'019a44c1d0947780a31bb34453a8461d', X'01993ea27be07dbbbb8c2a79be9df862') -- Hermit :: Medicine
+, (X'019a44c1d0947780a31bb34453a8461d', X'01993ea29d2c70f9bc47df7378496cd0') -- Hermit :: Survival
+, (X'019a44c1d0dc713186185f324244af46', X'01993ea1a9457087b8e568454a0bdadb') -- Noble :: Influence
+, (X'019a44c1d0dc713186185f324244af46', X'01993ea22e1a7d29952eedc454fa9f95') -- Noble :: Insight
+, (X'019a44c1d0f07d99b49529b4ab456541', X'01993ea24cee74f9a1e9c9d10269ac62') -- Sage :: Investigation
+, (X'019a44c1d0f07d99b49529b4ab456541', X'01993a736a8577e183451a57d7c324de') -- Sage :: Awareness
+, (X'019a44c1d1347fc3a516c3f7bf6b6d0a', X'01993b89eb9d7d71a9481f5dd0e6dd82') -- Sailor :: Athletics
+, (X'019a44c1d1347fc3a516c3f7bf6b6d0a', X'01993b8e7add7d34a04620c11a889327') -- Sailor :: Acrobatics
+, (X'019a44c1d18c7f1f9184345635504971', X'01993b8efd737f49a1c955f3e11f885c') -- Scout :: Stealth
+, (X'019a44c1d18c7f1f9184345635504971', X'01993ea29d2c70f9bc47df7378496cd0') -- Scout :: Survival
+, (X'019a44c1d1db763b871a7399dea2f769', X'01993b8ec8397c0fa20d9b17738aaf63') -- Urchin :: Trickery
+, (X'019a44c1d1db763b871a7399dea2f769', X'01993b8efd737f49a1c955f3e11f885c') -- Urchin :: Stealth
+ON CONFLICT (`background_id`, `skill_id`) DO NOTHING
+;
+
+INSERT INTO `backgrounds_trades`
+VALUES (X'01993ea09d21764d9a0b98bb22b619ca', X'01993c624f007611b33b6c5bfc97872c') -- Human Mercenery :: Cartography
+, (X'01993ea09d21764d9a0b98bb22b619ca', X'01993c64a33878f9a402856d3704c9da') -- Human Mercenery :: Vehicles
+, (X'019a44c1cfff7caaa2ad420ca3a14214', X'01993c271111702884ae000055dff749') -- Acolyte :: Religion
+, (X'019a44c1cfff7caaa2ad420ca3a14214', X'01993c3419ae7bd39c1b9dbc51822077') -- Acolyte :: Illustration
+, (X'019a44c1d01c7ca78ff2722ba30a1f02', X'01993c60a4d47cf4b28b04a2cab76f31') -- Artisan :: Blacksmithing
+, (X'019a44c1d01c7ca78ff2722ba30a1f02', X'01993c625d70790a88440d0dba805941') -- Artisan :: Carpentry
+, (X'019a44c1d02475d998582d0af013057c', X'01993c63f87c7e109e3bec9f469be611') -- Criminal :: Lockpicking
+, (X'019a44c1d02475d998582d0af013057c', X'01993c63065175abb414c08d8a76222c') -- Criminal :: Disguise
+, (X'019a44c1d03d77d7bbbb2ea99bd9603e', X'01993c35c6e77510b08ce43982d13b13') -- Entertainer :: Musician
+, (X'019a44c1d03d77d7bbbb2ea99bd9603e', X'01993c64630f78ee93da7ac5e029d4d5') -- Entertainer :: Theatre
+, (X'019a44c1d0557ecf8c5f1560df8cd644', X'01993c6375607c3aa0ea8f15f2331254') -- Farmer :: Herbalism
+, (X'019a44c1d0557ecf8c5f1560df8cd644', X'01993c65b4297edeb05e6bbc974a5c58') -- Farmer :: Cooking
+, (X'019a44c1d08b7cdfaada2864ba69fdb8', X'01993c634b6c7719ba3f26ded46cf7a3') -- Guard :: Gaming
+, (X'019a44c1d08b7cdfaada2864ba69fdb8', X'01993c60a4d47cf4b28b04a2cab76f31') -- Guard :: Blacksmithing
+, (X'019a44c1d0947780a31bb34453a8461d', X'01993c6375607c3aa0ea8f15f2331254') -- Hermit :: Herbalism
+, (X'019a44c1d0947780a31bb34453a8461d', X'01993c26e3fe743d80e99c7956d25f3a') -- Hermit :: Occultism
+, (X'019a44c1d0dc713186185f324244af46', X'01993c2692b37405ac3c43ce36d4e499') -- Noble :: History
+, (X'019a44c1d0dc713186185f324244af46', X'01993c638c9e7306b3069151eab22080') -- Noble :: Jeweler
+, (X'019a44c1d0f07d99b49529b4ab456541', X'01993c24fd977213be9b22cf1d9abba6') -- Sage :: Arcana
+, (X'019a44c1d0f07d99b49529b4ab456541', X'01993c2692b37405ac3c43ce36d4e499') -- Sage :: History
+, (X'019a44c1d1347fc3a516c3f7bf6b6d0a', X'01993c64a33878f9a402856d3704c9da') -- Sailor :: Vehicles
+, (X'019a44c1d1347fc3a516c3f7bf6b6d0a', X'01993c624f007611b33b6c5bfc97872c') -- Sailor :: Cartography
+, (X'019a44c1d18c7f1f9184345635504971', X'01993c624f007611b33b6c5bfc97872c') -- Scout :: Cartography
+, (X'019a44c1d18c7f1f9184345635504971', X'01993c26c3b277ea9e479a1ca7c697bf') -- Scout :: Nature
+, (X'019a44c1d1db763b871a7399dea2f769', X'01993c63f87c7e109e3bec9f469be611') -- Urchin :: Lockpicking
+, (X'019a44c1d1db763b871a7399dea2f769', X'01993c634b6c7719ba3f26ded46cf7a3') -- Urchin :: Gaming
+ON CONFLICT (`background_id`, `trade_id`) DO NOTHING
+;
+
+INSERT INTO `spell_schools`
+    (`spell_school_id`, `name`)
+VALUES (X'01999676d58d7840b02951505eb57504', 'Destruction')
+, (X'019a44c1d1fe78d887a7e9475b927b8d', 'Astromancy')
+, (X'019a44c1d23175f4a21d86eca63f8df6', 'Conjuration')
+, (X'019a44c1d25074a7bdeffb9a403d8530', 'Divination')
+, (X'019a44c1d2a07ad4a9a38222b2f3db08', 'Enchantment')
+, (X'019a44c1d2cc791db09f5df009cfdf35', 'Illusion')
+, (X'019a44c1d31e73519c8c08c84bd59a2c', 'Necromancy')
+, (X'019a44c1d34a7c11895c454a1d3d6a07', 'Protection')
+, (X'019a44c1d39872edb7f6aa7ae1cad3e9', 'Restoration')
+, (X'019a44c1d3bc757d860f1ec54624358f', 'Transmutation')
+ON CONFLICT (`spell_school_id`) DO NOTHING
+;
+
+INSERT INTO `spells`
+    (`spell_id`, `name`, `spell_school_id`, `action_point_cost`, `mana_point_cost`, `range_kind`, `range_value`, `duration_kind`, `duration_value`, `sustained`, `description`, `source_id`)
+VALUES (X'0199967326fe7954825fbbc78d2300a2', 'Fire Bolt', X'01999676d58d7840b02951505eb57504', 1, 0, 'Spaces', 10, 'Instant', NULL, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d5a1739385b6b6e38c4c6594', 'Frost Bolt', X'01999676d58d7840b02951505eb57504', 1, 0, 'Spaces', 10, 'Instant', NULL, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d6247b129d1841225073bdb7', 'Shock', X'01999676d58d7840b02951505eb57504', 1, 0, 'Touch', NULL, 'Instant', NULL, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d69b7b059efdfae1ff86a4d3', 'Sacred Bolt', X'01999676d58d7840b02951505eb57504', 1, 0, 'Spaces', 10, 'Instant', NULL, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d73c7ca690f2f0ea61059378', 'Death Bolt', X'019a44c1d31e73519c8c08c84bd59a2c', 1, 0, 'Spaces', 10, 'Instant', NULL, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d80b77c394628754488c6fd6', 'Mage Hand', X'019a44c1d23175f4a21d86eca63f8df6', 1, 0, 'Spaces', 6, 'Minute', 1, true, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d8967ae2acf4356ada1c2ad9', 'Minor Illusion', X'019a44c1d2cc791db09f5df009cfdf35', 1, 0, 'Spaces', 6, 'Minute', 1, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d93f735ba494a0e7deeb21c6', 'Light', X'019a44c1d23175f4a21d86eca63f8df6', 1, 0, 'Touch', NULL, 'Hour', 1, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d9607078a6eb2d8ab1e48262', 'Message', X'019a44c1d25074a7bdeffb9a403d8530', 1, 0, 'Spaces', 20, 'Round', 1, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1d9f4742ba6fae04bbc6478f1', 'Guidance', X'019a44c1d25074a7bdeffb9a403d8530', 1, 0, 'Touch', NULL, 'Minute', 1, true, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1da4a7978a6b3023add615f67', 'Nature''s Touch', X'019a44c1d3bc757d860f1ec54624358f', 1, 0, 'Spaces', 6, 'Instant', NULL, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1dae072c7b1abaee4bf50a940', 'Magic Missile', X'01999676d58d7840b02951505eb57504', 2, 1, 'Spaces', 15, 'Instant', NULL, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1db637ba284d589c97e51853d', 'Fireball', X'01999676d58d7840b02951505eb57504', 2, 2, 'Spaces', 30, 'Instant', NULL, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1dba674b586032b6f9f20dc22', 'Lightning Bolt', X'01999676d58d7840b02951505eb57504', 2, 2, 'Self', NULL, 'Instant', NULL, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1dc4d7dbc914f8798f7c20191', 'Heal', X'019a44c1d39872edb7f6aa7ae1cad3e9', 2, 1, 'Touch', NULL, 'Instant', NULL, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1dcb37135a7295ef64163ed9a', 'Return to Life', X'019a44c1d39872edb7f6aa7ae1cad3e9', 2, 3, 'Touch', NULL, 'Instant', NULL, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1dcfd73df9735f5ea2eb0d158', 'Bless', X'019a44c1d2a07ad4a9a38222b2f3db08', 2, 1, 'Spaces', 6, 'Minute', 1, true, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1dd3b7b0b9e50851a931bce42', 'Bane', X'019a44c1d2a07ad4a9a38222b2f3db08', 2, 1, 'Spaces', 6, 'Minute', 1, true, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1dd6874619a9c6ece8d8a22e8', 'Command', X'019a44c1d2a07ad4a9a38222b2f3db08', 1, 1, 'Spaces', 6, 'Round', 1, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1ddeb70d8ba616192d220ed07', 'Sleep', X'019a44c1d2a07ad4a9a38222b2f3db08', 2, 1, 'Spaces', 12, 'Minute', 1, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1de2e77178fdd3fca2febdf35', 'Charm', X'019a44c1d2a07ad4a9a38222b2f3db08', 2, 1, 'Spaces', 6, 'Hour', 1, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1de7d7861a3b474701986fe59', 'Shield', X'019a44c1d34a7c11895c454a1d3d6a07', 1, 1, 'Self', NULL, 'Round', 1, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1decb77c4a5b0fb0cd7650012', 'Mage Armor', X'019a44c1d34a7c11895c454a1d3d6a07', 2, 1, 'Touch', NULL, 'Hour', 8, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1df0374689462652aabe5743c', 'Sanctuary', X'019a44c1d34a7c11895c454a1d3d6a07', 1, 1, 'Spaces', 6, 'Minute', 1, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1df607823af865d5acd0688b8', 'Barkskin', X'019a44c1d34a7c11895c454a1d3d6a07', 2, 1, 'Touch', NULL, 'Hour', 1, true, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1dfcc71dd8e227eaef5b03d17', 'Fog Cloud', X'019a44c1d23175f4a21d86eca63f8df6', 2, 1, 'Spaces', 20, 'Hour', 1, true, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1dff67c818fa685ee234d3433', 'Web', X'019a44c1d23175f4a21d86eca63f8df6', 2, 1, 'Spaces', 12, 'Hour', 1, true, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1e03f752fa30c550dba758b12', 'Find Familiar', X'019a44c1d23175f4a21d86eca63f8df6', 2, 1, 'Spaces', 2, 'Hour', 24, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1e0b27fc1bd4067f052d1e0ce', 'Detect Magic', X'019a44c1d25074a7bdeffb9a403d8530', 1, 1, 'Self', NULL, 'Minute', 10, true, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1e14a728397181297472a5396', 'Identify', X'019a44c1d25074a7bdeffb9a403d8530', 2, 1, 'Touch', NULL, 'Instant', NULL, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1e1787d03b69644de0e488aeb', 'Tongues', X'019a44c1d25074a7bdeffb9a403d8530', 2, 1, 'Touch', NULL, 'Hour', 1, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1e2117cf6a729c3ae8e343f31', 'Misty Step', X'019a44c1d1fe78d887a7e9475b927b8d', 1, 1, 'Self', NULL, 'Instant', NULL, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1e28e72f79cc6dbf616201237', 'Haste', X'019a44c1d1fe78d887a7e9475b927b8d', 2, 2, 'Spaces', 6, 'Minute', 1, true, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1e3027c109fad0310c0ffa68d', 'Slow', X'019a44c1d1fe78d887a7e9475b927b8d', 2, 2, 'Spaces', 12, 'Minute', 1, true, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1e36873e4a2756a93b63267ad', 'Gravity Sink', X'019a44c1d1fe78d887a7e9475b927b8d', 2, 1, 'Spaces', 10, 'Round', 1, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1e3e27bd4bdd0a25fe5f89333', 'Invisibility', X'019a44c1d2cc791db09f5df009cfdf35', 2, 2, 'Touch', NULL, 'Hour', 1, true, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1e43a7bec81a0b5ece9f8bf86', 'Mirror Image', X'019a44c1d2cc791db09f5df009cfdf35', 2, 1, 'Self', NULL, 'Minute', 1, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1e4b5730480d296c572d21765', 'Entangle', X'019a44c1d3bc757d860f1ec54624358f', 2, 1, 'Spaces', 12, 'Minute', 1, true, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1e52a72ddb25616a3fe026797', 'Vampiric Touch', X'019a44c1d31e73519c8c08c84bd59a2c', 2, 1, 'Touch', NULL, 'Minute', 1, true, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a44c1e561750a99c40f429c27877b', 'Animate Dead', X'019a44c1d31e73519c8c08c84bd59a2c', 2, 2, 'Spaces', 2, 'Hour', 24, false, NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+ON CONFLICT (`spell_id`) DO NOTHING
+;
+
+INSERT INTO `spell_effects`
+    (`spell_effect_id`, `name`, `description`)
+VALUES (X'0199b77066f17129b38fbae190de4194', 'Spell Attack (Fire II)', 'You can make a Spell Check against the PD of a target within range. Hit: The target takes 2 Fire damage.')
+, (X'0199b787e5677f238326c33865489868', 'Fire Orb', 'A flickering flame appears in your hand...')
+, (X'0199b788024c75f9ae90fc6265abbae8', 'Cantrip Passive (Burning)', 'You deal +1 damage against creatures that are Burning.')
+, (X'019a44c1d5eb7fe093c784556b5614d9', 'Spell Attack (Cold II)', 'You can make a Spell Check against the PD of a target within range. Hit: The target takes 2 Cold damage.')
+, (X'019a44c1d61675fb87998573e96dcc0f', 'Cantrip Passive (Slowed)', 'A creature damaged by this Spell is Slowed until the start of your next turn.')
+, (X'019a44c1d6427c4fa9cf61151e122139', 'Spell Attack (Lightning II)', 'You can make a Spell Check against the PD of a target within range. Hit: The target takes 2 Lightning damage.')
+, (X'019a44c1d650789295cd6ee2aa109b0c', 'Cantrip Passive (Metal)', 'You have ADV on the Spell Check against creatures wearing metal Armor.')
+, (X'019a44c1d6ba7fa59469f8ed0f32874c', 'Spell Attack (Radiant II)', 'You can make a Spell Check against the PD of a target within range. Hit: The target takes 2 Radiant damage.')
+, (X'019a44c1d6e47caaaf248494bb120389', 'Cantrip Passive (Undead)', 'You deal +1 damage against Undead.')
+, (X'019a44c1d78a70f3906349ff136f8e03', 'Spell Attack (Umbral II)', 'You can make a Spell Check against the PD of a target within range. Hit: The target takes 2 Umbral damage.')
+, (X'019a44c1d7c77810a0d3aea03862c80b', 'Cantrip Passive (No Healing)', 'A creature damaged by this Spell can''t regain HP until the start of your next turn.')
+, (X'019a44c1d84578428fec4f00fedab174', 'Spectral Hand', 'A spectral hand appears at a point within range. You can spend 1 AP to use it to manipulate an object weighing up to 10 pounds.')
+, (X'019a44c1d8eb7e11a48167c4541f131f', 'Illusory Image', 'You create a sound or an image of an object no larger than 1 Space. A creature that studies it can make an Investigation Check against your Save DC to see through it.')
+, (X'019a44c1d9587307b03be6203d412d16', 'Shed Light', 'An object you touch sheds Bright Light in a 5 Space radius and Dim Light for a further 5 Spaces.')
+, (X'019a44c1d9a47dc3b4008381e62a0ab0', 'Whisper', 'You whisper a message to a creature within range, and only it hears. It can reply in a whisper that only you hear.')
+, (X'019a44c1da1c7e59bf30247b32d4a2ad', 'Guiding Hand', 'The target gains a d4 Help Die it can add to one Check of its choice before the Spell ends.')
+, (X'019a44c1da9d7e1c958cdeaf8f2856d3', 'Minor Nature', 'You make a flower bloom, predict the weather for the next day, or make a harmless sensory effect involving plants or animals.')
+, (X'019a44c1db0c75f68a0f780b2f4f5b16', 'Unerring Darts', 'You create 3 glowing darts. Each deals 1 True damage to a target of your choice within range. They always Hit.')
+, (X'019a44c1db6a76419db5bc3bfa1be647', 'Fiery Explosion', 'Each creature within a 4 Space Sphere centered on a point in range makes an Agility Save. Failure: 4 Fire damage. Success: 2 Fire damage.')
+, (X'019a44c1dbfa7b23b3d7184b7e088466', 'Bolt of Lightning', 'Each creature in a 15 Space Line makes an Agility Save. Failure: 4 Lightning damage. Success: 2 Lightning damage.')
+, (X'019a44c1dc7a74848de33531b1ff1cae', 'Restore Health', 'The target regains 4 HP.')
+, (X'019a44c1dce6752d92af08ca75af7fe4', 'Revive', 'A creature that died within the last minute returns to life with 1 HP.')
+, (X'019a44c1dd1d7ec682523f614f19fcf4', 'Blessing', 'Up to 3 creatures within range gain a d4 bonus to Attacks and Saves for the duration.')
+, (X'019a44c1dd4175b0a857a356025ce92e', 'Curse', 'Up to 3 creatures within range make a Charisma Save. Failure: they subtract a d4 from Attacks and Saves for the duration.')
+, (X'019a44c1ddae7ec19d100e993c6ef9b3', 'One Word', 'A creature within range makes a Charisma Save. Failure: it follows a one-word command on its next turn.')
+, (X'019a44c1ddf87b89aefa755f2150353d', 'Slumber', 'Each creature of your choice within a 2 Space Sphere in range makes a Charisma Save. Failure: it falls Unconscious until it takes damage or is woken.')
+, (X'019a44c1de36766d9cf020c2ac3634ae', 'Charming Words', 'A creature within range makes a Charisma Save. Failure: it is Charmed by you for the duration, and knows it was charmed when the Spell ends.')
+, (X'019a44c1de837ffa9cb1c3c98b65aec7', 'Arcane Shield', 'You can cast this Spell as a Reaction when you are targeted by an Attack. You gain +5 PD until the start of your next turn.')
+, (X'019a44c1dee27fa1bfb9016628958657', 'Arcane Armor', 'A willing creature not wearing Armor gains +2 PD and +2 AD for the duration.')
+, (X'019a44c1df1e78eaab37701ff6c776d9', 'Warding', 'A creature that targets the warded creature with an Attack or harmful Spell must first succeed on a Charisma Save or choose another target.')
+, (X'019a44c1df86775b959193a24160bbe7', 'Bark Hide', 'The target''s skin becomes tough as bark. It gains 1 PDR for the duration.')
+, (X'019a44c1dfef7c8d8e76afd1f2d5133c', 'Fog', 'You create a 4 Space Sphere of fog centered on a point in range. The area is Heavily Obscured.')
+, (X'019a44c1e0327bc6b7ae953eb6d82b47', 'Sticky Webs', 'You fill a 4 Space Cube with webs. It is Difficult Terrain, and creatures entering it make an Agility Save or become Restrained.')
+, (X'019a44c1e0947a7dbcefc7f0f6a49b08', 'Familiar', 'You summon a Tiny spirit in the form of an animal of your choice. It acts independently and you can see through its senses.')
+, (X'019a44c1e105718190a7de36cace0bf0', 'Sense Magic', 'You sense the presence of magic within 6 Spaces, and can spend 1 AP to see a faint aura around a visible magical creature or object.')
+, (X'019a44c1e15e7bf98509fa272a250a5d', 'Learn Properties', 'You learn the properties of a magical object you touch, including how to use it and how many charges it has.')
+, (X'019a44c1e1bc72dfba9401489a968200', 'Understanding', 'The target understands any spoken language it hears, and creatures that know a language understand what it says.')
+, (X'019a44c1e26271a482afa496493d9379', 'Teleport', 'You teleport up to 6 Spaces to an unoccupied space you can see.')
+, (X'019a44c1e2b471948c80b85e4c66db11', 'Quickened', 'A willing creature gains +2 Speed, +1 PD and 1 additional AP at the start of each of its turns.')
+, (X'019a44c1e3547ef889373855b8d5cd3e', 'Slowed Time', 'Up to 3 creatures within range make a Might Save. Failure: they are Slowed and have -1 AP for the duration.')
+, (X'019a44c1e38979bc907027be4ffedbf9', 'Crushing Gravity', 'Each creature in a 2 Space Sphere centered on a point in range makes a Might Save. Failure: it is pulled to the center and falls Prone.')
+, (X'019a44c1e41f7cf593c7afe506bafcd8', 'Invisible', 'The target becomes Invisible until the Spell ends or it makes an Attack or casts a Spell.')
+, (X'019a44c1e47472c9b84e6c1144ca370f', 'Duplicates', 'Three illusory duplicates of yourself appear. Each time you are targeted by an Attack, roll a d6; on a 3 or higher a duplicate is hit and destroyed instead.')
+, (X'019a44c1e4e77a2d99b10e9f3f530796', 'Grasping Plants', 'Plants sprout in a 4 Space Square. Creatures in the area make a Might Save or become Restrained.')
+, (X'019a44c1e54372dd83363f3d9a5f21a5', 'Life Drain', 'You can make a Spell Check against the PD of a creature within range. Hit: it takes 3 Umbral damage and you regain HP equal to half the damage dealt.')
+, (X'019a44c1e5b774cc9120e560bb98d9cf', 'Raise Undead', 'You raise a pile of bones or a corpse within range as a Skeleton or Zombie that obeys your commands.')
+ON CONFLICT (`spell_effect_id`) DO NOTHING
+;
+
+INSERT INTO `spells_spell_effects`
+    (`spell_id`, `spell_effect_id`)
+VALUES (X'0199967326fe7954825fbbc78d2300a2', X'0199b77066f17129b38fbae190de4194')
+, (X'0199967326fe7954825fbbc78d2300a2', X'0199b787e5677f238326c33865489868')
+, (X'0199967326fe7954825fbbc78d2300a2', X'0199b788024c75f9ae90fc6265abbae8')
+, (X'019a44c1d5a1739385b6b6e38c4c6594', X'019a44c1d5eb7fe093c784556b5614d9') -- Frost Bolt :: Spell Attack (Cold II)
+, (X'019a44c1d5a1739385b6b6e38c4c6594', X'019a44c1d61675fb87998573e96dcc0f') -- Frost Bolt :: Cantrip Passive (Slowed)
+, (X'019a44c1d6247b129d1841225073bdb7', X'019a44c1d6427c4fa9cf61151e122139') -- Shock :: Spell Attack (Lightning II)
+, (X'019a44c1d6247b129d1841225073bdb7', X'019a44c1d650789295cd6ee2aa109b0c') -- Shock :: Cantrip Passive (Metal)
+, (X'019a44c1d69b7b059efdfae1ff86a4d3', X'019a44c1d6ba7fa59469f8ed0f32874c') -- Sacred Bolt :: Spell Attack (Radiant II)
+, (X'019a44c1d69b7b059efdfae1ff86a4d3', X'019a44c1d6e47caaaf248494bb120389') -- Sacred Bolt :: Cantrip Passive (Undead)
+, (X'019a44c1d73c7ca690f2f0ea61059378', X'019a44c1d78a70f3906349ff136f8e03') -- Death Bolt :: Spell Attack (Umbral II)
+, (X'019a44c1d73c7ca690f2f0ea61059378', X'019a44c1d7c77810a0d3aea03862c80b') -- Death Bolt :: Cantrip Passive (No Healing)
+, (X'019a44c1d80b77c394628754488c6fd6', X'019a44c1d84578428fec4f00fedab174') -- Mage Hand :: Spectral Hand
+, (X'019a44c1d8967ae2acf4356ada1c2ad9', X'019a44c1d8eb7e11a48167c4541f131f') -- Minor Illusion :: Illusory Image
+, (X'019a44c1d93f735ba494a0e7deeb21c6', X'019a44c1d9587307b03be6203d412d16') -- Light :: Shed Light
+, (X'019a44c1d9607078a6eb2d8ab1e48262', X'019a44c1d9a47dc3b4008381e62a0ab0') -- Message :: Whisper
+, (X'019a44c1d9f4742ba6fae04bbc6478f1', X'019a44c1da1c7e59bf30247b32d4a2ad') -- Guidance :: Guiding Hand
+, (X'019a44c1da4a7978a6b3023add615f67', X'019a44c1da9d7e1c958cdeaf8f2856d3') -- Nature's Touch :: Minor Nature
+, (X'019a44c1dae072c7b1abaee4bf50a940', X'019a44c1db0c75f68a0f780b2f4f5b16') -- Magic Missile :: Unerring Darts
+, (X'019a44c1db637ba284d589c97e51853d', X'019a44c1db6a76419db5bc3bfa1be647') -- Fireball :: Fiery Explosion
+, (X'019a44c1dba674b586032b6f9f20dc22', X'019a44c1dbfa7b23b3d7184b7e088466') -- Lightning Bolt :: Bolt of Lightning
+, (X'019a44c1dc4d7dbc914f8798f7c20191', X'019a44c1dc7a74848de33531b1ff1cae') -- Heal :: Restore Health
+, (X'019a44c1dcb37135a7295ef64163ed9a', X'019a44c1dce6752d92af08ca75af7fe4') -- Return to Life :: Revive
+, (X'019a44c1dcfd73df9735f5ea2eb0d158', X'019a44c1dd1d7ec682523f614f19fcf4') -- Bless :: Blessing
+, (X'019a44c1dd3b7b0b9e50851a931bce42', X'019a44c1dd4175b0a857a356025ce92e') -- Bane :: Curse
+, (X'019a44c1dd6874619a9c6ece8d8a22e8', X'019a44c1ddae7ec19d100e993c6ef9b3') -- Command :: One Word
+, (X'019a44c1ddeb70d8ba616192d220ed07', X'019a44c1ddf87b89aefa755f2150353d') -- Sleep :: Slumber
+, (X'019a44c1de2e77178fdd3fca2febdf35', X'019a44c1de36766d9cf020c2ac3634ae') -- Charm :: Charming Words
+, (X'019a44c1de7d7861a3b474701986fe59', X'019a44c1de837ffa9cb1c3c98b65aec7') -- Shield :: Arcane Shield
+, (X'019a44c1decb77c4a5b0fb0cd7650012', X'019a44c1dee27fa1bfb9016628958657') -- Mage Armor :: Arcane Armor
+, (X'019a44c1df0374689462652aabe5743c', X'019a44c1df1e78eaab37701ff6c776d9') -- Sanctuary :: Warding
+, (X'019a44c1df607823af865d5acd0688b8', X'019a44c1df86775b959193a24160bbe7') -- Barkskin :: Bark Hide
+, (X'019a44c1dfcc71dd8e227eaef5b03d17', X'019a44c1dfef7c8d8e76afd1f2d5133c') -- Fog Cloud :: Fog
+, (X'019a44c1dff67c818fa685ee234d3433', X'019a44c1e0327bc6b7ae953eb6d82b47') -- Web :: Sticky Webs
+, (X'019a44c1e03f752fa30c550dba758b12', X'019a44c1e0947a7dbcefc7f0f6a49b08') -- Find Familiar :: Familiar
+, (X'019a44c1e0b27fc1bd4067f052d1e0ce', X'019a44c1e105718190a7de36cace0bf0') -- Detect Magic :: Sense Magic
+, (X'019a44c1e14a728397181297472a5396', X'019a44c1e15e7bf98509fa272a250a5d') -- Identify :: Learn Properties
+, (X'019a44c1e1787d03b69644de0e488aeb', X'019a44c1e1bc72dfba9401489a968200') -- Tongues :: Understanding
+, (X'019a44c1e2117cf6a729c3ae8e343f31', X'019a44c1e26271a482afa496493d9379') -- Misty Step :: Teleport
+, (X'019a44c1e28e72f79cc6dbf616201237', X'019a44c1e2b471948c80b85e4c66db11') -- Haste :: Quickened
+, (X'019a44c1e3027c109fad0310c0ffa68d', X'019a44c1e3547ef889373855b8d5cd3e') -- Slow :: Slowed Time
+, (X'019a44c1e36873e4a2756a93b63267ad', X'019a44c1e38979bc907027be4ffedbf9') -- Gravity Sink :: Crushing Gravity
+, (X'019a44c1e3e27bd4bdd0a25fe5f89333', X'019a44c1e41f7cf593c7afe506bafcd8') -- Invisibility :: Invisible
+, (X'019a44c1e43a7bec81a0b5ece9f8bf86', X'019a44c1e47472c9b84e6c1144ca370f') -- Mirror Image :: Duplicates
+, (X'019a44c1e4b5730480d296c572d21765', X'019a44c1e4e77a2d99b10e9f3f530796') -- Entangle :: Grasping Plants
+, (X'019a44c1e52a72ddb25616a3fe026797', X'019a44c1e54372dd83363f3d9a5f21a5') -- Vampiric Touch :: Life Drain
+, (X'019a44c1e561750a99c40f429c27877b', X'019a44c1e5b774cc9120e560bb98d9cf') -- Animate Dead :: Raise Undead
+ON CONFLICT (`spell_id`, `spell_effect_id`) DO NOTHING
+;
+
+INSERT INTO `spell_lists`
+    (`spell_list_id`, `name`)
+VALUES (X'01999679b6397fdf9dafaf18ce8ba4ef', 'Arcane')
+, (X'01999679f92373b19969345c1d68c409', 'Primal')
+, (X'0199967a1e3e74c884153d81011de5a2', 'Divine')
+ON CONFLICT (`spell_list_id`) DO NOTHING
+;
+
+INSERT INTO `spells_spell_lists`
+VALUES (X'0199967326fe7954825fbbc78d2300a2', X'01999679b6397fdf9dafaf18ce8ba4ef')
+, (X'0199967326fe7954825fbbc78d2300a2', X'01999679f92373b19969345c1d68c409')
+, (X'019a44c1d5a1739385b6b6e38c4c6594', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Frost Bolt :: Arcane
+, (X'019a44c1d5a1739385b6b6e38c4c6594', X'01999679f92373b19969345c1d68c409') -- Frost Bolt :: Primal
+, (X'019a44c1d6247b129d1841225073bdb7', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Shock :: Arcane
+, (X'019a44c1d6247b129d1841225073bdb7', X'01999679f92373b19969345c1d68c409') -- Shock :: Primal
+, (X'019a44c1d69b7b059efdfae1ff86a4d3', X'0199967a1e3e74c884153d81011de5a2') -- Sacred Bolt :: Divine
+, (X'019a44c1d73c7ca690f2f0ea61059378', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Death Bolt :: Arcane
+, (X'019a44c1d73c7ca690f2f0ea61059378', X'0199967a1e3e74c884153d81011de5a2') -- Death Bolt :: Divine
+, (X'019a44c1d80b77c394628754488c6fd6', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Mage Hand :: Arcane
+, (X'019a44c1d8967ae2acf4356ada1c2ad9', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Minor Illusion :: Arcane
+, (X'019a44c1d93f735ba494a0e7deeb21c6', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Light :: Arcane
+, (X'019a44c1d93f735ba494a0e7deeb21c6', X'0199967a1e3e74c884153d81011de5a2') -- Light :: Divine
+, (X'019a44c1d93f735ba494a0e7deeb21c6', X'01999679f92373b19969345c1d68c409') -- Light :: Primal
+, (X'019a44c1d9607078a6eb2d8ab1e48262', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Message :: Arcane
+, (X'019a44c1d9607078a6eb2d8ab1e48262', X'0199967a1e3e74c884153d81011de5a2') -- Message :: Divine
+, (X'019a44c1d9f4742ba6fae04bbc6478f1', X'0199967a1e3e74c884153d81011de5a2') -- Guidance :: Divine
+, (X'019a44c1d9f4742ba6fae04bbc6478f1', X'01999679f92373b19969345c1d68c409') -- Guidance :: Primal
+, (X'019a44c1da4a7978a6b3023add615f67', X'01999679f92373b19969345c1d68c409') -- Nature's Touch :: Primal
+, (X'019a44c1dae072c7b1abaee4bf50a940', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Magic Missile :: Arcane
+, (X'019a44c1db637ba284d589c97e51853d', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Fireball :: Arcane
+, (X'019a44c1dba674b586032b6f9f20dc22', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Lightning Bolt :: Arcane
+, (X'019a44c1dba674b586032b6f9f20dc22', X'01999679f92373b19969345c1d68c409') -- Lightning Bolt :: Primal
+, (X'019a44c1dc4d7dbc914f8798f7c20191', X'0199967a1e3e74c884153d81011de5a2') -- Heal :: Divine
+, (X'019a44c1dc4d7dbc914f8798f7c20191', X'01999679f92373b19969345c1d68c409') -- Heal :: Primal
+, (X'019a44c1dcb37135a7295ef64163ed9a', X'0199967a1e3e74c884153d81011de5a2') -- Return to Life :: Divine
+, (X'019a44c1dcb37135a7295ef64163ed9a', X'01999679f92373b19969345c1d68c409') -- Return to Life :: Primal
+, (X'019a44c1dcfd73df9735f5ea2eb0d158', X'0199967a1e3e74c884153d81011de5a2') -- Bless :: Divine
+, (X'019a44c1dd3b7b0b9e50851a931bce42', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Bane :: Arcane
+, (X'019a44c1dd3b7b0b9e50851a931bce42', X'0199967a1e3e74c884153d81011de5a2') -- Bane :: Divine
+, (X'019a44c1dd6874619a9c6ece8d8a22e8', X'0199967a1e3e74c884153d81011de5a2') -- Command :: Divine
+, (X'019a44c1ddeb70d8ba616192d220ed07', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Sleep :: Arcane
+, (X'019a44c1ddeb70d8ba616192d220ed07', X'01999679f92373b19969345c1d68c409') -- Sleep :: Primal
+, (X'019a44c1de2e77178fdd3fca2febdf35', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Charm :: Arcane
+, (X'019a44c1de2e77178fdd3fca2febdf35', X'01999679f92373b19969345c1d68c409') -- Charm :: Primal
+, (X'019a44c1de7d7861a3b474701986fe59', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Shield :: Arcane
+, (X'019a44c1decb77c4a5b0fb0cd7650012', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Mage Armor :: Arcane
+, (X'019a44c1df0374689462652aabe5743c', X'0199967a1e3e74c884153d81011de5a2') -- Sanctuary :: Divine
+, (X'019a44c1df607823af865d5acd0688b8', X'01999679f92373b19969345c1d68c409') -- Barkskin :: Primal
+, (X'019a44c1dfcc71dd8e227eaef5b03d17', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Fog Cloud :: Arcane
+, (X'019a44c1dfcc71dd8e227eaef5b03d17', X'01999679f92373b19969345c1d68c409') -- Fog Cloud :: Primal
+, (X'019a44c1dff67c818fa685ee234d3433', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Web :: Arcane
+, (X'019a44c1dff67c818fa685ee234d3433', X'01999679f92373b19969345c1d68c409') -- Web :: Primal
+, (X'019a44c1e03f752fa30c550dba758b12', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Find Familiar :: Arcane
+, (X'019a44c1e0b27fc1bd4067f052d1e0ce', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Detect Magic :: Arcane
+, (X'019a44c1e0b27fc1bd4067f052d1e0ce', X'0199967a1e3e74c884153d81011de5a2') -- Detect Magic :: Divine
+, (X'019a44c1e0b27fc1bd4067f052d1e0ce', X'01999679f92373b19969345c1d68c409') -- Detect Magic :: Primal
+, (X'019a44c1e14a728397181297472a5396', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Identify :: Arcane
+, (X'019a44c1e1787d03b69644de0e488aeb', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Tongues :: Arcane
+, (X'019a44c1e1787d03b69644de0e488aeb', X'0199967a1e3e74c884153d81011de5a2') -- Tongues :: Divine
+, (X'019a44c1e2117cf6a729c3ae8e343f31', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Misty Step :: Arcane
+, (X'019a44c1e28e72f79cc6dbf616201237', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Haste :: Arcane
+, (X'019a44c1e3027c109fad0310c0ffa68d', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Slow :: Arcane
+, (X'019a44c1e36873e4a2756a93b63267ad', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Gravity Sink :: Arcane
+, (X'019a44c1e3e27bd4bdd0a25fe5f89333', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Invisibility :: Arcane
+, (X'019a44c1e43a7bec81a0b5ece9f8bf86', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Mirror Image :: Arcane
+, (X'019a44c1e4b5730480d296c572d21765', X'01999679f92373b19969345c1d68c409') -- Entangle :: Primal
+, (X'019a44c1e52a72ddb25616a3fe026797', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Vampiric Touch :: Arcane
+, (X'019a44c1e561750a99c40f429c27877b', X'01999679b6397fdf9dafaf18ce8ba4ef') -- Animate Dead :: Arcane
+, (X'019a44c1e561750a99c40f429c27877b', X'0199967a1e3e74c884153d81011de5a2') -- Animate Dead :: Divine
+ON CONFLICT (`spell_id`, `spell_list_id`) DO NOTHING
+;
+
+INSERT INTO `spell_tags`
+VALUES (X'0199968302bc78d9a245f312ddecb3e6', 'Cantrip')
+, (X'0199968323f37a34bee4ab00d551238d', 'Ritual')
+ON CONFLICT (`spell_tag_id`) DO NOTHING
+;
+
+INSERT INTO `spells_spell_tags`
+VALUES (X'0199967326fe7954825fbbc78d2300a2', X'0199968302bc78d9a245f312ddecb3e6')
+, (X'019a44c1d5a1739385b6b6e38c4c6594', X'0199968302bc78d9a245f312ddecb3e6') -- Frost Bolt :: Cantrip
+, (X'019a44c1d6247b129d1841225073bdb7', X'0199968302bc78d9a245f312ddecb3e6') -- Shock :: Cantrip
+, (X'019a44c1d69b7b059efdfae1ff86a4d3', X'0199968302bc78d9a245f312ddecb3e6') -- Sacred Bolt :: Cantrip
+, (X'019a44c1d73c7ca690f2f0ea61059378', X'0199968302bc78d9a245f312ddecb3e6') -- Death Bolt :: Cantrip
+, (X'019a44c1d80b77c394628754488c6fd6', X'0199968302bc78d9a245f312ddecb3e6') -- Mage Hand :: Cantrip
+, (X'019a44c1d8967ae2acf4356ada1c2ad9', X'0199968302bc78d9a245f312ddecb3e6') -- Minor Illusion :: Cantrip
+, (X'019a44c1d93f735ba494a0e7deeb21c6', X'0199968302bc78d9a245f312ddecb3e6') -- Light :: Cantrip
+, (X'019a44c1d9607078a6eb2d8ab1e48262', X'0199968302bc78d9a245f312ddecb3e6') -- Message :: Cantrip
+, (X'019a44c1d9f4742ba6fae04bbc6478f1', X'0199968302bc78d9a245f312ddecb3e6') -- Guidance :: Cantrip
+, (X'019a44c1da4a7978a6b3023add615f67', X'0199968302bc78d9a245f312ddecb3e6') -- Nature's Touch :: Cantrip
+, (X'019a44c1dcb37135a7295ef64163ed9a', X'0199968323f37a34bee4ab00d551238d') -- Return to Life :: Ritual
+, (X'019a44c1e03f752fa30c550dba758b12', X'0199968323f37a34bee4ab00d551238d') -- Find Familiar :: Ritual
+, (X'019a44c1e0b27fc1bd4067f052d1e0ce', X'0199968323f37a34bee4ab00d551238d') -- Detect Magic :: Ritual
+, (X'019a44c1e14a728397181297472a5396', X'0199968323f37a34bee4ab00d551238d') -- Identify :: Ritual
+ON CONFLICT (`spell_id`, `spell_tag_id`) DO NOTHING
+;
+
+INSERT INTO `point_enhancements`
+    (`point_enhancement_id`, `name`, `action_point_cost`, `mana_point_cost`, `description`)
+VALUES (X'01999c5dc6b67c4cb5ae6b5e67fe9295', 'Damage I (Fire)', 1, 0, 'You deal +1 Fire damage.')
+, (X'01999c62e5ca7421b835b9bd813da70d', 'Range', 1, 0, 'You increase the range by +5 Spaces.')
+, (X'019a44c1d3e47353b0fafd4d09b199f8', 'Damage I (Cold)', 1, 0, 'You deal +1 Cold damage.')
+, (X'019a44c1d40979cfa9a40332ff3b06e6', 'Damage I (Lightning)', 1, 0, 'You deal +1 Lightning damage.')
+, (X'019a44c1d427732c9f82a13baed624d2', 'Damage I (Radiant)', 1, 0, 'You deal +1 Radiant damage.')
+, (X'019a44c1d469713da989f18b61ba36e7', 'Damage I (Umbral)', 1, 0, 'You deal +1 Umbral damage.')
+, (X'019a44c1d46e7a78bc726a61c4586587', 'Damage II', 0, 1, 'You deal +2 damage of the Spell''s type.')
+, (X'019a44c1d4bc7266b8ca83891b3ed080', 'Additional Target', 0, 1, 'You choose 1 additional target within range.')
+, (X'019a44c1d4f474d08e19d896ecadcf2e', 'Area', 0, 1, 'The area of the Spell increases by 1 Space.')
+, (X'019a44c1d52b7c04858397c830961dd5', 'Duration', 0, 1, 'The duration of the Spell doubles.')
+, (X'019a44c1d5497a46b80da236a262277d', 'Healing', 0, 1, 'The target regains 2 additional HP.')
+ON CONFLICT (`point_enhancement_id`) DO NOTHING
+;
+
+INSERT INTO `point_enhancements_spells`
+VALUES (X'01999c5dc6b67c4cb5ae6b5e67fe9295', X'0199967326fe7954825fbbc78d2300a2')
+, (X'01999c62e5ca7421b835b9bd813da70d', X'0199967326fe7954825fbbc78d2300a2')
+, (X'019a44c1d3e47353b0fafd4d09b199f8', X'019a44c1d5a1739385b6b6e38c4c6594') -- Damage I (Cold) :: Frost Bolt
+, (X'01999c62e5ca7421b835b9bd813da70d', X'019a44c1d5a1739385b6b6e38c4c6594') -- Range :: Frost Bolt
+, (X'019a44c1d40979cfa9a40332ff3b06e6', X'019a44c1d6247b129d1841225073bdb7') -- Damage I (Lightning) :: Shock
+, (X'019a44c1d427732c9f82a13baed624d2', X'019a44c1d69b7b059efdfae1ff86a4d3') -- Damage I (Radiant) :: Sacred Bolt
+, (X'01999c62e5ca7421b835b9bd813da70d', X'019a44c1d69b7b059efdfae1ff86a4d3') -- Range :: Sacred Bolt
+, (X'019a44c1d469713da989f18b61ba36e7', X'019a44c1d73c7ca690f2f0ea61059378') -- Damage I (Umbral) :: Death Bolt
+, (X'01999c62e5ca7421b835b9bd813da70d', X'019a44c1d73c7ca690f2f0ea61059378') -- Range :: Death Bolt
+, (X'01999c62e5ca7421b835b9bd813da70d', X'019a44c1d80b77c394628754488c6fd6') -- Range :: Mage Hand
+, (X'019a44c1d52b7c04858397c830961dd5', X'019a44c1d80b77c394628754488c6fd6') -- Duration :: Mage Hand
+, (X'01999c62e5ca7421b835b9bd813da70d', X'019a44c1d8967ae2acf4356ada1c2ad9') -- Range :: Minor Illusion
+, (X'019a44c1d4f474d08e19d896ecadcf2e', X'019a44c1d8967ae2acf4356ada1c2ad9') -- Area :: Minor Illusion
+, (X'019a44c1d52b7c04858397c830961dd5', X'019a44c1d93f735ba494a0e7deeb21c6') -- Duration :: Light
+, (X'01999c62e5ca7421b835b9bd813da70d', X'019a44c1d9607078a6eb2d8ab1e48262') -- Range :: Message
+, (X'019a44c1d4bc7266b8ca83891b3ed080', X'019a44c1d9607078a6eb2d8ab1e48262') -- Additional Target :: Message
+, (X'019a44c1d4bc7266b8ca83891b3ed080', X'019a44c1d9f4742ba6fae04bbc6478f1') -- Additional Target :: Guidance
+, (X'01999c62e5ca7421b835b9bd813da70d', X'019a44c1da4a7978a6b3023add615f67') -- Range :: Nature's Touch
+, (X'019a44c1d46e7a78bc726a61c4586587', X'019a44c1dae072c7b1abaee4bf50a940') -- Damage II :: Magic Missile
+, (X'01999c62e5ca7421b835b9bd813da70d', X'019a44c1dae072c7b1abaee4bf50a940') -- Range :: Magic Missile
+, (X'01999c5dc6b67c4cb5ae6b5e67fe9295', X'019a44c1db637ba284d589c97e51853d') -- Damage I (Fire) :: Fireball
+, (X'019a44c1d4f474d08e19d896ecadcf2e', X'019a44c1db637ba284d589c97e51853d') -- Area :: Fireball
+, (X'019a44c1d40979cfa9a40332ff3b06e6', X'019a44c1dba674b586032b6f9f20dc22') -- Damage I (Lightning) :: Lightning Bolt
+, (X'019a44c1d4f474d08e19d896ecadcf2e', X'019a44c1dba674b586032b6f9f20dc22') -- Area :: Lightning Bolt
+, (X'019a44c1d5497a46b80da236a262277d', X'019a44c1dc4d7dbc914f8798f7c20191') -- Healing :: Heal
+, (X'01999c62e5ca7421b835b9bd813da70d', X'019a44c1dc4d7dbc914f8798f7c20191') -- Range :: Heal
+, (X'019a44c1d4bc7266b8ca83891b3ed080', X'019a44c1dc4d7dbc914f8798f7c20191') -- Additional Target :: Heal
+, (X'019a44c1d4bc7266b8ca83891b3ed080', X'019a44c1dcfd73df9735f5ea2eb0d158') -- Additional Target :: Bless
+, (X'019a44c1d52b7c04858397c830961dd5', X'019a44c1dcfd73df9735f5ea2eb0d158') -- Duration :: Bless
+, (X'019a44c1d4bc7266b8ca83891b3ed080', X'019a44c1dd3b7b0b9e50851a931bce42') -- Additional Target :: Bane
+, (X'019a44c1d52b7c04858397c830961dd5', X'019a44c1dd3b7b0b9e50851a931bce42') -- Duration :: Bane
+, (X'019a44c1d4bc7266b8ca83891b3ed080', X'019a44c1dd6874619a9c6ece8d8a22e8') -- Additional Target :: Command
+, (X'019a44c1d4f474d08e19d896ecadcf2e', X'019a44c1ddeb70d8ba616192d220ed07') -- Area :: Sleep
+, (X'019a44c1d52b7c04858397c830961dd5', X'019a44c1ddeb70d8ba616192d220ed07') -- Duration :: Sleep
+, (X'019a44c1d4bc7266b8ca83891b3ed080', X'019a44c1de2e77178fdd3fca2febdf35') -- Additional Target :: Charm
+, (X'019a44c1d4bc7266b8ca83891b3ed080', X'019a44c1decb77c4a5b0fb0cd7650012') -- Additional Target :: Mage Armor
+, (X'019a44c1d52b7c04858397c830961dd5', X'019a44c1df0374689462652aabe5743c') -- Duration :: Sanctuary
+, (X'019a44c1d4bc7266b8ca83891b3ed080', X'019a44c1df607823af865d5acd0688b8') -- Additional Target :: Barkskin
+, (X'019a44c1d4f474d08e19d896ecadcf2e', X'019a44c1dfcc71dd8e227eaef5b03d17') -- Area :: Fog Cloud
+, (X'01999c62e5ca7421b835b9bd813da70d', X'019a44c1dfcc71dd8e227eaef5b03d17') -- Range :: Fog Cloud
+, (X'019a44c1d4f474d08e19d896ecadcf2e', X'019a44c1dff67c818fa685ee234d3433') -- Area :: Web
+, (X'019a44c1d52b7c04858397c830961dd5', X'019a44c1e03f752fa30c550dba758b12') -- Duration :: Find Familiar
+, (X'019a44c1d4f474d08e19d896ecadcf2e', X'019a44c1e0b27fc1bd4067f052d1e0ce') -- Area :: Detect Magic
+, (X'019a44c1d52b7c04858397c830961dd5', X'019a44c1e0b27fc1bd4067f052d1e0ce') -- Duration :: Detect Magic
+, (X'019a44c1d4bc7266b8ca83891b3ed080', X'019a44c1e1787d03b69644de0e488aeb') -- Additional Target :: Tongues
+, (X'01999c62e5ca7421b835b9bd813da70d', X'019a44c1e2117cf6a729c3ae8e343f31') -- Range :: Misty Step
+, (X'019a44c1d4bc7266b8ca83891b3ed080', X'019a44c1e28e72f79cc6dbf616201237') -- Additional Target :: Haste
+, (X'019a44c1d4bc7266b8ca83891b3ed080', X'019a44c1e3027c109fad0310c0ffa68d') -- Additional Target :: Slow
+, (X'01999c62e5ca7421b835b9bd813da70d', X'019a44c1e3027c109fad0310c0ffa68d') -- Range :: Slow
+, (X'019a44c1d4f474d08e19d896ecadcf2e', X'019a44c1e36873e4a2756a93b63267ad') -- Area :: Gravity Sink
+, (X'01999c62e5ca7421b835b9bd813da70d', X'019a44c1e36873e4a2756a93b63267ad') -- Range :: Gravity Sink
+, (X'019a44c1d4bc7266b8ca83891b3ed080', X'019a44c1e3e27bd4bdd0a25fe5f89333') -- Additional Target :: Invisibility
+, (X'019a44c1d52b7c04858397c830961dd5', X'019a44c1e43a7bec81a0b5ece9f8bf86') -- Duration :: Mirror Image
+, (X'019a44c1d4f474d08e19d896ecadcf2e', X'019a44c1e4b5730480d296c572d21765') -- Area :: Entangle
+, (X'019a44c1d469713da989f18b61ba36e7', X'019a44c1e52a72ddb25616a3fe026797') -- Damage I (Umbral) :: Vampiric Touch
+, (X'019a44c1d4bc7266b8ca83891b3ed080', X'019a44c1e561750a99c40f429c27877b') -- Additional Target :: Animate Dead
+ON CONFLICT (`point_enhancement_id`, `spell_id`) DO NOTHING
+;
+
+INSERT INTO `items`
+    (`item_id`, `name`, `kind`, `is_container`, `weight`, `price`)
+VALUES (X'0199b6adea827ccea30d79b0b5820617', 'Hand Axe', 'Weapon', FALSE, 2, 500)
+, (X'019a05b2d3c47e1f8a2b3c4d5e6f7001', 'Leather Armor', 'Armor', FALSE, 10, 1000)
+, (X'019a05b2d3c47e1f8a2b3c4d5e6f7002', 'Chain Mail', 'Armor', FALSE, 40, 5000)
+, (X'019a05b2d3c47e1f8a2b3c4d5e6f7003', 'Buckler', 'Armor', FALSE, 3, 500)
+, (X'019a05b2d3c47e1f8a2b3c4d5e6f7004', 'Tower Shield', 'Armor', FALSE, 15, 3000)
+, (X'019a05b2d3c47e1f8a2b3c4d5e6f7005', 'Backpack', 'Gear', TRUE, 5, 200)
+, (X'019a05b2d3c47e1f8a2b3c4d5e6f7006', 'Rope (50 ft)', 'Gear', FALSE, 10, 100)
+, (X'019a05b2d3c47e1f8a2b3c4d5e6f7007', 'Torch', 'Gear', FALSE, 1, 1)
+, (X'019a05b2d3c47e1f8a2b3c4d5e6f7008', 'Healing Potion', 'Consumable', FALSE, 0.5, 5000)
+, (X'019a44c1e60a717c8eb993ed4d824646', 'Dagger', 'Weapon', FALSE, 1, 200)
+, (X'019a44c1e61278f8bb78969578a4e04d', 'Shortsword', 'Weapon', FALSE, 2, 1000)
+, (X'019a44c1e63c73c6b553eb9f4bb8a002', 'Longsword', 'Weapon', FALSE, 3, 1500)
+, (X'019a44c1e67972dda3786cdf2d437e45', 'Greatsword', 'Weapon', FALSE, 6, 5000)
+, (X'019a44c1e6a27cab9281815d75cc91b6', 'Battleaxe', 'Weapon', FALSE, 4, 1000)
+, (X'019a44c1e6ca79ca835065b2bdc50d8d', 'Greataxe', 'Weapon', FALSE, 7, 3000)
+, (X'019a44c1e6f47e8aaa31d4eb09b62e0d', 'Warhammer', 'Weapon', FALSE, 2, 1500)
+, (X'019a44c1e6ff79c499947f0c996e2340', 'Maul', 'Weapon', FALSE, 10, 1000)
+, (X'019a44c1e74a7819895e0b956ccbeb9e', 'War Pick', 'Weapon', FALSE, 2, 500)
+, (X'019a44c1e77d7627a2bdd4fba8782d93', 'Spear', 'Weapon', FALSE, 3, 100)
+, (X'019a44c1e79c787b9a77c16e8f6b9885', 'Pike', 'Weapon', FALSE, 18, 500)
+, (X'019a44c1e7d778a2b8b14304d042b4df', 'Quarterstaff', 'Weapon', FALSE, 4, 20)
+, (X'019a44c1e7f1740a9480267f668f6a40', 'Flail', 'Weapon', FALSE, 2, 1000)
+, (X'019a44c1e81e7e63983296bd1fe880c1', 'Whip', 'Weapon', FALSE, 3, 200)
+, (X'019a44c1e858723faf03e20416fba86b', 'Gauntlet', 'Weapon', FALSE, 1, 200)
+, (X'019a44c1e89273fe8039eb163c557d9f', 'Shortbow', 'Weapon', FALSE, 2, 2500)
+, (X'019a44c1e8b578ac819fe25dd163c579', 'Longbow', 'Weapon', FALSE, 2, 5000)
+, (X'019a44c1e8e37658a202e1dddc2a5ca9', 'Light Crossbow', 'Weapon', FALSE, 5, 2500)
+, (X'019a44c1e90c7fb28cac4815b6854231', 'Heavy Crossbow', 'Weapon', FALSE, 18, 5000)
+, (X'019a44c1e92f7fb9ab6f2c351152e92a', 'Hand Crossbow', 'Weapon', FALSE, 3, 7500)
+ON CONFLICT (`item_id`) DO NOTHING
+;
+
+INSERT INTO `armors`
+    ( `armor_id`
+    , `type`
+    , `precision_defense_bonus`
+    , `area_defense_bonus`
+    , `precision_damage_reduction`
+    , `area_damage_reduction`
+    , `speed_penalty`
+    , `agility_disadvantage`
+    )
+VALUES (X'019a05b2d3c47e1f8a2b3c4d5e6f7001', 'Light Armor', 1, 1, 0, 0, 0, FALSE)
+, (X'019a05b2d3c47e1f8a2b3c4d5e6f7002', 'Heavy Armor', 2, 2, 1, 1, 1, TRUE)
+, (X'019a05b2d3c47e1f8a2b3c4d5e6f7003', 'Light Shield', 1, 0, 0, 0, 0, FALSE)
+, (X'019a05b2d3c47e1f8a2b3c4d5e6f7004', 'Heavy Shield', 2, 1, 0, 0, 1, TRUE)
+ON CONFLICT (`armor_id`) DO NOTHING
+;
+
+INSERT INTO `consumables`
+    (`consumable_id`, `charges`, `restores`, `amount`, `description`)
+VALUES (X'019a05b2d3c47e1f8a2b3c4d5e6f7008', 1, 'HitPoints', 2, 'You regain 2 HP.')
+ON CONFLICT (`consumable_id`) DO NOTHING
+;
+
+INSERT INTO `attributes_classes`
+VALUES (X'01993b8460827289a9e9cc105341940e', X'019964edee4b746ea5a85006d034d3ba')
+, (X'01993b8556b4774aa4a333bd7f76469e', X'019964edee4b746ea5a85006d034d3ba')
+, (X'01993b83e9f978d4a5ae97c2011f49c6', X'019964ee4cef7ec3a2ce3ea8844aadc9')
+, (X'01993b8460827289a9e9cc105341940e', X'019964ee4cef7ec3a2ce3ea8844aadc9')
+, (X'01993b8460827289a9e9cc105341940e', X'019964ee6f237b65b83cf0cd85bf985f')
+, (X'01993b84fcf17fcbb1fed093bfd9853d', X'019964ee6f237b65b83cf0cd85bf985f')
+, (X'01993b83e9f978d4a5ae97c2011f49c6', X'019964ee77db78869c6783619eee210c')
+, (X'01993b84fcf17fcbb1fed093bfd9853d', X'019964ee77db78869c6783619eee210c')
+, (X'01993b83e9f978d4a5ae97c2011f49c6', X'019964ee7ef475a68360cc0a3b12cffc')
+, (X'01993b84fcf17fcbb1fed093bfd9853d', X'019964ee7ef475a68360cc0a3b12cffc')
+, (X'01993b83e9f978d4a5ae97c2011f49c6', X'019964ee864d7c15b46cbd28180f1f27')
+, (X'01993b84fcf17fcbb1fed093bfd9853d', X'019964ee864d7c15b46cbd28180f1f27')
+, (X'01993b8460827289a9e9cc105341940e', X'019964ee8cf6787e91d0f48e5c194f7b')
+, (X'01993b8556b4774aa4a333bd7f76469e', X'019964ee8cf6787e91d0f48e5c194f7b')
+, (X'01993b8460827289a9e9cc105341940e', X'019964eea1ad7225a1692680524594a3')
+, (X'01993b8556b4774aa4a333bd7f76469e', X'019964eea1ad7225a1692680524594a3')
+, (X'01993b8460827289a9e9cc105341940e', X'019964eeaac57516a28f2fcfd17fdfb3')
+, (X'01993b84fcf17fcbb1fed093bfd9853d', X'019964eeaac57516a28f2fcfd17fdfb3')
+, (X'01993b84fcf17fcbb1fed093bfd9853d', X'019964eeb1ff7b288f9443d7cf59a4e5')
+, (X'01993b8556b4774aa4a333bd7f76469e', X'019964eeb1ff7b288f9443d7cf59a4e5')
+, (X'01993b8460827289a9e9cc105341940e', X'019964eeb92879d19159713bf7c8cdbd')
+, (X'01993b8556b4774aa4a333bd7f76469e', X'019964eeb92879d19159713bf7c8cdbd')
+, (X'01993b83e9f978d4a5ae97c2011f49c6', X'019964eebf03730a95530ec528bb68ce')
+, (X'01993b84fcf17fcbb1fed093bfd9853d', X'019964eebf03730a95530ec528bb68ce')
+, (X'01993b83e9f978d4a5ae97c2011f49c6', X'019964eec5df783bbf5365a4ba231213')
+, (X'01993b8556b4774aa4a333bd7f76469e', X'019964eec5df783bbf5365a4ba231213')
+, (X'01993b84fcf17fcbb1fed093bfd9853d', X'019964eecd22732fa0ab349338786aed')
+, (X'01993b8556b4774aa4a333bd7f76469e', X'019964eecd22732fa0ab349338786aed')
+, (X'01993b8460827289a9e9cc105341940e', X'019964eed31576dea71821db96213fcd')
+, (X'01993b8556b4774aa4a333bd7f76469e', X'019964eed31576dea71821db96213fcd')
+ON CONFLICT (`attribute_id`, `class_id`) DO NOTHING
+;
+
+INSERT INTO `class_starting_equipment`
+    (`class_id`, `item_id`, `quantity`)
+VALUES (X'019964edee4b746ea5a85006d034d3ba', X'019a44c1e8e37658a202e1dddc2a5ca9', 1)
+, (X'019964edee4b746ea5a85006d034d3ba', X'0199b6adea827ccea30d79b0b5820617', 1)
+, (X'019964edee4b746ea5a85006d034d3ba', X'019a05b2d3c47e1f8a2b3c4d5e6f7001', 1)
+, (X'019964edee4b746ea5a85006d034d3ba', X'019a05b2d3c47e1f8a2b3c4d5e6f7005', 1)
+, (X'019964edee4b746ea5a85006d034d3ba', X'019a05b2d3c47e1f8a2b3c4d5e6f7006', 1)
+, (X'019964edee4b746ea5a85006d034d3ba', X'019a05b2d3c47e1f8a2b3c4d5e6f7007', 3)
+, (X'019964edee4b746ea5a85006d034d3ba', X'019a05b2d3c47e1f8a2b3c4d5e6f7008', 1)
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', X'019a44c1e6ca79ca835065b2bdc50d8d', 1)
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', X'0199b6adea827ccea30d79b0b5820617', 2)
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', X'019a05b2d3c47e1f8a2b3c4d5e6f7001', 1)
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', X'019a05b2d3c47e1f8a2b3c4d5e6f7005', 1)
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', X'019a05b2d3c47e1f8a2b3c4d5e6f7006', 1)
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', X'019a05b2d3c47e1f8a2b3c4d5e6f7007', 3)
+, (X'019964ee4cef7ec3a2ce3ea8844aadc9', X'019a05b2d3c47e1f8a2b3c4d5e6f7008', 1)
+, (X'019964ee6f237b65b83cf0cd85bf985f', X'019a44c1e61278f8bb78969578a4e04d', 1)
+, (X'019964ee6f237b65b83cf0cd85bf985f', X'019a44c1e60a717c8eb993ed4d824646', 1)
+, (X'019964ee6f237b65b83cf0cd85bf985f', X'019a05b2d3c47e1f8a2b3c4d5e6f7001', 1)
+, (X'019964ee6f237b65b83cf0cd85bf985f', X'019a05b2d3c47e1f8a2b3c4d5e6f7005', 1)
+, (X'019964ee6f237b65b83cf0cd85bf985f', X'019a05b2d3c47e1f8a2b3c4d5e6f7006', 1)
+, (X'019964ee6f237b65b83cf0cd85bf985f', X'019a05b2d3c47e1f8a2b3c4d5e6f7007', 3)
+, (X'019964ee6f237b65b83cf0cd85bf985f', X'019a05b2d3c47e1f8a2b3c4d5e6f7008', 1)
+, (X'019964ee77db78869c6783619eee210c', X'019a44c1e63c73c6b553eb9f4bb8a002', 1)
+, (X'019964ee77db78869c6783619eee210c', X'019a05b2d3c47e1f8a2b3c4d5e6f7004', 1)
+, (X'019964ee77db78869c6783619eee210c', X'019a05b2d3c47e1f8a2b3c4d5e6f7002', 1)
+, (X'019964ee77db78869c6783619eee210c', X'019a05b2d3c47e1f8a2b3c4d5e6f7005', 1)
+, (X'019964ee77db78869c6783619eee210c', X'019a05b2d3c47e1f8a2b3c4d5e6f7006', 1)
+, (X'019964ee77db78869c6783619eee210c', X'019a05b2d3c47e1f8a2b3c4d5e6f7007', 3)
+, (X'019964ee77db78869c6783619eee210c', X'019a05b2d3c47e1f8a2b3c4d5e6f7008', 1)
+, (X'019964ee7ef475a68360cc0a3b12cffc', X'019a44c1e6f47e8aaa31d4eb09b62e0d', 1)
+, (X'019964ee7ef475a68360cc0a3b12cffc', X'019a05b2d3c47e1f8a2b3c4d5e6f7003', 1)
+, (X'019964ee7ef475a68360cc0a3b12cffc', X'019a05b2d3c47e1f8a2b3c4d5e6f7002', 1)
+, (X'019964ee7ef475a68360cc0a3b12cffc', X'019a05b2d3c47e1f8a2b3c4d5e6f7005', 1)
+, (X'019964ee7ef475a68360cc0a3b12cffc', X'019a05b2d3c47e1f8a2b3c4d5e6f7006', 1)
+, (X'019964ee7ef475a68360cc0a3b12cffc', X'019a05b2d3c47e1f8a2b3c4d5e6f7007', 3)
+, (X'019964ee7ef475a68360cc0a3b12cffc', X'019a05b2d3c47e1f8a2b3c4d5e6f7008', 1)
+, (X'019964ee864d7c15b46cbd28180f1f27', X'019a44c1e63c73c6b553eb9f4bb8a002', 1)
+, (X'019964ee864d7c15b46cbd28180f1f27', X'019a05b2d3c47e1f8a2b3c4d5e6f7003', 1)
+, (X'019964ee864d7c15b46cbd28180f1f27', X'019a05b2d3c47e1f8a2b3c4d5e6f7002', 1)
+, (X'019964ee864d7c15b46cbd28180f1f27', X'019a05b2d3c47e1f8a2b3c4d5e6f7005', 1)
+, (X'019964ee864d7c15b46cbd28180f1f27', X'019a05b2d3c47e1f8a2b3c4d5e6f7006', 1)
+, (X'019964ee864d7c15b46cbd28180f1f27', X'019a05b2d3c47e1f8a2b3c4d5e6f7007', 3)
+, (X'019964ee864d7c15b46cbd28180f1f27', X'019a05b2d3c47e1f8a2b3c4d5e6f7008', 1)
+, (X'019964ee8cf6787e91d0f48e5c194f7b', X'019a44c1e7d778a2b8b14304d042b4df', 1)
+, (X'019964ee8cf6787e91d0f48e5c194f7b', X'019a05b2d3c47e1f8a2b3c4d5e6f7001', 1)
+, (X'019964ee8cf6787e91d0f48e5c194f7b', X'019a05b2d3c47e1f8a2b3c4d5e6f7005', 1)
+, (X'019964ee8cf6787e91d0f48e5c194f7b', X'019a05b2d3c47e1f8a2b3c4d5e6f7006', 1)
+, (X'019964ee8cf6787e91d0f48e5c194f7b', X'019a05b2d3c47e1f8a2b3c4d5e6f7007', 3)
+, (X'019964ee8cf6787e91d0f48e5c194f7b', X'019a05b2d3c47e1f8a2b3c4d5e6f7008', 1)
+, (X'019964eea1ad7225a1692680524594a3', X'019a44c1e8b578ac819fe25dd163c579', 1)
+, (X'019964eea1ad7225a1692680524594a3', X'019a44c1e61278f8bb78969578a4e04d', 1)
+, (X'019964eea1ad7225a1692680524594a3', X'019a05b2d3c47e1f8a2b3c4d5e6f7001', 1)
+, (X'019964eea1ad7225a1692680524594a3', X'019a05b2d3c47e1f8a2b3c4d5e6f7005', 1)
+, (X'019964eea1ad7225a1692680524594a3', X'019a05b2d3c47e1f8a2b3c4d5e6f7006', 1)
+, (X'019964eea1ad7225a1692680524594a3', X'019a05b2d3c47e1f8a2b3c4d5e6f7007', 3)
+, (X'019964eea1ad7225a1692680524594a3', X'019a05b2d3c47e1f8a2b3c4d5e6f7008', 1)
+, (X'019964eeaac57516a28f2fcfd17fdfb3', X'019a44c1e7d778a2b8b14304d042b4df', 1)
+, (X'019964eeaac57516a28f2fcfd17fdfb3', X'019a44c1e858723faf03e20416fba86b', 2)
+, (X'019964eeaac57516a28f2fcfd17fdfb3', X'019a05b2d3c47e1f8a2b3c4d5e6f7005', 1)
+, (X'019964eeaac57516a28f2fcfd17fdfb3', X'019a05b2d3c47e1f8a2b3c4d5e6f7006', 1)
+, (X'019964eeaac57516a28f2fcfd17fdfb3', X'019a05b2d3c47e1f8a2b3c4d5e6f7007', 3)
+, (X'019964eeaac57516a28f2fcfd17fdfb3', X'019a05b2d3c47e1f8a2b3c4d5e6f7008', 1)
+, (X'019964eeb1ff7b288f9443d7cf59a4e5', X'019a44c1e7d778a2b8b14304d042b4df', 1)
+, (X'019964eeb1ff7b288f9443d7cf59a4e5', X'019a44c1e60a717c8eb993ed4d824646', 1)
+, (X'019964eeb1ff7b288f9443d7cf59a4e5', X'019a05b2d3c47e1f8a2b3c4d5e6f7005', 1)
+, (X'019964eeb1ff7b288f9443d7cf59a4e5', X'019a05b2d3c47e1f8a2b3c4d5e6f7006', 1)
+, (X'019964eeb1ff7b288f9443d7cf59a4e5', X'019a05b2d3c47e1f8a2b3c4d5e6f7007', 3)
+, (X'019964eeb1ff7b288f9443d7cf59a4e5', X'019a05b2d3c47e1f8a2b3c4d5e6f7008', 1)
+, (X'019964eeb92879d19159713bf7c8cdbd', X'019a44c1e61278f8bb78969578a4e04d', 1)
+, (X'019964eeb92879d19159713bf7c8cdbd', X'019a44c1e60a717c8eb993ed4d824646', 2)
+, (X'019964eeb92879d19159713bf7c8cdbd', X'019a44c1e89273fe8039eb163c557d9f', 1)
+, (X'019964eeb92879d19159713bf7c8cdbd', X'019a05b2d3c47e1f8a2b3c4d5e6f7001', 1)
+, (X'019964eeb92879d19159713bf7c8cdbd', X'019a05b2d3c47e1f8a2b3c4d5e6f7005', 1)
+, (X'019964eeb92879d19159713bf7c8cdbd', X'019a05b2d3c47e1f8a2b3c4d5e6f7006', 1)
+, (X'019964eeb92879d19159713bf7c8cdbd', X'019a05b2d3c47e1f8a2b3c4d5e6f7007', 3)
+, (X'019964eeb92879d19159713bf7c8cdbd', X'019a05b2d3c47e1f8a2b3c4d5e6f7008', 1)
+, (X'019964eebf03730a95530ec528bb68ce', X'019a44c1e7d778a2b8b14304d042b4df', 1)
+, (X'019964eebf03730a95530ec528bb68ce', X'019a44c1e60a717c8eb993ed4d824646', 1)
+, (X'019964eebf03730a95530ec528bb68ce', X'019a05b2d3c47e1f8a2b3c4d5e6f7005', 1)
+, (X'019964eebf03730a95530ec528bb68ce', X'019a05b2d3c47e1f8a2b3c4d5e6f7006', 1)
+, (X'019964eebf03730a95530ec528bb68ce', X'019a05b2d3c47e1f8a2b3c4d5e6f7007', 3)
+, (X'019964eebf03730a95530ec528bb68ce', X'019a05b2d3c47e1f8a2b3c4d5e6f7008', 1)
+, (X'019964eec5df783bbf5365a4ba231213', X'019a44c1e63c73c6b553eb9f4bb8a002', 1)
+, (X'019964eec5df783bbf5365a4ba231213', X'019a05b2d3c47e1f8a2b3c4d5e6f7003', 1)
+, (X'019964eec5df783bbf5365a4ba231213', X'019a05b2d3c47e1f8a2b3c4d5e6f7001', 1)
+, (X'019964eec5df783bbf5365a4ba231213', X'019a05b2d3c47e1f8a2b3c4d5e6f7005', 1)
+, (X'019964eec5df783bbf5365a4ba231213', X'019a05b2d3c47e1f8a2b3c4d5e6f7006', 1)
+, (X'019964eec5df783bbf5365a4ba231213', X'019a05b2d3c47e1f8a2b3c4d5e6f7007', 3)
+, (X'019964eec5df783bbf5365a4ba231213', X'019a05b2d3c47e1f8a2b3c4d5e6f7008', 1)
+, (X'019964eecd22732fa0ab349338786aed', X'019a44c1e8e37658a202e1dddc2a5ca9', 1)
+, (X'019964eecd22732fa0ab349338786aed', X'019a44c1e60a717c8eb993ed4d824646', 1)
+, (X'019964eecd22732fa0ab349338786aed', X'019a05b2d3c47e1f8a2b3c4d5e6f7001', 1)
+, (X'019964eecd22732fa0ab349338786aed', X'019a05b2d3c47e1f8a2b3c4d5e6f7005', 1)
+, (X'019964eecd22732fa0ab349338786aed', X'019a05b2d3c47e1f8a2b3c4d5e6f7006', 1)
+, (X'019964eecd22732fa0ab349338786aed', X'019a05b2d3c47e1f8a2b3c4d5e6f7007', 3)
+, (X'019964eecd22732fa0ab349338786aed', X'019a05b2d3c47e1f8a2b3c4d5e6f7008', 1)
+, (X'019964eed31576dea71821db96213fcd', X'019a44c1e7d778a2b8b14304d042b4df', 1)
+, (X'019964eed31576dea71821db96213fcd', X'019a44c1e60a717c8eb993ed4d824646', 1)
+, (X'019964eed31576dea71821db96213fcd', X'019a05b2d3c47e1f8a2b3c4d5e6f7005', 1)
+, (X'019964eed31576dea71821db96213fcd', X'019a05b2d3c47e1f8a2b3c4d5e6f7006', 1)
+, (X'019964eed31576dea71821db96213fcd', X'019a05b2d3c47e1f8a2b3c4d5e6f7007', 3)
+, (X'019964eed31576dea71821db96213fcd', X'019a05b2d3c47e1f8a2b3c4d5e6f7008', 1)
+ON CONFLICT (`class_id`, `item_id`) DO NOTHING
+;
+
+INSERT INTO `weapons`
+    (`weapon_id`, `type`, `damage_type`, `range_value`)
+VALUES (X'0199b6adea827ccea30d79b0b5820617', 'Melee', 'Slashing', NULL)
+, (X'019a44c1e60a717c8eb993ed4d824646', 'Melee', 'Piercing', NULL)
+, (X'019a44c1e61278f8bb78969578a4e04d', 'Melee', 'Slashing', NULL)
+, (X'019a44c1e63c73c6b553eb9f4bb8a002', 'Melee', 'Slashing', NULL)
+, (X'019a44c1e67972dda3786cdf2d437e45', 'Melee', 'Slashing', NULL)
+, (X'019a44c1e6a27cab9281815d75cc91b6', 'Melee', 'Slashing', NULL)
+, (X'019a44c1e6ca79ca835065b2bdc50d8d', 'Melee', 'Slashing', NULL)
+, (X'019a44c1e6f47e8aaa31d4eb09b62e0d', 'Melee', 'Bludgeoning', NULL)
+, (X'019a44c1e6ff79c499947f0c996e2340', 'Melee', 'Bludgeoning', NULL)
+, (X'019a44c1e74a7819895e0b956ccbeb9e', 'Melee', 'Piercing', NULL)
+, (X'019a44c1e77d7627a2bdd4fba8782d93', 'Melee', 'Piercing', NULL)
+, (X'019a44c1e79c787b9a77c16e8f6b9885', 'Melee', 'Piercing', NULL)
+, (X'019a44c1e7d778a2b8b14304d042b4df', 'Melee', 'Bludgeoning', NULL)
+, (X'019a44c1e7f1740a9480267f668f6a40', 'Melee', 'Bludgeoning', NULL)
+, (X'019a44c1e81e7e63983296bd1fe880c1', 'Melee', 'Slashing', NULL)
+, (X'019a44c1e858723faf03e20416fba86b', 'Melee', 'Bludgeoning', NULL)
+, (X'019a44c1e89273fe8039eb163c557d9f', 'Ranged', 'Piercing', 15)
+, (X'019a44c1e8b578ac819fe25dd163c579', 'Ranged', 'Piercing', 30)
+, (X'019a44c1e8e37658a202e1dddc2a5ca9', 'Ranged', 'Piercing', 15)
+, (X'019a44c1e90c7fb28cac4815b6854231', 'Ranged', 'Piercing', 30)
+, (X'019a44c1e92f7fb9ab6f2c351152e92a', 'Ranged', 'Piercing', 10)
+ON CONFLICT (`weapon_id`) DO NOTHING
+;
+
+INSERT INTO `weapon_styles`
+    (`weapon_style_id`, `name`, `description`, `damage_type`)
+VALUES (X'0199b6b3df197145a60fef49ad9698aa', 'Axe', 'You deal +1 damage against creatures that are Bleeding', 'Slashing')
+, (X'019a0348c1d87a51b1f2e3c4d5a6b701', 'Bow', 'You ignore the Cover bonus of creatures within half your range', 'Piercing')
+, (X'019a0348c1d87a51b1f2e3c4d5a6b702', 'Chained', 'You ignore the bonus to PD granted by Shields', 'Bludgeoning')
+, (X'019a0348c1d87a51b1f2e3c4d5a6b703', 'Crossbow', 'You deal +1 damage against creatures that are Hindered', 'Piercing')
+, (X'019a0348c1d87a51b1f2e3c4d5a6b704', 'Fist', 'You have ADV on Checks to maintain a Grapple', 'Bludgeoning')
+, (X'019a0348c1d87a51b1f2e3c4d5a6b705', 'Hammer', 'You deal +1 damage against creatures that are Dazed', 'Bludgeoning')
+, (X'019a0348c1d87a51b1f2e3c4d5a6b706', 'Pick', 'You deal +1 damage against creatures that are Impaired', 'Piercing')
+, (X'019a0348c1d87a51b1f2e3c4d5a6b707', 'Spear', 'You deal +1 damage against creatures that are Slowed', 'Piercing')
+, (X'019a0348c1d87a51b1f2e3c4d5a6b708', 'Staff', 'You deal +1 damage against creatures that are Grappled', 'Bludgeoning')
+, (X'019a0348c1d87a51b1f2e3c4d5a6b709', 'Sword', 'You gain +1 PD against Attacks while wielding the Weapon', 'Slashing')
+, (X'019a0348c1d87a51b1f2e3c4d5a6b70a', 'Whip', 'You can make Opportunity Attacks with the Weapon''s Reach', 'Slashing')
+ON CONFLICT (`weapon_style_id`) DO NOTHING
+;
+
+INSERT INTO `weapons_weapon_styles`
+    (`weapon_id`, `weapon_style_id`)
+VALUES (X'0199b6adea827ccea30d79b0b5820617', X'0199b6b3df197145a60fef49ad9698aa')
+, (X'019a44c1e60a717c8eb993ed4d824646', X'019a0348c1d87a51b1f2e3c4d5a6b709') -- Dagger :: Sword
+, (X'019a44c1e61278f8bb78969578a4e04d', X'019a0348c1d87a51b1f2e3c4d5a6b709') -- Shortsword :: Sword
+, (X'019a44c1e63c73c6b553eb9f4bb8a002', X'019a0348c1d87a51b1f2e3c4d5a6b709') -- Longsword :: Sword
+, (X'019a44c1e67972dda3786cdf2d437e45', X'019a0348c1d87a51b1f2e3c4d5a6b709') -- Greatsword :: Sword
+, (X'019a44c1e6a27cab9281815d75cc91b6', X'0199b6b3df197145a60fef49ad9698aa') -- Battleaxe :: Axe
+, (X'019a44c1e6ca79ca835065b2bdc50d8d', X'0199b6b3df197145a60fef49ad9698aa') -- Greataxe :: Axe
+, (X'019a44c1e6f47e8aaa31d4eb09b62e0d', X'019a0348c1d87a51b1f2e3c4d5a6b705') -- Warhammer :: Hammer
+, (X'019a44c1e6ff79c499947f0c996e2340', X'019a0348c1d87a51b1f2e3c4d5a6b705') -- Maul :: Hammer
+, (X'019a44c1e74a7819895e0b956ccbeb9e', X'019a0348c1d87a51b1f2e3c4d5a6b706') -- War Pick :: Pick
+, (X'019a44c1e77d7627a2bdd4fba8782d93', X'019a0348c1d87a51b1f2e3c4d5a6b707') -- Spear :: Spear
+, (X'019a44c1e79c787b9a77c16e8f6b9885', X'019a0348c1d87a51b1f2e3c4d5a6b707') -- Pike :: Spear
+, (X'019a44c1e7d778a2b8b14304d042b4df', X'019a0348c1d87a51b1f2e3c4d5a6b708') -- Quarterstaff :: Staff
+, (X'019a44c1e7f1740a9480267f668f6a40', X'019a0348c1d87a51b1f2e3c4d5a6b702') -- Flail :: Chained
+, (X'019a44c1e81e7e63983296bd1fe880c1', X'019a0348c1d87a51b1f2e3c4d5a6b70a') -- Whip :: Whip
+, (X'019a44c1e858723faf03e20416fba86b', X'019a0348c1d87a51b1f2e3c4d5a6b704') -- Gauntlet :: Fist
+, (X'019a44c1e89273fe8039eb163c557d9f', X'019a0348c1d87a51b1f2e3c4d5a6b701') -- Shortbow :: Bow
+, (X'019a44c1e8b578ac819fe25dd163c579', X'019a0348c1d87a51b1f2e3c4d5a6b701') -- Longbow :: Bow
+, (X'019a44c1e8e37658a202e1dddc2a5ca9', X'019a0348c1d87a51b1f2e3c4d5a6b703') -- Light Crossbow :: Crossbow
+, (X'019a44c1e90c7fb28cac4815b6854231', X'019a0348c1d87a51b1f2e3c4d5a6b703') -- Heavy Crossbow :: Crossbow
+, (X'019a44c1e92f7fb9ab6f2c351152e92a', X'019a0348c1d87a51b1f2e3c4d5a6b703') -- Hand Crossbow :: Crossbow
+ON CONFLICT (`weapon_id`, `weapon_style_id`) DO NOTHING
+;
+
+INSERT INTO `weapon_properties`
+    (`weapon_property_id`, `name`, `description`, `required_weapon_property_id`, `source_id`)
+VALUES (X'0199b6ba413671bdbb86189f5beacbb5', 'Concealable', 'Drawing the Weapon doesn''t provoke Opportunity Attacks.', NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'0199b6ba5b2a768fb474f05b92027443', 'Toss (5/10)', 'You can throw the Weapon to make a Ranged Martial Attack (5/10)', NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a034a0b5e7c2f9d1e8a7b6c5d4e01', 'Ammo', 'The Weapon requires ammunition to make an Attack.', NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a034a0b5e7c2f9d1e8a7b6c5d4e02', 'Guard', 'You gain +1 PD while wielding the Weapon.', NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a034a0b5e7c2f9d1e8a7b6c5d4e03', 'Two-Handed', 'The Weapon requires 2 hands to Attack with.', NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a034a0b5e7c2f9d1e8a7b6c5d4e04', 'Heavy', 'The Weapon deals +1 damage.', X'019a034a0b5e7c2f9d1e8a7b6c5d4e03', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a034a0b5e7c2f9d1e8a7b6c5d4e05', 'Impact', 'The Weapon deals +1 damage on a Heavy Hit.', NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a034a0b5e7c2f9d1e8a7b6c5d4e06', 'Long-Ranged', 'The Weapon''s range increases to 30/90.', NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a034a0b5e7c2f9d1e8a7b6c5d4e07', 'Multi-Faceted', 'The Weapon counts as an additional Weapon Style.', NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a034a0b5e7c2f9d1e8a7b6c5d4e08', 'Reach', 'The Weapon''s melee range increases by 1 Space.', NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a034a0b5e7c2f9d1e8a7b6c5d4e09', 'Reload', 'The Weapon must be reloaded with 1 AP after each Attack.', NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a034a0b5e7c2f9d1e8a7b6c5d4e0a', 'Silent', 'Attacking with the Weapon doesn''t reveal your position.', NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a034a0b5e7c2f9d1e8a7b6c5d4e0b', 'Thrown (10/20)', 'The Weapon''s Toss range increases to 10/20.', X'0199b6ba5b2a768fb474f05b92027443', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a034a0b5e7c2f9d1e8a7b6c5d4e0c', 'Unwieldy', 'You have DisADV on Attacks against targets within 1 Space.', NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a034a0b5e7c2f9d1e8a7b6c5d4e0d', 'Versatile', 'The Weapon deals +1 damage when wielded in 2 hands.', NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a034a0b5e7c2f9d1e8a7b6c5d4e0e', 'Returning', 'The Weapon returns to your hand after being thrown.', X'0199b6ba5b2a768fb474f05b92027443', X'019a1d3e5f607a81b2c3d4e5f6071829')
+, (X'019a034a0b5e7c2f9d1e8a7b6c5d4e0f', 'Capture', 'You can make the Grapple Maneuver with the Weapon at range.', NULL, X'019a1d3e5f607a81b2c3d4e5f6071829')
+ON CONFLICT (`weapon_property_id`) DO NOTHING
+;
+
+INSERT INTO `weapons_weapon_properties`
+    (`weapon_id`, `weapon_property_id`)
+VALUES (X'0199b6adea827ccea30d79b0b5820617', X'0199b6ba413671bdbb86189f5beacbb5')
+, (X'0199b6adea827ccea30d79b0b5820617', X'0199b6ba5b2a768fb474f05b92027443')
+, (X'019a44c1e60a717c8eb993ed4d824646', X'0199b6ba413671bdbb86189f5beacbb5') -- Dagger :: Concealable
+, (X'019a44c1e60a717c8eb993ed4d824646', X'0199b6ba5b2a768fb474f05b92027443') -- Dagger :: Toss (5/10)
+, (X'019a44c1e61278f8bb78969578a4e04d', X'0199b6ba413671bdbb86189f5beacbb5') -- Shortsword :: Concealable
+, (X'019a44c1e63c73c6b553eb9f4bb8a002', X'019a034a0b5e7c2f9d1e8a7b6c5d4e0d') -- Longsword :: Versatile
+, (X'019a44c1e67972dda3786cdf2d437e45', X'019a034a0b5e7c2f9d1e8a7b6c5d4e03') -- Greatsword :: Two-Handed
+, (X'019a44c1e67972dda3786cdf2d437e45', X'019a034a0b5e7c2f9d1e8a7b6c5d4e04') -- Greatsword :: Heavy
+, (X'019a44c1e6a27cab9281815d75cc91b6', X'019a034a0b5e7c2f9d1e8a7b6c5d4e0d') -- Battleaxe :: Versatile
+, (X'019a44c1e6ca79ca835065b2bdc50d8d', X'019a034a0b5e7c2f9d1e8a7b6c5d4e03') -- Greataxe :: Two-Handed
+, (X'019a44c1e6ca79ca835065b2bdc50d8d', X'019a034a0b5e7c2f9d1e8a7b6c5d4e04') -- Greataxe :: Heavy
+, (X'019a44c1e6f47e8aaa31d4eb09b62e0d', X'019a034a0b5e7c2f9d1e8a7b6c5d4e0d') -- Warhammer :: Versatile
+, (X'019a44c1e6ff79c499947f0c996e2340', X'019a034a0b5e7c2f9d1e8a7b6c5d4e03') -- Maul :: Two-Handed
+, (X'019a44c1e6ff79c499947f0c996e2340', X'019a034a0b5e7c2f9d1e8a7b6c5d4e04') -- Maul :: Heavy
+, (X'019a44c1e6ff79c499947f0c996e2340', X'019a034a0b5e7c2f9d1e8a7b6c5d4e05') -- Maul :: Impact
+, (X'019a44c1e74a7819895e0b956ccbeb9e', X'019a034a0b5e7c2f9d1e8a7b6c5d4e05') -- War Pick :: Impact
+, (X'019a44c1e77d7627a2bdd4fba8782d93', X'0199b6ba5b2a768fb474f05b92027443') -- Spear :: Toss (5/10)
+, (X'019a44c1e77d7627a2bdd4fba8782d93', X'019a034a0b5e7c2f9d1e8a7b6c5d4e0d') -- Spear :: Versatile
+, (X'019a44c1e79c787b9a77c16e8f6b9885', X'019a034a0b5e7c2f9d1e8a7b6c5d4e03') -- Pike :: Two-Handed
+, (X'019a44c1e79c787b9a77c16e8f6b9885', X'019a034a0b5e7c2f9d1e8a7b6c5d4e08') -- Pike :: Reach
+, (X'019a44c1e79c787b9a77c16e8f6b9885', X'019a034a0b5e7c2f9d1e8a7b6c5d4e0c') -- Pike :: Unwieldy
+, (X'019a44c1e7d778a2b8b14304d042b4df', X'019a034a0b5e7c2f9d1e8a7b6c5d4e0d') -- Quarterstaff :: Versatile
+, (X'019a44c1e7d778a2b8b14304d042b4df', X'019a034a0b5e7c2f9d1e8a7b6c5d4e02') -- Quarterstaff :: Guard
+, (X'019a44c1e7f1740a9480267f668f6a40', X'019a034a0b5e7c2f9d1e8a7b6c5d4e05') -- Flail :: Impact
+, (X'019a44c1e81e7e63983296bd1fe880c1', X'019a034a0b5e7c2f9d1e8a7b6c5d4e08') -- Whip :: Reach
+, (X'019a44c1e81e7e63983296bd1fe880c1', X'019a034a0b5e7c2f9d1e8a7b6c5d4e0f') -- Whip :: Capture
+, (X'019a44c1e858723faf03e20416fba86b', X'0199b6ba413671bdbb86189f5beacbb5') -- Gauntlet :: Concealable
+, (X'019a44c1e89273fe8039eb163c557d9f', X'019a034a0b5e7c2f9d1e8a7b6c5d4e01') -- Shortbow :: Ammo
+, (X'019a44c1e89273fe8039eb163c557d9f', X'019a034a0b5e7c2f9d1e8a7b6c5d4e03') -- Shortbow :: Two-Handed
+, (X'019a44c1e8b578ac819fe25dd163c579', X'019a034a0b5e7c2f9d1e8a7b6c5d4e01') -- Longbow :: Ammo
+, (X'019a44c1e8b578ac819fe25dd163c579', X'019a034a0b5e7c2f9d1e8a7b6c5d4e03') -- Longbow :: Two-Handed
+, (X'019a44c1e8b578ac819fe25dd163c579', X'019a034a0b5e7c2f9d1e8a7b6c5d4e06') -- Longbow :: Long-Ranged
+, (X'019a44c1e8e37658a202e1dddc2a5ca9', X'019a034a0b5e7c2f9d1e8a7b6c5d4e01') -- Light Crossbow :: Ammo
+, (X'019a44c1e8e37658a202e1dddc2a5ca9', X'019a034a0b5e7c2f9d1e8a7b6c5d4e09') -- Light Crossbow :: Reload
+, (X'019a44c1e8e37658a202e1dddc2a5ca9', X'019a034a0b5e7c2f9d1e8a7b6c5d4e03') -- Light Crossbow :: Two-Handed
+, (X'019a44c1e90c7fb28cac4815b6854231', X'019a034a0b5e7c2f9d1e8a7b6c5d4e01') -- Heavy Crossbow :: Ammo
+, (X'019a44c1e90c7fb28cac4815b6854231', X'019a034a0b5e7c2f9d1e8a7b6c5d4e09') -- Heavy Crossbow :: Reload
+, (X'019a44c1e90c7fb28cac4815b6854231', X'019a034a0b5e7c2f9d1e8a7b6c5d4e03') -- Heavy Crossbow :: Two-Handed
+, (X'019a44c1e90c7fb28cac4815b6854231', X'019a034a0b5e7c2f9d1e8a7b6c5d4e04') -- Heavy Crossbow :: Heavy
+, (X'019a44c1e90c7fb28cac4815b6854231', X'019a034a0b5e7c2f9d1e8a7b6c5d4e06') -- Heavy Crossbow :: Long-Ranged
+, (X'019a44c1e92f7fb9ab6f2c351152e92a', X'019a034a0b5e7c2f9d1e8a7b6c5d4e01') -- Hand Crossbow :: Ammo
+, (X'019a44c1e92f7fb9ab6f2c351152e92a', X'019a034a0b5e7c2f9d1e8a7b6c5d4e09') -- Hand Crossbow :: Reload
+, (X'019a44c1e92f7fb9ab6f2c351152e92a', X'0199b6ba413671bdbb86189f5beacbb5') -- Hand Crossbow :: Concealable
+ON CONFLICT (`weapon_id`, `weapon_property_id`) DO NOTHING
+;
+
+INSERT INTO `maneuvers`
+    (`maneuver_id`, `name`, `kind`, `action_point_cost`, `stamina_point_cost`, `description`)
+VALUES (X'019a034c55e97d0a8b3c2d1e0f9a8b01', 'Trip', 'Attack', 1, 1, 'On a Hit, the target falls Prone.')
+, (X'019a034c55e97d0a8b3c2d1e0f9a8b02', 'Parry', 'Defense', 1, 1, 'You grant a creature within 1 Space +5 PD against an Attack.')
+, (X'019a44c1e93873e2a7109c882cb3d959', 'Extend Attack', 'Attack', 1, 1, 'Your melee range increases by 1 Space for the Attack.')
+, (X'019a44c1e968758aabcd739215668569', 'Power Attack', 'Attack', 1, 1, 'You deal +1 damage with the Attack.')
+, (X'019a44c1e9aa742cb134dc1947d5d8c1', 'Sweep Attack', 'Attack', 1, 1, 'You choose 1 additional target within 1 Space of the original target. Compare your Attack Check against each target''s PD.')
+, (X'019a44c1e9f975e3b40fa04b3451b818', 'Volley', 'Attack', 1, 1, 'You make a Ranged Attack against every creature within a 1 Space radius of a point within range.')
+, (X'019a44c1ea027fe590ab2247d312e861', 'Body Blow', 'Save', 1, 1, 'On a Hit, the target makes a Might Save. Failure: it is Impaired until the end of your next turn.')
+, (X'019a44c1ea4373b986ffc1d4aa1d8a12', 'Bleed', 'Save', 1, 1, 'On a Hit, the target makes a Physical Save. Failure: it begins Bleeding.')
+, (X'019a44c1ea897d6b8bd02d7f4e3fe881', 'Daze', 'Save', 1, 1, 'On a Hit, the target makes a Mental Save. Failure: it is Dazed until the end of your next turn.')
+, (X'019a44c1eabe747fa5def058bd97f363', 'Expose', 'Save', 1, 1, 'On a Hit, the target makes a Mental Save. Failure: it is Exposed until the end of your next turn.')
+, (X'019a44c1eada7872b0a21a8147bf6653', 'Hamstring', 'Save', 1, 1, 'On a Hit, the target makes a Physical Save. Failure: it is Slowed until the end of your next turn.')
+, (X'019a44c1eaf1729589ce979951f24960', 'Hinder', 'Save', 1, 1, 'On a Hit, the target makes a Physical Save. Failure: it is Hindered until the end of your next turn.')
+, (X'019a44c1eb1777b69248d12f19453ab4', 'Knockback', 'Save', 1, 1, 'On a Hit, the target makes a Might Save. Failure: it is pushed 1 Space away from you.')
+, (X'019a44c1eb3b726aab2a5267641b5b02', 'Disarm', 'Grapple', 1, 1, 'You make an Athletics Check contested by the target. Success: it drops an object of your choice that it is holding.')
+, (X'019a44c1eb5a738cb0322288cd3e0f86', 'Grapple', 'Grapple', 1, 0, 'You make an Athletics Check contested by the target. Success: it is Grappled by you.')
+, (X'019a44c1eb737161929073beeb79fcad', 'Shove', 'Grapple', 1, 0, 'You make an Athletics Check contested by the target. Success: it is pushed 1 Space away from you.')
+, (X'019a44c1ebb87254b43a6603f9062368', 'Tackle', 'Grapple', 1, 1, 'You make an Athletics Check contested by the target. Success: you and the target both fall Prone and it is Grappled by you.')
+, (X'019a44c1ebf67c7082054c324b9239b7', 'Throw', 'Grapple', 1, 1, 'You throw a creature you have Grappled up to a number of Spaces equal to your Might. It falls Prone.')
+, (X'019a44c1ec067e33a383b030ae004e1f', 'Protect', 'Defense', 1, 1, 'You can swap places with an ally within 1 Space that is targeted by an Attack and become the target instead.')
+, (X'019a44c1ec2573518c9ab1df1b3688b3', 'Raise Shield', 'Defense', 1, 1, 'While wielding a Shield, you grant yourself or an adjacent creature +5 AD against an Attack.')
+, (X'019a44c1ec677621addcda534d6c19af', 'Side Step', 'Defense', 1, 1, 'When a creature misses you with a Melee Attack, you can move 1 Space without provoking Opportunity Attacks.')
+, (X'019a44c1ec8a703195777e20db47135c', 'Swap', 'Defense', 1, 1, 'You swap places with a willing creature within 1 Space.')
+, (X'019a44c1ecbb7fda863035e16b83539a', 'Taunt', 'Defense', 1, 1, 'A creature within 5 Spaces that can see or hear you makes a Charisma Save. Failure: it has DisADV on Attacks against creatures other than you until the end of your next turn.')
+ON CONFLICT (`maneuver_id`) DO NOTHING
+;
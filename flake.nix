@@ -38,6 +38,7 @@
             clippy
             git
             openssl
+            postgresql
             rustc
             rustfmt
             rust-analyzer
@@ -0,0 +1,12 @@
+ALTER TABLE weapons
+    ADD COLUMN damage_type TEXT    NULL DEFAULT NULL
+        CHECK (damage_type IN ('Bludgeoning', 'Piercing', 'Slashing'));
+
+ALTER TABLE weapons
+    ADD COLUMN range_value INTEGER NULL DEFAULT NULL
+        CHECK (range_value > 0);
+
+-- styles granted by the Multi-Faceted property sit next to the primary style
+ALTER TABLE weapons_weapon_styles
+    ADD COLUMN multi_faceted INTEGER NOT NULL DEFAULT FALSE
+        CHECK (multi_faceted IN (0, 1));
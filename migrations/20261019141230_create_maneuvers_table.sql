@@ -0,0 +1,12 @@
+CREATE TABLE maneuvers (
+    maneuver_id        BLOB    PRIMARY KEY
+        CHECK (length(maneuver_id) = 16),
+    name               TEXT    NOT NULL
+        CHECK (name <> ''),
+    kind               TEXT    NOT NULL
+        CHECK (kind IN ('Attack', 'Save', 'Grapple', 'Defense')),
+    action_point_cost  INTEGER NOT NULL DEFAULT 1,
+    stamina_point_cost INTEGER NOT NULL DEFAULT 1,
+    description        TEXT    NOT NULL
+        CHECK (description <> '')
+) STRICT, WITHOUT ROWID;
@@ -0,0 +1,18 @@
+CREATE TABLE creatures (
+    creature_id                BLOB    PRIMARY KEY
+        CHECK (length(creature_id) = 16),
+    name                       TEXT    NOT NULL
+        CHECK (name <> ''),
+    level                      INTEGER NOT NULL DEFAULT 0,
+    prime                      INTEGER NOT NULL DEFAULT 0,
+    might                      INTEGER NOT NULL DEFAULT 0,
+    agility                    INTEGER NOT NULL DEFAULT 0,
+    charisma                   INTEGER NOT NULL DEFAULT 0,
+    intelligence               INTEGER NOT NULL DEFAULT 0,
+    hit_points                 INTEGER NOT NULL
+        CHECK (hit_points > 0),
+    precision_defense          INTEGER NOT NULL,
+    precision_damage_reduction INTEGER NOT NULL DEFAULT 0,
+    area_defense               INTEGER NOT NULL,
+    area_damage_reduction      INTEGER NOT NULL DEFAULT 0
+) STRICT, WITHOUT ROWID;
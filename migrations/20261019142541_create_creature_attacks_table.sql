@@ -0,0 +1,18 @@
+CREATE TABLE creature_attacks (
+    creature_attack_id BLOB    PRIMARY KEY
+        CHECK (length(creature_attack_id) = 16),
+    creature_id        BLOB    NOT NULL
+        REFERENCES creatures(creature_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(creature_id) = 16),
+    weapon_id          BLOB    NOT NULL
+        REFERENCES weapons(weapon_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(weapon_id) = 16),
+    name               TEXT    NOT NULL
+        CHECK (name <> ''),
+    damage             INTEGER NOT NULL DEFAULT 1
+        CHECK (damage >= 0)
+) STRICT, WITHOUT ROWID;
@@ -0,0 +1,13 @@
+CREATE TABLE creatures_maneuvers (
+    creature_id BLOB NOT NULL
+        REFERENCES creatures(creature_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(creature_id) = 16),
+    maneuver_id BLOB NOT NULL
+        REFERENCES maneuvers(maneuver_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(maneuver_id) = 16),
+    PRIMARY KEY (creature_id, maneuver_id)
+) STRICT, WITHOUT ROWID;
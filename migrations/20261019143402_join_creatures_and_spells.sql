@@ -0,0 +1,13 @@
+CREATE TABLE creatures_spells (
+    creature_id BLOB NOT NULL
+        REFERENCES creatures(creature_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(creature_id) = 16),
+    spell_id    BLOB NOT NULL
+        REFERENCES spells(spell_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(spell_id) = 16),
+    PRIMARY KEY (creature_id, spell_id)
+) STRICT, WITHOUT ROWID;
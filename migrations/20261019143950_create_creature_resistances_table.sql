@@ -0,0 +1,19 @@
+CREATE TABLE creature_resistances (
+    creature_id BLOB    NOT NULL
+        REFERENCES creatures(creature_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(creature_id) = 16),
+    -- where it comes in the stat block; a damage type can have several,
+    -- as in Fire Resistance (Half) alongside Fire Vulnerability (1)
+    position    INTEGER NOT NULL
+        CHECK (position >= 0),
+    damage_type TEXT    NOT NULL
+        CHECK (damage_type <> ''),
+    kind        TEXT    NOT NULL
+        CHECK (kind IN ('Resistance', 'Half', 'Immunity', 'Vulnerability', 'Double')),
+    value       INTEGER     NULL,
+    CHECK ((`kind` IN ('Resistance', 'Vulnerability') AND `value` IS NOT NULL)
+        OR (`kind` IN ('Half', 'Immunity', 'Double') AND `value` IS NULL)),
+    PRIMARY KEY (creature_id, position)
+) STRICT, WITHOUT ROWID;
@@ -0,0 +1,3 @@
+ALTER TABLE items
+    ADD COLUMN is_container INTEGER NOT NULL DEFAULT FALSE
+        CHECK (is_container IN (0, 1));
@@ -0,0 +1,27 @@
+CREATE TABLE character_items (
+    character_item_id BLOB    PRIMARY KEY
+        CHECK (length(character_item_id) = 16),
+    character_id      BLOB    NOT NULL
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(character_id) = 16),
+    item_id           BLOB    NOT NULL
+        REFERENCES items(item_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(item_id) = 16),
+    quantity          INTEGER NOT NULL DEFAULT 1
+        CHECK (quantity > 0),
+    container_id      BLOB        NULL DEFAULT NULL
+        REFERENCES character_items(character_item_id)
+            ON DELETE SET NULL
+            ON UPDATE CASCADE
+        CHECK ((length(container_id) = 16)
+            AND (container_id <> character_item_id)),
+    slot              TEXT        NULL DEFAULT NULL
+        CHECK (slot IN ('MainHand', 'OffHand', 'Armor', 'Shield')),
+    attuned           INTEGER NOT NULL DEFAULT FALSE
+        CHECK (attuned IN (0, 1)),
+    UNIQUE (character_id, slot)
+) STRICT, WITHOUT ROWID;
@@ -0,0 +1,16 @@
+ALTER TABLE items
+    ADD COLUMN kind TEXT NOT NULL DEFAULT 'Gear'
+        CHECK (kind IN ('Gear', 'Weapon', 'Armor', 'Consumable'));
+
+ALTER TABLE items
+    ADD COLUMN weight REAL NOT NULL DEFAULT 0
+        CHECK (weight >= 0);
+
+-- in copper pieces
+ALTER TABLE items
+    ADD COLUMN price INTEGER NOT NULL DEFAULT 0
+        CHECK (price >= 0);
+
+UPDATE items
+SET kind = 'Weapon'
+WHERE item_id IN (SELECT weapon_id FROM weapons);
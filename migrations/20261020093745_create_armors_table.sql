@@ -0,0 +1,21 @@
+CREATE TABLE armors (
+    armor_id                   BLOB    PRIMARY KEY
+        REFERENCES items(item_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(armor_id) = 16),
+    type                       TEXT    NOT NULL
+        CHECK (type IN ('Light Armor', 'Heavy Armor', 'Light Shield', 'Heavy Shield')),
+    precision_defense_bonus    INTEGER NOT NULL DEFAULT 0
+        CHECK (precision_defense_bonus >= 0),
+    area_defense_bonus         INTEGER NOT NULL DEFAULT 0
+        CHECK (area_defense_bonus >= 0),
+    precision_damage_reduction INTEGER NOT NULL DEFAULT 0
+        CHECK (precision_damage_reduction >= 0),
+    area_damage_reduction      INTEGER NOT NULL DEFAULT 0
+        CHECK (area_damage_reduction >= 0),
+    speed_penalty              INTEGER NOT NULL DEFAULT 0
+        CHECK (speed_penalty >= 0),
+    agility_disadvantage       INTEGER NOT NULL DEFAULT FALSE
+        CHECK (agility_disadvantage IN (0, 1))
+) STRICT, WITHOUT ROWID;
@@ -0,0 +1,16 @@
+CREATE TABLE consumables (
+    consumable_id BLOB    PRIMARY KEY
+        REFERENCES items(item_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(consumable_id) = 16),
+    charges       INTEGER NOT NULL DEFAULT 1
+        CHECK (charges >= 0),
+    restores      TEXT        NULL DEFAULT NULL
+        CHECK (restores IN ('HitPoints', 'ManaPoints', 'StaminaPoints')),
+    amount        INTEGER     NULL DEFAULT NULL
+        CHECK (amount > 0),
+    description   TEXT    NOT NULL
+        CHECK (description <> ''),
+    CHECK ((restores IS NULL) = (amount IS NULL))
+) STRICT, WITHOUT ROWID;
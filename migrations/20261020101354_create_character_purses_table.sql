@@ -0,0 +1,15 @@
+CREATE TABLE character_purses (
+    character_id BLOB    PRIMARY KEY
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(character_id) = 16),
+    platinum     INTEGER NOT NULL DEFAULT 0
+        CHECK (platinum >= 0),
+    gold         INTEGER NOT NULL DEFAULT 0
+        CHECK (gold >= 0),
+    silver       INTEGER NOT NULL DEFAULT 0
+        CHECK (silver >= 0),
+    copper       INTEGER NOT NULL DEFAULT 0
+        CHECK (copper >= 0)
+) STRICT, WITHOUT ROWID;
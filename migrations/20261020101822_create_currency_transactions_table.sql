@@ -0,0 +1,22 @@
+CREATE TABLE currency_transactions (
+    currency_transaction_id BLOB    PRIMARY KEY
+        CHECK (length(currency_transaction_id) = 16),
+    character_id            BLOB    NOT NULL
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(character_id) = 16),
+    kind                    TEXT    NOT NULL
+        CHECK (kind IN ('Purchase', 'Sale', 'Award', 'Payment')),
+    -- kept when the Item is deleted so the ledger still balances
+    item_id                 BLOB        NULL DEFAULT NULL
+        REFERENCES items(item_id)
+            ON DELETE SET NULL
+            ON UPDATE CASCADE
+        CHECK (length(item_id) = 16),
+    quantity                INTEGER NOT NULL DEFAULT 0
+        CHECK (quantity >= 0),
+    -- in copper pieces, positive when the Character gains money
+    amount                  INTEGER NOT NULL,
+    note                    TEXT    NOT NULL DEFAULT ''
+) STRICT, WITHOUT ROWID;
@@ -0,0 +1,19 @@
+CREATE TABLE character_resources (
+    character_id       BLOB    PRIMARY KEY
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(character_id) = 16),
+    hit_points         INTEGER NOT NULL DEFAULT 0
+        CHECK (hit_points >= 0),
+    max_hit_points     INTEGER NOT NULL DEFAULT 0
+        CHECK (max_hit_points >= 0),
+    mana_points        INTEGER NOT NULL DEFAULT 0
+        CHECK (mana_points >= 0),
+    max_mana_points    INTEGER NOT NULL DEFAULT 0
+        CHECK (max_mana_points >= 0),
+    stamina_points     INTEGER NOT NULL DEFAULT 0
+        CHECK (stamina_points >= 0),
+    max_stamina_points INTEGER NOT NULL DEFAULT 0
+        CHECK (max_stamina_points >= 0)
+) STRICT, WITHOUT ROWID;
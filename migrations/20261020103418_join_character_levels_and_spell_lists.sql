@@ -0,0 +1,13 @@
+CREATE TABLE character_levels_spell_lists (
+    character_level_id BLOB NOT NULL
+        REFERENCES character_levels(character_level_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(character_level_id) = 16),
+    spell_list_id      BLOB NOT NULL
+        REFERENCES spell_lists(spell_list_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(spell_list_id) = 16),
+    PRIMARY KEY (character_level_id, spell_list_id)
+) STRICT, WITHOUT ROWID;
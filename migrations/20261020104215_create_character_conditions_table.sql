@@ -0,0 +1,10 @@
+CREATE TABLE character_conditions (
+    character_id BLOB NOT NULL
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(character_id) = 16),
+    name         TEXT NOT NULL
+        CHECK (name IN ('Dazed', 'Exposed', 'Hindered', 'Impaired', 'Encumbered', 'Heavily Encumbered')),
+    PRIMARY KEY (character_id, name)
+) STRICT, WITHOUT ROWID;
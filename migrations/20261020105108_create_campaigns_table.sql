@@ -0,0 +1,12 @@
+CREATE TABLE campaigns (
+    campaign_id    BLOB PRIMARY KEY
+        CHECK (length(campaign_id) = 16),
+    name           TEXT NOT NULL
+        CHECK (name <> ''),
+    -- the Player running the Campaign
+    game_master_id BLOB NOT NULL
+        REFERENCES players(player_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(game_master_id) = 16)
+) STRICT, WITHOUT ROWID;
@@ -0,0 +1,29 @@
+-- The shared numbers at a Campaign's table, bumped on every change so
+-- clients that drop out can ask for only what they missed.
+CREATE TABLE campaign_trackers (
+    campaign_id BLOB    PRIMARY KEY
+        REFERENCES campaigns(campaign_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(campaign_id) = 16),
+    version     INTEGER NOT NULL DEFAULT 0
+        CHECK (version >= 0)
+) STRICT, WITHOUT ROWID;
+
+CREATE TABLE campaign_tracker_characters (
+    campaign_id  BLOB    NOT NULL
+        REFERENCES campaign_trackers(campaign_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(campaign_id) = 16),
+    character_id BLOB    NOT NULL
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(character_id) = 16),
+    initiative   INTEGER     NULL DEFAULT NULL,
+    -- the tracker version the Character last changed at
+    version      INTEGER NOT NULL DEFAULT 0
+        CHECK (version >= 0),
+    PRIMARY KEY (campaign_id, character_id)
+) STRICT, WITHOUT ROWID;
@@ -0,0 +1,13 @@
+CREATE TABLE campaigns_characters (
+    campaign_id  BLOB NOT NULL
+        REFERENCES campaigns(campaign_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(campaign_id) = 16),
+    character_id BLOB NOT NULL
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(character_id) = 16),
+    PRIMARY KEY (campaign_id, character_id)
+) STRICT, WITHOUT ROWID;
@@ -0,0 +1,31 @@
+CREATE TABLE parties (
+    party_id    BLOB NOT NULL PRIMARY KEY
+        CHECK (length(party_id) = 16),
+    campaign_id BLOB NOT NULL
+        REFERENCES campaigns(campaign_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(campaign_id) = 16),
+    name        TEXT NOT NULL
+        CHECK (name <> ''),
+    UNIQUE (party_id, campaign_id)
+) STRICT, WITHOUT ROWID;
+
+-- a Party can only take Characters from its own Campaign
+CREATE TABLE parties_characters (
+    party_id     BLOB NOT NULL
+        CHECK (length(party_id) = 16),
+    campaign_id  BLOB NOT NULL
+        CHECK (length(campaign_id) = 16),
+    character_id BLOB NOT NULL
+        CHECK (length(character_id) = 16),
+    PRIMARY KEY (party_id, character_id),
+    FOREIGN KEY (party_id, campaign_id)
+        REFERENCES parties(party_id, campaign_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    FOREIGN KEY (campaign_id, character_id)
+        REFERENCES campaigns_characters(campaign_id, character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+) STRICT, WITHOUT ROWID;
@@ -0,0 +1,29 @@
+CREATE TABLE campaign_sessions (
+    session_id  BLOB PRIMARY KEY
+        CHECK (length(session_id) = 16),
+    campaign_id BLOB NOT NULL
+        REFERENCES campaigns(campaign_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(campaign_id) = 16),
+    -- YYYY-MM-DD
+    played_on   TEXT NOT NULL
+        CHECK (date(played_on) IS played_on),
+    title       TEXT NOT NULL DEFAULT '',
+    notes       TEXT NOT NULL DEFAULT ''
+) STRICT, WITHOUT ROWID;
+
+-- the Characters who were there
+CREATE TABLE campaign_sessions_characters (
+    session_id   BLOB NOT NULL
+        REFERENCES campaign_sessions(session_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(session_id) = 16),
+    character_id BLOB NOT NULL
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(character_id) = 16),
+    PRIMARY KEY (session_id, character_id)
+) STRICT, WITHOUT ROWID;
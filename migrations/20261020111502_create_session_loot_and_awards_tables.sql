@@ -0,0 +1,37 @@
+CREATE TABLE session_loot (
+    session_id  BLOB    NOT NULL
+        REFERENCES campaign_sessions(session_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(session_id) = 16),
+    -- keeps the order the loot was written down in
+    position    INTEGER NOT NULL
+        CHECK (position >= 0),
+    description TEXT    NOT NULL
+        CHECK (description <> ''),
+    quantity    INTEGER NOT NULL DEFAULT 1
+        CHECK (quantity > 0),
+    PRIMARY KEY (session_id, position)
+) STRICT, WITHOUT ROWID;
+
+CREATE TABLE session_awards (
+    session_id   BLOB    NOT NULL
+        REFERENCES campaign_sessions(session_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(session_id) = 16),
+    position     INTEGER NOT NULL
+        CHECK (position >= 0),
+    character_id BLOB    NOT NULL
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(character_id) = 16),
+    kind         TEXT    NOT NULL
+        CHECK (kind IN ('Experience', 'Milestone')),
+    -- only for Experience awards
+    experience   INTEGER NOT NULL DEFAULT 0
+        CHECK (experience >= 0),
+    note         TEXT    NOT NULL DEFAULT '',
+    PRIMARY KEY (session_id, position)
+) STRICT, WITHOUT ROWID;
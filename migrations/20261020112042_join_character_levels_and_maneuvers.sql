@@ -0,0 +1,13 @@
+CREATE TABLE character_levels_maneuvers (
+    character_level_id BLOB NOT NULL
+        REFERENCES character_levels(character_level_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(character_level_id) = 16),
+    maneuver_id        BLOB NOT NULL
+        REFERENCES maneuvers(maneuver_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(maneuver_id) = 16),
+    PRIMARY KEY (character_level_id, maneuver_id)
+) STRICT, WITHOUT ROWID;
@@ -0,0 +1,33 @@
+CREATE TABLE content_packs (
+    content_pack_id BLOB PRIMARY KEY
+        CHECK (length(content_pack_id) = 16),
+    name            TEXT NOT NULL
+        CHECK (name <> ''),
+    version         TEXT NOT NULL
+        CHECK (version <> ''),
+    author          TEXT NOT NULL
+        CHECK (author <> ''),
+    description     TEXT NOT NULL DEFAULT ''
+) STRICT, WITHOUT ROWID;
+
+-- the content each pack installed; a row belongs to at most one pack
+CREATE TABLE content_pack_contents (
+    content_pack_id BLOB NOT NULL
+        REFERENCES content_packs(content_pack_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(content_pack_id) = 16),
+    kind            TEXT NOT NULL
+        CHECK (kind IN (
+            'ancestry_trait',
+            'ancestry',
+            'background',
+            'class',
+            'spell',
+            'spell_list',
+            'weapon_property'
+        )),
+    content_id      BLOB NOT NULL
+        CHECK (length(content_id) = 16),
+    PRIMARY KEY (kind, content_id)
+) STRICT, WITHOUT ROWID;
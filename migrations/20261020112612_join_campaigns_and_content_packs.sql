@@ -0,0 +1,14 @@
+-- the packs enabled in a Campaign
+CREATE TABLE campaigns_content_packs (
+    campaign_id     BLOB NOT NULL
+        REFERENCES campaigns(campaign_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(campaign_id) = 16),
+    content_pack_id BLOB NOT NULL
+        REFERENCES content_packs(content_pack_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(content_pack_id) = 16),
+    PRIMARY KEY (campaign_id, content_pack_id)
+) STRICT, WITHOUT ROWID;
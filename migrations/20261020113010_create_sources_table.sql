@@ -0,0 +1,37 @@
+-- the rulebook, supplement or homebrew collection content comes from
+CREATE TABLE sources (
+    source_id BLOB PRIMARY KEY
+        CHECK (length(source_id) = 16),
+    name      TEXT NOT NULL UNIQUE
+        CHECK (name <> '')
+) STRICT, WITHOUT ROWID;
+
+ALTER TABLE spells ADD COLUMN source_id BLOB NULL DEFAULT NULL
+    REFERENCES sources(source_id)
+        ON DELETE SET NULL
+        ON UPDATE CASCADE
+    CHECK (length(source_id) = 16);
+
+ALTER TABLE classes ADD COLUMN source_id BLOB NULL DEFAULT NULL
+    REFERENCES sources(source_id)
+        ON DELETE SET NULL
+        ON UPDATE CASCADE
+    CHECK (length(source_id) = 16);
+
+ALTER TABLE backgrounds ADD COLUMN source_id BLOB NULL DEFAULT NULL
+    REFERENCES sources(source_id)
+        ON DELETE SET NULL
+        ON UPDATE CASCADE
+    CHECK (length(source_id) = 16);
+
+ALTER TABLE ancestry_traits ADD COLUMN source_id BLOB NULL DEFAULT NULL
+    REFERENCES sources(source_id)
+        ON DELETE SET NULL
+        ON UPDATE CASCADE
+    CHECK (length(source_id) = 16);
+
+ALTER TABLE weapon_properties ADD COLUMN source_id BLOB NULL DEFAULT NULL
+    REFERENCES sources(source_id)
+        ON DELETE SET NULL
+        ON UPDATE CASCADE
+    CHECK (length(source_id) = 16);
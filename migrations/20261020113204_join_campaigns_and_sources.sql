@@ -0,0 +1,14 @@
+-- the sources a Campaign allows content from; none means every source
+CREATE TABLE campaigns_sources (
+    campaign_id BLOB NOT NULL
+        REFERENCES campaigns(campaign_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(campaign_id) = 16),
+    source_id   BLOB NOT NULL
+        REFERENCES sources(source_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(source_id) = 16),
+    PRIMARY KEY (campaign_id, source_id)
+) STRICT, WITHOUT ROWID;
@@ -0,0 +1,26 @@
+-- a snapshot of the whole Character, as the JSON export, after each change
+CREATE TABLE character_revisions (
+    character_revision_id BLOB PRIMARY KEY
+        CHECK (length(character_revision_id) = 16),
+    character_id          BLOB NOT NULL
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(character_id) = 16),
+    -- the Player who made the change, when known
+    player_id             BLOB     NULL DEFAULT NULL
+        REFERENCES players(player_id)
+            ON DELETE SET NULL
+            ON UPDATE CASCADE
+        CHECK (length(player_id) = 16),
+    note                  TEXT NOT NULL DEFAULT '',
+    snapshot              TEXT NOT NULL
+        CHECK (json_valid(snapshot))
+) STRICT, WITHOUT ROWID;
+
+-- the history is only ever appended to; ids may still cascade
+CREATE TRIGGER character_revisions_append_only
+BEFORE UPDATE OF note, snapshot ON character_revisions
+BEGIN
+    SELECT RAISE(ABORT, 'character revisions cannot be changed');
+END;
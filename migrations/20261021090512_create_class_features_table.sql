@@ -0,0 +1,16 @@
+-- what a Class grants on reaching a level, as written in the book
+CREATE TABLE class_features (
+    class_feature_id BLOB    PRIMARY KEY
+        CHECK (length(class_feature_id) = 16),
+    class_id         BLOB    NOT NULL
+        REFERENCES classes(class_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(class_id) = 16),
+    level            INTEGER NOT NULL
+        CHECK (level BETWEEN 1 AND 10),
+    name             TEXT    NOT NULL
+        CHECK (name <> ''),
+    description      TEXT    NOT NULL
+        CHECK (description <> '')
+) STRICT, WITHOUT ROWID;
@@ -0,0 +1,31 @@
+-- a row of the Class Table: how much of each is gained on reaching the level
+CREATE TABLE class_table_rows (
+    class_id         BLOB    NOT NULL
+        REFERENCES classes(class_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(class_id) = 16),
+    level            INTEGER NOT NULL
+        CHECK (level BETWEEN 1 AND 10),
+    health_points    INTEGER NOT NULL DEFAULT 0
+        CHECK (health_points >= 0),
+    attribute_points INTEGER NOT NULL DEFAULT 0
+        CHECK (attribute_points >= 0),
+    skill_points     INTEGER NOT NULL DEFAULT 0
+        CHECK (skill_points >= 0),
+    trade_points     INTEGER NOT NULL DEFAULT 0
+        CHECK (trade_points >= 0),
+    stamina_points   INTEGER NOT NULL DEFAULT 0
+        CHECK (stamina_points >= 0),
+    maneuvers_known  INTEGER NOT NULL DEFAULT 0
+        CHECK (maneuvers_known >= 0),
+    techniques_known INTEGER NOT NULL DEFAULT 0
+        CHECK (techniques_known >= 0),
+    mana_points      INTEGER NOT NULL DEFAULT 0
+        CHECK (mana_points >= 0),
+    cantrips_known   INTEGER NOT NULL DEFAULT 0
+        CHECK (cantrips_known >= 0),
+    spells_known     INTEGER NOT NULL DEFAULT 0
+        CHECK (spells_known >= 0),
+    PRIMARY KEY (class_id, level)
+) STRICT, WITHOUT ROWID;
@@ -0,0 +1,16 @@
+-- what a new Character of the Class starts out carrying
+CREATE TABLE class_starting_equipment (
+    class_id BLOB    NOT NULL
+        REFERENCES classes(class_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(class_id) = 16),
+    item_id  BLOB    NOT NULL
+        REFERENCES items(item_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(item_id) = 16),
+    quantity INTEGER NOT NULL DEFAULT 1
+        CHECK (quantity > 0),
+    PRIMARY KEY (class_id, item_id)
+) STRICT, WITHOUT ROWID;
@@ -0,0 +1,19 @@
+-- the Mastery a Character raised a Skill to at a Level
+CREATE TABLE character_levels_skills (
+    character_level_id BLOB NOT NULL
+        REFERENCES character_levels(character_level_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(character_level_id) = 16),
+    skill_id           BLOB NOT NULL
+        REFERENCES skills(skill_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(skill_id) = 16),
+    mastery_id         BLOB NOT NULL
+        REFERENCES masteries(mastery_id)
+            ON DELETE RESTRICT
+            ON UPDATE CASCADE
+        CHECK (length(mastery_id) = 16),
+    PRIMARY KEY (character_level_id, skill_id)
+) STRICT, WITHOUT ROWID;
@@ -0,0 +1,14 @@
+-- The Experience a Campaign asks for each Level past the first. DC20
+-- levels by Milestone, so a Campaign without any only levels that way.
+CREATE TABLE campaign_experience_thresholds (
+    campaign_id BLOB    NOT NULL
+        REFERENCES campaigns(campaign_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(campaign_id) = 16),
+    level       INTEGER NOT NULL
+        CHECK (level BETWEEN 2 AND 10),
+    experience  INTEGER NOT NULL
+        CHECK (experience > 0),
+    PRIMARY KEY (campaign_id, level)
+) STRICT, WITHOUT ROWID;
@@ -0,0 +1,14 @@
+-- the Spells a Character learned at each Level, from their Spell Lists
+CREATE TABLE character_levels_spells (
+    character_level_id BLOB NOT NULL
+        REFERENCES character_levels(character_level_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(character_level_id) = 16),
+    spell_id           BLOB NOT NULL
+        REFERENCES spells(spell_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(spell_id) = 16),
+    PRIMARY KEY (character_level_id, spell_id)
+) STRICT, WITHOUT ROWID;
@@ -0,0 +1,14 @@
+-- the Attribute Saves a Class gives Mastery in to the Characters who start in it
+CREATE TABLE attributes_classes (
+    attribute_id BLOB NOT NULL
+        REFERENCES attributes(attribute_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(attribute_id) = 16),
+    class_id     BLOB NOT NULL
+        REFERENCES classes(class_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+        CHECK (length(class_id) = 16),
+    PRIMARY KEY (attribute_id, class_id)
+) STRICT, WITHOUT ROWID;
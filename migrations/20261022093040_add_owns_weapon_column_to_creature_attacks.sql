@@ -0,0 +1,5 @@
+-- whether the Attack's Weapon was made for the Creature when it was saved,
+-- rather than taken from the catalog, and so goes away with the Attack
+ALTER TABLE creature_attacks
+    ADD COLUMN owns_weapon INTEGER NOT NULL DEFAULT 0
+        CHECK (owns_weapon IN (0, 1));
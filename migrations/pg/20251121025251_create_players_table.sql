@@ -0,0 +1,5 @@
+CREATE TABLE players (
+    player_id UUID PRIMARY KEY,
+    name      TEXT NOT NULL
+        CHECK (name <> '')
+);
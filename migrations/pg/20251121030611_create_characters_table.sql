@@ -0,0 +1,9 @@
+CREATE TABLE characters (
+    character_id UUID PRIMARY KEY,
+    name         TEXT NOT NULL
+        CHECK (name <> ''),
+    player_id    UUID NOT NULL
+        REFERENCES players(player_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
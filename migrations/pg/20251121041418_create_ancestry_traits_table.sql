@@ -0,0 +1,8 @@
+CREATE TABLE ancestry_traits (
+    ancestry_trait_id UUID    PRIMARY KEY,
+    name              TEXT    NOT NULL
+        CHECK (name <> ''),
+    description       TEXT    NOT NULL
+        CHECK (description <> ''),
+    cost              BIGINT  NOT NULL
+);
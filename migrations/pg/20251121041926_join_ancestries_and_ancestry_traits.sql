@@ -0,0 +1,12 @@
+CREATE TABLE ancestries_ancestry_traits (
+    ancestry_id       UUID
+        REFERENCES ancestries(ancestry_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    ancestry_trait_id UUID
+        REFERENCES ancestry_traits(ancestry_trait_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    expanded          BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (ancestry_id, ancestry_trait_id)
+);
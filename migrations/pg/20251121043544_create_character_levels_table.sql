@@ -0,0 +1,9 @@
+CREATE TABLE character_levels (
+    character_level_id UUID PRIMARY KEY,
+    character_id       UUID
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    level              BIGINT  NOT NULL,
+    UNIQUE (character_id, level)
+);
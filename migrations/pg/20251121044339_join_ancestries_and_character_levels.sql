@@ -0,0 +1,11 @@
+CREATE TABLE ancestries_character_levels (
+    ancestry_id UUID
+        REFERENCES ancestries(ancestry_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    character_level_id UUID
+        REFERENCES character_levels(character_level_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (ancestry_id, character_level_id)
+);
@@ -0,0 +1,11 @@
+CREATE TABLE ancestry_traits_character_levels (
+    ancestry_trait_id  UUID NOT NULL
+        REFERENCES ancestry_traits(ancestry_trait_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    character_level_id UUID NOT NULL
+        REFERENCES character_levels(character_level_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (ancestry_trait_id, character_level_id)
+);
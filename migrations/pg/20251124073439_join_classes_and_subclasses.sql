@@ -0,0 +1,11 @@
+CREATE TABLE classes_subclasses (
+    class_id UUID NOT NULL
+        REFERENCES classes(class_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    subclass_id UUID NOT NULL
+        REFERENCES subclasses(subclass_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (class_id, subclass_id)
+);
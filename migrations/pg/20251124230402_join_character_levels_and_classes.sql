@@ -0,0 +1,11 @@
+CREATE TABLE character_levels_classes (
+    character_level_id UUID NOT NULL
+        REFERENCES character_levels(character_level_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    class_id           UUID NOT NULL
+        REFERENCES classes(class_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (character_level_id, class_id)
+);
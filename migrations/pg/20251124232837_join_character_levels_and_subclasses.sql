@@ -0,0 +1,11 @@
+CREATE TABLE character_levels_subclasses (
+    character_level_id UUID NOT NULL
+        REFERENCES character_levels(character_level_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    subclass_id        UUID NOT NULL
+        REFERENCES subclasses(subclass_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (character_level_id, subclass_id)
+);
@@ -0,0 +1,9 @@
+CREATE TABLE skills (
+    skill_id     UUID PRIMARY KEY,
+    name         TEXT NOT NULL
+        CHECK (name <> ''),
+    attribute_id UUID NOT NULL
+        REFERENCES attributes(attribute_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
@@ -0,0 +1,5 @@
+CREATE TABLE trades (
+    trade_id UUID PRIMARY KEY,
+    name     TEXT NOT NULL
+        CHECK (name <> '')
+);
@@ -0,0 +1,11 @@
+CREATE TABLE attributes_trades (
+    attribute_id UUID NOT NULL
+        REFERENCES attributes(attribute_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    trade_id     UUID NOT NULL
+        REFERENCES trades(trade_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (attribute_id, trade_id)
+);
@@ -0,0 +1,6 @@
+CREATE TABLE masteries (
+    mastery_id UUID PRIMARY KEY,
+    name       TEXT NOT NULL
+        CHECK (name <> ''),
+    bonus      BIGINT  NOT NULL
+);
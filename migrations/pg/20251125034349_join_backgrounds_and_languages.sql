@@ -0,0 +1,13 @@
+CREATE TABLE backgrounds_languages (
+    background_id UUID    NOT NULL
+        REFERENCES backgrounds(background_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    language_id   UUID    NOT NULL
+        REFERENCES languages(language_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    fluency       BIGINT  NOT NULL
+        CHECK (fluency IN (1, 2)),
+    PRIMARY KEY (background_id, language_id)
+);
@@ -0,0 +1,11 @@
+CREATE TABLE backgrounds_character_levels (
+    background_id      UUID NOT NULL
+        REFERENCES backgrounds(background_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    character_level_id UUID NOT NULL
+        REFERENCES character_levels(character_level_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (background_id, character_level_id)
+);
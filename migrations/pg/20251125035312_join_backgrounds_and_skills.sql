@@ -0,0 +1,11 @@
+CREATE TABLE backgrounds_skills (
+    background_id UUID NOT NULL
+        REFERENCES backgrounds(background_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    skill_id      UUID NOT NULL
+        REFERENCES skills(skill_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (background_id, skill_id)
+);
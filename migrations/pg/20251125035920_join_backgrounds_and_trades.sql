@@ -0,0 +1,11 @@
+CREATE TABLE backgrounds_trades (
+    background_id UUID NOT NULL
+        REFERENCES backgrounds(background_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    trade_id      UUID NOT NULL
+        REFERENCES trades(trade_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (background_id, trade_id)
+);
@@ -0,0 +1,12 @@
+CREATE TABLE character_level_base_attribute_values (
+    character_level_id UUID    NOT NULL
+        REFERENCES character_levels(character_level_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    attribute_id       UUID    NOT NULL
+        REFERENCES attributes(attribute_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    value              BIGINT  NOT NULL,
+    PRIMARY KEY (character_level_id, attribute_id)
+);
@@ -0,0 +1,5 @@
+CREATE TABLE spell_schools (
+    spell_school_id UUID PRIMARY KEY,
+    name            TEXT NOT NULL
+        CHECK (name <> '')
+);
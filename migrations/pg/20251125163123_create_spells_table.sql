@@ -0,0 +1,23 @@
+CREATE TABLE spells (
+    spell_id          UUID    PRIMARY KEY,
+    name              TEXT    NOT NULL
+        CHECK (name <> ''),
+    spell_school_id   UUID    NOT NULL
+        REFERENCES spell_schools(spell_school_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    has_verbal        BOOLEAN NOT NULL DEFAULT FALSE,
+    has_somatic       BOOLEAN NOT NULL DEFAULT FALSE,
+    action_point_cost BIGINT  NOT NULL DEFAULT 1,
+    mana_point_cost   BIGINT  NOT NULL DEFAULT 0,
+    range_kind        TEXT    NOT NULL,
+    range_value       BIGINT      NULL,
+    duration_kind     TEXT    NOT NULL,
+    duration_value    BIGINT      NULL,
+    sustained         BOOLEAN NOT NULL DEFAULT FALSE,
+    description       TEXT        NULL,
+    CHECK ((range_kind IN ('Self', 'Touch') AND range_value IS NULL)
+        OR (range_kind = 'Spaces' AND range_value IS NOT NULL)),
+    CHECK ((duration_kind = 'Instant' AND duration_value IS NULL)
+        OR (duration_kind IN ('Minute', 'Hour', 'Round') AND duration_value IS NOT NULL))
+);
@@ -0,0 +1,12 @@
+CREATE TABLE spell_material_components (
+    spell_id UUID NOT NULL
+        REFERENCES spells(spell_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    item_id  UUID NOT NULL
+        REFERENCES items(item_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    consumed BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (spell_id, item_id)
+);
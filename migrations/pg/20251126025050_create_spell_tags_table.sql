@@ -0,0 +1,5 @@
+CREATE TABLE spell_tags (
+    spell_tag_id UUID PRIMARY KEY,
+    name         TEXT NOT NULL
+        CHECK (name <> '')
+);
@@ -0,0 +1,11 @@
+CREATE TABLE spells_spell_tags (
+    spell_id UUID NOT NULL
+        REFERENCES spells(spell_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    spell_tag_id UUID NOT NULL
+        REFERENCES spell_tags(spell_tag_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (spell_id, spell_tag_id)
+);
@@ -0,0 +1,5 @@
+CREATE TABLE spell_lists (
+    spell_list_id UUID PRIMARY KEY,
+    name          TEXT NOT NULL
+        CHECK (name <> '')
+);
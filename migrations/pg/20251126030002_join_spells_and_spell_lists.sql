@@ -0,0 +1,11 @@
+CREATE TABLE spells_spell_lists (
+    spell_id UUID NOT NULL
+        REFERENCES spells(spell_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    spell_list_id UUID NOT NULL
+        REFERENCES spell_lists(spell_list_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (spell_id, spell_list_id)
+);
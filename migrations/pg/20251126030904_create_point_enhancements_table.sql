@@ -0,0 +1,11 @@
+CREATE TABLE point_enhancements (
+    point_enhancement_id UUID    PRIMARY KEY,
+    name                 TEXT    NOT NULL
+        CHECK (name <> ''),
+    action_point_cost    BIGINT  NOT NULL
+        DEFAULT 0,
+    mana_point_cost      BIGINT  NOT NULL
+        DEFAULT 0,
+    description          TEXT    NOT NULL
+        CHECK (description <> '')
+);
@@ -0,0 +1,11 @@
+CREATE TABLE point_enhancements_spells (
+    point_enhancement_id UUID NOT NULL
+        REFERENCES point_enhancements(point_enhancement_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    spell_id UUID NOT NULL
+        REFERENCES spells(spell_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (point_enhancement_id, spell_id)
+);
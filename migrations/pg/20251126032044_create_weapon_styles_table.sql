@@ -0,0 +1,9 @@
+CREATE TABLE weapon_styles (
+    weapon_style_id UUID PRIMARY KEY,
+    name            TEXT NOT NULL
+        CHECK (name <> ''),
+    description     TEXT NOT NULL
+        CHECK (description <> ''),
+    damage_type     TEXT NOT NULL
+        CHECK (damage_type IN ('Bludgeoning', 'Piercing', 'Slashing'))
+);
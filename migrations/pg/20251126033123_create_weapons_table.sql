@@ -0,0 +1,9 @@
+CREATE TABLE weapons (
+    weapon_id UUID PRIMARY KEY
+        REFERENCES items(item_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    type      TEXT NOT NULL
+        CHECK ((type <> '')
+            AND (type IN ('Melee', 'Ranged')))
+);
@@ -0,0 +1,11 @@
+CREATE TABLE weapons_weapon_styles (
+    weapon_id       UUID NOT NULL
+        REFERENCES weapons(weapon_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    weapon_style_id UUID NOT NULL
+        REFERENCES weapon_styles(weapon_style_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (weapon_id, weapon_style_id)
+);
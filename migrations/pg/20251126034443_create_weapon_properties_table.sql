@@ -0,0 +1,13 @@
+CREATE TABLE weapon_properties (
+    weapon_property_id           UUID    PRIMARY KEY,
+    name                         TEXT    NOT NULL
+        CHECK (name <> ''),
+    description                  TEXT    NOT NULL
+        CHECK (description <> ''),
+    cost                         BIGINT  NOT NULL DEFAULT 1,
+    required_weapon_property_id  UUID        NULL DEFAULT NULL
+        REFERENCES weapon_properties(weapon_property_id)
+            ON DELETE SET DEFAULT
+            ON UPDATE CASCADE
+        CHECK (weapon_property_id <> required_weapon_property_id)
+);
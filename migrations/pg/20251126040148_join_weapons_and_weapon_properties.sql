@@ -0,0 +1,11 @@
+CREATE TABLE weapons_weapon_properties (
+    weapon_id           UUID NOT NULL
+        REFERENCES weapons(weapon_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    weapon_property_id  UUID NOT NULL
+        REFERENCES weapon_properties(weapon_property_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (weapon_id, weapon_property_id)
+);
@@ -0,0 +1,7 @@
+CREATE TABLE spell_effects (
+    spell_effect_id UUID PRIMARY KEY,
+    name            TEXT NOT NULL
+        CHECK (name <> ''),
+    description     TEXT NOT NULL
+        CHECK (description <> '')
+);
@@ -0,0 +1,11 @@
+CREATE TABLE spells_spell_effects (
+    spell_id      UUID NOT NULL
+        REFERENCES spells(spell_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+  spell_effect_id UUID NOT NULL
+        REFERENCES spell_effects(spell_effect_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+  PRIMARY KEY (spell_id, spell_effect_id)
+);
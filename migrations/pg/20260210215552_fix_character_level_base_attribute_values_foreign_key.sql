@@ -0,0 +1,3 @@
+-- the table was created here with the right foreign key to begin with, so
+-- there is nothing to fix; kept so both sets of migrations line up
+SELECT 1;
@@ -0,0 +1,56 @@
+CREATE VIEW character_level_attributes
+    ( character_level_id
+    , prime
+    , might
+    , agility
+    , charisma
+    , intelligence
+    )
+AS
+SELECT character_level_id
+    , prime
+    , might
+    , agility
+    , charisma
+    , intelligence
+FROM
+    (SELECT character_level_id
+        , value AS prime
+    FROM character_level_base_attribute_values
+    JOIN attributes
+    USING (attribute_id)
+    WHERE name LIKE '%Prime%'
+    ) AS primes
+    JOIN
+    ( SELECT character_level_id
+        , value AS might
+    FROM character_level_base_attribute_values
+    JOIN attributes
+    USING (attribute_id)
+    WHERE name LIKE '%Might%'
+    ) AS mights USING (character_level_id)
+    JOIN
+    ( SELECT character_level_id
+        , value AS agility
+    FROM character_level_base_attribute_values
+    JOIN attributes
+    USING (attribute_id)
+    WHERE name LIKE '%Agility%'
+    ) AS agilities USING (character_level_id)
+    JOIN
+    (SELECT character_level_id
+        , value AS charisma
+    FROM character_level_base_attribute_values
+    JOIN attributes
+    USING (attribute_id)
+    WHERE name LIKE '%Charisma%'
+    ) AS charismas USING (character_level_id)
+    JOIN
+    (SELECT character_level_id
+        , value AS intelligence
+    FROM character_level_base_attribute_values
+    JOIN attributes
+    USING (attribute_id)
+    WHERE name LIKE '%Intelligence%'
+    ) AS intelligences USING (character_level_id)
+;
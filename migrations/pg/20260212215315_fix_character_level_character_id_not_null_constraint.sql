@@ -0,0 +1,6 @@
+-- account for levels left without a Character
+DELETE FROM character_levels
+WHERE character_id IS NULL;
+
+ALTER TABLE character_levels
+    ALTER COLUMN character_id SET NOT NULL;
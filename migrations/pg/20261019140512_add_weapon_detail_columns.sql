@@ -0,0 +1,11 @@
+ALTER TABLE weapons
+    ADD COLUMN damage_type TEXT    NULL DEFAULT NULL
+        CHECK (damage_type IN ('Bludgeoning', 'Piercing', 'Slashing'));
+
+ALTER TABLE weapons
+    ADD COLUMN range_value BIGINT  NULL DEFAULT NULL
+        CHECK (range_value > 0);
+
+-- styles granted by the Multi-Faceted property sit next to the primary style
+ALTER TABLE weapons_weapon_styles
+    ADD COLUMN multi_faceted BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,11 @@
+CREATE TABLE maneuvers (
+    maneuver_id        UUID    PRIMARY KEY,
+    name               TEXT    NOT NULL
+        CHECK (name <> ''),
+    kind               TEXT    NOT NULL
+        CHECK (kind IN ('Attack', 'Save', 'Grapple', 'Defense')),
+    action_point_cost  BIGINT  NOT NULL DEFAULT 1,
+    stamina_point_cost BIGINT  NOT NULL DEFAULT 1,
+    description        TEXT    NOT NULL
+        CHECK (description <> '')
+);
@@ -0,0 +1,17 @@
+CREATE TABLE creatures (
+    creature_id                UUID    PRIMARY KEY,
+    name                       TEXT    NOT NULL
+        CHECK (name <> ''),
+    level                      BIGINT  NOT NULL DEFAULT 0,
+    prime                      BIGINT  NOT NULL DEFAULT 0,
+    might                      BIGINT  NOT NULL DEFAULT 0,
+    agility                    BIGINT  NOT NULL DEFAULT 0,
+    charisma                   BIGINT  NOT NULL DEFAULT 0,
+    intelligence               BIGINT  NOT NULL DEFAULT 0,
+    hit_points                 BIGINT  NOT NULL
+        CHECK (hit_points > 0),
+    precision_defense          BIGINT  NOT NULL,
+    precision_damage_reduction BIGINT  NOT NULL DEFAULT 0,
+    area_defense               BIGINT  NOT NULL,
+    area_damage_reduction      BIGINT  NOT NULL DEFAULT 0
+);
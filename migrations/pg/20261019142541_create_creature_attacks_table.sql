@@ -0,0 +1,15 @@
+CREATE TABLE creature_attacks (
+    creature_attack_id UUID    PRIMARY KEY,
+    creature_id        UUID    NOT NULL
+        REFERENCES creatures(creature_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    weapon_id          UUID    NOT NULL
+        REFERENCES weapons(weapon_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    name               TEXT    NOT NULL
+        CHECK (name <> ''),
+    damage             BIGINT  NOT NULL DEFAULT 1
+        CHECK (damage >= 0)
+);
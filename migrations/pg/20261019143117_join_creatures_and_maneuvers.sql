@@ -0,0 +1,11 @@
+CREATE TABLE creatures_maneuvers (
+    creature_id UUID NOT NULL
+        REFERENCES creatures(creature_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    maneuver_id UUID NOT NULL
+        REFERENCES maneuvers(maneuver_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (creature_id, maneuver_id)
+);
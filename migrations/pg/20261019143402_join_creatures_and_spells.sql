@@ -0,0 +1,11 @@
+CREATE TABLE creatures_spells (
+    creature_id UUID NOT NULL
+        REFERENCES creatures(creature_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    spell_id    UUID NOT NULL
+        REFERENCES spells(spell_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (creature_id, spell_id)
+);
@@ -0,0 +1,2 @@
+ALTER TABLE items
+    ADD COLUMN is_container BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,22 @@
+CREATE TABLE character_items (
+    character_item_id UUID    PRIMARY KEY,
+    character_id      UUID    NOT NULL
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    item_id           UUID    NOT NULL
+        REFERENCES items(item_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    quantity          BIGINT  NOT NULL DEFAULT 1
+        CHECK (quantity > 0),
+    container_id      UUID        NULL DEFAULT NULL
+        REFERENCES character_items(character_item_id)
+            ON DELETE SET NULL
+            ON UPDATE CASCADE
+        CHECK (container_id <> character_item_id),
+    slot              TEXT        NULL DEFAULT NULL
+        CHECK (slot IN ('MainHand', 'OffHand', 'Armor', 'Shield')),
+    attuned           BOOLEAN NOT NULL DEFAULT FALSE,
+    UNIQUE (character_id, slot)
+);
@@ -0,0 +1,19 @@
+CREATE TABLE armors (
+    armor_id                   UUID    PRIMARY KEY
+        REFERENCES items(item_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    type                       TEXT    NOT NULL
+        CHECK (type IN ('Light Armor', 'Heavy Armor', 'Light Shield', 'Heavy Shield')),
+    precision_defense_bonus    BIGINT  NOT NULL DEFAULT 0
+        CHECK (precision_defense_bonus >= 0),
+    area_defense_bonus         BIGINT  NOT NULL DEFAULT 0
+        CHECK (area_defense_bonus >= 0),
+    precision_damage_reduction BIGINT  NOT NULL DEFAULT 0
+        CHECK (precision_damage_reduction >= 0),
+    area_damage_reduction      BIGINT  NOT NULL DEFAULT 0
+        CHECK (area_damage_reduction >= 0),
+    speed_penalty              BIGINT  NOT NULL DEFAULT 0
+        CHECK (speed_penalty >= 0),
+    agility_disadvantage       BOOLEAN NOT NULL DEFAULT FALSE
+);
@@ -0,0 +1,15 @@
+CREATE TABLE consumables (
+    consumable_id UUID    PRIMARY KEY
+        REFERENCES items(item_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    charges       BIGINT  NOT NULL DEFAULT 1
+        CHECK (charges >= 0),
+    restores      TEXT        NULL DEFAULT NULL
+        CHECK (restores IN ('HitPoints', 'ManaPoints', 'StaminaPoints')),
+    amount        BIGINT      NULL DEFAULT NULL
+        CHECK (amount > 0),
+    description   TEXT    NOT NULL
+        CHECK (description <> ''),
+    CHECK ((restores IS NULL) = (amount IS NULL))
+);
@@ -0,0 +1,4 @@
+-- charges left on the Consumable in use, NULL when it is untouched
+ALTER TABLE character_items
+    ADD COLUMN charges BIGINT  NULL DEFAULT NULL
+        CHECK (charges >= 0);
@@ -0,0 +1,14 @@
+CREATE TABLE character_purses (
+    character_id UUID    PRIMARY KEY
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    platinum     BIGINT  NOT NULL DEFAULT 0
+        CHECK (platinum >= 0),
+    gold         BIGINT  NOT NULL DEFAULT 0
+        CHECK (gold >= 0),
+    silver       BIGINT  NOT NULL DEFAULT 0
+        CHECK (silver >= 0),
+    copper       BIGINT  NOT NULL DEFAULT 0
+        CHECK (copper >= 0)
+);
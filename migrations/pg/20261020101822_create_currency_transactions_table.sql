@@ -0,0 +1,19 @@
+CREATE TABLE currency_transactions (
+    currency_transaction_id UUID    PRIMARY KEY,
+    character_id            UUID    NOT NULL
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    kind                    TEXT    NOT NULL
+        CHECK (kind IN ('Purchase', 'Sale', 'Award', 'Payment')),
+    -- kept when the Item is deleted so the ledger still balances
+    item_id                 UUID        NULL DEFAULT NULL
+        REFERENCES items(item_id)
+            ON DELETE SET NULL
+            ON UPDATE CASCADE,
+    quantity                BIGINT  NOT NULL DEFAULT 0
+        CHECK (quantity >= 0),
+    -- in copper pieces, positive when the Character gains money
+    amount                  BIGINT  NOT NULL,
+    note                    TEXT    NOT NULL DEFAULT ''
+);
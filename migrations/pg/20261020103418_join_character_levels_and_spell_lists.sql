@@ -0,0 +1,11 @@
+CREATE TABLE character_levels_spell_lists (
+    character_level_id UUID NOT NULL
+        REFERENCES character_levels(character_level_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    spell_list_id      UUID NOT NULL
+        REFERENCES spell_lists(spell_list_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (character_level_id, spell_list_id)
+);
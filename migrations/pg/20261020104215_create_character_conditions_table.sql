@@ -0,0 +1,9 @@
+CREATE TABLE character_conditions (
+    character_id UUID NOT NULL
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    name         TEXT NOT NULL
+        CHECK (name IN ('Dazed', 'Exposed', 'Hindered', 'Impaired', 'Encumbered', 'Heavily Encumbered')),
+    PRIMARY KEY (character_id, name)
+);
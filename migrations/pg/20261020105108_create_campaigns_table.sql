@@ -0,0 +1,10 @@
+CREATE TABLE campaigns (
+    campaign_id    UUID PRIMARY KEY,
+    name           TEXT NOT NULL
+        CHECK (name <> ''),
+    -- the Player running the Campaign
+    game_master_id UUID NOT NULL
+        REFERENCES players(player_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
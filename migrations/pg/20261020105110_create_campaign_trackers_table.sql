@@ -0,0 +1,26 @@
+-- The shared numbers at a Campaign's table, bumped on every change so
+-- clients that drop out can ask for only what they missed.
+CREATE TABLE campaign_trackers (
+    campaign_id UUID    PRIMARY KEY
+        REFERENCES campaigns(campaign_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    version     BIGINT  NOT NULL DEFAULT 0
+        CHECK (version >= 0)
+);
+
+CREATE TABLE campaign_tracker_characters (
+    campaign_id  UUID    NOT NULL
+        REFERENCES campaign_trackers(campaign_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    character_id UUID    NOT NULL
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    initiative   BIGINT      NULL DEFAULT NULL,
+    -- the tracker version the Character last changed at
+    version      BIGINT  NOT NULL DEFAULT 0
+        CHECK (version >= 0),
+    PRIMARY KEY (campaign_id, character_id)
+);
@@ -0,0 +1,11 @@
+CREATE TABLE campaigns_characters (
+    campaign_id  UUID NOT NULL
+        REFERENCES campaigns(campaign_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    character_id UUID NOT NULL
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (campaign_id, character_id)
+);
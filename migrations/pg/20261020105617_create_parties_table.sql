@@ -0,0 +1,26 @@
+CREATE TABLE parties (
+    party_id    UUID NOT NULL PRIMARY KEY,
+    campaign_id UUID NOT NULL
+        REFERENCES campaigns(campaign_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    name        TEXT NOT NULL
+        CHECK (name <> ''),
+    UNIQUE (party_id, campaign_id)
+);
+
+-- a Party can only take Characters from its own Campaign
+CREATE TABLE parties_characters (
+    party_id     UUID NOT NULL,
+    campaign_id  UUID NOT NULL,
+    character_id UUID NOT NULL,
+    PRIMARY KEY (party_id, character_id),
+    FOREIGN KEY (party_id, campaign_id)
+        REFERENCES parties(party_id, campaign_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    FOREIGN KEY (campaign_id, character_id)
+        REFERENCES campaigns_characters(campaign_id, character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
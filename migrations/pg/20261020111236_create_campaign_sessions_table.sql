@@ -0,0 +1,23 @@
+CREATE TABLE campaign_sessions (
+    session_id  UUID PRIMARY KEY,
+    campaign_id UUID NOT NULL
+        REFERENCES campaigns(campaign_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    played_on   DATE NOT NULL,
+    title       TEXT NOT NULL DEFAULT '',
+    notes       TEXT NOT NULL DEFAULT ''
+);
+
+-- the Characters who were there
+CREATE TABLE campaign_sessions_characters (
+    session_id   UUID NOT NULL
+        REFERENCES campaign_sessions(session_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    character_id UUID NOT NULL
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (session_id, character_id)
+);
@@ -0,0 +1,34 @@
+CREATE TABLE session_loot (
+    session_id  UUID    NOT NULL
+        REFERENCES campaign_sessions(session_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    -- keeps the order the loot was written down in
+    position    BIGINT  NOT NULL
+        CHECK (position >= 0),
+    description TEXT    NOT NULL
+        CHECK (description <> ''),
+    quantity    BIGINT  NOT NULL DEFAULT 1
+        CHECK (quantity > 0),
+    PRIMARY KEY (session_id, position)
+);
+
+CREATE TABLE session_awards (
+    session_id   UUID    NOT NULL
+        REFERENCES campaign_sessions(session_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    position     BIGINT  NOT NULL
+        CHECK (position >= 0),
+    character_id UUID    NOT NULL
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    kind         TEXT    NOT NULL
+        CHECK (kind IN ('Experience', 'Milestone')),
+    -- only for Experience awards
+    experience   BIGINT  NOT NULL DEFAULT 0
+        CHECK (experience >= 0),
+    note         TEXT    NOT NULL DEFAULT '',
+    PRIMARY KEY (session_id, position)
+);
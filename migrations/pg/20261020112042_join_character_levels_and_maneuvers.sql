@@ -0,0 +1,11 @@
+CREATE TABLE character_levels_maneuvers (
+    character_level_id UUID NOT NULL
+        REFERENCES character_levels(character_level_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    maneuver_id        UUID NOT NULL
+        REFERENCES maneuvers(maneuver_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (character_level_id, maneuver_id)
+);
@@ -0,0 +1,30 @@
+CREATE TABLE content_packs (
+    content_pack_id UUID PRIMARY KEY,
+    name            TEXT NOT NULL
+        CHECK (name <> ''),
+    version         TEXT NOT NULL
+        CHECK (version <> ''),
+    author          TEXT NOT NULL
+        CHECK (author <> ''),
+    description     TEXT NOT NULL DEFAULT ''
+);
+
+-- the content each pack installed; a row belongs to at most one pack
+CREATE TABLE content_pack_contents (
+    content_pack_id UUID NOT NULL
+        REFERENCES content_packs(content_pack_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    kind            TEXT NOT NULL
+        CHECK (kind IN (
+            'ancestry_trait',
+            'ancestry',
+            'background',
+            'class',
+            'spell',
+            'spell_list',
+            'weapon_property'
+        )),
+    content_id      UUID NOT NULL,
+    PRIMARY KEY (kind, content_id)
+);
@@ -0,0 +1,12 @@
+-- the packs enabled in a Campaign
+CREATE TABLE campaigns_content_packs (
+    campaign_id     UUID NOT NULL
+        REFERENCES campaigns(campaign_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    content_pack_id UUID NOT NULL
+        REFERENCES content_packs(content_pack_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (campaign_id, content_pack_id)
+);
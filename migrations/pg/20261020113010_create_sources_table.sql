@@ -0,0 +1,31 @@
+-- the rulebook, supplement or homebrew collection content comes from
+CREATE TABLE sources (
+    source_id UUID PRIMARY KEY,
+    name      TEXT NOT NULL UNIQUE
+        CHECK (name <> '')
+);
+
+ALTER TABLE spells ADD COLUMN source_id UUID NULL DEFAULT NULL
+    REFERENCES sources(source_id)
+        ON DELETE SET NULL
+        ON UPDATE CASCADE;
+
+ALTER TABLE classes ADD COLUMN source_id UUID NULL DEFAULT NULL
+    REFERENCES sources(source_id)
+        ON DELETE SET NULL
+        ON UPDATE CASCADE;
+
+ALTER TABLE backgrounds ADD COLUMN source_id UUID NULL DEFAULT NULL
+    REFERENCES sources(source_id)
+        ON DELETE SET NULL
+        ON UPDATE CASCADE;
+
+ALTER TABLE ancestry_traits ADD COLUMN source_id UUID NULL DEFAULT NULL
+    REFERENCES sources(source_id)
+        ON DELETE SET NULL
+        ON UPDATE CASCADE;
+
+ALTER TABLE weapon_properties ADD COLUMN source_id UUID NULL DEFAULT NULL
+    REFERENCES sources(source_id)
+        ON DELETE SET NULL
+        ON UPDATE CASCADE;
@@ -0,0 +1,12 @@
+-- the sources a Campaign allows content from; none means every source
+CREATE TABLE campaigns_sources (
+    campaign_id UUID NOT NULL
+        REFERENCES campaigns(campaign_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    source_id   UUID NOT NULL
+        REFERENCES sources(source_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (campaign_id, source_id)
+);
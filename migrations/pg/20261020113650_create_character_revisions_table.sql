@@ -0,0 +1,26 @@
+-- a snapshot of the whole Character, as the JSON export, after each change
+CREATE TABLE character_revisions (
+    character_revision_id UUID PRIMARY KEY,
+    character_id          UUID NOT NULL
+        REFERENCES characters(character_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    -- the Player who made the change, when known
+    player_id             UUID     NULL DEFAULT NULL
+        REFERENCES players(player_id)
+            ON DELETE SET NULL
+            ON UPDATE CASCADE,
+    note                  TEXT  NOT NULL DEFAULT '',
+    snapshot              JSONB NOT NULL
+);
+
+-- the history is only ever appended to; ids may still cascade
+CREATE FUNCTION character_revisions_append_only() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'character revisions cannot be changed';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER character_revisions_append_only
+BEFORE UPDATE OF note, snapshot ON character_revisions
+FOR EACH ROW EXECUTE FUNCTION character_revisions_append_only();
@@ -0,0 +1,14 @@
+-- what a Class grants on reaching a level, as written in the book
+CREATE TABLE class_features (
+    class_feature_id UUID    PRIMARY KEY,
+    class_id         UUID    NOT NULL
+        REFERENCES classes(class_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    level            BIGINT  NOT NULL
+        CHECK (level BETWEEN 1 AND 10),
+    name             TEXT    NOT NULL
+        CHECK (name <> ''),
+    description      TEXT    NOT NULL
+        CHECK (description <> '')
+);
@@ -0,0 +1,30 @@
+-- a row of the Class Table: how much of each is gained on reaching the level
+CREATE TABLE class_table_rows (
+    class_id         UUID    NOT NULL
+        REFERENCES classes(class_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    level            BIGINT  NOT NULL
+        CHECK (level BETWEEN 1 AND 10),
+    health_points    BIGINT  NOT NULL DEFAULT 0
+        CHECK (health_points >= 0),
+    attribute_points BIGINT  NOT NULL DEFAULT 0
+        CHECK (attribute_points >= 0),
+    skill_points     BIGINT  NOT NULL DEFAULT 0
+        CHECK (skill_points >= 0),
+    trade_points     BIGINT  NOT NULL DEFAULT 0
+        CHECK (trade_points >= 0),
+    stamina_points   BIGINT  NOT NULL DEFAULT 0
+        CHECK (stamina_points >= 0),
+    maneuvers_known  BIGINT  NOT NULL DEFAULT 0
+        CHECK (maneuvers_known >= 0),
+    techniques_known BIGINT  NOT NULL DEFAULT 0
+        CHECK (techniques_known >= 0),
+    mana_points      BIGINT  NOT NULL DEFAULT 0
+        CHECK (mana_points >= 0),
+    cantrips_known   BIGINT  NOT NULL DEFAULT 0
+        CHECK (cantrips_known >= 0),
+    spells_known     BIGINT  NOT NULL DEFAULT 0
+        CHECK (spells_known >= 0),
+    PRIMARY KEY (class_id, level)
+);
@@ -0,0 +1,14 @@
+-- what a new Character of the Class starts out carrying
+CREATE TABLE class_starting_equipment (
+    class_id UUID    NOT NULL
+        REFERENCES classes(class_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    item_id  UUID    NOT NULL
+        REFERENCES items(item_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    quantity BIGINT  NOT NULL DEFAULT 1
+        CHECK (quantity > 0),
+    PRIMARY KEY (class_id, item_id)
+);
@@ -0,0 +1,16 @@
+-- the Mastery a Character raised a Skill to at a Level
+CREATE TABLE character_levels_skills (
+    character_level_id UUID NOT NULL
+        REFERENCES character_levels(character_level_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    skill_id           UUID NOT NULL
+        REFERENCES skills(skill_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    mastery_id         UUID NOT NULL
+        REFERENCES masteries(mastery_id)
+            ON DELETE RESTRICT
+            ON UPDATE CASCADE,
+    PRIMARY KEY (character_level_id, skill_id)
+);
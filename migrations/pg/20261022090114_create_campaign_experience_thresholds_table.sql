@@ -0,0 +1,13 @@
+-- The Experience a Campaign asks for each Level past the first. DC20
+-- levels by Milestone, so a Campaign without any only levels that way.
+CREATE TABLE campaign_experience_thresholds (
+    campaign_id UUID    NOT NULL
+        REFERENCES campaigns(campaign_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    level       BIGINT  NOT NULL
+        CHECK (level BETWEEN 2 AND 10),
+    experience  BIGINT  NOT NULL
+        CHECK (experience > 0),
+    PRIMARY KEY (campaign_id, level)
+);
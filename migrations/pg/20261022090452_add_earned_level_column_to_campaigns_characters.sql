@@ -0,0 +1,5 @@
+-- the Level the Character's Awards in the Campaign add up to, kept up to
+-- date whenever they change
+ALTER TABLE campaigns_characters
+    ADD COLUMN earned_level BIGINT  NOT NULL DEFAULT 1
+        CHECK (earned_level BETWEEN 1 AND 10);
@@ -0,0 +1,12 @@
+-- the Spells a Character learned at each Level, from their Spell Lists
+CREATE TABLE character_levels_spells (
+    character_level_id UUID NOT NULL
+        REFERENCES character_levels(character_level_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    spell_id           UUID NOT NULL
+        REFERENCES spells(spell_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (character_level_id, spell_id)
+);
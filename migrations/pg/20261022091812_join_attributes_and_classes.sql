@@ -0,0 +1,12 @@
+-- the Attribute Saves a Class gives Mastery in to the Characters who start in it
+CREATE TABLE attributes_classes (
+    attribute_id UUID NOT NULL
+        REFERENCES attributes(attribute_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    class_id     UUID NOT NULL
+        REFERENCES classes(class_id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    PRIMARY KEY (attribute_id, class_id)
+);
@@ -0,0 +1,4 @@
+-- whether the Attack's Weapon was made for the Creature when it was saved,
+-- rather than taken from the catalog, and so goes away with the Attack
+ALTER TABLE creature_attacks
+    ADD COLUMN owns_weapon BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,273 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{get, put},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::{
+    campaign::{Campaign, Party, PartySummary},
+    dc20::{Character, Level, Source},
+    pack::Manifest,
+    player::Player,
+};
+
+use super::{check_id, deleted, ApiResult, NamedInput};
+
+pub fn routes() -> Router<SqlitePool> {
+    Router::new()
+        .route("/campaigns", get(list).post(create))
+        .route("/campaigns/{id}", get(show).put(replace).delete(remove))
+        .route("/campaigns/{id}/characters", get(characters))
+        .route(
+            "/campaigns/{id}/characters/{character_id}",
+            put(add_character).delete(remove_character),
+        )
+        .route("/campaigns/{id}/packs", get(content_packs))
+        .route(
+            "/campaigns/{id}/packs/{pack_id}",
+            put(enable_content_pack).delete(disable_content_pack),
+        )
+        .route("/campaigns/{id}/sources", get(sources))
+        .route(
+            "/campaigns/{id}/sources/{source_id}",
+            put(allow_source).delete(disallow_source),
+        )
+        .route("/campaigns/{id}/parties", get(parties).post(create_party))
+        .route("/parties/{id}", get(show_party).delete(remove_party))
+        .route("/parties/{id}/summary", get(summarize_party))
+        .route(
+            "/parties/{id}/characters/{character_id}",
+            put(add_party_character).delete(remove_party_character),
+        )
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct CampaignInput {
+    id: Option<Uuid>,
+    name: String,
+    game_master_id: Uuid,
+}
+
+async fn build(pool: &SqlitePool, input: CampaignInput) -> ApiResult<Campaign> {
+    let game_master = Player::load(pool, input.game_master_id).await?;
+
+    let mut builder = Campaign::builder();
+    builder.name(input.name)?.game_master(game_master);
+    if let Some(id) = input.id {
+        builder.id(id);
+    }
+
+    Ok(builder.build()?)
+}
+
+async fn list(State(pool): State<SqlitePool>) -> ApiResult<Json<Vec<Campaign>>> {
+    Ok(Json(Campaign::list(&pool).await?))
+}
+
+async fn create(
+    State(pool): State<SqlitePool>,
+    Json(input): Json<CampaignInput>,
+) -> ApiResult<(StatusCode, Json<Campaign>)> {
+    let campaign = build(&pool, input).await?;
+    campaign.clone().save(&pool).await?;
+
+    Ok((StatusCode::CREATED, Json(campaign)))
+}
+
+async fn show(State(pool): State<SqlitePool>, Path(id): Path<Uuid>) -> ApiResult<Json<Campaign>> {
+    Ok(Json(Campaign::load(&pool, id).await?))
+}
+
+async fn replace(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    Json(input): Json<CampaignInput>,
+) -> ApiResult<Json<Campaign>> {
+    let campaign = build(
+        &pool,
+        CampaignInput {
+            id: Some(input.id.unwrap_or(id)),
+            ..input
+        },
+    )
+    .await?;
+    check_id(id, *campaign.id())?;
+    campaign.clone().save(&pool).await?;
+
+    Ok(Json(campaign))
+}
+
+async fn remove(State(pool): State<SqlitePool>, Path(id): Path<Uuid>) -> ApiResult<StatusCode> {
+    deleted(Campaign::delete(&pool, id).await?)
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct CharacterAtLevel {
+    character: Character,
+    level: Level,
+}
+
+/// Every Character in the Campaign at their highest Level.
+async fn characters(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Vec<CharacterAtLevel>>> {
+    let campaign = Campaign::load(&pool, id).await?;
+
+    Ok(Json(
+        campaign
+            .load_characters_at_max_level(&pool)
+            .await?
+            .into_iter()
+            .map(|(character, level)| CharacterAtLevel { character, level })
+            .collect(),
+    ))
+}
+
+async fn add_character(
+    State(pool): State<SqlitePool>,
+    Path((id, character_id)): Path<(Uuid, Uuid)>,
+) -> ApiResult<StatusCode> {
+    let campaign = Campaign::load(&pool, id).await?;
+    campaign.add_character(&pool, character_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn remove_character(
+    State(pool): State<SqlitePool>,
+    Path((id, character_id)): Path<(Uuid, Uuid)>,
+) -> ApiResult<StatusCode> {
+    let campaign = Campaign::load(&pool, id).await?;
+
+    deleted(campaign.remove_character(&pool, character_id).await?)
+}
+
+async fn content_packs(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Vec<Manifest>>> {
+    let campaign = Campaign::load(&pool, id).await?;
+
+    Ok(Json(campaign.load_content_packs(&pool).await?))
+}
+
+async fn enable_content_pack(
+    State(pool): State<SqlitePool>,
+    Path((id, pack_id)): Path<(Uuid, Uuid)>,
+) -> ApiResult<StatusCode> {
+    let campaign = Campaign::load(&pool, id).await?;
+    campaign.enable_content_pack(&pool, pack_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn disable_content_pack(
+    State(pool): State<SqlitePool>,
+    Path((id, pack_id)): Path<(Uuid, Uuid)>,
+) -> ApiResult<StatusCode> {
+    let campaign = Campaign::load(&pool, id).await?;
+
+    deleted(campaign.disable_content_pack(&pool, pack_id).await?)
+}
+
+/// The Sources the Campaign allows; none at all means every Source is.
+async fn sources(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Vec<Source>>> {
+    let campaign = Campaign::load(&pool, id).await?;
+
+    Ok(Json(campaign.load_sources(&pool).await?))
+}
+
+async fn allow_source(
+    State(pool): State<SqlitePool>,
+    Path((id, source_id)): Path<(Uuid, Uuid)>,
+) -> ApiResult<StatusCode> {
+    let campaign = Campaign::load(&pool, id).await?;
+    campaign.allow_source(&pool, source_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn disallow_source(
+    State(pool): State<SqlitePool>,
+    Path((id, source_id)): Path<(Uuid, Uuid)>,
+) -> ApiResult<StatusCode> {
+    let campaign = Campaign::load(&pool, id).await?;
+
+    deleted(campaign.disallow_source(&pool, source_id).await?)
+}
+
+async fn parties(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Vec<Party>>> {
+    let campaign = Campaign::load(&pool, id).await?;
+
+    Ok(Json(campaign.load_parties(&pool).await?))
+}
+
+async fn create_party(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    Json(input): Json<NamedInput>,
+) -> ApiResult<(StatusCode, Json<Party>)> {
+    let mut builder = Party::builder();
+    builder.campaign_id(id).name(input.name)?;
+    if let Some(party_id) = input.id {
+        builder.id(party_id);
+    }
+    let party = builder.build()?;
+    party.clone().save(&pool).await?;
+
+    Ok((StatusCode::CREATED, Json(party)))
+}
+
+async fn show_party(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Party>> {
+    Ok(Json(Party::load(&pool, id).await?))
+}
+
+async fn remove_party(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    deleted(Party::delete(&pool, id).await?)
+}
+
+async fn summarize_party(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<PartySummary>> {
+    let party = Party::load(&pool, id).await?;
+
+    Ok(Json(party.summarize(&pool).await?))
+}
+
+/// Only Characters already in the Party's Campaign can join it.
+async fn add_party_character(
+    State(pool): State<SqlitePool>,
+    Path((id, character_id)): Path<(Uuid, Uuid)>,
+) -> ApiResult<StatusCode> {
+    let party = Party::load(&pool, id).await?;
+    party.add_character(&pool, character_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn remove_party_character(
+    State(pool): State<SqlitePool>,
+    Path((id, character_id)): Path<(Uuid, Uuid)>,
+) -> ApiResult<StatusCode> {
+    let party = Party::load(&pool, id).await?;
+
+    deleted(party.remove_character(&pool, character_id).await?)
+}
@@ -0,0 +1,464 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::{
+    dc20::{
+        AncestryTrait, Attack, Attributes, Background, Character, CharacterSummary, Class,
+        Combatant, Condition, Defense, Encumbrance, Level, Resources,
+    },
+    export::{
+        foundry::{self, Actor, Imported},
+        json::{self, CharacterExport, Ids},
+        sheet::CharacterSheet,
+    },
+    history::{self, Change, Entry, Point},
+    player::Player,
+    progression::{self, LevelComparison, LevelSnapshot, LevelStats},
+    repository::{Repository, SqliteRepository},
+};
+
+use super::{check_id, deleted, found, tracked, ApiResult, Author};
+
+pub fn routes() -> Router<SqlitePool> {
+    Router::new()
+        .route("/characters", get(list).post(create))
+        .route("/characters/import", post(import))
+        .route("/characters/import/foundry", post(import_foundry))
+        .route("/characters/{id}", get(show).put(replace).delete(remove))
+        .route("/characters/{id}/levels", get(levels).post(level_up))
+        .route("/characters/{id}/levels/compare", get(compare_levels))
+        .route("/characters/{id}/levels/{level}", get(at_level))
+        .route("/characters/{id}/progression", get(progression))
+        .route("/characters/{id}/stats", get(stats))
+        .route("/characters/{id}/defenses", get(defenses))
+        .route("/characters/{id}/export", get(export))
+        .route("/characters/{id}/sheet.md", get(sheet_markdown))
+        .route("/characters/{id}/sheet.html", get(sheet_html))
+        .route("/characters/{id}/sheet.pdf", get(sheet_pdf))
+        .route("/characters/{id}/foundry", get(export_foundry))
+        .route("/characters/{id}/history", get(show_history))
+        .route("/characters/{id}/history/diff", get(diff))
+        .route(
+            "/characters/{id}/history/{revision_id}/restore",
+            post(restore),
+        )
+}
+
+async fn list(State(pool): State<SqlitePool>) -> ApiResult<Json<Vec<CharacterSummary>>> {
+    Ok(Json(Character::list(&pool).await?))
+}
+
+/// Characters are written with the ids of their Player, Background and
+/// Ancestry Traits, which must already exist.
+#[derive(Clone, Debug, Deserialize)]
+struct CharacterInput {
+    id: Option<Uuid>,
+    character_name: String,
+    player_id: Uuid,
+    background_id: Uuid,
+    #[serde(default)]
+    ancestry_trait_ids: Vec<Uuid>,
+}
+
+/// A new Character, with the Class and base Attributes of their first
+/// Level.
+#[derive(Clone, Debug, Deserialize)]
+struct NewCharacterInput {
+    #[serde(flatten)]
+    character: CharacterInput,
+    #[serde(default)]
+    class_id: Option<Uuid>,
+    #[serde(default)]
+    attributes: Attributes,
+}
+
+async fn build_character(pool: &SqlitePool, input: CharacterInput) -> ApiResult<Character> {
+    let mut builder = Character::builder();
+    builder
+        .player(Player::load(pool, input.player_id).await?)
+        .character_name(input.character_name)
+        .background(Background::load(pool, input.background_id).await?);
+    if let Some(id) = input.id {
+        builder.id(id);
+    }
+    for id in input.ancestry_trait_ids {
+        builder.ancestry_trait(AncestryTrait::load(pool, id).await?);
+    }
+
+    Ok(builder.build()?)
+}
+
+/// Creates the Character at Level 1, giving them their Class's starting
+/// equipment.
+async fn create(
+    State(pool): State<SqlitePool>,
+    Author(author): Author,
+    Json(input): Json<NewCharacterInput>,
+) -> ApiResult<(StatusCode, Json<Character>)> {
+    let character = build_character(&pool, input.character).await?;
+    let id = *character.id();
+    let class = match input.class_id {
+        Some(class_id) => Some(Class::load(&pool, class_id).await?),
+        None => None,
+    };
+    let mut tx = pool.begin().await?;
+    character
+        .create(&mut *tx, class.as_ref(), &input.attributes)
+        .await?;
+    history::record(&mut *tx, id, author, "Created").await?;
+    tx.commit().await?;
+
+    Ok((StatusCode::CREATED, Json(Character::load(&pool, id).await?)))
+}
+
+async fn show(
+    State(characters): State<SqliteRepository>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Character>> {
+    Ok(Json(found(&characters, id).await?))
+}
+
+async fn replace(
+    State(characters): State<SqliteRepository>,
+    Path(id): Path<Uuid>,
+    author: Author,
+    Json(input): Json<CharacterInput>,
+) -> ApiResult<Json<Character>> {
+    let pool = characters.pool();
+    let character = build_character(
+        pool,
+        CharacterInput {
+            id: Some(input.id.unwrap_or(id)),
+            ..input
+        },
+    )
+    .await?;
+    check_id(id, *character.id())?;
+    tracked(
+        pool,
+        id,
+        author,
+        |_| "Replaced".into(),
+        async |tx| character.save(tx).await,
+    )
+    .await?;
+
+    Ok(Json(found(&characters, id).await?))
+}
+
+async fn remove(
+    State(characters): State<SqliteRepository>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    deleted(Repository::<Character>::delete(&characters, id).await?)
+}
+
+async fn export(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<CharacterExport>> {
+    Ok(Json(json::export(&pool, id).await?))
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+struct ImportQuery {
+    #[serde(default)]
+    ids: Ids,
+}
+
+/// `POST /characters/import?ids=Remap` imports the export in the body as a
+/// new Character; without `ids` the export's own ids are kept, and with
+/// `ids=Overwrite` a Character already saved with them is replaced.
+async fn import(
+    State(pool): State<SqlitePool>,
+    Query(ImportQuery { ids }): Query<ImportQuery>,
+    Author(author): Author,
+    body: String,
+) -> ApiResult<(StatusCode, Json<Character>)> {
+    let mut tx = pool.begin().await?;
+    let character = json::import(&mut *tx, &body, ids).await?;
+    history::record(&mut *tx, *character.id(), author, "Imported").await?;
+    tx.commit().await?;
+
+    Ok((StatusCode::CREATED, Json(character)))
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+struct FoundryImportQuery {
+    player: Uuid,
+}
+
+/// Creates a Character from a Foundry VTT actor, played by an existing Player.
+async fn import_foundry(
+    State(pool): State<SqlitePool>,
+    Query(FoundryImportQuery { player }): Query<FoundryImportQuery>,
+    Author(author): Author,
+    body: String,
+) -> ApiResult<(StatusCode, Json<Imported>)> {
+    let player = Player::load(&pool, player).await?;
+    let mut tx = pool.begin().await?;
+    let imported = foundry::import(&mut *tx, &body, player).await?;
+    let id = *imported.character.id();
+    history::record(&mut *tx, id, author, "Imported from Foundry VTT").await?;
+    tx.commit().await?;
+
+    Ok((StatusCode::CREATED, Json(imported)))
+}
+
+async fn levels(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Vec<Level>>> {
+    let character = Character::load(&pool, id).await?;
+
+    Ok(Json(character.load_levels(&pool).await?))
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct LevelUp {
+    /// Defaults to the Classes of the Character's current Level.
+    class_id: Option<Uuid>,
+}
+
+async fn level_up(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    author: Author,
+    Json(request): Json<LevelUp>,
+) -> ApiResult<(StatusCode, Json<Level>)> {
+    let character = Character::load(&pool, id).await?;
+    let class = match request.class_id {
+        Some(class_id) => Some(Class::load(&pool, class_id).await?),
+        None => None,
+    };
+
+    let level = tracked(
+        &pool,
+        id,
+        author,
+        |level: &Level| format!("Reached Level {}", level.level),
+        async |tx| character.level_up(tx, class.as_ref()).await,
+    )
+    .await?;
+
+    Ok((StatusCode::CREATED, Json(level)))
+}
+
+async fn at_level(
+    State(pool): State<SqlitePool>,
+    Path((id, level)): Path<(Uuid, u32)>,
+) -> ApiResult<Json<LevelSnapshot>> {
+    Ok(Json(progression::at_level(&pool, id, level).await?))
+}
+
+/// Two of the Character's Levels, in either order.
+#[derive(Clone, Copy, Debug, Deserialize)]
+struct CompareQuery {
+    from: u32,
+    to: u32,
+}
+
+async fn compare_levels(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    Query(CompareQuery { from, to }): Query<CompareQuery>,
+) -> ApiResult<Json<LevelComparison>> {
+    Ok(Json(progression::compare(&pool, id, from, to).await?))
+}
+
+async fn progression(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Vec<LevelStats>>> {
+    Ok(Json(progression::chart(&pool, id).await?))
+}
+
+/// Which of the Character's Levels to compute from; the latest by default.
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+struct AtLevel {
+    level: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct Stats {
+    level: Level,
+    combat_mastery: usize,
+    attributes: Attributes,
+    precision_defense: Defense,
+    area_defense: Defense,
+    attack_check_bonus: i64,
+    speed: u64,
+    encumbrance: Encumbrance,
+    conditions: Vec<Condition>,
+    attacks: Vec<Attack>,
+    resources: Resources,
+}
+
+async fn stats(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    Query(at): Query<AtLevel>,
+) -> ApiResult<Json<Stats>> {
+    let character = Character::load(&pool, id).await?;
+    let level = match at.level {
+        Some(level) => level,
+        None => character.load_max_level(&pool).await?.level,
+    };
+    let combatant = character.load_combatant(&pool, level).await?;
+
+    Ok(Json(Stats {
+        level: combatant.level.clone(),
+        combat_mastery: combatant.combat_mastery(),
+        attributes: combatant.attributes.clone(),
+        precision_defense: combatant.precision_defense(),
+        area_defense: combatant.area_defense(),
+        attack_check_bonus: combatant.attack_check_bonus(),
+        speed: combatant.speed(),
+        encumbrance: Encumbrance::new(&combatant.attributes, &combatant.inventory),
+        conditions: combatant.conditions.iter().copied().collect(),
+        attacks: combatant.attacks().to_vec(),
+        resources: Resources::load(&pool, id).await?,
+    }))
+}
+
+#[derive(Clone, Copy, Debug, Serialize)]
+struct Defenses {
+    precision_defense: Defense,
+    area_defense: Defense,
+}
+
+async fn defenses(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    Query(at): Query<AtLevel>,
+) -> ApiResult<Json<Defenses>> {
+    let character = Character::load(&pool, id).await?;
+    let level = match at.level {
+        Some(level) => level,
+        None => character.load_max_level(&pool).await?.level,
+    };
+    let combatant = character.load_combatant(&pool, level).await?;
+
+    Ok(Json(Defenses {
+        precision_defense: combatant.precision_defense(),
+        area_defense: combatant.area_defense(),
+    }))
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct SheetQuery {
+    level: Option<u32>,
+}
+
+async fn sheet(pool: &SqlitePool, id: Uuid, query: SheetQuery) -> ApiResult<CharacterSheet> {
+    let character = Character::load(pool, id).await?;
+    let level = match query.level {
+        Some(level) => character.load_level(pool, level).await?,
+        None => character.load_max_level(pool).await?,
+    };
+
+    CharacterSheet::load(pool, &character, &level).await
+}
+
+async fn sheet_markdown(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<SheetQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let sheet = sheet(&pool, id, query).await?;
+
+    Ok((
+        [(header::CONTENT_TYPE, "text/markdown; charset=utf-8")],
+        sheet.to_markdown(),
+    ))
+}
+
+async fn sheet_html(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<SheetQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let sheet = sheet(&pool, id, query).await?;
+
+    Ok((
+        [(header::CONTENT_TYPE, "text/html; charset=utf-8")],
+        sheet.to_html(),
+    ))
+}
+
+async fn sheet_pdf(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<SheetQuery>,
+) -> ApiResult<Response> {
+    let sheet = sheet(&pool, id, query).await?;
+
+    Ok(match sheet.to_pdf() {
+        Ok(pdf) => ([(header::CONTENT_TYPE, "application/pdf")], pdf).into_response(),
+        // Only the builtin fonts are used, so there's nothing the client can change
+        Err(error) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({ "error": error.to_string() })),
+        )
+            .into_response(),
+    })
+}
+
+async fn export_foundry(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<SheetQuery>,
+) -> ApiResult<Json<Actor>> {
+    let sheet = sheet(&pool, id, query).await?;
+
+    Ok(Json(Actor::from(&sheet)))
+}
+
+async fn show_history(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Vec<Entry>>> {
+    Character::load(&pool, id).await?;
+
+    Ok(Json(history::log(&pool, id).await?))
+}
+
+/// Two points in a Character's history, each a revision id or an RFC 3339
+/// time. `to` is the Character as they are now when left out.
+#[derive(Clone, Debug, Deserialize)]
+struct DiffQuery {
+    from: String,
+    to: Option<String>,
+}
+
+async fn diff(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    Query(DiffQuery { from, to }): Query<DiffQuery>,
+) -> ApiResult<Json<Vec<Change>>> {
+    let from = from.parse::<Point>()?;
+    let to = match to {
+        Some(to) => Some(to.parse::<Point>()?),
+        None => None,
+    };
+
+    Ok(Json(history::diff_between(&pool, id, from, to).await?))
+}
+
+async fn restore(
+    State(pool): State<SqlitePool>,
+    Path((id, revision_id)): Path<(Uuid, Uuid)>,
+    Author(author): Author,
+) -> ApiResult<Json<Character>> {
+    Ok(Json(
+        history::restore(&pool, id, revision_id, author).await?,
+    ))
+}
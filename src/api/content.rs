@@ -0,0 +1,393 @@
+//! Backgrounds, Ancestries and Classes: rules content that is little more
+//! than a name, with the details hanging off it. Listing any of them with
+//! `?campaign=<id>` leaves out what that Campaign's content packs don't offer.
+//! Sources, the books all of it comes from, live here too.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::{
+    campaign::Campaign,
+    dc20::{Ancestry, Background, Class, Language, Skill, Source, Subclass, Trade},
+    pack::{self, ContentKind},
+    repository::{Catalog, Repository, SqliteRepository},
+};
+
+use super::{check_id, deleted, found, ApiResult, NamedInput};
+
+pub fn routes() -> Router<SqlitePool> {
+    Router::new()
+        .route(
+            "/backgrounds",
+            get(list_backgrounds).post(create_background),
+        )
+        .route(
+            "/backgrounds/{id}",
+            get(show_background)
+                .put(replace_background)
+                .delete(remove_background),
+        )
+        .route("/ancestries", get(list_ancestries).post(create_ancestry))
+        .route(
+            "/ancestries/{id}",
+            get(show_ancestry)
+                .put(replace_ancestry)
+                .delete(remove_ancestry),
+        )
+        .route("/classes", get(list_classes).post(create_class))
+        .route(
+            "/classes/{id}",
+            get(show_class).put(replace_class).delete(remove_class),
+        )
+        .route("/sources", get(list_sources).post(create_source))
+        .route(
+            "/sources/{id}",
+            get(show_source).put(replace_source).delete(remove_source),
+        )
+}
+
+/// `?campaign=<id>` leaves out content the Campaign doesn't use: from packs
+/// it hasn't enabled, or from Sources it doesn't allow.
+#[derive(Debug, Default, Deserialize)]
+struct ContentFilter {
+    campaign: Option<Uuid>,
+}
+
+impl ContentFilter {
+    /// Filters `content` by id, and by Source when it has one to filter by.
+    async fn apply<T>(
+        &self,
+        pool: &SqlitePool,
+        kind: ContentKind,
+        content: Vec<T>,
+        id: impl Fn(&T) -> Uuid,
+        source: Option<fn(&T) -> Option<Uuid>>,
+    ) -> ApiResult<Vec<T>> {
+        let Some(campaign_id) = self.campaign else {
+            return Ok(content);
+        };
+        let hidden = pack::hidden(pool, campaign_id, kind).await?;
+        let sources = Campaign::load(pool, campaign_id)
+            .await?
+            .load_source_filter(pool)
+            .await?;
+
+        Ok(content
+            .into_iter()
+            .filter(|item| !hidden.contains(&id(item)))
+            .filter(|item| match (&sources, source) {
+                (Some(sources), Some(source)) => sources.allows(source(item)),
+                _ => true,
+            })
+            .collect())
+    }
+}
+
+/// A [`NamedInput`] for content that can name the Source it comes from.
+#[derive(Clone, Debug, Deserialize)]
+struct SourcedInput {
+    id: Option<Uuid>,
+    name: String,
+    #[serde(default)]
+    source: Option<Uuid>,
+}
+
+/// A Background with the Skills, Trades and Languages it grants.
+#[derive(Debug, Serialize)]
+struct BackgroundDetails {
+    #[serde(flatten)]
+    background: Background,
+    skills: Vec<Skill>,
+    trades: Vec<Trade>,
+    languages: Vec<Language>,
+}
+
+fn build_background(input: SourcedInput) -> ApiResult<Background> {
+    let mut builder = Background::builder();
+    builder.name(input.name)?;
+    if let Some(id) = input.id {
+        builder.id(id);
+    }
+
+    let mut background = builder.build()?;
+    background.source = input.source;
+
+    Ok(background)
+}
+
+async fn list_backgrounds(
+    State(content): State<SqliteRepository>,
+    Query(filter): Query<ContentFilter>,
+) -> ApiResult<Json<Vec<Background>>> {
+    let backgrounds = Catalog::<Background>::list(&content).await?;
+
+    Ok(Json(
+        filter
+            .apply(
+                content.pool(),
+                ContentKind::Background,
+                backgrounds,
+                |b| b.id,
+                Some(|b| b.source),
+            )
+            .await?,
+    ))
+}
+
+async fn create_background(
+    State(content): State<SqliteRepository>,
+    Json(input): Json<SourcedInput>,
+) -> ApiResult<(StatusCode, Json<Background>)> {
+    let background = build_background(input)?;
+    content.save(background.clone()).await?;
+
+    Ok((StatusCode::CREATED, Json(background)))
+}
+
+async fn show_background(
+    State(content): State<SqliteRepository>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<BackgroundDetails>> {
+    let background = found::<Background>(&content, id).await?;
+
+    Ok(Json(BackgroundDetails {
+        skills: background.load_skills(content.pool()).await?,
+        trades: background.load_trades(content.pool()).await?,
+        languages: background.load_languages(content.pool()).await?,
+        background,
+    }))
+}
+
+async fn replace_background(
+    State(content): State<SqliteRepository>,
+    Path(id): Path<Uuid>,
+    Json(input): Json<SourcedInput>,
+) -> ApiResult<Json<Background>> {
+    let background = build_background(SourcedInput {
+        id: Some(input.id.unwrap_or(id)),
+        ..input
+    })?;
+    check_id(id, background.id)?;
+    content.save(background.clone()).await?;
+
+    Ok(Json(background))
+}
+
+async fn remove_background(
+    State(content): State<SqliteRepository>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    deleted(Repository::<Background>::delete(&content, id).await?)
+}
+
+fn build_ancestry(input: NamedInput) -> ApiResult<Ancestry> {
+    let mut builder = Ancestry::builder();
+    builder.name(input.name);
+    if let Some(id) = input.id {
+        builder.id(id);
+    }
+
+    Ok(builder.build()?)
+}
+
+async fn list_ancestries(
+    State(content): State<SqliteRepository>,
+    Query(filter): Query<ContentFilter>,
+) -> ApiResult<Json<Vec<Ancestry>>> {
+    let ancestries = Catalog::<Ancestry>::list(&content).await?;
+
+    Ok(Json(
+        filter
+            .apply(
+                content.pool(),
+                ContentKind::Ancestry,
+                ancestries,
+                |a| a.id,
+                None,
+            )
+            .await?,
+    ))
+}
+
+async fn create_ancestry(
+    State(content): State<SqliteRepository>,
+    Json(input): Json<NamedInput>,
+) -> ApiResult<(StatusCode, Json<Ancestry>)> {
+    let ancestry = build_ancestry(input)?;
+    content.save(ancestry.clone()).await?;
+
+    Ok((StatusCode::CREATED, Json(ancestry)))
+}
+
+async fn show_ancestry(
+    State(content): State<SqliteRepository>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Ancestry>> {
+    Ok(Json(found::<Ancestry>(&content, id).await?))
+}
+
+async fn replace_ancestry(
+    State(content): State<SqliteRepository>,
+    Path(id): Path<Uuid>,
+    Json(input): Json<NamedInput>,
+) -> ApiResult<Json<Ancestry>> {
+    let ancestry = build_ancestry(NamedInput {
+        id: Some(input.id.unwrap_or(id)),
+        ..input
+    })?;
+    check_id(id, ancestry.id)?;
+    content.save(ancestry.clone()).await?;
+
+    Ok(Json(ancestry))
+}
+
+async fn remove_ancestry(
+    State(content): State<SqliteRepository>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    deleted(Repository::<Ancestry>::delete(&content, id).await?)
+}
+
+/// A Class with the Subclasses that can be taken in it.
+#[derive(Debug, Serialize)]
+struct ClassDetails {
+    #[serde(flatten)]
+    class: Class,
+    subclasses: Vec<Subclass>,
+}
+
+fn build_class(input: SourcedInput) -> ApiResult<Class> {
+    let mut builder = Class::builder();
+    builder.name(input.name)?;
+    if let Some(id) = input.id {
+        builder.id(id);
+    }
+
+    let mut class = builder.build()?;
+    class.source = input.source;
+
+    Ok(class)
+}
+
+async fn list_classes(
+    State(content): State<SqliteRepository>,
+    Query(filter): Query<ContentFilter>,
+) -> ApiResult<Json<Vec<Class>>> {
+    let classes = Catalog::<Class>::list(&content).await?;
+
+    Ok(Json(
+        filter
+            .apply(
+                content.pool(),
+                ContentKind::Class,
+                classes,
+                |c| c.id,
+                Some(|c| c.source),
+            )
+            .await?,
+    ))
+}
+
+async fn create_class(
+    State(content): State<SqliteRepository>,
+    Json(input): Json<SourcedInput>,
+) -> ApiResult<(StatusCode, Json<Class>)> {
+    let class = build_class(input)?;
+    content.save(class.clone()).await?;
+
+    Ok((StatusCode::CREATED, Json(class)))
+}
+
+async fn show_class(
+    State(content): State<SqliteRepository>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ClassDetails>> {
+    let class = found::<Class>(&content, id).await?;
+
+    Ok(Json(ClassDetails {
+        subclasses: class.load_sublasses(content.pool()).await?,
+        class,
+    }))
+}
+
+async fn replace_class(
+    State(content): State<SqliteRepository>,
+    Path(id): Path<Uuid>,
+    Json(input): Json<SourcedInput>,
+) -> ApiResult<Json<Class>> {
+    let class = build_class(SourcedInput {
+        id: Some(input.id.unwrap_or(id)),
+        ..input
+    })?;
+    check_id(id, class.id)?;
+    content.save(class.clone()).await?;
+
+    Ok(Json(class))
+}
+
+async fn remove_class(
+    State(content): State<SqliteRepository>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    deleted(Repository::<Class>::delete(&content, id).await?)
+}
+
+fn build_source(input: NamedInput) -> ApiResult<Source> {
+    let mut builder = Source::builder();
+    builder.name(input.name)?;
+    if let Some(id) = input.id {
+        builder.id(id);
+    }
+
+    Ok(builder.build()?)
+}
+
+async fn list_sources(State(content): State<SqliteRepository>) -> ApiResult<Json<Vec<Source>>> {
+    Ok(Json(Catalog::<Source>::list(&content).await?))
+}
+
+async fn create_source(
+    State(content): State<SqliteRepository>,
+    Json(input): Json<NamedInput>,
+) -> ApiResult<(StatusCode, Json<Source>)> {
+    let source = build_source(input)?;
+    content.save(source.clone()).await?;
+
+    Ok((StatusCode::CREATED, Json(source)))
+}
+
+async fn show_source(
+    State(content): State<SqliteRepository>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Source>> {
+    Ok(Json(found::<Source>(&content, id).await?))
+}
+
+async fn replace_source(
+    State(content): State<SqliteRepository>,
+    Path(id): Path<Uuid>,
+    Json(input): Json<NamedInput>,
+) -> ApiResult<Json<Source>> {
+    let source = build_source(NamedInput {
+        id: Some(input.id.unwrap_or(id)),
+        ..input
+    })?;
+    check_id(id, source.id)?;
+    content.save(source.clone()).await?;
+
+    Ok(Json(source))
+}
+
+async fn remove_source(
+    State(content): State<SqliteRepository>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    deleted(Repository::<Source>::delete(&content, id).await?)
+}
@@ -0,0 +1,130 @@
+use axum::{
+    extract::{Path, State},
+    http::{header, StatusCode},
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::{
+    campaign::Campaign,
+    journal::{ExperienceTable, Journal, Progress, Session},
+};
+
+use super::{check_id, deleted, ApiResult};
+
+pub fn routes() -> Router<SqlitePool> {
+    Router::new()
+        .route("/campaigns/{id}/sessions", get(list).post(create))
+        .route("/campaigns/{id}/journal.md", get(markdown))
+        .route("/sessions/{id}", get(show).put(replace).delete(remove))
+        .route(
+            "/campaigns/{id}/experience",
+            get(experience).put(replace_experience),
+        )
+        .route(
+            "/campaigns/{id}/characters/{character_id}/progress",
+            get(progress),
+        )
+}
+
+async fn list(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Vec<Session>>> {
+    let campaign = Campaign::load(&pool, id).await?;
+
+    Ok(Json(Session::list(&pool, *campaign.id()).await?))
+}
+
+async fn create(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    Json(session): Json<Session>,
+) -> ApiResult<(StatusCode, Json<Session>)> {
+    check_id(id, session.campaign_id)?;
+    let session_id = session.id;
+    session.save(&pool).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(Session::load(&pool, session_id).await?),
+    ))
+}
+
+/// The whole journal as a Markdown document.
+async fn markdown(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<impl IntoResponse> {
+    let journal = Journal::load(&pool, id).await?;
+
+    Ok((
+        [(header::CONTENT_TYPE, "text/markdown; charset=utf-8")],
+        journal.to_markdown(),
+    ))
+}
+
+async fn show(State(pool): State<SqlitePool>, Path(id): Path<Uuid>) -> ApiResult<Json<Session>> {
+    Ok(Json(Session::load(&pool, id).await?))
+}
+
+async fn replace(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    Json(session): Json<Session>,
+) -> ApiResult<Json<Session>> {
+    check_id(id, session.id)?;
+    session.save(&pool).await?;
+
+    Ok(Json(Session::load(&pool, id).await?))
+}
+
+async fn remove(State(pool): State<SqlitePool>, Path(id): Path<Uuid>) -> ApiResult<StatusCode> {
+    deleted(Session::delete(&pool, id).await?)
+}
+
+/// The Experience the Campaign asks for each Level past the first.
+async fn experience(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ExperienceTable>> {
+    let campaign = Campaign::load(&pool, id).await?;
+
+    Ok(Json(ExperienceTable::load(&pool, *campaign.id()).await?))
+}
+
+async fn replace_experience(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    Json(table): Json<ExperienceTable>,
+) -> ApiResult<Json<ExperienceTable>> {
+    let campaign = Campaign::load(&pool, id).await?;
+    table.save(&pool, *campaign.id()).await?;
+
+    Ok(Json(table))
+}
+
+#[derive(Clone, Copy, Debug, Serialize)]
+struct ProgressReport {
+    #[serde(flatten)]
+    progress: Progress,
+    eligible_for_level_up: bool,
+}
+
+/// Whether the Character's Awards in the Campaign have earned them a
+/// `level_up`.
+async fn progress(
+    State(pool): State<SqlitePool>,
+    Path((id, character_id)): Path<(Uuid, Uuid)>,
+) -> ApiResult<Json<ProgressReport>> {
+    let progress = Progress::load(&pool, id, character_id).await?;
+
+    Ok(Json(ProgressReport {
+        progress,
+        eligible_for_level_up: progress.is_eligible_for_level_up(),
+    }))
+}
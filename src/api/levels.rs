@@ -0,0 +1,205 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{get, put},
+    Json, Router,
+};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::{
+    dc20::{Attributes, Character, Level, Maneuver, Spell, SpellList, MAX_LEVEL},
+    Error,
+};
+
+use super::{check_id, deleted, tracked, ApiResult, Author};
+
+pub fn routes() -> Router<SqlitePool> {
+    Router::new()
+        .route("/levels/{id}", get(show).put(replace).delete(remove))
+        .route(
+            "/levels/{id}/attributes",
+            get(attributes).put(replace_attributes),
+        )
+        .route("/levels/{id}/maneuvers", get(maneuvers))
+        .route("/levels/{id}/maneuvers/{maneuver_id}", put(learn_maneuver))
+        .route("/levels/{id}/spell-lists", get(spell_lists))
+        .route(
+            "/levels/{id}/spell-lists/{spell_list_id}",
+            put(choose_spell_list),
+        )
+        .route("/levels/{id}/spells", get(spells))
+        .route("/levels/{id}/spells/{spell_id}", put(learn_spell))
+}
+
+async fn show(State(pool): State<SqlitePool>, Path(id): Path<Uuid>) -> ApiResult<Json<Level>> {
+    Ok(Json(Level::load(&pool, id).await?))
+}
+
+async fn replace(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    author: Author,
+    Json(level): Json<Level>,
+) -> ApiResult<Json<Level>> {
+    check_id(id, level.id)?;
+    let stored = Level::load(&pool, id).await?;
+    if level.character_id != stored.character_id {
+        return Err(Error::Validation(
+            format!(
+                "Level `{id}` belongs to Character `{}`, not `{}`",
+                stored.character_id, level.character_id
+            )
+            .into(),
+        ));
+    }
+    if !(1..=MAX_LEVEL).contains(&level.level) {
+        return Err(Error::RuleViolation(
+            format!("Level {} is not between 1 and {MAX_LEVEL}", level.level).into(),
+        ));
+    }
+    let character = Character::load(&pool, stored.character_id).await?;
+    if (character.load_levels(&pool).await?)
+        .iter()
+        .any(|taken| taken.level == level.level && taken.id != id)
+    {
+        return Err(Error::RuleViolation(
+            format!("The Character already has Level {}", level.level).into(),
+        ));
+    }
+    let note = |_: &()| format!("Replaced Level {}", level.level);
+    tracked(&pool, level.character_id, author, note, async |tx| {
+        level.clone().save(tx).await
+    })
+    .await?;
+
+    Ok(Json(level))
+}
+
+async fn remove(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    author: Author,
+) -> ApiResult<StatusCode> {
+    let level = Level::load(&pool, id).await?;
+    // Level 1 holds the Background, and Levels are taken in order, so only
+    // the last one taken can be given up
+    let character = Character::load(&pool, level.character_id).await?;
+    let highest = character.load_max_level(&pool).await?;
+    if level.level == 1 || level.id != highest.id {
+        return Err(Error::RuleViolation(
+            format!(
+                "Only the highest Level above 1 can be removed, not Level {}",
+                level.level
+            )
+            .into(),
+        ));
+    }
+    let note = |_: &bool| format!("Removed Level {}", level.level);
+    let found = tracked(&pool, level.character_id, author, note, async |tx| {
+        Level::delete(tx, id).await
+    })
+    .await?;
+
+    deleted(found)
+}
+
+async fn attributes(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Attributes>> {
+    let level = Level::load(&pool, id).await?;
+
+    Ok(Json(level.load_base_attributes(&pool).await?))
+}
+
+async fn replace_attributes(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    author: Author,
+    Json(attributes): Json<Attributes>,
+) -> ApiResult<Json<Attributes>> {
+    let level = Level::load(&pool, id).await?;
+    let note = |_: &()| format!("Changed Attributes at Level {}", level.level);
+    tracked(&pool, level.character_id, author, note, async |tx| {
+        level.save_base_attributes(tx, &attributes).await
+    })
+    .await?;
+
+    Ok(Json(attributes))
+}
+
+async fn maneuvers(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Vec<Maneuver>>> {
+    let level = Level::load(&pool, id).await?;
+
+    Ok(Json(level.load_maneuvers(&pool).await?))
+}
+
+async fn learn_maneuver(
+    State(pool): State<SqlitePool>,
+    Path((id, maneuver_id)): Path<(Uuid, Uuid)>,
+    author: Author,
+) -> ApiResult<StatusCode> {
+    let level = Level::load(&pool, id).await?;
+    let maneuver = Maneuver::load(&pool, maneuver_id).await?;
+    let note = |_: &()| format!("Learned {}", maneuver.name);
+    tracked(&pool, level.character_id, author, note, async |tx| {
+        level.save_maneuver(tx, &maneuver).await
+    })
+    .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn spell_lists(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Vec<SpellList>>> {
+    let level = Level::load(&pool, id).await?;
+
+    Ok(Json(level.load_spell_lists(&pool, None).await?))
+}
+
+async fn choose_spell_list(
+    State(pool): State<SqlitePool>,
+    Path((id, spell_list_id)): Path<(Uuid, Uuid)>,
+    author: Author,
+) -> ApiResult<StatusCode> {
+    let level = Level::load(&pool, id).await?;
+    let spell_list = SpellList::load(&pool, spell_list_id).await?;
+    let note = |_: &()| format!("Chose the {} Spell List", spell_list.name);
+    tracked(&pool, level.character_id, author, note, async |tx| {
+        level.save_spell_list(tx, &spell_list).await
+    })
+    .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn spells(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Vec<Spell>>> {
+    let level = Level::load(&pool, id).await?;
+
+    Ok(Json(level.load_spells(&pool).await?))
+}
+
+async fn learn_spell(
+    State(pool): State<SqlitePool>,
+    Path((id, spell_id)): Path<(Uuid, Uuid)>,
+    author: Author,
+) -> ApiResult<StatusCode> {
+    let level = Level::load(&pool, id).await?;
+    let spell = Spell::load(&pool, spell_id).await?;
+    let note = |_: &()| format!("Learned {}", spell.name);
+    tracked(&pool, level.character_id, author, note, async |tx| {
+        level.learn_spell(tx, &spell).await
+    })
+    .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,268 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Path, Query, State,
+    },
+    response::Response,
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tokio::sync::broadcast::{self, error::RecvError};
+use uuid::Uuid;
+
+use crate::dc20::{Tracker, TrackerChange, TrackerUpdate};
+
+use super::ApiResult;
+
+/// How many updates a slow client can fall behind before it is resynced.
+const BACKLOG: usize = 64;
+
+pub fn routes() -> Router<Live> {
+    Router::new()
+        .route("/campaigns/{id}/tracker", get(show).post(change))
+        .route("/campaigns/{id}/live", get(connect))
+}
+
+/// One broadcast channel per Campaign, shared by everyone at its table. A
+/// Campaign's channel is dropped once nobody is left at the table.
+#[derive(Clone, Debug)]
+pub struct Live {
+    pool: SqlitePool,
+    campaigns: Arc<Mutex<HashMap<Uuid, Arc<Table>>>>,
+}
+
+#[derive(Debug)]
+struct Table {
+    updates: broadcast::Sender<TrackerUpdate>,
+    /// Changes are applied one at a time so versions match their order.
+    applying: tokio::sync::Mutex<()>,
+}
+
+impl Live {
+    #[must_use]
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            campaigns: Arc::default(),
+        }
+    }
+
+    fn table(&self, campaign_id: Uuid) -> Arc<Table> {
+        let mut campaigns = self
+            .campaigns
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        campaigns
+            .entry(campaign_id)
+            .or_insert_with(|| {
+                Arc::new(Table {
+                    updates: broadcast::channel(BACKLOG).0,
+                    applying: tokio::sync::Mutex::new(()),
+                })
+            })
+            .clone()
+    }
+
+    /// Drops the Campaign's channel when nobody is subscribed to it or
+    /// applying a change through it.
+    fn release(&self, campaign_id: Uuid) {
+        let mut campaigns = self
+            .campaigns
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if campaigns.get(&campaign_id).is_some_and(|table| {
+            table.updates.receiver_count() == 0 && Arc::strong_count(table) == 1
+        }) {
+            campaigns.remove(&campaign_id);
+        }
+    }
+
+    /// Saves the change and sends the result to every connected client.
+    async fn apply(
+        &self,
+        campaign_id: Uuid,
+        change: TrackerChange,
+    ) -> crate::Result<TrackerUpdate> {
+        let table = self.table(campaign_id);
+        let applied = {
+            let _applying = table.applying.lock().await;
+            Tracker::apply(&self.pool, campaign_id, change).await
+        };
+        if let Ok(update) = &applied {
+            // Nobody listening is fine; they'll sync when they connect
+            let _ = table.updates.send(update.clone());
+        }
+        drop(table);
+        self.release(campaign_id);
+
+        applied
+    }
+}
+
+/// What the server sends down the socket.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type")]
+enum ServerMessage {
+    /// Every Character that changed after the version the client asked
+    /// for, or all of them.
+    Sync(Tracker),
+    Update(TrackerUpdate),
+    Error {
+        message: String,
+    },
+}
+
+/// The last version the client saw, when it has seen one.
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+struct Since {
+    since: Option<u64>,
+}
+
+async fn show(
+    State(live): State<Live>,
+    Path(id): Path<Uuid>,
+    Query(Since { since }): Query<Since>,
+) -> ApiResult<Json<Tracker>> {
+    Ok(Json(
+        Tracker::load_since(&live.pool, id, since.unwrap_or_default()).await?,
+    ))
+}
+
+async fn change(
+    State(live): State<Live>,
+    Path(id): Path<Uuid>,
+    Json(change): Json<TrackerChange>,
+) -> ApiResult<Json<TrackerUpdate>> {
+    Ok(Json(live.apply(id, change).await?))
+}
+
+/// `GET /campaigns/{id}/live?since=12` opens the socket. The client gets a
+/// `Sync` of what it missed, then an `Update` for every change anyone makes.
+/// It makes changes by sending `TrackerChange`s.
+async fn connect(
+    State(live): State<Live>,
+    Path(id): Path<Uuid>,
+    Query(Since { since }): Query<Since>,
+    upgrade: WebSocketUpgrade,
+) -> Response {
+    upgrade.on_upgrade(move |socket| session(socket, live, id, since.unwrap_or_default()))
+}
+
+async fn send(socket: &mut WebSocket, message: &ServerMessage) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(message).map_err(axum::Error::new)?;
+
+    socket.send(Message::Text(text.into())).await
+}
+
+/// Sends what changed after `seen`, returning the version the client is now at.
+async fn sync(
+    socket: &mut WebSocket,
+    pool: &SqlitePool,
+    campaign_id: Uuid,
+    seen: u64,
+) -> Result<u64, axum::Error> {
+    let tracker = match Tracker::load_since(pool, campaign_id, seen).await {
+        // A client ahead of the server, say after a restore, starts over
+        Ok(tracker) if tracker.version < seen => Tracker::load(pool, campaign_id).await,
+        loaded => loaded,
+    };
+    let message = match tracker {
+        Ok(tracker) => ServerMessage::Sync(tracker),
+        Err(e) => ServerMessage::Error {
+            message: e.to_string(),
+        },
+    };
+    send(socket, &message).await?;
+
+    Ok(match message {
+        ServerMessage::Sync(tracker) => tracker.version,
+        _ => seen,
+    })
+}
+
+async fn session(mut socket: WebSocket, live: Live, campaign_id: Uuid, since: u64) {
+    // Subscribing before syncing means nothing can slip between the two
+    let mut updates = live.table(campaign_id).updates.subscribe();
+
+    let Ok(mut seen) = sync(&mut socket, &live.pool, campaign_id, since).await else {
+        return;
+    };
+
+    loop {
+        let sent = tokio::select! {
+            message = socket.recv() => match message {
+                Some(Ok(Message::Text(text))) => {
+                    let error = match serde_json::from_str::<TrackerChange>(text.as_str()) {
+                        // The update comes back through the broadcast
+                        Ok(change) => live.apply(campaign_id, change).await.err(),
+                        Err(e) => Some(crate::Error::Validation(e.into())),
+                    };
+
+                    match error {
+                        Some(e) => {
+                            send(&mut socket, &ServerMessage::Error { message: e.to_string() })
+                                .await
+                        }
+                        None => Ok(()),
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => Ok(()),
+            },
+            update = updates.recv() => match update {
+                Ok(update) if update.version <= seen => Ok(()),
+                Ok(update) => {
+                    seen = update.version;
+                    send(&mut socket, &ServerMessage::Update(update)).await
+                }
+                Err(RecvError::Lagged(_)) => match sync(&mut socket, &live.pool, campaign_id, seen).await {
+                    Ok(version) => {
+                        seen = version;
+                        Ok(())
+                    }
+                    Err(e) => Err(e),
+                },
+                Err(RecvError::Closed) => break,
+            },
+        };
+
+        if sent.is_err() {
+            break;
+        }
+    }
+
+    drop(updates);
+    live.release(campaign_id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn _a_campaign_is_forgotten_once_its_table_is_empty() {
+        let live = Live::new(SqlitePool::connect_lazy("sqlite::memory:").unwrap());
+        let campaign_id = Uuid::now_v7();
+
+        let gm = live.table(campaign_id).updates.subscribe();
+        let player = live.table(campaign_id).updates.subscribe();
+        assert_eq!(live.campaigns.lock().unwrap().len(), 1);
+
+        drop(gm);
+        live.release(campaign_id);
+        assert_eq!(live.campaigns.lock().unwrap().len(), 1);
+
+        drop(player);
+        live.release(campaign_id);
+        assert!(live.campaigns.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,161 @@
+//! A JSON API over the `dc20` domain, for clients that can't link the crate.
+
+use axum::{
+    extract::{FromRef, FromRequestParts},
+    http::{request::Parts, StatusCode},
+    response::{IntoResponse, Response},
+    Json, Router,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    history,
+    repository::{Entity, Repository, SqliteRepository},
+    Error,
+};
+
+mod campaigns;
+mod characters;
+mod content;
+mod journal;
+mod levels;
+mod live;
+mod packs;
+mod players;
+mod spells;
+
+/// Every route, sharing the one pool.
+pub fn router(pool: sqlx::SqlitePool) -> Router {
+    let live = live::routes().with_state(live::Live::new(pool.clone()));
+
+    Router::new()
+        .merge(players::routes())
+        .merge(characters::routes())
+        .merge(campaigns::routes())
+        .merge(journal::routes())
+        .merge(levels::routes())
+        .merge(content::routes())
+        .merge(spells::routes())
+        .merge(packs::routes())
+        .with_state(pool)
+        .merge(live)
+}
+
+/// Every error is sent back as `{ "error": "..." }`, with the status for its kind.
+/// Database failures, and the constraints behind refused input, are only
+/// described in the server's log, so clients never see the database's own
+/// messages.
+impl IntoResponse for Error {
+    fn into_response(self) -> Response {
+        if let Some(cause) = std::error::Error::source(&self).and_then(|error| error.source()) {
+            eprintln!("{self}: {cause}");
+        }
+
+        let (status, message) = match self {
+            Error::NotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
+            Error::Validation(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+            Error::RuleViolation(_) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()),
+            Error::ContentReference(_) => (StatusCode::CONFLICT, self.to_string()),
+            Error::Storage(error) => {
+                eprintln!("Storage error: {error}");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Something went wrong with the database".into(),
+                )
+            }
+        };
+
+        (status, Json(serde_json::json!({ "error": message }))).into_response()
+    }
+}
+
+type ApiResult<T> = crate::Result<T>;
+
+/// Handlers that only load and save whole records take the repository
+/// rather than the pool.
+impl FromRef<sqlx::SqlitePool> for SqliteRepository {
+    fn from_ref(pool: &sqlx::SqlitePool) -> Self {
+        SqliteRepository::new(pool.clone())
+    }
+}
+
+/// Loads the record with `id`, or `404` when there isn't one, answered the
+/// same as a missing row.
+async fn found<T: Entity>(repository: &impl Repository<T>, id: Uuid) -> ApiResult<T> {
+    repository
+        .load(id)
+        .await?
+        .ok_or_else(|| Error::NotFound("Not found".into()))
+}
+
+/// The body accepted when creating or replacing anything that is just a name.
+#[derive(Clone, Debug, Deserialize)]
+struct NamedInput {
+    id: Option<Uuid>,
+    name: String,
+}
+
+/// Fails validation when the id in the path and in the body disagree.
+fn check_id(path: Uuid, body: Uuid) -> ApiResult<()> {
+    if path != body {
+        return Err(Error::Validation(
+            format!("id in body `{body}` does not match `{path}`").into(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// `204 No Content` when something was deleted, `404` when there was nothing to delete.
+fn deleted(found: bool) -> ApiResult<StatusCode> {
+    if found {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(Error::NotFound("Not found".into()))
+    }
+}
+
+/// The Player making a change, from the `X-Player-Id` header, for the
+/// Character's history. Changes without the header are still recorded.
+#[derive(Clone, Copy, Debug, Default)]
+struct Author(Option<Uuid>);
+
+impl<S: Send + Sync> FromRequestParts<S> for Author {
+    type Rejection = Error;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let Some(header) = parts.headers.get("x-player-id") else {
+            return Ok(Author(None));
+        };
+
+        header
+            .to_str()
+            .ok()
+            .and_then(|id| id.parse().ok())
+            .map(|id| Author(Some(id)))
+            .ok_or_else(|| Error::Validation("`X-Player-Id` is not a Player id".into()))
+    }
+}
+
+/// Runs `change` against the Character, recording it in their history as
+/// made by `author`. Anything changed outside the history since their last
+/// Revision is recorded first so the change has a before. All three share
+/// one transaction, so a change is never kept without its Revision.
+async fn tracked<T>(
+    pool: &sqlx::SqlitePool,
+    character_id: Uuid,
+    Author(author): Author,
+    note: impl Fn(&T) -> String,
+    change: impl AsyncFnOnce(&mut sqlx::SqliteConnection) -> ApiResult<T>,
+) -> ApiResult<T> {
+    let mut tx = pool.begin().await?;
+
+    history::checkpoint(&mut *tx, character_id).await?;
+    let changed = change(&mut tx).await?;
+    history::record(&mut *tx, character_id, author, &note(&changed)).await?;
+
+    tx.commit().await?;
+
+    Ok(changed)
+}
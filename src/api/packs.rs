@@ -0,0 +1,44 @@
+//! Content packs, installed from the JSON form of a pack rather than files.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    routing::get,
+    Json, Router,
+};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::pack::{ContentPack, Installed, Manifest};
+
+use super::{deleted, ApiResult};
+
+pub fn routes() -> Router<SqlitePool> {
+    Router::new()
+        .route("/packs", get(list).post(install))
+        .route("/packs/{id}", get(show).delete(uninstall))
+}
+
+async fn list(State(pool): State<SqlitePool>) -> ApiResult<Json<Vec<Manifest>>> {
+    Ok(Json(Manifest::list(&pool).await?))
+}
+
+/// `POST /packs` with `{ "manifest": { … }, "content": { … } }`. Posting a
+/// newer version of an installed pack updates it in place.
+async fn install(
+    State(pool): State<SqlitePool>,
+    Json(pack): Json<ContentPack>,
+) -> ApiResult<Json<Installed>> {
+    Ok(Json(pack.install(&pool).await?))
+}
+
+async fn show(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ContentPack>> {
+    Ok(Json(ContentPack::load(&pool, id).await?))
+}
+
+async fn uninstall(State(pool): State<SqlitePool>, Path(id): Path<Uuid>) -> ApiResult<StatusCode> {
+    deleted(ContentPack::uninstall(&pool, id).await?)
+}
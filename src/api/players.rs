@@ -0,0 +1,74 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    routing::get,
+    Json, Router,
+};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::{
+    player::Player,
+    repository::{Catalog, Repository, SqliteRepository},
+};
+
+use super::{check_id, deleted, found, ApiResult, NamedInput};
+
+pub fn routes() -> Router<SqlitePool> {
+    Router::new()
+        .route("/players", get(list).post(create))
+        .route("/players/{id}", get(show).put(replace).delete(remove))
+}
+
+fn build(input: NamedInput) -> ApiResult<Player> {
+    let mut builder = Player::builder();
+    builder.name(input.name)?;
+    if let Some(id) = input.id {
+        builder.id(id);
+    }
+
+    Ok(builder.build()?)
+}
+
+async fn list(State(players): State<SqliteRepository>) -> ApiResult<Json<Vec<Player>>> {
+    Ok(Json(Catalog::<Player>::list(&players).await?))
+}
+
+async fn create(
+    State(players): State<SqliteRepository>,
+    Json(input): Json<NamedInput>,
+) -> ApiResult<(StatusCode, Json<Player>)> {
+    let player = build(input)?;
+    players.save(player.clone()).await?;
+
+    Ok((StatusCode::CREATED, Json(player)))
+}
+
+async fn show(
+    State(players): State<SqliteRepository>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Player>> {
+    Ok(Json(found(&players, id).await?))
+}
+
+async fn replace(
+    State(players): State<SqliteRepository>,
+    Path(id): Path<Uuid>,
+    Json(input): Json<NamedInput>,
+) -> ApiResult<Json<Player>> {
+    let player = build(NamedInput {
+        id: Some(input.id.unwrap_or(id)),
+        ..input
+    })?;
+    check_id(id, *player.id())?;
+    players.save(player.clone()).await?;
+
+    Ok(Json(player))
+}
+
+async fn remove(
+    State(players): State<SqliteRepository>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    deleted(Repository::<Player>::delete(&players, id).await?)
+}
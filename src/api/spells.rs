@@ -0,0 +1,197 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::get,
+    Json, Router,
+};
+use serde::Deserialize;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::dc20::{Duration, PointEnhancement, Range, Spell, SpellList, SpellSchool, SpellSearch};
+
+use super::{check_id, deleted, ApiResult};
+
+pub fn routes() -> Router<SqlitePool> {
+    Router::new()
+        .route("/spells", get(search).post(create))
+        .route("/spells/{id}", get(show).put(replace).delete(remove))
+        .route(
+            "/spell-lists",
+            get(list_spell_lists).post(create_spell_list),
+        )
+        .route(
+            "/spell-lists/{id}",
+            get(show_spell_list)
+                .put(replace_spell_list)
+                .delete(remove_spell_list),
+        )
+}
+
+/// `GET /spells?name=bolt&school=Destruction&max_mana_cost=1&campaign=<id>`
+async fn search(
+    State(pool): State<SqlitePool>,
+    Query(search): Query<SpellSearch>,
+) -> ApiResult<Json<Vec<Spell>>> {
+    Ok(Json(Spell::search(&pool, &search, None).await?))
+}
+
+/// Spells are written with the name of their School, which must already
+/// exist.
+#[derive(Clone, Debug, Deserialize)]
+struct SpellInput {
+    id: Option<Uuid>,
+    name: String,
+    school: String,
+    #[serde(default)]
+    has_verbal: bool,
+    #[serde(default)]
+    has_somatic: bool,
+    #[serde(default)]
+    has_material: bool,
+    action_point_cost: u64,
+    mana_point_cost: u64,
+    #[serde(default)]
+    range: Range,
+    #[serde(default)]
+    duration: Duration,
+    #[serde(default)]
+    sustained: bool,
+    description: String,
+    #[serde(default)]
+    point_enhancements: Vec<PointEnhancement>,
+    #[serde(default)]
+    source: Option<Uuid>,
+}
+
+async fn build_spell(pool: &SqlitePool, input: SpellInput) -> ApiResult<Spell> {
+    let mut builder = Spell::builder();
+    builder
+        .name(input.name)
+        .school(SpellSchool::load_by_name(pool, &input.school).await?)
+        .has_verbal(input.has_verbal)
+        .has_somatic(input.has_somatic)
+        .has_material(input.has_material)
+        .action_point_cost(input.action_point_cost)
+        .mana_point_cost(input.mana_point_cost)
+        .range(input.range)
+        .duration(input.duration)
+        .sustained(input.sustained)
+        .description(input.description)
+        .point_enhancements(input.point_enhancements);
+    if let Some(id) = input.id {
+        builder.id(id);
+    }
+
+    Ok(Spell {
+        source: input.source,
+        ..builder.build()?
+    })
+}
+
+async fn create(
+    State(pool): State<SqlitePool>,
+    Json(input): Json<SpellInput>,
+) -> ApiResult<(StatusCode, Json<Spell>)> {
+    let spell = build_spell(&pool, input).await?;
+    let id = spell.id;
+    spell.save(&pool).await?;
+
+    Ok((StatusCode::CREATED, Json(Spell::load(&pool, id).await?)))
+}
+
+async fn show(State(pool): State<SqlitePool>, Path(id): Path<Uuid>) -> ApiResult<Json<Spell>> {
+    Ok(Json(Spell::load(&pool, id).await?))
+}
+
+async fn replace(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    Json(input): Json<SpellInput>,
+) -> ApiResult<Json<Spell>> {
+    let spell = build_spell(
+        &pool,
+        SpellInput {
+            id: Some(input.id.unwrap_or(id)),
+            ..input
+        },
+    )
+    .await?;
+    check_id(id, spell.id)?;
+    spell.save(&pool).await?;
+
+    Ok(Json(Spell::load(&pool, id).await?))
+}
+
+async fn remove(State(pool): State<SqlitePool>, Path(id): Path<Uuid>) -> ApiResult<StatusCode> {
+    deleted(Spell::delete(&pool, id).await?)
+}
+
+/// Spell Lists are written with just the ids of their Spells.
+#[derive(Clone, Debug, Deserialize)]
+struct SpellListInput {
+    id: Option<Uuid>,
+    name: String,
+    #[serde(default)]
+    spell_ids: Vec<Uuid>,
+}
+
+async fn build_spell_list(pool: &SqlitePool, input: SpellListInput) -> ApiResult<SpellList> {
+    let mut spells = vec![];
+    for id in input.spell_ids {
+        spells.push(Spell::load(pool, id).await?);
+    }
+
+    Ok(SpellList {
+        id: input.id.unwrap_or_else(Uuid::now_v7),
+        name: input.name,
+        spells,
+    })
+}
+
+async fn list_spell_lists(State(pool): State<SqlitePool>) -> ApiResult<Json<Vec<SpellList>>> {
+    Ok(Json(SpellList::list(&pool).await?))
+}
+
+async fn create_spell_list(
+    State(pool): State<SqlitePool>,
+    Json(input): Json<SpellListInput>,
+) -> ApiResult<(StatusCode, Json<SpellList>)> {
+    let spell_list = build_spell_list(&pool, input).await?;
+    spell_list.clone().save(&pool).await?;
+
+    Ok((StatusCode::CREATED, Json(spell_list)))
+}
+
+async fn show_spell_list(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<SpellList>> {
+    Ok(Json(SpellList::load(&pool, id).await?))
+}
+
+async fn replace_spell_list(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    Json(input): Json<SpellListInput>,
+) -> ApiResult<Json<SpellList>> {
+    let spell_list = build_spell_list(
+        &pool,
+        SpellListInput {
+            id: Some(input.id.unwrap_or(id)),
+            ..input
+        },
+    )
+    .await?;
+    check_id(id, spell_list.id)?;
+    spell_list.clone().save(&pool).await?;
+
+    Ok(Json(spell_list))
+}
+
+async fn remove_spell_list(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    deleted(SpellList::delete(&pool, id).await?)
+}
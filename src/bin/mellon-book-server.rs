@@ -0,0 +1,31 @@
+use clap::Parser;
+use mellon_book::db::OpenOptions;
+
+/// Serves the DC20 rules and characters as a JSON API.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Args {
+    /// SQLite database to serve. It is created, with the core rules, if it
+    /// doesn't exist yet.
+    #[arg(long, env = "DATABASE_URL", default_value = "sqlite://db.sqlite")]
+    database: String,
+    /// Address to listen on.
+    #[arg(long, default_value = "127.0.0.1:3000")]
+    bind: String,
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    let pool = OpenOptions::new()
+        .core_content(true)
+        .open_url(&args.database)
+        .await?;
+    let listener = tokio::net::TcpListener::bind(&args.bind).await?;
+
+    println!("Listening on http://{}", listener.local_addr()?);
+    axum::serve(listener, mellon_book::api::router(pool)).await?;
+
+    Ok(())
+}
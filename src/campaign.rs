@@ -0,0 +1,737 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use turann::Builder;
+use uuid::Uuid;
+
+use crate::{
+    db::Db,
+    dc20::{Character, Language, Level, Skill, Source, SourceFilter},
+    journal::Progress,
+    pack::Manifest,
+    player::Player,
+};
+
+impl CampaignBuilder {
+    fn validate_name(name: String) -> Result<String, CampaignBuilderError> {
+        if name.is_empty() {
+            return Err(CampaignBuilderError::InvalidField {
+                field_name: "name".into(),
+                message: "cannot be empty".into(),
+            });
+        }
+
+        Ok(name)
+    }
+}
+
+/// A game run by one Player, the Game Master, for the Characters taking part.
+#[derive(Builder, Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "CampaignFields")]
+pub struct Campaign {
+    #[builder(default = Uuid::new_v4)]
+    id: Uuid,
+    #[builder(validate = Self::validate_name)]
+    name: String,
+    game_master: Player,
+}
+
+/// A [`Campaign`] as it comes in, built up through its builder so that it is
+/// checked the same way.
+#[derive(Deserialize)]
+struct CampaignFields {
+    id: Option<Uuid>,
+    name: String,
+    game_master: Player,
+}
+
+impl TryFrom<CampaignFields> for Campaign {
+    type Error = CampaignBuilderError;
+
+    fn try_from(fields: CampaignFields) -> Result<Self, Self::Error> {
+        let mut builder = Campaign::builder();
+        builder.name(fields.name)?.game_master(fields.game_master);
+        if let Some(id) = fields.id {
+            builder.id(id);
+        }
+
+        builder.build()
+    }
+}
+
+impl Campaign {
+    pub async fn load(db: impl Db<'_>, id: Uuid) -> crate::Result<Campaign> {
+        let mut conn = db.connection().await?;
+
+        let row = sqlx::query!(
+            r#"
+                SELECT `campaign_id` AS "id: Uuid"
+                    , `name`
+                    , `game_master_id` AS "game_master_id: Uuid"
+                FROM `campaigns`
+                WHERE `campaign_id` = ?1
+                LIMIT 1
+                ;
+            "#,
+            id
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Ok(Campaign {
+            id: row.id,
+            name: row.name,
+            game_master: Player::load(&mut *conn, row.game_master_id).await?,
+        })
+    }
+
+    pub async fn list(db: impl Db<'_>) -> crate::Result<Vec<Campaign>> {
+        let mut conn = db.connection().await?;
+
+        let ids = sqlx::query_scalar!(
+            r#"
+                SELECT `campaign_id` AS "id: Uuid"
+                FROM `campaigns`
+                ORDER BY `name`
+                ;
+            "#
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut campaigns = vec![];
+        for id in ids {
+            campaigns.push(Campaign::load(&mut *conn, id).await?);
+        }
+
+        Ok(campaigns)
+    }
+
+    /// Saves the Campaign. Its Game Master must already be saved.
+    pub async fn save(self, executor: impl sqlx::SqliteExecutor<'_>) -> crate::Result<()> {
+        let Campaign {
+            id,
+            name,
+            game_master,
+        } = self;
+        let game_master_id = *game_master.id();
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `campaigns` (`campaign_id`, `name`, `game_master_id`)
+                VALUES (?1, ?2, ?3)
+                ON CONFLICT (`campaign_id`) DO UPDATE
+                    SET `name` = ?2
+                    , `game_master_id` = ?3
+                ;
+            "#,
+            id,
+            name,
+            game_master_id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(executor: impl sqlx::SqliteExecutor<'_>, id: Uuid) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `campaigns`
+                WHERE `campaign_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Adds the Character, marking the Level any Awards they were already
+    /// given in the Campaign have earned them.
+    pub async fn add_character(&self, db: impl Db<'_>, character_id: Uuid) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+
+        let mut tx = conn.begin().await?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `campaigns_characters` (`campaign_id`, `character_id`)
+                VALUES (?1, ?2)
+                ON CONFLICT DO NOTHING
+                ;
+            "#,
+            self.id,
+            character_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        Progress::mark_earned_levels(&mut *tx, self.id).await?;
+
+        Ok(tx.commit().await?)
+    }
+
+    /// Takes the Character out of the Campaign and any of its Parties.
+    pub async fn remove_character(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+        character_id: Uuid,
+    ) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `campaigns_characters`
+                WHERE `campaign_id` = ?1
+                    AND `character_id` = ?2
+                ;
+            "#,
+            self.id,
+            character_id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Makes the content pack's content available in the Campaign.
+    pub async fn enable_content_pack(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+        content_pack_id: Uuid,
+    ) -> crate::Result<()> {
+        sqlx::query!(
+            r#"
+                INSERT INTO `campaigns_content_packs` (`campaign_id`, `content_pack_id`)
+                VALUES (?1, ?2)
+                ON CONFLICT DO NOTHING
+                ;
+            "#,
+            self.id,
+            content_pack_id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn disable_content_pack(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+        content_pack_id: Uuid,
+    ) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `campaigns_content_packs`
+                WHERE `campaign_id` = ?1
+                    AND `content_pack_id` = ?2
+                ;
+            "#,
+            self.id,
+            content_pack_id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn load_content_packs(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Vec<Manifest>> {
+        Ok(sqlx::query_as!(
+            Manifest,
+            r#"
+                SELECT p.`content_pack_id` AS "id: Uuid"
+                    , p.`name`
+                    , p.`version`
+                    , p.`author`
+                    , p.`description`
+                FROM `content_packs` AS p
+                JOIN `campaigns_content_packs` AS c_p
+                    USING (`content_pack_id`)
+                WHERE c_p.`campaign_id` = ?1
+                ORDER BY p.`name`
+                ;
+            "#,
+            self.id
+        )
+        .fetch_all(executor)
+        .await?)
+    }
+
+    /// Allows content from the Source in the Campaign. Once any Source is
+    /// allowed, content from every other Source is left out.
+    pub async fn allow_source(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+        source_id: Uuid,
+    ) -> crate::Result<()> {
+        sqlx::query!(
+            r#"
+                INSERT INTO `campaigns_sources` (`campaign_id`, `source_id`)
+                VALUES (?1, ?2)
+                ON CONFLICT DO NOTHING
+                ;
+            "#,
+            self.id,
+            source_id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn disallow_source(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+        source_id: Uuid,
+    ) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `campaigns_sources`
+                WHERE `campaign_id` = ?1
+                    AND `source_id` = ?2
+                ;
+            "#,
+            self.id,
+            source_id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// The Sources the Campaign allows. Empty when it allows every Source.
+    pub async fn load_sources(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Vec<Source>> {
+        Ok(sqlx::query_as!(
+            Source,
+            r#"
+                SELECT s.`source_id` AS "id: Uuid"
+                    , s.`name`
+                FROM `sources` AS s
+                JOIN `campaigns_sources` AS c_s
+                    USING (`source_id`)
+                WHERE c_s.`campaign_id` = ?1
+                ORDER BY s.`name`
+                ;
+            "#,
+            self.id
+        )
+        .fetch_all(executor)
+        .await?)
+    }
+
+    /// A filter for loading only the content the Campaign allows, or `None`
+    /// when it allows every Source.
+    pub async fn load_source_filter(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Option<SourceFilter>> {
+        let sources = self.load_sources(executor).await?;
+
+        Ok((!sources.is_empty()).then(|| SourceFilter::new(sources.iter().map(|s| s.id))))
+    }
+
+    pub async fn load_characters(&self, db: impl Db<'_>) -> crate::Result<Vec<Character>> {
+        let mut conn = db.connection().await?;
+
+        let ids = sqlx::query_scalar!(
+            r#"
+                SELECT `cc`.`character_id` AS "id: Uuid"
+                FROM `campaigns_characters` AS `cc`
+                JOIN `characters` AS `c`
+                    ON `c`.`character_id` = `cc`.`character_id`
+                WHERE `cc`.`campaign_id` = ?1
+                ORDER BY `c`.`name`
+                ;
+            "#,
+            self.id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut characters = vec![];
+        for id in ids {
+            characters.push(Character::load(&mut *conn, id).await?);
+        }
+
+        Ok(characters)
+    }
+
+    /// Every Character in the Campaign alongside the highest Level they've reached.
+    pub async fn load_characters_at_max_level(
+        &self,
+        db: impl Db<'_>,
+    ) -> crate::Result<Vec<(Character, Level)>> {
+        let mut conn = db.connection().await?;
+
+        let mut characters = vec![];
+        for character in self.load_characters(&mut *conn).await? {
+            let level = character.load_max_level(&mut *conn).await?;
+            characters.push((character, level));
+        }
+
+        Ok(characters)
+    }
+
+    pub async fn load_parties(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Vec<Party>> {
+        Party::list(executor, self.id).await
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn game_master(&self) -> &Player {
+        &self.game_master
+    }
+}
+
+impl PartyBuilder {
+    fn validate_name(name: String) -> Result<String, PartyBuilderError> {
+        if name.is_empty() {
+            return Err(PartyBuilderError::InvalidField {
+                field_name: "name".into(),
+                message: "cannot be empty".into(),
+            });
+        }
+
+        Ok(name)
+    }
+}
+
+/// A group of a Campaign's Characters adventuring together.
+#[derive(Builder, Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "PartyFields")]
+pub struct Party {
+    #[builder(default = Uuid::new_v4)]
+    pub id: Uuid,
+    pub campaign_id: Uuid,
+    #[builder(validate = Self::validate_name)]
+    pub name: String,
+}
+
+/// A [`Party`] as it comes in, built up through its builder so that it is
+/// checked the same way.
+#[derive(Deserialize)]
+struct PartyFields {
+    id: Option<Uuid>,
+    campaign_id: Uuid,
+    name: String,
+}
+
+impl TryFrom<PartyFields> for Party {
+    type Error = PartyBuilderError;
+
+    fn try_from(fields: PartyFields) -> Result<Self, Self::Error> {
+        let mut builder = Party::builder();
+        builder.campaign_id(fields.campaign_id).name(fields.name)?;
+        if let Some(id) = fields.id {
+            builder.id(id);
+        }
+
+        builder.build()
+    }
+}
+
+impl Party {
+    pub async fn load(executor: impl sqlx::SqliteExecutor<'_>, id: Uuid) -> crate::Result<Party> {
+        Ok(sqlx::query_as!(
+            Party,
+            r#"
+                SELECT `party_id` AS "id: Uuid"
+                    , `campaign_id` AS "campaign_id: Uuid"
+                    , `name`
+                FROM `parties`
+                WHERE `party_id` = ?1
+                LIMIT 1
+                ;
+            "#,
+            id
+        )
+        .fetch_one(executor)
+        .await?)
+    }
+
+    pub async fn list(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        campaign_id: Uuid,
+    ) -> crate::Result<Vec<Party>> {
+        Ok(sqlx::query_as!(
+            Party,
+            r#"
+                SELECT `party_id` AS "id: Uuid"
+                    , `campaign_id` AS "campaign_id: Uuid"
+                    , `name`
+                FROM `parties`
+                WHERE `campaign_id` = ?1
+                ORDER BY `name`
+                ;
+            "#,
+            campaign_id
+        )
+        .fetch_all(executor)
+        .await?)
+    }
+
+    pub async fn save(self, executor: impl sqlx::SqliteExecutor<'_>) -> crate::Result<()> {
+        let Party {
+            id,
+            campaign_id,
+            name,
+        } = self;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `parties` (`party_id`, `campaign_id`, `name`)
+                VALUES (?1, ?2, ?3)
+                ON CONFLICT (`party_id`) DO UPDATE
+                    SET `campaign_id` = ?2
+                    , `name` = ?3
+                ;
+            "#,
+            id,
+            campaign_id,
+            name
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(executor: impl sqlx::SqliteExecutor<'_>, id: Uuid) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `parties`
+                WHERE `party_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Adds the Character to the Party. They must already be in its Campaign.
+    pub async fn add_character(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+        character_id: Uuid,
+    ) -> crate::Result<()> {
+        sqlx::query!(
+            r#"
+                INSERT INTO `parties_characters` (`party_id`, `campaign_id`, `character_id`)
+                VALUES (?1, ?2, ?3)
+                ON CONFLICT DO NOTHING
+                ;
+            "#,
+            self.id,
+            self.campaign_id,
+            character_id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_character(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+        character_id: Uuid,
+    ) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `parties_characters`
+                WHERE `party_id` = ?1
+                    AND `character_id` = ?2
+                ;
+            "#,
+            self.id,
+            character_id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Each Character in the Party at their highest Level, with what their
+    /// Background gives them.
+    pub async fn load_members(&self, db: impl Db<'_>) -> crate::Result<Vec<PartyMember>> {
+        let mut conn = db.connection().await?;
+
+        let ids = sqlx::query_scalar!(
+            r#"
+                SELECT `pc`.`character_id` AS "id: Uuid"
+                FROM `parties_characters` AS `pc`
+                JOIN `characters` AS `c`
+                    ON `c`.`character_id` = `pc`.`character_id`
+                WHERE `pc`.`party_id` = ?1
+                ORDER BY `c`.`name`
+                ;
+            "#,
+            self.id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut members = vec![];
+        for id in ids {
+            let character = Character::load(&mut *conn, id).await?;
+
+            members.push(PartyMember {
+                level: character.load_max_level(&mut *conn).await?,
+                languages: character.background().load_languages(&mut *conn).await?,
+                skills: character.background().load_skills(&mut *conn).await?,
+                character,
+            });
+        }
+
+        Ok(members)
+    }
+
+    pub async fn summarize(&self, db: impl Db<'_>) -> crate::Result<PartySummary> {
+        Ok(PartySummary::new(&self.load_members(db).await?))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PartyMember {
+    pub character: Character,
+    pub level: Level,
+    pub languages: Vec<Language>,
+    pub skills: Vec<Skill>,
+}
+
+/// What the Party can manage between them.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct PartySummary {
+    pub members: usize,
+    pub average_level: f64,
+    /// Every Language at least one member speaks, by name.
+    pub languages: Vec<Language>,
+    /// Every Skill at least one member has, by name.
+    pub skills: Vec<Skill>,
+}
+
+impl PartySummary {
+    #[must_use]
+    pub fn new(members: &[PartyMember]) -> Self {
+        if members.is_empty() {
+            return Self::default();
+        }
+
+        let total_levels: u32 = members.iter().map(|member| member.level.level).sum();
+
+        let mut seen = HashSet::new();
+        let mut languages: Vec<Language> = members
+            .iter()
+            .flat_map(|member| member.languages.iter())
+            .filter(|language| seen.insert(language.id))
+            .cloned()
+            .collect();
+        languages.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut seen = HashSet::new();
+        let mut skills: Vec<Skill> = members
+            .iter()
+            .flat_map(|member| member.skills.iter())
+            .filter(|skill| seen.insert(skill.id))
+            .cloned()
+            .collect();
+        skills.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self {
+            members: members.len(),
+            average_level: f64::from(total_levels) / members.len() as f64,
+            languages,
+            skills,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dc20::Background;
+
+    use super::*;
+
+    fn member(name: &str, level: u32, languages: &[&Language], skills: &[&Skill]) -> PartyMember {
+        let character = Character::builder()
+            .player(
+                Player::builder()
+                    .name("Spencer Dent")
+                    .unwrap()
+                    .build()
+                    .unwrap(),
+            )
+            .character_name(name)
+            .background(
+                Background::builder()
+                    .name("Human Mercenary")
+                    .unwrap()
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        PartyMember {
+            level: Level::builder().level(level).build().unwrap(),
+            character,
+            languages: languages.iter().map(|&language| language.clone()).collect(),
+            skills: skills.iter().map(|&skill| skill.clone()).collect(),
+        }
+    }
+
+    #[test]
+    fn _party_summary_covers_every_members_languages_and_skills_once() {
+        let common = Language::builder().name("Common").unwrap().build().unwrap();
+        let elvish = Language::builder().name("Elvish").unwrap().build().unwrap();
+        let skill = |name: &str| {
+            Skill::builder()
+                .name(name)
+                .attribute_id(Uuid::now_v7())
+                .build()
+                .unwrap()
+        };
+        let (athletics, insight) = (skill("Athletics"), skill("Insight"));
+
+        let summary = PartySummary::new(&[
+            member("Cygnus", 3, &[&elvish, &common], &[&insight]),
+            member("Vega", 2, &[&common], &[&athletics, &insight]),
+        ]);
+
+        assert_eq!(summary.members, 2);
+        assert_eq!(summary.average_level, 2.5);
+        assert_eq!(summary.languages, vec![common, elvish]);
+        assert_eq!(summary.skills, vec![athletics, insight]);
+    }
+
+    #[test]
+    fn _empty_party_has_nothing_covered() {
+        assert_eq!(PartySummary::new(&[]), PartySummary::default());
+    }
+}
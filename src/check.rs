@@ -0,0 +1,484 @@
+//! Looks through a database for rows the schema lets through but the rest of
+//! the crate can't make sense of, and puts right those it safely can.
+//!
+//! Older databases were written before foreign keys were enforced, and
+//! before `fix_character_level_base_attribute_values_foreign_key` and
+//! `fix_character_level_character_id_not_null_constraint`, so they may hold
+//! rows pointing at nothing or Levels with no Attributes. [`check`] reports
+//! every [`Problem`] it finds; [`repair`] fixes the ones that have only one
+//! right answer and leaves the rest to be looked at by hand.
+
+use std::{collections::BTreeMap, fmt};
+
+use serde::{Deserialize, Serialize};
+use sqlx::SqliteConnection;
+use uuid::Uuid;
+
+use crate::{db::Db, dc20::Duration};
+
+/// The Attributes `character_level_attributes` needs a value of before it
+/// has a row for a Level.
+const BASE_ATTRIBUTES: [&str; 5] = ["Prime", "Might", "Agility", "Charisma", "Intelligence"];
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "problem", rename_all = "snake_case")]
+pub enum Problem {
+    /// Rows of `table` whose `column` names a row of `parent` that isn't
+    /// there.
+    Orphans {
+        table: String,
+        column: String,
+        parent: String,
+        rows: u64,
+        /// What the foreign key does when the parent is deleted, and so what
+        /// repairing the rows does to them.
+        on_delete: String,
+    },
+    /// A Level without a value for every base Attribute, which
+    /// `character_level_attributes` leaves out.
+    MissingAttributes {
+        level_id: Uuid,
+        character_id: Uuid,
+        level: u32,
+        missing: Vec<String>,
+    },
+    /// A Spell whose duration [`Duration`] can't read.
+    UnreadableDuration {
+        spell_id: Uuid,
+        spell: String,
+        kind: String,
+        value: Option<i64>,
+    },
+    /// A Class with no Subclass to pick at level 3.
+    NoSubclasses { class_id: Uuid, class: String },
+    /// A Character whose Levels don't run from 1 up to their highest.
+    LevelGap {
+        character_id: Uuid,
+        character: String,
+        missing: Vec<u32>,
+    },
+}
+
+impl Problem {
+    /// Whether [`repair`] tries to put the Problem right.
+    ///
+    /// Orphaned rows get what their foreign key would have done to them had
+    /// it been enforced, and Levels missing Attributes get the values the
+    /// Character had at their previous Level. Anything else needs someone to
+    /// decide what was meant.
+    #[must_use]
+    pub fn can_repair(&self) -> bool {
+        match self {
+            Problem::Orphans { on_delete, .. } => {
+                matches!(on_delete.as_str(), "CASCADE" | "SET NULL" | "SET DEFAULT")
+            }
+            Problem::MissingAttributes { level, .. } => *level > 1,
+            Problem::UnreadableDuration { .. }
+            | Problem::NoSubclasses { .. }
+            | Problem::LevelGap { .. } => false,
+        }
+    }
+
+    async fn repair(&self, conn: &mut SqliteConnection) -> crate::Result<()> {
+        match self {
+            Problem::Orphans {
+                table,
+                column,
+                parent,
+                on_delete,
+                ..
+            } => {
+                let to = parent_column(&mut *conn, table, column).await?;
+                let orphaned = format!(
+                    "`{column}` IS NOT NULL AND NOT EXISTS (SELECT 1 FROM `{parent}` AS parent WHERE parent.`{to}` = `{table}`.`{column}`)"
+                );
+
+                let sql = match on_delete.as_str() {
+                    "CASCADE" => format!("DELETE FROM `{table}` WHERE {orphaned}"),
+                    "SET NULL" => {
+                        format!("UPDATE `{table}` SET `{column}` = NULL WHERE {orphaned}")
+                    }
+                    "SET DEFAULT" => {
+                        let default: Option<String> = sqlx::query_scalar(
+                            "SELECT `dflt_value` FROM pragma_table_info(?1) WHERE `name` = ?2",
+                        )
+                        .bind(table)
+                        .bind(column)
+                        .fetch_one(&mut *conn)
+                        .await?;
+                        let default = default.unwrap_or_else(|| "NULL".into());
+
+                        format!("UPDATE `{table}` SET `{column}` = {default} WHERE {orphaned}")
+                    }
+                    _ => return Ok(()),
+                };
+
+                sqlx::query(&sql).execute(&mut *conn).await?;
+            }
+            Problem::MissingAttributes {
+                level_id,
+                character_id,
+                level,
+                ..
+            } => {
+                sqlx::query!(
+                    r#"
+                        INSERT INTO `character_level_base_attribute_values`
+                            (`character_level_id`, `attribute_id`, `value`)
+                        SELECT ?1, v.`attribute_id`, v.`value`
+                        FROM `character_level_base_attribute_values` AS v
+                        JOIN `character_levels` AS l
+                            USING (`character_level_id`)
+                        WHERE l.`character_id` = ?2
+                            AND v.`attribute_id` NOT IN (
+                                SELECT `attribute_id`
+                                FROM `character_level_base_attribute_values`
+                                WHERE `character_level_id` = ?1
+                            )
+                            AND l.`level` = (
+                                SELECT max(p.`level`)
+                                FROM `character_levels` AS p
+                                JOIN `character_level_base_attribute_values` AS w
+                                    USING (`character_level_id`)
+                                WHERE p.`character_id` = ?2
+                                    AND p.`level` < ?3
+                                    AND w.`attribute_id` = v.`attribute_id`
+                            )
+                        ;
+                    "#,
+                    level_id,
+                    character_id,
+                    level
+                )
+                .execute(&mut *conn)
+                .await?;
+            }
+            Problem::UnreadableDuration { .. }
+            | Problem::NoSubclasses { .. }
+            | Problem::LevelGap { .. } => {}
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::Orphans {
+                table,
+                column,
+                parent,
+                rows,
+                ..
+            } => write!(
+                f,
+                "{rows} row(s) of `{table}` point at missing `{parent}` through `{column}`"
+            ),
+            Problem::MissingAttributes {
+                character_id,
+                level,
+                missing,
+                ..
+            } => write!(
+                f,
+                "Level {level} of Character {character_id} has no base {}",
+                missing.join(", ")
+            ),
+            Problem::UnreadableDuration {
+                spell, kind, value, ..
+            } => match value {
+                Some(value) => write!(
+                    f,
+                    "Spell `{spell}` has an unreadable duration of {value} `{kind}`"
+                ),
+                None => write!(f, "Spell `{spell}` has an unreadable duration of `{kind}`"),
+            },
+            Problem::NoSubclasses { class, .. } => write!(f, "Class `{class}` has no Subclasses"),
+            Problem::LevelGap {
+                character, missing, ..
+            } => write!(
+                f,
+                "Character `{character}` has no Level {}",
+                missing
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+/// What [`repair`] did.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Repair {
+    pub repaired: Vec<Problem>,
+    /// Problems still there afterwards, to be put right by hand.
+    pub remaining: Vec<Problem>,
+}
+
+/// Every Problem in the database, orphaned rows first.
+pub async fn check(db: impl Db<'_>) -> crate::Result<Vec<Problem>> {
+    let mut conn = db.connection().await?;
+
+    let mut problems = orphans(&mut conn).await?;
+    problems.extend(missing_attributes(&mut conn).await?);
+    problems.extend(unreadable_durations(&mut conn).await?);
+    problems.extend(classes_without_subclasses(&mut conn).await?);
+    problems.extend(level_gaps(&mut conn).await?);
+
+    Ok(problems)
+}
+
+/// Puts right every Problem that [`Problem::can_repair`], all at once, and
+/// checks again to see what is left.
+pub async fn repair(db: impl Db<'_>) -> crate::Result<Repair> {
+    let mut conn = db.connection().await?;
+    let mut tx = conn.begin().await?;
+
+    let found = check(&mut *tx).await?;
+    for problem in found.iter().filter(|problem| problem.can_repair()) {
+        problem.repair(&mut tx).await?;
+    }
+    let remaining = check(&mut *tx).await?;
+
+    tx.commit().await?;
+
+    let repaired = found
+        .into_iter()
+        .filter(|problem| !remaining.contains(problem))
+        .collect();
+
+    Ok(Repair {
+        repaired,
+        remaining,
+    })
+}
+
+/// Goes through every single-column foreign key in the schema, since
+/// `PRAGMA foreign_key_check` can't say which row of a `WITHOUT ROWID` table
+/// is at fault.
+async fn orphans(conn: &mut SqliteConnection) -> crate::Result<Vec<Problem>> {
+    let tables: Vec<String> = sqlx::query_scalar(
+        "
+            SELECT `name`
+            FROM `sqlite_master`
+            WHERE `type` = 'table'
+                AND `name` NOT LIKE 'sqlite_%'
+                AND `name` <> '_sqlx_migrations'
+            ORDER BY `name`
+            ;
+        ",
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let mut problems = Vec::new();
+    for table in tables {
+        let keys: Vec<(i64, String, String, String)> = sqlx::query_as(
+            "
+                SELECT `id`, `table`, `from`, `on_delete`
+                FROM pragma_foreign_key_list(?1)
+                WHERE `id` NOT IN (
+                    SELECT `id`
+                    FROM pragma_foreign_key_list(?1)
+                    WHERE `seq` > 0
+                )
+                ORDER BY `id`
+                ;
+            ",
+        )
+        .bind(&table)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        for (_, parent, column, on_delete) in keys {
+            let to = parent_column(&mut *conn, &table, &column).await?;
+            let rows: i64 = sqlx::query_scalar(&format!(
+                "SELECT count(*) FROM `{table}` AS child WHERE child.`{column}` IS NOT NULL AND NOT EXISTS (SELECT 1 FROM `{parent}` AS parent WHERE parent.`{to}` = child.`{column}`)"
+            ))
+            .fetch_one(&mut *conn)
+            .await?;
+
+            if rows > 0 {
+                problems.push(Problem::Orphans {
+                    table: table.clone(),
+                    column,
+                    parent,
+                    rows: rows as u64,
+                    on_delete,
+                });
+            }
+        }
+    }
+
+    Ok(problems)
+}
+
+/// The column of the parent table `table`.`column` refers to, which is its
+/// primary key when the foreign key doesn't name one.
+async fn parent_column(
+    conn: &mut SqliteConnection,
+    table: &str,
+    column: &str,
+) -> crate::Result<String> {
+    let (parent, to): (String, Option<String>) = sqlx::query_as(
+        "SELECT `table`, `to` FROM pragma_foreign_key_list(?1) WHERE `from` = ?2 LIMIT 1",
+    )
+    .bind(table)
+    .bind(column)
+    .fetch_one(&mut *conn)
+    .await?;
+
+    match to {
+        Some(to) => Ok(to),
+        None => Ok(
+            sqlx::query_scalar("SELECT `name` FROM pragma_table_info(?1) WHERE `pk` = 1")
+                .bind(parent)
+                .fetch_one(&mut *conn)
+                .await?,
+        ),
+    }
+}
+
+async fn missing_attributes(conn: &mut SqliteConnection) -> crate::Result<Vec<Problem>> {
+    let [prime, might, agility, charisma, intelligence] = BASE_ATTRIBUTES;
+    let rows = sqlx::query!(
+        r#"
+            SELECT l.`character_level_id` AS "level_id: Uuid"
+                , l.`character_id` AS "character_id: Uuid"
+                , l.`level` AS "level: u32"
+                , a.`name`
+            FROM `character_levels` AS l
+            CROSS JOIN `attributes` AS a
+            WHERE a.`name` IN (?1, ?2, ?3, ?4, ?5)
+                AND NOT EXISTS (
+                    SELECT 1
+                    FROM `character_level_base_attribute_values` AS v
+                    WHERE v.`character_level_id` = l.`character_level_id`
+                        AND v.`attribute_id` = a.`attribute_id`
+                )
+            ORDER BY l.`character_id`, l.`level`, a.`name`
+            ;
+        "#,
+        prime,
+        might,
+        agility,
+        charisma,
+        intelligence
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let mut problems: Vec<Problem> = Vec::new();
+    for row in rows {
+        match problems.last_mut() {
+            Some(Problem::MissingAttributes {
+                level_id, missing, ..
+            }) if *level_id == row.level_id => missing.push(row.name),
+            _ => problems.push(Problem::MissingAttributes {
+                level_id: row.level_id,
+                character_id: row.character_id,
+                level: row.level,
+                missing: vec![row.name],
+            }),
+        }
+    }
+
+    Ok(problems)
+}
+
+async fn unreadable_durations(conn: &mut SqliteConnection) -> crate::Result<Vec<Problem>> {
+    let rows = sqlx::query!(
+        r#"
+            SELECT `spell_id` AS "spell_id: Uuid"
+                , `name`
+                , `duration_kind`
+                , `duration_value`
+            FROM `spells`
+            ORDER BY `name`
+            ;
+        "#
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .filter(|row| Duration::parse(&row.duration_kind, row.duration_value).is_err())
+        .map(|row| Problem::UnreadableDuration {
+            spell_id: row.spell_id,
+            spell: row.name,
+            kind: row.duration_kind,
+            value: row.duration_value,
+        })
+        .collect())
+}
+
+async fn classes_without_subclasses(conn: &mut SqliteConnection) -> crate::Result<Vec<Problem>> {
+    let rows = sqlx::query!(
+        r#"
+            SELECT `class_id` AS "class_id: Uuid"
+                , `name`
+            FROM `classes`
+            WHERE `class_id` NOT IN (
+                SELECT `class_id`
+                FROM `classes_subclasses`
+            )
+            ORDER BY `name`
+            ;
+        "#
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| Problem::NoSubclasses {
+            class_id: row.class_id,
+            class: row.name,
+        })
+        .collect())
+}
+
+async fn level_gaps(conn: &mut SqliteConnection) -> crate::Result<Vec<Problem>> {
+    let rows = sqlx::query!(
+        r#"
+            SELECT c.`character_id` AS "character_id: Uuid"
+                , c.`name`
+                , l.`level` AS "level: u32"
+            FROM `characters` AS c
+            JOIN `character_levels` AS l
+                USING (`character_id`)
+            ORDER BY c.`name`, c.`character_id`, l.`level`
+            ;
+        "#
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let mut levels: BTreeMap<(String, Uuid), Vec<u32>> = BTreeMap::new();
+    for row in rows {
+        levels
+            .entry((row.name, row.character_id))
+            .or_default()
+            .push(row.level);
+    }
+
+    Ok(levels
+        .into_iter()
+        .filter_map(|((character, character_id), levels)| {
+            let highest = levels.last().copied()?;
+            let missing: Vec<u32> = (1..highest)
+                .filter(|level| !levels.contains(level))
+                .collect();
+
+            (!missing.is_empty()).then_some(Problem::LevelGap {
+                character_id,
+                character,
+                missing,
+            })
+        })
+        .collect())
+}
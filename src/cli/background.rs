@@ -0,0 +1,78 @@
+use clap::Subcommand;
+use mellon_book::dc20::{Background, Language, Skill, Trade};
+use serde::Serialize;
+
+use super::Output;
+
+#[derive(Debug, Subcommand)]
+pub enum BackgroundCommand {
+    /// Show a Background with the Skills, Trades and Languages it grants.
+    Show {
+        /// The Background's id or name.
+        background: String,
+    },
+}
+
+#[derive(Debug, Serialize)]
+struct BackgroundDetails {
+    #[serde(flatten)]
+    background: Background,
+    skills: Vec<Skill>,
+    trades: Vec<Trade>,
+    languages: Vec<Language>,
+}
+
+fn names<'a>(names: impl Iterator<Item = &'a str>) -> String {
+    names.collect::<Vec<_>>().join(", ")
+}
+
+pub async fn run(
+    pool: &sqlx::SqlitePool,
+    command: BackgroundCommand,
+    output: Output,
+) -> anyhow::Result<()> {
+    match command {
+        BackgroundCommand::Show { background } => {
+            let background = resolve(pool, &background).await?;
+            let details = BackgroundDetails {
+                skills: background.load_skills(pool).await?,
+                trades: background.load_trades(pool).await?,
+                languages: background.load_languages(pool).await?,
+                background,
+            };
+
+            output.print(&details, |details| {
+                [
+                    details.background.name.clone(),
+                    format!(
+                        "Skills: {}",
+                        names(details.skills.iter().map(|skill| skill.name.as_str()))
+                    ),
+                    format!(
+                        "Trades: {}",
+                        names(details.trades.iter().map(|trade| trade.name.as_str()))
+                    ),
+                    format!(
+                        "Languages: {}",
+                        names(
+                            details
+                                .languages
+                                .iter()
+                                .map(|language| language.name.as_str())
+                        )
+                    ),
+                ]
+                .join("\n")
+            })
+        }
+    }
+}
+
+/// Finds the Background by id or, failing that, by name.
+pub async fn resolve(pool: &sqlx::SqlitePool, id_or_name: &str) -> anyhow::Result<Background> {
+    match super::parse_id(id_or_name) {
+        Some(id) => Background::load(pool, id).await,
+        None => Background::load_by_name(pool, id_or_name, None).await,
+    }
+    .map_err(|e| super::not_found(e, "Background", id_or_name))
+}
@@ -0,0 +1,620 @@
+use std::{io::Write, path::Path};
+
+use clap::{Subcommand, ValueEnum};
+use mellon_book::{
+    dc20::{AncestryTrait, Attributes, Character, Defense, Level, MAX_LEVEL},
+    export::{
+        foundry::{self, Actor},
+        json::{self, Ids},
+        sheet::CharacterSheet,
+    },
+    history::{self, Point},
+    progression::{self, Changes, LevelComparison, LevelStats},
+};
+use serde::Serialize;
+
+use super::{background, class, player, Output};
+
+#[derive(Debug, Subcommand)]
+pub enum CharacterCommand {
+    /// Create a Character at Level 1.
+    New {
+        name: String,
+        /// The Player's id or name.
+        #[arg(long)]
+        player: String,
+        /// The Background's id or name.
+        #[arg(long)]
+        background: String,
+        /// The starting Class's id or name.
+        #[arg(long)]
+        class: Option<String>,
+        /// Id of an Ancestry Trait the Character has. Can be repeated.
+        #[arg(long = "ancestry-trait")]
+        ancestry_traits: Vec<uuid::Uuid>,
+        #[arg(long, default_value_t = 0, allow_negative_numbers = true)]
+        might: i64,
+        #[arg(long, default_value_t = 0, allow_negative_numbers = true)]
+        agility: i64,
+        #[arg(long, default_value_t = 0, allow_negative_numbers = true)]
+        charisma: i64,
+        #[arg(long, default_value_t = 0, allow_negative_numbers = true)]
+        intelligence: i64,
+    },
+    /// Show a Character's sheet.
+    Show {
+        /// The Character's id or name.
+        character: String,
+        /// Show the sheet as it was at this Level instead of the latest.
+        #[arg(long)]
+        level: Option<u32>,
+    },
+    /// List every Character.
+    List,
+    /// Delete a Character and everything recorded against them.
+    Delete {
+        /// The Character's id or name.
+        character: String,
+    },
+    /// Give a Character their next Level.
+    LevelUp {
+        /// The Character's id or name.
+        character: String,
+        /// The Class the Level is taken in; defaults to the previous Level's.
+        #[arg(long)]
+        class: Option<String>,
+    },
+    /// Show what changed in a Character from one of their Levels to another.
+    Compare {
+        /// The Character's id or name.
+        character: String,
+        #[arg(long)]
+        from: u32,
+        #[arg(long)]
+        to: u32,
+    },
+    /// Chart a Character's derived stats from their first Level to their last.
+    Progression {
+        /// The Character's id or name.
+        character: String,
+    },
+    /// Write a Character, with everything they have, as JSON.
+    Export {
+        /// The Character's id or name.
+        character: String,
+        /// File to write to instead of stdout.
+        #[arg(long)]
+        output: Option<std::path::PathBuf>,
+    },
+    /// Write a Character's sheet out to print.
+    Print {
+        /// The Character's id or name.
+        character: String,
+        /// Print the sheet as it was at this Level instead of the latest.
+        #[arg(long)]
+        level: Option<u32>,
+        /// Guessed from the output file's extension when left out.
+        #[arg(long, value_enum)]
+        format: Option<SheetFormat>,
+        /// File to write to instead of stdout.
+        #[arg(long)]
+        output: Option<std::path::PathBuf>,
+    },
+    /// Write a Character as a Foundry VTT actor for the DC20 system.
+    FoundryExport {
+        /// The Character's id or name.
+        character: String,
+        /// Export the Character as they were at this Level instead of the latest.
+        #[arg(long)]
+        level: Option<u32>,
+        /// File to write to instead of stdout.
+        #[arg(long)]
+        output: Option<std::path::PathBuf>,
+    },
+    /// Add a Character from a Foundry VTT actor.
+    FoundryImport {
+        file: std::path::PathBuf,
+        /// The Player's id or name.
+        #[arg(long)]
+        player: String,
+    },
+    /// Add a Character from a JSON export.
+    Import {
+        file: std::path::PathBuf,
+        /// Give the Character new ids, so a copy can sit beside the original.
+        #[arg(long)]
+        remap_ids: bool,
+    },
+    /// List every change made to a Character, oldest first.
+    History {
+        /// The Character's id or name.
+        character: String,
+    },
+    /// Show what changed in a Character between two points in their history.
+    Diff {
+        /// The Character's id or name.
+        character: String,
+        /// A revision id or an RFC 3339 time.
+        #[arg(long)]
+        from: Point,
+        /// A revision id or an RFC 3339 time; the Character as they are now
+        /// when left out.
+        #[arg(long)]
+        to: Option<Point>,
+    },
+    /// Put a Character back as they were at one of their revisions.
+    Restore {
+        /// The Character's id or name.
+        character: String,
+        revision: uuid::Uuid,
+    },
+}
+
+/// Everything shown by `character show`.
+#[derive(Debug, Serialize)]
+struct Sheet {
+    #[serde(flatten)]
+    character: Character,
+    level: Level,
+    classes: Vec<String>,
+    attributes: Attributes,
+    combat_mastery: usize,
+    precision_defense: Defense,
+    area_defense: Defense,
+}
+
+impl Sheet {
+    fn render(&self) -> String {
+        let Sheet {
+            character,
+            level,
+            classes,
+            attributes,
+            combat_mastery,
+            precision_defense,
+            area_defense,
+        } = self;
+
+        let mut lines = vec![
+            format!(
+                "{} (Level {} {})",
+                character.character_name(),
+                level.level,
+                classes.join("/")
+            ),
+            format!("Player: {}", character.player_name()),
+            format!("Background: {}", character.background().name),
+        ];
+
+        if !character.ancestry_traits().is_empty() {
+            lines.push(format!(
+                "Ancestry Traits: {}",
+                character
+                    .ancestry_traits()
+                    .iter()
+                    .map(|ancestry_trait| ancestry_trait.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        lines.extend([
+            format!(
+                "Prime {} | Might {} | Agility {} | Charisma {} | Intelligence {}",
+                attributes.prime,
+                attributes.might,
+                attributes.agility,
+                attributes.charisma,
+                attributes.intelligence
+            ),
+            format!("Combat Mastery: +{combat_mastery}"),
+            format!(
+                "PD: {} (Heavy {}, Brutal {})",
+                precision_defense.score,
+                precision_defense.heavy(),
+                precision_defense.brutal()
+            ),
+            format!(
+                "AD: {} (Heavy {}, Brutal {})",
+                area_defense.score,
+                area_defense.heavy(),
+                area_defense.brutal()
+            ),
+        ]);
+
+        lines.join("\n")
+    }
+}
+
+pub async fn run(
+    pool: &sqlx::SqlitePool,
+    command: CharacterCommand,
+    output: Output,
+) -> anyhow::Result<()> {
+    match command {
+        CharacterCommand::New {
+            name,
+            player,
+            background,
+            class,
+            ancestry_traits,
+            might,
+            agility,
+            charisma,
+            intelligence,
+        } => {
+            let player = player::resolve(pool, &player).await?;
+            let background = background::resolve(pool, &background).await?;
+            let class = match class {
+                Some(class) => Some(class::resolve(pool, &class).await?),
+                None => None,
+            };
+
+            let mut builder = Character::builder();
+            builder
+                .player(player)
+                .character_name(name)
+                .background(background);
+            for id in ancestry_traits {
+                builder.ancestry_trait(AncestryTrait::load(pool, id).await?);
+            }
+            let character = builder.build()?;
+            let attributes = Attributes {
+                prime: might.max(agility).max(charisma).max(intelligence),
+                might,
+                agility,
+                charisma,
+                intelligence,
+            };
+            character
+                .clone()
+                .create(pool, class.as_ref(), &attributes)
+                .await?;
+            history::record(pool, *character.id(), None, "Created").await?;
+
+            let sheet = load_sheet(pool, character, None).await?;
+            output.print(&sheet, Sheet::render)
+        }
+        CharacterCommand::Show { character, level } => {
+            let character = resolve(pool, &character).await?;
+            let sheet = load_sheet(pool, character, level).await?;
+
+            output.print(&sheet, Sheet::render)
+        }
+        CharacterCommand::List => {
+            let characters = Character::list(pool).await?;
+
+            output.print(&characters, |characters| {
+                characters
+                    .iter()
+                    .map(|character| {
+                        format!(
+                            "{}  {} (Level {}, played by {})",
+                            character.id, character.name, character.level, character.player_name
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+        }
+        CharacterCommand::Delete { character } => {
+            let character = resolve(pool, &character).await?;
+            Character::delete(pool, *character.id()).await?;
+
+            output.print(&character, |character| {
+                format!("Deleted {}", character.character_name())
+            })
+        }
+        CharacterCommand::LevelUp { character, class } => {
+            let character = resolve(pool, &character).await?;
+            let class = match class {
+                Some(class) => Some(class::resolve(pool, &class).await?),
+                None => None,
+            };
+
+            history::checkpoint(pool, *character.id()).await?;
+            let level = character.level_up(pool, class.as_ref()).await?;
+            let note = format!("Reached Level {}", level.level);
+            history::record(pool, *character.id(), None, &note).await?;
+            let sheet = load_sheet(pool, character, Some(level.level)).await?;
+
+            output.print(&sheet, |sheet| {
+                let mut text = sheet.render();
+                if sheet.level.level == MAX_LEVEL {
+                    text.push_str("\nMaximum Level reached.");
+                }
+                text
+            })
+        }
+        CharacterCommand::Compare {
+            character,
+            from,
+            to,
+        } => {
+            let character = resolve(pool, &character).await?;
+            let comparison = progression::compare(pool, *character.id(), from, to).await?;
+
+            output.print(&comparison, render_comparison)
+        }
+        CharacterCommand::Progression { character } => {
+            let character = resolve(pool, &character).await?;
+            let chart = progression::chart(pool, *character.id()).await?;
+
+            output.print(&chart, |chart| render_chart(chart))
+        }
+        CharacterCommand::Export {
+            character,
+            output: path,
+        } => {
+            let character = resolve(pool, &character).await?;
+            let export = json::export(pool, *character.id()).await?.to_json()?;
+
+            match path {
+                Some(path) => std::fs::write(path, export)?,
+                None => println!("{export}"),
+            }
+
+            Ok(())
+        }
+        CharacterCommand::Print {
+            character,
+            level,
+            format,
+            output: path,
+        } => {
+            let sheet = load_printable(pool, &character, level).await?;
+            let format = format
+                .or_else(|| path.as_deref().and_then(SheetFormat::from_path))
+                .unwrap_or_default();
+            let bytes = match format {
+                SheetFormat::Markdown => sheet.to_markdown().into_bytes(),
+                SheetFormat::Html => sheet.to_html().into_bytes(),
+                SheetFormat::Pdf => sheet.to_pdf()?,
+            };
+
+            match path {
+                Some(path) => std::fs::write(path, bytes)?,
+                None => std::io::stdout().write_all(&bytes)?,
+            }
+
+            Ok(())
+        }
+        CharacterCommand::FoundryExport {
+            character,
+            level,
+            output: path,
+        } => {
+            let sheet = load_printable(pool, &character, level).await?;
+            let actor = Actor::from(&sheet).to_json()?;
+
+            match path {
+                Some(path) => std::fs::write(path, actor)?,
+                None => println!("{actor}"),
+            }
+
+            Ok(())
+        }
+        CharacterCommand::FoundryImport { file, player } => {
+            let player = player::resolve(pool, &player).await?;
+            let imported = foundry::import(pool, &std::fs::read_to_string(file)?, player).await?;
+            for skipped in &imported.skipped {
+                eprintln!("Skipped {skipped}");
+            }
+            let id = *imported.character.id();
+            history::record(pool, id, None, "Imported from Foundry VTT").await?;
+            let sheet = load_sheet(pool, imported.character, None).await?;
+
+            output.print(&sheet, Sheet::render)
+        }
+        CharacterCommand::Import { file, remap_ids } => {
+            let ids = if remap_ids { Ids::Remap } else { Ids::Preserve };
+            let character = json::import(pool, &std::fs::read_to_string(file)?, ids).await?;
+            history::record(pool, *character.id(), None, "Imported").await?;
+            let sheet = load_sheet(pool, character, None).await?;
+
+            output.print(&sheet, Sheet::render)
+        }
+        CharacterCommand::History { character } => {
+            let character = resolve(pool, &character).await?;
+            let entries = history::log(pool, *character.id()).await?;
+
+            output.print(&entries, |entries| {
+                entries
+                    .iter()
+                    .map(|entry| {
+                        let when = entry
+                            .recorded_at
+                            .map(|at| at.format("%Y-%m-%d %H:%M:%S").to_string())
+                            .unwrap_or_default();
+                        format!(
+                            "{}  {when}  {} ({} changes)",
+                            entry.revision,
+                            entry.note,
+                            entry.changes.len()
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+        }
+        CharacterCommand::Diff {
+            character,
+            from,
+            to,
+        } => {
+            let character = resolve(pool, &character).await?;
+            let changes = history::diff_between(pool, *character.id(), from, to).await?;
+
+            output.print(&changes, |changes| {
+                if changes.is_empty() {
+                    return "No changes".into();
+                }
+                changes
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+        }
+        CharacterCommand::Restore {
+            character,
+            revision,
+        } => {
+            let character = resolve(pool, &character).await?;
+            let character = history::restore(pool, *character.id(), revision, None).await?;
+            let sheet = load_sheet(pool, character, None).await?;
+
+            output.print(&sheet, Sheet::render)
+        }
+    }
+}
+
+/// One line per change, `No changes` when the Levels are the same.
+fn render_comparison(comparison: &LevelComparison) -> String {
+    fn named<T>(lines: &mut Vec<String>, kind: &str, changes: &Changes<T>, name: fn(&T) -> &str) {
+        for added in &changes.added {
+            lines.push(format!("+ {kind} {}", name(added)));
+        }
+        for removed in &changes.removed {
+            lines.push(format!("- {kind} {}", name(removed)));
+        }
+    }
+
+    let show = |value: Option<String>| value.unwrap_or_else(|| "-".into());
+    let mut lines = vec![];
+
+    for change in &comparison.attributes {
+        lines.push(format!(
+            "{}: {} -> {}",
+            change.name,
+            show(change.from.map(|v| v.to_string())),
+            show(change.to.map(|v| v.to_string()))
+        ));
+    }
+    for change in &comparison.stats {
+        lines.push(format!(
+            "{}: {} -> {}",
+            change.name,
+            show(change.from.map(|v| v.to_string())),
+            show(change.to.map(|v| v.to_string()))
+        ));
+    }
+    for change in &comparison.classes {
+        lines.push(format!(
+            "{} Levels: {} -> {}",
+            change.class.name, change.from, change.to
+        ));
+    }
+    named(&mut lines, "Subclass", &comparison.subclasses, |s| &s.name);
+    named(&mut lines, "Background", &comparison.backgrounds, |b| {
+        &b.name
+    });
+    named(
+        &mut lines,
+        "Ancestry Trait",
+        &comparison.ancestry_traits,
+        |t| &t.name,
+    );
+    named(&mut lines, "Maneuver", &comparison.maneuvers, |m| &m.name);
+    named(&mut lines, "Spell", &comparison.spells, |s| &s.name);
+
+    if lines.is_empty() {
+        return "No changes".into();
+    }
+    lines.join("\n")
+}
+
+/// A row per Level, a column per stat.
+fn render_chart(chart: &[LevelStats]) -> String {
+    let Some(first) = chart.first() else {
+        return String::new();
+    };
+
+    let header: Vec<&str> = first.values().iter().map(|(name, _)| *name).collect();
+    let mut lines = vec![header.join(" | ")];
+    for stats in chart {
+        let row: Vec<String> = stats
+            .values()
+            .iter()
+            .zip(&header)
+            .map(|((_, value), name)| {
+                let value = value.map_or("-".into(), |v| v.to_string());
+                format!("{value:>width$}", width = name.len())
+            })
+            .collect();
+        lines.push(row.join(" | "));
+    }
+    lines.join("\n")
+}
+
+/// The printable sheet for the Character, by id or name, at `level` or their latest.
+async fn load_printable(
+    pool: &sqlx::SqlitePool,
+    character: &str,
+    level: Option<u32>,
+) -> anyhow::Result<CharacterSheet> {
+    let character = resolve(pool, character).await?;
+    let level = match level {
+        Some(level) => character.load_level(pool, level).await?,
+        None => character.load_max_level(pool).await?,
+    };
+
+    Ok(CharacterSheet::load(pool, &character, &level).await?)
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum SheetFormat {
+    #[default]
+    Markdown,
+    Html,
+    Pdf,
+}
+
+impl SheetFormat {
+    fn from_path(path: &Path) -> Option<SheetFormat> {
+        match path.extension()?.to_str()? {
+            "md" => Some(SheetFormat::Markdown),
+            "html" | "htm" => Some(SheetFormat::Html),
+            "pdf" => Some(SheetFormat::Pdf),
+            _ => None,
+        }
+    }
+}
+
+/// Finds the Character by id or, failing that, by name.
+pub async fn resolve(pool: &sqlx::SqlitePool, id_or_name: &str) -> anyhow::Result<Character> {
+    match super::parse_id(id_or_name) {
+        Some(id) => Character::load(pool, id).await,
+        None => Character::load_by_name(pool, id_or_name).await,
+    }
+    .map_err(|e| super::not_found(e, "Character", id_or_name))
+}
+
+async fn load_sheet(
+    pool: &sqlx::SqlitePool,
+    character: Character,
+    level: Option<u32>,
+) -> anyhow::Result<Sheet> {
+    let level = match level {
+        Some(level) => character.load_level(pool, level).await?,
+        None => character.load_max_level(pool).await?,
+    };
+    let classes = level
+        .load_classes(pool, None)
+        .await?
+        .into_iter()
+        .map(|class| class.name)
+        .collect();
+    let attributes = level.load_base_attributes(pool).await?;
+    let combat_mastery = level.calc_combat_mastery();
+
+    Ok(Sheet {
+        precision_defense: attributes.precision_defense(combat_mastery),
+        area_defense: attributes.area_defense(combat_mastery),
+        character,
+        level,
+        classes,
+        attributes,
+        combat_mastery,
+    })
+}
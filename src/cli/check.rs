@@ -0,0 +1,63 @@
+use mellon_book::check::{self, Problem, Repair};
+
+use super::Output;
+
+pub async fn run(pool: &sqlx::SqlitePool, repair: bool, output: Output) -> anyhow::Result<()> {
+    let remaining = if repair {
+        let repair = check::repair(pool).await?;
+        output.print(&repair, |repair| {
+            let Repair {
+                repaired,
+                remaining,
+            } = repair;
+            let mut lines = Vec::new();
+            if !repaired.is_empty() {
+                lines.push("Repaired:".into());
+                lines.extend(bullets(repaired, false));
+            }
+            if !remaining.is_empty() {
+                lines.push("Left to put right by hand:".into());
+                lines.extend(bullets(remaining, false));
+            }
+            if lines.is_empty() {
+                lines.push("No problems found".into());
+            }
+            lines.join("\n")
+        })?;
+        repair.remaining.len()
+    } else {
+        let problems = check::check(pool).await?;
+        output.print(&problems, |problems| {
+            if problems.is_empty() {
+                return "No problems found".into();
+            }
+            let mut lines = bullets(problems, true);
+            if problems.iter().any(Problem::can_repair) {
+                lines.push("Some of these can be put right with `check --repair`".into());
+            }
+            lines.join("\n")
+        })?;
+        problems.len()
+    };
+
+    if remaining > 0 {
+        anyhow::bail!("{remaining} problem(s) found");
+    }
+
+    Ok(())
+}
+
+/// One line per Problem, optionally saying which `--repair` would try to fix.
+fn bullets(problems: &[Problem], mark_repairable: bool) -> Vec<String> {
+    problems
+        .iter()
+        .map(|problem| {
+            let repairable = if mark_repairable && problem.can_repair() {
+                " (repairable)"
+            } else {
+                ""
+            };
+            format!("- {problem}{repairable}")
+        })
+        .collect()
+}
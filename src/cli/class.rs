@@ -0,0 +1,59 @@
+use clap::Subcommand;
+use mellon_book::dc20::{Class, Subclass};
+use serde::Serialize;
+
+use super::Output;
+
+#[derive(Debug, Subcommand)]
+pub enum ClassCommand {
+    /// Show a Class and its Subclasses.
+    Show {
+        /// The Class's id or name.
+        class: String,
+    },
+}
+
+#[derive(Debug, Serialize)]
+struct ClassDetails {
+    #[serde(flatten)]
+    class: Class,
+    subclasses: Vec<Subclass>,
+}
+
+pub async fn run(
+    pool: &sqlx::SqlitePool,
+    command: ClassCommand,
+    output: Output,
+) -> anyhow::Result<()> {
+    match command {
+        ClassCommand::Show { class } => {
+            let class = resolve(pool, &class).await?;
+            let subclasses = class.load_sublasses(pool).await?;
+
+            output.print(&ClassDetails { class, subclasses }, |details| {
+                let mut lines = vec![details.class.name.clone()];
+                if !details.subclasses.is_empty() {
+                    lines.push(format!(
+                        "Subclasses: {}",
+                        details
+                            .subclasses
+                            .iter()
+                            .map(|subclass| subclass.name.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ));
+                }
+                lines.join("\n")
+            })
+        }
+    }
+}
+
+/// Finds the Class by id or, failing that, by name.
+pub async fn resolve(pool: &sqlx::SqlitePool, id_or_name: &str) -> anyhow::Result<Class> {
+    match super::parse_id(id_or_name) {
+        Some(id) => Class::load(pool, id).await,
+        None => Class::load_by_name(pool, id_or_name, None).await,
+    }
+    .map_err(|e| super::not_found(e, "Class", id_or_name))
+}
@@ -0,0 +1,196 @@
+use clap::{Parser, Subcommand};
+use mellon_book::db::OpenOptions;
+use serde::Serialize;
+use uuid::Uuid;
+
+mod background;
+mod character;
+mod check;
+mod class;
+mod pack;
+mod player;
+mod spell;
+mod weapon;
+
+/// A DC20 character sheet manager.
+#[derive(Debug, Parser)]
+#[command(name = "mellon-book", version, about)]
+pub struct Cli {
+    /// SQLite database to read from and write to. It is created, with the
+    /// core rules, if it doesn't exist yet.
+    #[arg(
+        long,
+        env = "DATABASE_URL",
+        global = true,
+        default_value = "sqlite://db.sqlite"
+    )]
+    database: String,
+    /// Print results as JSON instead of plain text.
+    #[arg(long, global = true)]
+    json: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Manage the people playing.
+    #[command(subcommand)]
+    Player(player::PlayerCommand),
+    /// Create, inspect and level up Characters.
+    #[command(subcommand)]
+    Character(character::CharacterCommand),
+    /// Look up Spells.
+    #[command(subcommand)]
+    Spell(spell::SpellCommand),
+    /// Look up Classes.
+    #[command(subcommand)]
+    Class(class::ClassCommand),
+    /// Look up Backgrounds.
+    #[command(subcommand)]
+    Background(background::BackgroundCommand),
+    /// Install homebrew content packs and pick which Campaigns use them.
+    #[command(subcommand)]
+    Pack(pack::PackCommand),
+    /// Design Weapons.
+    #[command(subcommand)]
+    Weapon(weapon::WeaponCommand),
+    /// Look for rows the rest of the book can't make sense of.
+    Check {
+        /// Put right whatever can be without guessing what was meant.
+        #[arg(long)]
+        repair: bool,
+    },
+    /// Open a Character's sheet in the terminal for play.
+    Sheet {
+        /// The Character's id or name.
+        character: String,
+    },
+}
+
+/// How results are written to stdout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Output {
+    json: bool,
+}
+
+impl Output {
+    /// Prints `value` as pretty JSON, or as the text `human` makes of it.
+    fn print<T: Serialize>(
+        self,
+        value: &T,
+        human: impl FnOnce(&T) -> String,
+    ) -> anyhow::Result<()> {
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(value)?);
+        } else {
+            println!("{}", human(value));
+        }
+
+        Ok(())
+    }
+}
+
+/// Rows can be picked out by either their id or their name.
+fn parse_id(id_or_name: &str) -> Option<Uuid> {
+    Uuid::parse_str(id_or_name).ok()
+}
+
+/// Swaps a missing row's error for one naming what was looked for.
+fn not_found(error: mellon_book::Error, kind: &str, id_or_name: &str) -> anyhow::Error {
+    match error {
+        mellon_book::Error::NotFound(_) => {
+            anyhow::anyhow!("No {kind} found matching `{id_or_name}`")
+        }
+        error => error.into(),
+    }
+}
+
+pub async fn run(cli: Cli) -> anyhow::Result<()> {
+    let output = Output { json: cli.json };
+
+    if let Command::Weapon(command) = cli.command {
+        return weapon::run(command, output);
+    }
+
+    let pool = OpenOptions::new()
+        .core_content(true)
+        .open_url(&cli.database)
+        .await?;
+
+    let result = match cli.command {
+        Command::Player(command) => player::run(&pool, command, output).await,
+        Command::Character(command) => character::run(&pool, command, output).await,
+        Command::Spell(command) => spell::run(&pool, command, output).await,
+        Command::Class(command) => class::run(&pool, command, output).await,
+        Command::Background(command) => background::run(&pool, command, output).await,
+        Command::Pack(command) => pack::run(&pool, command, output).await,
+        Command::Check { repair } => check::run(&pool, repair, output).await,
+        Command::Sheet { character } => sheet(&pool, &character).await,
+        Command::Weapon(_) => unreachable!("Weapons are built without a database"),
+    };
+
+    pool.close().await;
+
+    result
+}
+
+async fn sheet(pool: &sqlx::SqlitePool, character: &str) -> anyhow::Result<()> {
+    let character = character::resolve(pool, character).await?;
+    let sheet = crate::tui::Sheet::load(pool, character).await?;
+
+    crate::tui::run(pool, sheet).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _cli_definition_is_consistent() {
+        use clap::CommandFactory;
+
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn _characters_can_be_picked_by_name_or_id() {
+        let cli =
+            Cli::try_parse_from(["mellon-book", "character", "show", "Cygnus", "--json"]).unwrap();
+
+        assert!(cli.json);
+        assert!(matches!(
+            cli.command,
+            Command::Character(character::CharacterCommand::Show { ref character, .. })
+                if character == "Cygnus"
+        ));
+        assert_eq!(parse_id("Cygnus"), None);
+        assert_eq!(
+            parse_id("166ae11a-3d40-4c61-8d39-0415e0cae6bb"),
+            Some(Uuid::from_u128(0x166ae11a3d404c618d390415e0cae6bb))
+        );
+    }
+
+    #[test]
+    fn _weapon_properties_are_parsed_from_the_command_line() {
+        let cli = Cli::try_parse_from([
+            "mellon-book",
+            "weapon",
+            "build",
+            "--type",
+            "melee",
+            "--style",
+            "sword",
+            "--property",
+            "Two-Handed",
+            "--property",
+            "heavy",
+        ])
+        .unwrap();
+
+        let Command::Weapon(weapon::WeaponCommand::Build { properties, .. }) = cli.command else {
+            panic!("expected `weapon build`");
+        };
+        assert_eq!(properties.len(), 2);
+    }
+}
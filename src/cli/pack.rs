@@ -0,0 +1,127 @@
+use std::path::PathBuf;
+
+use clap::Subcommand;
+use mellon_book::{
+    campaign::Campaign,
+    pack::{ContentPack, Manifest},
+};
+use uuid::Uuid;
+
+use super::Output;
+
+#[derive(Debug, Subcommand)]
+pub enum PackCommand {
+    /// List the installed content packs.
+    List,
+    /// Install a content pack, or update an installed one to its version.
+    Install {
+        /// A pack directory, or a .tar or .tar.gz archive of one.
+        path: PathBuf,
+    },
+    /// Delete a content pack and all its content.
+    Uninstall {
+        /// The pack's id or name.
+        pack: String,
+    },
+    /// Write an installed pack out as a directory of TOML files.
+    Export {
+        /// The pack's id or name.
+        pack: String,
+        /// Directory to write the pack to.
+        #[arg(long)]
+        output: PathBuf,
+    },
+    /// Make a pack's content available in a Campaign.
+    Enable {
+        /// The pack's id or name.
+        pack: String,
+        /// The Campaign's id.
+        #[arg(long)]
+        campaign: Uuid,
+    },
+    /// Hide a pack's content from a Campaign.
+    Disable {
+        /// The pack's id or name.
+        pack: String,
+        /// The Campaign's id.
+        #[arg(long)]
+        campaign: Uuid,
+    },
+}
+
+pub async fn run(
+    pool: &sqlx::SqlitePool,
+    command: PackCommand,
+    output: Output,
+) -> anyhow::Result<()> {
+    match command {
+        PackCommand::List => {
+            let manifests = Manifest::list(pool).await?;
+
+            output.print(&manifests, |manifests| {
+                manifests.iter().map(render).collect::<Vec<_>>().join("\n")
+            })
+        }
+        PackCommand::Install { path } => {
+            let installed = ContentPack::read(&path)?.install(pool).await?;
+
+            output.print(&installed, |installed| {
+                format!(
+                    "Installed {}: {} added, {} updated, {} removed",
+                    render(&installed.manifest),
+                    installed.added,
+                    installed.updated,
+                    installed.removed
+                )
+            })
+        }
+        PackCommand::Uninstall { pack } => {
+            let manifest = resolve(pool, &pack).await?;
+            ContentPack::uninstall(pool, manifest.id).await?;
+
+            output.print(&manifest, |manifest| {
+                format!("Uninstalled {}", render(manifest))
+            })
+        }
+        PackCommand::Export { pack, output: dir } => {
+            let manifest = resolve(pool, &pack).await?;
+            ContentPack::load(pool, manifest.id).await?.write(&dir)?;
+
+            Ok(())
+        }
+        PackCommand::Enable { pack, campaign } => {
+            let manifest = resolve(pool, &pack).await?;
+            let campaign = Campaign::load(pool, campaign)
+                .await
+                .map_err(|e| super::not_found(e, "Campaign", &campaign.to_string()))?;
+            campaign.enable_content_pack(pool, manifest.id).await?;
+
+            Ok(())
+        }
+        PackCommand::Disable { pack, campaign } => {
+            let manifest = resolve(pool, &pack).await?;
+            let campaign = Campaign::load(pool, campaign)
+                .await
+                .map_err(|e| super::not_found(e, "Campaign", &campaign.to_string()))?;
+            campaign.disable_content_pack(pool, manifest.id).await?;
+
+            Ok(())
+        }
+    }
+}
+
+fn render(manifest: &Manifest) -> String {
+    format!(
+        "{}  {} {} by {}",
+        manifest.id, manifest.name, manifest.version, manifest.author
+    )
+}
+
+/// Finds the installed pack by id or, failing that, by name.
+async fn resolve(pool: &sqlx::SqlitePool, id_or_name: &str) -> anyhow::Result<Manifest> {
+    match super::parse_id(id_or_name) {
+        Some(id) => Manifest::load(pool, id).await,
+        None => Manifest::load_by_name(pool, id_or_name).await,
+    }
+    .map_err(|e| super::not_found(e, "content pack", id_or_name))
+}
@@ -0,0 +1,54 @@
+use clap::Subcommand;
+use mellon_book::{
+    player::Player,
+    repository::{Catalog, Repository, SqliteRepository},
+};
+
+use super::Output;
+
+#[derive(Debug, Subcommand)]
+pub enum PlayerCommand {
+    /// Add a new Player.
+    Add { name: String },
+    /// List every Player.
+    List,
+}
+
+pub async fn run(
+    pool: &sqlx::SqlitePool,
+    command: PlayerCommand,
+    output: Output,
+) -> anyhow::Result<()> {
+    let players = SqliteRepository::new(pool.clone());
+
+    match command {
+        PlayerCommand::Add { name } => {
+            let player = Player::builder().name(name)?.build()?;
+            players.save(player.clone()).await?;
+
+            output.print(&player, |player| {
+                format!("Added {} ({})", player.name(), player.id())
+            })
+        }
+        PlayerCommand::List => {
+            let players = Catalog::<Player>::list(&players).await?;
+
+            output.print(&players, |players| {
+                players
+                    .iter()
+                    .map(|player| format!("{}  {}", player.id(), player.name()))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+        }
+    }
+}
+
+/// Finds the Player by id or, failing that, by name.
+pub async fn resolve(pool: &sqlx::SqlitePool, id_or_name: &str) -> anyhow::Result<Player> {
+    match super::parse_id(id_or_name) {
+        Some(id) => Player::load(pool, id).await,
+        None => Player::load_by_name(pool, id_or_name).await,
+    }
+    .map_err(|e| super::not_found(e, "Player", id_or_name))
+}
@@ -0,0 +1,118 @@
+use clap::Subcommand;
+use mellon_book::dc20::{Spell, SpellSearch};
+use uuid::Uuid;
+
+use super::Output;
+
+#[derive(Debug, Subcommand)]
+pub enum SpellCommand {
+    /// List the Spells matching every filter given.
+    Search {
+        /// Part of the Spell's name.
+        #[arg(long)]
+        name: Option<String>,
+        /// The Spell's School.
+        #[arg(long)]
+        school: Option<String>,
+        /// The most Mana Points the Spell may cost.
+        #[arg(long)]
+        max_mana: Option<u64>,
+        /// Only Spells available in this Campaign, by id.
+        #[arg(long)]
+        campaign: Option<Uuid>,
+    },
+    /// Show a Spell in full.
+    Show {
+        /// The Spell's id or name.
+        spell: String,
+    },
+}
+
+pub async fn run(
+    pool: &sqlx::SqlitePool,
+    command: SpellCommand,
+    output: Output,
+) -> anyhow::Result<()> {
+    match command {
+        SpellCommand::Search {
+            name,
+            school,
+            max_mana,
+            campaign,
+        } => {
+            let search = SpellSearch {
+                name,
+                school,
+                max_mana_cost: max_mana,
+                campaign,
+            };
+            let spells = Spell::search(pool, &search, None).await?;
+
+            output.print(&spells, |spells| {
+                spells
+                    .iter()
+                    .map(|spell| {
+                        format!(
+                            "{}  {} ({}, {} AP, {} MP)",
+                            spell.id,
+                            spell.name,
+                            spell.school.name(),
+                            spell.action_point_cost,
+                            spell.mana_point_cost
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+        }
+        SpellCommand::Show { spell } => {
+            let spell = match super::parse_id(&spell) {
+                Some(id) => Spell::load(pool, id).await,
+                None => Spell::load_by_name(pool, &spell, None).await,
+            }
+            .map_err(|e| super::not_found(e, "Spell", &spell))?;
+
+            output.print(&spell, render)
+        }
+    }
+}
+
+fn render(spell: &Spell) -> String {
+    let components = [
+        (spell.has_verbal, "V"),
+        (spell.has_somatic, "S"),
+        (spell.has_material, "M"),
+    ]
+    .into_iter()
+    .filter_map(|(has, component)| has.then_some(component))
+    .collect::<Vec<_>>()
+    .join(", ");
+
+    let mut lines = vec![
+        format!("{} ({})", spell.name, spell.school.name()),
+        format!(
+            "Cost: {} AP, {} MP | Components: {components}",
+            spell.action_point_cost, spell.mana_point_cost
+        ),
+        format!(
+            "Range: {} | Duration: {}{}",
+            spell.range,
+            spell.duration,
+            if spell.sustained { " (Sustained)" } else { "" }
+        ),
+        String::new(),
+        spell.description.clone(),
+    ];
+
+    for enhancement in &spell.point_enhancements {
+        lines.push(format!(
+            "\n{} ({} AP, {} MP): {}",
+            enhancement.name,
+            enhancement.action_point_cost,
+            enhancement.mana_point_cost,
+            enhancement.description
+        ));
+    }
+
+    lines.join("\n")
+}
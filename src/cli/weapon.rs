@@ -0,0 +1,78 @@
+use clap::Subcommand;
+use mellon_book::dc20::{
+    item::weapon::{DamageType, WeaponBuilder, WeaponProperty, WeaponStyle, WeaponType},
+    Range,
+};
+
+use super::Output;
+
+#[derive(Debug, Subcommand)]
+pub enum WeaponCommand {
+    /// Build a Weapon, checking its Style and Properties are compatible.
+    Build {
+        /// `melee` or `ranged`. Defaults to the Style's usual type.
+        #[arg(long = "type")]
+        weapon_type: Option<WeaponType>,
+        #[arg(long)]
+        style: WeaponStyle,
+        /// Defaults to the Style's usual Damage Type.
+        #[arg(long)]
+        damage_type: Option<DamageType>,
+        /// A Weapon Property. Can be repeated.
+        #[arg(long = "property")]
+        properties: Vec<WeaponProperty>,
+    },
+}
+
+pub fn run(command: WeaponCommand, output: Output) -> anyhow::Result<()> {
+    match command {
+        WeaponCommand::Build {
+            weapon_type,
+            style,
+            damage_type,
+            properties,
+        } => {
+            // Ranged Weapons start with the Properties every ranged Weapon has.
+            let mut builder = match weapon_type.unwrap_or(style.get_weapon_type()) {
+                WeaponType::Melee => WeaponBuilder::new_melee(),
+                WeaponType::Ranged => WeaponBuilder::new_ranged(),
+            };
+            builder.style(style)?;
+            if let Some(damage_type) = damage_type {
+                builder.damage_type(damage_type)?;
+            }
+            builder.add_properties(&properties)?;
+            let weapon = builder.build()?;
+
+            output.print(&weapon, |weapon| {
+                let range = match weapon.base_range {
+                    Range::Spaces(spaces) => format!("{spaces} Spaces"),
+                    Range::Touch | Range::Caster => "Melee".to_string(),
+                };
+                let properties = weapon
+                    .properties
+                    .iter()
+                    .map(WeaponProperty::to_string)
+                    .collect::<Vec<_>>();
+
+                [
+                    format!(
+                        "{} {} Weapon ({} damage)",
+                        weapon.style, weapon.weapon_type, weapon.damage_type
+                    ),
+                    format!("Damage: {} {}", weapon.base_damage(), weapon.damage_type),
+                    format!("Range: {range}"),
+                    format!(
+                        "Properties: {}",
+                        if properties.is_empty() {
+                            "None".to_string()
+                        } else {
+                            properties.join(", ")
+                        }
+                    ),
+                ]
+                .join("\n")
+            })
+        }
+    }
+}
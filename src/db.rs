@@ -0,0 +1,165 @@
+//! What the library's queries run against, and getting a database ready
+//! for them.
+
+use std::{
+    future::Future,
+    ops::{Deref, DerefMut},
+    path::Path,
+    pin::Pin,
+};
+
+use sqlx::{
+    migrate::Migrator,
+    pool::PoolConnection,
+    sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+    Sqlite, SqliteConnection, SqlitePool, Transaction,
+};
+
+/// The migrations in `migrations/`, built into the library.
+pub static MIGRATOR: Migrator = sqlx::migrate!();
+
+/// The DC20 core rules: Ancestries, Backgrounds, Classes, Spells, Weapons and
+/// everything they need.
+pub const CORE_CONTENT: &str = include_str!("../content/core.sql");
+
+/// Opens the SQLite database at `path`, creating it if it doesn't exist yet
+/// and bringing its schema up to date. Foreign keys are enforced.
+pub async fn open(path: impl AsRef<Path>) -> crate::Result<SqlitePool> {
+    OpenOptions::new().open(path).await
+}
+
+/// How [`open`] gets a database ready.
+#[derive(Clone, Debug, Default)]
+pub struct OpenOptions {
+    core_content: bool,
+}
+
+impl OpenOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether a database that has never been migrated gets the core rules
+    /// too. Ones already in use are left as they are, so core content a
+    /// table has deleted stays deleted.
+    pub fn core_content(&mut self, core_content: bool) -> &mut Self {
+        self.core_content = core_content;
+        self
+    }
+
+    pub async fn open(&self, path: impl AsRef<Path>) -> crate::Result<SqlitePool> {
+        self.connect(SqliteConnectOptions::new().filename(path))
+            .await
+    }
+
+    /// Like [`OpenOptions::open`], for a `sqlite://` URL.
+    pub async fn open_url(&self, url: &str) -> crate::Result<SqlitePool> {
+        self.connect(url.parse()?).await
+    }
+
+    async fn connect(&self, options: SqliteConnectOptions) -> crate::Result<SqlitePool> {
+        let options = options.create_if_missing(true).foreign_keys(true);
+        let pool = SqlitePoolOptions::new().connect_with(options).await?;
+
+        let migrated: bool = sqlx::query_scalar(
+            "
+                SELECT EXISTS (
+                    SELECT 1
+                    FROM `sqlite_master`
+                    WHERE `type` = 'table'
+                        AND `name` = '_sqlx_migrations'
+                );
+            ",
+        )
+        .fetch_one(&pool)
+        .await?;
+
+        MIGRATOR.run(&pool).await?;
+
+        if self.core_content && !migrated {
+            load_core_content(&pool).await?;
+        }
+
+        Ok(pool)
+    }
+}
+
+/// Adds the core rules, all at once. Rows already there are left alone, so
+/// it is safe to load again.
+pub async fn load_core_content(db: impl Db<'_>) -> crate::Result<()> {
+    let mut conn = db.connection().await?;
+    let mut tx = conn.begin().await?;
+
+    sqlx::raw_sql(CORE_CONTENT).execute(&mut *tx).await?;
+
+    Ok(tx.commit().await?)
+}
+
+/// Anything queries can be run against: a pool, a connection or an open
+/// transaction. Functions that run more than one query take a `Db` so a
+/// caller can have them join a transaction of their own; those that run
+/// only one take an `sqlx::SqliteExecutor`.
+///
+/// Unlike `sqlx::Acquire`, the connection handed back is always a [`Conn`],
+/// so futures that hold one stay `Send` however deeply they are nested.
+pub trait Db<'c>: Send {
+    fn connection(self) -> Pin<Box<dyn Future<Output = crate::Result<Conn<'c>>> + Send + 'c>>;
+}
+
+/// A connection taken from a pool, or borrowed from the caller.
+#[derive(Debug)]
+pub enum Conn<'c> {
+    Pooled(PoolConnection<Sqlite>),
+    Borrowed(&'c mut SqliteConnection),
+}
+
+impl Conn<'_> {
+    /// Starts a transaction, or a savepoint when the caller already has one open.
+    pub async fn begin(&mut self) -> crate::Result<Transaction<'_, Sqlite>> {
+        Ok(sqlx::Connection::begin(&mut **self).await?)
+    }
+}
+
+impl Deref for Conn<'_> {
+    type Target = SqliteConnection;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Conn::Pooled(conn) => conn,
+            Conn::Borrowed(conn) => conn,
+        }
+    }
+}
+
+impl DerefMut for Conn<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Conn::Pooled(conn) => conn,
+            Conn::Borrowed(conn) => conn,
+        }
+    }
+}
+
+impl<'c> Db<'c> for &'c SqlitePool {
+    fn connection(self) -> Pin<Box<dyn Future<Output = crate::Result<Conn<'c>>> + Send + 'c>> {
+        Box::pin(async move { Ok(Conn::Pooled(self.acquire().await?)) })
+    }
+}
+
+impl<'c> Db<'c> for &'c mut SqliteConnection {
+    fn connection(self) -> Pin<Box<dyn Future<Output = crate::Result<Conn<'c>>> + Send + 'c>> {
+        Box::pin(async move { Ok(Conn::Borrowed(self)) })
+    }
+}
+
+impl<'c> Db<'c> for &'c mut PoolConnection<Sqlite> {
+    fn connection(self) -> Pin<Box<dyn Future<Output = crate::Result<Conn<'c>>> + Send + 'c>> {
+        Box::pin(async move { Ok(Conn::Borrowed(self)) })
+    }
+}
+
+impl<'c> Db<'c> for &'c mut Transaction<'_, Sqlite> {
+    fn connection(self) -> Pin<Box<dyn Future<Output = crate::Result<Conn<'c>>> + Send + 'c>> {
+        Box::pin(async move { Ok(Conn::Borrowed(self)) })
+    }
+}
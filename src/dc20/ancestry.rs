@@ -1,7 +1,10 @@
+use serde::{Deserialize, Serialize};
 use turann::Builder;
 use uuid::Uuid;
 
-#[derive(Builder, Clone, Debug, PartialEq, Eq)]
+use crate::db::Db;
+
+#[derive(Builder, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Ancestry {
     #[builder(default = Uuid::now_v7)]
     pub id: Uuid,
@@ -9,8 +12,11 @@ pub struct Ancestry {
 }
 
 impl Ancestry {
-    pub async fn load(pool: &sqlx::SqlitePool, id: Uuid) -> sqlx::Result<Ancestry> {
-        sqlx::query_as!(
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: Uuid,
+    ) -> crate::Result<Ancestry> {
+        Ok(sqlx::query_as!(
             Ancestry,
             r#"
                 SELECT `ancestry_id` as "id: Uuid", name
@@ -20,12 +26,39 @@ impl Ancestry {
             "#,
             id
         )
-        .fetch_one(pool)
-        .await
+        .fetch_one(executor)
+        .await?)
+    }
+
+    pub async fn list(executor: impl sqlx::SqliteExecutor<'_>) -> crate::Result<Vec<Ancestry>> {
+        Ok(sqlx::query_as!(
+            Ancestry,
+            r#"
+                SELECT `ancestry_id` as "id: Uuid", name
+                FROM ancestries
+                ORDER BY name;
+            "#
+        )
+        .fetch_all(executor)
+        .await?)
+    }
+
+    pub async fn delete(executor: impl sqlx::SqliteExecutor<'_>, id: Uuid) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM ancestries
+                WHERE ancestry_id = ?;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
     }
 
-    pub async fn save(self, pool: &sqlx::SqlitePool) -> sqlx::Result<()> {
-        let mut conn = pool.acquire().await?;
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
 
         let Ancestry { id, name } = self;
 
@@ -46,7 +79,7 @@ impl Ancestry {
     }
 }
 
-#[derive(Builder, Clone, Debug, Default, PartialEq)]
+#[derive(Builder, Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
 pub struct AncestryTrait {
     #[builder(default = Uuid::now_v7)]
     pub id: Uuid,
@@ -54,35 +87,63 @@ pub struct AncestryTrait {
     pub description: String,
     #[builder(default)]
     pub cost: i8,
+    /// The book the Trait comes from, when known.
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<Uuid>,
 }
 
 impl AncestryTrait {
-    pub async fn load(pool: &sqlx::SqlitePool, id: Uuid) -> sqlx::Result<AncestryTrait> {
-        sqlx::query_as!(
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: Uuid,
+    ) -> crate::Result<AncestryTrait> {
+        Ok(sqlx::query_as!(
             AncestryTrait,
             r#"
                 SELECT ancestry_trait_id as "id: Uuid"
                     , name
                     , description
                     , cost as "cost: i8"
+                    , source_id as "source?: Uuid"
                 FROM ancestry_traits
                 WHERE ancestry_trait_id = ?1
                 LIMIT 1;
             "#,
             id
         )
-        .fetch_one(pool)
-        .await
+        .fetch_one(executor)
+        .await?)
+    }
+
+    pub async fn list(
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Vec<AncestryTrait>> {
+        Ok(sqlx::query_as!(
+            AncestryTrait,
+            r#"
+                SELECT ancestry_trait_id as "id: Uuid"
+                    , name
+                    , description
+                    , cost as "cost: i8"
+                    , source_id as "source?: Uuid"
+                FROM ancestry_traits
+                ORDER BY name;
+            "#
+        )
+        .fetch_all(executor)
+        .await?)
     }
 
-    pub async fn save(self, pool: &mut sqlx::SqlitePool) -> sqlx::Result<()> {
-        let mut conn = pool.acquire().await?;
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
 
         let AncestryTrait {
             id,
             name,
             description,
             cost,
+            source,
         } = self;
 
         sqlx::query!(
@@ -92,23 +153,40 @@ impl AncestryTrait {
                     , `name`
                     , `description`
                     , `cost`
+                    , `source_id`
                 )
-                VALUES (?1, ?2, ?3, ?4)
+                VALUES (?1, ?2, ?3, ?4, ?5)
                 ON CONFLICT(`ancestry_trait_id`) DO UPDATE SET
                     `name` = ?2,
                     `description` = ?3,
-                    `cost` = ?4;
+                    `cost` = ?4,
+                    `source_id` = ?5;
             "#,
             id,
             name,
             description,
-            cost
+            cost,
+            source
         )
         .execute(&mut *conn)
         .await?;
 
         Ok(())
     }
+
+    pub async fn delete(executor: impl sqlx::SqliteExecutor<'_>, id: Uuid) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM ancestry_traits
+                WHERE ancestry_trait_id = ?;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
 }
 
 #[cfg(test)]
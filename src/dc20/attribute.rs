@@ -1,18 +1,25 @@
 use std::{error::Error, fmt, str::FromStr};
 
+use serde::{Deserialize, Serialize};
 use turann::Builder;
 
-use crate::dc20::{Defense, Level, Skill};
+use crate::{
+    db::Db,
+    dc20::{Defense, Level, Skill},
+};
 
-#[derive(Builder, Debug, Clone, PartialEq, Eq)]
+#[derive(Builder, Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Attribute {
     pub id: uuid::Uuid,
     pub name: String,
 }
 
 impl Attribute {
-    pub async fn load(pool: &sqlx::SqlitePool, id: uuid::Uuid) -> sqlx::Result<Attribute> {
-        sqlx::query_as!(
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: uuid::Uuid,
+    ) -> crate::Result<Attribute> {
+        Ok(sqlx::query_as!(
             Attribute,
             r#"
                 SELECT `attribute_id` AS "id: uuid::Uuid", `name`
@@ -22,12 +29,12 @@ impl Attribute {
             "#,
             id
         )
-        .fetch_one(pool)
-        .await
+        .fetch_one(executor)
+        .await?)
     }
 
-    pub async fn save(self, pool: &sqlx::SqlitePool) -> sqlx::Result<()> {
-        let mut conn = pool.acquire().await?;
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
 
         let Attribute { id, name } = self;
 
@@ -49,7 +56,7 @@ impl Attribute {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub enum AttributeName {
     Prime,
     Might,
@@ -90,7 +97,7 @@ impl FromStr for AttributeName {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
 pub struct Attributes {
     pub prime: i64,
     pub might: i64,
@@ -120,24 +127,32 @@ impl Attributes {
         self.intelligence
     }
 
+    pub fn get(&self, attribute: AttributeName) -> i64 {
+        match attribute {
+            AttributeName::Prime => self.prime,
+            AttributeName::Might => self.might,
+            AttributeName::Agility => self.agility,
+            AttributeName::Charisma => self.charisma,
+            AttributeName::Intelligence => self.intelligence,
+        }
+    }
+
     #[must_use]
     pub fn precision_defense(&self, combat_mastery: usize) -> Defense {
-        let agility = self.agility as usize;
-        let intelligence = self.intelligence as usize;
+        let score = 8 + combat_mastery as i64 + self.agility + self.intelligence;
 
         Defense {
-            score: 8 + combat_mastery + agility + intelligence,
+            score: score.max(0) as usize,
             reduction: 0,
         }
     }
 
     #[must_use]
     pub fn area_defense(&self, combat_mastery: usize) -> Defense {
-        let might = self.might as usize;
-        let charisma = self.charisma as usize;
+        let score = 8 + combat_mastery as i64 + self.might + self.charisma;
 
         Defense {
-            score: 8 + combat_mastery + might + charisma,
+            score: score.max(0) as usize,
             reduction: 0,
         }
     }
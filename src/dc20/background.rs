@@ -1,14 +1,50 @@
+use serde::{Deserialize, Serialize};
 use turann::Builder;
 use uuid::Uuid;
 
-use crate::dc20::{Language, Skill, Trade};
+use crate::{
+    db::Db,
+    dc20::{source::filter_sources, Language, Skill, SourceFilter, Trade},
+};
 
-#[derive(Builder, Clone, Debug, PartialEq)]
+#[derive(Builder, Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "BackgroundFields")]
 pub struct Background {
     #[builder(default = Uuid::new_v4)]
     pub id: Uuid,
     #[builder(validate = Self::validate_name)]
     pub name: String,
+    /// The book the Background comes from, when known.
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<uuid::Uuid>,
+}
+
+/// A [`Background`] as it comes in, built up through its builder so that it is
+/// checked the same way.
+#[derive(Deserialize)]
+struct BackgroundFields {
+    id: Option<Uuid>,
+    name: String,
+    #[serde(default)]
+    source: Option<Uuid>,
+}
+
+impl TryFrom<BackgroundFields> for Background {
+    type Error = BackgroundBuilderError;
+
+    fn try_from(fields: BackgroundFields) -> Result<Self, Self::Error> {
+        let mut builder = Background::builder();
+        builder.name(fields.name)?;
+        if let Some(id) = fields.id {
+            builder.id(id);
+        }
+
+        Ok(Background {
+            source: fields.source,
+            ..builder.build()?
+        })
+    }
 }
 
 impl BackgroundBuilder {
@@ -25,35 +61,107 @@ impl BackgroundBuilder {
 }
 
 impl Background {
-    pub async fn load(pool: &sqlx::SqlitePool, id: uuid::Uuid) -> sqlx::Result<Background> {
-        sqlx::query_as!(
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: uuid::Uuid,
+    ) -> crate::Result<Background> {
+        Ok(sqlx::query_as!(
             Background,
             r#"
-                SELECT `background_id` AS "id: uuid::Uuid", `name`
+                SELECT `background_id` AS "id: uuid::Uuid"
+                    , `name`
+                    , `source_id` AS "source?: uuid::Uuid"
                 FROM `backgrounds`
                 WHERE `background_id` = ?1
                 LIMIT 1;
             "#,
             id
         )
-        .fetch_one(pool)
-        .await
+        .fetch_one(executor)
+        .await?)
     }
 
-    pub async fn save(self, pool: &sqlx::SqlitePool) -> sqlx::Result<()> {
-        let mut conn = pool.acquire().await?;
+    /// Finds the Background by name, passing over any from Sources not allowed.
+    pub async fn load_by_name(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        name: &str,
+        sources: Option<&SourceFilter>,
+    ) -> crate::Result<Background> {
+        let backgrounds = sqlx::query_as!(
+            Background,
+            r#"
+                SELECT `background_id` AS "id: uuid::Uuid"
+                    , `name`
+                    , `source_id` AS "source?: uuid::Uuid"
+                FROM `backgrounds`
+                WHERE `name` = ?1 COLLATE NOCASE;
+            "#,
+            name
+        )
+        .fetch_all(executor)
+        .await?;
 
-        let Background { id, name } = self;
+        filter_sources(backgrounds, sources, |background| background.source)
+            .into_iter()
+            .next()
+            .ok_or_else(|| crate::Error::not_found(format!("Background `{name}`")))
+    }
+
+    pub async fn list(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        sources: Option<&SourceFilter>,
+    ) -> crate::Result<Vec<Background>> {
+        let backgrounds = sqlx::query_as!(
+            Background,
+            r#"
+                SELECT `background_id` AS "id: uuid::Uuid"
+                    , `name`
+                    , `source_id` AS "source?: uuid::Uuid"
+                FROM `backgrounds`
+                ORDER BY `name`;
+            "#
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(filter_sources(backgrounds, sources, |background| {
+            background.source
+        }))
+    }
+
+    pub async fn delete(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: uuid::Uuid,
+    ) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `backgrounds`
+                WHERE `background_id` = ?1;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+
+        let Background { id, name, source } = self;
 
         sqlx::query!(
             r#"
-                INSERT INTO `backgrounds` VALUES
-                (?1, ?2)
+                INSERT INTO `backgrounds` (`background_id`, `name`, `source_id`)
+                VALUES (?1, ?2, ?3)
                 ON CONFLICT (`background_id`) DO UPDATE
-                SET `name` = ?2;
+                SET `name` = ?2
+                    , `source_id` = ?3;
             "#,
             id,
-            name
+            name,
+            source
         )
         .execute(&mut *conn)
         .await?;
@@ -61,8 +169,11 @@ impl Background {
         Ok(())
     }
 
-    pub async fn load_languages(&self, pool: &sqlx::SqlitePool) -> sqlx::Result<Vec<Language>> {
-        sqlx::query_as!(
+    pub async fn load_languages(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Vec<Language>> {
+        Ok(sqlx::query_as!(
             Language,
             r#"
                 SELECT l.`language_id` AS "id: uuid::Uuid"
@@ -75,14 +186,17 @@ impl Background {
             "#,
             self.id
         )
-        .fetch_all(pool)
-        .await
+        .fetch_all(executor)
+        .await?)
     }
 
-    pub async fn load_skills(&self, pool: &sqlx::SqlitePool) -> sqlx::Result<Vec<Skill>> {
+    pub async fn load_skills(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Vec<Skill>> {
         let Background { ref id, .. } = self;
 
-        sqlx::query_as!(
+        Ok(sqlx::query_as!(
             Skill,
             r#"
                 SELECT s.`skill_id` AS "id: uuid::Uuid"
@@ -96,14 +210,17 @@ impl Background {
             "#,
             id
         )
-        .fetch_all(pool)
-        .await
+        .fetch_all(executor)
+        .await?)
     }
 
-    pub async fn load_trades(&self, pool: &sqlx::SqlitePool) -> sqlx::Result<Vec<Trade>> {
+    pub async fn load_trades(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Vec<Trade>> {
         let Background { ref id, .. } = self;
 
-        sqlx::query_as!(
+        Ok(sqlx::query_as!(
             Trade,
             r#"
                 SELECT t.`trade_id` AS "id: uuid::Uuid"
@@ -116,8 +233,8 @@ impl Background {
             "#,
             id
         )
-        .fetch_all(pool)
-        .await
+        .fetch_all(executor)
+        .await?)
     }
 }
 
@@ -126,7 +243,7 @@ mod tests {
     #[test]
     #[ignore = "API changed"]
     fn _require_name_and_at_least_one_skill_trade_and_language_to_build_background(
-    ) -> anyhow::Result<()> {
+    ) -> crate::Result<()> {
         todo!()
     }
 }
@@ -1,12 +1,21 @@
+use serde::{Deserialize, Serialize};
 use turann::Builder;
 use uuid::Uuid;
 
 use crate::{
-    dc20::{Ancestry, AncestryTrait, Attributes, Background, Class, Subclass},
+    db::Db,
+    dc20::{
+        source::filter_sources, Ancestry, AncestryTrait, AttributeName, Attributes, Background,
+        CharacterCombatant, Class, ClassTable, Conditions, Encumbrance, HitResult, Inventory,
+        Maneuver, Mastery, Resources, Skill, SkillCheck, SourceFilter, Spell, SpellList, Subclass,
+    },
     player::Player,
 };
 
-#[derive(Builder, Clone, Debug, PartialEq)]
+/// The highest Level a Character can reach.
+pub const MAX_LEVEL: u32 = 10;
+
+#[derive(Builder, Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct Character {
     #[builder(default = Uuid::new_v4)]
     id: Uuid,
@@ -23,6 +32,11 @@ impl Character {
         &self.id
     }
 
+    #[must_use]
+    pub fn player(&self) -> &Player {
+        &self.player
+    }
+
     #[must_use]
     pub fn player_name(&self) -> &str {
         self.player.name()
@@ -38,8 +52,17 @@ impl Character {
         &self.background
     }
 
-    pub async fn load_level(&self, pool: &sqlx::SqlitePool, level: u32) -> sqlx::Result<Level> {
-        sqlx::query_as!(
+    #[must_use]
+    pub fn ancestry_traits(&self) -> &[AncestryTrait] {
+        &self.ancestry_traits
+    }
+
+    pub async fn load_level(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+        level: u32,
+    ) -> crate::Result<Level> {
+        Ok(sqlx::query_as!(
             Level,
             r#"
                 SELECT `character_level_id` AS "id: uuid::Uuid"
@@ -54,12 +77,84 @@ impl Character {
             self.id,
             level
         )
-        .fetch_one(pool)
-        .await
+        .fetch_one(executor)
+        .await?)
+    }
+
+    pub async fn load_combatant(
+        &self,
+        db: impl Db<'_>,
+        level: u32,
+    ) -> crate::Result<CharacterCombatant> {
+        let mut conn = db.connection().await?;
+
+        let level = self.load_level(&mut *conn, level).await?;
+        let attributes = level.load_base_attributes(&mut *conn).await?;
+        let inventory = self.load_inventory(&mut *conn).await?;
+
+        let mut combatant =
+            CharacterCombatant::new(self.character_name.clone(), level, attributes, inventory);
+        for condition in Conditions::load(&mut *conn, self.id).await?.iter() {
+            combatant.conditions.insert(*condition);
+        }
+
+        Ok(combatant)
+    }
+
+    pub async fn load_inventory(&self, db: impl Db<'_>) -> crate::Result<Inventory> {
+        let mut conn = db.connection().await?;
+
+        Inventory::load(&mut *conn, self.id).await
+    }
+
+    /// Gives a new Character their Class's starting equipment. Saving an
+    /// Inventory replaces the one stored, so this is only for [`Self::create`].
+    async fn seed_inventory(&self, db: impl Db<'_>, class: &Class) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+
+        let equipment = class.load_starting_equipment(&mut *conn).await?;
+        Inventory::from_starting_equipment(self.id, equipment)
+            .save(&mut *conn)
+            .await
     }
 
-    pub async fn load_max_level(&self, pool: &sqlx::SqlitePool) -> sqlx::Result<Level> {
-        sqlx::query_as!(
+    /// Saves a new Character with their first Level's base Attributes and
+    /// Class, and gives them that Class's starting equipment and full
+    /// resources, all at once.
+    pub async fn create(
+        self,
+        db: impl Db<'_>,
+        class: Option<&Class>,
+        attributes: &Attributes,
+    ) -> crate::Result<Level> {
+        let mut conn = db.connection().await?;
+        let mut tx = conn.begin().await?;
+
+        self.clone().save(&mut *tx).await?;
+        let first = self.load_level(&mut *tx, 1).await?;
+        first.save_base_attributes(&mut *tx, attributes).await?;
+        let table = match class {
+            Some(class) => {
+                first.save_class(&mut *tx, class).await?;
+                self.seed_inventory(&mut *tx, class).await?;
+                class.load_table(&mut *tx).await?
+            }
+            None => ClassTable::default(),
+        };
+        Resources::starting(&table, attributes)
+            .save(&mut *tx, self.id)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(first)
+    }
+
+    pub async fn load_max_level(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Level> {
+        Ok(sqlx::query_as!(
             Level,
             r#"
                 SELECT `character_level_id` AS "id: uuid::Uuid"
@@ -73,9 +168,415 @@ impl Character {
             "#,
             self.id
         )
-        .fetch_one(pool)
-        .await
+        .fetch_one(executor)
+        .await?)
     }
+
+    pub async fn load_levels(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Vec<Level>> {
+        Ok(sqlx::query_as!(
+            Level,
+            r#"
+                SELECT `character_level_id` AS "id: uuid::Uuid"
+                    , `character_id` AS "character_id: uuid::Uuid"
+                    , `level` AS "level: u32"
+                FROM `character_levels`
+                WHERE `character_id` = ?1
+                ORDER BY `level`
+                ;
+            "#,
+            self.id
+        )
+        .fetch_all(executor)
+        .await?)
+    }
+
+    /// Loads the Character with the Background and Ancestry Traits picked
+    /// across all of their Levels.
+    pub async fn load(db: impl Db<'_>, id: Uuid) -> crate::Result<Character> {
+        let mut conn = db.connection().await?;
+
+        let row = sqlx::query!(
+            r#"
+                SELECT `character_id` AS "id: Uuid"
+                    , `name`
+                    , `player_id` AS "player_id: Uuid"
+                FROM `characters`
+                WHERE `character_id` = ?1
+                LIMIT 1
+                ;
+            "#,
+            id
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        let background = sqlx::query_as!(
+            Background,
+            r#"
+                SELECT `background_id` AS "id: Uuid"
+                    , b.`name`
+                    , b.`source_id` AS "source?: Uuid"
+                FROM `backgrounds` AS b
+                JOIN `backgrounds_character_levels`
+                    USING (`background_id`)
+                JOIN `character_levels`
+                    USING (`character_level_id`)
+                WHERE `character_id` = ?1
+                ORDER BY `level`
+                LIMIT 1
+                ;
+            "#,
+            id
+        )
+        .fetch_optional(&mut *conn)
+        .await?
+        .ok_or_else(|| {
+            crate::Error::ContentReference(
+                format!("Character `{}` has no Background", row.name).into(),
+            )
+        })?;
+
+        let ancestry_traits = sqlx::query_as!(
+            AncestryTrait,
+            r#"
+                SELECT DISTINCT `ancestry_trait_id` AS "id: Uuid"
+                    , a_t.`name`
+                    , a_t.`description`
+                    , a_t.`cost` AS "cost: i8"
+                    , a_t.`source_id` AS "source?: Uuid"
+                FROM `ancestry_traits` AS a_t
+                JOIN `ancestry_traits_character_levels`
+                    USING (`ancestry_trait_id`)
+                JOIN `character_levels`
+                    USING (`character_level_id`)
+                WHERE `character_id` = ?1
+                ORDER BY a_t.`name`
+                ;
+            "#,
+            id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        Ok(Character {
+            id: row.id,
+            player: Player::load(&mut *conn, row.player_id).await?,
+            character_name: row.name,
+            ancestry_traits,
+            background,
+        })
+    }
+
+    pub async fn load_by_name(db: impl Db<'_>, name: &str) -> crate::Result<Character> {
+        let mut conn = db.connection().await?;
+
+        let row = sqlx::query!(
+            r#"
+                SELECT `character_id` AS "id: Uuid"
+                FROM `characters`
+                WHERE `name` = ?1 COLLATE NOCASE
+                LIMIT 1
+                ;
+            "#,
+            name
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Self::load(&mut *conn, row.id).await
+    }
+
+    pub async fn list(
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Vec<CharacterSummary>> {
+        Ok(sqlx::query_as!(
+            CharacterSummary,
+            r#"
+                SELECT c.`character_id` AS "id!: Uuid"
+                    , c.`name` AS "name!"
+                    , p.`name` AS "player_name!"
+                    , COALESCE(MAX(l.`level`), 0) AS "level!: u32"
+                FROM `characters` AS c
+                JOIN `players` AS p
+                    USING (`player_id`)
+                LEFT JOIN `character_levels` AS l
+                    USING (`character_id`)
+                GROUP BY c.`character_id`
+                ORDER BY c.`name`
+                ;
+            "#
+        )
+        .fetch_all(executor)
+        .await?)
+    }
+
+    /// Saves the Character, giving them their first Level if they have none
+    /// yet. The Background and Ancestry Traits are recorded against that
+    /// first Level, and Traits left out are dropped. The Player, Background
+    /// and Ancestry Traits must already be saved.
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+        let mut tx = conn.begin().await?;
+
+        let Character {
+            id,
+            player,
+            character_name,
+            ancestry_traits,
+            background,
+        } = self;
+
+        let player_id = *player.id();
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `characters` (`character_id`, `name`, `player_id`)
+                VALUES (?1, ?2, ?3)
+                ON CONFLICT (`character_id`) DO UPDATE
+                    SET `name` = ?2
+                    , `player_id` = ?3
+                ;
+            "#,
+            id,
+            character_name,
+            player_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let new_level_id = Uuid::now_v7();
+        sqlx::query!(
+            r#"
+                INSERT INTO `character_levels` (`character_level_id`, `character_id`, `level`)
+                VALUES (?1, ?2, 1)
+                ON CONFLICT (`character_id`, `level`) DO NOTHING
+                ;
+            "#,
+            new_level_id,
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let first_level_id = sqlx::query!(
+            r#"
+                SELECT `character_level_id` AS "id: Uuid"
+                FROM `character_levels`
+                WHERE `character_id` = ?1
+                ORDER BY `level`
+                LIMIT 1
+                ;
+            "#,
+            id
+        )
+        .fetch_one(&mut *tx)
+        .await?
+        .id;
+
+        sqlx::query!(
+            r#"
+                DELETE FROM `backgrounds_character_levels`
+                WHERE `character_level_id` = ?1
+                ;
+            "#,
+            first_level_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `backgrounds_character_levels` (`background_id`, `character_level_id`)
+                VALUES (?1, ?2)
+                ;
+            "#,
+            background.id,
+            first_level_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // Traits the Character no longer has are dropped from every Level,
+        // as loading gathers them from every Level
+        let linked = sqlx::query_scalar!(
+            r#"
+                SELECT DISTINCT `ancestry_trait_id` AS "id: Uuid"
+                FROM `ancestry_traits_character_levels`
+                JOIN `character_levels`
+                    USING (`character_level_id`)
+                WHERE `character_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        for dropped in linked
+            .into_iter()
+            .filter(|&linked| !ancestry_traits.iter().any(|kept| kept.id == linked))
+        {
+            sqlx::query!(
+                r#"
+                    DELETE FROM `ancestry_traits_character_levels`
+                    WHERE `ancestry_trait_id` = ?1
+                        AND `character_level_id` IN (
+                            SELECT `character_level_id`
+                            FROM `character_levels`
+                            WHERE `character_id` = ?2
+                        )
+                    ;
+                "#,
+                dropped,
+                id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for ancestry_trait in ancestry_traits {
+            sqlx::query!(
+                r#"
+                    INSERT INTO `ancestry_traits_character_levels`
+                        (`ancestry_trait_id`, `character_level_id`)
+                    SELECT ?1, ?2
+                    WHERE NOT EXISTS (
+                        SELECT 1
+                        FROM `ancestry_traits_character_levels`
+                        JOIN `character_levels`
+                            USING (`character_level_id`)
+                        WHERE `ancestry_trait_id` = ?1
+                            AND `character_id` = ?3
+                    )
+                    ;
+                "#,
+                ancestry_trait.id,
+                first_level_id,
+                id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        Ok(tx.commit().await?)
+    }
+
+    /// Deletes the Character along with everything recorded against them.
+    pub async fn delete(executor: impl sqlx::SqliteExecutor<'_>, id: Uuid) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `characters`
+                WHERE `character_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Adds the Character's next Level, carrying over their Attributes.
+    /// The new Level is taken in `class`, or in the same Classes as the
+    /// Level before it when no Class is given.
+    pub async fn level_up(&self, db: impl Db<'_>, class: Option<&Class>) -> crate::Result<Level> {
+        let mut conn = db.connection().await?;
+
+        let previous = self.load_max_level(&mut *conn).await?;
+
+        if previous.level >= MAX_LEVEL {
+            return Err(crate::Error::RuleViolation(
+                format!(
+                    "{} is already at the maximum Level of {MAX_LEVEL}",
+                    self.character_name
+                )
+                .into(),
+            ));
+        }
+
+        let level = Level {
+            id: Uuid::now_v7(),
+            character_id: self.id,
+            level: previous.level + 1,
+        };
+
+        let mut tx = conn.begin().await?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `character_levels` (`character_level_id`, `character_id`, `level`)
+                VALUES (?1, ?2, ?3)
+                ;
+            "#,
+            level.id,
+            level.character_id,
+            level.level
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `character_level_base_attribute_values`
+                    (`character_level_id`, `attribute_id`, `value`)
+                SELECT ?1, `attribute_id`, `value`
+                FROM `character_level_base_attribute_values`
+                WHERE `character_level_id` = ?2
+                ;
+            "#,
+            level.id,
+            previous.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        match class {
+            Some(class) => {
+                sqlx::query!(
+                    r#"
+                        INSERT INTO `character_levels_classes` (`character_level_id`, `class_id`)
+                        VALUES (?1, ?2)
+                        ;
+                    "#,
+                    level.id,
+                    class.id
+                )
+                .execute(&mut *tx)
+                .await?;
+            }
+            None => {
+                sqlx::query!(
+                    r#"
+                        INSERT INTO `character_levels_classes` (`character_level_id`, `class_id`)
+                        SELECT ?1, `class_id`
+                        FROM `character_levels_classes`
+                        WHERE `character_level_id` = ?2
+                        ;
+                    "#,
+                    level.id,
+                    previous.id
+                )
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+
+        tx.commit().await?;
+
+        Ok(level)
+    }
+}
+
+/// A Character as shown in a list: enough to pick one out.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CharacterSummary {
+    pub id: Uuid,
+    pub name: String,
+    pub player_name: String,
+    pub level: u32,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -85,7 +586,7 @@ pub struct BaseAttributeValue {
     pub value: i32,
 }
 
-#[derive(Builder, Clone, Debug, PartialEq, PartialOrd)]
+#[derive(Builder, Clone, Debug, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct Level {
     #[builder(default = uuid::Uuid::now_v7)]
     pub id: uuid::Uuid,
@@ -107,8 +608,11 @@ impl Level {
         level.div_ceil(2)
     }
 
-    pub async fn load(pool: &sqlx::SqlitePool, id: uuid::Uuid) -> sqlx::Result<Level> {
-        sqlx::query_as!(
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: uuid::Uuid,
+    ) -> crate::Result<Level> {
+        Ok(sqlx::query_as!(
             Level,
             r#"
                 SELECT `character_level_id` AS "id: uuid::Uuid"
@@ -120,12 +624,12 @@ impl Level {
             "#,
             id
         )
-        .fetch_one(pool)
-        .await
+        .fetch_one(executor)
+        .await?)
     }
 
-    pub async fn save(self, pool: &sqlx::SqlitePool) -> sqlx::Result<()> {
-        let mut conn = pool.acquire().await?;
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
 
         let Level {
             id,
@@ -152,8 +656,39 @@ impl Level {
         Ok(())
     }
 
-    pub async fn load_ancestries(&self, pool: &sqlx::SqlitePool) -> sqlx::Result<Vec<Ancestry>> {
-        sqlx::query_as!(
+    pub async fn delete(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: uuid::Uuid,
+    ) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `character_levels`
+                WHERE `character_level_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// How much the Character is carrying against what their Might allows.
+    pub async fn encumbrance(&self, db: impl Db<'_>) -> crate::Result<Encumbrance> {
+        let mut conn = db.connection().await?;
+
+        let attributes = self.load_base_attributes(&mut *conn).await?;
+        let inventory = Inventory::load(&mut *conn, self.character_id).await?;
+
+        Ok(Encumbrance::new(&attributes, &inventory))
+    }
+
+    pub async fn load_ancestries(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Vec<Ancestry>> {
+        Ok(sqlx::query_as!(
             Ancestry,
             r#"
                 SELECT `ancestry_id` AS "id: uuid::Uuid"
@@ -166,12 +701,73 @@ impl Level {
             "#,
             self.id
         )
-        .fetch_all(pool)
-        .await
+        .fetch_all(executor)
+        .await?)
+    }
+
+    pub async fn load_ancestry_traits(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+        sources: Option<&SourceFilter>,
+    ) -> crate::Result<Vec<AncestryTrait>> {
+        let ancestry_traits = sqlx::query_as!(
+            AncestryTrait,
+            r#"
+                SELECT `ancestry_trait_id` AS "id: uuid::Uuid"
+                    , `name`
+                    , `description`
+                    , `cost` AS "cost: i8"
+                    , `source_id` AS "source?: uuid::Uuid"
+                FROM `ancestry_traits`
+                JOIN `ancestry_traits_character_levels`
+                    USING (`ancestry_trait_id`)
+                WHERE `character_level_id` = ?1
+                ORDER BY `name`
+                ;
+            "#,
+            self.id
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(filter_sources(ancestry_traits, sources, |ancestry_trait| {
+            ancestry_trait.source
+        }))
+    }
+
+    /// The Backgrounds chosen at this Level, which is usually only the first.
+    pub async fn load_backgrounds(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+        sources: Option<&SourceFilter>,
+    ) -> crate::Result<Vec<Background>> {
+        let backgrounds = sqlx::query_as!(
+            Background,
+            r#"
+                SELECT `background_id` AS "id: uuid::Uuid"
+                    , `name`
+                    , `source_id` AS "source?: uuid::Uuid"
+                FROM `backgrounds`
+                JOIN `backgrounds_character_levels`
+                    USING (`background_id`)
+                WHERE `character_level_id` = ?1
+                ;
+            "#,
+            self.id
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(filter_sources(backgrounds, sources, |background| {
+            background.source
+        }))
     }
 
-    pub async fn load_base_attributes(&self, pool: &sqlx::SqlitePool) -> sqlx::Result<Attributes> {
-        sqlx::query_as!(
+    pub async fn load_base_attributes(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Attributes> {
+        Ok(sqlx::query_as!(
             Attributes,
             r#"
                 SELECT `prime`
@@ -184,16 +780,83 @@ impl Level {
             "#,
             self.id
         )
-        .fetch_one(pool)
-        .await
+        .fetch_one(executor)
+        .await?)
+    }
+
+    /// Records the Level's base Attribute values, replacing any already saved.
+    pub async fn save_base_attributes(
+        &self,
+        db: impl Db<'_>,
+        attributes: &Attributes,
+    ) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+        let mut tx = conn.begin().await?;
+
+        for name in [
+            AttributeName::Prime,
+            AttributeName::Might,
+            AttributeName::Agility,
+            AttributeName::Charisma,
+            AttributeName::Intelligence,
+        ] {
+            let attribute_name = name.to_string();
+            let value = attributes.get(name);
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO `character_level_base_attribute_values`
+                        (`character_level_id`, `attribute_id`, `value`)
+                    SELECT ?1, `attribute_id`, ?3
+                    FROM `attributes`
+                    WHERE `name` = ?2
+                    ON CONFLICT (`character_level_id`, `attribute_id`) DO UPDATE
+                        SET `value` = ?3
+                    ;
+                "#,
+                self.id,
+                attribute_name,
+                value
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        Ok(tx.commit().await?)
+    }
+
+    pub async fn save_class(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+        class: &Class,
+    ) -> crate::Result<()> {
+        sqlx::query!(
+            r#"
+                INSERT INTO `character_levels_classes` (`character_level_id`, `class_id`)
+                VALUES (?1, ?2)
+                ON CONFLICT (`character_level_id`, `class_id`) DO NOTHING
+                ;
+            "#,
+            self.id,
+            class.id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
     }
 
-    pub async fn load_classes(&self, pool: &sqlx::SqlitePool) -> sqlx::Result<Vec<Class>> {
-        sqlx::query_as!(
+    pub async fn load_classes(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+        sources: Option<&SourceFilter>,
+    ) -> crate::Result<Vec<Class>> {
+        let classes = sqlx::query_as!(
             Class,
             r#"
                 SELECT `class_id` AS "id: uuid::Uuid"
                     , `name`
+                    , `source_id` AS "source?: uuid::Uuid"
                 FROM `classes`
                 JOIN `character_levels_classes`
                     USING (`class_id`)
@@ -202,12 +865,17 @@ impl Level {
             "#,
             self.id
         )
-        .fetch_all(pool)
-        .await
+        .fetch_all(executor)
+        .await?;
+
+        Ok(filter_sources(classes, sources, |class| class.source))
     }
 
-    pub async fn load_sublasses(&self, pool: &sqlx::SqlitePool) -> sqlx::Result<Vec<Subclass>> {
-        sqlx::query_as!(
+    pub async fn load_sublasses(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Vec<Subclass>> {
+        Ok(sqlx::query_as!(
             Subclass,
             r#"
                 SELECT `subclass_id` AS "id: uuid::Uuid"
@@ -220,8 +888,351 @@ impl Level {
             "#,
             self.id
         )
-        .fetch_all(pool)
-        .await
+        .fetch_all(executor)
+        .await?)
+    }
+
+    /// The Maneuvers the Character learned at this Level.
+    pub async fn load_maneuvers(&self, db: impl Db<'_>) -> crate::Result<Vec<Maneuver>> {
+        let mut conn = db.connection().await?;
+
+        let rows = sqlx::query!(
+            r#"
+                SELECT `maneuver_id` AS "id: uuid::Uuid"
+                FROM `character_levels_maneuvers`
+                WHERE `character_level_id` = ?1
+                ;
+            "#,
+            self.id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut maneuvers = vec![];
+
+        for row in rows {
+            maneuvers.push(Maneuver::load(&mut *conn, row.id).await?);
+        }
+
+        maneuvers.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(maneuvers)
+    }
+
+    pub async fn save_maneuver(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+        maneuver: &Maneuver,
+    ) -> crate::Result<()> {
+        sqlx::query!(
+            r#"
+                INSERT INTO `character_levels_maneuvers` (`character_level_id`, `maneuver_id`)
+                VALUES (?1, ?2)
+                ON CONFLICT (`character_level_id`, `maneuver_id`) DO NOTHING
+                ;
+            "#,
+            self.id,
+            maneuver.uuid
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Every Skill, with the highest Mastery the Character has raised it to
+    /// by this Level.
+    pub async fn load_skill_checks(&self, db: impl Db<'_>) -> crate::Result<Vec<SkillCheck>> {
+        let mut conn = db.connection().await?;
+
+        let rows = sqlx::query!(
+            r#"
+                SELECT s.`skill_id` AS "id: uuid::Uuid"
+                    , s.`name`
+                    , s.`attribute_id` AS "attribute_id: uuid::Uuid"
+                    , a.`name` AS attribute_name
+                    , (
+                        SELECT c_l_s.`mastery_id`
+                        FROM `character_levels_skills` AS c_l_s
+                        JOIN `character_levels` AS c_l
+                            USING (`character_level_id`)
+                        JOIN `masteries` AS m
+                            USING (`mastery_id`)
+                        WHERE c_l.`character_id` = ?1
+                            AND c_l.`level` <= ?2
+                            AND c_l_s.`skill_id` = s.`skill_id`
+                        ORDER BY m.`bonus` DESC
+                        LIMIT 1
+                    ) AS "mastery_id?: uuid::Uuid"
+                FROM `skills` AS s
+                JOIN `attributes` AS a
+                    USING (`attribute_id`)
+                ORDER BY s.`name`
+                ;
+            "#,
+            self.character_id,
+            self.level
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut checks = vec![];
+
+        for row in rows {
+            let mastery = match row.mastery_id {
+                Some(id) => Some(Mastery::load(&mut *conn, id).await?),
+                None => None,
+            };
+            checks.push(SkillCheck {
+                skill: Skill {
+                    id: row.id,
+                    name: row.name,
+                    attribute_id: row.attribute_id,
+                },
+                attribute: row
+                    .attribute_name
+                    .parse()
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+                mastery,
+            });
+        }
+
+        Ok(checks)
+    }
+
+    /// The Skills raised at this Level, and the Mastery each was raised to.
+    pub async fn load_skill_masteries(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Vec<(Skill, Mastery)>> {
+        let rows = sqlx::query!(
+            r#"
+                SELECT s.`skill_id` AS "skill_id: uuid::Uuid"
+                    , s.`name` AS skill_name
+                    , s.`attribute_id` AS "attribute_id: uuid::Uuid"
+                    , m.`mastery_id` AS "mastery_id: uuid::Uuid"
+                    , m.`name` AS mastery_name
+                    , m.`bonus` AS "bonus: u8"
+                FROM `character_levels_skills` AS c_l_s
+                JOIN `skills` AS s
+                    USING (`skill_id`)
+                JOIN `masteries` AS m
+                    USING (`mastery_id`)
+                WHERE c_l_s.`character_level_id` = ?1
+                ORDER BY s.`name`
+                ;
+            "#,
+            self.id
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                (
+                    Skill {
+                        id: row.skill_id,
+                        name: row.skill_name,
+                        attribute_id: row.attribute_id,
+                    },
+                    Mastery {
+                        id: row.mastery_id,
+                        name: row.mastery_name,
+                        bonus: row.bonus,
+                    },
+                )
+            })
+            .collect())
+    }
+
+    /// Raises a Skill to `mastery` at this Level.
+    pub async fn save_skill_mastery(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+        skill: &Skill,
+        mastery: &Mastery,
+    ) -> crate::Result<()> {
+        sqlx::query!(
+            r#"
+                INSERT INTO `character_levels_skills`
+                    (`character_level_id`, `skill_id`, `mastery_id`)
+                VALUES (?1, ?2, ?3)
+                ON CONFLICT (`character_level_id`, `skill_id`) DO UPDATE
+                    SET `mastery_id` = ?3
+                ;
+            "#,
+            self.id,
+            skill.id,
+            mastery.id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// The Spell Lists the Character can learn Spells from at this Level,
+    /// holding only the Spells `sources` allows.
+    pub async fn load_spell_lists(
+        &self,
+        db: impl Db<'_>,
+        sources: Option<&SourceFilter>,
+    ) -> crate::Result<Vec<SpellList>> {
+        let mut conn = db.connection().await?;
+
+        let rows = sqlx::query!(
+            r#"
+                SELECT `spell_list_id` AS "id: uuid::Uuid"
+                FROM `character_levels_spell_lists`
+                WHERE `character_level_id` = ?1
+                ;
+            "#,
+            self.id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut spell_lists = vec![];
+
+        for row in rows {
+            let mut spell_list = SpellList::load(&mut *conn, row.id).await?;
+            spell_list.spells = filter_sources(spell_list.spells, sources, |spell| spell.source);
+            spell_lists.push(spell_list);
+        }
+
+        Ok(spell_lists)
+    }
+
+    /// Gives the Character `spell_list` to learn Spells from at this Level.
+    pub async fn save_spell_list(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+        spell_list: &SpellList,
+    ) -> crate::Result<()> {
+        sqlx::query!(
+            r#"
+                INSERT INTO `character_levels_spell_lists` (`character_level_id`, `spell_list_id`)
+                VALUES (?1, ?2)
+                ON CONFLICT (`character_level_id`, `spell_list_id`) DO NOTHING
+                ;
+            "#,
+            self.id,
+            spell_list.id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// The Spells the Character learned at this Level.
+    pub async fn load_spells(&self, db: impl Db<'_>) -> crate::Result<Vec<Spell>> {
+        let mut conn = db.connection().await?;
+
+        let ids = sqlx::query_scalar!(
+            r#"
+                SELECT `spell_id` AS "id: uuid::Uuid"
+                FROM `character_levels_spells`
+                WHERE `character_level_id` = ?1
+                ;
+            "#,
+            self.id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut spells = vec![];
+        for id in ids {
+            spells.push(Spell::load(&mut *conn, id).await?);
+        }
+
+        spells.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(spells)
+    }
+
+    /// Every Spell the Character knows by this Level.
+    pub async fn load_known_spells(&self, db: impl Db<'_>) -> crate::Result<Vec<Spell>> {
+        let mut conn = db.connection().await?;
+
+        let ids = sqlx::query_scalar!(
+            r#"
+                SELECT DISTINCT c_l_s.`spell_id` AS "id: uuid::Uuid"
+                FROM `character_levels_spells` AS c_l_s
+                JOIN `character_levels` AS c_l
+                    USING (`character_level_id`)
+                WHERE c_l.`character_id` = ?1
+                    AND c_l.`level` <= ?2
+                ;
+            "#,
+            self.character_id,
+            self.level
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut spells = vec![];
+        for id in ids {
+            spells.push(Spell::load(&mut *conn, id).await?);
+        }
+
+        spells.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(spells)
+    }
+
+    /// Learns `spell` at this Level. It has to be on one of the Spell Lists
+    /// the Character has by this Level.
+    pub async fn learn_spell(&self, db: impl Db<'_>, spell: &Spell) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+
+        let listed = sqlx::query_scalar!(
+            r#"
+                SELECT EXISTS (
+                    SELECT 1
+                    FROM `character_levels_spell_lists` AS c_l_s_l
+                    JOIN `character_levels` AS c_l
+                        USING (`character_level_id`)
+                    JOIN `spells_spell_lists` AS s_s_l
+                        USING (`spell_list_id`)
+                    WHERE c_l.`character_id` = ?1
+                        AND c_l.`level` <= ?2
+                        AND s_s_l.`spell_id` = ?3
+                ) AS "listed!: bool"
+                ;
+            "#,
+            self.character_id,
+            self.level,
+            spell.id
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        if !listed {
+            return Err(crate::Error::RuleViolation(
+                format!(
+                    "{} is not on any of the Character's Spell Lists by Level {}",
+                    spell.name, self.level
+                )
+                .into(),
+            ));
+        }
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `character_levels_spells` (`character_level_id`, `spell_id`)
+                VALUES (?1, ?2)
+                ON CONFLICT (`character_level_id`, `spell_id`) DO NOTHING
+                ;
+            "#,
+            self.id,
+            spell.id
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
     }
 }
 
@@ -235,7 +1246,7 @@ impl Default for Level {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
 pub struct Defense {
     pub score: usize,
     pub reduction: usize,
@@ -251,6 +1262,16 @@ impl Defense {
     pub fn brutal(&self) -> usize {
         self.score + 10
     }
+
+    #[must_use]
+    pub fn resolve(&self, check: i64) -> HitResult {
+        match check {
+            check if check >= self.brutal() as i64 => HitResult::BrutalHit,
+            check if check >= self.heavy() as i64 => HitResult::HeavyHit,
+            check if check >= self.score as i64 => HitResult::Hit,
+            _ => HitResult::Miss,
+        }
+    }
 }
 
 #[cfg(test)]
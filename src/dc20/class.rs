@@ -1,13 +1,50 @@
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::{Item, Level, Maneuver, SpellList};
+use super::{
+    source::filter_sources, AttributeName, Item, Level, Maneuver, SourceFilter, SpellList,
+};
 
-#[derive(turann::Builder, Clone, Debug)]
+use crate::db::Db;
+
+#[derive(turann::Builder, Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "ClassFields")]
 pub struct Class {
     #[builder(default = uuid::Uuid::now_v7)]
     pub id: uuid::Uuid,
     #[builder(validate = ClassBuilder::validate_name)]
     pub name: String,
+    /// The book the Class comes from, when known.
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<uuid::Uuid>,
+}
+
+/// A [`Class`] as it comes in, built up through its builder so that it is
+/// checked the same way.
+#[derive(Deserialize)]
+struct ClassFields {
+    id: Option<Uuid>,
+    name: String,
+    #[serde(default)]
+    source: Option<Uuid>,
+}
+
+impl TryFrom<ClassFields> for Class {
+    type Error = ClassBuilderError;
+
+    fn try_from(fields: ClassFields) -> Result<Self, Self::Error> {
+        let mut builder = Class::builder();
+        builder.name(fields.name)?;
+        if let Some(id) = fields.id {
+            builder.id(id);
+        }
+
+        Ok(Class {
+            source: fields.source,
+            ..builder.build()?
+        })
+    }
 }
 
 impl ClassBuilder {
@@ -26,8 +63,122 @@ impl ClassBuilder {
 }
 
 impl Class {
-    pub async fn load_sublasses(&self, pool: &sqlx::SqlitePool) -> sqlx::Result<Vec<Subclass>> {
-        sqlx::query_as!(
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: uuid::Uuid,
+    ) -> crate::Result<Class> {
+        Ok(sqlx::query_as!(
+            Class,
+            r#"
+                SELECT `class_id` AS "id: uuid::Uuid"
+                    , `name`
+                    , `source_id` AS "source?: uuid::Uuid"
+                FROM `classes`
+                WHERE `class_id` = ?1
+                LIMIT 1
+                ;
+            "#,
+            id
+        )
+        .fetch_one(executor)
+        .await?)
+    }
+
+    /// Finds the Class by name, passing over any from Sources not allowed.
+    pub async fn load_by_name(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        name: &str,
+        sources: Option<&SourceFilter>,
+    ) -> crate::Result<Class> {
+        let classes = sqlx::query_as!(
+            Class,
+            r#"
+                SELECT `class_id` AS "id: uuid::Uuid"
+                    , `name`
+                    , `source_id` AS "source?: uuid::Uuid"
+                FROM `classes`
+                WHERE `name` = ?1 COLLATE NOCASE
+                ;
+            "#,
+            name
+        )
+        .fetch_all(executor)
+        .await?;
+
+        filter_sources(classes, sources, |class| class.source)
+            .into_iter()
+            .next()
+            .ok_or_else(|| crate::Error::not_found(format!("Class `{name}`")))
+    }
+
+    pub async fn list(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        sources: Option<&SourceFilter>,
+    ) -> crate::Result<Vec<Class>> {
+        let classes = sqlx::query_as!(
+            Class,
+            r#"
+                SELECT `class_id` AS "id: uuid::Uuid"
+                    , `name`
+                    , `source_id` AS "source?: uuid::Uuid"
+                FROM `classes`
+                ORDER BY `name`
+                ;
+            "#
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(filter_sources(classes, sources, |class| class.source))
+    }
+
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+
+        let Class { id, name, source } = self;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `classes` (`class_id`, `name`, `source_id`)
+                VALUES (?1, ?2, ?3)
+                ON CONFLICT (`class_id`) DO UPDATE
+                    SET `name` = ?2
+                    , `source_id` = ?3
+                ;
+            "#,
+            id,
+            name,
+            source
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: uuid::Uuid,
+    ) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `classes`
+                WHERE `class_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn load_sublasses(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Vec<Subclass>> {
+        Ok(sqlx::query_as!(
             Subclass,
             r#"
                 SELECT `subclass_id` AS "id: uuid::Uuid"
@@ -40,12 +191,154 @@ impl Class {
             "#,
             self.id
         )
-        .fetch_all(pool)
-        .await
+        .fetch_all(executor)
+        .await?)
+    }
+
+    /// The Class Features in the order they are gained, each with the level
+    /// it is gained at.
+    pub async fn load_features(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Vec<(u8, ClassFeature)>> {
+        let rows = sqlx::query!(
+            r#"
+                SELECT `class_feature_id` AS "id: uuid::Uuid"
+                    , `level` AS "level: u8"
+                    , `name`
+                    , `description`
+                FROM `class_features`
+                WHERE `class_id` = ?1
+                ORDER BY `level`, `class_feature_id`
+                ;
+            "#,
+            self.id
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let feature = ClassFeature {
+                    uuid: row.id,
+                    name: row.name,
+                    description: row.description,
+                };
+                (row.level, feature)
+            })
+            .collect())
+    }
+
+    /// The Class Table, with nothing gained at the levels it has no row for.
+    pub async fn load_table(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<ClassTable> {
+        let rows = sqlx::query!(
+            r#"
+                SELECT `level` AS "level: u32"
+                    , `health_points` AS "health_points: u32"
+                    , `attribute_points` AS "attribute_points: u32"
+                    , `skill_points` AS "skill_points: u32"
+                    , `trade_points` AS "trade_points: u32"
+                    , `stamina_points` AS "stamina_points: u32"
+                    , `maneuvers_known` AS "maneuvers_known: u32"
+                    , `techniques_known` AS "techniques_known: u32"
+                    , `mana_points` AS "mana_points: u32"
+                    , `cantrips_known` AS "cantrips_known: u32"
+                    , `spells_known` AS "spells_known: u32"
+                FROM `class_table_rows`
+                WHERE `class_id` = ?1
+                ;
+            "#,
+            self.id
+        )
+        .fetch_all(executor)
+        .await?;
+
+        let mut table = ClassTable::default();
+        for row in rows {
+            let i = row.level as usize - 1;
+            table.health_points.0[i] = row.health_points as usize;
+            table.attribute_points.0[i] = row.attribute_points as usize;
+            table.skill_points.0[i] = row.skill_points as usize;
+            table.trade_points.0[i] = row.trade_points as usize;
+            table.stamina_points.0[i] = row.stamina_points as usize;
+            table.maneuvers_known.0[i] = row.maneuvers_known as usize;
+            table.techniques_known.0[i] = row.techniques_known as usize;
+            table.mana_points.0[i] = row.mana_points as usize;
+            table.cantrips_known.0[i] = row.cantrips_known as usize;
+            table.spells_known.0[i] = row.spells_known as usize;
+        }
+
+        Ok(table)
+    }
+
+    /// What a new Character of the Class starts out carrying, and how many
+    /// of each.
+    pub async fn load_starting_equipment(
+        &self,
+        db: impl Db<'_>,
+    ) -> crate::Result<Vec<(Item, u64)>> {
+        let mut conn = db.connection().await?;
+
+        let rows = sqlx::query!(
+            r#"
+                SELECT `item_id` AS "item_id: uuid::Uuid"
+                    , `quantity`
+                FROM `class_starting_equipment`
+                WHERE `class_id` = ?1
+                ORDER BY `item_id`
+                ;
+            "#,
+            self.id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut equipment = vec![];
+        for row in rows {
+            equipment.push((
+                Item::load(&mut *conn, row.item_id).await?,
+                row.quantity as u64,
+            ));
+        }
+
+        Ok(equipment)
+    }
+
+    /// The Attribute Saves the Class gives Mastery in to the Characters who
+    /// start in it.
+    pub async fn load_save_masteries(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Vec<AttributeName>> {
+        let names = sqlx::query_scalar!(
+            r#"
+                SELECT a.`name`
+                FROM `attributes_classes` AS a_c
+                JOIN `attributes` AS a
+                    USING (`attribute_id`)
+                WHERE a_c.`class_id` = ?1
+                ORDER BY a.`name`
+                ;
+            "#,
+            self.id
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(names
+            .into_iter()
+            .map(|name| name.parse())
+            .collect::<Result<_, _>>()
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))?)
     }
 }
 
-#[derive(turann::Builder, Clone, Debug)]
+#[derive(turann::Builder, Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "SubclassFields")]
 pub struct Subclass {
     #[builder(default = uuid::Uuid::now_v7)]
     pub id: uuid::Uuid,
@@ -53,6 +346,28 @@ pub struct Subclass {
     pub name: String,
 }
 
+/// A [`Subclass`] as it comes in, built up through its builder so that it is
+/// checked the same way.
+#[derive(Deserialize)]
+struct SubclassFields {
+    id: Option<Uuid>,
+    name: String,
+}
+
+impl TryFrom<SubclassFields> for Subclass {
+    type Error = SubclassBuilderError;
+
+    fn try_from(fields: SubclassFields) -> Result<Self, Self::Error> {
+        let mut builder = Subclass::builder();
+        builder.name(fields.name)?;
+        if let Some(id) = fields.id {
+            builder.id(id);
+        }
+
+        builder.build()
+    }
+}
+
 impl SubclassBuilder {
     fn validate_name(name: impl Into<String>) -> Result<String, SubclassBuilderError> {
         let name: String = name.into();
@@ -68,13 +383,62 @@ impl SubclassBuilder {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+impl Subclass {
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: uuid::Uuid,
+    ) -> crate::Result<Subclass> {
+        Ok(sqlx::query_as!(
+            Subclass,
+            r#"
+                SELECT `subclass_id` AS "id: uuid::Uuid"
+                    , `name`
+                FROM `subclasses`
+                WHERE `subclass_id` = ?1
+                LIMIT 1
+                ;
+            "#,
+            id
+        )
+        .fetch_one(executor)
+        .await?)
+    }
+
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+
+        let Subclass { id, name } = self;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `subclasses` (`subclass_id`, `name`)
+                VALUES (?1, ?2)
+                ON CONFLICT (`subclass_id`) DO UPDATE
+                    SET `name` = ?2
+                ;
+            "#,
+            id,
+            name
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct ClassTableColumn(pub [usize; 10]);
 
 impl ClassTableColumn {
     pub fn get(&self, level: usize) -> usize {
         self.0[level - 1]
     }
+
+    /// Everything gained from level 1 up to and including `level`.
+    pub fn total(&self, level: usize) -> usize {
+        self.0[..level].iter().sum()
+    }
 }
 
 impl From<[usize; 10]> for ClassTableColumn {
@@ -83,6 +447,21 @@ impl From<[usize; 10]> for ClassTableColumn {
     }
 }
 
+/// The Class Table from the book: what is gained on reaching each level.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClassTable {
+    pub health_points: ClassTableColumn,
+    pub attribute_points: ClassTableColumn,
+    pub skill_points: ClassTableColumn,
+    pub trade_points: ClassTableColumn,
+    pub stamina_points: ClassTableColumn,
+    pub maneuvers_known: ClassTableColumn,
+    pub techniques_known: ClassTableColumn,
+    pub mana_points: ClassTableColumn,
+    pub cantrips_known: ClassTableColumn,
+    pub spells_known: ClassTableColumn,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum CombatStyle {
     Martial {
@@ -0,0 +1,557 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::dc20::{
+    item::weapon::{DamageType, Weapon},
+    roll::{roll_check, CheckRoll, RollMode},
+    AttributeName, Attributes, Conditions, Defense, Encumbrance, Inventory, Level, BASE_SPEED,
+};
+
+static NO_CONDITIONS: Conditions = Conditions::new();
+
+/// Anything that can make and receive attacks: player Characters and
+/// Creatures share the same rolls and resolution rules.
+pub trait Combatant {
+    fn name(&self) -> &str;
+
+    fn combat_mastery(&self) -> usize;
+
+    fn attributes(&self) -> &Attributes;
+
+    fn attacks(&self) -> &[Attack];
+
+    fn resistances(&self) -> &[Resistance] {
+        &[]
+    }
+
+    fn conditions(&self) -> &Conditions {
+        &NO_CONDITIONS
+    }
+
+    fn speed(&self) -> u64 {
+        self.conditions().speed(BASE_SPEED)
+    }
+
+    /// The ADV or DisADV imposed on Checks using `attribute`.
+    fn check_mode(&self, attribute: AttributeName) -> RollMode {
+        self.conditions().check_mode(attribute)
+    }
+
+    fn precision_defense(&self) -> Defense {
+        self.attributes().precision_defense(self.combat_mastery())
+    }
+
+    fn area_defense(&self) -> Defense {
+        self.attributes().area_defense(self.combat_mastery())
+    }
+
+    fn attack_check_bonus(&self) -> i64 {
+        self.attributes().prime() + self.combat_mastery() as i64
+    }
+
+    fn check_bonus(&self, attribute: AttributeName) -> i64 {
+        self.attributes().get(attribute)
+    }
+
+    fn roll_attack_check<R: Rng + ?Sized>(&self, rng: &mut R, mode: RollMode) -> CheckRoll
+    where
+        Self: Sized,
+    {
+        let mode = mode.combine(self.conditions().attack_mode());
+
+        roll_check(rng, self.attack_check_bonus(), mode)
+    }
+
+    fn roll_check<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        attribute: AttributeName,
+        mode: RollMode,
+    ) -> CheckRoll
+    where
+        Self: Sized,
+    {
+        let mode = mode.combine(self.check_mode(attribute));
+
+        roll_check(rng, self.check_bonus(attribute), mode)
+    }
+
+    /// Applies the relevant reductions and resistances to incoming damage.
+    fn mitigate(&self, damage: u64, damage_type: DamageType, defense: Defense) -> u64 {
+        let damage = if damage_type.is_physical() {
+            damage.saturating_sub(defense.reduction as u64)
+        } else {
+            damage
+        };
+
+        mitigate(self.resistances(), damage, damage_type)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+pub enum HitResult {
+    Miss,
+    Hit,
+    HeavyHit,
+    BrutalHit,
+}
+
+impl HitResult {
+    #[must_use]
+    pub fn bonus_damage(self) -> u64 {
+        match self {
+            HitResult::Miss | HitResult::Hit => 0,
+            HitResult::HeavyHit => 1,
+            HitResult::BrutalHit => 2,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Attack {
+    pub id: Uuid,
+    pub name: String,
+    pub weapon: Weapon,
+    pub damage: u64,
+}
+
+impl Attack {
+    #[must_use]
+    pub fn from_weapon(name: impl Into<String>, weapon: Weapon) -> Self {
+        Self {
+            id: Uuid::now_v7(),
+            name: name.into(),
+            damage: weapon.base_damage(),
+            weapon,
+        }
+    }
+
+    #[must_use]
+    pub fn damage_type(&self) -> DamageType {
+        self.weapon.damage_type
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttackOutcome {
+    pub roll: CheckRoll,
+    pub hit: HitResult,
+    pub damage: u64,
+}
+
+impl AttackOutcome {
+    #[must_use]
+    pub fn is_critical(&self) -> bool {
+        self.roll.is_critical_success()
+    }
+}
+
+/// Resolves an already rolled Attack Check against the target's PD.
+///
+/// A natural 1 always misses, while a natural 20 always hits and deals
+/// +2 damage on top of any Heavy or Brutal bonus.
+pub fn resolve_attack(roll: CheckRoll, attack: &Attack, target: &impl Combatant) -> AttackOutcome {
+    let defense = target.precision_defense();
+
+    let hit = if roll.is_critical_failure() {
+        HitResult::Miss
+    } else if roll.is_critical_success() {
+        defense.resolve(roll.total()).max(HitResult::Hit)
+    } else {
+        defense.resolve(roll.total())
+    };
+
+    let damage = if hit == HitResult::Miss {
+        0
+    } else {
+        let critical_bonus = if roll.is_critical_success() { 2 } else { 0 };
+        let raw = attack.damage + hit.bonus_damage() + critical_bonus;
+
+        target.mitigate(raw, attack.damage_type(), defense)
+    };
+
+    AttackOutcome { roll, hit, damage }
+}
+
+pub fn roll_attack<R: Rng + ?Sized>(
+    rng: &mut R,
+    attacker: &impl Combatant,
+    attack: &Attack,
+    target: &impl Combatant,
+    mode: RollMode,
+) -> AttackOutcome {
+    let mode = mode.combine(target.conditions().targeted_mode());
+
+    resolve_attack(attacker.roll_attack_check(rng, mode), attack, target)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ResistanceKind {
+    /// Resistance (X): reduce the damage taken by X.
+    Resistance(u64),
+    /// Resistance (Half): halve the damage taken, rounding down.
+    Half,
+    Immunity,
+    /// Vulnerability (X): increase the damage taken by X.
+    Vulnerability(u64),
+    /// Vulnerability (Double): double the damage taken.
+    Double,
+}
+
+impl ResistanceKind {
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            ResistanceKind::Resistance(_) => "Resistance",
+            ResistanceKind::Half => "Half",
+            ResistanceKind::Immunity => "Immunity",
+            ResistanceKind::Vulnerability(_) => "Vulnerability",
+            ResistanceKind::Double => "Double",
+        }
+    }
+
+    #[must_use]
+    pub fn value(&self) -> Option<u64> {
+        match self {
+            ResistanceKind::Resistance(value) | ResistanceKind::Vulnerability(value) => {
+                Some(*value)
+            }
+            ResistanceKind::Half | ResistanceKind::Immunity | ResistanceKind::Double => None,
+        }
+    }
+
+    pub fn parse(name: &str, value: Option<u64>) -> Result<Self, ResistanceParseError> {
+        match (name, value) {
+            ("Resistance", Some(value)) => Ok(ResistanceKind::Resistance(value)),
+            ("Half", None) => Ok(ResistanceKind::Half),
+            ("Immunity", None) => Ok(ResistanceKind::Immunity),
+            ("Vulnerability", Some(value)) => Ok(ResistanceKind::Vulnerability(value)),
+            ("Double", None) => Ok(ResistanceKind::Double),
+            _ => Err(ResistanceParseError(name.into())),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Resistance {
+    pub damage_type: DamageType,
+    pub kind: ResistanceKind,
+}
+
+impl fmt::Display for Resistance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ResistanceKind::Resistance(value) => {
+                write!(f, "{} Resistance ({value})", self.damage_type)
+            }
+            ResistanceKind::Half => write!(f, "{} Resistance (Half)", self.damage_type),
+            ResistanceKind::Immunity => write!(f, "{} Immunity", self.damage_type),
+            ResistanceKind::Vulnerability(value) => {
+                write!(f, "{} Vulnerability ({value})", self.damage_type)
+            }
+            ResistanceKind::Double => write!(f, "{} Vulnerability (Double)", self.damage_type),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResistanceParseError(pub String);
+
+impl fmt::Display for ResistanceParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unable to parse Resistance: `{}`", self.0)
+    }
+}
+
+impl Error for ResistanceParseError {}
+
+impl FromStr for Resistance {
+    type Err = ResistanceParseError;
+
+    /// Parses the `Display` form, e.g. "Fire Resistance (Half)",
+    /// "Cold Resistance (1)", "Poison Immunity" or
+    /// "Radiant Vulnerability (Double)".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ResistanceParseError(s.into());
+
+        let (head, amount) = match s.trim().split_once('(') {
+            Some((head, rest)) => (
+                head.trim(),
+                Some(rest.strip_suffix(')').ok_or_else(error)?.trim()),
+            ),
+            None => (s.trim(), None),
+        };
+
+        let (damage_type, kind) = head.split_once(' ').ok_or_else(error)?;
+        let damage_type = damage_type.parse().map_err(|_| error())?;
+
+        let kind = match (kind.trim(), amount) {
+            ("Resistance", Some("Half")) => ResistanceKind::Half,
+            ("Resistance", Some(value)) => {
+                ResistanceKind::Resistance(value.parse().map_err(|_| error())?)
+            }
+            ("Immunity", None) => ResistanceKind::Immunity,
+            ("Vulnerability", Some("Double")) => ResistanceKind::Double,
+            ("Vulnerability", Some(value)) => {
+                ResistanceKind::Vulnerability(value.parse().map_err(|_| error())?)
+            }
+            _ => return Err(error()),
+        };
+
+        Ok(Resistance { damage_type, kind })
+    }
+}
+
+/// Flat Resistances and Vulnerabilities are applied before Half and Double.
+#[must_use]
+pub fn mitigate(resistances: &[Resistance], damage: u64, damage_type: DamageType) -> u64 {
+    let relevant = || {
+        resistances
+            .iter()
+            .filter(move |resistance| resistance.damage_type == damage_type)
+            .map(|resistance| resistance.kind)
+    };
+
+    if relevant().any(|kind| kind == ResistanceKind::Immunity) {
+        return 0;
+    }
+
+    let flat = relevant().fold(damage, |damage, kind| match kind {
+        ResistanceKind::Resistance(value) => damage.saturating_sub(value),
+        ResistanceKind::Vulnerability(value) => damage + value,
+        _ => damage,
+    });
+
+    relevant().fold(flat, |damage, kind| match kind {
+        ResistanceKind::Half => damage / 2,
+        ResistanceKind::Double => damage * 2,
+        _ => damage,
+    })
+}
+
+/// A Character as they stand at one of their Levels, ready for combat.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CharacterCombatant {
+    pub character_name: String,
+    pub level: Level,
+    pub attributes: Attributes,
+    pub inventory: Inventory,
+    pub conditions: Conditions,
+    attacks: Vec<Attack>,
+}
+
+impl CharacterCombatant {
+    #[must_use]
+    pub fn new(
+        character_name: impl Into<String>,
+        level: Level,
+        attributes: Attributes,
+        inventory: Inventory,
+    ) -> Self {
+        let conditions = Encumbrance::new(&attributes, &inventory)
+            .condition()
+            .into_iter()
+            .collect();
+
+        Self {
+            character_name: character_name.into(),
+            level,
+            attributes,
+            attacks: inventory.attacks(),
+            inventory,
+            conditions,
+        }
+    }
+}
+
+impl Combatant for CharacterCombatant {
+    fn name(&self) -> &str {
+        &self.character_name
+    }
+
+    fn combat_mastery(&self) -> usize {
+        self.level.calc_combat_mastery()
+    }
+
+    fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    fn attacks(&self) -> &[Attack] {
+        &self.attacks
+    }
+
+    fn precision_defense(&self) -> Defense {
+        self.inventory
+            .apply_precision_defense(self.attributes.precision_defense(self.combat_mastery()))
+    }
+
+    fn area_defense(&self) -> Defense {
+        self.inventory
+            .apply_area_defense(self.attributes.area_defense(self.combat_mastery()))
+    }
+
+    fn conditions(&self) -> &Conditions {
+        &self.conditions
+    }
+
+    fn speed(&self) -> u64 {
+        self.conditions
+            .speed(BASE_SPEED)
+            .saturating_sub(self.inventory.speed_penalty())
+    }
+
+    fn check_mode(&self, attribute: AttributeName) -> RollMode {
+        let mode = self.conditions.check_mode(attribute);
+
+        if attribute == AttributeName::Agility && self.inventory.has_agility_disadvantage() {
+            mode.combine(RollMode::Disadvantage(1))
+        } else {
+            mode
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dc20::{
+        item::weapon::{WeaponBuilder, WeaponStyle},
+        Condition, Item,
+    };
+
+    use super::*;
+
+    fn combatant(agility: i64) -> CharacterCombatant {
+        CharacterCombatant::new(
+            "Test",
+            Level::default(),
+            Attributes {
+                prime: 3,
+                might: 0,
+                agility,
+                charisma: 0,
+                intelligence: 0,
+            },
+            Inventory::default(),
+        )
+    }
+
+    fn sword() -> Attack {
+        let weapon = WeaponBuilder::new_melee()
+            .style(WeaponStyle::Sword)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        Attack::from_weapon("Sword", weapon)
+    }
+
+    fn roll(natural: u8, bonus: i64) -> CheckRoll {
+        CheckRoll {
+            dice: vec![natural],
+            mode: RollMode::Normal,
+            bonus,
+        }
+    }
+
+    #[test]
+    fn _heavy_and_brutal_hits_add_damage() {
+        // PD 8 + 1 (CM) + 1 (Agility) = 10
+        let target = combatant(1);
+
+        assert_eq!(
+            resolve_attack(roll(5, 4), &sword(), &target).hit,
+            HitResult::Miss
+        );
+        assert_eq!(resolve_attack(roll(6, 4), &sword(), &target).damage, 1);
+
+        let heavy = resolve_attack(roll(11, 4), &sword(), &target);
+        assert_eq!(heavy.hit, HitResult::HeavyHit);
+        assert_eq!(heavy.damage, 2);
+
+        let brutal = resolve_attack(roll(16, 4), &sword(), &target);
+        assert_eq!(brutal.hit, HitResult::BrutalHit);
+        assert_eq!(brutal.damage, 3);
+    }
+
+    #[test]
+    fn _natural_one_misses_and_natural_twenty_hits() {
+        let target = combatant(10);
+
+        assert_eq!(
+            resolve_attack(roll(1, 100), &sword(), &target).hit,
+            HitResult::Miss
+        );
+
+        let critical = resolve_attack(roll(20, 0), &sword(), &target);
+        assert_eq!(critical.hit, HitResult::Hit);
+        assert_eq!(critical.damage, 3);
+    }
+
+    #[test]
+    fn _apply_flat_resistances_before_halving() {
+        let resistances = [
+            Resistance {
+                damage_type: DamageType::Fire,
+                kind: ResistanceKind::Half,
+            },
+            Resistance {
+                damage_type: DamageType::Fire,
+                kind: ResistanceKind::Vulnerability(1),
+            },
+        ];
+
+        assert_eq!(mitigate(&resistances, 5, DamageType::Fire), 3);
+        assert_eq!(mitigate(&resistances, 5, DamageType::Cold), 5);
+    }
+
+    #[test]
+    fn _parse_resistances_from_display_form() {
+        for resistance in [
+            Resistance {
+                damage_type: DamageType::Cold,
+                kind: ResistanceKind::Resistance(1),
+            },
+            Resistance {
+                damage_type: DamageType::Poison,
+                kind: ResistanceKind::Immunity,
+            },
+            Resistance {
+                damage_type: DamageType::Radiant,
+                kind: ResistanceKind::Double,
+            },
+        ] {
+            assert_eq!(resistance.to_string().parse::<Resistance>(), Ok(resistance));
+        }
+    }
+
+    #[test]
+    fn _overloaded_characters_are_slowed_and_disadvantaged() {
+        let mut inventory = Inventory::default();
+        inventory.add(
+            Item {
+                name: "Anvil".into(),
+                weight: 120.0,
+                ..Default::default()
+            },
+            1,
+        );
+
+        assert_eq!(combatant(0).speed(), BASE_SPEED);
+
+        let character =
+            CharacterCombatant::new("Test", Level::default(), combatant(0).attributes, inventory);
+
+        assert!(character
+            .conditions()
+            .contains(Condition::HeavilyEncumbered));
+        assert_eq!(character.speed(), 3);
+        assert_eq!(
+            character.check_mode(AttributeName::Agility),
+            RollMode::Disadvantage(1)
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    db::Db,
+    dc20::{roll::RollMode, AttributeName},
+};
+
+/// How far a creature can move on its turn before Conditions are applied.
+pub const BASE_SPEED: u64 = 5;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Condition {
+    /// DisADV on Mental Checks.
+    Dazed,
+    /// Attacks against the creature have ADV.
+    Exposed,
+    /// DisADV on Attacks.
+    Hindered,
+    /// DisADV on Physical Checks.
+    Impaired,
+    /// Carrying more than the creature's capacity.
+    Encumbered,
+    /// Carrying more than twice the creature's capacity.
+    HeavilyEncumbered,
+}
+
+impl Condition {
+    /// The ADV or DisADV the Condition imposes on Checks using `attribute`.
+    #[must_use]
+    pub fn check_mode(self, attribute: AttributeName) -> RollMode {
+        use AttributeName::*;
+
+        let disadvantaged = match self {
+            Condition::Dazed => matches!(attribute, Charisma | Intelligence),
+            Condition::Impaired => matches!(attribute, Might | Agility),
+            Condition::Encumbered => matches!(attribute, Agility),
+            Condition::HeavilyEncumbered => matches!(attribute, Might | Agility),
+            Condition::Exposed | Condition::Hindered => false,
+        };
+
+        if disadvantaged {
+            RollMode::Disadvantage(1)
+        } else {
+            RollMode::Normal
+        }
+    }
+
+    /// The ADV or DisADV the Condition imposes on the creature's Attacks.
+    #[must_use]
+    pub fn attack_mode(self) -> RollMode {
+        match self {
+            Condition::Hindered | Condition::HeavilyEncumbered => RollMode::Disadvantage(1),
+            _ => RollMode::Normal,
+        }
+    }
+
+    /// The ADV or DisADV granted to Attacks made against the creature.
+    #[must_use]
+    pub fn targeted_mode(self) -> RollMode {
+        match self {
+            Condition::Exposed => RollMode::Advantage(1),
+            _ => RollMode::Normal,
+        }
+    }
+
+    #[must_use]
+    pub fn speed_penalty(self) -> u64 {
+        match self {
+            Condition::Encumbered => 1,
+            Condition::HeavilyEncumbered => 2,
+            _ => 0,
+        }
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Condition::HeavilyEncumbered => write!(f, "Heavily Encumbered"),
+            other => write!(f, "{other:?}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConditionParseError(pub String);
+
+impl fmt::Display for ConditionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unable to parse `{}`", self.0)
+    }
+}
+
+impl Error for ConditionParseError {}
+
+impl FromStr for Condition {
+    type Err = ConditionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Dazed" => Ok(Condition::Dazed),
+            "Exposed" => Ok(Condition::Exposed),
+            "Hindered" => Ok(Condition::Hindered),
+            "Impaired" => Ok(Condition::Impaired),
+            "Encumbered" => Ok(Condition::Encumbered),
+            "Heavily Encumbered" | "HeavilyEncumbered" => Ok(Condition::HeavilyEncumbered),
+            _ => Err(ConditionParseError(s.into())),
+        }
+    }
+}
+
+/// Every Condition currently affecting a creature. Each Condition is only
+/// ever present once.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Conditions(Vec<Condition>);
+
+impl Conditions {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    #[must_use]
+    pub fn contains(&self, condition: Condition) -> bool {
+        self.0.contains(&condition)
+    }
+
+    pub fn insert(&mut self, condition: Condition) {
+        if !self.contains(condition) {
+            self.0.push(condition);
+        }
+    }
+
+    pub fn remove(&mut self, condition: Condition) {
+        self.0.retain(|c| *c != condition);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Condition> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub fn check_mode(&self, attribute: AttributeName) -> RollMode {
+        self.combine(|condition| condition.check_mode(attribute))
+    }
+
+    #[must_use]
+    pub fn attack_mode(&self) -> RollMode {
+        self.combine(Condition::attack_mode)
+    }
+
+    #[must_use]
+    pub fn targeted_mode(&self) -> RollMode {
+        self.combine(Condition::targeted_mode)
+    }
+
+    /// The creature's Speed after every Condition's penalty.
+    #[must_use]
+    pub fn speed(&self, base_speed: u64) -> u64 {
+        let penalty: u64 = self
+            .0
+            .iter()
+            .map(|condition| condition.speed_penalty())
+            .sum();
+
+        base_speed.saturating_sub(penalty)
+    }
+
+    fn combine(&self, mode: impl Fn(Condition) -> RollMode) -> RollMode {
+        self.0.iter().fold(RollMode::Normal, |combined, condition| {
+            combined.combine(mode(*condition))
+        })
+    }
+
+    /// Loads the Conditions saved against a Character, leaving out any
+    /// derived from what they carry.
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        character_id: Uuid,
+    ) -> crate::Result<Conditions> {
+        let names = sqlx::query_scalar!(
+            r#"
+                SELECT `name`
+                FROM `character_conditions`
+                WHERE `character_id` = ?1
+                ORDER BY `name`
+                ;
+            "#,
+            character_id
+        )
+        .fetch_all(executor)
+        .await?;
+
+        names
+            .iter()
+            .map(|name| {
+                name.parse::<Condition>()
+                    .map_err(|e| crate::Error::Storage(sqlx::Error::Decode(Box::new(e))))
+            })
+            .collect()
+    }
+
+    /// Replaces every Condition saved against the Character with these.
+    pub async fn save(&self, db: impl Db<'_>, character_id: Uuid) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+        let mut tx = conn.begin().await?;
+
+        sqlx::query!(
+            r#"
+                DELETE FROM `character_conditions`
+                WHERE `character_id` = ?1
+                ;
+            "#,
+            character_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for condition in self.iter() {
+            let name = condition.to_string();
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO `character_conditions` (`character_id`, `name`)
+                    VALUES (?1, ?2)
+                    ;
+                "#,
+                character_id,
+                name
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        Ok(tx.commit().await?)
+    }
+}
+
+impl FromIterator<Condition> for Conditions {
+    fn from_iter<T: IntoIterator<Item = Condition>>(iter: T) -> Self {
+        let mut conditions = Conditions::new();
+
+        for condition in iter {
+            conditions.insert(condition);
+        }
+
+        conditions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _heavily_encumbered_hinders_physical_checks_and_speed() {
+        let conditions = Conditions::from_iter([Condition::HeavilyEncumbered]);
+
+        assert_eq!(
+            conditions.check_mode(AttributeName::Might),
+            RollMode::Disadvantage(1)
+        );
+        assert_eq!(
+            conditions.check_mode(AttributeName::Charisma),
+            RollMode::Normal
+        );
+        assert_eq!(conditions.attack_mode(), RollMode::Disadvantage(1));
+        assert_eq!(conditions.speed(BASE_SPEED), 3);
+    }
+
+    #[test]
+    fn _conditions_round_trip_through_their_names() {
+        assert_eq!(
+            Condition::HeavilyEncumbered.to_string(),
+            "Heavily Encumbered"
+        );
+        assert_eq!(
+            "Heavily Encumbered".parse::<Condition>(),
+            Ok(Condition::HeavilyEncumbered)
+        );
+        assert_eq!("Dazed".parse::<Condition>(), Ok(Condition::Dazed));
+    }
+}
@@ -0,0 +1,981 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use turann::Builder;
+use uuid::Uuid;
+
+use crate::{
+    db::Db,
+    dc20::{
+        item::weapon::{Weapon, WeaponBuilder, WeaponProperty, WeaponStyle},
+        Attack, AttributeName, Attributes, Combatant, Defense, Item, ItemKind, Maneuver,
+        Resistance, ResistanceKind, Spell,
+    },
+};
+
+/// A monster or NPC, described by its stat block rather than built up
+/// Level by Level like a player Character.
+#[derive(Builder, Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "CreatureFields")]
+pub struct Creature {
+    #[builder(default = Uuid::now_v7)]
+    pub id: Uuid,
+    #[builder(validate = CreatureBuilder::validate_name)]
+    pub name: String,
+    #[builder(default)]
+    pub level: i64,
+    pub attributes: Attributes,
+    pub hit_points: u64,
+    pub precision_defense: Defense,
+    pub area_defense: Defense,
+    #[builder(each = "attack")]
+    pub attacks: Vec<Attack>,
+    #[builder(each = "maneuver")]
+    pub maneuvers: Vec<Maneuver>,
+    #[builder(each = "spell")]
+    pub spells: Vec<Spell>,
+    #[builder(each = "resistance")]
+    pub resistances: Vec<Resistance>,
+}
+
+/// A [`Creature`] as it comes in, built up through its builder so that it
+/// is checked the same way.
+#[derive(Deserialize)]
+struct CreatureFields {
+    id: Option<Uuid>,
+    name: String,
+    #[serde(default)]
+    level: i64,
+    attributes: Attributes,
+    hit_points: u64,
+    precision_defense: Defense,
+    area_defense: Defense,
+    #[serde(default)]
+    attacks: Vec<Attack>,
+    #[serde(default)]
+    maneuvers: Vec<Maneuver>,
+    #[serde(default)]
+    spells: Vec<Spell>,
+    #[serde(default)]
+    resistances: Vec<Resistance>,
+}
+
+impl TryFrom<CreatureFields> for Creature {
+    type Error = CreatureBuilderError;
+
+    fn try_from(fields: CreatureFields) -> Result<Self, Self::Error> {
+        let mut builder = Creature::builder();
+        builder
+            .name(fields.name)?
+            .level(fields.level)
+            .attributes(fields.attributes)
+            .hit_points(fields.hit_points)
+            .precision_defense(fields.precision_defense)
+            .area_defense(fields.area_defense);
+        if let Some(id) = fields.id {
+            builder.id(id);
+        }
+
+        for attack in fields.attacks {
+            builder.attack(attack);
+        }
+        for maneuver in fields.maneuvers {
+            builder.maneuver(maneuver);
+        }
+        for spell in fields.spells {
+            builder.spell(spell);
+        }
+        for resistance in fields.resistances {
+            builder.resistance(resistance);
+        }
+
+        builder.build()
+    }
+}
+
+impl CreatureBuilder {
+    fn validate_name(name: impl Into<String>) -> Result<String, CreatureBuilderError> {
+        let name: String = name.into();
+
+        if name.is_empty() {
+            return Err(CreatureBuilderError::InvalidField {
+                field_name: "name".into(),
+                message: "cannot be empty".into(),
+            });
+        }
+
+        Ok(name)
+    }
+}
+
+impl Combatant for Creature {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn combat_mastery(&self) -> usize {
+        (self.level.max(0) as usize).div_ceil(2)
+    }
+
+    fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    fn attacks(&self) -> &[Attack] {
+        &self.attacks
+    }
+
+    fn resistances(&self) -> &[Resistance] {
+        &self.resistances
+    }
+
+    fn precision_defense(&self) -> Defense {
+        self.precision_defense
+    }
+
+    fn area_defense(&self) -> Defense {
+        self.area_defense
+    }
+}
+
+impl Creature {
+    pub async fn load(db: impl Db<'_>, id: Uuid) -> crate::Result<Creature> {
+        let mut conn = db.connection().await?;
+
+        let row = sqlx::query!(
+            r#"
+                SELECT `creature_id` AS "id: Uuid"
+                    , `name`
+                    , `level`
+                    , `prime`
+                    , `might`
+                    , `agility`
+                    , `charisma`
+                    , `intelligence`
+                    , `hit_points`
+                    , `precision_defense`
+                    , `precision_damage_reduction`
+                    , `area_defense`
+                    , `area_damage_reduction`
+                FROM `creatures`
+                WHERE `creature_id` = ?1
+                LIMIT 1
+                ;
+            "#,
+            id
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        let attack_rows = sqlx::query!(
+            r#"
+                SELECT `creature_attack_id` AS "id: Uuid"
+                    , `weapon_id` AS "weapon_id: Uuid"
+                    , `name`
+                    , `damage`
+                FROM `creature_attacks`
+                WHERE `creature_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut attacks = vec![];
+        for attack in attack_rows {
+            attacks.push(Attack {
+                id: attack.id,
+                name: attack.name,
+                weapon: Weapon::load(&mut *conn, attack.weapon_id).await?,
+                damage: attack.damage as u64,
+            });
+        }
+
+        let maneuver_ids = sqlx::query_scalar!(
+            r#"
+                SELECT `maneuver_id` AS "id: Uuid"
+                FROM `creatures_maneuvers`
+                WHERE `creature_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut maneuvers = vec![];
+        for maneuver_id in maneuver_ids {
+            maneuvers.push(Maneuver::load(&mut *conn, maneuver_id).await?);
+        }
+
+        let spell_ids = sqlx::query_scalar!(
+            r#"
+                SELECT `spell_id` AS "id: Uuid"
+                FROM `creatures_spells`
+                WHERE `creature_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut spells = vec![];
+        for spell_id in spell_ids {
+            spells.push(Spell::load(&mut *conn, spell_id).await?);
+        }
+
+        let resistance_rows = sqlx::query!(
+            r#"
+                SELECT `damage_type`
+                    , `kind`
+                    , `value`
+                FROM `creature_resistances`
+                WHERE `creature_id` = ?1
+                ORDER BY `position`
+                ;
+            "#,
+            id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut resistances = vec![];
+        for resistance in resistance_rows {
+            resistances.push(Resistance {
+                damage_type: resistance
+                    .damage_type
+                    .parse()
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+                kind: ResistanceKind::parse(
+                    &resistance.kind,
+                    resistance.value.map(|value| value as u64),
+                )
+                .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            });
+        }
+
+        Ok(Creature {
+            id: row.id,
+            name: row.name,
+            level: row.level,
+            attributes: Attributes {
+                prime: row.prime,
+                might: row.might,
+                agility: row.agility,
+                charisma: row.charisma,
+                intelligence: row.intelligence,
+            },
+            hit_points: row.hit_points as u64,
+            precision_defense: Defense {
+                score: row.precision_defense as usize,
+                reduction: row.precision_damage_reduction as usize,
+            },
+            area_defense: Defense {
+                score: row.area_defense as usize,
+                reduction: row.area_damage_reduction as usize,
+            },
+            attacks,
+            maneuvers,
+            spells,
+            resistances,
+        })
+    }
+
+    /// Saves the Creature along with its Attacks, Maneuvers and
+    /// Resistances, all at once. Known Spells must already be in the
+    /// database.
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+        let mut tx = conn.begin().await?;
+
+        let Creature {
+            id,
+            name,
+            level,
+            attributes,
+            hit_points,
+            precision_defense,
+            area_defense,
+            attacks,
+            maneuvers,
+            spells,
+            resistances,
+        } = self;
+
+        let hit_points = hit_points as i64;
+        let precision_defense_score = precision_defense.score as i64;
+        let precision_damage_reduction = precision_defense.reduction as i64;
+        let area_defense_score = area_defense.score as i64;
+        let area_damage_reduction = area_defense.reduction as i64;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `creatures`
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+                ON CONFLICT (`creature_id`) DO UPDATE
+                    SET `name` = ?2
+                    , `level` = ?3
+                    , `prime` = ?4
+                    , `might` = ?5
+                    , `agility` = ?6
+                    , `charisma` = ?7
+                    , `intelligence` = ?8
+                    , `hit_points` = ?9
+                    , `precision_defense` = ?10
+                    , `precision_damage_reduction` = ?11
+                    , `area_defense` = ?12
+                    , `area_damage_reduction` = ?13
+                ;
+            "#,
+            id,
+            name,
+            level,
+            attributes.prime,
+            attributes.might,
+            attributes.agility,
+            attributes.charisma,
+            attributes.intelligence,
+            hit_points,
+            precision_defense_score,
+            precision_damage_reduction,
+            area_defense_score,
+            area_damage_reduction
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let replaced = sqlx::query_scalar!(
+            r#"
+                SELECT `weapon_id` AS "id: Uuid"
+                FROM `creature_attacks`
+                WHERE `creature_id` = ?1
+                    AND `owns_weapon`
+                ;
+            "#,
+            id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "DELETE FROM `creature_attacks` WHERE `creature_id` = ?1;",
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for Attack {
+            id: attack_id,
+            name: attack_name,
+            weapon,
+            damage,
+        } in attacks
+        {
+            let weapon_id = weapon.uuid;
+            let damage = damage as i64;
+
+            // Catalog Weapons are shared, so only a Weapon made for one of
+            // the Creature's Attacks is written
+            let saved = sqlx::query_scalar!(
+                r#"
+                    SELECT EXISTS (
+                        SELECT 1 FROM `items` WHERE `item_id` = ?1
+                    ) AS "saved!: bool"
+                    ;
+                "#,
+                weapon_id
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+            let owns_weapon = replaced.contains(&weapon_id) || !saved;
+
+            if owns_weapon {
+                Item {
+                    uuid: weapon_id,
+                    name: attack_name.clone(),
+                    kind: ItemKind::Weapon,
+                    ..Default::default()
+                }
+                .save(&mut *tx)
+                .await?;
+                weapon.save(&mut *tx).await?;
+            }
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO `creature_attacks`
+                        (`creature_attack_id`, `creature_id`, `weapon_id`, `name`, `damage`, `owns_weapon`)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                    ;
+                "#,
+                attack_id,
+                id,
+                weapon_id,
+                attack_name,
+                damage,
+                owns_weapon
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for weapon_id in replaced {
+            sqlx::query!(
+                r#"
+                    DELETE FROM `items`
+                    WHERE `item_id` = ?1
+                        AND NOT EXISTS (
+                            SELECT 1 FROM `creature_attacks` WHERE `weapon_id` = ?1
+                        )
+                    ;
+                "#,
+                weapon_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        sqlx::query!(
+            "DELETE FROM `creatures_maneuvers` WHERE `creature_id` = ?1;",
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for maneuver in maneuvers {
+            let maneuver_id = maneuver.uuid;
+            maneuver.save(&mut *tx).await?;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO `creatures_maneuvers` (`creature_id`, `maneuver_id`)
+                    VALUES (?1, ?2)
+                    ON CONFLICT (`creature_id`, `maneuver_id`) DO NOTHING
+                    ;
+                "#,
+                id,
+                maneuver_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        sqlx::query!(
+            "DELETE FROM `creatures_spells` WHERE `creature_id` = ?1;",
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for spell in spells {
+            sqlx::query!(
+                r#"
+                    INSERT INTO `creatures_spells` (`creature_id`, `spell_id`)
+                    VALUES (?1, ?2)
+                    ON CONFLICT (`creature_id`, `spell_id`) DO NOTHING
+                    ;
+                "#,
+                id,
+                spell.id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        sqlx::query!(
+            "DELETE FROM `creature_resistances` WHERE `creature_id` = ?1;",
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for (position, Resistance { damage_type, kind }) in resistances.into_iter().enumerate() {
+            let position = position as i64;
+            let damage_type = damage_type.to_string();
+            let kind_name = kind.name();
+            let value = kind.value().map(|value| value as i64);
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO `creature_resistances`
+                        (`creature_id`, `position`, `damage_type`, `kind`, `value`)
+                    VALUES (?1, ?2, ?3, ?4, ?5)
+                    ;
+                "#,
+                id,
+                position,
+                damage_type,
+                kind_name,
+                value
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        Ok(tx.commit().await?)
+    }
+
+    /// Imports every stat block in `text` (see [`StatBlock`]), resolving
+    /// Maneuvers and Spells by name, and saves the resulting Creatures. None
+    /// are saved when any of them can't be.
+    pub async fn import(db: impl Db<'_>, text: &str) -> crate::Result<Vec<Creature>> {
+        let mut conn = db.connection().await?;
+        let mut tx = conn.begin().await?;
+
+        let mut creatures = vec![];
+
+        for block in StatBlock::parse_all(text)? {
+            let mut maneuvers = vec![];
+            for name in &block.maneuvers {
+                let maneuver_id = sqlx::query_scalar!(
+                    r#"
+                        SELECT `maneuver_id` AS "id: Uuid"
+                        FROM `maneuvers`
+                        WHERE `name` = ?1
+                        LIMIT 1
+                        ;
+                    "#,
+                    name
+                )
+                .fetch_optional(&mut *tx)
+                .await?
+                .ok_or_else(|| {
+                    crate::Error::ContentReference(format!("unknown Maneuver `{name}`").into())
+                })?;
+
+                maneuvers.push(Maneuver::load(&mut *tx, maneuver_id).await?);
+            }
+
+            let mut spells = vec![];
+            for name in &block.spells {
+                let spell_id = sqlx::query_scalar!(
+                    r#"
+                        SELECT `spell_id` AS "id: Uuid"
+                        FROM `spells`
+                        WHERE `name` = ?1
+                        LIMIT 1
+                        ;
+                    "#,
+                    name
+                )
+                .fetch_optional(&mut *tx)
+                .await?
+                .ok_or_else(|| {
+                    crate::Error::ContentReference(format!("unknown Spell `{name}`").into())
+                })?;
+
+                spells.push(Spell::load(&mut *tx, spell_id).await?);
+            }
+
+            let creature = block.into_creature(maneuvers, spells)?;
+            creature.clone().save(&mut *tx).await?;
+            creatures.push(creature);
+        }
+
+        tx.commit().await?;
+
+        Ok(creatures)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatBlockParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for StatBlockParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unable to parse stat block (line {}): {}",
+            self.line, self.message
+        )
+    }
+}
+
+impl Error for StatBlockParseError {}
+
+/// The plain text format GMs use to enter monsters, one `Key: Value` per
+/// line. Blank lines and lines starting with `#` are ignored, and several
+/// stat blocks can share a file when separated by a `---` line.
+///
+/// ```text
+/// Name: Goblin Skirmisher
+/// Level: 1
+/// Hit Points: 6
+/// PD: 12
+/// AD: 10
+/// Attributes: Prime 2, Might 0, Agility 2, Charisma -1, Intelligence 0
+/// Attack: Scimitar | Sword | 1 | Concealable
+/// Resistance: Poison Resistance (1)
+/// Maneuver: Trip
+/// Spell: Fire Bolt
+/// ```
+///
+/// An Attack is `name | style | damage | properties`, where damage and
+/// properties are optional. `PDR` and `ADR` set damage reduction, and PD or
+/// AD default to the values calculated from Attributes and Level.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StatBlock {
+    pub name: String,
+    pub level: i64,
+    pub hit_points: u64,
+    pub attributes: Option<Attributes>,
+    pub precision_defense: Option<usize>,
+    pub precision_damage_reduction: usize,
+    pub area_defense: Option<usize>,
+    pub area_damage_reduction: usize,
+    pub attacks: Vec<Attack>,
+    pub resistances: Vec<Resistance>,
+    pub maneuvers: Vec<String>,
+    pub spells: Vec<String>,
+}
+
+impl StatBlock {
+    pub fn parse_all(text: &str) -> Result<Vec<StatBlock>, StatBlockParseError> {
+        let mut blocks = vec![];
+        let mut current: Vec<(usize, &str)> = vec![];
+
+        // Sections with nothing but blanks and comments, like the one before
+        // a leading `---`, hold no stat block
+        for (index, line) in text.lines().enumerate() {
+            if line.trim() == "---" {
+                if Self::has_content(&current) {
+                    blocks.push(Self::parse_lines(&current)?);
+                }
+                current.clear();
+            } else {
+                current.push((index + 1, line));
+            }
+        }
+
+        if Self::has_content(&current) {
+            blocks.push(Self::parse_lines(&current)?);
+        }
+
+        Ok(blocks)
+    }
+
+    fn has_content(lines: &[(usize, &str)]) -> bool {
+        lines
+            .iter()
+            .any(|(_, line)| !line.trim().is_empty() && !line.trim().starts_with('#'))
+    }
+
+    fn parse_lines(lines: &[(usize, &str)]) -> Result<StatBlock, StatBlockParseError> {
+        let mut block = StatBlock::default();
+        let mut has_hit_points = false;
+
+        for &(line_number, line) in lines {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = |message: &str| StatBlockParseError {
+                line: line_number,
+                message: message.into(),
+            };
+
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| error("expected `Key: Value`"))?;
+            let value = value.trim();
+            let number = |value: &str| value.parse::<i64>().map_err(|_| error("expected a number"));
+            let count = |value: &str| {
+                value
+                    .parse::<u64>()
+                    .map_err(|_| error("expected a positive number"))
+            };
+
+            match key.trim().to_lowercase().as_str() {
+                "name" => block.name = value.into(),
+                "level" => block.level = number(value)?,
+                "hit points" | "hp" => {
+                    block.hit_points = count(value)?;
+                    has_hit_points = true;
+                }
+                "pd" => block.precision_defense = Some(count(value)? as usize),
+                "pdr" => block.precision_damage_reduction = count(value)? as usize,
+                "ad" => block.area_defense = Some(count(value)? as usize),
+                "adr" => block.area_damage_reduction = count(value)? as usize,
+                "attributes" => {
+                    block.attributes = Some(parse_attributes(value).map_err(|m| error(&m))?)
+                }
+                "attack" => block
+                    .attacks
+                    .push(parse_attack(value).map_err(|m| error(&m))?),
+                "resistance" | "immunity" | "vulnerability" => block.resistances.push(
+                    value
+                        .parse()
+                        .map_err(|e: crate::dc20::ResistanceParseError| error(&e.to_string()))?,
+                ),
+                "maneuver" => block.maneuvers.push(value.into()),
+                "spell" => block.spells.push(value.into()),
+                other => return Err(error(&format!("unknown key `{other}`"))),
+            }
+        }
+
+        let line = lines.first().map(|(number, _)| *number).unwrap_or_default();
+        let missing = |field: &str| StatBlockParseError {
+            line,
+            message: format!("missing `{field}`"),
+        };
+
+        if block.name.is_empty() {
+            return Err(missing("Name"));
+        }
+        if !has_hit_points {
+            return Err(missing("Hit Points"));
+        }
+        if block.attributes.is_none() {
+            return Err(missing("Attributes"));
+        }
+
+        Ok(block)
+    }
+
+    pub fn into_creature(
+        self,
+        maneuvers: Vec<Maneuver>,
+        spells: Vec<Spell>,
+    ) -> crate::Result<Creature> {
+        let attributes = self
+            .attributes
+            .ok_or_else(|| crate::Error::Validation("missing Attributes".into()))?;
+
+        let mut builder = Creature::builder();
+        builder
+            .name(self.name)?
+            .level(self.level)
+            .attributes(attributes.clone())
+            .hit_points(self.hit_points);
+
+        for attack in self.attacks {
+            builder.attack(attack);
+        }
+        for maneuver in maneuvers {
+            builder.maneuver(maneuver);
+        }
+        for spell in spells {
+            builder.spell(spell);
+        }
+        for resistance in self.resistances {
+            builder.resistance(resistance);
+        }
+
+        let combat_mastery = (self.level.max(0) as usize).div_ceil(2);
+
+        builder.precision_defense(Defense {
+            score: self
+                .precision_defense
+                .unwrap_or(attributes.precision_defense(combat_mastery).score),
+            reduction: self.precision_damage_reduction,
+        });
+        builder.area_defense(Defense {
+            score: self
+                .area_defense
+                .unwrap_or(attributes.area_defense(combat_mastery).score),
+            reduction: self.area_damage_reduction,
+        });
+
+        Ok(builder.build()?)
+    }
+}
+
+impl FromStr for StatBlock {
+    type Err = StatBlockParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<_> = s.lines().enumerate().map(|(i, l)| (i + 1, l)).collect();
+
+        Self::parse_lines(&lines)
+    }
+}
+
+fn parse_attributes(value: &str) -> Result<Attributes, String> {
+    let mut attributes = Attributes {
+        prime: 0,
+        might: 0,
+        agility: 0,
+        charisma: 0,
+        intelligence: 0,
+    };
+
+    for pair in value.split(',') {
+        let (name, score) = pair
+            .trim()
+            .split_once(' ')
+            .ok_or(format!("expected `Attribute score`, found `{pair}`"))?;
+        let score: i64 = score
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid score for {name}"))?;
+
+        match name
+            .parse()
+            .map_err(|_| format!("unknown Attribute `{name}`"))?
+        {
+            AttributeName::Prime => attributes.prime = score,
+            AttributeName::Might => attributes.might = score,
+            AttributeName::Agility => attributes.agility = score,
+            AttributeName::Charisma => attributes.charisma = score,
+            AttributeName::Intelligence => attributes.intelligence = score,
+        }
+    }
+
+    Ok(attributes)
+}
+
+fn parse_attack(value: &str) -> Result<Attack, String> {
+    let mut parts = value.split('|').map(str::trim);
+
+    let name = parts
+        .next()
+        .filter(|name| !name.is_empty())
+        .ok_or("missing Attack name")?;
+    let style: WeaponStyle = parts
+        .next()
+        .ok_or("missing weapon style")?
+        .parse()
+        .map_err(|e| format!("{e}"))?;
+    let damage = parts
+        .next()
+        .filter(|damage| !damage.is_empty())
+        .map(|damage| {
+            damage
+                .parse::<u64>()
+                .map_err(|_| "invalid damage".to_string())
+        })
+        .transpose()?;
+    let properties = parts
+        .next()
+        .map(|properties| {
+            properties
+                .split(',')
+                .map(str::trim)
+                .filter(|property| !property.is_empty())
+                .map(|property| {
+                    property
+                        .parse::<WeaponProperty>()
+                        .map_err(|e| format!("{e}"))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?
+        .unwrap_or_default();
+
+    let weapon = WeaponBuilder::new()
+        .style(style)
+        .and_then(|builder| builder.add_properties(&properties))
+        .and_then(|builder| builder.build())
+        .map_err(|e| e.to_string())?;
+
+    let mut attack = Attack::from_weapon(name, weapon);
+    if let Some(damage) = damage {
+        attack.damage = damage;
+    }
+
+    Ok(attack)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dc20::item::weapon::DamageType;
+
+    use super::*;
+
+    const GOBLIN: &str = "
+        # a sneaky one
+        Name: Goblin Skirmisher
+        Level: 1
+        Hit Points: 6
+        PD: 12
+        Attributes: Prime 2, Might 0, Agility 2, Charisma -1, Intelligence 0
+        Attack: Scimitar | Sword | 2 | Concealable
+        Resistance: Poison Resistance (1)
+        Maneuver: Trip
+    ";
+
+    #[test]
+    fn _parse_stat_block() -> Result<(), Box<dyn Error>> {
+        let block: StatBlock = GOBLIN.parse()?;
+
+        assert_eq!(block.name, "Goblin Skirmisher");
+        assert_eq!(block.hit_points, 6);
+        assert_eq!(block.precision_defense, Some(12));
+        assert_eq!(block.attacks.len(), 1);
+        assert_eq!(block.attacks[0].damage, 2);
+        assert_eq!(block.attacks[0].damage_type(), DamageType::Slashing);
+        assert_eq!(
+            block.attacks[0].weapon.properties,
+            vec![WeaponProperty::Concealable]
+        );
+        assert_eq!(block.maneuvers, vec!["Trip".to_string()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _missing_defenses_are_calculated_from_attributes() -> Result<(), Box<dyn Error>> {
+        let creature = GOBLIN.parse::<StatBlock>()?.into_creature(vec![], vec![])?;
+
+        assert_eq!(creature.precision_defense.score, 12);
+        // 8 + 1 (CM) + 0 (Might) + -1 (Charisma)
+        assert_eq!(creature.area_defense.score, 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _parse_several_stat_blocks_separated_by_dashes() -> Result<(), Box<dyn Error>> {
+        let text = format!("{GOBLIN}\n---\n{}", GOBLIN.replace("Goblin", "Hobgoblin"));
+
+        let blocks = StatBlock::parse_all(&text)?;
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[1].name, "Hobgoblin Skirmisher");
+
+        Ok(())
+    }
+
+    #[test]
+    fn _parse_stat_blocks_after_a_leading_separator() -> Result<(), Box<dyn Error>> {
+        let text = format!("---\n{GOBLIN}\n---\n\n---\n# the end\n");
+
+        let blocks = StatBlock::parse_all(&text)?;
+
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].name, "Goblin Skirmisher");
+
+        Ok(())
+    }
+
+    #[test]
+    fn _deserializing_goes_through_the_builder() -> Result<(), Box<dyn Error>> {
+        let creature = GOBLIN.parse::<StatBlock>()?.into_creature(vec![], vec![])?;
+        let mut json = serde_json::to_value(&creature)?;
+
+        assert_eq!(serde_json::from_value::<Creature>(json.clone())?, creature);
+
+        json["name"] = "".into();
+        let error = serde_json::from_value::<Creature>(json).unwrap_err();
+        assert!(error.to_string().contains("cannot be empty"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn _report_line_of_unknown_key() {
+        assert_eq!(
+            "Name: Bat\nWingspan: 3".parse::<StatBlock>(),
+            Err(StatBlockParseError {
+                line: 2,
+                message: "unknown key `wingspan`".into(),
+            })
+        );
+    }
+}
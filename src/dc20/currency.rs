@@ -0,0 +1,575 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    db::Db,
+    dc20::{Inventory, Item},
+};
+
+pub const COPPER_PER_SILVER: u64 = 10;
+pub const COPPER_PER_GOLD: u64 = 100;
+pub const COPPER_PER_PLATINUM: u64 = 1_000;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CurrencyError {
+    InsufficientFunds { price: u64, available: u64 },
+    NotEnoughItems { entry_id: Uuid, available: u64 },
+}
+
+impl fmt::Display for CurrencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CurrencyError::InsufficientFunds { price, available } => write!(
+                f,
+                "Unable to pay {}: only {} available",
+                Purse::from_copper(*price),
+                Purse::from_copper(*available)
+            ),
+            CurrencyError::NotEnoughItems {
+                entry_id,
+                available,
+            } => write!(f, "Unable to sell: entry `{entry_id}` only has {available}"),
+        }
+    }
+}
+
+impl Error for CurrencyError {}
+
+/// The coins a Character is carrying.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Purse {
+    pub platinum: u64,
+    pub gold: u64,
+    pub silver: u64,
+    pub copper: u64,
+}
+
+impl Purse {
+    /// The fewest coins worth `copper` copper pieces.
+    #[must_use]
+    pub fn from_copper(copper: u64) -> Self {
+        Self {
+            platinum: copper / COPPER_PER_PLATINUM,
+            gold: copper % COPPER_PER_PLATINUM / COPPER_PER_GOLD,
+            silver: copper % COPPER_PER_GOLD / COPPER_PER_SILVER,
+            copper: copper % COPPER_PER_SILVER,
+        }
+    }
+
+    #[must_use]
+    pub fn total_copper(&self) -> u64 {
+        self.platinum * COPPER_PER_PLATINUM
+            + self.gold * COPPER_PER_GOLD
+            + self.silver * COPPER_PER_SILVER
+            + self.copper
+    }
+
+    pub fn deposit(&mut self, coins: Purse) {
+        self.platinum += coins.platinum;
+        self.gold += coins.gold;
+        self.silver += coins.silver;
+        self.copper += coins.copper;
+    }
+
+    /// Pays `price` copper pieces, spending the smallest coins first and
+    /// breaking larger coins for change when needed.
+    pub fn spend(&mut self, price: u64) -> Result<(), CurrencyError> {
+        let available = self.total_copper();
+        if available < price {
+            return Err(CurrencyError::InsufficientFunds { price, available });
+        }
+
+        let mut owed = price;
+        let mut change = 0;
+
+        for (coins, value) in [
+            (&mut self.copper, 1),
+            (&mut self.silver, COPPER_PER_SILVER),
+            (&mut self.gold, COPPER_PER_GOLD),
+            (&mut self.platinum, COPPER_PER_PLATINUM),
+        ] {
+            let used = (*coins).min(owed.div_ceil(value));
+            *coins -= used;
+
+            let paid = used * value;
+            change += paid.saturating_sub(owed);
+            owed = owed.saturating_sub(paid);
+        }
+
+        self.deposit(Purse::from_copper(change));
+
+        Ok(())
+    }
+
+    pub async fn load(db: impl Db<'_>, character_id: Uuid) -> crate::Result<Purse> {
+        let mut conn = db.connection().await?;
+
+        Self::load_with(&mut conn, character_id).await
+    }
+
+    async fn load_with(
+        conn: &mut sqlx::SqliteConnection,
+        character_id: Uuid,
+    ) -> crate::Result<Purse> {
+        let row = sqlx::query!(
+            r#"
+                SELECT `platinum`
+                    , `gold`
+                    , `silver`
+                    , `copper`
+                FROM `character_purses`
+                WHERE `character_id` = ?1
+                LIMIT 1
+                ;
+            "#,
+            character_id
+        )
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        Ok(row
+            .map(|row| Purse {
+                platinum: row.platinum as u64,
+                gold: row.gold as u64,
+                silver: row.silver as u64,
+                copper: row.copper as u64,
+            })
+            .unwrap_or_default())
+    }
+
+    pub async fn save(self, db: impl Db<'_>, character_id: Uuid) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+
+        self.save_with(&mut conn, character_id).await
+    }
+
+    async fn save_with(
+        self,
+        conn: &mut sqlx::SqliteConnection,
+        character_id: Uuid,
+    ) -> crate::Result<()> {
+        let platinum = self.platinum as i64;
+        let gold = self.gold as i64;
+        let silver = self.silver as i64;
+        let copper = self.copper as i64;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `character_purses`
+                    (`character_id`, `platinum`, `gold`, `silver`, `copper`)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ON CONFLICT (`character_id`) DO UPDATE
+                    SET `platinum` = ?2
+                    , `gold` = ?3
+                    , `silver` = ?4
+                    , `copper` = ?5
+                ;
+            "#,
+            character_id,
+            platinum,
+            gold,
+            silver,
+            copper
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Purse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let coins: Vec<String> = [
+            (self.platinum, "pp"),
+            (self.gold, "gp"),
+            (self.silver, "sp"),
+            (self.copper, "cp"),
+        ]
+        .into_iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, unit)| format!("{count} {unit}"))
+        .collect();
+
+        if coins.is_empty() {
+            write!(f, "0 cp")
+        } else {
+            write!(f, "{}", coins.join(", "))
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TransactionKind {
+    Purchase,
+    Sale,
+    /// Coins handed out by the GM, such as a share of loot.
+    Award,
+    /// Coins spent or lost outside of buying Items.
+    Payment,
+}
+
+impl fmt::Display for TransactionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TransactionKindParseError();
+
+impl fmt::Display for TransactionKindParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unable to parse TransactionKind")
+    }
+}
+
+impl Error for TransactionKindParseError {}
+
+impl FromStr for TransactionKind {
+    type Err = TransactionKindParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Purchase" => Ok(TransactionKind::Purchase),
+            "Sale" => Ok(TransactionKind::Sale),
+            "Award" => Ok(TransactionKind::Award),
+            "Payment" => Ok(TransactionKind::Payment),
+            _ => Err(TransactionKindParseError()),
+        }
+    }
+}
+
+/// One change to a Character's Purse. The ledger is only ever appended to.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct LedgerEntry {
+    pub id: Uuid,
+    pub character_id: Uuid,
+    pub kind: TransactionKind,
+    pub item_id: Option<Uuid>,
+    pub quantity: u64,
+    /// In copper pieces: positive when the Character gains money.
+    pub amount: i64,
+    pub note: String,
+}
+
+impl LedgerEntry {
+    /// When the entry was recorded, taken from its v7 id.
+    #[must_use]
+    pub fn recorded_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let (secs, nsecs) = self.id.get_timestamp()?.to_unix();
+
+        chrono::DateTime::from_timestamp(secs as i64, nsecs)
+    }
+
+    /// Every entry for the Character, oldest first.
+    pub async fn load_all(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        character_id: Uuid,
+    ) -> crate::Result<Vec<LedgerEntry>> {
+        let rows = sqlx::query!(
+            r#"
+                SELECT `currency_transaction_id` AS "id: Uuid"
+                    , `character_id` AS "character_id: Uuid"
+                    , `kind`
+                    , `item_id` AS "item_id: Uuid"
+                    , `quantity`
+                    , `amount`
+                    , `note`
+                FROM `currency_transactions`
+                WHERE `character_id` = ?1
+                ORDER BY `currency_transaction_id`
+                ;
+            "#,
+            character_id
+        )
+        .fetch_all(executor)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(LedgerEntry {
+                    id: row.id,
+                    character_id: row.character_id,
+                    kind: row
+                        .kind
+                        .parse()
+                        .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+                    item_id: row.item_id,
+                    quantity: row.quantity as u64,
+                    amount: row.amount,
+                    note: row.note,
+                })
+            })
+            .collect()
+    }
+
+    async fn record(self, conn: &mut sqlx::SqliteConnection) -> crate::Result<()> {
+        let kind = self.kind.to_string();
+        let quantity = self.quantity as i64;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `currency_transactions`
+                    ( `currency_transaction_id`
+                    , `character_id`
+                    , `kind`
+                    , `item_id`
+                    , `quantity`
+                    , `amount`
+                    , `note`
+                    )
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                ;
+            "#,
+            self.id,
+            self.character_id,
+            kind,
+            self.item_id,
+            quantity,
+            self.amount,
+            self.note
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+}
+
+/// What `quantity` of something costs at `unit_price` copper pieces each,
+/// so long as it fits in the ledger.
+fn total_price(unit_price: u64, quantity: u64) -> crate::Result<u64> {
+    unit_price
+        .checked_mul(quantity)
+        .filter(|&price| i64::try_from(price).is_ok())
+        .ok_or_else(|| {
+            crate::Error::Validation(
+                format!("{quantity} at {unit_price} cp each is too much to trade").into(),
+            )
+        })
+}
+
+/// Buys `quantity` of an Item at the price saved for it, adding it to the
+/// Character's loose Items. The Purse, Inventory and ledger are updated
+/// together or not at all.
+pub async fn purchase(
+    db: impl Db<'_>,
+    character_id: Uuid,
+    item_id: Uuid,
+    quantity: u64,
+) -> crate::Result<LedgerEntry> {
+    if quantity == 0 {
+        return Err(crate::Error::Validation(
+            "At least one Item has to be bought".into(),
+        ));
+    }
+
+    let mut conn = db.connection().await?;
+    let mut tx = conn.begin().await?;
+
+    let item = Item::load(&mut *tx, item_id).await?;
+    let price = total_price(item.price, quantity)?;
+
+    let mut purse = Purse::load_with(&mut tx, character_id).await?;
+    purse.spend(price)?;
+    purse.save_with(&mut tx, character_id).await?;
+
+    // stacked the same way as any other Items added to the Inventory
+    let mut inventory = Inventory::load(&mut *tx, character_id).await?;
+    inventory.add(item.clone(), quantity);
+    inventory.save(&mut *tx).await?;
+
+    let entry = LedgerEntry {
+        id: Uuid::now_v7(),
+        character_id,
+        kind: TransactionKind::Purchase,
+        item_id: Some(item.uuid),
+        quantity,
+        amount: -(price as i64),
+        note: format!("Bought {quantity} {}", item.name),
+    };
+    entry.clone().record(&mut tx).await?;
+
+    tx.commit().await?;
+
+    Ok(entry)
+}
+
+/// Sells `quantity` from one of the Character's Inventory entries for
+/// `unit_price` copper pieces each.
+pub async fn sell(
+    db: impl Db<'_>,
+    character_id: Uuid,
+    entry_id: Uuid,
+    quantity: u64,
+    unit_price: u64,
+) -> crate::Result<LedgerEntry> {
+    let mut conn = db.connection().await?;
+    let mut tx = conn.begin().await?;
+
+    let row = sqlx::query!(
+        r#"
+            SELECT c_i.`item_id` AS "item_id: Uuid"
+                , c_i.`quantity`
+                , i.`name`
+            FROM `character_items` AS c_i
+            JOIN `items` AS i
+                USING (`item_id`)
+            WHERE c_i.`character_item_id` = ?1
+                AND c_i.`character_id` = ?2
+            LIMIT 1
+            ;
+        "#,
+        entry_id,
+        character_id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let available = row.quantity as u64;
+    if available < quantity {
+        Err(CurrencyError::NotEnoughItems {
+            entry_id,
+            available,
+        })?;
+    }
+
+    if available == quantity {
+        sqlx::query!(
+            "DELETE FROM `character_items` WHERE `character_item_id` = ?1;",
+            entry_id
+        )
+        .execute(&mut *tx)
+        .await?;
+    } else {
+        let count = quantity as i64;
+        sqlx::query!(
+            r#"
+                UPDATE `character_items`
+                SET `quantity` = `quantity` - ?2
+                WHERE `character_item_id` = ?1
+                ;
+            "#,
+            entry_id,
+            count
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    let price = total_price(unit_price, quantity)?;
+    let mut purse = Purse::load_with(&mut tx, character_id).await?;
+    purse.deposit(Purse::from_copper(price));
+    purse.save_with(&mut tx, character_id).await?;
+
+    let entry = LedgerEntry {
+        id: Uuid::now_v7(),
+        character_id,
+        kind: TransactionKind::Sale,
+        item_id: Some(row.item_id),
+        quantity,
+        amount: price as i64,
+        note: format!("Sold {quantity} {}", row.name),
+    };
+    entry.clone().record(&mut tx).await?;
+
+    tx.commit().await?;
+
+    Ok(entry)
+}
+
+/// Gives or takes coins outside of trading Items, such as when the GM
+/// splits up loot. A negative `amount` is paid from the Purse.
+pub async fn adjust_purse(
+    db: impl Db<'_>,
+    character_id: Uuid,
+    amount: i64,
+    note: impl Into<String>,
+) -> crate::Result<LedgerEntry> {
+    let mut conn = db.connection().await?;
+    let mut tx = conn.begin().await?;
+
+    let mut purse = Purse::load_with(&mut tx, character_id).await?;
+    let kind = if amount < 0 {
+        purse.spend(amount.unsigned_abs())?;
+        TransactionKind::Payment
+    } else {
+        purse.deposit(Purse::from_copper(amount as u64));
+        TransactionKind::Award
+    };
+    purse.save_with(&mut tx, character_id).await?;
+
+    let entry = LedgerEntry {
+        id: Uuid::now_v7(),
+        character_id,
+        kind,
+        item_id: None,
+        quantity: 0,
+        amount,
+        note: note.into(),
+    };
+    entry.clone().record(&mut tx).await?;
+
+    tx.commit().await?;
+
+    Ok(entry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _convert_copper_into_fewest_coins() {
+        let purse = Purse::from_copper(1_234);
+
+        assert_eq!(
+            purse,
+            Purse {
+                platinum: 1,
+                gold: 2,
+                silver: 3,
+                copper: 4,
+            }
+        );
+        assert_eq!(purse.total_copper(), 1_234);
+        assert_eq!(purse.to_string(), "1 pp, 2 gp, 3 sp, 4 cp");
+    }
+
+    #[test]
+    fn _spending_breaks_larger_coins_for_change() {
+        let mut purse = Purse {
+            gold: 1,
+            copper: 2,
+            ..Default::default()
+        };
+
+        purse.spend(15).unwrap();
+
+        assert_eq!(
+            purse,
+            Purse {
+                silver: 8,
+                copper: 7,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn _cannot_spend_more_than_the_purse_holds() {
+        let mut purse = Purse::from_copper(99);
+
+        assert_eq!(
+            purse.spend(100),
+            Err(CurrencyError::InsufficientFunds {
+                price: 100,
+                available: 99,
+            })
+        );
+        assert_eq!(purse.total_copper(), 99);
+    }
+}
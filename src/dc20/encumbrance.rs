@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+use crate::dc20::{Attributes, Condition, Inventory};
+
+/// Carrying capacity, in pounds, of a creature with 0 Might.
+pub const BASE_CARRYING_CAPACITY: f64 = 50.0;
+/// Carrying capacity gained, or lost, for each point of Might.
+pub const CARRYING_CAPACITY_PER_MIGHT: f64 = 25.0;
+/// No creature's capacity drops below this, however weak.
+pub const MIN_CARRYING_CAPACITY: f64 = 10.0;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum EncumbranceState {
+    #[default]
+    Unencumbered,
+    Encumbered,
+    HeavilyEncumbered,
+}
+
+impl EncumbranceState {
+    #[must_use]
+    pub fn condition(self) -> Option<Condition> {
+        match self {
+            EncumbranceState::Unencumbered => None,
+            EncumbranceState::Encumbered => Some(Condition::Encumbered),
+            EncumbranceState::HeavilyEncumbered => Some(Condition::HeavilyEncumbered),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Encumbrance {
+    pub carried: f64,
+    pub capacity: f64,
+}
+
+impl Encumbrance {
+    #[must_use]
+    pub fn new(attributes: &Attributes, inventory: &Inventory) -> Self {
+        Self {
+            carried: inventory.total_weight(),
+            capacity: carrying_capacity(attributes),
+        }
+    }
+
+    /// Encumbered past capacity, and Heavily Encumbered past double it.
+    #[must_use]
+    pub fn state(&self) -> EncumbranceState {
+        if self.carried > self.capacity * 2.0 {
+            EncumbranceState::HeavilyEncumbered
+        } else if self.carried > self.capacity {
+            EncumbranceState::Encumbered
+        } else {
+            EncumbranceState::Unencumbered
+        }
+    }
+
+    #[must_use]
+    pub fn condition(&self) -> Option<Condition> {
+        self.state().condition()
+    }
+}
+
+#[must_use]
+pub fn carrying_capacity(attributes: &Attributes) -> f64 {
+    (BASE_CARRYING_CAPACITY + CARRYING_CAPACITY_PER_MIGHT * attributes.might as f64)
+        .max(MIN_CARRYING_CAPACITY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _capacity_scales_with_might() {
+        let mut attributes = Attributes {
+            prime: 2,
+            might: 2,
+            agility: 0,
+            charisma: 0,
+            intelligence: 0,
+        };
+
+        assert_eq!(carrying_capacity(&attributes), 100.0);
+
+        attributes.might = -2;
+        assert_eq!(carrying_capacity(&attributes), 10.0);
+    }
+
+    #[test]
+    fn _state_follows_carried_weight() {
+        let encumbrance = |carried| Encumbrance {
+            carried,
+            capacity: 50.0,
+        };
+
+        assert_eq!(encumbrance(50.0).state(), EncumbranceState::Unencumbered);
+        assert_eq!(encumbrance(50.5).state(), EncumbranceState::Encumbered);
+        assert_eq!(
+            encumbrance(101.0).condition(),
+            Some(Condition::HeavilyEncumbered)
+        );
+    }
+}
@@ -0,0 +1,929 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    db::Db,
+    dc20::{
+        item::{
+            armor::Armor,
+            consumable::{Consumable, ConsumableEffect},
+            weapon::{Weapon, WeaponProperty},
+        },
+        Attack, Defense, Item, ItemKind,
+    },
+};
+
+/// The most magic items a Character can be attuned to at once.
+pub const MAX_ATTUNED_ITEMS: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum EquipmentSlot {
+    MainHand,
+    OffHand,
+    Armor,
+    Shield,
+}
+
+impl EquipmentSlot {
+    #[must_use]
+    pub fn is_hand(self) -> bool {
+        matches!(self, EquipmentSlot::MainHand | EquipmentSlot::OffHand)
+    }
+}
+
+impl fmt::Display for EquipmentSlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EquipmentSlotParseError();
+
+impl fmt::Display for EquipmentSlotParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unable to parse EquipmentSlot")
+    }
+}
+
+impl Error for EquipmentSlotParseError {}
+
+impl FromStr for EquipmentSlot {
+    type Err = EquipmentSlotParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "MainHand" => Ok(EquipmentSlot::MainHand),
+            "OffHand" => Ok(EquipmentSlot::OffHand),
+            "Armor" => Ok(EquipmentSlot::Armor),
+            "Shield" => Ok(EquipmentSlot::Shield),
+            _ => Err(EquipmentSlotParseError()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum InventoryError {
+    EntryNotFound(Uuid),
+    SlotOccupied(EquipmentSlot),
+    WrongSlot(EquipmentSlot),
+    NotAContainer(Uuid),
+    ContainerCycle(Uuid),
+    EquippedInContainer(Uuid),
+    AttunementLimit,
+    NotConsumable(Uuid),
+    NoChargesLeft(Uuid),
+    NotEnough { entry_id: Uuid, available: u64 },
+}
+
+impl fmt::Display for InventoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unable to update Inventory: {}",
+            match self {
+                InventoryError::EntryNotFound(id) => format!("no entry `{id}`"),
+                InventoryError::SlotOccupied(slot) => format!("{slot} slot is occupied"),
+                InventoryError::WrongSlot(slot) => format!("item cannot go in the {slot} slot"),
+                InventoryError::NotAContainer(id) => format!("entry `{id}` is not a container"),
+                InventoryError::ContainerCycle(id) => {
+                    format!("entry `{id}` cannot be placed inside itself")
+                }
+                InventoryError::EquippedInContainer(id) => {
+                    format!("entry `{id}` cannot be equipped while inside a container")
+                }
+                InventoryError::AttunementLimit => {
+                    format!("cannot attune to more than {MAX_ATTUNED_ITEMS} items")
+                }
+                InventoryError::NotConsumable(id) => format!("entry `{id}` is not consumable"),
+                InventoryError::NoChargesLeft(id) => format!("entry `{id}` has no charges left"),
+                InventoryError::NotEnough {
+                    entry_id,
+                    available,
+                } => format!("entry `{entry_id}` only has {available}"),
+            }
+        )
+    }
+}
+
+impl Error for InventoryError {}
+
+type Result<T> = std::result::Result<T, InventoryError>;
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct InventoryEntry {
+    pub id: Uuid,
+    pub item: Item,
+    pub weapon: Option<Weapon>,
+    pub armor: Option<Armor>,
+    /// The Consumable in use, with however many charges it has left.
+    pub consumable: Option<Consumable>,
+    pub quantity: u64,
+    pub container_id: Option<Uuid>,
+    pub slot: Option<EquipmentSlot>,
+    pub attuned: bool,
+}
+
+impl InventoryEntry {
+    #[must_use]
+    pub fn new(item: Item, quantity: u64) -> Self {
+        Self {
+            id: Uuid::now_v7(),
+            item,
+            weapon: None,
+            armor: None,
+            consumable: None,
+            quantity,
+            container_id: None,
+            slot: None,
+            attuned: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_weapon(mut self, weapon: Weapon) -> Self {
+        self.weapon = Some(weapon);
+
+        self
+    }
+
+    #[must_use]
+    pub fn with_armor(mut self, armor: Armor) -> Self {
+        self.armor = Some(armor);
+
+        self
+    }
+
+    #[must_use]
+    pub fn with_consumable(mut self, consumable: Consumable) -> Self {
+        self.consumable = Some(consumable);
+
+        self
+    }
+
+    #[must_use]
+    pub fn is_two_handed(&self) -> bool {
+        self.weapon
+            .as_ref()
+            .is_some_and(|weapon| weapon.has_property(WeaponProperty::TwoHanded))
+    }
+}
+
+/// Everything a Character carries, including what they have equipped.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Inventory {
+    pub character_id: Uuid,
+    pub entries: Vec<InventoryEntry>,
+}
+
+impl Inventory {
+    #[must_use]
+    pub fn new(character_id: Uuid) -> Self {
+        Self {
+            character_id,
+            entries: vec![],
+        }
+    }
+
+    /// The Inventory a new Character starts with from their Class's
+    /// starting equipment.
+    #[must_use]
+    pub fn from_starting_equipment(
+        character_id: Uuid,
+        equipment: impl IntoIterator<Item = (Item, u64)>,
+    ) -> Self {
+        let mut inventory = Self::new(character_id);
+
+        for (item, quantity) in equipment {
+            inventory.add(item, quantity);
+        }
+
+        inventory
+    }
+
+    pub fn entry(&self, entry_id: Uuid) -> Result<&InventoryEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.id == entry_id)
+            .ok_or(InventoryError::EntryNotFound(entry_id))
+    }
+
+    fn entry_mut(&mut self, entry_id: Uuid) -> Result<&mut InventoryEntry> {
+        self.entries
+            .iter_mut()
+            .find(|entry| entry.id == entry_id)
+            .ok_or(InventoryError::EntryNotFound(entry_id))
+    }
+
+    pub fn equipped(&self) -> impl Iterator<Item = (EquipmentSlot, &InventoryEntry)> {
+        self.entries
+            .iter()
+            .filter_map(|entry| entry.slot.map(|slot| (slot, entry)))
+    }
+
+    #[must_use]
+    pub fn in_slot(&self, slot: EquipmentSlot) -> Option<&InventoryEntry> {
+        self.equipped()
+            .find(|(equipped_slot, _)| *equipped_slot == slot)
+            .map(|(_, entry)| entry)
+    }
+
+    /// Adds loose Items to the Inventory, stacking them onto an existing
+    /// loose stack of the same Item. A Consumable stack that may have spent
+    /// charges is left alone, so the new ones keep all of theirs. Returns
+    /// the id of the entry.
+    pub fn add(&mut self, item: Item, quantity: u64) -> Uuid {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| {
+            entry.item.uuid == item.uuid
+                && entry.container_id.is_none()
+                && entry.slot.is_none()
+                && entry.consumable.is_none()
+        }) {
+            entry.quantity += quantity;
+            return entry.id;
+        }
+
+        self.add_entry(InventoryEntry::new(item, quantity))
+    }
+
+    pub fn add_entry(&mut self, entry: InventoryEntry) -> Uuid {
+        let id = entry.id;
+        self.entries.push(entry);
+
+        id
+    }
+
+    /// Removes some of an entry, dropping the entry entirely when none are
+    /// left. Anything stored inside a removed container is left loose.
+    pub fn remove(&mut self, entry_id: Uuid, quantity: u64) -> Result<()> {
+        let entry = self.entry_mut(entry_id)?;
+
+        if entry.quantity < quantity {
+            return Err(InventoryError::NotEnough {
+                entry_id,
+                available: entry.quantity,
+            });
+        }
+
+        entry.quantity -= quantity;
+
+        if entry.quantity == 0 {
+            self.entries.retain(|entry| entry.id != entry_id);
+
+            for entry in &mut self.entries {
+                if entry.container_id == Some(entry_id) {
+                    entry.container_id = None;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Puts an entry inside a container, or takes it out with `None`.
+    pub fn store(&mut self, entry_id: Uuid, container_id: Option<Uuid>) -> Result<()> {
+        if let Some(container_id) = container_id {
+            if !self.entry(container_id)?.item.is_container {
+                return Err(InventoryError::NotAContainer(container_id));
+            }
+
+            let mut ancestor = Some(container_id);
+            while let Some(id) = ancestor {
+                if id == entry_id {
+                    return Err(InventoryError::ContainerCycle(entry_id));
+                }
+                ancestor = self.entry(id)?.container_id;
+            }
+        }
+
+        let entry = self.entry_mut(entry_id)?;
+        entry.container_id = container_id;
+        if container_id.is_some() {
+            entry.slot = None;
+        }
+
+        Ok(())
+    }
+
+    /// Equips an entry, following the rules for hands: a Two-Handed Weapon
+    /// needs both hands free, and a Shield takes up the off hand. Armor and
+    /// Shields only go in their own slots.
+    pub fn equip(&mut self, entry_id: Uuid, slot: EquipmentSlot) -> Result<()> {
+        let entry = self.entry(entry_id)?;
+
+        if entry.container_id.is_some() {
+            return Err(InventoryError::EquippedInContainer(entry_id));
+        }
+
+        let fits = match &entry.armor {
+            Some(armor) => armor.armor_type.slot() == slot,
+            None => slot.is_hand(),
+        };
+        if !fits {
+            return Err(InventoryError::WrongSlot(slot));
+        }
+
+        let two_handed = entry.is_two_handed();
+        let occupied = |slot: EquipmentSlot| {
+            self.in_slot(slot)
+                .is_some_and(|equipped| equipped.id != entry_id)
+        };
+
+        let conflicts: &[EquipmentSlot] = match slot {
+            EquipmentSlot::MainHand | EquipmentSlot::OffHand if two_handed => &[
+                EquipmentSlot::MainHand,
+                EquipmentSlot::OffHand,
+                EquipmentSlot::Shield,
+            ],
+            EquipmentSlot::MainHand => &[EquipmentSlot::MainHand],
+            EquipmentSlot::OffHand | EquipmentSlot::Shield => {
+                &[EquipmentSlot::OffHand, EquipmentSlot::Shield]
+            }
+            EquipmentSlot::Armor => &[EquipmentSlot::Armor],
+        };
+
+        if let Some(&conflict) = conflicts.iter().find(|&&conflict| occupied(conflict)) {
+            return Err(InventoryError::SlotOccupied(conflict));
+        }
+
+        if slot != EquipmentSlot::Armor {
+            if let Some((held, _)) = self.equipped().find(|(held, equipped)| {
+                held.is_hand() && equipped.id != entry_id && equipped.is_two_handed()
+            }) {
+                return Err(InventoryError::SlotOccupied(held));
+            }
+        }
+
+        self.entry_mut(entry_id)?.slot = Some(slot);
+
+        Ok(())
+    }
+
+    pub fn unequip(&mut self, entry_id: Uuid) -> Result<()> {
+        self.entry_mut(entry_id)?.slot = None;
+
+        Ok(())
+    }
+
+    pub fn attune(&mut self, entry_id: Uuid, attuned: bool) -> Result<()> {
+        let already_attuned = self.entries.iter().filter(|entry| entry.attuned).count();
+
+        let entry = self.entry_mut(entry_id)?;
+        if attuned && !entry.attuned && already_attuned >= MAX_ATTUNED_ITEMS {
+            return Err(InventoryError::AttunementLimit);
+        }
+
+        entry.attuned = attuned;
+
+        Ok(())
+    }
+
+    /// Uses one charge of a Consumable, using up one of the stack once it
+    /// has none left. Using part of a stack splits the one in use off into
+    /// its own loose entry, so the rest keep their charges.
+    pub fn consume(&mut self, entry_id: Uuid) -> Result<ConsumableEffect> {
+        let entry = self.entry(entry_id)?;
+        let Some(mut consumable) = entry.consumable.clone() else {
+            return Err(InventoryError::NotConsumable(entry_id));
+        };
+
+        let effect = consumable
+            .consume()
+            .map_err(|_| InventoryError::NoChargesLeft(entry_id))?;
+
+        if consumable.charges == 0 {
+            self.remove(entry_id, 1)?;
+        } else if entry.quantity > 1 {
+            let in_use = InventoryEntry {
+                id: Uuid::now_v7(),
+                consumable: Some(consumable),
+                quantity: 1,
+                slot: None,
+                attuned: false,
+                ..entry.clone()
+            };
+            self.remove(entry_id, 1)?;
+            self.add_entry(in_use);
+        } else {
+            self.entry_mut(entry_id)?.consumable = Some(consumable);
+        }
+
+        Ok(effect)
+    }
+
+    /// Attack profiles for every Weapon held in hand.
+    #[must_use]
+    pub fn attacks(&self) -> Vec<Attack> {
+        self.equipped()
+            .filter(|(slot, _)| slot.is_hand())
+            .filter_map(|(_, entry)| {
+                entry.weapon.clone().map(|weapon| Attack {
+                    id: entry.id,
+                    ..Attack::from_weapon(entry.item.name.clone(), weapon)
+                })
+            })
+            .collect()
+    }
+
+    /// Armor and Shields currently being worn.
+    pub fn worn_armor(&self) -> impl Iterator<Item = &Armor> {
+        self.equipped()
+            .filter(|(slot, _)| !slot.is_hand())
+            .filter_map(|(_, entry)| entry.armor.as_ref())
+    }
+
+    /// Applies worn Armor to a PD calculated without it, along with the +1
+    /// PD granted by wielding Weapons with the Guard property.
+    #[must_use]
+    pub fn apply_precision_defense(&self, defense: Defense) -> Defense {
+        let guarding = self
+            .equipped()
+            .filter(|(slot, _)| slot.is_hand())
+            .filter_map(|(_, entry)| entry.weapon.as_ref())
+            .any(|weapon| weapon.has_property(WeaponProperty::Guard));
+
+        let mut defense = self.worn_armor().fold(defense, |defense, armor| {
+            armor.apply_precision_defense(defense)
+        });
+        defense.score += usize::from(guarding);
+
+        defense
+    }
+
+    /// Applies worn Armor to an AD calculated without it.
+    #[must_use]
+    pub fn apply_area_defense(&self, defense: Defense) -> Defense {
+        self.worn_armor()
+            .fold(defense, |defense, armor| armor.apply_area_defense(defense))
+    }
+
+    #[must_use]
+    pub fn speed_penalty(&self) -> u64 {
+        self.worn_armor().map(|armor| armor.speed_penalty).sum()
+    }
+
+    #[must_use]
+    pub fn has_agility_disadvantage(&self) -> bool {
+        self.worn_armor().any(|armor| armor.agility_disadvantage)
+    }
+
+    #[must_use]
+    pub fn total_weight(&self) -> f64 {
+        self.entries
+            .iter()
+            // Summing from 0.0 rather than `Sum`'s -0.0 keeps an empty pack at 0
+            .fold(0.0, |total, entry| {
+                total + entry.item.weight * entry.quantity as f64
+            })
+    }
+
+    pub async fn load(db: impl Db<'_>, character_id: Uuid) -> crate::Result<Inventory> {
+        let mut conn = db.connection().await?;
+
+        let rows = sqlx::query!(
+            r#"
+                SELECT `character_item_id` AS "id: Uuid"
+                    , `item_id` AS "item_id: Uuid"
+                    , `quantity`
+                    , `container_id` AS "container_id: Uuid"
+                    , `slot`
+                    , `attuned` AS "attuned: bool"
+                    , `charges`
+                FROM `character_items`
+                WHERE `character_id` = ?1
+                ORDER BY `character_item_id`
+                ;
+            "#,
+            character_id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut entries = vec![];
+        for row in rows {
+            let item = Item::load(&mut *conn, row.item_id).await?;
+
+            let mut entry = InventoryEntry {
+                id: row.id,
+                quantity: row.quantity as u64,
+                container_id: row.container_id,
+                slot: row
+                    .slot
+                    .map(|slot| slot.parse())
+                    .transpose()
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+                attuned: row.attuned,
+                ..InventoryEntry::new(item.clone(), 0)
+            };
+
+            match item.kind {
+                ItemKind::Weapon => entry.weapon = Some(Weapon::load(&mut *conn, item.uuid).await?),
+                ItemKind::Armor => entry.armor = Some(Armor::load(&mut *conn, item.uuid).await?),
+                ItemKind::Consumable => {
+                    let mut consumable = Consumable::load(&mut *conn, item.uuid).await?;
+                    if let Some(charges) = row.charges {
+                        consumable.charges = charges as u64;
+                    }
+                    entry.consumable = Some(consumable);
+                }
+                ItemKind::Gear => {}
+            }
+
+            entries.push(entry);
+        }
+
+        Ok(Inventory {
+            character_id,
+            entries,
+        })
+    }
+
+    /// Replaces the Character's stored Inventory with this one. The Items
+    /// themselves must already be saved.
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+        let mut tx = conn.begin().await?;
+
+        let Inventory {
+            character_id,
+            entries,
+        } = self;
+
+        sqlx::query!(
+            "DELETE FROM `character_items` WHERE `character_id` = ?1;",
+            character_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // containers are linked up afterwards so insertion order doesn't matter
+        for entry in &entries {
+            let quantity = entry.quantity as i64;
+            let slot = entry.slot.map(|slot| slot.to_string());
+            let charges = entry
+                .consumable
+                .as_ref()
+                .map(|consumable| consumable.charges as i64);
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO `character_items`
+                        ( `character_item_id`
+                        , `character_id`
+                        , `item_id`
+                        , `quantity`
+                        , `container_id`
+                        , `slot`
+                        , `attuned`
+                        , `charges`
+                        )
+                    VALUES (?1, ?2, ?3, ?4, NULL, ?5, ?6, ?7)
+                    ;
+                "#,
+                entry.id,
+                character_id,
+                entry.item.uuid,
+                quantity,
+                slot,
+                entry.attuned,
+                charges
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for entry in &entries {
+            if let Some(container_id) = entry.container_id {
+                sqlx::query!(
+                    r#"
+                        UPDATE `character_items`
+                        SET `container_id` = ?2
+                        WHERE `character_item_id` = ?1
+                        ;
+                    "#,
+                    entry.id,
+                    container_id
+                )
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+
+        Ok(tx.commit().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dc20::item::{
+        armor::ArmorType,
+        consumable::Resource,
+        weapon::{WeaponBuilder, WeaponStyle},
+    };
+
+    use super::*;
+
+    fn item(name: &str) -> Item {
+        Item {
+            uuid: Uuid::now_v7(),
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    fn defense() -> Defense {
+        Defense {
+            score: 8,
+            reduction: 0,
+        }
+    }
+
+    fn shield_entry(name: &str) -> InventoryEntry {
+        InventoryEntry::new(item(name), 1).with_armor(Armor {
+            armor_type: ArmorType::LightShield,
+            precision_defense_bonus: 1,
+            ..Default::default()
+        })
+    }
+
+    fn weapon_entry(name: &str, properties: &[WeaponProperty]) -> InventoryEntry {
+        let weapon = WeaponBuilder::new_melee()
+            .style(WeaponStyle::Sword)
+            .and_then(|builder| builder.add_properties(properties))
+            .and_then(|builder| builder.build())
+            .unwrap();
+
+        InventoryEntry::new(item(name), 1).with_weapon(weapon)
+    }
+
+    #[test]
+    fn _adding_the_same_item_stacks() {
+        let mut inventory = Inventory::new(Uuid::nil());
+        let torch = item("Torch");
+
+        let first = inventory.add(torch.clone(), 2);
+        let second = inventory.add(torch, 3);
+
+        assert_eq!(first, second);
+        assert_eq!(inventory.entry(first).unwrap().quantity, 5);
+    }
+
+    #[test]
+    fn _removing_a_container_leaves_contents_loose() -> Result<()> {
+        let mut inventory = Inventory::new(Uuid::nil());
+        let backpack = inventory.add(
+            Item {
+                is_container: true,
+                ..item("Backpack")
+            },
+            1,
+        );
+        let rope = inventory.add(item("Rope"), 1);
+
+        inventory.store(rope, Some(backpack))?;
+        assert_eq!(inventory.entry(rope)?.container_id, Some(backpack));
+
+        inventory.remove(backpack, 1)?;
+        assert_eq!(inventory.entry(rope)?.container_id, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _cannot_store_items_in_non_containers_or_themselves() -> Result<()> {
+        let mut inventory = Inventory::new(Uuid::nil());
+        let rope = inventory.add(item("Rope"), 1);
+        let bag = inventory.add(
+            Item {
+                is_container: true,
+                ..item("Bag")
+            },
+            1,
+        );
+
+        assert_eq!(
+            inventory.store(bag, Some(rope)),
+            Err(InventoryError::NotAContainer(rope))
+        );
+        assert_eq!(
+            inventory.store(bag, Some(bag)),
+            Err(InventoryError::ContainerCycle(bag))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _two_handed_weapons_need_both_hands() -> Result<()> {
+        let mut inventory = Inventory::new(Uuid::nil());
+        let greatsword =
+            inventory.add_entry(weapon_entry("Greatsword", &[WeaponProperty::TwoHanded]));
+        let shield = inventory.add_entry(shield_entry("Buckler"));
+
+        inventory.equip(shield, EquipmentSlot::Shield)?;
+        assert_eq!(
+            inventory.equip(greatsword, EquipmentSlot::MainHand),
+            Err(InventoryError::SlotOccupied(EquipmentSlot::Shield))
+        );
+
+        inventory.unequip(shield)?;
+        inventory.equip(greatsword, EquipmentSlot::MainHand)?;
+        assert_eq!(
+            inventory.equip(shield, EquipmentSlot::Shield),
+            Err(InventoryError::SlotOccupied(EquipmentSlot::MainHand))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _two_handed_weapons_need_both_hands_in_the_off_hand_too() -> Result<()> {
+        let mut inventory = Inventory::new(Uuid::nil());
+        let greatsword =
+            inventory.add_entry(weapon_entry("Greatsword", &[WeaponProperty::TwoHanded]));
+        let dagger = inventory.add_entry(weapon_entry("Dagger", &[]));
+
+        inventory.equip(dagger, EquipmentSlot::MainHand)?;
+        assert_eq!(
+            inventory.equip(greatsword, EquipmentSlot::OffHand),
+            Err(InventoryError::SlotOccupied(EquipmentSlot::MainHand))
+        );
+
+        inventory.unequip(dagger)?;
+        inventory.equip(greatsword, EquipmentSlot::OffHand)?;
+        assert_eq!(
+            inventory.equip(dagger, EquipmentSlot::MainHand),
+            Err(InventoryError::SlotOccupied(EquipmentSlot::OffHand))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _weapons_in_hand_become_attacks() -> Result<()> {
+        let mut inventory = Inventory::new(Uuid::nil());
+        let sword = inventory.add_entry(weapon_entry("Longsword", &[WeaponProperty::Guard]));
+        inventory.add_entry(weapon_entry("Spare Sword", &[]));
+
+        assert!(inventory.attacks().is_empty());
+        assert_eq!(inventory.apply_precision_defense(defense()).score, 8);
+
+        inventory.equip(sword, EquipmentSlot::MainHand)?;
+
+        let attacks = inventory.attacks();
+        assert_eq!(attacks.len(), 1);
+        assert_eq!(attacks[0].name, "Longsword");
+        assert_eq!(attacks[0].id, sword);
+        assert_eq!(inventory.apply_precision_defense(defense()).score, 9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _worn_armor_adds_to_defenses() -> Result<()> {
+        let mut inventory = Inventory::new(Uuid::nil());
+        let plate = inventory.add_entry(InventoryEntry::new(item("Plate"), 1).with_armor(Armor {
+            armor_type: ArmorType::HeavyArmor,
+            precision_defense_bonus: 2,
+            area_defense_bonus: 1,
+            precision_damage_reduction: 1,
+            speed_penalty: 1,
+            agility_disadvantage: true,
+            ..Default::default()
+        }));
+        let buckler = inventory.add_entry(shield_entry("Buckler"));
+
+        assert_eq!(
+            inventory.equip(plate, EquipmentSlot::Shield),
+            Err(InventoryError::WrongSlot(EquipmentSlot::Shield))
+        );
+
+        inventory.equip(plate, EquipmentSlot::Armor)?;
+        inventory.equip(buckler, EquipmentSlot::Shield)?;
+
+        assert_eq!(
+            inventory.apply_precision_defense(defense()),
+            Defense {
+                score: 11,
+                reduction: 1
+            }
+        );
+        assert_eq!(inventory.apply_area_defense(defense()).score, 9);
+        assert_eq!(inventory.speed_penalty(), 1);
+        assert!(inventory.has_agility_disadvantage());
+
+        Ok(())
+    }
+
+    #[test]
+    fn _consuming_the_last_charge_uses_up_one_of_the_stack() -> Result<()> {
+        let mut inventory = Inventory::new(Uuid::nil());
+        let potions = inventory.add_entry(InventoryEntry {
+            quantity: 2,
+            ..InventoryEntry::new(item("Healing Potion"), 0).with_consumable(Consumable {
+                charges: 1,
+                effect: ConsumableEffect::Restore {
+                    resource: Resource::HitPoints,
+                    amount: 2,
+                },
+                ..Default::default()
+            })
+        });
+
+        inventory.consume(potions)?;
+        assert_eq!(inventory.entry(potions)?.quantity, 1);
+
+        inventory.consume(potions)?;
+        assert_eq!(
+            inventory.entry(potions),
+            Err(InventoryError::EntryNotFound(potions))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _consuming_from_a_stack_splits_off_the_one_in_use() -> Result<()> {
+        let mut inventory = Inventory::new(Uuid::nil());
+        let wands = inventory.add_entry(InventoryEntry {
+            quantity: 3,
+            ..InventoryEntry::new(item("Wand of Sparks"), 0).with_consumable(Consumable {
+                charges: 3,
+                effect: ConsumableEffect::Restore {
+                    resource: Resource::ManaPoints,
+                    amount: 1,
+                },
+                ..Default::default()
+            })
+        });
+
+        inventory.consume(wands)?;
+
+        let rest = inventory.entry(wands)?;
+        assert_eq!(rest.quantity, 2);
+        assert_eq!(rest.consumable.as_ref().map(|wand| wand.charges), Some(3));
+        let in_use = inventory
+            .entries
+            .iter()
+            .find(|entry| entry.id != wands)
+            .expect("the wand in use to be split off");
+        assert_eq!(in_use.quantity, 1);
+        assert_eq!(in_use.consumable.as_ref().map(|wand| wand.charges), Some(2));
+
+        let in_use = in_use.id;
+        inventory.consume(in_use)?;
+        assert_eq!(inventory.entries.len(), 2);
+        assert_eq!(
+            inventory
+                .entry(in_use)?
+                .consumable
+                .as_ref()
+                .map(|wand| wand.charges),
+            Some(1)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _adding_does_not_stack_onto_used_consumables() -> Result<()> {
+        let mut inventory = Inventory::new(Uuid::nil());
+        let wand = item("Wand of Sparks");
+        let used = inventory.add_entry(InventoryEntry::new(wand.clone(), 1).with_consumable(
+            Consumable {
+                charges: 1,
+                ..Default::default()
+            },
+        ));
+
+        let added = inventory.add(wand, 2);
+
+        assert_ne!(added, used);
+        assert_eq!(inventory.entry(used)?.quantity, 1);
+        assert_eq!(inventory.entry(added)?.quantity, 2);
+        assert_eq!(inventory.entry(added)?.consumable, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _limit_attunement() -> Result<()> {
+        let mut inventory = Inventory::new(Uuid::nil());
+        let rings: Vec<_> = (0..=MAX_ATTUNED_ITEMS)
+            .map(|i| inventory.add(item(&format!("Ring {i}")), 1))
+            .collect();
+
+        for ring in &rings[..MAX_ATTUNED_ITEMS] {
+            inventory.attune(*ring, true)?;
+        }
+
+        assert_eq!(
+            inventory.attune(rings[MAX_ATTUNED_ITEMS], true),
+            Err(InventoryError::AttunementLimit)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,255 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    db::Db,
+    dc20::{Defense, EquipmentSlot},
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArmorParseError(pub String);
+
+impl fmt::Display for ArmorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unable to parse `{}`", self.0)
+    }
+}
+
+impl Error for ArmorParseError {}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum ArmorType {
+    #[default]
+    LightArmor,
+    HeavyArmor,
+    LightShield,
+    HeavyShield,
+}
+
+impl ArmorType {
+    #[must_use]
+    pub fn is_shield(self) -> bool {
+        matches!(self, ArmorType::LightShield | ArmorType::HeavyShield)
+    }
+
+    /// The only slot this kind of Armor can be equipped in.
+    #[must_use]
+    pub fn slot(self) -> EquipmentSlot {
+        if self.is_shield() {
+            EquipmentSlot::Shield
+        } else {
+            EquipmentSlot::Armor
+        }
+    }
+}
+
+impl fmt::Display for ArmorType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ArmorType::LightArmor => "Light Armor",
+                ArmorType::HeavyArmor => "Heavy Armor",
+                ArmorType::LightShield => "Light Shield",
+                ArmorType::HeavyShield => "Heavy Shield",
+            }
+        )
+    }
+}
+
+impl FromStr for ArmorType {
+    type Err = ArmorParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Light Armor" => Ok(ArmorType::LightArmor),
+            "Heavy Armor" => Ok(ArmorType::HeavyArmor),
+            "Light Shield" => Ok(ArmorType::LightShield),
+            "Heavy Shield" => Ok(ArmorType::HeavyShield),
+            _ => Err(ArmorParseError(s.into())),
+        }
+    }
+}
+
+/// Armor and Shields, along with what they cost the wearer to use.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Armor {
+    pub uuid: Uuid,
+    pub armor_type: ArmorType,
+    pub precision_defense_bonus: u64,
+    pub area_defense_bonus: u64,
+    pub precision_damage_reduction: u64,
+    pub area_damage_reduction: u64,
+    pub speed_penalty: u64,
+    pub agility_disadvantage: bool,
+}
+
+impl Armor {
+    /// Adds this Armor's bonuses to a PD calculated without it.
+    #[must_use]
+    pub fn apply_precision_defense(&self, defense: Defense) -> Defense {
+        Defense {
+            score: defense.score + self.precision_defense_bonus as usize,
+            reduction: defense.reduction + self.precision_damage_reduction as usize,
+        }
+    }
+
+    /// Adds this Armor's bonuses to an AD calculated without it.
+    #[must_use]
+    pub fn apply_area_defense(&self, defense: Defense) -> Defense {
+        Defense {
+            score: defense.score + self.area_defense_bonus as usize,
+            reduction: defense.reduction + self.area_damage_reduction as usize,
+        }
+    }
+
+    pub async fn load(executor: impl sqlx::SqliteExecutor<'_>, id: Uuid) -> crate::Result<Armor> {
+        let row = sqlx::query!(
+            r#"
+                SELECT `armor_id` AS "uuid: Uuid"
+                    , `type` AS "armor_type"
+                    , `precision_defense_bonus`
+                    , `area_defense_bonus`
+                    , `precision_damage_reduction`
+                    , `area_damage_reduction`
+                    , `speed_penalty`
+                    , `agility_disadvantage` AS "agility_disadvantage: bool"
+                FROM `armors`
+                WHERE `armor_id` = ?1
+                LIMIT 1
+                ;
+            "#,
+            id
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(Armor {
+            uuid: row.uuid,
+            armor_type: row
+                .armor_type
+                .parse()
+                .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            precision_defense_bonus: row.precision_defense_bonus as u64,
+            area_defense_bonus: row.area_defense_bonus as u64,
+            precision_damage_reduction: row.precision_damage_reduction as u64,
+            area_damage_reduction: row.area_damage_reduction as u64,
+            speed_penalty: row.speed_penalty as u64,
+            agility_disadvantage: row.agility_disadvantage,
+        })
+    }
+
+    /// Saves the Armor's details. The matching Item must already be saved.
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+
+        let Armor {
+            uuid,
+            armor_type,
+            precision_defense_bonus,
+            area_defense_bonus,
+            precision_damage_reduction,
+            area_damage_reduction,
+            speed_penalty,
+            agility_disadvantage,
+        } = self;
+
+        let armor_type = armor_type.to_string();
+        let precision_defense_bonus = precision_defense_bonus as i64;
+        let area_defense_bonus = area_defense_bonus as i64;
+        let precision_damage_reduction = precision_damage_reduction as i64;
+        let area_damage_reduction = area_damage_reduction as i64;
+        let speed_penalty = speed_penalty as i64;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `armors`
+                    ( `armor_id`
+                    , `type`
+                    , `precision_defense_bonus`
+                    , `area_defense_bonus`
+                    , `precision_damage_reduction`
+                    , `area_damage_reduction`
+                    , `speed_penalty`
+                    , `agility_disadvantage`
+                    )
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                ON CONFLICT (`armor_id`) DO UPDATE
+                    SET `type` = ?2
+                    , `precision_defense_bonus` = ?3
+                    , `area_defense_bonus` = ?4
+                    , `precision_damage_reduction` = ?5
+                    , `area_damage_reduction` = ?6
+                    , `speed_penalty` = ?7
+                    , `agility_disadvantage` = ?8
+                ;
+            "#,
+            uuid,
+            armor_type,
+            precision_defense_bonus,
+            area_defense_bonus,
+            precision_damage_reduction,
+            area_damage_reduction,
+            speed_penalty,
+            agility_disadvantage
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Deletes the Armor details, leaving the Item they belong to.
+    pub async fn delete(executor: impl sqlx::SqliteExecutor<'_>, id: Uuid) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `armors`
+                WHERE `armor_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _armor_types_round_trip_through_their_names() {
+        for armor_type in [
+            ArmorType::LightArmor,
+            ArmorType::HeavyArmor,
+            ArmorType::LightShield,
+            ArmorType::HeavyShield,
+        ] {
+            assert_eq!(armor_type.to_string().parse::<ArmorType>(), Ok(armor_type));
+        }
+    }
+
+    #[test]
+    fn _heavy_armor_adds_reduction() {
+        let armor = Armor {
+            armor_type: ArmorType::HeavyArmor,
+            precision_defense_bonus: 2,
+            precision_damage_reduction: 1,
+            ..Default::default()
+        };
+
+        let defense = armor.apply_precision_defense(Defense {
+            score: 10,
+            reduction: 0,
+        });
+
+        assert_eq!(defense.score, 12);
+        assert_eq!(defense.reduction, 1);
+    }
+}
@@ -0,0 +1,232 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::db::Db;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Resource {
+    HitPoints,
+    ManaPoints,
+    StaminaPoints,
+}
+
+impl fmt::Display for Resource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ResourceParseError();
+
+impl fmt::Display for ResourceParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unable to parse Resource")
+    }
+}
+
+impl Error for ResourceParseError {}
+
+impl FromStr for Resource {
+    type Err = ResourceParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "HitPoints" => Ok(Resource::HitPoints),
+            "ManaPoints" => Ok(Resource::ManaPoints),
+            "StaminaPoints" => Ok(Resource::StaminaPoints),
+            _ => Err(ResourceParseError()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ConsumableEffect {
+    /// Whatever the description says; nothing is tracked on the sheet.
+    #[default]
+    Narrative,
+    Restore {
+        resource: Resource,
+        amount: u64,
+    },
+}
+
+impl ConsumableEffect {
+    /// The new value of `resource` once the effect has been applied,
+    /// never going above its `maximum`.
+    #[must_use]
+    pub fn apply(&self, resource: Resource, current: u64, maximum: u64) -> u64 {
+        match *self {
+            ConsumableEffect::Restore {
+                resource: restores,
+                amount,
+            } if restores == resource => current.saturating_add(amount).min(maximum.max(current)),
+            _ => current,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConsumableError {
+    NoChargesLeft(Uuid),
+}
+
+impl fmt::Display for ConsumableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConsumableError::NoChargesLeft(id) => {
+                write!(f, "Consumable `{id}` has no charges left")
+            }
+        }
+    }
+}
+
+impl Error for ConsumableError {}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Consumable {
+    pub uuid: Uuid,
+    pub charges: u64,
+    pub effect: ConsumableEffect,
+    pub description: String,
+}
+
+impl Consumable {
+    /// Spends a charge, returning the effect to apply.
+    pub fn consume(&mut self) -> Result<ConsumableEffect, ConsumableError> {
+        if self.charges == 0 {
+            return Err(ConsumableError::NoChargesLeft(self.uuid));
+        }
+
+        self.charges -= 1;
+
+        Ok(self.effect)
+    }
+
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: Uuid,
+    ) -> crate::Result<Consumable> {
+        let row = sqlx::query!(
+            r#"
+                SELECT `consumable_id` AS "uuid: Uuid"
+                    , `charges`
+                    , `restores`
+                    , `amount`
+                    , `description`
+                FROM `consumables`
+                WHERE `consumable_id` = ?1
+                LIMIT 1
+                ;
+            "#,
+            id
+        )
+        .fetch_one(executor)
+        .await?;
+
+        let effect = match (row.restores, row.amount) {
+            (Some(resource), Some(amount)) => ConsumableEffect::Restore {
+                resource: resource
+                    .parse()
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+                amount: amount as u64,
+            },
+            _ => ConsumableEffect::Narrative,
+        };
+
+        Ok(Consumable {
+            uuid: row.uuid,
+            charges: row.charges as u64,
+            effect,
+            description: row.description,
+        })
+    }
+
+    /// Saves the Consumable's details. The matching Item must already be saved.
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+
+        let Consumable {
+            uuid,
+            charges,
+            effect,
+            description,
+        } = self;
+
+        let charges = charges as i64;
+        let (restores, amount) = match effect {
+            ConsumableEffect::Narrative => (None, None),
+            ConsumableEffect::Restore { resource, amount } => {
+                (Some(resource.to_string()), Some(amount as i64))
+            }
+        };
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `consumables`
+                    (`consumable_id`, `charges`, `restores`, `amount`, `description`)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ON CONFLICT (`consumable_id`) DO UPDATE
+                    SET `charges` = ?2
+                    , `restores` = ?3
+                    , `amount` = ?4
+                    , `description` = ?5
+                ;
+            "#,
+            uuid,
+            charges,
+            restores,
+            amount,
+            description
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Deletes the Consumable details, leaving the Item they belong to.
+    pub async fn delete(executor: impl sqlx::SqliteExecutor<'_>, id: Uuid) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `consumables`
+                WHERE `consumable_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _healing_potion_restores_hit_points_up_to_maximum() {
+        let mut potion = Consumable {
+            charges: 1,
+            effect: ConsumableEffect::Restore {
+                resource: Resource::HitPoints,
+                amount: 4,
+            },
+            ..Default::default()
+        };
+
+        let effect = potion.consume().unwrap();
+
+        assert_eq!(effect.apply(Resource::HitPoints, 3, 10), 7);
+        assert_eq!(effect.apply(Resource::HitPoints, 8, 10), 10);
+        assert_eq!(effect.apply(Resource::ManaPoints, 3, 10), 3);
+        assert_eq!(
+            potion.consume(),
+            Err(ConsumableError::NoChargesLeft(potion.uuid))
+        );
+    }
+}
@@ -1,9 +1,211 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::db::Db;
+
+pub mod armor;
+pub mod consumable;
 pub mod weapon;
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum ItemKind {
+    #[default]
+    Gear,
+    Weapon,
+    Armor,
+    Consumable,
+}
+
+impl fmt::Display for ItemKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ItemKindParseError();
+
+impl fmt::Display for ItemKindParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unable to parse ItemKind")
+    }
+}
+
+impl Error for ItemKindParseError {}
+
+impl FromStr for ItemKind {
+    type Err = ItemKindParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Gear" => Ok(ItemKind::Gear),
+            "Weapon" => Ok(ItemKind::Weapon),
+            "Armor" => Ok(ItemKind::Armor),
+            "Consumable" => Ok(ItemKind::Consumable),
+            _ => Err(ItemKindParseError()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
 pub struct Item {
     pub uuid: Uuid,
     pub name: String,
+    pub kind: ItemKind,
+    pub is_container: bool,
+    pub weight: f64,
+    /// In copper pieces.
+    pub price: u64,
+}
+
+/// Filters for [`Item::search`]. Any filter left as `None` matches every Item.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ItemSearch {
+    pub kind: Option<ItemKind>,
+    pub min_price: Option<u64>,
+    pub max_price: Option<u64>,
+}
+
+struct ItemRow {
+    uuid: Uuid,
+    name: String,
+    kind: String,
+    is_container: bool,
+    weight: f64,
+    price: i64,
+}
+
+impl TryFrom<ItemRow> for Item {
+    type Error = sqlx::Error;
+
+    fn try_from(row: ItemRow) -> Result<Self, Self::Error> {
+        Ok(Item {
+            uuid: row.uuid,
+            name: row.name,
+            kind: row
+                .kind
+                .parse()
+                .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            is_container: row.is_container,
+            weight: row.weight,
+            price: row.price as u64,
+        })
+    }
+}
+
+impl Item {
+    pub async fn load(executor: impl sqlx::SqliteExecutor<'_>, id: Uuid) -> crate::Result<Item> {
+        Ok(sqlx::query_as!(
+            ItemRow,
+            r#"
+                SELECT `item_id` AS "uuid: Uuid"
+                    , `name`
+                    , `kind`
+                    , `is_container` AS "is_container: bool"
+                    , `weight`
+                    , `price`
+                FROM `items`
+                WHERE `item_id` = ?1
+                LIMIT 1
+                ;
+            "#,
+            id
+        )
+        .fetch_one(executor)
+        .await?
+        .try_into()?)
+    }
+
+    pub async fn search(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        search: &ItemSearch,
+    ) -> crate::Result<Vec<Item>> {
+        let kind = search.kind.map(|kind| kind.to_string());
+        let min_price = search.min_price.map(|price| price as i64);
+        let max_price = search.max_price.map(|price| price as i64);
+
+        sqlx::query_as!(
+            ItemRow,
+            r#"
+                SELECT `item_id` AS "uuid: Uuid"
+                    , `name`
+                    , `kind`
+                    , `is_container` AS "is_container: bool"
+                    , `weight`
+                    , `price`
+                FROM `items`
+                WHERE (?1 IS NULL OR `kind` = ?1)
+                    AND (?2 IS NULL OR `price` >= ?2)
+                    AND (?3 IS NULL OR `price` <= ?3)
+                ORDER BY `price`, `name`
+                ;
+            "#,
+            kind,
+            min_price,
+            max_price
+        )
+        .fetch_all(executor)
+        .await?
+        .into_iter()
+        .map(|row| Ok(Item::try_from(row)?))
+        .collect()
+    }
+
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+
+        let Item {
+            uuid,
+            name,
+            kind,
+            is_container,
+            weight,
+            price,
+        } = self;
+
+        let kind = kind.to_string();
+        let price = price as i64;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `items` (`item_id`, `name`, `kind`, `is_container`, `weight`, `price`)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                ON CONFLICT (`item_id`) DO UPDATE
+                    SET `name` = ?2
+                    , `kind` = ?3
+                    , `is_container` = ?4
+                    , `weight` = ?5
+                    , `price` = ?6
+                ;
+            "#,
+            uuid,
+            name,
+            kind,
+            is_container,
+            weight,
+            price
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Deletes the Item along with its Weapon, Armor or Consumable details.
+    pub async fn delete(executor: impl sqlx::SqliteExecutor<'_>, id: Uuid) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `items`
+                WHERE `item_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
 }
@@ -1,11 +1,36 @@
 use crate::{
+    db::Db,
     dc20::Range,
     utils::{FieldAggregator, Logical, SwapResult},
 };
-use std::{error::Error, fmt};
+use serde::{Deserialize, Serialize};
+use std::{error::Error, fmt, str::FromStr};
 use uuid::Uuid;
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WeaponParseError(pub String);
+
+impl fmt::Display for WeaponParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unable to parse `{}`", self.0)
+    }
+}
+
+impl Error for WeaponParseError {}
+
+/// Strips the parts of a book name that the enums don't care about,
+/// so "Two-Handed" and "Toss (5/10)" match `TwoHanded` and `Toss`.
+fn normalize_name(name: &str) -> String {
+    name.split('(')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<String>()
+        .to_lowercase()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
 pub enum WeaponType {
     Melee,
     Ranged,
@@ -40,7 +65,19 @@ impl fmt::Display for WeaponType {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+impl FromStr for WeaponType {
+    type Err = WeaponParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match normalize_name(s).as_str() {
+            "melee" => Ok(Self::Melee),
+            "ranged" => Ok(Self::Ranged),
+            _ => Err(WeaponParseError(s.into())),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
 pub enum WeaponStyle {
     Axe,
     Bow,
@@ -97,14 +134,84 @@ impl fmt::Display for WeaponStyle {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+impl FromStr for WeaponStyle {
+    type Err = WeaponParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match normalize_name(s).as_str() {
+            "axe" => Ok(Self::Axe),
+            "bow" => Ok(Self::Bow),
+            "chained" => Ok(Self::Chained),
+            "crossbow" => Ok(Self::Crossbow),
+            "fist" => Ok(Self::Fist),
+            "hammer" => Ok(Self::Hammer),
+            "pick" => Ok(Self::Pick),
+            "spear" => Ok(Self::Spear),
+            "staff" => Ok(Self::Staff),
+            "sword" => Ok(Self::Sword),
+            "whip" => Ok(Self::Whip),
+            _ => Err(WeaponParseError(s.into())),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub enum DamageType {
     Bludgeoning,
     Piercing,
     Slashing,
+    Cold,
+    Corrosion,
+    Fire,
+    Lightning,
+    Poison,
+    Psychic,
+    Radiant,
+    Umbral,
+    Sonic,
+    True,
+}
+
+impl DamageType {
+    #[must_use]
+    pub fn is_physical(self) -> bool {
+        matches!(
+            self,
+            DamageType::Bludgeoning | DamageType::Piercing | DamageType::Slashing
+        )
+    }
+}
+
+impl fmt::Display for DamageType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl FromStr for DamageType {
+    type Err = WeaponParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match normalize_name(s).as_str() {
+            "bludgeoning" => Ok(Self::Bludgeoning),
+            "piercing" => Ok(Self::Piercing),
+            "slashing" => Ok(Self::Slashing),
+            "cold" => Ok(Self::Cold),
+            "corrosion" => Ok(Self::Corrosion),
+            "fire" => Ok(Self::Fire),
+            "lightning" => Ok(Self::Lightning),
+            "poison" => Ok(Self::Poison),
+            "psychic" => Ok(Self::Psychic),
+            "radiant" => Ok(Self::Radiant),
+            "umbral" => Ok(Self::Umbral),
+            "sonic" => Ok(Self::Sonic),
+            "true" => Ok(Self::True),
+            _ => Err(WeaponParseError(s.into())),
+        }
+    }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
 pub enum WeaponProperty {
     Ammo,
     Concealable,
@@ -221,6 +328,46 @@ impl fmt::Display for WeaponProperty {
     }
 }
 
+impl FromStr for WeaponProperty {
+    type Err = WeaponParseError;
+
+    /// Accepts both the `Display` form (`MultiFaceted(Whip)`) and the
+    /// book form (`Toss (5/10)`). A bare "Multi-Faceted" cannot be
+    /// parsed since it doesn't say which style it grants.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let normalized = normalize_name(s);
+
+        if normalized == "multifaceted" {
+            let style = s
+                .split_once('(')
+                .and_then(|(_, rest)| rest.strip_suffix(')'))
+                .ok_or(WeaponParseError(s.into()))?;
+
+            return Ok(Self::MultiFaceted(style.parse()?));
+        }
+
+        match normalized.as_str() {
+            "ammo" => Ok(Self::Ammo),
+            "concealable" => Ok(Self::Concealable),
+            "guard" => Ok(Self::Guard),
+            "heavy" => Ok(Self::Heavy),
+            "impact" => Ok(Self::Impact),
+            "longranged" => Ok(Self::LongRanged),
+            "reach" => Ok(Self::Reach),
+            "reload" => Ok(Self::Reload),
+            "silent" => Ok(Self::Silent),
+            "toss" => Ok(Self::Toss),
+            "thrown" => Ok(Self::Thrown),
+            "twohanded" => Ok(Self::TwoHanded),
+            "unwieldy" => Ok(Self::Unwieldy),
+            "versatile" => Ok(Self::Versatile),
+            "returning" => Ok(Self::Returning),
+            "capture" => Ok(Self::Capture),
+            _ => Err(WeaponParseError(s.into())),
+        }
+    }
+}
+
 impl From<WeaponStyle> for Logical<WeaponStyle> {
     fn from(value: WeaponStyle) -> Self {
         Logical::Unit(value)
@@ -549,7 +696,7 @@ impl WeaponBuilder {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct Weapon {
     pub uuid: Uuid,
     pub weapon_type: WeaponType,
@@ -564,6 +711,342 @@ impl Weapon {
     pub fn get_range(&self) -> Range {
         Range::Caster
     }
+
+    #[must_use]
+    pub fn has_property(&self, property: WeaponProperty) -> bool {
+        self.properties.contains(&property)
+    }
+
+    /// Every Weapon deals 1 damage, and the Heavy property adds another.
+    #[must_use]
+    pub fn base_damage(&self) -> u64 {
+        if self.has_property(WeaponProperty::Heavy) {
+            2
+        } else {
+            1
+        }
+    }
+
+    pub async fn load(db: impl Db<'_>, id: Uuid) -> crate::Result<Weapon> {
+        let mut conn = db.connection().await?;
+
+        let weapon = sqlx::query!(
+            r#"
+                SELECT `weapon_id` AS "id: Uuid"
+                    , `type` AS "weapon_type"
+                    , `damage_type`
+                    , `range_value`
+                FROM `weapons`
+                WHERE `weapon_id` = ?1
+                LIMIT 1
+                ;
+            "#,
+            id
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        let styles = sqlx::query!(
+            r#"
+                SELECT ws.`name`
+                    , ws.`damage_type`
+                    , w_ws.`multi_faceted` AS "multi_faceted: bool"
+                FROM `weapon_styles` AS ws
+                JOIN `weapons_weapon_styles` AS w_ws
+                    USING (`weapon_style_id`)
+                WHERE w_ws.`weapon_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .fetch_all(&mut *conn)
+        .await?
+        .into_iter()
+        .map(|style| StyleRow {
+            name: style.name,
+            damage_type: style.damage_type,
+            multi_faceted: style.multi_faceted,
+        })
+        .collect::<Vec<_>>();
+
+        let property_names = sqlx::query_scalar!(
+            r#"
+                SELECT wp.`name`
+                FROM `weapon_properties` AS wp
+                JOIN `weapons_weapon_properties` AS w_wp
+                    USING (`weapon_property_id`)
+                WHERE w_wp.`weapon_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        Ok(Weapon::from_rows(
+            weapon.id,
+            &weapon.weapon_type,
+            weapon.damage_type.as_deref(),
+            weapon.range_value,
+            &styles,
+            &property_names,
+        )?)
+    }
+
+    /// Puts a Weapon back together from its row in `weapons`, its styles and
+    /// the names of its properties, however they were stored.
+    pub(crate) fn from_rows(
+        uuid: Uuid,
+        weapon_type: &str,
+        damage_type: Option<&str>,
+        range_value: Option<i64>,
+        styles: &[StyleRow],
+        property_names: &[String],
+    ) -> std::result::Result<Weapon, sqlx::Error> {
+        let decode = |e: WeaponParseError| sqlx::Error::Decode(Box::new(e));
+
+        let weapon_type: WeaponType = weapon_type.parse().map_err(decode)?;
+
+        let primary_style = styles
+            .iter()
+            .find(|style| !style.multi_faceted)
+            .ok_or(sqlx::Error::RowNotFound)?;
+        let style: WeaponStyle = primary_style.name.parse().map_err(decode)?;
+
+        let damage_type: DamageType = damage_type
+            .unwrap_or(&primary_style.damage_type)
+            .parse()
+            .map_err(decode)?;
+
+        let mut properties = vec![];
+        for name in property_names {
+            if normalize_name(name) == "multifaceted" {
+                continue;
+            }
+
+            properties.push(name.parse().map_err(decode)?);
+        }
+        for extra_style in styles.iter().filter(|style| style.multi_faceted) {
+            properties.push(WeaponProperty::MultiFaceted(
+                extra_style.name.parse().map_err(decode)?,
+            ));
+        }
+
+        let base_range = range_value.map_or(weapon_type.default_base_range(), |spaces| {
+            Range::Spaces(spaces as u64)
+        });
+
+        Ok(Weapon {
+            uuid,
+            weapon_type,
+            style,
+            damage_type,
+            properties,
+            base_range,
+        })
+    }
+
+    /// The `type`, `damage_type` and `range_value` the Weapon is saved with.
+    pub(crate) fn columns(&self) -> (String, String, Option<i64>) {
+        let range_value = match self.base_range {
+            Range::Spaces(spaces) => Some(spaces as i64),
+            Range::Caster | Range::Touch => None,
+        };
+
+        (
+            self.weapon_type.to_string(),
+            self.damage_type.to_string(),
+            range_value,
+        )
+    }
+
+    /// Picks the rows the Weapon's styles and properties link to out of the
+    /// `(id, name)` rows of those known.
+    pub(crate) fn links(
+        &self,
+        known_styles: &[(Uuid, String)],
+        known_properties: &[(Uuid, String)],
+    ) -> crate::Result<WeaponLinks> {
+        let extra_styles = self
+            .properties
+            .iter()
+            .filter_map(|property| match property {
+                WeaponProperty::MultiFaceted(extra_style) => Some((*extra_style, true)),
+                _ => None,
+            });
+
+        let mut styles = vec![];
+        for (style, multi_faceted) in std::iter::once((self.style, false)).chain(extra_styles) {
+            let style_id = known_styles
+                .iter()
+                .find(|(_, name)| name.parse::<WeaponStyle>().is_ok_and(|s| s == style))
+                .map(|(id, _)| *id)
+                .ok_or_else(|| {
+                    crate::Error::ContentReference(format!("no Weapon Style `{style}`").into())
+                })?;
+
+            styles.push((style_id, multi_faceted));
+        }
+
+        let mut properties = vec![];
+        for &property in &self.properties {
+            let property_id = known_properties
+                .iter()
+                .find(|(_, name)| match property {
+                    WeaponProperty::MultiFaceted(_) => normalize_name(name) == "multifaceted",
+                    _ => name.parse::<WeaponProperty>().is_ok_and(|p| p == property),
+                })
+                .map(|(id, _)| *id)
+                .ok_or_else(|| {
+                    crate::Error::ContentReference(
+                        format!("no Weapon Property `{property}`").into(),
+                    )
+                })?;
+
+            properties.push(property_id);
+        }
+
+        Ok(WeaponLinks { styles, properties })
+    }
+
+    /// Saves the Weapon details of an already saved [`Item`](super::Item).
+    ///
+    /// Styles and properties are matched by name against the
+    /// `weapon_styles` and `weapon_properties` tables, so those rows must
+    /// already exist.
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+
+        let known_styles = sqlx::query!(
+            r#"
+                SELECT `weapon_style_id` AS "id: Uuid"
+                    , `name`
+                FROM `weapon_styles`
+                ;
+            "#
+        )
+        .fetch_all(&mut *conn)
+        .await?
+        .into_iter()
+        .map(|row| (row.id, row.name))
+        .collect::<Vec<_>>();
+
+        let known_properties = sqlx::query!(
+            r#"
+                SELECT `weapon_property_id` AS "id: Uuid"
+                    , `name`
+                FROM `weapon_properties`
+                ;
+            "#
+        )
+        .fetch_all(&mut *conn)
+        .await?
+        .into_iter()
+        .map(|row| (row.id, row.name))
+        .collect::<Vec<_>>();
+
+        let WeaponLinks { styles, properties } = self.links(&known_styles, &known_properties)?;
+        let (weapon_type, damage_type, range_value) = self.columns();
+        let uuid = self.uuid;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `weapons` (`weapon_id`, `type`, `damage_type`, `range_value`)
+                VALUES (?1, ?2, ?3, ?4)
+                ON CONFLICT (`weapon_id`) DO UPDATE
+                    SET `type` = ?2
+                    , `damage_type` = ?3
+                    , `range_value` = ?4
+                ;
+            "#,
+            uuid,
+            weapon_type,
+            damage_type,
+            range_value
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        sqlx::query!(
+            "DELETE FROM `weapons_weapon_styles` WHERE `weapon_id` = ?1;",
+            uuid
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        sqlx::query!(
+            "DELETE FROM `weapons_weapon_properties` WHERE `weapon_id` = ?1;",
+            uuid
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        for (style_id, multi_faceted) in styles {
+            sqlx::query!(
+                r#"
+                    INSERT INTO `weapons_weapon_styles`
+                        (`weapon_id`, `weapon_style_id`, `multi_faceted`)
+                    VALUES (?1, ?2, ?3)
+                    ON CONFLICT (`weapon_id`, `weapon_style_id`) DO NOTHING
+                    ;
+                "#,
+                uuid,
+                style_id,
+                multi_faceted
+            )
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        for property_id in properties {
+            sqlx::query!(
+                r#"
+                    INSERT INTO `weapons_weapon_properties`
+                        (`weapon_id`, `weapon_property_id`)
+                    VALUES (?1, ?2)
+                    ON CONFLICT (`weapon_id`, `weapon_property_id`) DO NOTHING
+                    ;
+                "#,
+                uuid,
+                property_id
+            )
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Deletes the Weapon details, leaving the Item they belong to.
+    pub async fn delete(executor: impl sqlx::SqliteExecutor<'_>, id: Uuid) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `weapons`
+                WHERE `weapon_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+/// The `weapon_styles` and `weapon_properties` rows a Weapon links to.
+pub(crate) struct WeaponLinks {
+    /// Each style's id, with whether the Multi-Faceted property granted it.
+    pub styles: Vec<(Uuid, bool)>,
+    pub properties: Vec<Uuid>,
+}
+
+/// One of a Weapon's styles as stored, with the damage it deals by default.
+pub(crate) struct StyleRow {
+    pub name: String,
+    pub damage_type: String,
+    /// Whether the Multi-Faceted property granted the style.
+    pub multi_faceted: bool,
 }
 
 impl TryFrom<WeaponBuilder> for Weapon {
@@ -946,6 +1429,41 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn _parse_property_from_book_and_display_names() {
+        assert_eq!(
+            "Two-Handed".parse::<WeaponProperty>(),
+            Ok(WeaponProperty::TwoHanded)
+        );
+        assert_eq!(
+            "Toss (5/10)".parse::<WeaponProperty>(),
+            Ok(WeaponProperty::Toss)
+        );
+        assert_eq!(
+            WeaponProperty::MultiFaceted(WeaponStyle::Whip)
+                .to_string()
+                .parse::<WeaponProperty>(),
+            Ok(WeaponProperty::MultiFaceted(WeaponStyle::Whip))
+        );
+        assert!("Multi-Faceted".parse::<WeaponProperty>().is_err());
+    }
+
+    #[test]
+    fn _heavy_weapons_deal_an_extra_point_of_damage() -> Result<()> {
+        let sword = WeaponBuilder::new_melee()
+            .style(WeaponStyle::Sword)?
+            .build()?;
+        assert_eq!(sword.base_damage(), 1);
+
+        let great_axe = WeaponBuilder::new_melee()
+            .style(WeaponStyle::Axe)?
+            .add_properties(&[WeaponProperty::Heavy, WeaponProperty::TwoHanded])?
+            .build()?;
+        assert_eq!(great_axe.base_damage(), 2);
+
+        Ok(())
+    }
+
     #[test]
     #[ignore = "not yet implemented"]
     fn _cannot_have_duplicate_style_in_multi_faceted_property() -> Result<()> {
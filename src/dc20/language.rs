@@ -1,4 +1,9 @@
-#[derive(turann::Builder, Clone, Debug, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+use crate::db::Db;
+
+#[derive(turann::Builder, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "LanguageFields")]
 pub struct Language {
     #[builder(default = uuid::Uuid::now_v7)]
     pub id: uuid::Uuid,
@@ -6,6 +11,28 @@ pub struct Language {
     pub name: String,
 }
 
+/// A [`Language`] as it comes in, built up through its builder so that it is
+/// checked the same way.
+#[derive(Deserialize)]
+struct LanguageFields {
+    id: Option<uuid::Uuid>,
+    name: String,
+}
+
+impl TryFrom<LanguageFields> for Language {
+    type Error = LanguageBuilderError;
+
+    fn try_from(fields: LanguageFields) -> Result<Self, Self::Error> {
+        let mut builder = Language::builder();
+        builder.name(fields.name)?;
+        if let Some(id) = fields.id {
+            builder.id(id);
+        }
+
+        builder.build()
+    }
+}
+
 impl LanguageBuilder {
     fn validate_name(name: impl Into<String>) -> Result<String, LanguageBuilderError> {
         let name: String = name.into();
@@ -22,8 +49,11 @@ impl LanguageBuilder {
 }
 
 impl Language {
-    pub async fn load(pool: &sqlx::SqlitePool, id: uuid::Uuid) -> sqlx::Result<Language> {
-        sqlx::query_as!(
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: uuid::Uuid,
+    ) -> crate::Result<Language> {
+        Ok(sqlx::query_as!(
             Language,
             r#"
                 SELECT `language_id` AS "id: uuid::Uuid"
@@ -35,12 +65,27 @@ impl Language {
             "#,
             id
         )
-        .fetch_one(pool)
-        .await
+        .fetch_one(executor)
+        .await?)
     }
 
-    pub async fn save(self, pool: &mut sqlx::SqlitePool) -> sqlx::Result<()> {
-        let mut conn = pool.acquire().await?;
+    pub async fn list(executor: impl sqlx::SqliteExecutor<'_>) -> crate::Result<Vec<Language>> {
+        Ok(sqlx::query_as!(
+            Language,
+            r#"
+                SELECT `language_id` AS "id: uuid::Uuid"
+                    , `name`
+                FROM `languages`
+                ORDER BY `name`
+                ;
+            "#
+        )
+        .fetch_all(executor)
+        .await?)
+    }
+
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
 
         let Language { id, name } = self;
 
@@ -60,4 +105,22 @@ impl Language {
 
         Ok(())
     }
+
+    pub async fn delete(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: uuid::Uuid,
+    ) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `languages`
+                WHERE `language_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
 }
@@ -1,7 +1,12 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
 use turann::Builder;
 use uuid::Uuid;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+use crate::db::Db;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub enum ManeuverKind {
     Attack,
     Save,
@@ -9,7 +14,38 @@ pub enum ManeuverKind {
     Defense,
 }
 
-#[derive(Builder, Clone, Debug, PartialEq)]
+impl fmt::Display for ManeuverKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ManeuverKindParseError();
+
+impl fmt::Display for ManeuverKindParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unable to parse ManeuverKind")
+    }
+}
+
+impl Error for ManeuverKindParseError {}
+
+impl FromStr for ManeuverKind {
+    type Err = ManeuverKindParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Attack" => Ok(ManeuverKind::Attack),
+            "Save" => Ok(ManeuverKind::Save),
+            "Grapple" => Ok(ManeuverKind::Grapple),
+            "Defense" => Ok(ManeuverKind::Defense),
+            _ => Err(ManeuverKindParseError()),
+        }
+    }
+}
+
+#[derive(Builder, Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct Maneuver {
     #[builder(default = Uuid::new_v4)]
     pub uuid: Uuid,
@@ -19,3 +55,145 @@ pub struct Maneuver {
     pub stamina_point_cost: u64,
     pub description: String,
 }
+
+impl Maneuver {
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: Uuid,
+    ) -> crate::Result<Maneuver> {
+        let row = sqlx::query!(
+            r#"
+                SELECT `maneuver_id` AS "uuid: Uuid"
+                    , `name`
+                    , `kind`
+                    , `action_point_cost`
+                    , `stamina_point_cost`
+                    , `description`
+                FROM `maneuvers`
+                WHERE `maneuver_id` = ?1
+                LIMIT 1
+                ;
+            "#,
+            id
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(Maneuver {
+            uuid: row.uuid,
+            name: row.name,
+            kind: row
+                .kind
+                .parse()
+                .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            action_point_cost: row.action_point_cost as u64,
+            stamina_point_cost: row.stamina_point_cost as u64,
+            description: row.description,
+        })
+    }
+
+    pub async fn list(db: impl Db<'_>) -> crate::Result<Vec<Maneuver>> {
+        let mut conn = db.connection().await?;
+
+        let ids = sqlx::query_scalar!(
+            r#"
+                SELECT `maneuver_id` AS "id: Uuid"
+                FROM `maneuvers`
+                ORDER BY `name`
+                ;
+            "#
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut maneuvers = vec![];
+
+        for id in ids {
+            maneuvers.push(Self::load(&mut *conn, id).await?);
+        }
+
+        Ok(maneuvers)
+    }
+
+    pub async fn load_by_name(db: impl Db<'_>, name: &str) -> crate::Result<Maneuver> {
+        let mut conn = db.connection().await?;
+
+        let id = sqlx::query_scalar!(
+            r#"
+                SELECT `maneuver_id` AS "id: Uuid"
+                FROM `maneuvers`
+                WHERE `name` = ?1 COLLATE NOCASE
+                LIMIT 1
+                ;
+            "#,
+            name
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Self::load(&mut *conn, id).await
+    }
+
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+
+        let Maneuver {
+            uuid,
+            name,
+            kind,
+            action_point_cost,
+            stamina_point_cost,
+            description,
+        } = self;
+
+        let kind = kind.to_string();
+        let action_point_cost = action_point_cost as i64;
+        let stamina_point_cost = stamina_point_cost as i64;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `maneuvers`
+                    ( `maneuver_id`
+                    , `name`
+                    , `kind`
+                    , `action_point_cost`
+                    , `stamina_point_cost`
+                    , `description`
+                    )
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                ON CONFLICT (`maneuver_id`) DO UPDATE
+                    SET `name` = ?2
+                    , `kind` = ?3
+                    , `action_point_cost` = ?4
+                    , `stamina_point_cost` = ?5
+                    , `description` = ?6
+                ;
+            "#,
+            uuid,
+            name,
+            kind,
+            action_point_cost,
+            stamina_point_cost,
+            description
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(executor: impl sqlx::SqliteExecutor<'_>, id: Uuid) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `maneuvers`
+                WHERE `maneuver_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
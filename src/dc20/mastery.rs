@@ -1,4 +1,6 @@
-#[derive(turann::Builder, Clone, Debug, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(turann::Builder, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Mastery {
     #[builder(default = uuid::Uuid::now_v7)]
     pub id: uuid::Uuid,
@@ -7,8 +9,11 @@ pub struct Mastery {
 }
 
 impl Mastery {
-    pub async fn load(pool: &sqlx::SqlitePool, id: uuid::Uuid) -> sqlx::Result<Mastery> {
-        sqlx::query_as!(
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: uuid::Uuid,
+    ) -> crate::Result<Mastery> {
+        Ok(sqlx::query_as!(
             Mastery,
             r#"
                 SELECT `mastery_id` AS "id: uuid::Uuid",
@@ -20,7 +25,7 @@ impl Mastery {
             "#,
             id
         )
-        .fetch_one(pool)
-        .await
+        .fetch_one(executor)
+        .await?)
     }
 }
@@ -3,12 +3,22 @@ pub mod attribute;
 pub mod background;
 pub mod character;
 pub mod class;
+pub mod combat;
+pub mod condition;
+pub mod creature;
+pub mod currency;
+pub mod encumbrance;
+pub mod inventory;
 pub mod item;
 pub mod language;
 pub mod maneuver;
 pub mod mastery;
+pub mod resources;
+pub mod roll;
 pub mod skill;
+pub mod source;
 pub mod spell;
+pub mod tracker;
 pub mod trade;
 
 pub use ancestry::*;
@@ -16,10 +26,20 @@ pub use attribute::*;
 pub use background::*;
 pub use character::*;
 pub use class::*;
+pub use combat::*;
+pub use condition::*;
+pub use creature::*;
+pub use currency::*;
+pub use encumbrance::*;
+pub use inventory::*;
 pub use item::*;
 pub use language::*;
 pub use maneuver::*;
 pub use mastery::*;
+pub use resources::*;
+pub use roll::*;
 pub use skill::*;
+pub use source::*;
 pub use spell::*;
+pub use tracker::*;
 pub use trade::*;
@@ -0,0 +1,237 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    db::Db,
+    dc20::{
+        item::consumable::{ConsumableEffect, Resource},
+        Attributes, ClassTable,
+    },
+};
+
+/// A resource spent and recovered during play, such as Hit Points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ResourcePool {
+    pub current: u64,
+    pub maximum: u64,
+}
+
+impl ResourcePool {
+    #[must_use]
+    pub fn full(maximum: u64) -> Self {
+        Self {
+            current: maximum,
+            maximum,
+        }
+    }
+
+    /// Spends (negative `amount`) or recovers the resource, never going
+    /// below 0 or above its maximum.
+    pub fn adjust(&mut self, amount: i64) {
+        self.current = self.current.saturating_add_signed(amount).min(self.maximum);
+    }
+
+    /// Changes the maximum, pulling the current value down with it if needed.
+    pub fn adjust_maximum(&mut self, amount: i64) {
+        self.maximum = self.maximum.saturating_add_signed(amount);
+        self.current = self.current.min(self.maximum);
+    }
+}
+
+/// The Hit, Mana and Stamina Points a Character has left.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Resources {
+    pub hit_points: ResourcePool,
+    pub mana_points: ResourcePool,
+    pub stamina_points: ResourcePool,
+}
+
+impl Resources {
+    /// Full pools for a new Character: what their Class gives at Level 1,
+    /// with Might added to Hit Points.
+    #[must_use]
+    pub fn starting(table: &ClassTable, attributes: &Attributes) -> Self {
+        Self {
+            hit_points: ResourcePool::full(
+                (table.health_points.get(1) as u64).saturating_add_signed(attributes.might),
+            ),
+            mana_points: ResourcePool::full(table.mana_points.get(1) as u64),
+            stamina_points: ResourcePool::full(table.stamina_points.get(1) as u64),
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, resource: Resource) -> &ResourcePool {
+        match resource {
+            Resource::HitPoints => &self.hit_points,
+            Resource::ManaPoints => &self.mana_points,
+            Resource::StaminaPoints => &self.stamina_points,
+        }
+    }
+
+    pub fn get_mut(&mut self, resource: Resource) -> &mut ResourcePool {
+        match resource {
+            Resource::HitPoints => &mut self.hit_points,
+            Resource::ManaPoints => &mut self.mana_points,
+            Resource::StaminaPoints => &mut self.stamina_points,
+        }
+    }
+
+    /// Applies a Consumable's effect to whichever resource it restores.
+    pub fn apply(&mut self, effect: ConsumableEffect) {
+        if let ConsumableEffect::Restore { resource, .. } = effect {
+            let pool = self.get_mut(resource);
+            pool.current = effect.apply(resource, pool.current, pool.maximum);
+        }
+    }
+
+    /// Every resource back to its maximum, as after a Full Rest.
+    pub fn restore_all(&mut self) {
+        for pool in [
+            &mut self.hit_points,
+            &mut self.mana_points,
+            &mut self.stamina_points,
+        ] {
+            pool.current = pool.maximum;
+        }
+    }
+
+    /// Loads the Character's resources. A Character who has never had them
+    /// saved has none.
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        character_id: Uuid,
+    ) -> crate::Result<Resources> {
+        let row = sqlx::query!(
+            r#"
+                SELECT `hit_points`
+                    , `max_hit_points`
+                    , `mana_points`
+                    , `max_mana_points`
+                    , `stamina_points`
+                    , `max_stamina_points`
+                FROM `character_resources`
+                WHERE `character_id` = ?1
+                LIMIT 1
+                ;
+            "#,
+            character_id
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(row
+            .map(|row| Resources {
+                hit_points: ResourcePool {
+                    current: row.hit_points as u64,
+                    maximum: row.max_hit_points as u64,
+                },
+                mana_points: ResourcePool {
+                    current: row.mana_points as u64,
+                    maximum: row.max_mana_points as u64,
+                },
+                stamina_points: ResourcePool {
+                    current: row.stamina_points as u64,
+                    maximum: row.max_stamina_points as u64,
+                },
+            })
+            .unwrap_or_default())
+    }
+
+    pub async fn save(self, db: impl Db<'_>, character_id: Uuid) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+
+        let Resources {
+            hit_points,
+            mana_points,
+            stamina_points,
+        } = self;
+
+        let (hit_points, max_hit_points) = (hit_points.current as i64, hit_points.maximum as i64);
+        let (mana_points, max_mana_points) =
+            (mana_points.current as i64, mana_points.maximum as i64);
+        let (stamina_points, max_stamina_points) =
+            (stamina_points.current as i64, stamina_points.maximum as i64);
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `character_resources`
+                    ( `character_id`
+                    , `hit_points`
+                    , `max_hit_points`
+                    , `mana_points`
+                    , `max_mana_points`
+                    , `stamina_points`
+                    , `max_stamina_points`
+                    )
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                ON CONFLICT (`character_id`) DO UPDATE
+                    SET `hit_points` = ?2
+                    , `max_hit_points` = ?3
+                    , `mana_points` = ?4
+                    , `max_mana_points` = ?5
+                    , `stamina_points` = ?6
+                    , `max_stamina_points` = ?7
+                ;
+            "#,
+            character_id,
+            hit_points,
+            max_hit_points,
+            mana_points,
+            max_mana_points,
+            stamina_points,
+            max_stamina_points
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _resources_stay_between_zero_and_maximum() {
+        let mut resources = Resources {
+            hit_points: ResourcePool::full(10),
+            ..Default::default()
+        };
+
+        resources.get_mut(Resource::HitPoints).adjust(-4);
+        assert_eq!(resources.hit_points.current, 6);
+
+        resources.hit_points.adjust(-20);
+        assert_eq!(resources.hit_points.current, 0);
+
+        resources.apply(ConsumableEffect::Restore {
+            resource: Resource::HitPoints,
+            amount: 15,
+        });
+        assert_eq!(resources.hit_points.current, 10);
+
+        resources.hit_points.adjust_maximum(-3);
+        assert_eq!(resources.hit_points, ResourcePool::full(7));
+    }
+
+    #[test]
+    fn _a_new_character_starts_with_full_pools_from_their_class() {
+        let table = ClassTable {
+            health_points: [9, 1, 1, 1, 1, 1, 1, 1, 1, 1].into(),
+            stamina_points: [1, 0, 1, 0, 0, 1, 0, 0, 1, 0].into(),
+            ..Default::default()
+        };
+        let attributes = Attributes {
+            might: 2,
+            ..Default::default()
+        };
+
+        let resources = Resources::starting(&table, &attributes);
+
+        assert_eq!(resources.hit_points, ResourcePool::full(11));
+        assert_eq!(resources.mana_points, ResourcePool::full(0));
+        assert_eq!(resources.stamina_points, ResourcePool::full(1));
+    }
+}
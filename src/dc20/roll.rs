@@ -0,0 +1,137 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// DC20 lets ADV and DisADV stack: every level rolls one more d20,
+/// keeping the highest (ADV) or lowest (DisADV) result.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum RollMode {
+    #[default]
+    Normal,
+    Advantage(u8),
+    Disadvantage(u8),
+}
+
+impl RollMode {
+    fn net(self) -> i16 {
+        match self {
+            RollMode::Normal => 0,
+            RollMode::Advantage(n) => n as i16,
+            RollMode::Disadvantage(n) => -(n as i16),
+        }
+    }
+
+    fn from_net(net: i16) -> Self {
+        match net {
+            0 => RollMode::Normal,
+            n if n > 0 => RollMode::Advantage(n.min(u8::MAX as i16) as u8),
+            n => RollMode::Disadvantage((-n).min(u8::MAX as i16) as u8),
+        }
+    }
+
+    /// ADV and DisADV cancel each other out one for one.
+    #[must_use]
+    pub fn combine(self, other: Self) -> Self {
+        Self::from_net(self.net() + other.net())
+    }
+
+    #[must_use]
+    pub fn dice_count(self) -> usize {
+        1 + self.net().unsigned_abs() as usize
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CheckRoll {
+    pub dice: Vec<u8>,
+    pub mode: RollMode,
+    pub bonus: i64,
+}
+
+impl CheckRoll {
+    /// The d20 that counts, after ADV or DisADV has been applied.
+    #[must_use]
+    pub fn natural(&self) -> u8 {
+        let dice = self.dice.iter().copied();
+
+        match self.mode {
+            RollMode::Disadvantage(_) => dice.min(),
+            RollMode::Normal | RollMode::Advantage(_) => dice.max(),
+        }
+        .unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn total(&self) -> i64 {
+        self.natural() as i64 + self.bonus
+    }
+
+    #[must_use]
+    pub fn is_critical_success(&self) -> bool {
+        self.natural() == 20
+    }
+
+    #[must_use]
+    pub fn is_critical_failure(&self) -> bool {
+        self.natural() == 1
+    }
+}
+
+pub fn roll_die<R: Rng + ?Sized>(rng: &mut R, sides: u8) -> u8 {
+    rng.random_range(1..=sides)
+}
+
+pub fn roll_check<R: Rng + ?Sized>(rng: &mut R, bonus: i64, mode: RollMode) -> CheckRoll {
+    let dice = (0..mode.dice_count()).map(|_| roll_die(rng, 20)).collect();
+
+    CheckRoll { dice, mode, bonus }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn _advantage_and_disadvantage_cancel_out() {
+        assert_eq!(
+            RollMode::Advantage(2).combine(RollMode::Disadvantage(1)),
+            RollMode::Advantage(1)
+        );
+        assert_eq!(
+            RollMode::Advantage(1).combine(RollMode::Disadvantage(1)),
+            RollMode::Normal
+        );
+        assert_eq!(
+            RollMode::Normal.combine(RollMode::Disadvantage(2)),
+            RollMode::Disadvantage(2)
+        );
+    }
+
+    #[test]
+    fn _keep_highest_with_advantage_and_lowest_with_disadvantage() {
+        let mut roll = CheckRoll {
+            dice: vec![4, 17, 9],
+            mode: RollMode::Advantage(2),
+            bonus: 3,
+        };
+        assert_eq!(roll.natural(), 17);
+        assert_eq!(roll.total(), 20);
+
+        roll.mode = RollMode::Disadvantage(2);
+        assert_eq!(roll.natural(), 4);
+        assert_eq!(roll.total(), 7);
+    }
+
+    #[test]
+    fn _roll_one_extra_die_per_level_of_advantage() {
+        let mut rng = StdRng::seed_from_u64(20);
+
+        let roll = roll_check(&mut rng, 0, RollMode::Advantage(2));
+        assert_eq!(roll.dice.len(), 3);
+        assert!(roll.dice.iter().all(|die| (1..=20).contains(die)));
+
+        let roll = roll_check(&mut rng, 0, RollMode::Normal);
+        assert_eq!(roll.dice.len(), 1);
+    }
+}
@@ -1,9 +1,13 @@
+use serde::{Deserialize, Serialize};
 use turann::Builder;
 use uuid::Uuid;
 
-use crate::dc20::Attribute;
+use crate::{
+    db::Db,
+    dc20::{Attribute, AttributeName, Attributes, Mastery},
+};
 
-#[derive(Builder, Clone, Debug, PartialEq)]
+#[derive(Builder, Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct Skill {
     #[builder(default = Uuid::now_v7)]
     pub id: Uuid,
@@ -12,8 +16,8 @@ pub struct Skill {
 }
 
 impl Skill {
-    pub async fn load(pool: &sqlx::SqlitePool, id: Uuid) -> sqlx::Result<Skill> {
-        sqlx::query_as!(
+    pub async fn load(executor: impl sqlx::SqliteExecutor<'_>, id: Uuid) -> crate::Result<Skill> {
+        Ok(sqlx::query_as!(
             Skill,
             r#"
                 SELECT `skill_id` AS "id: Uuid"
@@ -26,12 +30,28 @@ impl Skill {
             "#,
             id
         )
-        .fetch_one(pool)
-        .await
+        .fetch_one(executor)
+        .await?)
     }
 
-    pub async fn save(self, pool: &sqlx::SqlitePool) -> sqlx::Result<()> {
-        let mut conn = pool.acquire().await?;
+    pub async fn list(executor: impl sqlx::SqliteExecutor<'_>) -> crate::Result<Vec<Skill>> {
+        Ok(sqlx::query_as!(
+            Skill,
+            r#"
+                SELECT `skill_id` AS "id: Uuid"
+                    , `name`
+                    , `attribute_id` AS "attribute_id: Uuid"
+                FROM skills
+                ORDER BY `name`
+                ;
+            "#
+        )
+        .fetch_all(executor)
+        .await?)
+    }
+
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
 
         let Skill {
             id,
@@ -57,12 +77,30 @@ impl Skill {
         Ok(())
     }
 
-    pub async fn load_attribute(&self, pool: &sqlx::SqlitePool) -> sqlx::Result<Attribute> {
+    pub async fn delete(executor: impl sqlx::SqliteExecutor<'_>, id: Uuid) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM skills
+                WHERE `skill_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn load_attribute(
+        &self,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> crate::Result<Attribute> {
         let Skill {
             ref attribute_id, ..
         } = self;
 
-        sqlx::query_as!(
+        Ok(sqlx::query_as!(
             Attribute,
             r#"
                 SELECT `attribute_id` AS "id: uuid::Uuid"
@@ -74,22 +112,69 @@ impl Skill {
             "#,
             attribute_id
         )
-        .fetch_one(pool)
-        .await
+        .fetch_one(executor)
+        .await?)
+    }
+}
+
+/// A Skill as a Character checks it: with its Attribute, and the Mastery
+/// they have in it if they have trained it.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SkillCheck {
+    pub skill: Skill,
+    pub attribute: AttributeName,
+    pub mastery: Option<Mastery>,
+}
+
+impl SkillCheck {
+    /// What is added to a Check with the Skill.
+    #[must_use]
+    pub fn bonus(&self, attributes: &Attributes) -> i64 {
+        attributes.get(self.attribute)
+            + self
+                .mastery
+                .as_ref()
+                .map_or(0, |mastery| i64::from(mastery.bonus))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    fn check(mastery: Option<Mastery>) -> SkillCheck {
+        SkillCheck {
+            skill: Skill {
+                id: Uuid::nil(),
+                name: "Athletics".into(),
+                attribute_id: Uuid::nil(),
+            },
+            attribute: AttributeName::Might,
+            mastery,
+        }
+    }
+
+    fn attributes() -> Attributes {
+        Attributes {
+            prime: 3,
+            might: 3,
+            ..Default::default()
+        }
+    }
+
     #[test]
-    #[ignore = "API changed"]
     fn _skill_without_mastery_should_have_same_score_as_attribute() {
-        todo!()
+        assert_eq!(check(None).bonus(&attributes()), 3);
     }
 
     #[test]
-    #[ignore = "API changed"]
     fn _skill_with_mastery_should_add_mastery_value_to_score() {
-        todo!()
+        let adept = Mastery {
+            id: Uuid::nil(),
+            name: "Adept".into(),
+            bonus: 4,
+        };
+
+        assert_eq!(check(Some(adept)).bonus(&attributes()), 7);
     }
 }
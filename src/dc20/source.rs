@@ -0,0 +1,209 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A rulebook, supplement or homebrew collection that content comes from.
+#[derive(turann::Builder, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "SourceFields")]
+pub struct Source {
+    #[builder(default = Uuid::now_v7)]
+    pub id: Uuid,
+    #[builder(validate = SourceBuilder::validate_name)]
+    pub name: String,
+}
+
+/// A [`Source`] as it comes in, built up through its builder so that it is
+/// checked the same way.
+#[derive(Deserialize)]
+struct SourceFields {
+    id: Option<Uuid>,
+    name: String,
+}
+
+impl TryFrom<SourceFields> for Source {
+    type Error = SourceBuilderError;
+
+    fn try_from(fields: SourceFields) -> Result<Self, Self::Error> {
+        let mut builder = Source::builder();
+        builder.name(fields.name)?;
+        if let Some(id) = fields.id {
+            builder.id(id);
+        }
+
+        builder.build()
+    }
+}
+
+impl SourceBuilder {
+    fn validate_name(name: impl Into<String>) -> Result<String, SourceBuilderError> {
+        let name: String = name.into();
+
+        if name.is_empty() {
+            return Err(SourceBuilderError::InvalidField {
+                field_name: "name".into(),
+                message: "cannot be empty".into(),
+            });
+        }
+
+        Ok(name)
+    }
+}
+
+impl Source {
+    pub async fn load(executor: impl sqlx::SqliteExecutor<'_>, id: Uuid) -> crate::Result<Source> {
+        Ok(sqlx::query_as!(
+            Source,
+            r#"
+                SELECT `source_id` AS "id: Uuid"
+                    , `name`
+                FROM `sources`
+                WHERE `source_id` = ?1
+                LIMIT 1
+                ;
+            "#,
+            id
+        )
+        .fetch_one(executor)
+        .await?)
+    }
+
+    pub async fn load_by_name(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        name: &str,
+    ) -> crate::Result<Source> {
+        Ok(sqlx::query_as!(
+            Source,
+            r#"
+                SELECT `source_id` AS "id: Uuid"
+                    , `name`
+                FROM `sources`
+                WHERE `name` = ?1 COLLATE NOCASE
+                LIMIT 1
+                ;
+            "#,
+            name
+        )
+        .fetch_one(executor)
+        .await?)
+    }
+
+    pub async fn list(executor: impl sqlx::SqliteExecutor<'_>) -> crate::Result<Vec<Source>> {
+        Ok(sqlx::query_as!(
+            Source,
+            r#"
+                SELECT `source_id` AS "id: Uuid"
+                    , `name`
+                FROM `sources`
+                ORDER BY `name`
+                ;
+            "#
+        )
+        .fetch_all(executor)
+        .await?)
+    }
+
+    pub async fn save(self, executor: impl sqlx::SqliteExecutor<'_>) -> crate::Result<()> {
+        let Source { id, name } = self;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `sources` (`source_id`, `name`)
+                VALUES (?1, ?2)
+                ON CONFLICT (`source_id`) DO UPDATE
+                    SET `name` = ?2
+                ;
+            "#,
+            id,
+            name
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Deletes the Source. Its content stays, without a Source.
+    pub async fn delete(executor: impl sqlx::SqliteExecutor<'_>, id: Uuid) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `sources`
+                WHERE `source_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+/// The Sources content may come from. Loaders that take an
+/// `Option<&SourceFilter>` allow everything when given `None`; with a filter,
+/// content without a Source is left out along with content from any Source
+/// not on it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SourceFilter {
+    pub sources: HashSet<Uuid>,
+}
+
+impl SourceFilter {
+    #[must_use]
+    pub fn new(sources: impl IntoIterator<Item = Uuid>) -> Self {
+        Self {
+            sources: sources.into_iter().collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn allows(&self, source: Option<Uuid>) -> bool {
+        source.is_some_and(|source| self.sources.contains(&source))
+    }
+
+    /// Content is allowed when both filters allow it.
+    #[must_use]
+    pub fn intersect(&self, other: &SourceFilter) -> SourceFilter {
+        SourceFilter {
+            sources: self.sources.intersection(&other.sources).copied().collect(),
+        }
+    }
+}
+
+/// Keeps the `content` `sources` allows, or all of it without a filter.
+pub(crate) fn filter_sources<T>(
+    content: Vec<T>,
+    sources: Option<&SourceFilter>,
+    source: impl Fn(&T) -> Option<Uuid>,
+) -> Vec<T> {
+    match sources {
+        Some(sources) => content
+            .into_iter()
+            .filter(|item| sources.allows(source(item)))
+            .collect(),
+        None => content,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _filters_leave_out_content_without_an_allowed_source() {
+        let core = Uuid::now_v7();
+        let homebrew = Uuid::now_v7();
+        let content = vec![Some(core), Some(homebrew), None];
+
+        let core_only = SourceFilter::new([core]);
+        assert_eq!(
+            filter_sources(content.clone(), Some(&core_only), |&source| source),
+            [Some(core)]
+        );
+        assert_eq!(filter_sources(content, None, |&source| source).len(), 3);
+
+        let both = SourceFilter::new([core, homebrew]);
+        assert_eq!(both.intersect(&core_only), core_only);
+    }
+}
@@ -1,12 +1,52 @@
-use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq)]
+use super::{source::filter_sources, SourceFilter};
+
+use crate::db::Db;
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct SpellSchool {
-    id: uuid::Uuid,
-    name: String,
+    pub(crate) id: uuid::Uuid,
+    pub(crate) name: String,
+}
+
+impl SpellSchool {
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub async fn load_by_name(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        name: &str,
+    ) -> crate::Result<SpellSchool> {
+        Ok(sqlx::query_as!(
+            SpellSchool,
+            r#"
+                SELECT `spell_school_id` AS "id: uuid::Uuid"
+                    , `name`
+                FROM `spell_schools`
+                WHERE `name` = ?1
+                LIMIT 1
+                ;
+            "#,
+            name
+        )
+        .fetch_one(executor)
+        .await?)
+    }
+}
+
+/// A saved range or duration that isn't one the rules have.
+fn unreadable(column: &str, kind: &str, value: Option<i64>) -> crate::Error {
+    let value = value.map_or("no value".to_string(), |value| value.to_string());
+
+    crate::Error::Storage(sqlx::Error::Decode(
+        format!("invalid {column} `{kind}` with {value}").into(),
+    ))
 }
 
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
 pub enum Range {
     #[default]
     Caster,
@@ -15,17 +55,35 @@ pub enum Range {
 }
 
 impl Range {
-    fn parse(kind: &str, value: Option<i64>) -> anyhow::Result<Range> {
-        match kind {
-            "Self" => Ok(Range::Caster),
-            "Touch" => Ok(Range::Touch),
-            "Spaces" => Ok(Range::Spaces(value.ok_or(anyhow!("invalid range"))? as u64)),
-            _ => Err(anyhow!("invalid range")),
+    pub(crate) fn parse(kind: &str, value: Option<i64>) -> crate::Result<Range> {
+        match (kind, value) {
+            ("Self", _) => Ok(Range::Caster),
+            ("Touch", _) => Ok(Range::Touch),
+            ("Spaces", Some(spaces)) => Ok(Range::Spaces(spaces as u64)),
+            _ => Err(unreadable("range", kind, value)),
+        }
+    }
+
+    pub(crate) fn columns(self) -> (&'static str, Option<i64>) {
+        match self {
+            Range::Caster => ("Self", None),
+            Range::Touch => ("Touch", None),
+            Range::Spaces(spaces) => ("Spaces", Some(spaces as i64)),
         }
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+impl std::fmt::Display for Range {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Range::Caster => write!(f, "Self"),
+            Range::Touch => write!(f, "Touch"),
+            Range::Spaces(spaces) => write!(f, "{spaces} Spaces"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
 pub enum Duration {
     #[default]
     Instant,
@@ -35,24 +93,38 @@ pub enum Duration {
 }
 
 impl Duration {
-    fn parse(kind: &str, value: Option<i64>) -> anyhow::Result<Duration> {
-        match kind {
-            "Instant" => Ok(Duration::Instant),
-            "Minutes" => Ok(Duration::Minutes(
-                value.ok_or(anyhow!("invalid duration"))? as u64
-            )),
-            "Hours" => Ok(Duration::Hours(
-                value.ok_or(anyhow!("invalid duration"))? as u64
-            )),
-            "Rounds" => Ok(Duration::Rounds(
-                value.ok_or(anyhow!("invalid duration"))? as u64
-            )),
-            _ => Err(anyhow!("invalid duration")),
+    pub(crate) fn parse(kind: &str, value: Option<i64>) -> crate::Result<Duration> {
+        match (kind, value) {
+            ("Instant", _) => Ok(Duration::Instant),
+            ("Minute" | "Minutes", Some(minutes)) => Ok(Duration::Minutes(minutes as u64)),
+            ("Hour" | "Hours", Some(hours)) => Ok(Duration::Hours(hours as u64)),
+            ("Round" | "Rounds", Some(rounds)) => Ok(Duration::Rounds(rounds as u64)),
+            _ => Err(unreadable("duration", kind, value)),
+        }
+    }
+
+    pub(crate) fn columns(self) -> (&'static str, Option<i64>) {
+        match self {
+            Duration::Instant => ("Instant", None),
+            Duration::Minutes(minutes) => ("Minute", Some(minutes as i64)),
+            Duration::Hours(hours) => ("Hour", Some(hours as i64)),
+            Duration::Rounds(rounds) => ("Round", Some(rounds as i64)),
+        }
+    }
+}
+
+impl std::fmt::Display for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Duration::Instant => write!(f, "Instant"),
+            Duration::Minutes(minutes) => write!(f, "{minutes} Minute(s)"),
+            Duration::Hours(hours) => write!(f, "{hours} Hour(s)"),
+            Duration::Rounds(rounds) => write!(f, "{rounds} Round(s)"),
         }
     }
 }
 
-#[derive(turann::Builder, Clone, Debug, PartialEq)]
+#[derive(turann::Builder, Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct PointEnhancement {
     #[builder(default = uuid::Uuid::now_v7)]
     pub id: uuid::Uuid,
@@ -62,7 +134,7 @@ pub struct PointEnhancement {
     pub description: String,
 }
 
-#[derive(turann::Builder, Clone, Debug, PartialEq)]
+#[derive(turann::Builder, Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct Spell {
     #[builder(default = uuid::Uuid::now_v7)]
     pub id: uuid::Uuid,
@@ -78,13 +150,29 @@ pub struct Spell {
     pub sustained: bool,
     pub description: String,
     pub point_enhancements: Vec<PointEnhancement>,
+    /// The book the Spell comes from, when known.
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<uuid::Uuid>,
+}
+
+/// Filters for [`Spell::search`]. Any filter left as `None` matches every Spell.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct SpellSearch {
+    /// Matches any part of the Spell's name, ignoring case.
+    pub name: Option<String>,
+    pub school: Option<String>,
+    pub max_mana_cost: Option<u64>,
+    /// Leaves out Spells this Campaign doesn't use: those from content packs
+    /// it hasn't enabled, or from Sources it doesn't allow.
+    pub campaign: Option<uuid::Uuid>,
 }
 
 impl Spell {
     async fn create_description_from_effects(
-        pool: &sqlx::SqlitePool,
+        executor: impl sqlx::SqliteExecutor<'_>,
         id: uuid::Uuid,
-    ) -> anyhow::Result<String> {
+    ) -> crate::Result<String> {
         let mut description: Vec<String> = vec![];
 
         let spell_effects = sqlx::query!(
@@ -99,7 +187,7 @@ impl Spell {
             "#,
             id
         )
-        .fetch_all(pool)
+        .fetch_all(executor)
         .await?;
 
         for spell_effect in spell_effects {
@@ -115,7 +203,9 @@ impl Spell {
         ))
     }
 
-    pub async fn load(pool: &sqlx::SqlitePool, id: uuid::Uuid) -> anyhow::Result<Spell> {
+    pub async fn load(db: impl Db<'_>, id: uuid::Uuid) -> crate::Result<Spell> {
+        let mut conn = db.connection().await?;
+
         let row = sqlx::query!(
             r#"
                 SELECT `spell_id` AS "id: uuid::Uuid"
@@ -133,6 +223,7 @@ impl Spell {
                     , duration_value
                     , sustained as "sustained: bool"
                     , description
+                    , spell.`source_id` AS "source?: uuid::Uuid"
                 FROM `spells` AS spell
                 JOIN `spell_schools` AS school
                     USING (`spell_school_id`)
@@ -140,7 +231,7 @@ impl Spell {
             "#,
             id
         )
-        .fetch_one(pool)
+        .fetch_one(&mut *conn)
         .await?;
 
         let point_enhancements = sqlx::query_as!(
@@ -159,12 +250,12 @@ impl Spell {
             "#,
             id
         )
-        .fetch_all(pool)
+        .fetch_all(&mut *conn)
         .await?;
 
         let description = match row.description {
             Some(description) => description,
-            None => Self::create_description_from_effects(pool, id).await?,
+            None => Self::create_description_from_effects(&mut *conn, id).await?,
         };
 
         Ok(Self {
@@ -184,11 +275,275 @@ impl Spell {
             sustained: row.sustained,
             description,
             point_enhancements,
+            source: row.source,
         })
     }
+
+    /// Saves the Spell with its School and Point Enhancements. Material
+    /// components are tracked separately, so `has_material` isn't saved.
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+        let mut tx = conn.begin().await?;
+
+        let Spell {
+            id,
+            name,
+            school,
+            has_verbal,
+            has_somatic,
+            has_material: _,
+            action_point_cost,
+            mana_point_cost,
+            range,
+            duration,
+            sustained,
+            description,
+            point_enhancements,
+            source,
+        } = self;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `spell_schools` (`spell_school_id`, `name`)
+                VALUES (?1, ?2)
+                ON CONFLICT (`spell_school_id`) DO UPDATE
+                    SET `name` = ?2
+                ;
+            "#,
+            school.id,
+            school.name
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let action_point_cost = action_point_cost as i64;
+        let mana_point_cost = mana_point_cost as i64;
+        let (range_kind, range_value) = range.columns();
+        let (duration_kind, duration_value) = duration.columns();
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `spells`
+                    ( `spell_id`
+                    , `name`
+                    , `spell_school_id`
+                    , `has_verbal`
+                    , `has_somatic`
+                    , `action_point_cost`
+                    , `mana_point_cost`
+                    , `range_kind`
+                    , `range_value`
+                    , `duration_kind`
+                    , `duration_value`
+                    , `sustained`
+                    , `description`
+                    , `source_id`
+                    )
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
+                ON CONFLICT (`spell_id`) DO UPDATE
+                    SET `name` = ?2
+                    , `spell_school_id` = ?3
+                    , `has_verbal` = ?4
+                    , `has_somatic` = ?5
+                    , `action_point_cost` = ?6
+                    , `mana_point_cost` = ?7
+                    , `range_kind` = ?8
+                    , `range_value` = ?9
+                    , `duration_kind` = ?10
+                    , `duration_value` = ?11
+                    , `sustained` = ?12
+                    , `description` = ?13
+                    , `source_id` = ?14
+                ;
+            "#,
+            id,
+            name,
+            school.id,
+            has_verbal,
+            has_somatic,
+            action_point_cost,
+            mana_point_cost,
+            range_kind,
+            range_value,
+            duration_kind,
+            duration_value,
+            sustained,
+            description,
+            source
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+                DELETE FROM `point_enhancements_spells`
+                WHERE `spell_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for enhancement in point_enhancements {
+            let PointEnhancement {
+                id: enhancement_id,
+                name,
+                action_point_cost,
+                mana_point_cost,
+                description,
+            } = enhancement;
+            let action_point_cost = action_point_cost as i64;
+            let mana_point_cost = mana_point_cost as i64;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO `point_enhancements`
+                        (`point_enhancement_id`, `name`, `action_point_cost`, `mana_point_cost`, `description`)
+                    VALUES (?1, ?2, ?3, ?4, ?5)
+                    ON CONFLICT (`point_enhancement_id`) DO UPDATE
+                        SET `name` = ?2
+                        , `action_point_cost` = ?3
+                        , `mana_point_cost` = ?4
+                        , `description` = ?5
+                    ;
+                "#,
+                enhancement_id,
+                name,
+                action_point_cost,
+                mana_point_cost,
+                description
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO `point_enhancements_spells` (`point_enhancement_id`, `spell_id`)
+                    VALUES (?1, ?2)
+                    ;
+                "#,
+                enhancement_id,
+                id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        Ok(tx.commit().await?)
+    }
+
+    pub async fn delete(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: uuid::Uuid,
+    ) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `spells`
+                WHERE `spell_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Finds the Spell by name, passing over any from Sources not allowed.
+    pub async fn load_by_name(
+        db: impl Db<'_>,
+        name: &str,
+        sources: Option<&SourceFilter>,
+    ) -> crate::Result<Spell> {
+        let mut conn = db.connection().await?;
+
+        let rows = sqlx::query!(
+            r#"
+                SELECT `spell_id` AS "id: uuid::Uuid"
+                    , `source_id` AS "source?: uuid::Uuid"
+                FROM `spells`
+                WHERE `name` = ?1 COLLATE NOCASE
+                ;
+            "#,
+            name
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let row = filter_sources(rows, sources, |row| row.source)
+            .into_iter()
+            .next()
+            .ok_or_else(|| crate::Error::not_found(format!("Spell `{name}`")))?;
+
+        Self::load(&mut *conn, row.id).await
+    }
+
+    pub async fn search(
+        db: impl Db<'_>,
+        search: &SpellSearch,
+        sources: Option<&SourceFilter>,
+    ) -> crate::Result<Vec<Spell>> {
+        let mut conn = db.connection().await?;
+
+        let name = search.name.as_ref().map(|name| format!("%{name}%"));
+        let school = search.school.as_deref();
+        let max_mana_cost = search.max_mana_cost.map(|cost| cost as i64);
+
+        let rows = sqlx::query!(
+            r#"
+                SELECT `spell_id` AS "id: uuid::Uuid"
+                FROM `spells` AS spell
+                JOIN `spell_schools` AS school
+                    USING (`spell_school_id`)
+                WHERE (?1 IS NULL OR spell.`name` LIKE ?1)
+                    AND (?2 IS NULL OR school.`name` = ?2 COLLATE NOCASE)
+                    AND (?3 IS NULL OR `mana_point_cost` <= ?3)
+                    AND (?4 IS NULL OR `spell_id` NOT IN (
+                        SELECT `content_id`
+                        FROM `content_pack_contents`
+                        WHERE `kind` = 'spell'
+                            AND `content_pack_id` NOT IN (
+                                SELECT `content_pack_id`
+                                FROM `campaigns_content_packs`
+                                WHERE `campaign_id` = ?4
+                            )
+                    ))
+                    AND (?4 IS NULL
+                        OR NOT EXISTS (
+                            SELECT 1
+                            FROM `campaigns_sources`
+                            WHERE `campaign_id` = ?4
+                        )
+                        OR spell.`source_id` IN (
+                            SELECT `source_id`
+                            FROM `campaigns_sources`
+                            WHERE `campaign_id` = ?4
+                        )
+                    )
+                ORDER BY spell.`name`
+                ;
+            "#,
+            name,
+            school,
+            max_mana_cost,
+            search.campaign
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut spells = vec![];
+
+        for row in rows {
+            spells.push(Self::load(&mut *conn, row.id).await?);
+        }
+
+        Ok(filter_sources(spells, sources, |spell| spell.source))
+    }
 }
 
-#[derive(turann::Builder, Clone, Debug, Default, PartialEq)]
+#[derive(turann::Builder, Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
 pub struct SpellList {
     #[builder(default = uuid::Uuid::now_v7)]
     pub id: uuid::Uuid,
@@ -198,7 +553,9 @@ pub struct SpellList {
 }
 
 impl SpellList {
-    pub async fn load(pool: &sqlx::SqlitePool, id: uuid::Uuid) -> anyhow::Result<SpellList> {
+    pub async fn load(db: impl Db<'_>, id: uuid::Uuid) -> crate::Result<SpellList> {
+        let mut conn = db.connection().await?;
+
         let list_details = sqlx::query!(
             r#"
                 SELECT `spell_list_id` AS "id: uuid::Uuid"
@@ -210,31 +567,25 @@ impl SpellList {
             "#,
             id
         )
-        .fetch_one(pool)
+        .fetch_one(&mut *conn)
         .await?;
 
-        let spell_ids = sqlx::query!(
+        let spell_ids = sqlx::query_scalar!(
             r#"
-                SELECT `spell_id`
+                SELECT `spell_id` AS "id: uuid::Uuid"
                 FROM `spells_spell_lists`
                 WHERE `spell_list_id` = ?1
                 ;
             "#,
             id
         )
-        .fetch_all(pool)
+        .fetch_all(&mut *conn)
         .await?;
 
         let mut spells = vec![];
 
-        for spell_id_row in spell_ids {
-            spells.push(
-                Spell::load(
-                    pool,
-                    uuid::Uuid::from_slice(spell_id_row.spell_id.as_slice()).expect("boom"),
-                )
-                .await?,
-            );
+        for spell_id in spell_ids {
+            spells.push(Spell::load(&mut *conn, spell_id).await?);
         }
 
         Ok(Self {
@@ -243,4 +594,95 @@ impl SpellList {
             spells,
         })
     }
+
+    pub async fn list(db: impl Db<'_>) -> crate::Result<Vec<SpellList>> {
+        let mut conn = db.connection().await?;
+
+        let rows = sqlx::query!(
+            r#"
+                SELECT `spell_list_id` AS "id: uuid::Uuid"
+                FROM `spell_lists`
+                ORDER BY `name`
+                ;
+            "#
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut spell_lists = vec![];
+
+        for row in rows {
+            spell_lists.push(Self::load(&mut *conn, row.id).await?);
+        }
+
+        Ok(spell_lists)
+    }
+
+    /// Saves the Spell List and which Spells are on it. The Spells
+    /// themselves must already be saved.
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+        let mut tx = conn.begin().await?;
+
+        let SpellList { id, name, spells } = self;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `spell_lists` (`spell_list_id`, `name`)
+                VALUES (?1, ?2)
+                ON CONFLICT (`spell_list_id`) DO UPDATE
+                    SET `name` = ?2
+                ;
+            "#,
+            id,
+            name
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+                DELETE FROM `spells_spell_lists`
+                WHERE `spell_list_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for spell in spells {
+            sqlx::query!(
+                r#"
+                    INSERT INTO `spells_spell_lists` (`spell_id`, `spell_list_id`)
+                    VALUES (?1, ?2)
+                    ;
+                "#,
+                spell.id,
+                id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        Ok(tx.commit().await?)
+    }
+
+    pub async fn delete(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: uuid::Uuid,
+    ) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `spell_lists`
+                WHERE `spell_list_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
 }
@@ -0,0 +1,356 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    db::Db,
+    dc20::{item::consumable::Resource, Condition, Conditions, ResourcePool, Resources},
+};
+
+/// The numbers a Character's table keeps an eye on during play.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TrackedCharacter {
+    pub character_id: Uuid,
+    pub resources: Resources,
+    pub conditions: Conditions,
+    pub initiative: Option<i64>,
+}
+
+/// A change any client at the table can make.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum TrackerChange {
+    /// Starts tracking the Character, with whatever resources and
+    /// Conditions they already have.
+    Join { character_id: Uuid },
+    /// Spends (negative `amount`) or recovers a resource.
+    AdjustResource {
+        character_id: Uuid,
+        resource: Resource,
+        amount: i64,
+    },
+    SetResource {
+        character_id: Uuid,
+        resource: Resource,
+        pool: ResourcePool,
+    },
+    AddCondition {
+        character_id: Uuid,
+        condition: Condition,
+    },
+    RemoveCondition {
+        character_id: Uuid,
+        condition: Condition,
+    },
+    SetInitiative {
+        character_id: Uuid,
+        initiative: Option<i64>,
+    },
+}
+
+impl TrackerChange {
+    #[must_use]
+    pub fn character_id(&self) -> Uuid {
+        match *self {
+            TrackerChange::Join { character_id }
+            | TrackerChange::AdjustResource { character_id, .. }
+            | TrackerChange::SetResource { character_id, .. }
+            | TrackerChange::AddCondition { character_id, .. }
+            | TrackerChange::RemoveCondition { character_id, .. }
+            | TrackerChange::SetInitiative { character_id, .. } => character_id,
+        }
+    }
+
+    pub fn apply(self, character: &mut TrackedCharacter) {
+        match self {
+            TrackerChange::Join { .. } => {}
+            TrackerChange::AdjustResource {
+                resource, amount, ..
+            } => character.resources.get_mut(resource).adjust(amount),
+            TrackerChange::SetResource { resource, pool, .. } => {
+                *character.resources.get_mut(resource) = ResourcePool {
+                    current: pool.current.min(pool.maximum),
+                    maximum: pool.maximum,
+                };
+            }
+            TrackerChange::AddCondition { condition, .. } => {
+                character.conditions.insert(condition);
+            }
+            TrackerChange::RemoveCondition { condition, .. } => {
+                character.conditions.remove(condition);
+            }
+            TrackerChange::SetInitiative { initiative, .. } => character.initiative = initiative,
+        }
+    }
+}
+
+/// A Character's numbers just after the change that gave the tracker `version`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TrackerUpdate {
+    pub version: u64,
+    pub character: TrackedCharacter,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TrackerError {
+    /// Only Characters in the Campaign can join its tracker.
+    NotInCampaign(Uuid),
+    /// Only Characters who have joined the tracker can be changed.
+    NotTracked(Uuid),
+    /// Someone else changed the tracker first; the change can be retried.
+    Stale(Uuid),
+}
+
+impl std::fmt::Display for TrackerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TrackerError::NotInCampaign(id) => {
+                write!(f, "Character `{id}` is not in the Campaign")
+            }
+            TrackerError::NotTracked(id) => write!(f, "Character `{id}` is not being tracked"),
+            TrackerError::Stale(id) => {
+                write!(f, "the tracker for `{id}` changed first, try again")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TrackerError {}
+
+/// The authoritative state of a Campaign's table. Every change bumps the
+/// version, so a client that reconnects only needs what changed after the
+/// last version it saw.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Tracker {
+    pub campaign_id: Uuid,
+    pub version: u64,
+    /// Sorted by initiative, highest first, then those without one.
+    pub characters: Vec<TrackedCharacter>,
+}
+
+impl Tracker {
+    /// Everything being tracked for the Campaign. A Campaign nobody has
+    /// tracked anything for is at version 0.
+    pub async fn load(db: impl Db<'_>, campaign_id: Uuid) -> crate::Result<Tracker> {
+        let mut conn = db.connection().await?;
+
+        Tracker::load_since(&mut *conn, campaign_id, 0).await
+    }
+
+    /// Only the Characters that changed after version `since`.
+    pub async fn load_since(
+        db: impl Db<'_>,
+        campaign_id: Uuid,
+        since: u64,
+    ) -> crate::Result<Tracker> {
+        let mut conn = db.connection().await?;
+
+        let version = sqlx::query_scalar!(
+            r#"
+                SELECT `version`
+                FROM `campaign_trackers`
+                WHERE `campaign_id` = ?1
+                ;
+            "#,
+            campaign_id
+        )
+        .fetch_optional(&mut *conn)
+        .await?
+        .unwrap_or_default() as u64;
+
+        let since = since as i64;
+        let rows = sqlx::query!(
+            r#"
+                SELECT `character_id` AS "character_id: Uuid"
+                    , `initiative`
+                FROM `campaign_tracker_characters`
+                WHERE `campaign_id` = ?1
+                    AND `version` > ?2
+                ORDER BY `initiative` IS NULL, `initiative` DESC
+                ;
+            "#,
+            campaign_id,
+            since
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut characters = vec![];
+        for row in rows {
+            characters.push(TrackedCharacter {
+                character_id: row.character_id,
+                resources: Resources::load(&mut *conn, row.character_id).await?,
+                conditions: Conditions::load(&mut *conn, row.character_id).await?,
+                initiative: row.initiative,
+            });
+        }
+
+        Ok(Tracker {
+            campaign_id,
+            version,
+            characters,
+        })
+    }
+
+    /// Applies the change, saving the Character's new numbers and bumping
+    /// the version together. Only Characters in the Campaign can join, and a
+    /// change that races another to the same version is turned away rather
+    /// than losing one of them.
+    pub async fn apply(
+        db: impl Db<'_>,
+        campaign_id: Uuid,
+        change: TrackerChange,
+    ) -> crate::Result<TrackerUpdate> {
+        let mut conn = db.connection().await?;
+        let mut tx = conn.begin().await?;
+
+        let character_id = change.character_id();
+
+        let seen = sqlx::query_scalar!(
+            r#"
+                SELECT `version`
+                FROM `campaign_trackers`
+                WHERE `campaign_id` = ?1
+                ;
+            "#,
+            campaign_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let row = sqlx::query!(
+            r#"
+                SELECT t_c.`character_id` IS NOT NULL AS "tracked!: bool"
+                    , t_c.`initiative`
+                FROM `campaigns_characters` AS c_c
+                LEFT JOIN `campaign_tracker_characters` AS t_c
+                    USING (`campaign_id`, `character_id`)
+                WHERE c_c.`campaign_id` = ?1
+                    AND c_c.`character_id` = ?2
+                ;
+            "#,
+            campaign_id,
+            character_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(TrackerError::NotInCampaign(character_id))?;
+
+        if !row.tracked && !matches!(change, TrackerChange::Join { .. }) {
+            return Err(TrackerError::NotTracked(character_id).into());
+        }
+
+        let mut character = TrackedCharacter {
+            character_id,
+            resources: Resources::load(&mut *tx, character_id).await?,
+            conditions: Conditions::load(&mut *tx, character_id).await?,
+            initiative: row.initiative,
+        };
+        change.apply(&mut character);
+
+        character.resources.save(&mut *tx, character_id).await?;
+        character.conditions.save(&mut *tx, character_id).await?;
+
+        let version = match seen {
+            Some(seen) => {
+                sqlx::query_scalar!(
+                    r#"
+                        UPDATE `campaign_trackers`
+                        SET `version` = `version` + 1
+                        WHERE `campaign_id` = ?1
+                            AND `version` = ?2
+                        RETURNING `version`
+                        ;
+                    "#,
+                    campaign_id,
+                    seen
+                )
+                .fetch_optional(&mut *tx)
+                .await?
+            }
+            None => {
+                sqlx::query_scalar!(
+                    r#"
+                        INSERT INTO `campaign_trackers` (`campaign_id`, `version`)
+                        VALUES (?1, 1)
+                        ON CONFLICT (`campaign_id`) DO NOTHING
+                        RETURNING `version`
+                        ;
+                    "#,
+                    campaign_id
+                )
+                .fetch_optional(&mut *tx)
+                .await?
+            }
+        }
+        .ok_or(TrackerError::Stale(campaign_id))?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `campaign_tracker_characters`
+                    (`campaign_id`, `character_id`, `initiative`, `version`)
+                VALUES (?1, ?2, ?3, ?4)
+                ON CONFLICT (`campaign_id`, `character_id`) DO UPDATE
+                    SET `initiative` = ?3
+                    , `version` = ?4
+                ;
+            "#,
+            campaign_id,
+            character_id,
+            character.initiative,
+            version
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(TrackerUpdate {
+            version: version as u64,
+            character,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _changes_only_touch_their_own_numbers() {
+        let character_id = Uuid::now_v7();
+        let mut character = TrackedCharacter {
+            character_id,
+            resources: Resources {
+                hit_points: ResourcePool::full(12),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        TrackerChange::AdjustResource {
+            character_id,
+            resource: Resource::HitPoints,
+            amount: -5,
+        }
+        .apply(&mut character);
+        TrackerChange::AddCondition {
+            character_id,
+            condition: Condition::Dazed,
+        }
+        .apply(&mut character);
+        TrackerChange::SetResource {
+            character_id,
+            resource: Resource::ManaPoints,
+            pool: ResourcePool {
+                current: 9,
+                maximum: 6,
+            },
+        }
+        .apply(&mut character);
+
+        assert_eq!(character.resources.hit_points.current, 7);
+        assert_eq!(character.resources.mana_points, ResourcePool::full(6));
+        assert!(character.conditions.contains(Condition::Dazed));
+        assert_eq!(character.initiative, None);
+    }
+}
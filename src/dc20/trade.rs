@@ -1,6 +1,9 @@
+use serde::{Deserialize, Serialize};
 use turann::Builder;
 
-#[derive(Builder, Clone, Debug, PartialEq)]
+use crate::db::Db;
+
+#[derive(Builder, Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct Trade {
     #[builder(default = uuid::Uuid::now_v7)]
     pub id: uuid::Uuid,
@@ -8,8 +11,11 @@ pub struct Trade {
 }
 
 impl Trade {
-    pub async fn load(pool: &sqlx::SqlitePool, id: uuid::Uuid) -> sqlx::Result<Trade> {
-        sqlx::query_as!(
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: uuid::Uuid,
+    ) -> crate::Result<Trade> {
+        Ok(sqlx::query_as!(
             Trade,
             r#"
                 SELECT `trade_id` AS "id: uuid::Uuid"
@@ -20,12 +26,26 @@ impl Trade {
             "#,
             id
         )
-        .fetch_one(pool)
-        .await
+        .fetch_one(executor)
+        .await?)
+    }
+
+    pub async fn list(executor: impl sqlx::SqliteExecutor<'_>) -> crate::Result<Vec<Trade>> {
+        Ok(sqlx::query_as!(
+            Trade,
+            r#"
+                SELECT `trade_id` AS "id: uuid::Uuid"
+                    , `name`
+                FROM `trades`
+                ORDER BY `name`;
+            "#
+        )
+        .fetch_all(executor)
+        .await?)
     }
 
-    pub async fn save(self, pool: &sqlx::SqlitePool) -> sqlx::Result<()> {
-        let mut conn = pool.acquire().await?;
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
 
         let Trade { id, name } = self;
 
@@ -45,4 +65,21 @@ impl Trade {
 
         Ok(())
     }
+
+    pub async fn delete(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: uuid::Uuid,
+    ) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `trades`
+                WHERE `trade_id` = ?1;
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
 }
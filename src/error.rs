@@ -0,0 +1,244 @@
+//! The one error every public function returns, sorted by what went wrong
+//! rather than where, so callers like the API can answer each kind the same
+//! way.
+
+use std::fmt::{self, Display};
+
+use crate::{
+    campaign::{CampaignBuilderError, PartyBuilderError},
+    dc20::{
+        item::{consumable::ConsumableError, weapon::WeaponBuildError},
+        *,
+    },
+    export::json::ImportError,
+    history::HistoryError,
+    journal::{ExperienceTableError, SessionBuilderError},
+    pack::PackError,
+    player::PlayerBuilderError,
+};
+
+type Boxed = Box<dyn std::error::Error + Send + Sync>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// Nothing is saved under the id or name asked for.
+    NotFound(Boxed),
+    /// Input that is wrong whatever is saved: an empty name, a missing
+    /// field, a file that isn't a content pack.
+    Validation(Boxed),
+    /// Input the DC20 rules or the state of play don't allow: a Level past
+    /// the last, more than a Character can pay for, a Character who isn't
+    /// being tracked.
+    RuleViolation(Boxed),
+    /// Content that is referred to but not saved, or that is in the way of
+    /// content being added.
+    ContentReference(Boxed),
+    /// The database failed, or holds something that can't be read back.
+    Storage(sqlx::Error),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    pub fn not_found(what: impl Display) -> Self {
+        Error::NotFound(format!("{what} not found").into())
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotFound(error)
+            | Error::Validation(error)
+            | Error::RuleViolation(error)
+            | Error::ContentReference(error) => write!(f, "{error}"),
+            Error::Storage(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::NotFound(error)
+            | Error::Validation(error)
+            | Error::RuleViolation(error)
+            | Error::ContentReference(error) => Some(&**error),
+            Error::Storage(error) => Some(error),
+        }
+    }
+}
+
+/// A constraint the database held to, told in words that are safe to show
+/// anyone. The database's own message is kept as the source, for logs.
+#[derive(Debug)]
+struct Constraint {
+    message: &'static str,
+    error: Box<dyn sqlx::error::DatabaseError>,
+}
+
+impl Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message)
+    }
+}
+
+impl std::error::Error for Constraint {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.error.as_error())
+    }
+}
+
+impl From<sqlx::Error> for Error {
+    fn from(error: sqlx::Error) -> Self {
+        let constraint = |message, error| Box::new(Constraint { message, error });
+
+        match error {
+            sqlx::Error::RowNotFound => Error::NotFound("Not found".into()),
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => {
+                Error::ContentReference(constraint(
+                    "It refers to something that isn't saved, or something saved refers to it",
+                    e,
+                ))
+            }
+            sqlx::Error::Database(e) if e.is_unique_violation() => {
+                Error::Validation(constraint("Something the same is already saved", e))
+            }
+            // A name that's empty, or a number out of range
+            sqlx::Error::Database(e) if e.is_check_violation() => {
+                Error::Validation(constraint("A value given isn't allowed", e))
+            }
+            error => Error::Storage(error),
+        }
+    }
+}
+
+impl From<sqlx::migrate::MigrateError> for Error {
+    fn from(error: sqlx::migrate::MigrateError) -> Self {
+        Error::Storage(error.into())
+    }
+}
+
+impl From<HistoryError> for Error {
+    fn from(error: HistoryError) -> Self {
+        match error {
+            HistoryError::InvalidPoint(_) => Error::Validation(error.into()),
+            HistoryError::UnknownRevision { .. } | HistoryError::NothingBefore(_) => {
+                Error::NotFound(error.into())
+            }
+        }
+    }
+}
+
+impl From<ImportError> for Error {
+    fn from(error: ImportError) -> Self {
+        match error {
+            ImportError::MissingContent { .. } => Error::ContentReference(error.into()),
+            ImportError::Invalid(_)
+            | ImportError::UnsupportedVersion(_)
+            | ImportError::CharacterExists(_) => Error::Validation(error.into()),
+        }
+    }
+}
+
+impl From<PackError> for Error {
+    fn from(error: PackError) -> Self {
+        match error {
+            PackError::Conflicts(_) => Error::ContentReference(error.into()),
+            _ => Error::Validation(error.into()),
+        }
+    }
+}
+
+impl From<InventoryError> for Error {
+    fn from(error: InventoryError) -> Self {
+        match error {
+            InventoryError::EntryNotFound(_) => Error::NotFound(error.into()),
+            _ => Error::RuleViolation(error.into()),
+        }
+    }
+}
+
+/// Errors that are always the rules saying no.
+macro_rules! rule_violations {
+    ($($error:ty),* $(,)?) => {
+        $(
+            impl From<$error> for Error {
+                fn from(error: $error) -> Self {
+                    Error::RuleViolation(error.into())
+                }
+            }
+        )*
+    };
+}
+
+rule_violations!(
+    ConsumableError,
+    CurrencyError,
+    TrackerError,
+    WeaponBuildError,
+);
+
+/// Errors from input with missing or invalid fields.
+macro_rules! validations {
+    ($($error:ty),* $(,)?) => {
+        $(
+            impl From<$error> for Error {
+                fn from(error: $error) -> Self {
+                    Error::Validation(error.into())
+                }
+            }
+        )*
+    };
+}
+
+validations!(
+    AncestryBuilderError,
+    AncestryTraitBuilderError,
+    AttributeBuilderError,
+    BackgroundBuilderError,
+    CampaignBuilderError,
+    CharacterBuilderError,
+    ClassBuilderError,
+    CreatureBuilderError,
+    ExperienceTableError,
+    LanguageBuilderError,
+    LevelBuilderError,
+    ManeuverBuilderError,
+    MasteryBuilderError,
+    PartyBuilderError,
+    PlayerBuilderError,
+    PointEnhancementBuilderError,
+    SessionBuilderError,
+    SkillBuilderError,
+    SourceBuilderError,
+    SpellBuilderError,
+    SpellListBuilderError,
+    StatBlockParseError,
+    SubclassBuilderError,
+    TradeBuilderError,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _missing_rows_are_not_found() {
+        assert!(matches!(
+            Error::from(sqlx::Error::RowNotFound),
+            Error::NotFound(_)
+        ));
+    }
+
+    #[test]
+    fn _domain_errors_keep_their_message() {
+        let error = Error::from(CurrencyError::InsufficientFunds {
+            price: 100,
+            available: 5,
+        });
+
+        assert!(matches!(error, Error::RuleViolation(_)));
+        assert_eq!(error.to_string(), "Unable to pay 1 gp: only 5 cp available");
+    }
+}
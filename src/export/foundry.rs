@@ -0,0 +1,827 @@
+//! Characters as actors for the DC20 system (`dc20rpg`) in Foundry VTT.
+//!
+//! Only the part of an actor this book also keeps is written or read: the
+//! details, Attributes, defences, resources, movement and skills under
+//! `system`, and the Ancestry, Background, Class, Subclass, weapon, spell
+//! and technique (Maneuver) items. Everything else Foundry puts in an actor,
+//! such as `_id`, `img`, `prototypeToken`, `effects` or other item types,
+//! is ignored on import.
+//!
+//! ```json
+//! {
+//!   "name": "Cygnus",
+//!   "type": "character",
+//!   "system": {
+//!     "details": { "level": 3, "combatMastery": 2 },
+//!     "attributes": {
+//!       "prime": { "value": 3 },
+//!       "mig": { "value": 3, "save": 5, "saveMastery": true },
+//!       …
+//!     },
+//!     "defences": { "precision": { "value": 13, "heavy": 18, "brutal": 23, "damageReduction": 1 }, … },
+//!     "resources": { "health": { "value": 9, "max": 12 }, … },
+//!     "movement": { "speed": 4 },
+//!     "skills": { "athletics": { "label": "Athletics", "attribute": "mig", "modifier": 3 } },
+//!     "languages": ["Common"]
+//!   },
+//!   "items": [
+//!     { "name": "Barbarian", "type": "class" },
+//!     { "name": "Longsword", "type": "weapon", "system": { "weaponType": "melee", … } }
+//!   ]
+//! }
+//! ```
+//!
+//! Importing builds a [`CharacterExport`] and restores it, so content is
+//! matched by name against what is already saved and created when missing.
+//! The Attributes given are used for every Level up to the actor's. Spells
+//! can't be imported one by one since Characters learn them through Spell
+//! Lists; they are reported back as skipped, as is any weapon that isn't a
+//! valid [`Weapon`].
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{
+    json::{self, CharacterExport, ContentRef, Ids, ImportError, LevelExport},
+    sheet::CharacterSheet,
+};
+use crate::{
+    db::Db,
+    dc20::{
+        item::weapon::{Weapon, WeaponProperty},
+        Ancestry, AttributeName, Attributes, Background, Character, Class, Defense, InventoryEntry,
+        Item, ItemKind, Maneuver, ManeuverKind, Range, ResourcePool, Resources, Spell, Subclass,
+    },
+    player::Player,
+    Error,
+};
+
+/// The only kind of actor a Character can become.
+pub const ACTOR_TYPE: &str = "character";
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Actor {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub system: ActorData,
+    #[serde(default)]
+    pub items: Vec<ActorItem>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ActorData {
+    pub details: Details,
+    pub attributes: ActorAttributes,
+    #[serde(default)]
+    pub defences: Defences,
+    #[serde(default)]
+    pub resources: ActorResources,
+    #[serde(default)]
+    pub movement: Movement,
+    /// Keyed by the Skill's name in lower case, without spaces.
+    #[serde(default)]
+    pub skills: BTreeMap<String, SkillData>,
+    #[serde(default)]
+    pub languages: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Details {
+    pub level: u32,
+    #[serde(default)]
+    pub combat_mastery: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ActorAttributes {
+    pub prime: Score,
+    pub mig: AttributeData,
+    pub agi: AttributeData,
+    pub cha: AttributeData,
+    pub int: AttributeData,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Score {
+    pub value: i64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttributeData {
+    pub value: i64,
+    #[serde(default)]
+    pub save: isize,
+    #[serde(default)]
+    pub save_mastery: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Defences {
+    pub precision: DefenceData,
+    pub area: DefenceData,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DefenceData {
+    pub value: usize,
+    pub heavy: usize,
+    pub brutal: usize,
+    pub damage_reduction: usize,
+}
+
+impl From<&Defense> for DefenceData {
+    fn from(defense: &Defense) -> Self {
+        DefenceData {
+            value: defense.score,
+            heavy: defense.heavy(),
+            brutal: defense.brutal(),
+            damage_reduction: defense.reduction,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct ActorResources {
+    pub health: ResourceData,
+    pub stamina: ResourceData,
+    pub mana: ResourceData,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct ResourceData {
+    pub value: u64,
+    pub max: u64,
+}
+
+impl From<ResourcePool> for ResourceData {
+    fn from(pool: ResourcePool) -> Self {
+        ResourceData {
+            value: pool.current,
+            max: pool.maximum,
+        }
+    }
+}
+
+impl From<ResourceData> for ResourcePool {
+    fn from(data: ResourceData) -> Self {
+        ResourcePool {
+            current: data.value,
+            maximum: data.max,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Movement {
+    pub speed: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SkillData {
+    pub label: String,
+    pub attribute: String,
+    pub modifier: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ActorItem {
+    Ancestry {
+        name: String,
+    },
+    Background {
+        name: String,
+    },
+    Class {
+        name: String,
+    },
+    Subclass {
+        name: String,
+    },
+    Weapon {
+        name: String,
+        system: WeaponData,
+    },
+    Spell {
+        name: String,
+        system: SpellData,
+    },
+    Technique {
+        name: String,
+        system: TechniqueData,
+    },
+    /// Any item type this book has no use for.
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeaponData {
+    pub weapon_type: String,
+    pub weapon_style: String,
+    pub damage_type: String,
+    /// Keyed by the property's name in camel case, e.g. `twoHanded`.
+    #[serde(default)]
+    pub properties: BTreeMap<String, PropertyData>,
+    /// In Spaces.
+    #[serde(default)]
+    pub range: u64,
+    #[serde(default)]
+    pub weight: f64,
+    #[serde(default = "one")]
+    pub quantity: u64,
+    #[serde(default)]
+    pub equipped: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PropertyData {
+    pub active: bool,
+    /// The extra Weapon Style granted by Multi-Faceted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpellData {
+    pub magic_school: String,
+    pub components: Components,
+    pub costs: Costs,
+    pub range: String,
+    pub duration: String,
+    #[serde(default)]
+    pub sustained: bool,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub enhancements: Vec<EnhancementData>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Components {
+    pub verbal: bool,
+    pub somatic: bool,
+    pub material: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Costs {
+    #[serde(default)]
+    pub action_point: u64,
+    #[serde(default)]
+    pub stamina: u64,
+    #[serde(default)]
+    pub mana: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct EnhancementData {
+    pub name: String,
+    pub costs: Costs,
+    #[serde(default)]
+    pub description: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TechniqueData {
+    /// Always `maneuver`; techniques are how the system files Maneuvers.
+    pub technique_type: String,
+    pub maneuver_type: String,
+    pub costs: Costs,
+    #[serde(default)]
+    pub description: String,
+}
+
+fn one() -> u64 {
+    1
+}
+
+impl Actor {
+    /// Reads an actor, rejecting anything but a character.
+    pub fn parse(json: &str) -> Result<Actor, ImportError> {
+        let actor: Actor =
+            serde_json::from_str(json).map_err(|e| ImportError::Invalid(e.to_string()))?;
+
+        if actor.kind != ACTOR_TYPE {
+            return Err(ImportError::Invalid(format!(
+                "expected a `{ACTOR_TYPE}` actor, found `{}`",
+                actor.kind
+            )));
+        }
+
+        Ok(actor)
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// The Character as the actor would be rebuilt: `skipped` describes
+    /// whatever in the actor couldn't be brought over.
+    async fn to_export(
+        &self,
+        db: impl Db<'_>,
+        player: Player,
+    ) -> crate::Result<(CharacterExport, Vec<String>)> {
+        let mut conn = db.connection().await?;
+
+        let system = &self.system;
+        let attributes = Attributes {
+            prime: system.attributes.prime.value,
+            might: system.attributes.mig.value,
+            agility: system.attributes.agi.value,
+            charisma: system.attributes.cha.value,
+            intelligence: system.attributes.int.value,
+        };
+
+        let mut levels: Vec<LevelExport> = (1..=system.details.level.max(1))
+            .map(|level| LevelExport {
+                id: Uuid::now_v7(),
+                level,
+                attributes: Some(attributes.clone()),
+                ancestries: vec![],
+                ancestry_traits: vec![],
+                backgrounds: vec![],
+                classes: vec![],
+                subclasses: vec![],
+                maneuvers: vec![],
+                spell_lists: vec![],
+                spells: vec![],
+                skills: vec![],
+            })
+            .collect();
+        let mut first = levels.remove(0);
+        let mut classes = vec![];
+        let mut subclasses = vec![];
+        let mut inventory = vec![];
+        let mut skipped = vec![];
+
+        let saved_ancestries = Ancestry::list(&mut *conn).await?;
+        for item in &self.items {
+            match item {
+                ActorItem::Ancestry { name } => {
+                    first.ancestries.push(
+                        match saved_ancestries
+                            .iter()
+                            .find(|ancestry| ancestry.name.eq_ignore_ascii_case(name))
+                        {
+                            Some(ancestry) => ContentRef::Reference { id: ancestry.id },
+                            None => ContentRef::Embedded(Ancestry {
+                                id: Uuid::now_v7(),
+                                name: name.clone(),
+                            }),
+                        },
+                    );
+                }
+                ActorItem::Background { name } => {
+                    first.backgrounds.push(
+                        match Background::load_by_name(&mut *conn, name, None).await {
+                            Ok(background) => ContentRef::Reference { id: background.id },
+                            Err(Error::NotFound(_)) => ContentRef::Embedded(
+                                Background::builder().name(name.clone())?.build()?,
+                            ),
+                            Err(e) => return Err(e),
+                        },
+                    );
+                }
+                ActorItem::Class { name } => {
+                    match Class::load_by_name(&mut *conn, name, None).await {
+                        Ok(class) => classes.push(class),
+                        Err(Error::NotFound(_)) => {
+                            classes.push(Class::builder().name(name.clone())?.build()?);
+                        }
+                        Err(e) => return Err(e),
+                    }
+                }
+                ActorItem::Subclass { name } => subclasses.push(name.clone()),
+                ActorItem::Weapon { name, system } => match system.to_weapon() {
+                    Ok(weapon) => {
+                        let item = Item {
+                            uuid: weapon.uuid,
+                            name: name.clone(),
+                            kind: ItemKind::Weapon,
+                            weight: system.weight,
+                            ..Default::default()
+                        };
+                        inventory
+                            .push(InventoryEntry::new(item, system.quantity).with_weapon(weapon));
+                    }
+                    Err(e) => skipped.push(format!("Weapon `{name}`: {e}")),
+                },
+                ActorItem::Spell { name, .. } => skipped.push(format!(
+                    "Spell `{name}`: Spells are learned through Spell Lists"
+                )),
+                ActorItem::Technique { name, system } => {
+                    first
+                        .maneuvers
+                        .push(match Maneuver::load_by_name(&mut *conn, name).await {
+                            Ok(maneuver) => ContentRef::Reference { id: maneuver.uuid },
+                            Err(Error::NotFound(_)) => match system.to_maneuver(name) {
+                                Ok(maneuver) => ContentRef::Embedded(maneuver),
+                                Err(e) => {
+                                    skipped.push(format!("Maneuver `{name}`: {e}"));
+                                    continue;
+                                }
+                            },
+                            Err(e) => return Err(e),
+                        });
+                }
+                ActorItem::Other => {}
+            }
+        }
+        levels.insert(0, first);
+
+        // Every Level is taken in the first Class; Subclasses come with the last one
+        for level in &mut levels {
+            level.classes = classes.iter().cloned().map(ContentRef::Embedded).collect();
+        }
+        let mut saved_subclasses = vec![];
+        for class in &classes {
+            if let Ok(found) = class.load_sublasses(&mut *conn).await {
+                saved_subclasses.extend(found);
+            }
+        }
+        if let Some(last) = levels.last_mut() {
+            for name in subclasses {
+                last.subclasses.push(
+                    match saved_subclasses
+                        .iter()
+                        .find(|subclass| subclass.name.eq_ignore_ascii_case(&name))
+                    {
+                        Some(subclass) => ContentRef::Reference { id: subclass.id },
+                        None => ContentRef::Embedded(Subclass::builder().name(name)?.build()?),
+                    },
+                );
+            }
+        }
+
+        let export = CharacterExport {
+            format: json::FORMAT.into(),
+            version: json::VERSION,
+            id: Uuid::now_v7(),
+            name: self.name.clone(),
+            player,
+            levels,
+            inventory: inventory.into_iter().map(Into::into).collect(),
+            purse: Default::default(),
+            resources: Resources {
+                hit_points: system.resources.health.into(),
+                mana_points: system.resources.mana.into(),
+                stamina_points: system.resources.stamina.into(),
+            },
+            conditions: Default::default(),
+        };
+
+        Ok((export, skipped))
+    }
+}
+
+impl From<&CharacterSheet> for Actor {
+    fn from(sheet: &CharacterSheet) -> Self {
+        let attribute = |name: AttributeName| {
+            let score = sheet
+                .attributes
+                .iter()
+                .find(|attribute| attribute.name == name);
+
+            AttributeData {
+                value: score.map(|score| score.score).unwrap_or_default(),
+                save: score.map(|score| score.save).unwrap_or_default(),
+                save_mastery: score.is_some_and(|score| score.save_mastery),
+            }
+        };
+
+        let mut items = vec![];
+        items.extend(
+            sheet
+                .ancestries
+                .iter()
+                .map(|name| ActorItem::Ancestry { name: name.clone() }),
+        );
+        items.push(ActorItem::Background {
+            name: sheet.background.clone(),
+        });
+        items.extend(
+            sheet
+                .classes
+                .iter()
+                .map(|name| ActorItem::Class { name: name.clone() }),
+        );
+        items.extend(
+            sheet
+                .subclasses
+                .iter()
+                .map(|name| ActorItem::Subclass { name: name.clone() }),
+        );
+        for entry in &sheet.equipment {
+            if let Some(weapon) = &entry.weapon {
+                items.push(ActorItem::Weapon {
+                    name: entry.item.name.clone(),
+                    system: WeaponData::new(entry, weapon),
+                });
+            }
+        }
+        items.extend(sheet.spells.iter().map(|spell| ActorItem::Spell {
+            name: spell.name.clone(),
+            system: SpellData::from(spell),
+        }));
+        items.extend(sheet.maneuvers.iter().map(|maneuver| ActorItem::Technique {
+            name: maneuver.name.clone(),
+            system: TechniqueData::from(maneuver),
+        }));
+
+        Actor {
+            name: sheet.name.clone(),
+            kind: ACTOR_TYPE.into(),
+            system: ActorData {
+                details: Details {
+                    level: sheet.level,
+                    combat_mastery: sheet.combat_mastery,
+                },
+                attributes: ActorAttributes {
+                    prime: Score { value: sheet.prime },
+                    mig: attribute(AttributeName::Might),
+                    agi: attribute(AttributeName::Agility),
+                    cha: attribute(AttributeName::Charisma),
+                    int: attribute(AttributeName::Intelligence),
+                },
+                defences: Defences {
+                    precision: (&sheet.precision_defense).into(),
+                    area: (&sheet.area_defense).into(),
+                },
+                resources: ActorResources {
+                    health: sheet.resources.hit_points.into(),
+                    stamina: sheet.resources.stamina_points.into(),
+                    mana: sheet.resources.mana_points.into(),
+                },
+                movement: Movement { speed: sheet.speed },
+                skills: sheet
+                    .skills
+                    .iter()
+                    .map(|skill| {
+                        (
+                            key(&skill.name),
+                            SkillData {
+                                label: skill.name.clone(),
+                                attribute: attribute_key(skill.attribute).into(),
+                                modifier: skill.bonus,
+                            },
+                        )
+                    })
+                    .collect(),
+                languages: sheet.languages.clone(),
+            },
+            items,
+        }
+    }
+}
+
+impl WeaponData {
+    fn new(entry: &InventoryEntry, weapon: &Weapon) -> Self {
+        let mut properties = BTreeMap::new();
+        for property in &weapon.properties {
+            let (name, style) = match property {
+                WeaponProperty::MultiFaceted(style) => {
+                    ("multiFaceted".to_string(), Some(key(&style.to_string())))
+                }
+                property => (camel_case(&property.to_string()), None),
+            };
+            properties.insert(
+                name,
+                PropertyData {
+                    active: true,
+                    style,
+                },
+            );
+        }
+
+        WeaponData {
+            weapon_type: key(&weapon.weapon_type.to_string()),
+            weapon_style: key(&weapon.style.to_string()),
+            damage_type: key(&weapon.damage_type.to_string()),
+            properties,
+            range: match weapon.base_range {
+                Range::Spaces(spaces) => spaces,
+                Range::Caster | Range::Touch => 0,
+            },
+            weight: entry.item.weight,
+            quantity: entry.quantity,
+            equipped: entry.slot.is_some(),
+        }
+    }
+
+    /// The Weapon, with a new id, if every name in it is one the book knows.
+    fn to_weapon(&self) -> Result<Weapon, String> {
+        let error = |e: crate::dc20::item::weapon::WeaponParseError| e.to_string();
+        let mut properties = vec![];
+        for (name, property) in &self.properties {
+            if !property.active {
+                continue;
+            }
+            let name = match &property.style {
+                Some(style) => format!("MultiFaceted({style})"),
+                None => name.clone(),
+            };
+            properties.push(name.parse().map_err(error)?);
+        }
+
+        Ok(Weapon {
+            uuid: Uuid::now_v7(),
+            weapon_type: self.weapon_type.parse().map_err(error)?,
+            style: self.weapon_style.parse().map_err(error)?,
+            damage_type: self.damage_type.parse().map_err(error)?,
+            properties,
+            base_range: Range::Spaces(self.range),
+        })
+    }
+}
+
+impl From<&Spell> for SpellData {
+    fn from(spell: &Spell) -> Self {
+        SpellData {
+            magic_school: key(spell.school.name()),
+            components: Components {
+                verbal: spell.has_verbal,
+                somatic: spell.has_somatic,
+                material: spell.has_material,
+            },
+            costs: Costs {
+                action_point: spell.action_point_cost,
+                mana: spell.mana_point_cost,
+                ..Default::default()
+            },
+            range: spell.range.to_string(),
+            duration: spell.duration.to_string(),
+            sustained: spell.sustained,
+            description: spell.description.clone(),
+            enhancements: spell
+                .point_enhancements
+                .iter()
+                .map(|enhancement| EnhancementData {
+                    name: enhancement.name.clone(),
+                    costs: Costs {
+                        action_point: enhancement.action_point_cost,
+                        mana: enhancement.mana_point_cost,
+                        ..Default::default()
+                    },
+                    description: enhancement.description.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&Maneuver> for TechniqueData {
+    fn from(maneuver: &Maneuver) -> Self {
+        TechniqueData {
+            technique_type: "maneuver".into(),
+            maneuver_type: key(&maneuver.kind.to_string()),
+            costs: Costs {
+                action_point: maneuver.action_point_cost,
+                stamina: maneuver.stamina_point_cost,
+                ..Default::default()
+            },
+            description: maneuver.description.clone(),
+        }
+    }
+}
+
+impl TechniqueData {
+    fn to_maneuver(&self, name: &str) -> Result<Maneuver, String> {
+        let mut kind = self.maneuver_type.clone();
+        if let Some(first) = kind.get_mut(..1) {
+            first.make_ascii_uppercase();
+        }
+        let kind: ManeuverKind = kind
+            .parse()
+            .map_err(|_| format!("unknown maneuver type `{}`", self.maneuver_type))?;
+
+        Ok(Maneuver {
+            uuid: Uuid::new_v4(),
+            name: name.into(),
+            kind,
+            action_point_cost: self.costs.action_point,
+            stamina_point_cost: self.costs.stamina,
+            description: self.description.clone(),
+        })
+    }
+}
+
+/// The result of importing an actor.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Imported {
+    pub character: Character,
+    /// What in the actor was left out, and why.
+    pub skipped: Vec<String>,
+}
+
+/// Saves a Foundry actor as a new Character played by `player`.
+pub async fn import(db: impl Db<'_>, json: &str, player: Player) -> crate::Result<Imported> {
+    let mut conn = db.connection().await?;
+
+    let actor = Actor::parse(json)?;
+    let (export, skipped) = actor.to_export(&mut *conn, player).await?;
+    let character = json::restore(&mut *conn, export, Ids::Preserve).await?;
+
+    Ok(Imported { character, skipped })
+}
+
+/// The system's short name for an Attribute.
+fn attribute_key(name: AttributeName) -> &'static str {
+    match name {
+        AttributeName::Prime => "prime",
+        AttributeName::Might => "mig",
+        AttributeName::Agility => "agi",
+        AttributeName::Charisma => "cha",
+        AttributeName::Intelligence => "int",
+    }
+}
+
+/// "Animal Handling" becomes `animalhandling`.
+fn key(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<String>()
+        .to_lowercase()
+}
+
+/// "TwoHanded" becomes `twoHanded`.
+fn camel_case(name: &str) -> String {
+    let mut chars = name.chars();
+
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _weapon_properties_survive_the_round_trip() {
+        let weapon = Weapon {
+            uuid: Uuid::now_v7(),
+            weapon_type: "Melee".parse().unwrap(),
+            style: "Whip".parse().unwrap(),
+            damage_type: "Slashing".parse().unwrap(),
+            properties: vec![
+                WeaponProperty::TwoHanded,
+                "MultiFaceted(Chained)".parse().unwrap(),
+            ],
+            base_range: Range::Spaces(1),
+        };
+        let entry = InventoryEntry::new(Item::default(), 1);
+
+        let data = WeaponData::new(&entry, &weapon);
+        assert!(data.properties.contains_key("twoHanded"));
+        assert_eq!(
+            data.properties["multiFaceted"].style.as_deref(),
+            Some("chained")
+        );
+
+        let mut properties = data.to_weapon().unwrap().properties;
+        properties.sort_by_key(|property| property.to_string());
+        assert_eq!(
+            properties,
+            vec![
+                "MultiFaceted(Chained)".parse().unwrap(),
+                WeaponProperty::TwoHanded
+            ]
+        );
+    }
+
+    #[test]
+    fn _only_character_actors_are_read() {
+        let json = r#"{
+            "name": "Goblin",
+            "type": "npc",
+            "system": {
+                "details": { "level": 1 },
+                "attributes": {
+                    "prime": { "value": 1 },
+                    "mig": { "value": 0 },
+                    "agi": { "value": 1 },
+                    "cha": { "value": 0 },
+                    "int": { "value": -1 }
+                }
+            }
+        }"#;
+
+        assert!(matches!(
+            Actor::parse(json),
+            Err(ImportError::Invalid(message)) if message.contains("npc")
+        ));
+    }
+}
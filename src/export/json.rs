@@ -0,0 +1,1061 @@
+//! A versioned JSON format for moving a whole Character between databases.
+//!
+//! ```json
+//! {
+//!   "format": "mellon-book/character",
+//!   "version": 2,
+//!   "id": "…",
+//!   "name": "Cygnus",
+//!   "player": { "id": "…", "name": "Spencer Dent" },
+//!   "levels": [
+//!     {
+//!       "id": "…",
+//!       "level": 1,
+//!       "attributes": { "prime": 3, "might": 3, "agility": 1, "charisma": 0, "intelligence": -2 },
+//!       "ancestries": [{ "content": "embedded", "id": "…", "name": "Human" }],
+//!       "ancestry_traits": [{ "content": "reference", "id": "…" }],
+//!       "backgrounds": [{ "content": "embedded", "id": "…", "name": "Human Mercenary" }],
+//!       "classes": [{ "content": "embedded", "id": "…", "name": "Barbarian" }],
+//!       "subclasses": [],
+//!       "maneuvers": [],
+//!       "spell_lists": [],
+//!       "spells": [],
+//!       "skills": [{ "skill_id": "…", "mastery": { "id": "…", "name": "Adept", "bonus": 4 } }]
+//!     }
+//!   ],
+//!   "inventory": [],
+//!   "purse": { "platinum": 0, "gold": 2, "silver": 5, "copper": 0 },
+//!   "resources": { "hit_points": { "current": 9, "maximum": 12 }, … },
+//!   "conditions": ["Dazed"]
+//! }
+//! ```
+//!
+//! Each Level lists the content chosen at it: Ancestries, Ancestry Traits,
+//! Backgrounds, Classes, Subclasses, Maneuvers, Spell Lists and the Spells
+//! learned from them, along with the Skills raised to a Mastery at it. Skills
+//! are referred to by id and must already be saved, as must the Masteries,
+//! which are embedded only to be readable. Content is either
+//! embedded whole, as [`export`] writes it, or referenced by its id alone, and
+//! says which with its `content` tag.
+//! On import, content already saved is used as it is, embedded content that
+//! is missing gets saved, and a reference to missing content is an error.
+//! Spell Lists embed their Spells and inventory entries embed their Items.
+//! A Consumable is embedded as it is saved, with the charges the Character
+//! has left on it kept on their entry as `charges`.
+//!
+//! `attributes` is `null` for a Level without base Attributes saved. The
+//! `version` changes whenever the format gains something an older file
+//! would be read without. Version 2 added `skills`; files of version 1 are
+//! still read, as Characters who haven't raised any Skills.
+
+use std::{collections::HashMap, error::Error, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+use sqlx::SqliteConnection;
+use uuid::Uuid;
+
+use crate::{
+    db::Db,
+    dc20::{
+        item::consumable::Consumable, Ancestry, AncestryTrait, Attributes, Background, Character,
+        Class, Conditions, Inventory, InventoryEntry, Item, Level, Maneuver, Mastery, Purse,
+        Resources, Skill, Spell, SpellList, Subclass,
+    },
+    player::Player,
+};
+
+/// Marks a JSON document as a Character export.
+pub const FORMAT: &str = "mellon-book/character";
+
+/// The version of the format written by [`export`].
+pub const VERSION: u32 = 2;
+
+/// The oldest version [`CharacterExport::parse`] still reads.
+pub const OLDEST_VERSION: u32 = 1;
+
+/// Content embedded in an export, or a reference to content already saved.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "content", rename_all = "snake_case")]
+pub enum ContentRef<T> {
+    Embedded(T),
+    Reference { id: Uuid },
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct LevelExport {
+    pub id: Uuid,
+    pub level: u32,
+    pub attributes: Option<Attributes>,
+    #[serde(default)]
+    pub ancestries: Vec<ContentRef<Ancestry>>,
+    #[serde(default)]
+    pub ancestry_traits: Vec<ContentRef<AncestryTrait>>,
+    #[serde(default)]
+    pub backgrounds: Vec<ContentRef<Background>>,
+    #[serde(default)]
+    pub classes: Vec<ContentRef<Class>>,
+    #[serde(default)]
+    pub subclasses: Vec<ContentRef<Subclass>>,
+    #[serde(default)]
+    pub maneuvers: Vec<ContentRef<Maneuver>>,
+    #[serde(default)]
+    pub spell_lists: Vec<ContentRef<SpellList>>,
+    #[serde(default)]
+    pub spells: Vec<ContentRef<Spell>>,
+    #[serde(default)]
+    pub skills: Vec<SkillMasteryExport>,
+}
+
+/// A Skill raised to a Mastery at a Level.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SkillMasteryExport {
+    pub skill_id: Uuid,
+    pub mastery: Mastery,
+}
+
+/// An inventory entry whose Consumable is the one saved, with the charges
+/// the Character has left on it kept apart.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct EntryExport {
+    #[serde(flatten)]
+    pub entry: InventoryEntry,
+    /// Only for an entry with a Consumable.
+    #[serde(default)]
+    pub charges: Option<u64>,
+}
+
+impl From<InventoryEntry> for EntryExport {
+    fn from(entry: InventoryEntry) -> Self {
+        EntryExport {
+            charges: entry
+                .consumable
+                .as_ref()
+                .map(|consumable| consumable.charges),
+            entry,
+        }
+    }
+}
+
+/// A Character with every Level, choice and possession they have.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CharacterExport {
+    pub format: String,
+    pub version: u32,
+    pub id: Uuid,
+    pub name: String,
+    pub player: Player,
+    pub levels: Vec<LevelExport>,
+    #[serde(default)]
+    pub inventory: Vec<EntryExport>,
+    #[serde(default)]
+    pub purse: Purse,
+    #[serde(default)]
+    pub resources: Resources,
+    #[serde(default)]
+    pub conditions: Conditions,
+}
+
+/// Read before the rest so an unknown version gets a clear error.
+#[derive(Deserialize)]
+struct Header {
+    format: String,
+    version: u32,
+}
+
+impl CharacterExport {
+    /// Reads an export, rejecting versions of the format it doesn't know.
+    pub fn parse(json: &str) -> Result<CharacterExport, ImportError> {
+        let header: Header =
+            serde_json::from_str(json).map_err(|e| ImportError::Invalid(e.to_string()))?;
+
+        if header.format != FORMAT {
+            return Err(ImportError::Invalid(format!(
+                "expected format `{FORMAT}`, found `{}`",
+                header.format
+            )));
+        }
+
+        if !(OLDEST_VERSION..=VERSION).contains(&header.version) {
+            return Err(ImportError::UnsupportedVersion(header.version));
+        }
+
+        serde_json::from_str(json).map_err(|e| ImportError::Invalid(e.to_string()))
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ImportError {
+    /// The JSON is not a Character export.
+    Invalid(String),
+    UnsupportedVersion(u32),
+    /// Content referenced by id that the database does not have.
+    MissingContent {
+        kind: &'static str,
+        id: Uuid,
+    },
+    /// Importing with the same ids would overwrite this Character.
+    CharacterExists(Uuid),
+}
+
+impl Display for ImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImportError::Invalid(message) => write!(f, "Not a Character export: {message}"),
+            ImportError::UnsupportedVersion(version) => write!(
+                f,
+                "Character export version {version} is not supported, only versions \
+                 {OLDEST_VERSION} to {VERSION}"
+            ),
+            ImportError::MissingContent { kind, id } => {
+                write!(
+                    f,
+                    "{kind} `{id}` is neither saved nor embedded in the export"
+                )
+            }
+            ImportError::CharacterExists(id) => write!(
+                f,
+                "Character `{id}` already exists; import them with new ids instead"
+            ),
+        }
+    }
+}
+
+impl Error for ImportError {}
+
+/// What happens to the Character's own ids on import. Content keeps its ids
+/// either way so it is shared rather than duplicated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Ids {
+    /// Keep every id, refusing to overwrite a Character already saved.
+    #[default]
+    Preserve,
+    /// Give the Character, their Levels and their inventory entries new ids.
+    Remap,
+    /// Keep every id, replacing the Levels and inventory of a Character
+    /// already saved while leaving their Campaigns and history alone.
+    Overwrite,
+}
+
+/// Exports the Character with all content embedded.
+pub async fn export(db: impl Db<'_>, id: Uuid) -> crate::Result<CharacterExport> {
+    let mut conn = db.connection().await?;
+
+    let character = Character::load(&mut *conn, id).await?;
+
+    let mut levels = vec![];
+    for level in character.load_levels(&mut *conn).await? {
+        let attributes = match level.load_base_attributes(&mut *conn).await {
+            Ok(attributes) => Some(attributes),
+            Err(crate::Error::NotFound(_)) => None,
+            Err(e) => return Err(e),
+        };
+
+        levels.push(LevelExport {
+            id: level.id,
+            level: level.level,
+            attributes,
+            ancestries: embed(level.load_ancestries(&mut *conn).await?),
+            ancestry_traits: embed(level.load_ancestry_traits(&mut *conn, None).await?),
+            backgrounds: embed(level.load_backgrounds(&mut *conn, None).await?),
+            classes: embed(level.load_classes(&mut *conn, None).await?),
+            subclasses: embed(level.load_sublasses(&mut *conn).await?),
+            maneuvers: embed(level.load_maneuvers(&mut *conn).await?),
+            spell_lists: embed(level.load_spell_lists(&mut *conn, None).await?),
+            spells: embed(level.load_spells(&mut *conn).await?),
+            skills: level
+                .load_skill_masteries(&mut *conn)
+                .await?
+                .into_iter()
+                .map(|(skill, mastery)| SkillMasteryExport {
+                    skill_id: skill.id,
+                    mastery,
+                })
+                .collect(),
+        });
+    }
+
+    Ok(CharacterExport {
+        format: FORMAT.into(),
+        version: VERSION,
+        id,
+        name: character.character_name().into(),
+        player: character.player().clone(),
+        levels,
+        inventory: export_inventory(&mut *conn, id).await?,
+        purse: Purse::load(&mut *conn, id).await?,
+        resources: Resources::load(&mut *conn, id).await?,
+        conditions: Conditions::load(&mut *conn, id).await?,
+    })
+}
+
+/// The Character's inventory with each Consumable as it is saved.
+async fn export_inventory(db: impl Db<'_>, id: Uuid) -> crate::Result<Vec<EntryExport>> {
+    let mut conn = db.connection().await?;
+
+    let mut entries = vec![];
+    for entry in Inventory::load(&mut *conn, id).await?.entries {
+        let mut entry = EntryExport::from(entry);
+        if let Some(consumable) = &mut entry.entry.consumable {
+            *consumable = Consumable::load(&mut *conn, consumable.uuid).await?;
+        }
+        entries.push(entry);
+    }
+
+    Ok(entries)
+}
+
+fn embed<T>(content: Vec<T>) -> Vec<ContentRef<T>> {
+    content.into_iter().map(ContentRef::Embedded).collect()
+}
+
+/// Parses and imports an export, returning the saved Character.
+pub async fn import(db: impl Db<'_>, json: &str, ids: Ids) -> crate::Result<Character> {
+    restore(db, CharacterExport::parse(json)?, ids).await
+}
+
+/// Saves an export as a Character, all at once or not at all. Every content
+/// reference is checked before anything is written.
+pub async fn restore(
+    db: impl Db<'_>,
+    export: CharacterExport,
+    ids: Ids,
+) -> crate::Result<Character> {
+    let mut conn = db.connection().await?;
+
+    let mut tx = conn.begin().await?;
+
+    if export
+        .levels
+        .iter()
+        .all(|level| level.backgrounds.is_empty())
+    {
+        return Err(ImportError::Invalid("the Character has no Background".into()).into());
+    }
+
+    for level in &export.levels {
+        level.check(&mut *tx).await?;
+    }
+
+    let remap = |id: Uuid| match ids {
+        Ids::Preserve | Ids::Overwrite => id,
+        Ids::Remap => Uuid::now_v7(),
+    };
+
+    let character_id = remap(export.id);
+    let existed = character_exists(&mut *tx, character_id).await?;
+    if ids == Ids::Preserve && existed {
+        return Err(ImportError::CharacterExists(character_id).into());
+    }
+
+    let player_id = *export.player.id();
+    if find::<Player>(&mut *tx, player_id).await?.is_none() {
+        export.player.clone().save(&mut *tx).await?;
+    }
+
+    let mut levels = vec![];
+    for level in &export.levels {
+        let links = level.resolve(&mut *tx).await?;
+        let saved = Level {
+            id: remap(level.id),
+            character_id,
+            level: level.level,
+        };
+
+        levels.push((saved, level.attributes.clone(), links));
+    }
+
+    for entry in &export.inventory {
+        save_item(&mut *tx, &entry.entry).await?;
+    }
+
+    sqlx::query!(
+        r#"
+            INSERT INTO `characters` (`character_id`, `name`, `player_id`)
+            VALUES (?1, ?2, ?3)
+            ON CONFLICT (`character_id`) DO UPDATE SET
+                `name` = ?2,
+                `player_id` = ?3
+            ;
+        "#,
+        character_id,
+        export.name,
+        player_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // Only an overwritten Character has Levels already
+    sqlx::query!(
+        r#"
+            DELETE FROM `character_levels`
+            WHERE `character_id` = ?1
+            ;
+        "#,
+        character_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    for (level, _, links) in &levels {
+        sqlx::query!(
+            r#"
+                INSERT INTO `character_levels` (`character_level_id`, `character_id`, `level`)
+                VALUES (?1, ?2, ?3)
+                ;
+            "#,
+            level.id,
+            level.character_id,
+            level.level
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for ancestry_id in &links.ancestries {
+            sqlx::query!(
+                r#"
+                    INSERT INTO `ancestries_character_levels` (`ancestry_id`, `character_level_id`)
+                    VALUES (?1, ?2)
+                    ;
+                "#,
+                ancestry_id,
+                level.id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for ancestry_trait_id in &links.ancestry_traits {
+            sqlx::query!(
+                r#"
+                    INSERT INTO `ancestry_traits_character_levels`
+                        (`ancestry_trait_id`, `character_level_id`)
+                    VALUES (?1, ?2)
+                    ;
+                "#,
+                ancestry_trait_id,
+                level.id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for background_id in &links.backgrounds {
+            sqlx::query!(
+                r#"
+                    INSERT INTO `backgrounds_character_levels` (`background_id`, `character_level_id`)
+                    VALUES (?1, ?2)
+                    ;
+                "#,
+                background_id,
+                level.id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for class_id in &links.classes {
+            sqlx::query!(
+                r#"
+                    INSERT INTO `character_levels_classes` (`character_level_id`, `class_id`)
+                    VALUES (?1, ?2)
+                    ;
+                "#,
+                level.id,
+                class_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for subclass_id in &links.subclasses {
+            sqlx::query!(
+                r#"
+                    INSERT INTO `character_levels_subclasses` (`character_level_id`, `subclass_id`)
+                    VALUES (?1, ?2)
+                    ;
+                "#,
+                level.id,
+                subclass_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for maneuver_id in &links.maneuvers {
+            sqlx::query!(
+                r#"
+                    INSERT INTO `character_levels_maneuvers` (`character_level_id`, `maneuver_id`)
+                    VALUES (?1, ?2)
+                    ;
+                "#,
+                level.id,
+                maneuver_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for spell_id in &links.spells {
+            sqlx::query!(
+                r#"
+                    INSERT INTO `character_levels_spells` (`character_level_id`, `spell_id`)
+                    VALUES (?1, ?2)
+                    ;
+                "#,
+                level.id,
+                spell_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for (skill_id, mastery_id) in &links.skills {
+            sqlx::query!(
+                r#"
+                    INSERT INTO `character_levels_skills`
+                        (`character_level_id`, `skill_id`, `mastery_id`)
+                    VALUES (?1, ?2, ?3)
+                    ;
+                "#,
+                level.id,
+                skill_id,
+                mastery_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for spell_list_id in &links.spell_lists {
+            sqlx::query!(
+                r#"
+                    INSERT INTO `character_levels_spell_lists`
+                        (`character_level_id`, `spell_list_id`)
+                    VALUES (?1, ?2)
+                    ;
+                "#,
+                level.id,
+                spell_list_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+    }
+
+    let entry_ids: HashMap<Uuid, Uuid> = export
+        .inventory
+        .iter()
+        .map(|entry| (entry.entry.id, remap(entry.entry.id)))
+        .collect();
+    let inventory = Inventory {
+        character_id,
+        entries: export
+            .inventory
+            .into_iter()
+            .map(|EntryExport { entry, charges }| InventoryEntry {
+                id: entry_ids[&entry.id],
+                container_id: entry
+                    .container_id
+                    .and_then(|container_id| entry_ids.get(&container_id).copied()),
+                consumable: entry.consumable.map(|consumable| Consumable {
+                    charges: charges.unwrap_or(consumable.charges),
+                    ..consumable
+                }),
+                ..entry
+            })
+            .collect(),
+    };
+
+    for (level, attributes, _) in &levels {
+        if let Some(attributes) = attributes {
+            level.save_base_attributes(&mut *tx, attributes).await?;
+        }
+    }
+    inventory.save(&mut *tx).await?;
+    export.purse.save(&mut *tx, character_id).await?;
+    export.resources.save(&mut *tx, character_id).await?;
+    export.conditions.save(&mut *tx, character_id).await?;
+
+    let character = Character::load(&mut *tx, character_id).await?;
+
+    tx.commit().await?;
+
+    Ok(character)
+}
+
+async fn character_exists(
+    executor: impl sqlx::SqliteExecutor<'_>,
+    id: Uuid,
+) -> crate::Result<bool> {
+    let row = sqlx::query!(
+        r#"
+            SELECT `character_id`
+            FROM `characters`
+            WHERE `character_id` = ?1
+            LIMIT 1
+            ;
+        "#,
+        id
+    )
+    .fetch_optional(executor)
+    .await?;
+
+    Ok(row.is_some())
+}
+
+/// Saves an inventory entry's Item, and its details, when the database does
+/// not have it yet.
+async fn save_item(db: impl Db<'_>, entry: &InventoryEntry) -> crate::Result<()> {
+    let mut conn = db.connection().await?;
+
+    if find::<Item>(&mut *conn, entry.item.uuid).await?.is_some() {
+        return Ok(());
+    }
+
+    entry.item.clone().save(&mut *conn).await?;
+    if let Some(weapon) = &entry.weapon {
+        weapon.clone().save(&mut *conn).await?;
+    }
+    if let Some(armor) = &entry.armor {
+        armor.clone().save(&mut *conn).await?;
+    }
+    if let Some(consumable) = &entry.consumable {
+        consumable.clone().save(&mut *conn).await?;
+    }
+
+    Ok(())
+}
+
+/// The ids of the content a Level links to, once it is all saved.
+#[derive(Clone, Debug, Default)]
+struct Links {
+    ancestries: Vec<Uuid>,
+    ancestry_traits: Vec<Uuid>,
+    backgrounds: Vec<Uuid>,
+    classes: Vec<Uuid>,
+    subclasses: Vec<Uuid>,
+    maneuvers: Vec<Uuid>,
+    spell_lists: Vec<Uuid>,
+    spells: Vec<Uuid>,
+    /// Each Skill raised, with the Mastery it was raised to.
+    skills: Vec<(Uuid, Uuid)>,
+}
+
+impl LevelExport {
+    /// Fails on the first reference to content that isn't saved.
+    async fn check(&self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+
+        check_all(&mut *conn, &self.ancestries).await?;
+        check_all(&mut *conn, &self.ancestry_traits).await?;
+        check_all(&mut *conn, &self.backgrounds).await?;
+        check_all(&mut *conn, &self.classes).await?;
+        check_all(&mut *conn, &self.subclasses).await?;
+        check_all(&mut *conn, &self.maneuvers).await?;
+        check_all(&mut *conn, &self.spell_lists).await?;
+        check_all(&mut *conn, &self.spells).await?;
+
+        for SkillMasteryExport { skill_id, mastery } in &self.skills {
+            if find::<Skill>(&mut *conn, *skill_id).await?.is_none() {
+                return Err(ImportError::MissingContent {
+                    kind: Skill::KIND,
+                    id: *skill_id,
+                }
+                .into());
+            }
+            match Mastery::load(&mut *conn, mastery.id).await {
+                Ok(_) => {}
+                Err(crate::Error::NotFound(_)) => {
+                    return Err(ImportError::MissingContent {
+                        kind: "Mastery",
+                        id: mastery.id,
+                    }
+                    .into())
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn resolve(&self, db: impl Db<'_>) -> crate::Result<Links> {
+        let mut conn = db.connection().await?;
+
+        Ok(Links {
+            ancestries: resolve_all(&mut *conn, &self.ancestries).await?,
+            ancestry_traits: resolve_all(&mut *conn, &self.ancestry_traits).await?,
+            backgrounds: resolve_all(&mut *conn, &self.backgrounds).await?,
+            classes: resolve_all(&mut *conn, &self.classes).await?,
+            subclasses: resolve_all(&mut *conn, &self.subclasses).await?,
+            maneuvers: resolve_all(&mut *conn, &self.maneuvers).await?,
+            spell_lists: resolve_all(&mut *conn, &self.spell_lists).await?,
+            spells: resolve_all(&mut *conn, &self.spells).await?,
+            skills: self
+                .skills
+                .iter()
+                .map(|skill| (skill.skill_id, skill.mastery.id))
+                .collect(),
+        })
+    }
+}
+
+async fn check_all<T: Content>(db: impl Db<'_>, content: &[ContentRef<T>]) -> crate::Result<()> {
+    let mut conn = db.connection().await?;
+
+    for content in content {
+        if let ContentRef::Reference { id } = content {
+            if find::<T>(&mut *conn, *id).await?.is_none() {
+                return Err(ImportError::MissingContent {
+                    kind: T::KIND,
+                    id: *id,
+                }
+                .into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Saves whatever embedded content is missing, returning the ids to link to.
+async fn resolve_all<T: Content>(
+    db: impl Db<'_>,
+    content: &[ContentRef<T>],
+) -> crate::Result<Vec<Uuid>> {
+    let mut conn = db.connection().await?;
+
+    let mut ids = vec![];
+
+    for content in content {
+        let id = match content {
+            ContentRef::Embedded(content) => content.id(),
+            ContentRef::Reference { id } => *id,
+        };
+
+        if find::<T>(&mut *conn, id).await?.is_none() {
+            match content {
+                ContentRef::Embedded(content) => content.clone().save(&mut conn).await?,
+                ContentRef::Reference { id } => {
+                    return Err(ImportError::MissingContent {
+                        kind: T::KIND,
+                        id: *id,
+                    }
+                    .into())
+                }
+            }
+        }
+
+        ids.push(id);
+    }
+
+    Ok(ids)
+}
+
+/// Loads the row, or `None` when there isn't one.
+async fn find<T: Content>(db: impl Db<'_>, id: Uuid) -> crate::Result<Option<T>> {
+    let mut conn = db.connection().await?;
+
+    match T::load(&mut conn, id).await {
+        Ok(content) => Ok(Some(content)),
+        Err(crate::Error::NotFound(_)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Anything an export can carry that is shared between Characters.
+trait Content: Clone {
+    const KIND: &'static str;
+
+    fn id(&self) -> Uuid;
+
+    async fn load(conn: &mut SqliteConnection, id: Uuid) -> crate::Result<Self>;
+
+    async fn save(self, conn: &mut SqliteConnection) -> crate::Result<()>;
+}
+
+impl Content for Player {
+    const KIND: &'static str = "Player";
+
+    fn id(&self) -> Uuid {
+        *Player::id(self)
+    }
+
+    async fn load(conn: &mut SqliteConnection, id: Uuid) -> crate::Result<Self> {
+        Player::load(conn, id).await
+    }
+
+    async fn save(self, conn: &mut SqliteConnection) -> crate::Result<()> {
+        Player::save(self, conn).await.map(|_| ())
+    }
+}
+
+impl Content for Ancestry {
+    const KIND: &'static str = "Ancestry";
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    async fn load(conn: &mut SqliteConnection, id: Uuid) -> crate::Result<Self> {
+        Ancestry::load(conn, id).await
+    }
+
+    async fn save(self, conn: &mut SqliteConnection) -> crate::Result<()> {
+        Ancestry::save(self, conn).await
+    }
+}
+
+impl Content for AncestryTrait {
+    const KIND: &'static str = "Ancestry Trait";
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    async fn load(conn: &mut SqliteConnection, id: Uuid) -> crate::Result<Self> {
+        AncestryTrait::load(conn, id).await
+    }
+
+    async fn save(self, conn: &mut SqliteConnection) -> crate::Result<()> {
+        AncestryTrait::save(self, conn).await
+    }
+}
+
+impl Content for Skill {
+    const KIND: &'static str = "Skill";
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    async fn load(conn: &mut SqliteConnection, id: Uuid) -> crate::Result<Self> {
+        Skill::load(conn, id).await
+    }
+
+    async fn save(self, conn: &mut SqliteConnection) -> crate::Result<()> {
+        Skill::save(self, conn).await
+    }
+}
+
+impl Content for Background {
+    const KIND: &'static str = "Background";
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    async fn load(conn: &mut SqliteConnection, id: Uuid) -> crate::Result<Self> {
+        Background::load(conn, id).await
+    }
+
+    async fn save(self, conn: &mut SqliteConnection) -> crate::Result<()> {
+        Background::save(self, conn).await
+    }
+}
+
+impl Content for Class {
+    const KIND: &'static str = "Class";
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    async fn load(conn: &mut SqliteConnection, id: Uuid) -> crate::Result<Self> {
+        Class::load(conn, id).await
+    }
+
+    async fn save(self, conn: &mut SqliteConnection) -> crate::Result<()> {
+        Class::save(self, conn).await
+    }
+}
+
+impl Content for Subclass {
+    const KIND: &'static str = "Subclass";
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    async fn load(conn: &mut SqliteConnection, id: Uuid) -> crate::Result<Self> {
+        Subclass::load(conn, id).await
+    }
+
+    async fn save(self, conn: &mut SqliteConnection) -> crate::Result<()> {
+        Subclass::save(self, conn).await
+    }
+}
+
+impl Content for Maneuver {
+    const KIND: &'static str = "Maneuver";
+
+    fn id(&self) -> Uuid {
+        self.uuid
+    }
+
+    async fn load(conn: &mut SqliteConnection, id: Uuid) -> crate::Result<Self> {
+        Maneuver::load(conn, id).await
+    }
+
+    async fn save(self, conn: &mut SqliteConnection) -> crate::Result<()> {
+        Maneuver::save(self, conn).await
+    }
+}
+
+impl Content for Spell {
+    const KIND: &'static str = "Spell";
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    async fn load(conn: &mut SqliteConnection, id: Uuid) -> crate::Result<Self> {
+        Spell::load(conn, id).await
+    }
+
+    async fn save(self, conn: &mut SqliteConnection) -> crate::Result<()> {
+        Spell::save(self, conn).await
+    }
+}
+
+impl Content for SpellList {
+    const KIND: &'static str = "Spell List";
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    async fn load(conn: &mut SqliteConnection, id: Uuid) -> crate::Result<Self> {
+        SpellList::load(conn, id).await
+    }
+
+    /// Saves any of the Spell List's Spells that are missing first.
+    async fn save(self, conn: &mut SqliteConnection) -> crate::Result<()> {
+        let spells: Vec<_> = self
+            .spells
+            .iter()
+            .cloned()
+            .map(ContentRef::Embedded)
+            .collect();
+        resolve_all(&mut *conn, &spells).await?;
+
+        SpellList::save(self, conn).await
+    }
+}
+
+impl Content for Item {
+    const KIND: &'static str = "Item";
+
+    fn id(&self) -> Uuid {
+        self.uuid
+    }
+
+    async fn load(conn: &mut SqliteConnection, id: Uuid) -> crate::Result<Self> {
+        Item::load(conn, id).await
+    }
+
+    async fn save(self, conn: &mut SqliteConnection) -> crate::Result<()> {
+        Item::save(self, conn).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn export() -> CharacterExport {
+        CharacterExport {
+            format: FORMAT.into(),
+            version: VERSION,
+            id: Uuid::now_v7(),
+            name: "Cygnus".into(),
+            player: Player::builder()
+                .name("Spencer Dent".to_string())
+                .unwrap()
+                .build()
+                .unwrap(),
+            levels: vec![LevelExport {
+                id: Uuid::now_v7(),
+                level: 1,
+                attributes: None,
+                ancestries: vec![],
+                ancestry_traits: vec![],
+                backgrounds: vec![ContentRef::Reference { id: Uuid::now_v7() }],
+                classes: vec![],
+                subclasses: vec![],
+                maneuvers: vec![],
+                spell_lists: vec![],
+                spells: vec![],
+                skills: vec![],
+            }],
+            inventory: vec![],
+            purse: Purse::default(),
+            resources: Resources::default(),
+            conditions: Conditions::default(),
+        }
+    }
+
+    #[test]
+    fn _exports_read_back_the_same() {
+        let export = export();
+
+        let json = export.to_json().unwrap();
+
+        assert_eq!(CharacterExport::parse(&json), Ok(export));
+    }
+
+    #[test]
+    fn _content_can_be_embedded_or_referenced_by_id() {
+        let background: ContentRef<Background> = serde_json::from_str(
+            r#"{ "content": "reference", "id": "019a0f2b-7c3e-7a10-b4d2-e6f8a1c3e5f7" }"#,
+        )
+        .unwrap();
+        assert!(matches!(background, ContentRef::Reference { .. }));
+
+        let background: ContentRef<Background> = serde_json::from_str(
+            r#"{ "content": "embedded", "id": "019a0f2b-7c3e-7a10-b4d2-e6f8a1c3e5f7", "name": "Human Mercenary" }"#,
+        )
+        .unwrap();
+        assert!(matches!(background, ContentRef::Embedded(_)));
+
+        // Without its tag, an id alone could be either
+        assert!(serde_json::from_str::<ContentRef<Background>>(
+            r#"{ "id": "019a0f2b-7c3e-7a10-b4d2-e6f8a1c3e5f7" }"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn _older_versions_are_read_without_skills() {
+        let mut export = serde_json::to_value(export()).unwrap();
+        export["version"] = 1.into();
+        export["levels"][0]
+            .as_object_mut()
+            .unwrap()
+            .remove("skills");
+
+        let parsed = CharacterExport::parse(&export.to_string()).unwrap();
+
+        assert!(parsed.levels[0].skills.is_empty());
+    }
+
+    #[test]
+    fn _other_versions_are_rejected() {
+        let mut export = serde_json::to_value(export()).unwrap();
+        export["version"] = (VERSION + 1).into();
+
+        assert_eq!(
+            CharacterExport::parse(&export.to_string()),
+            Err(ImportError::UnsupportedVersion(VERSION + 1))
+        );
+
+        export["format"] = "foundry".into();
+        assert!(matches!(
+            CharacterExport::parse(&export.to_string()),
+            Err(ImportError::Invalid(_))
+        ));
+    }
+}
@@ -0,0 +1,5 @@
+//! Getting Characters out of the database and into other hands.
+
+pub mod foundry;
+pub mod json;
+pub mod sheet;
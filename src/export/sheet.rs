@@ -0,0 +1,710 @@
+//! Printable character sheets, as Markdown, a self-contained HTML page or a
+//! PDF.
+
+mod pdf;
+
+use std::{fmt, fmt::Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    db::Db,
+    dc20::{
+        Attack, AttributeLevel, AttributeName, Character, CharacterCombatant, Combatant, Condition,
+        Defense, InventoryEntry, Level, Maneuver, Resources, Spell,
+    },
+};
+
+/// Everything but Prime, which has no Save of its own.
+const SAVED_ATTRIBUTES: [AttributeName; 4] = [
+    AttributeName::Might,
+    AttributeName::Agility,
+    AttributeName::Charisma,
+    AttributeName::Intelligence,
+];
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AttributeScore {
+    pub name: AttributeName,
+    pub score: i64,
+    pub save: isize,
+    pub save_mastery: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SkillBonus {
+    pub name: String,
+    pub attribute: AttributeName,
+    pub bonus: i64,
+}
+
+/// A Character as they are at one Level, ready to print.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CharacterSheet {
+    pub name: String,
+    pub player_name: String,
+    pub level: u32,
+    pub combat_mastery: usize,
+    pub ancestries: Vec<String>,
+    pub background: String,
+    pub classes: Vec<String>,
+    pub subclasses: Vec<String>,
+    pub prime: i64,
+    pub attributes: Vec<AttributeScore>,
+    pub precision_defense: Defense,
+    pub area_defense: Defense,
+    pub speed: u64,
+    pub conditions: Vec<Condition>,
+    pub resources: Resources,
+    pub skills: Vec<SkillBonus>,
+    pub languages: Vec<String>,
+    pub trades: Vec<String>,
+    pub attacks: Vec<Attack>,
+    pub maneuvers: Vec<Maneuver>,
+    pub spells: Vec<Spell>,
+    pub equipment: Vec<InventoryEntry>,
+}
+
+impl CharacterSheet {
+    /// The sheet's numbers, worked out from the Character in combat. Their
+    /// Classes, Skills, Spells and the like are left for the caller to fill.
+    #[must_use]
+    pub fn new(
+        character: &Character,
+        combatant: &CharacterCombatant,
+        save_masteries: &[AttributeName],
+    ) -> Self {
+        let attributes = combatant.attributes();
+
+        Self {
+            name: character.character_name().into(),
+            player_name: character.player_name().into(),
+            level: combatant.level.level,
+            combat_mastery: combatant.combat_mastery(),
+            ancestries: vec![],
+            background: character.background().name.clone(),
+            classes: vec![],
+            subclasses: vec![],
+            prime: attributes.prime(),
+            attributes: SAVED_ATTRIBUTES
+                .into_iter()
+                .map(|name| {
+                    let save_mastery = save_masteries.contains(&name);
+                    let mut attribute =
+                        AttributeLevel::new().with_base_score(attributes.get(name) as isize);
+                    if save_mastery {
+                        attribute = attribute.with_save_proficiency();
+                    }
+
+                    AttributeScore {
+                        name,
+                        score: attributes.get(name),
+                        save: attribute.calc_save(combatant.level.clone()),
+                        save_mastery,
+                    }
+                })
+                .collect(),
+            precision_defense: combatant.precision_defense(),
+            area_defense: combatant.area_defense(),
+            speed: combatant.speed(),
+            conditions: combatant.conditions().iter().copied().collect(),
+            resources: Resources::default(),
+            skills: vec![],
+            languages: vec![],
+            trades: vec![],
+            attacks: combatant.attacks().to_vec(),
+            maneuvers: vec![],
+            spells: vec![],
+            equipment: combatant.inventory.entries.clone(),
+        }
+    }
+
+    /// Loads the whole sheet for the Character at `level`. Saves are
+    /// increased for the Attributes the Class they started in has Mastery in.
+    pub async fn load(
+        db: impl Db<'_>,
+        character: &Character,
+        level: &Level,
+    ) -> crate::Result<CharacterSheet> {
+        let mut conn = db.connection().await?;
+
+        let levels = character.load_levels(&mut *conn).await?;
+        let mut save_masteries = vec![];
+        if let Some(first) = levels.first() {
+            for class in first.load_classes(&mut *conn, None).await? {
+                save_masteries.extend(class.load_save_masteries(&mut *conn).await?);
+            }
+        }
+
+        let combatant = character.load_combatant(&mut *conn, level.level).await?;
+        let mut sheet = Self::new(character, &combatant, &save_masteries);
+
+        sheet.skills = level
+            .load_skill_checks(&mut *conn)
+            .await?
+            .into_iter()
+            .map(|check| SkillBonus {
+                bonus: check.bonus(&combatant.attributes),
+                name: check.skill.name,
+                attribute: check.attribute,
+            })
+            .collect();
+        sheet.spells = level.load_known_spells(&mut *conn).await?;
+
+        // Maneuvers and Ancestries build up over every Level taken so far
+        for taken in levels {
+            if taken.level > level.level {
+                continue;
+            }
+            sheet
+                .maneuvers
+                .extend(taken.load_maneuvers(&mut *conn).await?);
+            sheet.ancestries.extend(
+                taken
+                    .load_ancestries(&mut *conn)
+                    .await?
+                    .into_iter()
+                    .map(|ancestry| ancestry.name),
+            );
+        }
+        sheet.maneuvers.sort_by(|a, b| a.name.cmp(&b.name));
+        sheet.maneuvers.dedup_by_key(|maneuver| maneuver.uuid);
+        sheet.ancestries.sort();
+        sheet.ancestries.dedup();
+
+        sheet.classes = names(level.load_classes(&mut *conn, None).await?, |class| {
+            class.name
+        });
+        sheet.subclasses = names(level.load_sublasses(&mut *conn).await?, |subclass| {
+            subclass.name
+        });
+        let background = character.background();
+        sheet.languages = names(background.load_languages(&mut *conn).await?, |language| {
+            language.name
+        });
+        sheet.trades = names(background.load_trades(&mut *conn).await?, |trade| {
+            trade.name
+        });
+        sheet.resources = Resources::load(&mut *conn, *character.id()).await?;
+
+        Ok(sheet)
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        // Writing to a String can't fail
+        let _ = self.write_markdown(&mut out);
+
+        out
+    }
+
+    pub fn to_html(&self) -> String {
+        let mut out = String::new();
+        let _ = self.write_html(&mut out);
+
+        out
+    }
+
+    /// "Level 3 Psion (Telepath) · Human · Human Mercenary"
+    fn summary(&self) -> String {
+        let mut summary = format!("Level {}", self.level);
+        if !self.classes.is_empty() {
+            let _ = write!(summary, " {}", self.classes.join("/"));
+        }
+        if !self.subclasses.is_empty() {
+            let _ = write!(summary, " ({})", self.subclasses.join("/"));
+        }
+        for part in self.ancestries.iter().chain([&self.background]) {
+            let _ = write!(summary, " · {part}");
+        }
+
+        summary
+    }
+
+    fn conditions_line(&self) -> String {
+        if self.conditions.is_empty() {
+            return "None".into();
+        }
+
+        self.conditions
+            .iter()
+            .map(Condition::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn attributes_table(&self) -> Table {
+        Table {
+            headers: &["Attribute", "Score", "Save"],
+            rows: self
+                .attributes
+                .iter()
+                .map(|attribute| {
+                    let mut save = format!("{:+}", attribute.save);
+                    if attribute.save_mastery {
+                        save.push_str(" (Mastery)");
+                    }
+
+                    vec![
+                        attribute.name.to_string(),
+                        format!("{:+}", attribute.score),
+                        save,
+                    ]
+                })
+                .collect(),
+        }
+    }
+
+    fn defenses_table(&self) -> Table {
+        let row = |name: &str, defense: &Defense| {
+            vec![
+                name.to_string(),
+                defense.score.to_string(),
+                defense.heavy().to_string(),
+                defense.brutal().to_string(),
+                defense.reduction.to_string(),
+            ]
+        };
+
+        Table {
+            headers: &["Defense", "Score", "Heavy", "Brutal", "Reduction"],
+            rows: vec![
+                row("Precision (PD)", &self.precision_defense),
+                row("Area (AD)", &self.area_defense),
+            ],
+        }
+    }
+
+    fn resources_table(&self) -> Table {
+        let resources = &self.resources;
+
+        Table {
+            headers: &["Resource", "Current", "Maximum"],
+            rows: [
+                ("Hit Points", resources.hit_points),
+                ("Stamina Points", resources.stamina_points),
+                ("Mana Points", resources.mana_points),
+            ]
+            .into_iter()
+            .map(|(name, pool)| {
+                vec![
+                    name.to_string(),
+                    pool.current.to_string(),
+                    pool.maximum.to_string(),
+                ]
+            })
+            .collect(),
+        }
+    }
+
+    fn skills_table(&self) -> Table {
+        Table {
+            headers: &["Skill", "Attribute", "Bonus"],
+            rows: self
+                .skills
+                .iter()
+                .map(|skill| {
+                    vec![
+                        skill.name.clone(),
+                        skill.attribute.to_string(),
+                        format!("{:+}", skill.bonus),
+                    ]
+                })
+                .collect(),
+        }
+    }
+
+    fn attacks_table(&self) -> Table {
+        Table {
+            headers: &["Attack", "Damage", "Damage Type", "Range"],
+            rows: self
+                .attacks
+                .iter()
+                .map(|attack| {
+                    vec![
+                        attack.name.clone(),
+                        attack.damage.to_string(),
+                        attack.weapon.damage_type.to_string(),
+                        attack.weapon.base_range.to_string(),
+                    ]
+                })
+                .collect(),
+        }
+    }
+
+    fn equipment_table(&self) -> Table {
+        Table {
+            headers: &["Item", "Quantity", "Weight", "Equipped", "Attuned"],
+            rows: self
+                .equipment
+                .iter()
+                .map(|entry| {
+                    vec![
+                        entry.item.name.clone(),
+                        entry.quantity.to_string(),
+                        (entry.item.weight * entry.quantity as f64).to_string(),
+                        entry.slot.map(|slot| slot.to_string()).unwrap_or_default(),
+                        if entry.attuned { "Yes" } else { "" }.to_string(),
+                    ]
+                })
+                .collect(),
+        }
+    }
+
+    fn total_weight(&self) -> f64 {
+        self.equipment.iter().fold(0.0, |total, entry| {
+            total + entry.item.weight * entry.quantity as f64
+        })
+    }
+
+    fn write_markdown(&self, out: &mut String) -> fmt::Result {
+        writeln!(out, "# {}", self.name)?;
+        writeln!(out)?;
+        writeln!(out, "{}  ", self.summary())?;
+        writeln!(out, "Played by {}", self.player_name)?;
+        writeln!(out)?;
+
+        writeln!(out, "## Attributes")?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "Prime {:+} · Combat Mastery {:+}",
+            self.prime, self.combat_mastery
+        )?;
+        writeln!(out)?;
+        self.attributes_table().write_markdown(out)?;
+
+        writeln!(out, "## Defenses")?;
+        writeln!(out)?;
+        self.defenses_table().write_markdown(out)?;
+        writeln!(out, "Speed: {} Spaces  ", self.speed)?;
+        writeln!(out, "Conditions: {}", self.conditions_line())?;
+        writeln!(out)?;
+
+        writeln!(out, "## Resources")?;
+        writeln!(out)?;
+        self.resources_table().write_markdown(out)?;
+
+        writeln!(out, "## Skills")?;
+        writeln!(out)?;
+        self.skills_table().write_markdown(out)?;
+        writeln!(out, "Languages: {}  ", list(&self.languages))?;
+        writeln!(out, "Trades: {}", list(&self.trades))?;
+        writeln!(out)?;
+
+        writeln!(out, "## Attacks")?;
+        writeln!(out)?;
+        self.attacks_table().write_markdown(out)?;
+
+        writeln!(out, "## Maneuvers")?;
+        writeln!(out)?;
+        if self.maneuvers.is_empty() {
+            writeln!(out, "None")?;
+        }
+        for maneuver in &self.maneuvers {
+            writeln!(
+                out,
+                "- **{}** ({}, {} AP, {} SP): {}",
+                maneuver.name,
+                maneuver.kind,
+                maneuver.action_point_cost,
+                maneuver.stamina_point_cost,
+                maneuver.description
+            )?;
+        }
+        writeln!(out)?;
+
+        writeln!(out, "## Spells")?;
+        writeln!(out)?;
+        if self.spells.is_empty() {
+            writeln!(out, "None")?;
+            writeln!(out)?;
+        }
+        for spell in &self.spells {
+            writeln!(out, "### {} ({})", spell.name, spell.school.name())?;
+            writeln!(out)?;
+            writeln!(out, "{}", spell_details(spell))?;
+            writeln!(out)?;
+            writeln!(out, "{}", spell.description)?;
+            writeln!(out)?;
+            for enhancement in &spell.point_enhancements {
+                writeln!(
+                    out,
+                    "- **{}** ({} AP, {} MP): {}",
+                    enhancement.name,
+                    enhancement.action_point_cost,
+                    enhancement.mana_point_cost,
+                    enhancement.description
+                )?;
+            }
+            if !spell.point_enhancements.is_empty() {
+                writeln!(out)?;
+            }
+        }
+
+        writeln!(out, "## Equipment")?;
+        writeln!(out)?;
+        self.equipment_table().write_markdown(out)?;
+        writeln!(out, "Total weight: {}", self.total_weight())
+    }
+
+    fn write_html(&self, out: &mut String) -> fmt::Result {
+        writeln!(out, "<!DOCTYPE html>")?;
+        writeln!(out, "<html lang=\"en\">")?;
+        writeln!(out, "<head>")?;
+        writeln!(out, "<meta charset=\"utf-8\">")?;
+        writeln!(out, "<title>{}</title>", escape(&self.name))?;
+        writeln!(out, "<style>{STYLE}</style>")?;
+        writeln!(out, "</head>")?;
+        writeln!(out, "<body>")?;
+
+        writeln!(out, "<header>")?;
+        writeln!(out, "<h1>{}</h1>", escape(&self.name))?;
+        writeln!(out, "<p>{}<br>", escape(&self.summary()))?;
+        writeln!(out, "Played by {}</p>", escape(&self.player_name))?;
+        writeln!(out, "</header>")?;
+
+        writeln!(out, "<div class=\"columns\">")?;
+        writeln!(out, "<section>")?;
+        writeln!(out, "<h2>Attributes</h2>")?;
+        writeln!(
+            out,
+            "<p>Prime {:+} · Combat Mastery {:+}</p>",
+            self.prime, self.combat_mastery
+        )?;
+        self.attributes_table().write_html(out)?;
+        writeln!(out, "</section>")?;
+
+        writeln!(out, "<section>")?;
+        writeln!(out, "<h2>Defenses</h2>")?;
+        self.defenses_table().write_html(out)?;
+        writeln!(
+            out,
+            "<p>Speed: {} Spaces<br>Conditions: {}</p>",
+            self.speed,
+            escape(&self.conditions_line())
+        )?;
+        writeln!(out, "</section>")?;
+
+        writeln!(out, "<section>")?;
+        writeln!(out, "<h2>Resources</h2>")?;
+        self.resources_table().write_html(out)?;
+        writeln!(out, "</section>")?;
+
+        writeln!(out, "<section>")?;
+        writeln!(out, "<h2>Skills</h2>")?;
+        self.skills_table().write_html(out)?;
+        writeln!(
+            out,
+            "<p>Languages: {}<br>Trades: {}</p>",
+            escape(&list(&self.languages)),
+            escape(&list(&self.trades))
+        )?;
+        writeln!(out, "</section>")?;
+        writeln!(out, "</div>")?;
+
+        writeln!(out, "<section>")?;
+        writeln!(out, "<h2>Attacks</h2>")?;
+        self.attacks_table().write_html(out)?;
+        writeln!(out, "</section>")?;
+
+        writeln!(out, "<section>")?;
+        writeln!(out, "<h2>Maneuvers</h2>")?;
+        if self.maneuvers.is_empty() {
+            writeln!(out, "<p>None</p>")?;
+        } else {
+            writeln!(out, "<ul>")?;
+            for maneuver in &self.maneuvers {
+                writeln!(
+                    out,
+                    "<li><strong>{}</strong> ({}, {} AP, {} SP): {}</li>",
+                    escape(&maneuver.name),
+                    maneuver.kind,
+                    maneuver.action_point_cost,
+                    maneuver.stamina_point_cost,
+                    escape(&maneuver.description)
+                )?;
+            }
+            writeln!(out, "</ul>")?;
+        }
+        writeln!(out, "</section>")?;
+
+        writeln!(out, "<section>")?;
+        writeln!(out, "<h2>Spells</h2>")?;
+        if self.spells.is_empty() {
+            writeln!(out, "<p>None</p>")?;
+        }
+        for spell in &self.spells {
+            writeln!(out, "<article class=\"spell\">")?;
+            writeln!(
+                out,
+                "<h3>{} ({})</h3>",
+                escape(&spell.name),
+                escape(spell.school.name())
+            )?;
+            writeln!(
+                out,
+                "<p class=\"details\">{}</p>",
+                escape(&spell_details(spell))
+            )?;
+            writeln!(out, "<p>{}</p>", escape(&spell.description))?;
+            if !spell.point_enhancements.is_empty() {
+                writeln!(out, "<ul>")?;
+                for enhancement in &spell.point_enhancements {
+                    writeln!(
+                        out,
+                        "<li><strong>{}</strong> ({} AP, {} MP): {}</li>",
+                        escape(&enhancement.name),
+                        enhancement.action_point_cost,
+                        enhancement.mana_point_cost,
+                        escape(&enhancement.description)
+                    )?;
+                }
+                writeln!(out, "</ul>")?;
+            }
+            writeln!(out, "</article>")?;
+        }
+        writeln!(out, "</section>")?;
+
+        writeln!(out, "<section>")?;
+        writeln!(out, "<h2>Equipment</h2>")?;
+        self.equipment_table().write_html(out)?;
+        writeln!(out, "<p>Total weight: {}</p>", self.total_weight())?;
+        writeln!(out, "</section>")?;
+
+        writeln!(out, "</body>")?;
+        writeln!(out, "</html>")
+    }
+}
+
+/// Sized to fit a sheet on as few printed pages as it can.
+const STYLE: &str = "
+body { font-family: Georgia, serif; color: #111; max-width: 60rem; margin: 2rem auto; }
+h1 { margin-bottom: 0.25rem; }
+h2 { border-bottom: 2px solid #111; margin-bottom: 0.5rem; }
+h3 { margin: 0 0 0.25rem; }
+table { border-collapse: collapse; width: 100%; }
+th, td { border: 1px solid #999; padding: 0.2rem 0.4rem; text-align: left; }
+th { background: #eee; }
+.columns { display: grid; grid-template-columns: 1fr 1fr; gap: 0 1.5rem; }
+.spell { border: 1px solid #999; padding: 0.5rem; margin-bottom: 0.5rem; }
+.details { font-style: italic; }
+@page { size: letter; margin: 1.5cm; }
+@media print {
+  body { font-size: 10pt; margin: 0; max-width: none; }
+  section, .spell { break-inside: avoid; }
+  th { background: none; }
+}
+";
+
+struct Table {
+    headers: &'static [&'static str],
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    fn write_markdown(&self, out: &mut String) -> fmt::Result {
+        if self.rows.is_empty() {
+            writeln!(out, "None")?;
+            return writeln!(out);
+        }
+
+        writeln!(out, "| {} |", self.headers.join(" | "))?;
+        writeln!(out, "|{}", " --- |".repeat(self.headers.len()))?;
+        for row in &self.rows {
+            let cells: Vec<String> = row.iter().map(|cell| cell.replace('|', "\\|")).collect();
+            writeln!(out, "| {} |", cells.join(" | "))?;
+        }
+
+        writeln!(out)
+    }
+
+    fn write_html(&self, out: &mut String) -> fmt::Result {
+        if self.rows.is_empty() {
+            return writeln!(out, "<p>None</p>");
+        }
+
+        writeln!(out, "<table>")?;
+        write!(out, "<tr>")?;
+        for header in self.headers {
+            write!(out, "<th>{header}</th>")?;
+        }
+        writeln!(out, "</tr>")?;
+        for row in &self.rows {
+            write!(out, "<tr>")?;
+            for cell in row {
+                write!(out, "<td>{}</td>", escape(cell))?;
+            }
+            writeln!(out, "</tr>")?;
+        }
+
+        writeln!(out, "</table>")
+    }
+}
+
+/// "1 AP, 0 MP · Range 10 Spaces · Instant · Verbal, Somatic"
+fn spell_details(spell: &Spell) -> String {
+    let components: Vec<&str> = [
+        (spell.has_verbal, "Verbal"),
+        (spell.has_somatic, "Somatic"),
+        (spell.has_material, "Material"),
+    ]
+    .into_iter()
+    .filter_map(|(has, component)| has.then_some(component))
+    .collect();
+
+    let mut details = format!(
+        "{} AP, {} MP · Range {} · {}",
+        spell.action_point_cost, spell.mana_point_cost, spell.range, spell.duration
+    );
+    if spell.sustained {
+        details.push_str(" (Sustained)");
+    }
+    if !components.is_empty() {
+        let _ = write!(details, " · {}", components.join(", "));
+    }
+
+    details
+}
+
+fn names<T>(rows: Vec<T>, name: impl Fn(T) -> String) -> Vec<String> {
+    rows.into_iter().map(name).collect()
+}
+
+fn list(items: &[String]) -> String {
+    if items.is_empty() {
+        "None".into()
+    } else {
+        items.join(", ")
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _html_is_escaped() {
+        assert_eq!(
+            escape(r#"<b>"Tom" & 'Jerry'</b>"#),
+            "&lt;b&gt;&quot;Tom&quot; &amp; &#39;Jerry&#39;&lt;/b&gt;"
+        );
+    }
+}
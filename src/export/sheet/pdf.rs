@@ -0,0 +1,431 @@
+//! The sheet as a PDF laid out after the official DC20 one, with a card for
+//! each Spell on the pages after it.
+
+use printpdf::{
+    path::PaintMode, BuiltinFont, Color, Greyscale, IndirectFontRef, Line, Mm, PdfDocument,
+    PdfDocumentReference, PdfLayerReference, Point, Rect,
+};
+
+use super::{list, spell_details, CharacterSheet, Table};
+use crate::dc20::Spell;
+
+/// US Letter, like the official sheet. Everything is measured in mm from
+/// the top left, and only flipped when it's drawn.
+const PAGE_WIDTH: f32 = 215.9;
+const PAGE_HEIGHT: f32 = 279.4;
+const MARGIN: f32 = 15.0;
+const CONTENT_WIDTH: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+
+const PT: f32 = 0.3528;
+/// The builtin fonts don't come with metrics, so text is wrapped by
+/// counting characters about half an em wide.
+const CHAR_WIDTH: f32 = 0.5;
+
+const CARD_COLUMNS: usize = 2;
+const CARD_ROWS: usize = 2;
+const CARD_GAP: f32 = 6.0;
+
+impl CharacterSheet {
+    /// A printable PDF: the sheet itself, then the Spell cards four to a page.
+    pub fn to_pdf(&self) -> Result<Vec<u8>, printpdf::Error> {
+        let (doc, page, layer) =
+            PdfDocument::new(&self.name, Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Sheet");
+        let layer = doc.get_page(page).get_layer(layer);
+        let mut pen = Pen {
+            regular: doc.add_builtin_font(BuiltinFont::Helvetica)?,
+            bold: doc.add_builtin_font(BuiltinFont::HelveticaBold)?,
+            doc: &doc,
+            layer,
+            y: MARGIN,
+        };
+
+        self.draw_sheet(&mut pen);
+
+        let card_width =
+            (CONTENT_WIDTH - CARD_GAP * (CARD_COLUMNS - 1) as f32) / CARD_COLUMNS as f32;
+        let card_height =
+            (PAGE_HEIGHT - 2.0 * MARGIN - CARD_GAP * (CARD_ROWS - 1) as f32) / CARD_ROWS as f32;
+        for (i, spell) in self.spells.iter().enumerate() {
+            let slot = i % (CARD_COLUMNS * CARD_ROWS);
+            if slot == 0 {
+                pen.new_page("Spells");
+            }
+            let x = MARGIN + (slot % CARD_COLUMNS) as f32 * (card_width + CARD_GAP);
+            let y = MARGIN + (slot / CARD_COLUMNS) as f32 * (card_height + CARD_GAP);
+            pen.spell_card(spell, x, y, card_width, card_height);
+        }
+
+        doc.save_to_bytes()
+    }
+
+    fn draw_sheet(&self, pen: &mut Pen) {
+        pen.text(&self.name, 20.0, MARGIN, pen.y, true);
+        pen.text(
+            &format!("Player: {}", self.player_name),
+            9.0,
+            PAGE_WIDTH - MARGIN - 60.0,
+            pen.y + 2.0,
+            false,
+        );
+        pen.y += 9.0;
+        pen.text(&self.summary(), 10.0, MARGIN, pen.y, false);
+        pen.y += 7.0;
+
+        // The Attribute row along the top, Prime first
+        let width = CONTENT_WIDTH / 5.0;
+        pen.stat_box(
+            MARGIN,
+            pen.y,
+            width,
+            "Prime",
+            &format!("{:+}", self.prime),
+            "",
+        );
+        for (i, attribute) in self.attributes.iter().enumerate() {
+            let mastery = if attribute.save_mastery { " (M)" } else { "" };
+            pen.stat_box(
+                MARGIN + (i + 1) as f32 * width,
+                pen.y,
+                width,
+                &attribute.name.to_string(),
+                &format!("{:+}", attribute.score),
+                &format!("Save {:+}{mastery}", attribute.save),
+            );
+        }
+        pen.y += STAT_BOX_HEIGHT + 3.0;
+
+        // Then the numbers that matter most in a fight
+        let resources = &self.resources;
+        let pd = &self.precision_defense;
+        let ad = &self.area_defense;
+        let boxes = [
+            (
+                "Combat Mastery",
+                format!("{:+}", self.combat_mastery),
+                String::new(),
+            ),
+            (
+                "Precision Def.",
+                pd.score.to_string(),
+                format!("{} / {} / DR {}", pd.heavy(), pd.brutal(), pd.reduction),
+            ),
+            (
+                "Area Def.",
+                ad.score.to_string(),
+                format!("{} / {} / DR {}", ad.heavy(), ad.brutal(), ad.reduction),
+            ),
+            ("Speed", self.speed.to_string(), "Spaces".into()),
+            (
+                "Hit Points",
+                resources.hit_points.current.to_string(),
+                format!("of {}", resources.hit_points.maximum),
+            ),
+            (
+                "Stamina",
+                resources.stamina_points.current.to_string(),
+                format!("of {}", resources.stamina_points.maximum),
+            ),
+            (
+                "Mana",
+                resources.mana_points.current.to_string(),
+                format!("of {}", resources.mana_points.maximum),
+            ),
+        ];
+        let width = CONTENT_WIDTH / boxes.len() as f32;
+        for (i, (label, value, note)) in boxes.iter().enumerate() {
+            pen.stat_box(MARGIN + i as f32 * width, pen.y, width, label, value, note);
+        }
+        pen.y += STAT_BOX_HEIGHT + 3.0;
+        pen.paragraph(&format!("Conditions: {}", self.conditions_line()), 9.0);
+
+        pen.heading("Skills");
+        pen.table(&self.skills_table());
+        pen.paragraph(&format!("Languages: {}", list(&self.languages)), 9.0);
+        pen.paragraph(&format!("Trades: {}", list(&self.trades)), 9.0);
+
+        pen.heading("Attacks");
+        pen.table(&self.attacks_table());
+
+        pen.heading("Maneuvers");
+        if self.maneuvers.is_empty() {
+            pen.paragraph("None", 9.0);
+        }
+        for maneuver in &self.maneuvers {
+            pen.paragraph(
+                &format!(
+                    "{} ({}, {} AP, {} SP): {}",
+                    maneuver.name,
+                    maneuver.kind,
+                    maneuver.action_point_cost,
+                    maneuver.stamina_point_cost,
+                    maneuver.description
+                ),
+                9.0,
+            );
+        }
+
+        pen.heading("Equipment");
+        pen.table(&self.equipment_table());
+        pen.paragraph(&format!("Total weight: {}", self.total_weight()), 9.0);
+    }
+}
+
+const STAT_BOX_HEIGHT: f32 = 20.0;
+const ROW_HEIGHT: f32 = 5.0;
+const TABLE_TEXT: f32 = 8.5;
+
+/// Draws onto the current page, moving down it as it goes and onto a new
+/// one when it runs out of room.
+struct Pen<'a> {
+    doc: &'a PdfDocumentReference,
+    layer: PdfLayerReference,
+    regular: IndirectFontRef,
+    bold: IndirectFontRef,
+    /// How far down the page the next thing goes.
+    y: f32,
+}
+
+impl Pen<'_> {
+    fn new_page(&mut self, name: &str) {
+        let (page, layer) = self.doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), name);
+        self.layer = self.doc.get_page(page).get_layer(layer);
+        self.y = MARGIN;
+    }
+
+    /// Starts a new page unless `height` more fits on this one.
+    fn make_room(&mut self, height: f32) {
+        if self.y + height > PAGE_HEIGHT - MARGIN {
+            self.new_page("Sheet");
+        }
+    }
+
+    /// Writes a line of text with its top at `y`.
+    fn text(&self, text: &str, size: f32, x: f32, y: f32, bold: bool) {
+        let font = if bold { &self.bold } else { &self.regular };
+        self.layer
+            .use_text(text, size, Mm(x), Mm(PAGE_HEIGHT - y - size * PT), font);
+    }
+
+    fn line(&self, x1: f32, x2: f32, y: f32) {
+        self.layer.add_line(Line {
+            points: vec![
+                (Point::new(Mm(x1), Mm(PAGE_HEIGHT - y)), false),
+                (Point::new(Mm(x2), Mm(PAGE_HEIGHT - y)), false),
+            ],
+            is_closed: false,
+        });
+    }
+
+    fn rect(&self, x: f32, y: f32, width: f32, height: f32, mode: PaintMode) {
+        self.layer.add_rect(
+            Rect::new(
+                Mm(x),
+                Mm(PAGE_HEIGHT - y - height),
+                Mm(x + width),
+                Mm(PAGE_HEIGHT - y),
+            )
+            .with_mode(mode),
+        );
+    }
+
+    /// A grey band behind headers, drawn before the text that goes on it.
+    fn band(&self, x: f32, y: f32, width: f32, height: f32) {
+        self.layer
+            .set_fill_color(Color::Greyscale(Greyscale::new(0.85, None)));
+        self.rect(x, y, width, height, PaintMode::Fill);
+        self.layer
+            .set_fill_color(Color::Greyscale(Greyscale::new(0.0, None)));
+    }
+
+    /// A labelled box with one big number in it, like the official sheet's.
+    fn stat_box(&self, x: f32, y: f32, width: f32, label: &str, value: &str, note: &str) {
+        let width = width - 1.0;
+        self.band(x, y, width, 5.0);
+        self.rect(x, y, width, STAT_BOX_HEIGHT, PaintMode::Stroke);
+        self.text(&fit(label, width - 2.0, 7.0), 7.0, x + 1.0, y + 1.2, true);
+        self.text(value, 16.0, x + 2.0, y + 6.5, true);
+        if !note.is_empty() {
+            self.text(&fit(note, width - 2.0, 6.5), 6.5, x + 1.0, y + 15.5, false);
+        }
+    }
+
+    fn heading(&mut self, text: &str) {
+        self.make_room(12.0);
+        self.y += 3.0;
+        self.text(text, 12.0, MARGIN, self.y, true);
+        self.y += 5.5;
+        self.line(MARGIN, PAGE_WIDTH - MARGIN, self.y);
+        self.y += 1.5;
+    }
+
+    fn paragraph(&mut self, text: &str, size: f32) {
+        for line in wrap(text, CONTENT_WIDTH, size) {
+            self.make_room(size * PT * 1.4);
+            self.text(&line, size, MARGIN, self.y, false);
+            self.y += size * PT * 1.4;
+        }
+    }
+
+    /// Evenly sized columns, cut short rather than wrapped.
+    fn table(&mut self, table: &Table) {
+        if table.rows.is_empty() {
+            self.paragraph("None", 9.0);
+            return;
+        }
+
+        self.make_room(2.0 * ROW_HEIGHT);
+        self.band(MARGIN, self.y, CONTENT_WIDTH, ROW_HEIGHT);
+        self.row(table.headers, true);
+        for row in &table.rows {
+            if self.y + ROW_HEIGHT > PAGE_HEIGHT - MARGIN {
+                self.new_page("Sheet");
+                self.band(MARGIN, self.y, CONTENT_WIDTH, ROW_HEIGHT);
+                self.row(table.headers, true);
+            }
+            self.row(row, false);
+            self.line(MARGIN, PAGE_WIDTH - MARGIN, self.y);
+        }
+        self.y += 2.0;
+    }
+
+    fn row(&mut self, cells: &[impl AsRef<str>], bold: bool) {
+        let width = CONTENT_WIDTH / cells.len() as f32;
+        for (i, cell) in cells.iter().enumerate() {
+            let x = MARGIN + i as f32 * width;
+            let text = fit(cell.as_ref(), width - 2.0, TABLE_TEXT);
+            self.text(&text, TABLE_TEXT, x + 1.0, self.y + 1.2, bold);
+        }
+        self.y += ROW_HEIGHT;
+    }
+
+    /// One Spell, boxed up to be cut out. Whatever doesn't fit on the card
+    /// is cut short.
+    fn spell_card(&self, spell: &Spell, x: f32, y: f32, width: f32, height: f32) {
+        let inner = width - 4.0;
+        let bottom = y + height - 3.0;
+
+        self.rect(x, y, width, height, PaintMode::Stroke);
+        self.band(x, y, width, 12.0);
+        self.text(&fit(&spell.name, inner, 12.0), 12.0, x + 2.0, y + 1.5, true);
+        self.text(
+            &fit(spell.school.name(), inner, 8.0),
+            8.0,
+            x + 2.0,
+            y + 7.5,
+            false,
+        );
+
+        let mut lines: Vec<(String, f32, bool)> = vec![];
+        for detail in spell_details(spell).split(" · ") {
+            lines.push((detail.to_string(), 8.0, true));
+        }
+        lines.push((String::new(), 8.0, false));
+        for line in wrap(&spell.description, inner, 8.0) {
+            lines.push((line, 8.0, false));
+        }
+        for enhancement in &spell.point_enhancements {
+            lines.push((String::new(), 8.0, false));
+            lines.push((
+                format!(
+                    "{} ({} AP, {} MP)",
+                    enhancement.name, enhancement.action_point_cost, enhancement.mana_point_cost
+                ),
+                8.0,
+                true,
+            ));
+            for line in wrap(&enhancement.description, inner, 8.0) {
+                lines.push((line, 8.0, false));
+            }
+        }
+
+        let mut line_y = y + 14.0;
+        for (i, (text, size, bold)) in lines.iter().enumerate() {
+            let height = size * PT * 1.4;
+            if line_y + 2.0 * height > bottom && i + 1 < lines.len() {
+                self.text("...", *size, x + 2.0, line_y, false);
+                break;
+            }
+            self.text(text, *size, x + 2.0, line_y, *bold);
+            line_y += height;
+        }
+    }
+}
+
+/// How many characters of `size` pt text fit across `width` mm.
+fn chars_across(width: f32, size: f32) -> usize {
+    (width / (size * PT * CHAR_WIDTH)).max(1.0) as usize
+}
+
+/// Cuts `text` short with "..." when it won't fit across `width` mm.
+fn fit(text: &str, width: f32, size: f32) -> String {
+    let max = chars_across(width, size);
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+
+    let mut fitted: String = text.chars().take(max.saturating_sub(3)).collect();
+    fitted.push_str("...");
+
+    fitted
+}
+
+/// Breaks `text` into lines that fit across `width` mm, splitting words
+/// only when a single one is too long for a line.
+fn wrap(text: &str, width: f32, size: f32) -> Vec<String> {
+    let max = chars_across(width, size);
+    let mut lines = vec![];
+
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let mut word: Vec<char> = word.chars().collect();
+            while word.len() > max {
+                if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+                lines.push(word.drain(..max).collect());
+            }
+
+            let length = line.chars().count();
+            if length > 0 && length + 1 + word.len() > max {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.extend(word);
+        }
+        lines.push(line);
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _text_is_wrapped_at_word_boundaries() {
+        // 10 characters across
+        let width = 10.5 * 10.0 * PT * CHAR_WIDTH;
+
+        assert_eq!(
+            wrap("Hurl a mote of fire at the target", width, 10.0),
+            vec!["Hurl a", "mote of", "fire at", "the target"]
+        );
+        assert_eq!(
+            wrap("Incandescence!", width, 10.0),
+            vec!["Incandesce", "nce!"]
+        );
+        assert_eq!(wrap("One\n\nTwo", width, 10.0), vec!["One", "", "Two"]);
+    }
+
+    #[test]
+    fn _text_that_does_not_fit_is_cut_short() {
+        let width = 10.5 * 10.0 * PT * CHAR_WIDTH;
+
+        assert_eq!(fit("Longsword", width, 10.0), "Longsword");
+        assert_eq!(fit("Greatsword of Dawn", width, 10.0), "Greatsw...");
+    }
+}
@@ -0,0 +1,568 @@
+//! An append-only history of each Character, so a wrecked sheet can be put
+//! back the way it was.
+//!
+//! Every [`Revision`] is a whole [`CharacterExport`] of the Character as they
+//! were just after a change, along with who made it and a note on what it
+//! was. Changes are never stored themselves: [`diff`] works them out between
+//! any two snapshots, and restoring a Revision saves a new one on top rather
+//! than rewriting what came after it.
+
+use std::{collections::BTreeSet, error::Error, fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::{
+    db::Db,
+    dc20::Character,
+    export::json::{self, CharacterExport, Ids},
+};
+
+/// The note on Revisions saved for changes made without one being recorded,
+/// found just before the next change is.
+pub const UNTRACKED: &str = "Untracked changes";
+
+/// The Character as they were after one change.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Revision {
+    pub id: Uuid,
+    pub character_id: Uuid,
+    /// The Player who made the change, when known.
+    pub author: Option<Uuid>,
+    pub note: String,
+    pub snapshot: CharacterExport,
+}
+
+impl Revision {
+    /// When the Revision was saved, taken from its v7 id.
+    #[must_use]
+    pub fn recorded_at(&self) -> Option<DateTime<Utc>> {
+        let (secs, nsecs) = self.id.get_timestamp()?.to_unix();
+
+        DateTime::from_timestamp(secs as i64, nsecs)
+    }
+
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: Uuid,
+    ) -> crate::Result<Revision> {
+        let row = sqlx::query!(
+            r#"
+                SELECT `character_revision_id` AS "id: Uuid"
+                    , `character_id` AS "character_id: Uuid"
+                    , `player_id` AS "author: Uuid"
+                    , `note`
+                    , `snapshot`
+                FROM `character_revisions`
+                WHERE `character_revision_id` = ?1
+                LIMIT 1
+                ;
+            "#,
+            id
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(Revision {
+            id: row.id,
+            character_id: row.character_id,
+            author: row.author,
+            note: row.note,
+            snapshot: CharacterExport::parse(&row.snapshot)
+                .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+        })
+    }
+
+    /// Every Revision of the Character, oldest first.
+    pub async fn load_all(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        character_id: Uuid,
+    ) -> crate::Result<Vec<Revision>> {
+        let rows = sqlx::query!(
+            r#"
+                SELECT `character_revision_id` AS "id: Uuid"
+                    , `character_id` AS "character_id: Uuid"
+                    , `player_id` AS "author: Uuid"
+                    , `note`
+                    , `snapshot`
+                FROM `character_revisions`
+                WHERE `character_id` = ?1
+                ORDER BY `character_revision_id`
+                ;
+            "#,
+            character_id
+        )
+        .fetch_all(executor)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(Revision {
+                    id: row.id,
+                    character_id: row.character_id,
+                    author: row.author,
+                    note: row.note,
+                    snapshot: CharacterExport::parse(&row.snapshot)
+                        .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+                })
+            })
+            .collect()
+    }
+
+    /// The Character's newest Revision, if they have one.
+    pub async fn load_latest(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        character_id: Uuid,
+    ) -> crate::Result<Option<Revision>> {
+        let row = sqlx::query!(
+            r#"
+                SELECT `character_revision_id` AS "id: Uuid"
+                    , `character_id` AS "character_id: Uuid"
+                    , `player_id` AS "author: Uuid"
+                    , `note`
+                    , `snapshot`
+                FROM `character_revisions`
+                WHERE `character_id` = ?1
+                ORDER BY `character_revision_id` DESC
+                LIMIT 1
+                ;
+            "#,
+            character_id
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        row.map(|row| {
+            Ok(Revision {
+                id: row.id,
+                character_id: row.character_id,
+                author: row.author,
+                note: row.note,
+                snapshot: CharacterExport::parse(&row.snapshot)
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            })
+        })
+        .transpose()
+    }
+
+    /// The Revision the Character was at when `point` was, if they had one yet.
+    pub async fn load_at(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        character_id: Uuid,
+        point: Point,
+    ) -> crate::Result<Option<Revision>> {
+        let revisions = Revision::load_all(executor, character_id).await?;
+
+        let revision = match point {
+            Point::Revision(id) => {
+                let Some(revision) = revisions.into_iter().find(|revision| revision.id == id)
+                else {
+                    return Err(HistoryError::UnknownRevision { character_id, id }.into());
+                };
+                Some(revision)
+            }
+            Point::At(at) => revisions
+                .into_iter()
+                .take_while(|revision| revision.recorded_at().is_some_and(|time| time <= at))
+                .last(),
+        };
+
+        Ok(revision)
+    }
+
+    pub(crate) async fn save(&self, executor: impl sqlx::SqliteExecutor<'_>) -> crate::Result<()> {
+        let snapshot = self
+            .snapshot
+            .to_json()
+            .map_err(|e| sqlx::Error::Encode(Box::new(e)))?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `character_revisions`
+                    ( `character_revision_id`
+                    , `character_id`
+                    , `player_id`
+                    , `note`
+                    , `snapshot`
+                    )
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ;
+            "#,
+            self.id,
+            self.character_id,
+            self.author,
+            self.note,
+            snapshot
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+}
+
+/// A moment in a Character's history: a Revision, or a time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Point {
+    Revision(Uuid),
+    At(DateTime<Utc>),
+}
+
+impl FromStr for Point {
+    type Err = HistoryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(id) = s.parse() {
+            return Ok(Point::Revision(id));
+        }
+
+        DateTime::parse_from_rfc3339(s)
+            .map(|at| Point::At(at.to_utc()))
+            .map_err(|_| HistoryError::InvalidPoint(s.into()))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HistoryError {
+    /// Neither a Revision id nor an RFC 3339 time.
+    InvalidPoint(String),
+    UnknownRevision {
+        character_id: Uuid,
+        id: Uuid,
+    },
+    /// The Character had no Revision yet at the time asked about.
+    NothingBefore(DateTime<Utc>),
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoryError::InvalidPoint(point) => {
+                write!(f, "`{point}` is neither a revision id nor an RFC 3339 time")
+            }
+            HistoryError::UnknownRevision { character_id, id } => {
+                write!(f, "Character `{character_id}` has no revision `{id}`")
+            }
+            HistoryError::NothingBefore(at) => {
+                write!(f, "the Character has no revision from before {at}")
+            }
+        }
+    }
+}
+
+impl Error for HistoryError {}
+
+/// Saves the Character as they are now, unless nothing changed since their
+/// latest Revision. Returns the new Revision.
+pub async fn record(
+    db: impl Db<'_>,
+    character_id: Uuid,
+    author: Option<Uuid>,
+    note: &str,
+) -> crate::Result<Option<Revision>> {
+    let mut conn = db.connection().await?;
+
+    let snapshot = json::export(&mut *conn, character_id).await?;
+
+    let latest = Revision::load_latest(&mut *conn, character_id).await?;
+    if latest.is_some_and(|latest| latest.snapshot == snapshot) {
+        return Ok(None);
+    }
+
+    let revision = Revision {
+        id: Uuid::now_v7(),
+        character_id,
+        author,
+        note: note.into(),
+        snapshot,
+    };
+    revision.save(&mut *conn).await?;
+
+    Ok(Some(revision))
+}
+
+/// Records anything changed since the latest Revision as [`UNTRACKED`], so
+/// the change about to be made has a before to compare against.
+pub async fn checkpoint(db: impl Db<'_>, character_id: Uuid) -> crate::Result<()> {
+    record(db, character_id, None, UNTRACKED).await?;
+
+    Ok(())
+}
+
+/// Puts the Character back as they were at the Revision. What they were
+/// before is kept as a Revision of its own, so a restore can be undone too.
+pub async fn restore(
+    db: impl Db<'_>,
+    character_id: Uuid,
+    revision_id: Uuid,
+    author: Option<Uuid>,
+) -> crate::Result<Character> {
+    let mut conn = db.connection().await?;
+
+    let revision = Revision::load(&mut *conn, revision_id).await?;
+    if revision.character_id != character_id {
+        return Err(HistoryError::UnknownRevision {
+            character_id,
+            id: revision_id,
+        }
+        .into());
+    }
+
+    let mut tx = conn.begin().await?;
+
+    checkpoint(&mut *tx, character_id).await?;
+    let character = json::restore(&mut *tx, revision.snapshot, Ids::Overwrite).await?;
+    record(
+        &mut *tx,
+        character_id,
+        author,
+        &format!("Restored revision {revision_id}"),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(character)
+}
+
+/// One line of a Character's history: who changed what, and when.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Entry {
+    pub revision: Uuid,
+    pub recorded_at: Option<DateTime<Utc>>,
+    pub author: Option<Uuid>,
+    pub note: String,
+    /// Against the Revision before; everything is new in the first one.
+    pub changes: Vec<Change>,
+}
+
+/// The Character's whole history, oldest first.
+pub async fn log(
+    executor: impl sqlx::SqliteExecutor<'_>,
+    character_id: Uuid,
+) -> crate::Result<Vec<Entry>> {
+    let revisions = Revision::load_all(executor, character_id).await?;
+
+    let mut entries = vec![];
+    let mut previous: Option<&Revision> = None;
+    for revision in &revisions {
+        let changes = match previous {
+            Some(previous) => diff(&previous.snapshot, &revision.snapshot),
+            None => {
+                let mut changes = vec![];
+                let after = serde_json::to_value(&revision.snapshot)
+                    .map_err(|e| sqlx::Error::Encode(Box::new(e)))?;
+                diff_values(
+                    "",
+                    Some(&Value::Object(Default::default())),
+                    Some(&after),
+                    &mut changes,
+                );
+                changes
+            }
+        };
+
+        entries.push(Entry {
+            revision: revision.id,
+            recorded_at: revision.recorded_at(),
+            author: revision.author,
+            note: revision.note.clone(),
+            changes,
+        });
+        previous = Some(revision);
+    }
+
+    Ok(entries)
+}
+
+/// Works out what changed in the Character between two points, the second
+/// being now when left out.
+pub async fn diff_between(
+    db: impl Db<'_>,
+    character_id: Uuid,
+    from: Point,
+    to: Option<Point>,
+) -> crate::Result<Vec<Change>> {
+    let mut conn = db.connection().await?;
+
+    let before = snapshot_at(&mut *conn, character_id, from).await?;
+    let after = match to {
+        Some(to) => snapshot_at(&mut *conn, character_id, to).await?,
+        None => json::export(&mut *conn, character_id).await?,
+    };
+
+    Ok(diff(&before, &after))
+}
+
+async fn snapshot_at(
+    executor: impl sqlx::SqliteExecutor<'_>,
+    character_id: Uuid,
+    point: Point,
+) -> crate::Result<CharacterExport> {
+    match Revision::load_at(executor, character_id, point).await? {
+        Some(revision) => Ok(revision.snapshot),
+        None => match point {
+            Point::At(at) => Err(HistoryError::NothingBefore(at).into()),
+            Point::Revision(id) => Err(HistoryError::UnknownRevision { character_id, id }.into()),
+        },
+    }
+}
+
+/// One value that differs between two snapshots. `path` is dotted, with
+/// list entries that have ids picked out by them, as in
+/// `levels[<id>].attributes.might`. A missing `before` means the value was
+/// added and a missing `after` that it was removed.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Change {
+    pub path: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |value: &Option<Value>| match value {
+            Some(value) => value.to_string(),
+            None => "nothing".into(),
+        };
+
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.path,
+            show(&self.before),
+            show(&self.after)
+        )
+    }
+}
+
+/// Every value that differs between the two snapshots.
+#[must_use]
+pub fn diff(before: &CharacterExport, after: &CharacterExport) -> Vec<Change> {
+    let before = serde_json::to_value(before).unwrap_or_default();
+    let after = serde_json::to_value(after).unwrap_or_default();
+
+    let mut changes = vec![];
+    diff_values("", Some(&before), Some(&after), &mut changes);
+
+    changes
+}
+
+fn diff_values(
+    path: &str,
+    before: Option<&Value>,
+    after: Option<&Value>,
+    changes: &mut Vec<Change>,
+) {
+    match (before, after) {
+        (Some(Value::Object(before)), Some(Value::Object(after))) => {
+            let keys: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+            for key in keys {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                diff_values(&path, before.get(key), after.get(key), changes);
+            }
+        }
+        (Some(Value::Array(before)), Some(Value::Array(after)))
+            if before
+                .iter()
+                .chain(after)
+                .all(|value| id_of(value).is_some()) =>
+        {
+            let mut ids: Vec<&str> = before.iter().filter_map(id_of).collect();
+            for id in after.iter().filter_map(id_of) {
+                if !ids.contains(&id) {
+                    ids.push(id);
+                }
+            }
+
+            for id in ids {
+                diff_values(
+                    &format!("{path}[{id}]"),
+                    find_by_id(before, id),
+                    find_by_id(after, id),
+                    changes,
+                );
+            }
+        }
+        (before, after) if before != after => changes.push(Change {
+            path: path.into(),
+            before: before.cloned(),
+            after: after.cloned(),
+        }),
+        _ => {}
+    }
+}
+
+fn id_of(value: &Value) -> Option<&str> {
+    value.get("id")?.as_str()
+}
+
+fn find_by_id<'a>(values: &'a [Value], id: &str) -> Option<&'a Value> {
+    values.iter().find(|value| id_of(value) == Some(id))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn _lists_of_things_with_ids_are_compared_by_id() {
+        let before = json!({
+            "name": "Cygnus",
+            "levels": [{ "id": "a", "level": 1 }, { "id": "b", "level": 2 }],
+            "conditions": ["Dazed"]
+        });
+        let after = json!({
+            "name": "Cygnus",
+            "levels": [{ "id": "b", "level": 2 }, { "id": "c", "level": 3 }],
+            "conditions": []
+        });
+
+        let mut changes = vec![];
+        diff_values("", Some(&before), Some(&after), &mut changes);
+
+        assert_eq!(
+            changes,
+            [
+                Change {
+                    path: "conditions".into(),
+                    before: Some(json!(["Dazed"])),
+                    after: Some(json!([])),
+                },
+                Change {
+                    path: "levels[a]".into(),
+                    before: Some(json!({ "id": "a", "level": 1 })),
+                    after: None,
+                },
+                Change {
+                    path: "levels[c]".into(),
+                    before: None,
+                    after: Some(json!({ "id": "c", "level": 3 })),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn _points_are_revision_ids_or_times() {
+        let id = Uuid::now_v7();
+        assert_eq!(id.to_string().parse(), Ok(Point::Revision(id)));
+        assert!(matches!(
+            "2026-10-19T20:00:00Z".parse::<Point>(),
+            Ok(Point::At(_))
+        ));
+        assert_eq!(
+            "yesterday".parse::<Point>(),
+            Err(HistoryError::InvalidPoint("yesterday".into()))
+        );
+    }
+}
@@ -0,0 +1,964 @@
+use std::{collections::HashMap, error::Error, fmt, fmt::Write, str::FromStr};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use turann::Builder;
+use uuid::Uuid;
+
+use crate::{campaign::Campaign, db::Db, dc20::MAX_LEVEL};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExperienceTableError {
+    /// There are only so many Levels past the first to ask Experience for.
+    TooManyLevels(usize),
+    /// Each Level has to ask for more Experience than the one before.
+    NotIncreasing { level: u32, experience: u64 },
+}
+
+impl fmt::Display for ExperienceTableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExperienceTableError::TooManyLevels(count) => write!(
+                f,
+                "{count} thresholds given, but only Levels 2 to {MAX_LEVEL} can have one"
+            ),
+            ExperienceTableError::NotIncreasing { level, experience } => write!(
+                f,
+                "Level {level} asks for {experience} Experience, which is not more than the Level before"
+            ),
+        }
+    }
+}
+
+impl Error for ExperienceTableError {}
+
+/// The Experience a Campaign asks for each Level past the first. DC20
+/// levels by Milestone, so the default asks for none and only Milestones
+/// earn Levels.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "Vec<u64>", into = "Vec<u64>")]
+pub struct ExperienceTable(Vec<u64>);
+
+impl TryFrom<Vec<u64>> for ExperienceTable {
+    type Error = ExperienceTableError;
+
+    /// Takes the thresholds for Level 2 onwards, each higher than the last.
+    fn try_from(thresholds: Vec<u64>) -> Result<Self, Self::Error> {
+        if thresholds.len() >= MAX_LEVEL as usize {
+            return Err(ExperienceTableError::TooManyLevels(thresholds.len()));
+        }
+
+        let mut previous = 0;
+        for (level, experience) in (2..).zip(&thresholds) {
+            if *experience <= previous {
+                return Err(ExperienceTableError::NotIncreasing {
+                    level,
+                    experience: *experience,
+                });
+            }
+            previous = *experience;
+        }
+
+        Ok(ExperienceTable(thresholds))
+    }
+}
+
+impl From<ExperienceTable> for Vec<u64> {
+    fn from(table: ExperienceTable) -> Self {
+        table.0
+    }
+}
+
+impl ExperienceTable {
+    #[must_use]
+    pub fn thresholds(&self) -> &[u64] {
+        &self.0
+    }
+
+    /// The highest Level `experience` is enough for.
+    #[must_use]
+    pub fn level_for(&self, experience: u64) -> u32 {
+        1 + self
+            .0
+            .iter()
+            .take_while(|threshold| **threshold <= experience)
+            .count() as u32
+    }
+
+    /// The Level Experience and Milestones add up to. Each Milestone is
+    /// worth one Level on top of what the Experience is worth.
+    #[must_use]
+    pub fn earned_level(&self, experience: u64, milestones: u32) -> u32 {
+        (self.level_for(experience) + milestones).min(MAX_LEVEL)
+    }
+
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        campaign_id: Uuid,
+    ) -> crate::Result<ExperienceTable> {
+        let thresholds = sqlx::query_scalar!(
+            r#"
+                SELECT `experience`
+                FROM `campaign_experience_thresholds`
+                WHERE `campaign_id` = ?1
+                ORDER BY `level`
+                ;
+            "#,
+            campaign_id
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(ExperienceTable(
+            thresholds.into_iter().map(|t| t as u64).collect(),
+        ))
+    }
+
+    /// Replaces the Campaign's thresholds and marks the Level each of its
+    /// Characters has earned under them.
+    pub async fn save(&self, db: impl Db<'_>, campaign_id: Uuid) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+
+        let mut tx = conn.begin().await?;
+
+        sqlx::query!(
+            r#"
+                DELETE FROM `campaign_experience_thresholds`
+                WHERE `campaign_id` = ?1
+                ;
+            "#,
+            campaign_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for (level, experience) in (2_i64..).zip(&self.0) {
+            let experience = *experience as i64;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO `campaign_experience_thresholds`
+                        (`campaign_id`, `level`, `experience`)
+                    VALUES (?1, ?2, ?3)
+                    ;
+                "#,
+                campaign_id,
+                level,
+                experience
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        Progress::mark_earned_levels(&mut *tx, campaign_id).await?;
+
+        Ok(tx.commit().await?)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Award {
+    Experience(u64),
+    /// A story beat worth a whole Level, described by its note.
+    Milestone,
+}
+
+#[derive(Clone, Debug)]
+pub struct AwardParseError(pub String);
+
+impl fmt::Display for AwardParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unable to parse Award `{}`", self.0)
+    }
+}
+
+impl Error for AwardParseError {}
+
+impl Award {
+    fn parse(kind: &str, experience: i64) -> Result<Self, AwardParseError> {
+        match kind {
+            "Experience" => Ok(Award::Experience(experience as u64)),
+            "Milestone" => Ok(Award::Milestone),
+            _ => Err(AwardParseError(kind.into())),
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Award::Experience(_) => "Experience",
+            Award::Milestone => "Milestone",
+        }
+    }
+}
+
+/// An Award given to one of the Characters at a Session.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CharacterAward {
+    pub character_id: Uuid,
+    pub award: Award,
+    #[serde(default)]
+    pub note: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Loot {
+    pub description: String,
+    pub quantity: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct LootParseError(pub String);
+
+impl fmt::Display for LootParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unable to parse Loot `{}`", self.0)
+    }
+}
+
+impl Error for LootParseError {}
+
+impl FromStr for Loot {
+    type Err = LootParseError;
+
+    /// Reads `"2 Healing Potion"` as two, and anything without a leading
+    /// quantity as one. There is no such thing as none of something.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        let (quantity, description) = match s.split_once(' ') {
+            Some((quantity, description)) if quantity.parse::<u64>().is_ok() => {
+                (quantity.parse().unwrap_or(1), description.trim())
+            }
+            _ => (1, s),
+        };
+
+        if quantity == 0 || description.is_empty() {
+            return Err(LootParseError(s.into()));
+        }
+
+        Ok(Loot {
+            description: description.into(),
+            quantity,
+        })
+    }
+}
+
+impl SessionBuilder {
+    fn today() -> NaiveDate {
+        chrono::Local::now().date_naive()
+    }
+}
+
+/// One night of play in a Campaign's journal.
+#[derive(Builder, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Session {
+    #[builder(default = Uuid::now_v7)]
+    pub id: Uuid,
+    pub campaign_id: Uuid,
+    #[builder(default = SessionBuilder::today)]
+    pub played_on: NaiveDate,
+    #[builder(default)]
+    #[serde(default)]
+    pub title: String,
+    #[builder(default)]
+    #[serde(default)]
+    pub notes: String,
+    /// The ids of the Characters who were there.
+    #[builder(each = "attendee")]
+    #[serde(default)]
+    pub attendees: Vec<Uuid>,
+    #[builder(each = "loot_item")]
+    #[serde(default)]
+    pub loot: Vec<Loot>,
+    #[builder(each = "award")]
+    #[serde(default)]
+    pub awards: Vec<CharacterAward>,
+}
+
+impl Session {
+    /// When the Session was written down, taken from its v7 id.
+    #[must_use]
+    pub fn recorded_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let (secs, nsecs) = self.id.get_timestamp()?.to_unix();
+
+        chrono::DateTime::from_timestamp(secs as i64, nsecs)
+    }
+
+    pub async fn load(db: impl Db<'_>, id: Uuid) -> crate::Result<Session> {
+        let mut conn = db.connection().await?;
+
+        let row = sqlx::query!(
+            r#"
+                SELECT `session_id` AS "id: Uuid"
+                    , `campaign_id` AS "campaign_id: Uuid"
+                    , `played_on` AS "played_on: NaiveDate"
+                    , `title`
+                    , `notes`
+                FROM `campaign_sessions`
+                WHERE `session_id` = ?1
+                LIMIT 1
+                ;
+            "#,
+            id
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        let attendees = sqlx::query_scalar!(
+            r#"
+                SELECT `character_id` AS "id: Uuid"
+                FROM `campaign_sessions_characters`
+                WHERE `session_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let loot = sqlx::query!(
+            r#"
+                SELECT `description`
+                    , `quantity`
+                FROM `session_loot`
+                WHERE `session_id` = ?1
+                ORDER BY `position`
+                ;
+            "#,
+            id
+        )
+        .fetch_all(&mut *conn)
+        .await?
+        .into_iter()
+        .map(|row| Loot {
+            description: row.description,
+            quantity: row.quantity as u64,
+        })
+        .collect();
+
+        let awards = sqlx::query!(
+            r#"
+                SELECT `character_id` AS "character_id: Uuid"
+                    , `kind`
+                    , `experience`
+                    , `note`
+                FROM `session_awards`
+                WHERE `session_id` = ?1
+                ORDER BY `position`
+                ;
+            "#,
+            id
+        )
+        .fetch_all(&mut *conn)
+        .await?
+        .into_iter()
+        .map(|row| {
+            Ok(CharacterAward {
+                character_id: row.character_id,
+                award: Award::parse(&row.kind, row.experience)
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+                note: row.note,
+            })
+        })
+        .collect::<crate::Result<_>>()?;
+
+        Ok(Session {
+            id: row.id,
+            campaign_id: row.campaign_id,
+            played_on: row.played_on,
+            title: row.title,
+            notes: row.notes,
+            attendees,
+            loot,
+            awards,
+        })
+    }
+
+    /// Every Session played in the Campaign, oldest first.
+    pub async fn list(db: impl Db<'_>, campaign_id: Uuid) -> crate::Result<Vec<Session>> {
+        let mut conn = db.connection().await?;
+
+        let ids = sqlx::query_scalar!(
+            r#"
+                SELECT `session_id` AS "id: Uuid"
+                FROM `campaign_sessions`
+                WHERE `campaign_id` = ?1
+                ORDER BY `played_on`, `session_id`
+                ;
+            "#,
+            campaign_id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut sessions = vec![];
+        for id in ids {
+            sessions.push(Session::load(&mut *conn, id).await?);
+        }
+
+        Ok(sessions)
+    }
+
+    /// Saves the Session, replacing its attendees, loot and awards.
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+
+        let mut tx = conn.begin().await?;
+
+        let Session {
+            id,
+            campaign_id,
+            played_on,
+            title,
+            notes,
+            attendees,
+            loot,
+            awards,
+        } = self;
+
+        // Moving the Session takes its Awards out of the Campaign it was in
+        let previous_campaign_id = sqlx::query_scalar!(
+            r#"
+                SELECT `campaign_id` AS "campaign_id: Uuid"
+                FROM `campaign_sessions`
+                WHERE `session_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `campaign_sessions`
+                    (`session_id`, `campaign_id`, `played_on`, `title`, `notes`)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ON CONFLICT (`session_id`) DO UPDATE
+                    SET `campaign_id` = ?2
+                    , `played_on` = ?3
+                    , `title` = ?4
+                    , `notes` = ?5
+                ;
+            "#,
+            id,
+            campaign_id,
+            played_on,
+            title,
+            notes
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+                DELETE FROM `campaign_sessions_characters`
+                WHERE `session_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for character_id in attendees {
+            sqlx::query!(
+                r#"
+                    INSERT INTO `campaign_sessions_characters` (`session_id`, `character_id`)
+                    VALUES (?1, ?2)
+                    ON CONFLICT DO NOTHING
+                    ;
+                "#,
+                id,
+                character_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        sqlx::query!(
+            r#"
+                DELETE FROM `session_loot`
+                WHERE `session_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for (
+            position,
+            Loot {
+                description,
+                quantity,
+            },
+        ) in loot.into_iter().enumerate()
+        {
+            let (position, quantity) = (position as i64, quantity as i64);
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO `session_loot`
+                        (`session_id`, `position`, `description`, `quantity`)
+                    VALUES (?1, ?2, ?3, ?4)
+                    ;
+                "#,
+                id,
+                position,
+                description,
+                quantity
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        sqlx::query!(
+            r#"
+                DELETE FROM `session_awards`
+                WHERE `session_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for (position, award) in awards.into_iter().enumerate() {
+            let position = position as i64;
+            let kind = award.award.kind();
+            let experience = match award.award {
+                Award::Experience(experience) => experience as i64,
+                Award::Milestone => 0,
+            };
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO `session_awards`
+                        (`session_id`, `position`, `character_id`, `kind`, `experience`, `note`)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                    ;
+                "#,
+                id,
+                position,
+                award.character_id,
+                kind,
+                experience,
+                award.note
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        Progress::mark_earned_levels(&mut *tx, campaign_id).await?;
+        if let Some(previous_campaign_id) =
+            previous_campaign_id.filter(|previous| *previous != campaign_id)
+        {
+            Progress::mark_earned_levels(&mut *tx, previous_campaign_id).await?;
+        }
+
+        Ok(tx.commit().await?)
+    }
+
+    /// Deletes the Session along with the Awards given at it.
+    pub async fn delete(db: impl Db<'_>, id: Uuid) -> crate::Result<bool> {
+        let mut conn = db.connection().await?;
+
+        let mut tx = conn.begin().await?;
+
+        let campaign_id = sqlx::query_scalar!(
+            r#"
+                DELETE FROM `campaign_sessions`
+                WHERE `session_id` = ?1
+                RETURNING `campaign_id` AS "campaign_id: Uuid"
+                ;
+            "#,
+            id
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(campaign_id) = campaign_id else {
+            return Ok(false);
+        };
+        Progress::mark_earned_levels(&mut *tx, campaign_id).await?;
+
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    /// Writes the Session as a Markdown section, naming Characters from
+    /// `names` and falling back to their ids.
+    pub fn write_markdown(&self, out: &mut String, names: &HashMap<Uuid, String>) -> fmt::Result {
+        let name = |id: &Uuid| names.get(id).cloned().unwrap_or_else(|| id.to_string());
+
+        write!(out, "## {}", self.played_on.format("%Y-%m-%d"))?;
+        if !self.title.is_empty() {
+            write!(out, ": {}", self.title)?;
+        }
+        writeln!(out)?;
+        writeln!(out)?;
+
+        if !self.attendees.is_empty() {
+            let attendees: Vec<String> = self.attendees.iter().map(name).collect();
+            writeln!(out, "**Attendees:** {}", attendees.join(", "))?;
+            writeln!(out)?;
+        }
+
+        if !self.notes.is_empty() {
+            writeln!(out, "{}", self.notes.trim_end())?;
+            writeln!(out)?;
+        }
+
+        if !self.loot.is_empty() {
+            writeln!(out, "### Loot")?;
+            writeln!(out)?;
+            for loot in &self.loot {
+                writeln!(out, "- {} × {}", loot.quantity, loot.description)?;
+            }
+            writeln!(out)?;
+        }
+
+        if !self.awards.is_empty() {
+            writeln!(out, "### Awards")?;
+            writeln!(out)?;
+            for award in &self.awards {
+                write!(out, "- {}: ", name(&award.character_id))?;
+                match award.award {
+                    Award::Experience(experience) => write!(out, "{experience} XP")?,
+                    Award::Milestone => write!(out, "Milestone")?,
+                }
+                if !award.note.is_empty() {
+                    write!(out, " ({})", award.note)?;
+                }
+                writeln!(out)?;
+            }
+            writeln!(out)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Everything a Campaign's table has written down.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Journal {
+    pub campaign: Campaign,
+    pub sessions: Vec<Session>,
+    /// The names of every Character the Sessions mention.
+    pub character_names: HashMap<Uuid, String>,
+}
+
+impl Journal {
+    pub async fn load(db: impl Db<'_>, campaign_id: Uuid) -> crate::Result<Journal> {
+        let mut conn = db.connection().await?;
+
+        let campaign = Campaign::load(&mut *conn, campaign_id).await?;
+        let sessions = Session::list(&mut *conn, campaign_id).await?;
+
+        let rows = sqlx::query!(
+            r#"
+                SELECT `c`.`character_id` AS "id!: Uuid"
+                    , `c`.`name`
+                FROM `characters` AS `c`
+                WHERE `c`.`character_id` IN (
+                    SELECT `scs`.`character_id`
+                    FROM `campaign_sessions_characters` AS `scs`
+                    JOIN `campaign_sessions` AS `s`
+                        ON `s`.`session_id` = `scs`.`session_id`
+                    WHERE `s`.`campaign_id` = ?1
+                    UNION
+                    SELECT `sa`.`character_id`
+                    FROM `session_awards` AS `sa`
+                    JOIN `campaign_sessions` AS `s`
+                        ON `s`.`session_id` = `sa`.`session_id`
+                    WHERE `s`.`campaign_id` = ?1
+                )
+                ;
+            "#,
+            campaign_id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        Ok(Journal {
+            campaign,
+            sessions,
+            character_names: rows.into_iter().map(|row| (row.id, row.name)).collect(),
+        })
+    }
+
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# {} Journal\n\n", self.campaign.name());
+        out.push_str(&format!(
+            "Game Master: {}\n\n",
+            self.campaign.game_master().name()
+        ));
+
+        for session in &self.sessions {
+            // Writing to a String can't fail
+            let _ = session.write_markdown(&mut out, &self.character_names);
+        }
+
+        out.truncate(out.trim_end().len());
+        out.push('\n');
+
+        out
+    }
+}
+
+/// How far a Character has come in a Campaign, and whether they've
+/// earned another Level there.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Progress {
+    pub character_id: Uuid,
+    pub campaign_id: Uuid,
+    /// The highest Level they've taken.
+    pub level: u32,
+    pub experience: u64,
+    pub milestones: u32,
+    /// The Level their Awards in the Campaign add up to, as marked when
+    /// they were last given or the Campaign's thresholds last changed.
+    pub earned_level: u32,
+}
+
+impl Progress {
+    #[must_use]
+    pub fn is_eligible_for_level_up(&self) -> bool {
+        self.earned_level > self.level
+    }
+
+    /// Adds up the Awards the Character has been given in the Campaign.
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        campaign_id: Uuid,
+        character_id: Uuid,
+    ) -> crate::Result<Progress> {
+        let row = sqlx::query!(
+            r#"
+                SELECT `cc`.`earned_level` AS "earned_level: u32"
+                    , (
+                        SELECT MAX(`level`)
+                        FROM `character_levels`
+                        WHERE `character_id` = ?2
+                    ) AS "level: u32"
+                    , (
+                        SELECT TOTAL(`sa`.`experience`)
+                        FROM `session_awards` AS `sa`
+                        JOIN `campaign_sessions` AS `s`
+                            ON `s`.`session_id` = `sa`.`session_id`
+                        WHERE `s`.`campaign_id` = ?1
+                            AND `sa`.`character_id` = ?2
+                    ) AS "experience!: f64"
+                    , (
+                        SELECT COUNT(*)
+                        FROM `session_awards` AS `sa`
+                        JOIN `campaign_sessions` AS `s`
+                            ON `s`.`session_id` = `sa`.`session_id`
+                        WHERE `s`.`campaign_id` = ?1
+                            AND `sa`.`character_id` = ?2
+                            AND `sa`.`kind` = 'Milestone'
+                    ) AS "milestones!: u32"
+                FROM `campaigns_characters` AS `cc`
+                WHERE `cc`.`campaign_id` = ?1
+                    AND `cc`.`character_id` = ?2
+                ;
+            "#,
+            campaign_id,
+            character_id
+        )
+        .fetch_optional(executor)
+        .await?
+        .ok_or_else(|| {
+            crate::Error::not_found(format!(
+                "Character `{character_id}` in Campaign `{campaign_id}`"
+            ))
+        })?;
+
+        Ok(Progress {
+            character_id,
+            campaign_id,
+            level: row.level.ok_or(sqlx::Error::RowNotFound)?,
+            experience: row.experience as u64,
+            milestones: row.milestones,
+            earned_level: row.earned_level,
+        })
+    }
+
+    /// Marks the Level every Character in the Campaign has earned with the
+    /// Awards given there, under the Campaign's thresholds.
+    pub async fn mark_earned_levels(db: impl Db<'_>, campaign_id: Uuid) -> crate::Result<()> {
+        let mut conn = db.connection().await?;
+
+        let mut tx = conn.begin().await?;
+
+        let table = ExperienceTable::load(&mut *tx, campaign_id).await?;
+
+        let rows = sqlx::query!(
+            r#"
+                SELECT `cc`.`character_id` AS "character_id: Uuid"
+                    , (
+                        SELECT TOTAL(`sa`.`experience`)
+                        FROM `session_awards` AS `sa`
+                        JOIN `campaign_sessions` AS `s`
+                            ON `s`.`session_id` = `sa`.`session_id`
+                        WHERE `s`.`campaign_id` = ?1
+                            AND `sa`.`character_id` = `cc`.`character_id`
+                    ) AS "experience!: f64"
+                    , (
+                        SELECT COUNT(*)
+                        FROM `session_awards` AS `sa`
+                        JOIN `campaign_sessions` AS `s`
+                            ON `s`.`session_id` = `sa`.`session_id`
+                        WHERE `s`.`campaign_id` = ?1
+                            AND `sa`.`character_id` = `cc`.`character_id`
+                            AND `sa`.`kind` = 'Milestone'
+                    ) AS "milestones!: u32"
+                FROM `campaigns_characters` AS `cc`
+                WHERE `cc`.`campaign_id` = ?1
+                ;
+            "#,
+            campaign_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for row in rows {
+            let earned_level = table.earned_level(row.experience as u64, row.milestones);
+
+            sqlx::query!(
+                r#"
+                    UPDATE `campaigns_characters`
+                    SET `earned_level` = ?3
+                    WHERE `campaign_id` = ?1
+                        AND `character_id` = ?2
+                    ;
+                "#,
+                campaign_id,
+                row.character_id,
+                earned_level
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        Ok(tx.commit().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _experience_and_milestones_earn_levels() {
+        let table = ExperienceTable::try_from(vec![300, 900, 2_700]).unwrap();
+        assert_eq!(table.level_for(0), 1);
+        assert_eq!(table.level_for(899), 2);
+        assert_eq!(table.level_for(900), 3);
+        assert_eq!(table.level_for(1_000_000), 4);
+        assert_eq!(table.earned_level(300, 1), 3);
+        assert_eq!(table.earned_level(1_000_000, 20), MAX_LEVEL);
+
+        // DC20 levels by Milestone alone
+        let milestones = ExperienceTable::default();
+        assert_eq!(milestones.earned_level(1_000_000, 0), 1);
+        assert_eq!(milestones.earned_level(0, 2), 3);
+
+        let progress = Progress {
+            level: 2,
+            earned_level: 3,
+            ..Default::default()
+        };
+        assert!(progress.is_eligible_for_level_up());
+
+        let caught_up = Progress {
+            level: 3,
+            ..progress
+        };
+        assert!(!caught_up.is_eligible_for_level_up());
+    }
+
+    #[test]
+    fn _thresholds_must_rise_level_by_level() {
+        assert!(matches!(
+            ExperienceTable::try_from(vec![300, 300]),
+            Err(ExperienceTableError::NotIncreasing { level: 3, .. })
+        ));
+        assert!(matches!(
+            ExperienceTable::try_from(vec![0]),
+            Err(ExperienceTableError::NotIncreasing { level: 2, .. })
+        ));
+        assert!(matches!(
+            ExperienceTable::try_from((1..=MAX_LEVEL as u64).collect::<Vec<_>>()),
+            Err(ExperienceTableError::TooManyLevels(_))
+        ));
+        assert!(serde_json::from_str::<ExperienceTable>("[900, 300]").is_err());
+    }
+
+    #[test]
+    fn _loot_needs_a_quantity_of_at_least_one() {
+        let potions: Loot = "2 Healing Potion".parse().unwrap();
+        assert_eq!(
+            (potions.quantity, potions.description.as_str()),
+            (2, "Healing Potion")
+        );
+
+        let rope: Loot = "Rope".parse().unwrap();
+        assert_eq!(rope.quantity, 1);
+
+        assert!("0 x".parse::<Loot>().is_err());
+        assert!("".parse::<Loot>().is_err());
+    }
+
+    #[test]
+    fn _session_is_written_as_markdown() {
+        let cygnus = Uuid::now_v7();
+        let session = Session {
+            id: Uuid::now_v7(),
+            campaign_id: Uuid::now_v7(),
+            played_on: NaiveDate::from_ymd_opt(2026, 10, 17).unwrap(),
+            title: "The Drowned Vault".into(),
+            notes: "The party found the vault flooded.\n".into(),
+            attendees: vec![cygnus],
+            loot: vec!["2 Healing Potion".parse().unwrap()],
+            awards: vec![
+                CharacterAward {
+                    character_id: cygnus,
+                    award: Award::Experience(300),
+                    note: String::new(),
+                },
+                CharacterAward {
+                    character_id: cygnus,
+                    award: Award::Milestone,
+                    note: "Opened the vault".into(),
+                },
+            ],
+        };
+        let names = HashMap::from([(cygnus, "Cygnus".to_string())]);
+
+        let mut out = String::new();
+        session.write_markdown(&mut out, &names).unwrap();
+
+        assert_eq!(
+            out,
+            "## 2026-10-17: The Drowned Vault\n\
+             \n\
+             **Attendees:** Cygnus\n\
+             \n\
+             The party found the vault flooded.\n\
+             \n\
+             ### Loot\n\
+             \n\
+             - 2 × Healing Potion\n\
+             \n\
+             ### Awards\n\
+             \n\
+             - Cygnus: 300 XP\n\
+             - Cygnus: Milestone (Opened the vault)\n\
+             \n"
+        );
+    }
+}
@@ -1,3 +1,17 @@
+#[cfg(feature = "api")]
+pub mod api;
+pub mod campaign;
+pub mod check;
+pub mod db;
 pub mod dc20;
+mod error;
+pub mod export;
+pub mod history;
+pub mod journal;
+pub mod pack;
 pub mod player;
+pub mod progression;
+pub mod repository;
 pub mod utils;
+
+pub use error::{Error, Result};
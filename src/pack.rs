@@ -0,0 +1,1150 @@
+//! Content packs: homebrew and supplements shared as files rather than SQL.
+//!
+//! A pack is a directory, or a `.tar` or `.tar.gz` archive of one, holding
+//! a manifest and any number of content files, each TOML or JSON:
+//!
+//! ```toml
+//! # manifest.toml
+//! id = "0199f5c2-…"
+//! name = "Tome of Tides"
+//! version = "1.2.0"
+//! author = "Spencer Dent"
+//! description = "Spells of the deep."
+//!
+//! # spells.toml
+//! [[spells]]
+//! id = "0199f5c3-…"
+//! name = "Riptide"
+//! school = { id = "0199f5c4-…", name = "Hydromancy" }
+//! …
+//!
+//! [[spell_lists]]
+//! id = "0199f5c5-…"
+//! name = "Tidecaller"
+//! spells = ["0199f5c3-…"]
+//! ```
+//!
+//! Content files may hold any of the lists in [`PackContent`]; lists found
+//! in several files are joined in file name order. Files with other
+//! extensions, like a README, are ignored.
+//!
+//! Installing a pack saves its content with the ids it was given and
+//! records that the pack owns it. Installing the same pack again updates
+//! what it owns and removes what a newer version dropped, so it is safe to
+//! repeat. Content the pack doesn't own, whether from another pack or from
+//! elsewhere, is never overwritten: every such id is reported as a
+//! [`Conflict`] and nothing is installed. Uninstalling deletes exactly what
+//! the pack owns.
+//!
+//! Each pack is also a [`Source`] with the pack's id and name, which its
+//! content comes from unless it names another Source that already exists.
+//!
+//! A Campaign only sees content from the packs enabled in it; content that
+//! no pack owns is seen by every Campaign.
+
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt::{self, Display},
+    io::Read,
+    path::Path,
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    db::Db,
+    dc20::{Ancestry, AncestryTrait, Background, Class, Source, Spell, SpellList},
+};
+
+/// Who made a pack and which version of it this is.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Manifest {
+    pub id: Uuid,
+    pub name: String,
+    pub version: String,
+    pub author: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// An Ancestry with the Traits it offers.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PackAncestry {
+    #[serde(flatten)]
+    pub ancestry: Ancestry,
+    #[serde(default)]
+    pub traits: Vec<Uuid>,
+}
+
+/// A Spell List naming its Spells by id.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PackSpellList {
+    pub id: Uuid,
+    pub name: String,
+    #[serde(default)]
+    pub spells: Vec<Uuid>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PackWeaponProperty {
+    pub id: Uuid,
+    pub name: String,
+    pub description: String,
+    #[serde(default = "PackWeaponProperty::default_cost")]
+    pub cost: i64,
+    /// The property a Weapon must already have to take this one.
+    #[serde(default)]
+    pub requires: Option<Uuid>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<Uuid>,
+}
+
+impl PackWeaponProperty {
+    fn default_cost() -> i64 {
+        1
+    }
+}
+
+/// Everything a pack adds. Ids are required so the same pack installed in
+/// two databases shares content between them.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PackContent {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ancestry_traits: Vec<AncestryTrait>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ancestries: Vec<PackAncestry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub backgrounds: Vec<Background>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub classes: Vec<Class>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub spells: Vec<Spell>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub spell_lists: Vec<PackSpellList>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub weapon_properties: Vec<PackWeaponProperty>,
+}
+
+impl PackContent {
+    fn extend(&mut self, other: PackContent) {
+        self.ancestry_traits.extend(other.ancestry_traits);
+        self.ancestries.extend(other.ancestries);
+        self.backgrounds.extend(other.backgrounds);
+        self.classes.extend(other.classes);
+        self.spells.extend(other.spells);
+        self.spell_lists.extend(other.spell_lists);
+        self.weapon_properties.extend(other.weapon_properties);
+    }
+
+    /// Every piece of content, in the order it is installed.
+    #[must_use]
+    pub fn ids(&self) -> Vec<(ContentKind, Uuid)> {
+        let weapon_properties = self
+            .weapon_properties
+            .iter()
+            .map(|property| (ContentKind::WeaponProperty, property.id));
+        let ancestry_traits = self
+            .ancestry_traits
+            .iter()
+            .map(|ancestry_trait| (ContentKind::AncestryTrait, ancestry_trait.id));
+        let ancestries = self
+            .ancestries
+            .iter()
+            .map(|ancestry| (ContentKind::Ancestry, ancestry.ancestry.id));
+        let backgrounds = self
+            .backgrounds
+            .iter()
+            .map(|background| (ContentKind::Background, background.id));
+        let classes = self
+            .classes
+            .iter()
+            .map(|class| (ContentKind::Class, class.id));
+        let spells = self
+            .spells
+            .iter()
+            .map(|spell| (ContentKind::Spell, spell.id));
+        let spell_lists = self
+            .spell_lists
+            .iter()
+            .map(|spell_list| (ContentKind::SpellList, spell_list.id));
+
+        weapon_properties
+            .chain(ancestry_traits)
+            .chain(ancestries)
+            .chain(backgrounds)
+            .chain(classes)
+            .chain(spells)
+            .chain(spell_lists)
+            .collect()
+    }
+}
+
+/// The kinds of content a pack can own, in the order they are installed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentKind {
+    WeaponProperty,
+    AncestryTrait,
+    Ancestry,
+    Background,
+    Class,
+    Spell,
+    SpellList,
+}
+
+impl ContentKind {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ContentKind::WeaponProperty => "weapon_property",
+            ContentKind::AncestryTrait => "ancestry_trait",
+            ContentKind::Ancestry => "ancestry",
+            ContentKind::Background => "background",
+            ContentKind::Class => "class",
+            ContentKind::Spell => "spell",
+            ContentKind::SpellList => "spell_list",
+        }
+    }
+
+    /// The table holding this kind of content and its id column.
+    fn table(self) -> (&'static str, &'static str) {
+        match self {
+            ContentKind::WeaponProperty => ("weapon_properties", "weapon_property_id"),
+            ContentKind::AncestryTrait => ("ancestry_traits", "ancestry_trait_id"),
+            ContentKind::Ancestry => ("ancestries", "ancestry_id"),
+            ContentKind::Background => ("backgrounds", "background_id"),
+            ContentKind::Class => ("classes", "class_id"),
+            ContentKind::Spell => ("spells", "spell_id"),
+            ContentKind::SpellList => ("spell_lists", "spell_list_id"),
+        }
+    }
+}
+
+impl Display for ContentKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ContentKind {
+    type Err = PackError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            ContentKind::WeaponProperty,
+            ContentKind::AncestryTrait,
+            ContentKind::Ancestry,
+            ContentKind::Background,
+            ContentKind::Class,
+            ContentKind::Spell,
+            ContentKind::SpellList,
+        ]
+        .into_iter()
+        .find(|kind| kind.as_str() == s)
+        .ok_or_else(|| PackError::Invalid {
+            file: "database".into(),
+            message: format!("unknown content kind `{s}`"),
+        })
+    }
+}
+
+/// Content a pack would overwrite that it doesn't own.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Conflict {
+    pub kind: ContentKind,
+    pub id: Uuid,
+    /// The pack that owns it, or `None` for content from outside any pack.
+    pub owner: Option<Uuid>,
+}
+
+impl Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.owner {
+            Some(owner) => write!(f, "{} `{}` belongs to pack `{owner}`", self.kind, self.id),
+            None => write!(f, "{} `{}` already exists", self.kind, self.id),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PackError {
+    /// A file that couldn't be read or written.
+    Io {
+        file: String,
+        message: String,
+    },
+    MissingManifest,
+    /// A file that isn't a manifest or pack content.
+    Invalid {
+        file: String,
+        message: String,
+    },
+    /// The same content appears twice in the pack.
+    Duplicate {
+        kind: ContentKind,
+        id: Uuid,
+    },
+    Conflicts(Vec<Conflict>),
+}
+
+impl Display for PackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PackError::Io { file, message } => write!(f, "Unable to access `{file}`: {message}"),
+            PackError::MissingManifest => {
+                write!(f, "Content pack has no manifest.toml or manifest.json")
+            }
+            PackError::Invalid { file, message } => write!(f, "Invalid `{file}`: {message}"),
+            PackError::Duplicate { kind, id } => {
+                write!(f, "{kind} `{id}` appears more than once in the pack")
+            }
+            PackError::Conflicts(conflicts) => {
+                write!(f, "Content pack conflicts with existing content: ")?;
+                let conflicts = conflicts
+                    .iter()
+                    .map(Conflict::to_string)
+                    .collect::<Vec<_>>();
+                write!(f, "{}", conflicts.join("; "))
+            }
+        }
+    }
+}
+
+impl Error for PackError {}
+
+/// What installing a pack changed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Installed {
+    pub manifest: Manifest,
+    pub added: usize,
+    pub updated: usize,
+    /// Content an earlier version of the pack had that this one doesn't.
+    pub removed: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ContentPack {
+    pub manifest: Manifest,
+    #[serde(default)]
+    pub content: PackContent,
+}
+
+fn io_error(file: &Path, error: std::io::Error) -> PackError {
+    PackError::Io {
+        file: file.display().to_string(),
+        message: error.to_string(),
+    }
+}
+
+impl ContentPack {
+    /// Reads a pack from a directory or a `.tar` or `.tar.gz` archive.
+    pub fn read(path: &Path) -> Result<ContentPack, PackError> {
+        let mut files = vec![];
+
+        if path.is_dir() {
+            read_dir(path, &mut files)?;
+        } else {
+            let file = std::fs::File::open(path).map_err(|e| io_error(path, e))?;
+            let name = path.to_string_lossy();
+
+            if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+                read_archive(flate2::read::GzDecoder::new(file), path, &mut files)?;
+            } else if name.ends_with(".tar") {
+                read_archive(file, path, &mut files)?;
+            } else {
+                return Err(PackError::Invalid {
+                    file: name.into_owned(),
+                    message: "expected a directory, .tar or .tar.gz".into(),
+                });
+            }
+        }
+
+        Self::from_files(files)
+    }
+
+    /// Builds a pack from `(path, contents)` pairs, as read from disk.
+    pub fn from_files(mut files: Vec<(String, String)>) -> Result<ContentPack, PackError> {
+        files.sort();
+
+        let mut manifest = None;
+        let mut content = PackContent::default();
+
+        for (file, text) in files {
+            let name = file.rsplit('/').next().unwrap_or(&file);
+            let invalid = |message: String| PackError::Invalid {
+                file: file.clone(),
+                message,
+            };
+
+            match name {
+                "manifest.toml" | "manifest.json" if manifest.is_some() => {
+                    return Err(invalid("a pack has only one manifest".into()));
+                }
+                "manifest.toml" => {
+                    manifest = Some(toml::from_str(&text).map_err(|e| invalid(e.to_string()))?)
+                }
+                "manifest.json" => {
+                    manifest =
+                        Some(serde_json::from_str(&text).map_err(|e| invalid(e.to_string()))?)
+                }
+                _ if name.ends_with(".toml") => {
+                    content.extend(toml::from_str(&text).map_err(|e| invalid(e.to_string()))?)
+                }
+                _ if name.ends_with(".json") => {
+                    content.extend(serde_json::from_str(&text).map_err(|e| invalid(e.to_string()))?)
+                }
+                _ => {}
+            }
+        }
+
+        let pack = ContentPack {
+            manifest: manifest.ok_or(PackError::MissingManifest)?,
+            content,
+        };
+        pack.validate()?;
+
+        Ok(pack)
+    }
+
+    fn validate(&self) -> Result<(), PackError> {
+        let Manifest {
+            name,
+            version,
+            author,
+            ..
+        } = &self.manifest;
+        for (field, value) in [("name", name), ("version", version), ("author", author)] {
+            if value.is_empty() {
+                return Err(PackError::Invalid {
+                    file: "manifest".into(),
+                    message: format!("{field} cannot be empty"),
+                });
+            }
+        }
+
+        let mut seen = HashSet::new();
+        for (kind, id) in self.content.ids() {
+            if !seen.insert((kind, id)) {
+                return Err(PackError::Duplicate { kind, id });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes the pack as a directory of TOML files [`read`](Self::read)
+    /// can load again.
+    pub fn write(&self, dir: &Path) -> Result<(), PackError> {
+        let invalid = |e: toml::ser::Error| PackError::Invalid {
+            file: dir.display().to_string(),
+            message: e.to_string(),
+        };
+        let manifest = toml::to_string(&self.manifest).map_err(invalid)?;
+        let content = toml::to_string(&self.content).map_err(invalid)?;
+
+        std::fs::create_dir_all(dir).map_err(|e| io_error(dir, e))?;
+        for (name, text) in [("manifest.toml", manifest), ("content.toml", content)] {
+            let path = dir.join(name);
+            std::fs::write(&path, text).map_err(|e| io_error(&path, e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Loads an installed pack with all the content it owns.
+    pub async fn load(db: impl Db<'_>, id: Uuid) -> crate::Result<ContentPack> {
+        let mut conn = db.connection().await?;
+
+        let manifest = Manifest::load(&mut *conn, id).await?;
+        let mut content = PackContent::default();
+
+        for (kind, content_id) in owned(&mut *conn, id).await? {
+            match kind {
+                ContentKind::WeaponProperty => content
+                    .weapon_properties
+                    .push(load_weapon_property(&mut *conn, content_id).await?),
+                ContentKind::AncestryTrait => content
+                    .ancestry_traits
+                    .push(AncestryTrait::load(&mut *conn, content_id).await?),
+                ContentKind::Ancestry => {
+                    let traits = sqlx::query_scalar!(
+                        r#"
+                            SELECT `ancestry_trait_id` AS "id: Uuid"
+                            FROM `ancestries_ancestry_traits`
+                            WHERE `ancestry_id` = ?1
+                            ;
+                        "#,
+                        content_id
+                    )
+                    .fetch_all(&mut *conn)
+                    .await?;
+
+                    content.ancestries.push(PackAncestry {
+                        ancestry: Ancestry::load(&mut *conn, content_id).await?,
+                        traits,
+                    });
+                }
+                ContentKind::Background => content
+                    .backgrounds
+                    .push(Background::load(&mut *conn, content_id).await?),
+                ContentKind::Class => content
+                    .classes
+                    .push(Class::load(&mut *conn, content_id).await?),
+                ContentKind::Spell => content
+                    .spells
+                    .push(Spell::load(&mut *conn, content_id).await?),
+                ContentKind::SpellList => {
+                    let SpellList { id, name, spells } =
+                        SpellList::load(&mut *conn, content_id).await?;
+
+                    content.spell_lists.push(PackSpellList {
+                        id,
+                        name,
+                        spells: spells.into_iter().map(|spell| spell.id).collect(),
+                    });
+                }
+            }
+        }
+
+        // Content from the pack's own Source was written without one
+        let unsourced = |source: &mut Option<Uuid>| {
+            if *source == Some(id) {
+                *source = None;
+            }
+        };
+        content
+            .weapon_properties
+            .iter_mut()
+            .for_each(|property| unsourced(&mut property.source));
+        content
+            .ancestry_traits
+            .iter_mut()
+            .for_each(|ancestry_trait| unsourced(&mut ancestry_trait.source));
+        content
+            .backgrounds
+            .iter_mut()
+            .for_each(|background| unsourced(&mut background.source));
+        content
+            .classes
+            .iter_mut()
+            .for_each(|class| unsourced(&mut class.source));
+        content
+            .spells
+            .iter_mut()
+            .for_each(|spell| unsourced(&mut spell.source));
+
+        Ok(ContentPack { manifest, content })
+    }
+
+    /// Installs the pack, or brings an installed copy up to this version.
+    /// Nothing is saved when any content conflicts with content the pack
+    /// doesn't own.
+    pub async fn install(&self, db: impl Db<'_>) -> crate::Result<Installed> {
+        let mut conn = db.connection().await?;
+
+        self.validate()?;
+
+        let ContentPack { manifest, content } = self;
+        let pack_id = manifest.id;
+
+        let previously_owned: HashSet<_> = owned(&mut *conn, pack_id).await?.into_iter().collect();
+        let ids = content.ids();
+
+        let mut conflicts = vec![];
+        for &(kind, id) in &ids {
+            if previously_owned.contains(&(kind, id)) {
+                continue;
+            }
+
+            let owner = owner(&mut *conn, kind, id).await?;
+            if owner.is_some() || exists(&mut *conn, kind, id).await? {
+                conflicts.push(Conflict { kind, id, owner });
+            }
+        }
+        if !conflicts.is_empty() {
+            return Err(PackError::Conflicts(conflicts).into());
+        }
+
+        manifest.clone().save(&mut *conn).await?;
+        Source {
+            id: pack_id,
+            name: manifest.name.clone(),
+        }
+        .save(&mut *conn)
+        .await?;
+
+        for property in &content.weapon_properties {
+            let mut property = property.clone();
+            property.source.get_or_insert(pack_id);
+            save_weapon_property(&mut *conn, &property).await?;
+        }
+        // Requirements are set once every property exists to refer to
+        for property in &content.weapon_properties {
+            sqlx::query!(
+                r#"
+                    UPDATE `weapon_properties`
+                    SET `required_weapon_property_id` = ?2
+                    WHERE `weapon_property_id` = ?1
+                    ;
+                "#,
+                property.id,
+                property.requires
+            )
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        for ancestry_trait in &content.ancestry_traits {
+            let mut ancestry_trait = ancestry_trait.clone();
+            ancestry_trait.source.get_or_insert(pack_id);
+            ancestry_trait.save(&mut *conn).await?;
+        }
+
+        for PackAncestry { ancestry, traits } in &content.ancestries {
+            ancestry.clone().save(&mut *conn).await?;
+
+            sqlx::query!(
+                r#"
+                    DELETE FROM `ancestries_ancestry_traits`
+                    WHERE `ancestry_id` = ?1
+                    ;
+                "#,
+                ancestry.id
+            )
+            .execute(&mut *conn)
+            .await?;
+
+            for trait_id in traits {
+                sqlx::query!(
+                    r#"
+                        INSERT INTO `ancestries_ancestry_traits` (`ancestry_id`, `ancestry_trait_id`)
+                        VALUES (?1, ?2)
+                        ;
+                    "#,
+                    ancestry.id,
+                    trait_id
+                )
+                .execute(&mut *conn)
+                .await?;
+            }
+        }
+
+        for background in &content.backgrounds {
+            let mut background = background.clone();
+            background.source.get_or_insert(pack_id);
+            background.save(&mut *conn).await?;
+        }
+
+        for class in &content.classes {
+            let mut class = class.clone();
+            class.source.get_or_insert(pack_id);
+            class.save(&mut *conn).await?;
+        }
+
+        for spell in &content.spells {
+            let mut spell = spell.clone();
+            spell.source.get_or_insert(pack_id);
+            spell.save(&mut *conn).await?;
+        }
+
+        for PackSpellList { id, name, spells } in &content.spell_lists {
+            let mut list = SpellList {
+                id: *id,
+                name: name.clone(),
+                spells: vec![],
+            };
+            for spell_id in spells {
+                list.spells.push(Spell::load(&mut *conn, *spell_id).await?);
+            }
+
+            list.save(&mut *conn).await?;
+        }
+
+        for &(kind, id) in &ids {
+            let kind_name = kind.as_str();
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO `content_pack_contents` (`content_pack_id`, `kind`, `content_id`)
+                    VALUES (?1, ?2, ?3)
+                    ON CONFLICT DO NOTHING
+                    ;
+                "#,
+                pack_id,
+                kind_name,
+                id
+            )
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        let current: HashSet<_> = ids.iter().copied().collect();
+        let mut stale: Vec<_> = previously_owned.difference(&current).copied().collect();
+        // Lists before the Spells on them, and so on back up the install order
+        stale.sort_by(|a, b| b.cmp(a));
+        for &(kind, id) in &stale {
+            remove(&mut *conn, kind, id).await?;
+        }
+
+        let updated = ids
+            .iter()
+            .filter(|content| previously_owned.contains(content))
+            .count();
+
+        Ok(Installed {
+            manifest: manifest.clone(),
+            added: ids.len() - updated,
+            updated,
+            removed: stale.len(),
+        })
+    }
+
+    /// Deletes the pack, its Source and all the content it owns. Characters
+    /// lose any of that content they had chosen.
+    pub async fn uninstall(db: impl Db<'_>, id: Uuid) -> crate::Result<bool> {
+        let mut conn = db.connection().await?;
+
+        let mut contents = owned(&mut *conn, id).await?;
+        contents.sort_by(|a, b| b.cmp(a));
+
+        for (kind, content_id) in contents {
+            remove(&mut *conn, kind, content_id).await?;
+        }
+        Source::delete(&mut *conn, id).await?;
+
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM `content_packs`
+                WHERE `content_pack_id` = ?1
+                ;
+            "#,
+            id
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+impl Manifest {
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        id: Uuid,
+    ) -> crate::Result<Manifest> {
+        Ok(sqlx::query_as!(
+            Manifest,
+            r#"
+                SELECT `content_pack_id` AS "id: Uuid"
+                    , `name`
+                    , `version`
+                    , `author`
+                    , `description`
+                FROM `content_packs`
+                WHERE `content_pack_id` = ?1
+                LIMIT 1
+                ;
+            "#,
+            id
+        )
+        .fetch_one(executor)
+        .await?)
+    }
+
+    pub async fn load_by_name(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        name: &str,
+    ) -> crate::Result<Manifest> {
+        Ok(sqlx::query_as!(
+            Manifest,
+            r#"
+                SELECT `content_pack_id` AS "id: Uuid"
+                    , `name`
+                    , `version`
+                    , `author`
+                    , `description`
+                FROM `content_packs`
+                WHERE `name` = ?1 COLLATE NOCASE
+                LIMIT 1
+                ;
+            "#,
+            name
+        )
+        .fetch_one(executor)
+        .await?)
+    }
+
+    /// The manifests of every installed pack.
+    pub async fn list(executor: impl sqlx::SqliteExecutor<'_>) -> crate::Result<Vec<Manifest>> {
+        Ok(sqlx::query_as!(
+            Manifest,
+            r#"
+                SELECT `content_pack_id` AS "id: Uuid"
+                    , `name`
+                    , `version`
+                    , `author`
+                    , `description`
+                FROM `content_packs`
+                ORDER BY `name`
+                ;
+            "#
+        )
+        .fetch_all(executor)
+        .await?)
+    }
+
+    async fn save(self, executor: impl sqlx::SqliteExecutor<'_>) -> crate::Result<()> {
+        let Manifest {
+            id,
+            name,
+            version,
+            author,
+            description,
+        } = self;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO `content_packs`
+                    (`content_pack_id`, `name`, `version`, `author`, `description`)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ON CONFLICT (`content_pack_id`) DO UPDATE
+                    SET `name` = ?2
+                    , `version` = ?3
+                    , `author` = ?4
+                    , `description` = ?5
+                ;
+            "#,
+            id,
+            name,
+            version,
+            author,
+            description
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+}
+
+/// The content of `kind` a Campaign can't see: everything owned by a pack
+/// that isn't enabled in it.
+pub async fn hidden(
+    executor: impl sqlx::SqliteExecutor<'_>,
+    campaign_id: Uuid,
+    kind: ContentKind,
+) -> crate::Result<HashSet<Uuid>> {
+    let kind = kind.as_str();
+
+    let ids = sqlx::query_scalar!(
+        r#"
+            SELECT `content_id` AS "id: Uuid"
+            FROM `content_pack_contents`
+            WHERE `kind` = ?2
+                AND `content_pack_id` NOT IN (
+                    SELECT `content_pack_id`
+                    FROM `campaigns_content_packs`
+                    WHERE `campaign_id` = ?1
+                )
+            ;
+        "#,
+        campaign_id,
+        kind
+    )
+    .fetch_all(executor)
+    .await?;
+
+    Ok(ids.into_iter().collect())
+}
+
+async fn owned(
+    executor: impl sqlx::SqliteExecutor<'_>,
+    pack_id: Uuid,
+) -> crate::Result<Vec<(ContentKind, Uuid)>> {
+    let rows = sqlx::query!(
+        r#"
+            SELECT `kind`
+                , `content_id` AS "id: Uuid"
+            FROM `content_pack_contents`
+            WHERE `content_pack_id` = ?1
+            ;
+        "#,
+        pack_id
+    )
+    .fetch_all(executor)
+    .await?;
+
+    let mut contents = vec![];
+    for row in rows {
+        contents.push((row.kind.parse()?, row.id));
+    }
+    contents.sort();
+
+    Ok(contents)
+}
+
+async fn owner(
+    executor: impl sqlx::SqliteExecutor<'_>,
+    kind: ContentKind,
+    id: Uuid,
+) -> crate::Result<Option<Uuid>> {
+    let kind = kind.as_str();
+
+    Ok(sqlx::query_scalar!(
+        r#"
+            SELECT `content_pack_id` AS "id: Uuid"
+            FROM `content_pack_contents`
+            WHERE `kind` = ?1
+                AND `content_id` = ?2
+            LIMIT 1
+            ;
+        "#,
+        kind,
+        id
+    )
+    .fetch_optional(executor)
+    .await?)
+}
+
+async fn exists(
+    executor: impl sqlx::SqliteExecutor<'_>,
+    kind: ContentKind,
+    id: Uuid,
+) -> crate::Result<bool> {
+    let (table, column) = kind.table();
+
+    let found: Option<i64> = sqlx::query_scalar(&format!(
+        "SELECT 1 FROM `{table}` WHERE `{column}` = ?1 LIMIT 1;"
+    ))
+    .bind(id)
+    .fetch_optional(executor)
+    .await?;
+
+    Ok(found.is_some())
+}
+
+/// Deletes a piece of content along with the record of which pack owned it.
+/// A Spell's Point Enhancements go with it, but its School is shared and stays.
+async fn remove(db: impl Db<'_>, kind: ContentKind, id: Uuid) -> crate::Result<()> {
+    let mut conn = db.connection().await?;
+
+    let (table, column) = kind.table();
+
+    if kind == ContentKind::Spell {
+        sqlx::query!(
+            r#"
+                DELETE FROM `point_enhancements`
+                WHERE `point_enhancement_id` IN (
+                    SELECT `point_enhancement_id`
+                    FROM `point_enhancements_spells`
+                    WHERE `spell_id` = ?1
+                )
+                ;
+            "#,
+            id
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    sqlx::query(&format!("DELETE FROM `{table}` WHERE `{column}` = ?1;"))
+        .bind(id)
+        .execute(&mut *conn)
+        .await?;
+
+    let kind = kind.as_str();
+    sqlx::query!(
+        r#"
+            DELETE FROM `content_pack_contents`
+            WHERE `kind` = ?1
+                AND `content_id` = ?2
+            ;
+        "#,
+        kind,
+        id
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+async fn save_weapon_property(
+    executor: impl sqlx::SqliteExecutor<'_>,
+    property: &PackWeaponProperty,
+) -> crate::Result<()> {
+    sqlx::query!(
+        r#"
+            INSERT INTO `weapon_properties`
+                (`weapon_property_id`, `name`, `description`, `cost`, `source_id`)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT (`weapon_property_id`) DO UPDATE
+                SET `name` = ?2
+                , `description` = ?3
+                , `cost` = ?4
+                , `source_id` = ?5
+            ;
+        "#,
+        property.id,
+        property.name,
+        property.description,
+        property.cost,
+        property.source
+    )
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+async fn load_weapon_property(
+    executor: impl sqlx::SqliteExecutor<'_>,
+    id: Uuid,
+) -> crate::Result<PackWeaponProperty> {
+    Ok(sqlx::query_as!(
+        PackWeaponProperty,
+        r#"
+            SELECT `weapon_property_id` AS "id: Uuid"
+                , `name`
+                , `description`
+                , `cost`
+                , `required_weapon_property_id` AS "requires: Uuid"
+                , `source_id` AS "source?: Uuid"
+            FROM `weapon_properties`
+            WHERE `weapon_property_id` = ?1
+            LIMIT 1
+            ;
+        "#,
+        id
+    )
+    .fetch_one(executor)
+    .await?)
+}
+
+fn read_dir(dir: &Path, files: &mut Vec<(String, String)>) -> Result<(), PackError> {
+    for entry in std::fs::read_dir(dir).map_err(|e| io_error(dir, e))? {
+        let path = entry.map_err(|e| io_error(dir, e))?.path();
+
+        if path.is_dir() {
+            read_dir(&path, files)?;
+        } else if is_pack_file(&path) {
+            let text = std::fs::read_to_string(&path).map_err(|e| io_error(&path, e))?;
+            files.push((path.to_string_lossy().replace('\\', "/"), text));
+        }
+    }
+
+    Ok(())
+}
+
+fn read_archive(
+    reader: impl Read,
+    archive_path: &Path,
+    files: &mut Vec<(String, String)>,
+) -> Result<(), PackError> {
+    let mut archive = tar::Archive::new(reader);
+
+    for entry in archive.entries().map_err(|e| io_error(archive_path, e))? {
+        let mut entry = entry.map_err(|e| io_error(archive_path, e))?;
+        let path = entry
+            .path()
+            .map_err(|e| io_error(archive_path, e))?
+            .into_owned();
+
+        if entry.header().entry_type().is_file() && is_pack_file(&path) {
+            let mut text = String::new();
+            entry
+                .read_to_string(&mut text)
+                .map_err(|e| io_error(&path, e))?;
+            files.push((path.to_string_lossy().replace('\\', "/"), text));
+        }
+    }
+
+    Ok(())
+}
+
+fn is_pack_file(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "toml" || extension == "json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"
+        id = "0199f5c2-0000-7000-8000-000000000001"
+        name = "Tome of Tides"
+        version = "1.0.0"
+        author = "Spencer Dent"
+    "#;
+
+    #[test]
+    fn _content_files_are_joined_in_name_order() -> Result<(), PackError> {
+        let pack = ContentPack::from_files(vec![
+            (
+                "tides/b.json".into(),
+                r#"{ "classes": [{ "id": "0199f5c2-0000-7000-8000-000000000003", "name": "Tidecaller" }] }"#.into(),
+            ),
+            ("tides/manifest.toml".into(), MANIFEST.into()),
+            (
+                "tides/a.toml".into(),
+                r#"
+                    [[classes]]
+                    id = "0199f5c2-0000-7000-8000-000000000002"
+                    name = "Wavebreaker"
+                "#
+                .into(),
+            ),
+            ("tides/README.md".into(), "# Tome of Tides".into()),
+        ])?;
+
+        assert_eq!(pack.manifest.name, "Tome of Tides");
+        assert_eq!(
+            pack.content
+                .classes
+                .iter()
+                .map(|class| class.name.as_str())
+                .collect::<Vec<_>>(),
+            ["Wavebreaker", "Tidecaller"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _packs_without_a_manifest_or_with_repeated_ids_are_rejected() {
+        assert_eq!(
+            ContentPack::from_files(vec![("classes.toml".into(), String::new())]),
+            Err(PackError::MissingManifest)
+        );
+
+        let class = r#"
+            [[classes]]
+            id = "0199f5c2-0000-7000-8000-000000000002"
+            name = "Wavebreaker"
+        "#;
+        assert_eq!(
+            ContentPack::from_files(vec![
+                ("manifest.toml".into(), MANIFEST.into()),
+                ("a.toml".into(), class.into()),
+                ("b.toml".into(), class.into()),
+            ]),
+            Err(PackError::Duplicate {
+                kind: ContentKind::Class,
+                id: Uuid::from_u128(0x0199f5c2_0000_7000_8000_000000000002)
+            })
+        );
+
+        assert!(matches!(
+            ContentPack::from_files(vec![
+                ("manifest.toml".into(), MANIFEST.into()),
+                ("a.toml".into(), "[[subraces]]\nname = \"Merfolk\"".into()),
+            ]),
+            Err(PackError::Invalid { file, .. }) if file == "a.toml"
+        ));
+    }
+}
@@ -1,6 +1,9 @@
+use serde::{Deserialize, Serialize};
 use turann::Builder;
 use uuid::Uuid;
 
+use crate::db::Db;
+
 impl PlayerBuilder {
     fn validate_name(name: String) -> Result<String, PlayerBuilderError> {
         if name.is_empty() {
@@ -14,7 +17,8 @@ impl PlayerBuilder {
     }
 }
 
-#[derive(Builder, Clone, Debug, PartialEq)]
+#[derive(Builder, Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "PlayerFields")]
 pub struct Player {
     #[builder(default = Uuid::new_v4)]
     id: Uuid,
@@ -22,8 +26,33 @@ pub struct Player {
     name: String,
 }
 
+/// A [`Player`] as it comes in, built up through its builder so that it is
+/// checked the same way.
+#[derive(Deserialize)]
+struct PlayerFields {
+    id: Option<Uuid>,
+    name: String,
+}
+
+impl TryFrom<PlayerFields> for Player {
+    type Error = PlayerBuilderError;
+
+    fn try_from(fields: PlayerFields) -> Result<Self, Self::Error> {
+        let mut builder = Player::builder();
+        builder.name(fields.name)?;
+        if let Some(id) = fields.id {
+            builder.id(id);
+        }
+
+        builder.build()
+    }
+}
+
 impl Player {
-    pub async fn load(pool: &sqlx::SqlitePool, uuid: Uuid) -> anyhow::Result<Player> {
+    pub async fn load(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        uuid: Uuid,
+    ) -> crate::Result<Player> {
         let result = sqlx::query_as!(
             Player,
             r#"
@@ -34,14 +63,49 @@ impl Player {
             "#,
             uuid
         )
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await?;
 
         Ok(result)
     }
 
-    pub async fn save(self, pool: &sqlx::SqlitePool) -> anyhow::Result<i64> {
-        let mut conn = pool.acquire().await?;
+    pub async fn load_by_name(
+        executor: impl sqlx::SqliteExecutor<'_>,
+        name: &str,
+    ) -> crate::Result<Player> {
+        let result = sqlx::query_as!(
+            Player,
+            r#"
+                SELECT player_id as "id: Uuid", name
+                FROM players
+                WHERE name = ? COLLATE NOCASE
+                LIMIT 1
+            "#,
+            name
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(result)
+    }
+
+    pub async fn list(executor: impl sqlx::SqliteExecutor<'_>) -> crate::Result<Vec<Player>> {
+        let result = sqlx::query_as!(
+            Player,
+            r#"
+                SELECT player_id as "id: Uuid", name
+                FROM players
+                ORDER BY name
+            "#
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(result)
+    }
+
+    pub async fn save(self, db: impl Db<'_>) -> crate::Result<i64> {
+        let mut conn = db.connection().await?;
 
         let Player { id, name } = self;
 
@@ -62,6 +126,20 @@ impl Player {
         Ok(id)
     }
 
+    pub async fn delete(executor: impl sqlx::SqliteExecutor<'_>, id: Uuid) -> crate::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM players
+                WHERE player_id = ?
+            "#,
+            id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     pub fn id(&self) -> &Uuid {
         &self.id
     }
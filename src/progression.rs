@@ -0,0 +1,385 @@
+//! A Character Level by Level, for planning builds and reviewing balance.
+//!
+//! Each [`Level`] holds the Character's Attributes at that Level and what
+//! they took on reaching it: the Class the Level went to, and any Subclass,
+//! Maneuvers or Spell Lists picked up. The Character at a past Level is
+//! everything taken up to and including it, so [`at_level`] rebuilds them
+//! from those rows alone, [`compare`] sets two such Levels side by side and
+//! [`chart`] follows the derived stats from the first Level to the last.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    db::Db,
+    dc20::{
+        AncestryTrait, AttributeName, Attributes, Background, Character, Class, ClassTable,
+        ClassTableColumn, Level, Maneuver, Spell, Subclass,
+    },
+    Error,
+};
+
+/// A Class and how many of the Character's Levels have gone to it.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ClassLevels {
+    pub class: Class,
+    pub levels: u32,
+}
+
+/// A Character as they stood at one of their Levels.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct LevelSnapshot {
+    pub level: Level,
+    /// `None` when the Level was saved without its Attributes.
+    pub attributes: Option<Attributes>,
+    /// In the order the Character first took them.
+    pub classes: Vec<ClassLevels>,
+    pub subclasses: Vec<Subclass>,
+    pub backgrounds: Vec<Background>,
+    pub ancestry_traits: Vec<AncestryTrait>,
+    pub maneuvers: Vec<Maneuver>,
+    /// Every Spell learned so far, by name.
+    pub spells: Vec<Spell>,
+    pub stats: LevelStats,
+}
+
+/// What follows from a Level rather than being chosen at it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LevelStats {
+    pub level: u32,
+    pub combat_mastery: usize,
+    /// From Attributes and Combat Mastery alone, since the Armor worn now
+    /// says nothing about what was worn then.
+    pub precision_defense: Option<usize>,
+    pub area_defense: Option<usize>,
+    /// The rest are Class Table totals: what each Level gave in the Class
+    /// it went to, at that Class's own Level.
+    pub health_points: usize,
+    pub attribute_points: usize,
+    pub skill_points: usize,
+    pub trade_points: usize,
+    pub stamina_points: usize,
+    pub maneuvers_known: usize,
+    pub techniques_known: usize,
+    pub mana_points: usize,
+    pub cantrips_known: usize,
+    pub spells_known: usize,
+}
+
+impl LevelStats {
+    /// Every stat by name, for comparing and charting.
+    #[must_use]
+    pub fn values(&self) -> [(&'static str, Option<usize>); 14] {
+        [
+            ("Level", Some(self.level as usize)),
+            ("Combat Mastery", Some(self.combat_mastery)),
+            ("Precision Defense", self.precision_defense),
+            ("Area Defense", self.area_defense),
+            ("Health Points", Some(self.health_points)),
+            ("Attribute Points", Some(self.attribute_points)),
+            ("Skill Points", Some(self.skill_points)),
+            ("Trade Points", Some(self.trade_points)),
+            ("Stamina Points", Some(self.stamina_points)),
+            ("Maneuvers Known", Some(self.maneuvers_known)),
+            ("Techniques Known", Some(self.techniques_known)),
+            ("Mana Points", Some(self.mana_points)),
+            ("Cantrips Known", Some(self.cantrips_known)),
+            ("Spells Known", Some(self.spells_known)),
+        ]
+    }
+
+    /// Adds what reaching `class_level` in a Class gives.
+    fn gain(&mut self, table: &ClassTable, class_level: u32) {
+        let at = |column: &ClassTableColumn| {
+            column.0.get(class_level as usize - 1).copied().unwrap_or(0)
+        };
+
+        self.health_points += at(&table.health_points);
+        self.attribute_points += at(&table.attribute_points);
+        self.skill_points += at(&table.skill_points);
+        self.trade_points += at(&table.trade_points);
+        self.stamina_points += at(&table.stamina_points);
+        self.maneuvers_known += at(&table.maneuvers_known);
+        self.techniques_known += at(&table.techniques_known);
+        self.mana_points += at(&table.mana_points);
+        self.cantrips_known += at(&table.cantrips_known);
+        self.spells_known += at(&table.spells_known);
+    }
+}
+
+/// What was taken or given up between two Levels. Going back to an earlier
+/// Level gives things up.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Changes<T> {
+    pub added: Vec<T>,
+    pub removed: Vec<T>,
+}
+
+impl<T: Clone + PartialEq> Changes<T> {
+    fn between(from: &[T], to: &[T]) -> Self {
+        Changes {
+            added: to.iter().filter(|t| !from.contains(t)).cloned().collect(),
+            removed: from.iter().filter(|t| !to.contains(t)).cloned().collect(),
+        }
+    }
+}
+
+/// A base Attribute or derived stat that differs between two Levels.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Change<T> {
+    pub name: String,
+    pub from: Option<T>,
+    pub to: Option<T>,
+}
+
+/// A Class the two Levels have a different number of Levels in.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ClassChange {
+    pub class: Class,
+    pub from: u32,
+    pub to: u32,
+}
+
+/// Everything that differs between the Character at two of their Levels.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct LevelComparison {
+    pub from: u32,
+    pub to: u32,
+    pub attributes: Vec<Change<i64>>,
+    pub classes: Vec<ClassChange>,
+    pub subclasses: Changes<Subclass>,
+    pub backgrounds: Changes<Background>,
+    pub ancestry_traits: Changes<AncestryTrait>,
+    pub maneuvers: Changes<Maneuver>,
+    pub spells: Changes<Spell>,
+    pub stats: Vec<Change<usize>>,
+}
+
+impl LevelComparison {
+    #[must_use]
+    pub fn new(from: &LevelSnapshot, to: &LevelSnapshot) -> Self {
+        let attributes = [
+            AttributeName::Prime,
+            AttributeName::Might,
+            AttributeName::Agility,
+            AttributeName::Charisma,
+            AttributeName::Intelligence,
+        ]
+        .into_iter()
+        .map(|name| Change {
+            name: name.to_string(),
+            from: from.attributes.as_ref().map(|a| a.get(name)),
+            to: to.attributes.as_ref().map(|a| a.get(name)),
+        })
+        .filter(|change| change.from != change.to)
+        .collect();
+
+        let levels_in = |snapshot: &LevelSnapshot, class: &Class| {
+            snapshot
+                .classes
+                .iter()
+                .find(|taken| taken.class.id == class.id)
+                .map_or(0, |taken| taken.levels)
+        };
+        let mut classes: Vec<ClassChange> = from
+            .classes
+            .iter()
+            .chain(&to.classes)
+            .map(|taken| ClassChange {
+                class: taken.class.clone(),
+                from: levels_in(from, &taken.class),
+                to: levels_in(to, &taken.class),
+            })
+            .filter(|change| change.from != change.to)
+            .collect();
+        classes.dedup_by(|a, b| a.class.id == b.class.id);
+
+        let stats = from
+            .stats
+            .values()
+            .into_iter()
+            .zip(to.stats.values())
+            .filter(|((_, before), (_, after))| before != after)
+            .map(|((name, before), (_, after))| Change {
+                name: name.into(),
+                from: before,
+                to: after,
+            })
+            .collect();
+
+        LevelComparison {
+            from: from.level.level,
+            to: to.level.level,
+            attributes,
+            classes,
+            subclasses: Changes::between(&from.subclasses, &to.subclasses),
+            backgrounds: Changes::between(&from.backgrounds, &to.backgrounds),
+            ancestry_traits: Changes::between(&from.ancestry_traits, &to.ancestry_traits),
+            maneuvers: Changes::between(&from.maneuvers, &to.maneuvers),
+            spells: Changes::between(&from.spells, &to.spells),
+            stats,
+        }
+    }
+}
+
+/// The Character as they were at `level`.
+pub async fn at_level(
+    db: impl Db<'_>,
+    character_id: Uuid,
+    level: u32,
+) -> crate::Result<LevelSnapshot> {
+    snapshots(db, character_id, level)
+        .await?
+        .pop()
+        .filter(|snapshot| snapshot.level.level == level)
+        .ok_or_else(|| Error::not_found(format!("Level {level} of Character `{character_id}`")))
+}
+
+/// What changed in the Character from Level `from` to Level `to`.
+pub async fn compare(
+    db: impl Db<'_>,
+    character_id: Uuid,
+    from: u32,
+    to: u32,
+) -> crate::Result<LevelComparison> {
+    let snapshots = snapshots(db, character_id, from.max(to)).await?;
+    let find = |level: u32| {
+        snapshots
+            .iter()
+            .find(|snapshot| snapshot.level.level == level)
+            .ok_or_else(|| Error::not_found(format!("Level {level} of Character `{character_id}`")))
+    };
+
+    Ok(LevelComparison::new(find(from)?, find(to)?))
+}
+
+/// The derived stats at each of the Character's Levels, first to last.
+pub async fn chart(db: impl Db<'_>, character_id: Uuid) -> crate::Result<Vec<LevelStats>> {
+    Ok(snapshots(db, character_id, u32::MAX)
+        .await?
+        .into_iter()
+        .map(|snapshot| snapshot.stats)
+        .collect())
+}
+
+/// The Character at each of their Levels up to and including `up_to`,
+/// carrying what was taken at each one on to the next.
+async fn snapshots(
+    db: impl Db<'_>,
+    character_id: Uuid,
+    up_to: u32,
+) -> crate::Result<Vec<LevelSnapshot>> {
+    let mut conn = db.connection().await?;
+
+    let character = Character::load(&mut *conn, character_id).await?;
+    let mut tables: HashMap<Uuid, ClassTable> = HashMap::new();
+    let mut snapshots: Vec<LevelSnapshot> = vec![];
+
+    for level in character.load_levels(&mut *conn).await? {
+        if level.level > up_to {
+            break;
+        }
+
+        let attributes = match level.load_base_attributes(&mut *conn).await {
+            Ok(attributes) => Some(attributes),
+            Err(Error::NotFound(_)) => None,
+            Err(e) => return Err(e),
+        };
+
+        let mut snapshot = match snapshots.last() {
+            Some(previous) => LevelSnapshot {
+                level: level.clone(),
+                attributes,
+                ..previous.clone()
+            },
+            None => LevelSnapshot {
+                level: level.clone(),
+                attributes,
+                classes: vec![],
+                subclasses: vec![],
+                backgrounds: vec![],
+                ancestry_traits: vec![],
+                maneuvers: vec![],
+                spells: vec![],
+                stats: LevelStats::default(),
+            },
+        };
+
+        let combat_mastery = level.calc_combat_mastery();
+        snapshot.stats.level = level.level;
+        snapshot.stats.combat_mastery = combat_mastery;
+        snapshot.stats.precision_defense = snapshot
+            .attributes
+            .as_ref()
+            .map(|a| a.precision_defense(combat_mastery).score);
+        snapshot.stats.area_defense = snapshot
+            .attributes
+            .as_ref()
+            .map(|a| a.area_defense(combat_mastery).score);
+
+        for class in level.load_classes(&mut *conn, None).await? {
+            let class_levels = match snapshot
+                .classes
+                .iter_mut()
+                .find(|taken| taken.class.id == class.id)
+            {
+                Some(taken) => {
+                    taken.levels += 1;
+                    taken.levels
+                }
+                None => {
+                    snapshot.classes.push(ClassLevels {
+                        class: class.clone(),
+                        levels: 1,
+                    });
+                    1
+                }
+            };
+
+            let table = match tables.get(&class.id) {
+                Some(table) => *table,
+                None => {
+                    let table = class.load_table(&mut *conn).await?;
+                    tables.insert(class.id, table);
+                    table
+                }
+            };
+            snapshot.stats.gain(&table, class_levels);
+        }
+
+        add_new(
+            &mut snapshot.subclasses,
+            level.load_sublasses(&mut *conn).await?,
+        );
+        add_new(
+            &mut snapshot.backgrounds,
+            level.load_backgrounds(&mut *conn, None).await?,
+        );
+        add_new(
+            &mut snapshot.ancestry_traits,
+            level.load_ancestry_traits(&mut *conn, None).await?,
+        );
+        add_new(
+            &mut snapshot.maneuvers,
+            level.load_maneuvers(&mut *conn).await?,
+        );
+        add_new(&mut snapshot.spells, level.load_spells(&mut *conn).await?);
+        snapshot.maneuvers.sort_by(|a, b| a.name.cmp(&b.name));
+        snapshot.spells.sort_by(|a, b| a.name.cmp(&b.name));
+
+        snapshots.push(snapshot);
+    }
+
+    Ok(snapshots)
+}
+
+/// Adds whatever `taken` has that `all` doesn't yet.
+fn add_new<T: PartialEq>(all: &mut Vec<T>, taken: Vec<T>) {
+    for t in taken {
+        if !all.contains(&t) {
+            all.push(t);
+        }
+    }
+}
@@ -0,0 +1,660 @@
+//! Where content, Players and Characters are kept, behind traits so code
+//! that only needs the rules content can be worked through without a
+//! database.
+//!
+//! [`SqliteRepository`] keeps them in the database, through each type's own
+//! `load`, `save` and `delete`. [`MemoryRepository`] keeps them in a map,
+//! which is all most tests need. With the `postgres` feature,
+//! `PostgresRepository` keeps them in PostgreSQL. None of them check
+//! references between records: that is left to the database's foreign keys.
+//!
+//! Characters, their Levels, Items and Campaigns are [`Repository`]s like
+//! the content. What each Character has one of, such as their Inventory or
+//! Resources, is a [`CharacterRecord`] kept through [`CharacterRecords`];
+//! their [`Revision`]s are kept through [`History`], and who is at each
+//! Campaign's table through [`Trackers`]. Characters are not listed here:
+//! their list is of [`CharacterSummary`](crate::dc20::CharacterSummary).
+
+use std::{
+    any::{Any, TypeId},
+    collections::{BTreeMap, BTreeSet, HashMap},
+    future::Future,
+    sync::Mutex,
+};
+
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::{
+    campaign::Campaign,
+    dc20::{
+        item::{armor::Armor, consumable::Consumable, weapon::Weapon},
+        Ancestry, AncestryTrait, Background, Character, Class, Conditions, Inventory, Item,
+        Language, Level, Maneuver, Purse, Resources, Skill, Source, Spell, SpellList, SpellSearch,
+        TrackedCharacter, Tracker, TrackerChange, TrackerError, TrackerUpdate, Trade,
+    },
+    history::Revision,
+    player::Player,
+    Error,
+};
+
+#[cfg(feature = "postgres")]
+mod postgres;
+
+#[cfg(feature = "postgres")]
+pub use postgres::PostgresRepository;
+
+/// Something kept by its id.
+pub trait Entity: Clone + Send + Sync + 'static {
+    fn key(&self) -> Uuid;
+}
+
+/// An [`Entity`] picked out by name, and so listed by it.
+pub trait Named: Entity {
+    fn name(&self) -> &str;
+}
+
+/// Loads, saves and deletes one kind of [`Entity`].
+pub trait Repository<T: Entity>: Sync {
+    /// The one with this id, or `None` when there isn't one.
+    fn load(&self, id: Uuid) -> impl Future<Output = crate::Result<Option<T>>> + Send;
+
+    /// Adds it, or replaces the one with the same id.
+    fn save(&self, value: T) -> impl Future<Output = crate::Result<()>> + Send;
+
+    /// Whether there was one with this id to delete.
+    fn delete(&self, id: Uuid) -> impl Future<Output = crate::Result<bool>> + Send;
+}
+
+/// A [`Repository`] that can list everything it keeps, by name.
+pub trait Catalog<T: Named>: Repository<T> {
+    fn list(&self) -> impl Future<Output = crate::Result<Vec<T>>> + Send;
+}
+
+/// Something each Character has exactly one of, kept by their id.
+pub trait CharacterRecord: Clone + Send + Sync + 'static {
+    /// What a Character has before any is saved.
+    fn empty(character_id: Uuid) -> Self;
+}
+
+/// Loads and saves one kind of [`CharacterRecord`].
+pub trait CharacterRecords<T: CharacterRecord>: Sync {
+    /// What the Character has, or [`CharacterRecord::empty`] when none was
+    /// saved.
+    fn load_for(&self, character_id: Uuid) -> impl Future<Output = crate::Result<T>> + Send;
+
+    /// Replaces what the Character has.
+    fn save_for(
+        &self,
+        character_id: Uuid,
+        value: T,
+    ) -> impl Future<Output = crate::Result<()>> + Send;
+}
+
+/// Keeps each Character's [`Revision`]s, which are only ever added to.
+pub trait History: Sync {
+    fn save_revision(&self, revision: Revision) -> impl Future<Output = crate::Result<()>> + Send;
+
+    /// Every Revision of the Character, oldest first.
+    fn load_revisions(
+        &self,
+        character_id: Uuid,
+    ) -> impl Future<Output = crate::Result<Vec<Revision>>> + Send;
+
+    /// The Character's newest Revision, if they have one.
+    fn load_latest_revision(
+        &self,
+        character_id: Uuid,
+    ) -> impl Future<Output = crate::Result<Option<Revision>>> + Send;
+}
+
+/// Who takes part in each [`Campaign`], and the [`Tracker`] for its table.
+pub trait Trackers: Sync {
+    fn add_character(
+        &self,
+        campaign_id: Uuid,
+        character_id: Uuid,
+    ) -> impl Future<Output = crate::Result<()>> + Send;
+
+    /// Whether the Character was in the Campaign to be taken out of it.
+    fn remove_character(
+        &self,
+        campaign_id: Uuid,
+        character_id: Uuid,
+    ) -> impl Future<Output = crate::Result<bool>> + Send;
+
+    /// Only the Characters that changed after version `since`, as
+    /// [`Tracker::load_since`] gives them.
+    fn load_tracker(
+        &self,
+        campaign_id: Uuid,
+        since: u64,
+    ) -> impl Future<Output = crate::Result<Tracker>> + Send;
+
+    /// Applies the change as [`Tracker::apply`] does.
+    fn apply(
+        &self,
+        campaign_id: Uuid,
+        change: TrackerChange,
+    ) -> impl Future<Output = crate::Result<TrackerUpdate>> + Send;
+}
+
+/// Turns the not-found error `load` gives into `None`.
+fn found<T>(result: crate::Result<T>) -> crate::Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(Error::NotFound(_)) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Keeps everything in the SQLite database.
+#[derive(Clone, Debug)]
+pub struct SqliteRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+}
+
+macro_rules! sqlite_repository {
+    ($type:ident) => {
+        impl Repository<$type> for SqliteRepository {
+            async fn load(&self, id: Uuid) -> crate::Result<Option<$type>> {
+                found($type::load(&self.pool, id).await)
+            }
+
+            async fn save(&self, value: $type) -> crate::Result<()> {
+                value.save(&self.pool).await?;
+
+                Ok(())
+            }
+
+            async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+                $type::delete(&self.pool, id).await.map_err(Into::into)
+            }
+        }
+    };
+    ($type:ident, |$pool:ident| $list:expr) => {
+        sqlite_repository!($type);
+
+        impl Catalog<$type> for SqliteRepository {
+            async fn list(&self) -> crate::Result<Vec<$type>> {
+                let $pool = &self.pool;
+
+                $list.await.map_err(Into::into)
+            }
+        }
+    };
+}
+
+sqlite_repository!(Player, |pool| Player::list(pool));
+sqlite_repository!(Ancestry, |pool| Ancestry::list(pool));
+sqlite_repository!(AncestryTrait, |pool| AncestryTrait::list(pool));
+sqlite_repository!(Background, |pool| Background::list(pool, None));
+sqlite_repository!(Class, |pool| Class::list(pool, None));
+sqlite_repository!(Language, |pool| Language::list(pool));
+sqlite_repository!(Maneuver, |pool| Maneuver::list(pool));
+sqlite_repository!(Skill, |pool| Skill::list(pool));
+sqlite_repository!(Source, |pool| Source::list(pool));
+sqlite_repository!(Spell, |pool| Spell::search(
+    pool,
+    &SpellSearch::default(),
+    None
+));
+sqlite_repository!(SpellList, |pool| SpellList::list(pool));
+sqlite_repository!(Trade, |pool| Trade::list(pool));
+sqlite_repository!(Campaign, |pool| Campaign::list(pool));
+sqlite_repository!(Level);
+sqlite_repository!(Character);
+sqlite_repository!(Item);
+sqlite_repository!(Weapon);
+sqlite_repository!(Armor);
+sqlite_repository!(Consumable);
+
+impl CharacterRecords<Inventory> for SqliteRepository {
+    async fn load_for(&self, character_id: Uuid) -> crate::Result<Inventory> {
+        Inventory::load(&self.pool, character_id).await
+    }
+
+    async fn save_for(&self, character_id: Uuid, value: Inventory) -> crate::Result<()> {
+        Inventory {
+            character_id,
+            ..value
+        }
+        .save(&self.pool)
+        .await
+    }
+}
+
+macro_rules! sqlite_records {
+    ($type:ident) => {
+        impl CharacterRecords<$type> for SqliteRepository {
+            async fn load_for(&self, character_id: Uuid) -> crate::Result<$type> {
+                $type::load(&self.pool, character_id).await
+            }
+
+            async fn save_for(&self, character_id: Uuid, value: $type) -> crate::Result<()> {
+                value.save(&self.pool, character_id).await
+            }
+        }
+    };
+}
+
+sqlite_records!(Resources);
+sqlite_records!(Conditions);
+sqlite_records!(Purse);
+
+impl History for SqliteRepository {
+    async fn save_revision(&self, revision: Revision) -> crate::Result<()> {
+        revision.save(&self.pool).await
+    }
+
+    async fn load_revisions(&self, character_id: Uuid) -> crate::Result<Vec<Revision>> {
+        Revision::load_all(&self.pool, character_id).await
+    }
+
+    async fn load_latest_revision(&self, character_id: Uuid) -> crate::Result<Option<Revision>> {
+        Revision::load_latest(&self.pool, character_id).await
+    }
+}
+
+impl Trackers for SqliteRepository {
+    async fn add_character(&self, campaign_id: Uuid, character_id: Uuid) -> crate::Result<()> {
+        Campaign::load(&self.pool, campaign_id)
+            .await?
+            .add_character(&self.pool, character_id)
+            .await
+    }
+
+    async fn remove_character(&self, campaign_id: Uuid, character_id: Uuid) -> crate::Result<bool> {
+        let Some(campaign) = found(Campaign::load(&self.pool, campaign_id).await)? else {
+            return Ok(false);
+        };
+
+        campaign.remove_character(&self.pool, character_id).await
+    }
+
+    async fn load_tracker(&self, campaign_id: Uuid, since: u64) -> crate::Result<Tracker> {
+        Tracker::load_since(&self.pool, campaign_id, since).await
+    }
+
+    async fn apply(
+        &self,
+        campaign_id: Uuid,
+        change: TrackerChange,
+    ) -> crate::Result<TrackerUpdate> {
+        Tracker::apply(&self.pool, campaign_id, change).await
+    }
+}
+
+/// Keeps everything in memory, for as long as it lives.
+#[derive(Debug, Default)]
+pub struct MemoryRepository {
+    tables: Mutex<HashMap<TypeId, Box<dyn Any + Send>>>,
+}
+
+impl MemoryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_table<T: Entity, R>(&self, f: impl FnOnce(&mut BTreeMap<Uuid, T>) -> R) -> R {
+        let mut tables = self
+            .tables
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+        let table = tables
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(BTreeMap::<Uuid, T>::new()))
+            .downcast_mut()
+            .expect("tables are keyed by their type");
+
+        f(table)
+    }
+}
+
+impl<T: Entity> Repository<T> for MemoryRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<T>> {
+        Ok(self.with_table(|table: &mut BTreeMap<Uuid, T>| table.get(&id).cloned()))
+    }
+
+    async fn save(&self, value: T) -> crate::Result<()> {
+        self.with_table(|table| table.insert(value.key(), value));
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        Ok(self.with_table(|table: &mut BTreeMap<Uuid, T>| table.remove(&id).is_some()))
+    }
+}
+
+/// A [`CharacterRecord`] kept in memory, in a table of its own.
+#[derive(Clone)]
+struct Record<T>(Uuid, T);
+
+impl<T: CharacterRecord> Entity for Record<T> {
+    fn key(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl<T: CharacterRecord> CharacterRecords<T> for MemoryRepository {
+    async fn load_for(&self, character_id: Uuid) -> crate::Result<T> {
+        Ok(self
+            .with_table(|table: &mut BTreeMap<Uuid, Record<T>>| {
+                table
+                    .get(&character_id)
+                    .map(|Record(_, value)| value.clone())
+            })
+            .unwrap_or_else(|| T::empty(character_id)))
+    }
+
+    async fn save_for(&self, character_id: Uuid, value: T) -> crate::Result<()> {
+        self.with_table(|table| table.insert(character_id, Record(character_id, value)));
+
+        Ok(())
+    }
+}
+
+impl History for MemoryRepository {
+    async fn save_revision(&self, revision: Revision) -> crate::Result<()> {
+        self.save(revision).await
+    }
+
+    async fn load_revisions(&self, character_id: Uuid) -> crate::Result<Vec<Revision>> {
+        // v7 ids keep the table in the order the Revisions were saved
+        Ok(self.with_table(|table: &mut BTreeMap<Uuid, Revision>| {
+            table
+                .values()
+                .filter(|revision| revision.character_id == character_id)
+                .cloned()
+                .collect()
+        }))
+    }
+
+    async fn load_latest_revision(&self, character_id: Uuid) -> crate::Result<Option<Revision>> {
+        Ok(self.load_revisions(character_id).await?.pop())
+    }
+}
+
+/// Who takes part in a Campaign and where its tracker is at.
+#[derive(Clone, Debug, Default)]
+struct Table {
+    campaign_id: Uuid,
+    members: BTreeSet<Uuid>,
+    version: u64,
+    /// Each tracked Character's initiative, and the version they last
+    /// changed at.
+    tracked: BTreeMap<Uuid, (Option<i64>, u64)>,
+}
+
+impl Entity for Table {
+    fn key(&self) -> Uuid {
+        self.campaign_id
+    }
+}
+
+impl MemoryRepository {
+    fn table(&self, campaign_id: Uuid) -> Table {
+        self.with_table(|tables: &mut BTreeMap<Uuid, Table>| tables.get(&campaign_id).cloned())
+            .unwrap_or(Table {
+                campaign_id,
+                ..Table::default()
+            })
+    }
+}
+
+impl Trackers for MemoryRepository {
+    async fn add_character(&self, campaign_id: Uuid, character_id: Uuid) -> crate::Result<()> {
+        self.with_table(|tables: &mut BTreeMap<Uuid, Table>| {
+            tables
+                .entry(campaign_id)
+                .or_insert_with(|| Table {
+                    campaign_id,
+                    ..Table::default()
+                })
+                .members
+                .insert(character_id)
+        });
+
+        Ok(())
+    }
+
+    async fn remove_character(&self, campaign_id: Uuid, character_id: Uuid) -> crate::Result<bool> {
+        Ok(self.with_table(|tables: &mut BTreeMap<Uuid, Table>| {
+            tables
+                .get_mut(&campaign_id)
+                .is_some_and(|table| table.members.remove(&character_id))
+        }))
+    }
+
+    async fn load_tracker(&self, campaign_id: Uuid, since: u64) -> crate::Result<Tracker> {
+        let table = self.table(campaign_id);
+
+        let mut characters = vec![];
+        for (&character_id, &(initiative, _)) in table
+            .tracked
+            .iter()
+            .filter(|(_, (_, version))| *version > since)
+        {
+            characters.push(TrackedCharacter {
+                character_id,
+                resources: CharacterRecords::<Resources>::load_for(self, character_id).await?,
+                conditions: CharacterRecords::<Conditions>::load_for(self, character_id).await?,
+                initiative,
+            });
+        }
+        characters.sort_by_key(|character| {
+            (
+                character.initiative.is_none(),
+                std::cmp::Reverse(character.initiative),
+            )
+        });
+
+        Ok(Tracker {
+            campaign_id,
+            version: table.version,
+            characters,
+        })
+    }
+
+    /// Applies the change like [`Tracker::apply`] does, turning it away when
+    /// another change got to the table first.
+    async fn apply(
+        &self,
+        campaign_id: Uuid,
+        change: TrackerChange,
+    ) -> crate::Result<TrackerUpdate> {
+        let character_id = change.character_id();
+        let seen = self.table(campaign_id);
+
+        if !seen.members.contains(&character_id) {
+            return Err(TrackerError::NotInCampaign(character_id).into());
+        }
+        let initiative = match seen.tracked.get(&character_id) {
+            Some(&(initiative, _)) => initiative,
+            None if matches!(change, TrackerChange::Join { .. }) => None,
+            None => return Err(TrackerError::NotTracked(character_id).into()),
+        };
+
+        let mut character = TrackedCharacter {
+            character_id,
+            resources: CharacterRecords::<Resources>::load_for(self, character_id).await?,
+            conditions: CharacterRecords::<Conditions>::load_for(self, character_id).await?,
+            initiative,
+        };
+        change.apply(&mut character);
+
+        self.save_for(character_id, character.resources).await?;
+        self.save_for(character_id, character.conditions.clone())
+            .await?;
+
+        let version = self.with_table(|tables: &mut BTreeMap<Uuid, Table>| {
+            let table = tables
+                .get_mut(&campaign_id)
+                .filter(|table| table.version == seen.version)
+                .ok_or(TrackerError::Stale(campaign_id))?;
+            table.version += 1;
+            table
+                .tracked
+                .insert(character_id, (character.initiative, table.version));
+
+            Ok::<_, TrackerError>(table.version)
+        })?;
+
+        Ok(TrackerUpdate { version, character })
+    }
+}
+
+impl<T: Named> Catalog<T> for MemoryRepository {
+    async fn list(&self) -> crate::Result<Vec<T>> {
+        let mut values: Vec<T> =
+            self.with_table(|table: &mut BTreeMap<Uuid, T>| table.values().cloned().collect());
+        values.sort_by(|a, b| a.name().cmp(b.name()));
+
+        Ok(values)
+    }
+}
+
+macro_rules! named {
+    ($type:ident, $id:ident) => {
+        impl Entity for $type {
+            fn key(&self) -> Uuid {
+                self.$id
+            }
+        }
+
+        impl Named for $type {
+            fn name(&self) -> &str {
+                &self.name
+            }
+        }
+    };
+}
+
+named!(Ancestry, id);
+named!(AncestryTrait, id);
+named!(Background, id);
+named!(Class, id);
+named!(Language, id);
+named!(Maneuver, uuid);
+named!(Skill, id);
+named!(Source, id);
+named!(Spell, id);
+named!(SpellList, id);
+named!(Trade, id);
+
+impl Entity for Player {
+    fn key(&self) -> Uuid {
+        *self.id()
+    }
+}
+
+impl Named for Player {
+    fn name(&self) -> &str {
+        Player::name(self)
+    }
+}
+
+impl Entity for Level {
+    fn key(&self) -> Uuid {
+        self.id
+    }
+}
+
+impl Entity for Character {
+    fn key(&self) -> Uuid {
+        *self.id()
+    }
+}
+
+impl Entity for Campaign {
+    fn key(&self) -> Uuid {
+        *self.id()
+    }
+}
+
+impl Named for Campaign {
+    fn name(&self) -> &str {
+        Campaign::name(self)
+    }
+}
+
+impl Entity for Revision {
+    fn key(&self) -> Uuid {
+        self.id
+    }
+}
+
+macro_rules! item {
+    ($type:ident) => {
+        impl Entity for $type {
+            fn key(&self) -> Uuid {
+                self.uuid
+            }
+        }
+    };
+}
+
+item!(Item);
+item!(Weapon);
+item!(Armor);
+item!(Consumable);
+
+impl CharacterRecord for Inventory {
+    fn empty(character_id: Uuid) -> Self {
+        Inventory::new(character_id)
+    }
+}
+
+macro_rules! record {
+    ($type:ident) => {
+        impl CharacterRecord for $type {
+            fn empty(_: Uuid) -> Self {
+                $type::default()
+            }
+        }
+    };
+}
+
+record!(Resources);
+record!(Conditions);
+record!(Purse);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn _memory_keeps_each_kind_apart() -> crate::Result<()> {
+        let repository = MemoryRepository::new();
+        let language = Language::builder().name("Dwarvish")?.build()?;
+        let id = language.id;
+        repository.save(language.clone()).await?;
+
+        assert_eq!(repository.load(id).await?, Some(language));
+        assert_eq!(Repository::<Trade>::load(&repository, id).await?, None);
+        assert!(Catalog::<Trade>::list(&repository).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn _only_missing_rows_are_not_found() {
+        assert!(matches!(found(Ok(1)), Ok(Some(1))));
+        assert!(matches!(
+            found::<()>(Err(sqlx::Error::RowNotFound.into())),
+            Ok(None)
+        ));
+        assert!(found::<()>(Err(sqlx::Error::PoolClosed.into())).is_err());
+    }
+}
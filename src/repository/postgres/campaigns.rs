@@ -0,0 +1,265 @@
+//! Campaigns, who takes part in them, and the tracker at each one's table.
+
+use sqlx::Row;
+use uuid::Uuid;
+
+use super::{
+    characters::{load_conditions, load_resources, save_conditions, save_resources},
+    PostgresRepository,
+};
+use crate::{
+    campaign::Campaign,
+    dc20::{TrackedCharacter, Tracker, TrackerChange, TrackerError, TrackerUpdate},
+    player::Player,
+    repository::{Catalog, Repository, Trackers},
+};
+
+impl PostgresRepository {
+    async fn campaign(
+        &self,
+        (id, name, game_master_id): (Uuid, String, Uuid),
+    ) -> crate::Result<Campaign> {
+        let game_master = Repository::<Player>::load(self, game_master_id)
+            .await?
+            .ok_or(sqlx::Error::RowNotFound)?;
+
+        Ok(Campaign::builder()
+            .id(id)
+            .name(name)?
+            .game_master(game_master)
+            .build()?)
+    }
+}
+
+impl Repository<Campaign> for PostgresRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<Campaign>> {
+        let row: Option<(Uuid, String, Uuid)> = sqlx::query_as(
+            "SELECT campaign_id, name, game_master_id FROM campaigns WHERE campaign_id = $1",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(row) => Ok(Some(self.campaign(row).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Saves the Campaign. Its Game Master must already be saved.
+    async fn save(&self, value: Campaign) -> crate::Result<()> {
+        sqlx::query(
+            "
+                INSERT INTO campaigns (campaign_id, name, game_master_id)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (campaign_id) DO UPDATE
+                    SET name = $2
+                    , game_master_id = $3
+            ",
+        )
+        .bind(value.id())
+        .bind(value.name())
+        .bind(value.game_master().id())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("campaigns", "campaign_id", id).await
+    }
+}
+
+impl Catalog<Campaign> for PostgresRepository {
+    async fn list(&self) -> crate::Result<Vec<Campaign>> {
+        let rows: Vec<(Uuid, String, Uuid)> =
+            sqlx::query_as("SELECT campaign_id, name, game_master_id FROM campaigns ORDER BY name")
+                .fetch_all(&self.pool)
+                .await?;
+
+        let mut campaigns = vec![];
+        for row in rows {
+            campaigns.push(self.campaign(row).await?);
+        }
+
+        Ok(campaigns)
+    }
+}
+
+impl Trackers for PostgresRepository {
+    async fn add_character(&self, campaign_id: Uuid, character_id: Uuid) -> crate::Result<()> {
+        sqlx::query(
+            "
+                INSERT INTO campaigns_characters (campaign_id, character_id)
+                VALUES ($1, $2)
+                ON CONFLICT DO NOTHING
+            ",
+        )
+        .bind(campaign_id)
+        .bind(character_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn remove_character(&self, campaign_id: Uuid, character_id: Uuid) -> crate::Result<bool> {
+        let result = sqlx::query(
+            "DELETE FROM campaigns_characters WHERE campaign_id = $1 AND character_id = $2",
+        )
+        .bind(campaign_id)
+        .bind(character_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn load_tracker(&self, campaign_id: Uuid, since: u64) -> crate::Result<Tracker> {
+        let mut conn = self.pool.acquire().await?;
+
+        let version: Option<i64> =
+            sqlx::query_scalar("SELECT version FROM campaign_trackers WHERE campaign_id = $1")
+                .bind(campaign_id)
+                .fetch_optional(&mut *conn)
+                .await?;
+
+        let rows: Vec<(Uuid, Option<i64>)> = sqlx::query_as(
+            "
+                SELECT character_id, initiative
+                FROM campaign_tracker_characters
+                WHERE campaign_id = $1
+                    AND version > $2
+                ORDER BY initiative IS NULL, initiative DESC
+            ",
+        )
+        .bind(campaign_id)
+        .bind(since as i64)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut characters = vec![];
+        for (character_id, initiative) in rows {
+            characters.push(TrackedCharacter {
+                character_id,
+                resources: load_resources(&mut conn, character_id).await?,
+                conditions: load_conditions(&mut conn, character_id).await?,
+                initiative,
+            });
+        }
+
+        Ok(Tracker {
+            campaign_id,
+            version: version.unwrap_or_default() as u64,
+            characters,
+        })
+    }
+
+    /// Applies the change like [`Tracker::apply`] does: only Characters in
+    /// the Campaign can join, and a change that races another to the same
+    /// version is turned away.
+    async fn apply(
+        &self,
+        campaign_id: Uuid,
+        change: TrackerChange,
+    ) -> crate::Result<TrackerUpdate> {
+        let mut tx = self.pool.begin().await?;
+
+        let character_id = change.character_id();
+
+        let seen: Option<i64> =
+            sqlx::query_scalar("SELECT version FROM campaign_trackers WHERE campaign_id = $1")
+                .bind(campaign_id)
+                .fetch_optional(&mut *tx)
+                .await?;
+
+        let row = sqlx::query(
+            "
+                SELECT t_c.character_id IS NOT NULL AS tracked
+                    , t_c.initiative
+                FROM campaigns_characters AS c_c
+                LEFT JOIN campaign_tracker_characters AS t_c
+                    USING (campaign_id, character_id)
+                WHERE c_c.campaign_id = $1
+                    AND c_c.character_id = $2
+            ",
+        )
+        .bind(campaign_id)
+        .bind(character_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(TrackerError::NotInCampaign(character_id))?;
+
+        if !row.try_get::<bool, _>("tracked")? && !matches!(change, TrackerChange::Join { .. }) {
+            return Err(TrackerError::NotTracked(character_id).into());
+        }
+
+        let mut character = TrackedCharacter {
+            character_id,
+            resources: load_resources(&mut tx, character_id).await?,
+            conditions: load_conditions(&mut tx, character_id).await?,
+            initiative: row.try_get("initiative")?,
+        };
+        change.apply(&mut character);
+
+        save_resources(&mut tx, character_id, character.resources).await?;
+        save_conditions(&mut tx, character_id, &character.conditions).await?;
+
+        let version: i64 = match seen {
+            Some(seen) => {
+                sqlx::query_scalar(
+                    "
+                        UPDATE campaign_trackers
+                        SET version = version + 1
+                        WHERE campaign_id = $1
+                            AND version = $2
+                        RETURNING version
+                    ",
+                )
+                .bind(campaign_id)
+                .bind(seen)
+                .fetch_optional(&mut *tx)
+                .await?
+            }
+            None => {
+                sqlx::query_scalar(
+                    "
+                        INSERT INTO campaign_trackers (campaign_id, version)
+                        VALUES ($1, 1)
+                        ON CONFLICT (campaign_id) DO NOTHING
+                        RETURNING version
+                    ",
+                )
+                .bind(campaign_id)
+                .fetch_optional(&mut *tx)
+                .await?
+            }
+        }
+        .ok_or(TrackerError::Stale(campaign_id))?;
+
+        sqlx::query(
+            "
+                INSERT INTO campaign_tracker_characters
+                    (campaign_id, character_id, initiative, version)
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (campaign_id, character_id) DO UPDATE
+                    SET initiative = $3
+                    , version = $4
+            ",
+        )
+        .bind(campaign_id)
+        .bind(character_id)
+        .bind(character.initiative)
+        .bind(version)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(TrackerUpdate {
+            version: version as u64,
+            character,
+        })
+    }
+}
@@ -0,0 +1,1017 @@
+//! Characters, their Levels and everything kept against them: the Items
+//! they carry, their Resources, Conditions and Purse, and their history.
+
+use sqlx::{PgConnection, Row};
+use uuid::Uuid;
+
+use super::{ancestry_trait, AncestryTraitRow, PostgresRepository};
+use crate::{
+    dc20::{
+        item::{
+            armor::Armor,
+            consumable::{Consumable, ConsumableEffect},
+            weapon::{StyleRow, Weapon, WeaponLinks},
+        },
+        Background, Character, Condition, Conditions, Inventory, InventoryEntry, Item, ItemKind,
+        Level, Purse, ResourcePool, Resources,
+    },
+    export::json::CharacterExport,
+    history::Revision,
+    player::Player,
+    repository::{CharacterRecords, History, Repository},
+};
+
+impl Repository<Character> for PostgresRepository {
+    /// Loads the Character with the Background and Ancestry Traits picked
+    /// across all of their Levels, like [`Character::load`] does.
+    async fn load(&self, id: Uuid) -> crate::Result<Option<Character>> {
+        let Some((id, character_name, player_id)): Option<(Uuid, String, Uuid)> = sqlx::query_as(
+            "SELECT character_id, name, player_id FROM characters WHERE character_id = $1",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let (background_id, background_name, source): (Uuid, String, Option<Uuid>) =
+            sqlx::query_as(
+                "
+                    SELECT background_id, b.name, b.source_id
+                    FROM backgrounds AS b
+                    JOIN backgrounds_character_levels
+                        USING (background_id)
+                    JOIN character_levels
+                        USING (character_level_id)
+                    WHERE character_id = $1
+                    ORDER BY level
+                    LIMIT 1
+                ",
+            )
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| {
+                crate::Error::ContentReference(
+                    format!("Character `{character_name}` has no Background").into(),
+                )
+            })?;
+
+        let ancestry_traits: Vec<AncestryTraitRow> = sqlx::query_as(
+            "
+                SELECT DISTINCT ancestry_trait_id, a_t.name, a_t.description, a_t.cost, a_t.source_id
+                FROM ancestry_traits AS a_t
+                JOIN ancestry_traits_character_levels
+                    USING (ancestry_trait_id)
+                JOIN character_levels
+                    USING (character_level_id)
+                WHERE character_id = $1
+                ORDER BY a_t.name
+            ",
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let player = Repository::<Player>::load(self, player_id)
+            .await?
+            .ok_or(sqlx::Error::RowNotFound)?;
+
+        let mut builder = Character::builder();
+        builder
+            .id(id)
+            .player(player)
+            .character_name(character_name)
+            .background(Background {
+                id: background_id,
+                name: background_name,
+                source,
+            });
+        for row in ancestry_traits {
+            builder.ancestry_trait(ancestry_trait(row));
+        }
+
+        Ok(Some(builder.build()?))
+    }
+
+    /// Saves the Character like [`Character::save`] does, giving them their
+    /// first Level if they have none yet and dropping Ancestry Traits left
+    /// out. The Player, Background and Ancestry Traits must already be saved.
+    async fn save(&self, value: Character) -> crate::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let id = *value.id();
+
+        sqlx::query(
+            "
+                INSERT INTO characters (character_id, name, player_id)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (character_id) DO UPDATE
+                    SET name = $2
+                    , player_id = $3
+            ",
+        )
+        .bind(id)
+        .bind(value.character_name())
+        .bind(value.player().id())
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "
+                INSERT INTO character_levels (character_level_id, character_id, level)
+                VALUES ($1, $2, 1)
+                ON CONFLICT (character_id, level) DO NOTHING
+            ",
+        )
+        .bind(Uuid::now_v7())
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+        let first_level_id: Uuid = sqlx::query_scalar(
+            "
+                SELECT character_level_id
+                FROM character_levels
+                WHERE character_id = $1
+                ORDER BY level
+                LIMIT 1
+            ",
+        )
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query("DELETE FROM backgrounds_character_levels WHERE character_level_id = $1")
+            .bind(first_level_id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query(
+            "
+                INSERT INTO backgrounds_character_levels (background_id, character_level_id)
+                VALUES ($1, $2)
+            ",
+        )
+        .bind(value.background().id)
+        .bind(first_level_id)
+        .execute(&mut *tx)
+        .await?;
+
+        let kept: Vec<Uuid> = value
+            .ancestry_traits()
+            .iter()
+            .map(|ancestry_trait| ancestry_trait.id)
+            .collect();
+
+        // Traits the Character no longer has are dropped from every Level,
+        // as loading gathers them from every Level
+        sqlx::query(
+            "
+                DELETE FROM ancestry_traits_character_levels
+                WHERE NOT (ancestry_trait_id = ANY($2))
+                    AND character_level_id IN (
+                        SELECT character_level_id
+                        FROM character_levels
+                        WHERE character_id = $1
+                    )
+            ",
+        )
+        .bind(id)
+        .bind(&kept)
+        .execute(&mut *tx)
+        .await?;
+
+        for ancestry_trait_id in kept {
+            sqlx::query(
+                "
+                    INSERT INTO ancestry_traits_character_levels
+                        (ancestry_trait_id, character_level_id)
+                    SELECT $1, $2
+                    WHERE NOT EXISTS (
+                        SELECT 1
+                        FROM ancestry_traits_character_levels
+                        JOIN character_levels
+                            USING (character_level_id)
+                        WHERE ancestry_trait_id = $1
+                            AND character_id = $3
+                    )
+                ",
+            )
+            .bind(ancestry_trait_id)
+            .bind(first_level_id)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("characters", "character_id", id).await
+    }
+}
+
+impl Repository<Level> for PostgresRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<Level>> {
+        let row: Option<(Uuid, Uuid, i64)> = sqlx::query_as(
+            "
+                SELECT character_level_id, character_id, level
+                FROM character_levels
+                WHERE character_level_id = $1
+            ",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|(id, character_id, level)| Level {
+            id,
+            character_id,
+            level: level as u32,
+        }))
+    }
+
+    async fn save(&self, value: Level) -> crate::Result<()> {
+        sqlx::query(
+            "
+                INSERT INTO character_levels (character_level_id, character_id, level)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (character_level_id) DO UPDATE
+                    SET character_id = $2
+                    , level = $3
+            ",
+        )
+        .bind(value.id)
+        .bind(value.character_id)
+        .bind(value.level as i64)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("character_levels", "character_level_id", id)
+            .await
+    }
+}
+
+impl Repository<Item> for PostgresRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<Item>> {
+        let row: Option<(Uuid, String, String, bool, f64, i64)> = sqlx::query_as(
+            "
+                SELECT item_id, name, kind, is_container, weight, price
+                FROM items
+                WHERE item_id = $1
+            ",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|(uuid, name, kind, is_container, weight, price)| {
+            Ok(Item {
+                uuid,
+                name,
+                kind: kind.parse().map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+                is_container,
+                weight,
+                price: price as u64,
+            })
+        })
+        .transpose()
+    }
+
+    async fn save(&self, value: Item) -> crate::Result<()> {
+        sqlx::query(
+            "
+                INSERT INTO items (item_id, name, kind, is_container, weight, price)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ON CONFLICT (item_id) DO UPDATE
+                    SET name = $2
+                    , kind = $3
+                    , is_container = $4
+                    , weight = $5
+                    , price = $6
+            ",
+        )
+        .bind(value.uuid)
+        .bind(value.name)
+        .bind(value.kind.to_string())
+        .bind(value.is_container)
+        .bind(value.weight)
+        .bind(value.price as i64)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("items", "item_id", id).await
+    }
+}
+
+type WeaponRow = (Uuid, String, Option<String>, Option<i64>);
+
+impl Repository<Weapon> for PostgresRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<Weapon>> {
+        let Some((uuid, weapon_type, damage_type, range_value)): Option<WeaponRow> = sqlx::query_as(
+            "SELECT weapon_id, type, damage_type, range_value FROM weapons WHERE weapon_id = $1",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let styles: Vec<(String, String, bool)> = sqlx::query_as(
+            "
+                SELECT ws.name, ws.damage_type, w_ws.multi_faceted
+                FROM weapon_styles AS ws
+                JOIN weapons_weapon_styles AS w_ws
+                    USING (weapon_style_id)
+                WHERE w_ws.weapon_id = $1
+            ",
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let property_names: Vec<String> = sqlx::query_scalar(
+            "
+                SELECT wp.name
+                FROM weapon_properties AS wp
+                JOIN weapons_weapon_properties AS w_wp
+                    USING (weapon_property_id)
+                WHERE w_wp.weapon_id = $1
+            ",
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let styles: Vec<StyleRow> = styles
+            .into_iter()
+            .map(|(name, damage_type, multi_faceted)| StyleRow {
+                name,
+                damage_type,
+                multi_faceted,
+            })
+            .collect();
+
+        Ok(Some(Weapon::from_rows(
+            uuid,
+            &weapon_type,
+            damage_type.as_deref(),
+            range_value,
+            &styles,
+            &property_names,
+        )?))
+    }
+
+    /// Saves the Weapon details of an already saved [`Item`], matching its
+    /// styles and properties by name like [`Weapon::save`] does.
+    async fn save(&self, value: Weapon) -> crate::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let known_styles: Vec<(Uuid, String)> =
+            sqlx::query_as("SELECT weapon_style_id, name FROM weapon_styles")
+                .fetch_all(&mut *tx)
+                .await?;
+        let known_properties: Vec<(Uuid, String)> =
+            sqlx::query_as("SELECT weapon_property_id, name FROM weapon_properties")
+                .fetch_all(&mut *tx)
+                .await?;
+
+        let WeaponLinks { styles, properties } = value.links(&known_styles, &known_properties)?;
+        let (weapon_type, damage_type, range_value) = value.columns();
+
+        sqlx::query(
+            "
+                INSERT INTO weapons (weapon_id, type, damage_type, range_value)
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (weapon_id) DO UPDATE
+                    SET type = $2
+                    , damage_type = $3
+                    , range_value = $4
+            ",
+        )
+        .bind(value.uuid)
+        .bind(weapon_type)
+        .bind(damage_type)
+        .bind(range_value)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query("DELETE FROM weapons_weapon_styles WHERE weapon_id = $1")
+            .bind(value.uuid)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("DELETE FROM weapons_weapon_properties WHERE weapon_id = $1")
+            .bind(value.uuid)
+            .execute(&mut *tx)
+            .await?;
+
+        for (style_id, multi_faceted) in styles {
+            sqlx::query(
+                "
+                    INSERT INTO weapons_weapon_styles (weapon_id, weapon_style_id, multi_faceted)
+                    VALUES ($1, $2, $3)
+                    ON CONFLICT (weapon_id, weapon_style_id) DO NOTHING
+                ",
+            )
+            .bind(value.uuid)
+            .bind(style_id)
+            .bind(multi_faceted)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for property_id in properties {
+            sqlx::query(
+                "
+                    INSERT INTO weapons_weapon_properties (weapon_id, weapon_property_id)
+                    VALUES ($1, $2)
+                    ON CONFLICT (weapon_id, weapon_property_id) DO NOTHING
+                ",
+            )
+            .bind(value.uuid)
+            .bind(property_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("weapons", "weapon_id", id).await
+    }
+}
+
+type ArmorRow = (Uuid, String, i64, i64, i64, i64, i64, bool);
+
+impl Repository<Armor> for PostgresRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<Armor>> {
+        let row: Option<ArmorRow> = sqlx::query_as(
+            "
+                SELECT armor_id
+                    , type
+                    , precision_defense_bonus
+                    , area_defense_bonus
+                    , precision_damage_reduction
+                    , area_damage_reduction
+                    , speed_penalty
+                    , agility_disadvantage
+                FROM armors
+                WHERE armor_id = $1
+            ",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|row| {
+            let (
+                uuid,
+                armor_type,
+                precision_defense_bonus,
+                area_defense_bonus,
+                precision_damage_reduction,
+                area_damage_reduction,
+                speed_penalty,
+                agility_disadvantage,
+            ) = row;
+
+            Ok(Armor {
+                uuid,
+                armor_type: armor_type
+                    .parse()
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+                precision_defense_bonus: precision_defense_bonus as u64,
+                area_defense_bonus: area_defense_bonus as u64,
+                precision_damage_reduction: precision_damage_reduction as u64,
+                area_damage_reduction: area_damage_reduction as u64,
+                speed_penalty: speed_penalty as u64,
+                agility_disadvantage,
+            })
+        })
+        .transpose()
+    }
+
+    async fn save(&self, value: Armor) -> crate::Result<()> {
+        sqlx::query(
+            "
+                INSERT INTO armors
+                    ( armor_id
+                    , type
+                    , precision_defense_bonus
+                    , area_defense_bonus
+                    , precision_damage_reduction
+                    , area_damage_reduction
+                    , speed_penalty
+                    , agility_disadvantage
+                    )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                ON CONFLICT (armor_id) DO UPDATE
+                    SET type = $2
+                    , precision_defense_bonus = $3
+                    , area_defense_bonus = $4
+                    , precision_damage_reduction = $5
+                    , area_damage_reduction = $6
+                    , speed_penalty = $7
+                    , agility_disadvantage = $8
+            ",
+        )
+        .bind(value.uuid)
+        .bind(value.armor_type.to_string())
+        .bind(value.precision_defense_bonus as i64)
+        .bind(value.area_defense_bonus as i64)
+        .bind(value.precision_damage_reduction as i64)
+        .bind(value.area_damage_reduction as i64)
+        .bind(value.speed_penalty as i64)
+        .bind(value.agility_disadvantage)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("armors", "armor_id", id).await
+    }
+}
+
+type ConsumableRow = (Uuid, i64, Option<String>, Option<i64>, String);
+
+impl Repository<Consumable> for PostgresRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<Consumable>> {
+        let row: Option<ConsumableRow> = sqlx::query_as(
+            "
+                SELECT consumable_id, charges, restores, amount, description
+                FROM consumables
+                WHERE consumable_id = $1
+            ",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|(uuid, charges, restores, amount, description)| {
+            let effect = match (restores, amount) {
+                (Some(resource), Some(amount)) => ConsumableEffect::Restore {
+                    resource: resource
+                        .parse()
+                        .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+                    amount: amount as u64,
+                },
+                _ => ConsumableEffect::Narrative,
+            };
+
+            Ok(Consumable {
+                uuid,
+                charges: charges as u64,
+                effect,
+                description,
+            })
+        })
+        .transpose()
+    }
+
+    async fn save(&self, value: Consumable) -> crate::Result<()> {
+        let (restores, amount) = match value.effect {
+            ConsumableEffect::Narrative => (None, None),
+            ConsumableEffect::Restore { resource, amount } => {
+                (Some(resource.to_string()), Some(amount as i64))
+            }
+        };
+
+        sqlx::query(
+            "
+                INSERT INTO consumables (consumable_id, charges, restores, amount, description)
+                VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (consumable_id) DO UPDATE
+                    SET charges = $2
+                    , restores = $3
+                    , amount = $4
+                    , description = $5
+            ",
+        )
+        .bind(value.uuid)
+        .bind(value.charges as i64)
+        .bind(restores)
+        .bind(amount)
+        .bind(value.description)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("consumables", "consumable_id", id).await
+    }
+}
+
+impl CharacterRecords<Inventory> for PostgresRepository {
+    async fn load_for(&self, character_id: Uuid) -> crate::Result<Inventory> {
+        let rows = sqlx::query(
+            "
+                SELECT character_item_id
+                    , item_id
+                    , quantity
+                    , container_id
+                    , slot
+                    , attuned
+                    , charges
+                FROM character_items
+                WHERE character_id = $1
+                ORDER BY character_item_id
+            ",
+        )
+        .bind(character_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut entries = vec![];
+        for row in rows {
+            let item = Repository::<Item>::load(self, row.try_get("item_id")?)
+                .await?
+                .ok_or(sqlx::Error::RowNotFound)?;
+
+            let mut entry = InventoryEntry {
+                id: row.try_get("character_item_id")?,
+                quantity: row.try_get::<i64, _>("quantity")? as u64,
+                container_id: row.try_get("container_id")?,
+                slot: row
+                    .try_get::<Option<String>, _>("slot")?
+                    .map(|slot| slot.parse())
+                    .transpose()
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+                attuned: row.try_get("attuned")?,
+                ..InventoryEntry::new(item.clone(), 0)
+            };
+
+            match item.kind {
+                ItemKind::Weapon => {
+                    entry.weapon = Repository::<Weapon>::load(self, item.uuid).await?
+                }
+                ItemKind::Armor => entry.armor = Repository::<Armor>::load(self, item.uuid).await?,
+                ItemKind::Consumable => {
+                    let mut consumable = Repository::<Consumable>::load(self, item.uuid)
+                        .await?
+                        .ok_or(sqlx::Error::RowNotFound)?;
+                    if let Some(charges) = row.try_get::<Option<i64>, _>("charges")? {
+                        consumable.charges = charges as u64;
+                    }
+                    entry.consumable = Some(consumable);
+                }
+                ItemKind::Gear => {}
+            }
+
+            entries.push(entry);
+        }
+
+        Ok(Inventory {
+            character_id,
+            entries,
+        })
+    }
+
+    /// Replaces the Character's stored Inventory like [`Inventory::save`]
+    /// does. The Items themselves must already be saved.
+    async fn save_for(&self, character_id: Uuid, value: Inventory) -> crate::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM character_items WHERE character_id = $1")
+            .bind(character_id)
+            .execute(&mut *tx)
+            .await?;
+
+        // containers are linked up afterwards so insertion order doesn't matter
+        for entry in &value.entries {
+            sqlx::query(
+                "
+                    INSERT INTO character_items
+                        ( character_item_id
+                        , character_id
+                        , item_id
+                        , quantity
+                        , container_id
+                        , slot
+                        , attuned
+                        , charges
+                        )
+                    VALUES ($1, $2, $3, $4, NULL, $5, $6, $7)
+                ",
+            )
+            .bind(entry.id)
+            .bind(character_id)
+            .bind(entry.item.uuid)
+            .bind(entry.quantity as i64)
+            .bind(entry.slot.map(|slot| slot.to_string()))
+            .bind(entry.attuned)
+            .bind(
+                entry
+                    .consumable
+                    .as_ref()
+                    .map(|consumable| consumable.charges as i64),
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for entry in &value.entries {
+            if let Some(container_id) = entry.container_id {
+                sqlx::query(
+                    "UPDATE character_items SET container_id = $2 WHERE character_item_id = $1",
+                )
+                .bind(entry.id)
+                .bind(container_id)
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+/// The Character's Resources, or none when they have never had them saved.
+pub(super) async fn load_resources(
+    conn: &mut PgConnection,
+    character_id: Uuid,
+) -> crate::Result<Resources> {
+    let row: Option<(i64, i64, i64, i64, i64, i64)> = sqlx::query_as(
+        "
+            SELECT hit_points
+                , max_hit_points
+                , mana_points
+                , max_mana_points
+                , stamina_points
+                , max_stamina_points
+            FROM character_resources
+            WHERE character_id = $1
+        ",
+    )
+    .bind(character_id)
+    .fetch_optional(conn)
+    .await?;
+
+    let pool = |current: i64, maximum: i64| ResourcePool {
+        current: current as u64,
+        maximum: maximum as u64,
+    };
+
+    Ok(row
+        .map(|(hp, max_hp, mp, max_mp, sp, max_sp)| Resources {
+            hit_points: pool(hp, max_hp),
+            mana_points: pool(mp, max_mp),
+            stamina_points: pool(sp, max_sp),
+        })
+        .unwrap_or_default())
+}
+
+pub(super) async fn save_resources(
+    conn: &mut PgConnection,
+    character_id: Uuid,
+    resources: Resources,
+) -> crate::Result<()> {
+    let Resources {
+        hit_points,
+        mana_points,
+        stamina_points,
+    } = resources;
+
+    sqlx::query(
+        "
+            INSERT INTO character_resources
+                ( character_id
+                , hit_points
+                , max_hit_points
+                , mana_points
+                , max_mana_points
+                , stamina_points
+                , max_stamina_points
+                )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (character_id) DO UPDATE
+                SET hit_points = $2
+                , max_hit_points = $3
+                , mana_points = $4
+                , max_mana_points = $5
+                , stamina_points = $6
+                , max_stamina_points = $7
+        ",
+    )
+    .bind(character_id)
+    .bind(hit_points.current as i64)
+    .bind(hit_points.maximum as i64)
+    .bind(mana_points.current as i64)
+    .bind(mana_points.maximum as i64)
+    .bind(stamina_points.current as i64)
+    .bind(stamina_points.maximum as i64)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// The Conditions saved against the Character, like [`Conditions::load`].
+pub(super) async fn load_conditions(
+    conn: &mut PgConnection,
+    character_id: Uuid,
+) -> crate::Result<Conditions> {
+    let names: Vec<String> = sqlx::query_scalar(
+        "SELECT name FROM character_conditions WHERE character_id = $1 ORDER BY name",
+    )
+    .bind(character_id)
+    .fetch_all(conn)
+    .await?;
+
+    names
+        .iter()
+        .map(|name| {
+            name.parse::<Condition>()
+                .map_err(|e| crate::Error::Storage(sqlx::Error::Decode(Box::new(e))))
+        })
+        .collect()
+}
+
+/// Replaces every Condition saved against the Character. Callers hold a
+/// transaction, so the Conditions are never seen half replaced.
+pub(super) async fn save_conditions(
+    conn: &mut PgConnection,
+    character_id: Uuid,
+    conditions: &Conditions,
+) -> crate::Result<()> {
+    sqlx::query("DELETE FROM character_conditions WHERE character_id = $1")
+        .bind(character_id)
+        .execute(&mut *conn)
+        .await?;
+
+    for condition in conditions.iter() {
+        sqlx::query("INSERT INTO character_conditions (character_id, name) VALUES ($1, $2)")
+            .bind(character_id)
+            .bind(condition.to_string())
+            .execute(&mut *conn)
+            .await?;
+    }
+
+    Ok(())
+}
+
+impl CharacterRecords<Resources> for PostgresRepository {
+    async fn load_for(&self, character_id: Uuid) -> crate::Result<Resources> {
+        load_resources(&mut *self.pool.acquire().await?, character_id).await
+    }
+
+    async fn save_for(&self, character_id: Uuid, value: Resources) -> crate::Result<()> {
+        save_resources(&mut *self.pool.acquire().await?, character_id, value).await
+    }
+}
+
+impl CharacterRecords<Conditions> for PostgresRepository {
+    async fn load_for(&self, character_id: Uuid) -> crate::Result<Conditions> {
+        load_conditions(&mut *self.pool.acquire().await?, character_id).await
+    }
+
+    async fn save_for(&self, character_id: Uuid, value: Conditions) -> crate::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        save_conditions(&mut tx, character_id, &value).await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+impl CharacterRecords<Purse> for PostgresRepository {
+    async fn load_for(&self, character_id: Uuid) -> crate::Result<Purse> {
+        let row: Option<(i64, i64, i64, i64)> = sqlx::query_as(
+            "
+                SELECT platinum, gold, silver, copper
+                FROM character_purses
+                WHERE character_id = $1
+            ",
+        )
+        .bind(character_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row
+            .map(|(platinum, gold, silver, copper)| Purse {
+                platinum: platinum as u64,
+                gold: gold as u64,
+                silver: silver as u64,
+                copper: copper as u64,
+            })
+            .unwrap_or_default())
+    }
+
+    async fn save_for(&self, character_id: Uuid, value: Purse) -> crate::Result<()> {
+        sqlx::query(
+            "
+                INSERT INTO character_purses (character_id, platinum, gold, silver, copper)
+                VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (character_id) DO UPDATE
+                    SET platinum = $2
+                    , gold = $3
+                    , silver = $4
+                    , copper = $5
+            ",
+        )
+        .bind(character_id)
+        .bind(value.platinum as i64)
+        .bind(value.gold as i64)
+        .bind(value.silver as i64)
+        .bind(value.copper as i64)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+type RevisionRow = (Uuid, Uuid, Option<Uuid>, String, String);
+
+fn revision((id, character_id, author, note, snapshot): RevisionRow) -> crate::Result<Revision> {
+    Ok(Revision {
+        id,
+        character_id,
+        author,
+        note,
+        snapshot: CharacterExport::parse(&snapshot)
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+    })
+}
+
+impl History for PostgresRepository {
+    async fn save_revision(&self, revision: Revision) -> crate::Result<()> {
+        let snapshot = revision
+            .snapshot
+            .to_json()
+            .map_err(|e| sqlx::Error::Encode(Box::new(e)))?;
+
+        sqlx::query(
+            "
+                INSERT INTO character_revisions
+                    (character_revision_id, character_id, player_id, note, snapshot)
+                VALUES ($1, $2, $3, $4, $5::JSONB)
+            ",
+        )
+        .bind(revision.id)
+        .bind(revision.character_id)
+        .bind(revision.author)
+        .bind(revision.note)
+        .bind(snapshot)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn load_revisions(&self, character_id: Uuid) -> crate::Result<Vec<Revision>> {
+        let rows: Vec<RevisionRow> = sqlx::query_as(
+            "
+                SELECT character_revision_id, character_id, player_id, note, snapshot::TEXT
+                FROM character_revisions
+                WHERE character_id = $1
+                ORDER BY character_revision_id
+            ",
+        )
+        .bind(character_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(revision).collect()
+    }
+
+    async fn load_latest_revision(&self, character_id: Uuid) -> crate::Result<Option<Revision>> {
+        let row: Option<RevisionRow> = sqlx::query_as(
+            "
+                SELECT character_revision_id, character_id, player_id, note, snapshot::TEXT
+                FROM character_revisions
+                WHERE character_id = $1
+                ORDER BY character_revision_id DESC
+                LIMIT 1
+            ",
+        )
+        .bind(character_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(revision).transpose()
+    }
+}
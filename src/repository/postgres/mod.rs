@@ -0,0 +1,965 @@
+//! Keeps everything in PostgreSQL, for instances shared by more Players
+//! than SQLite's single writer keeps up with.
+//!
+//! The rules content and Players are kept here; Characters and everything
+//! kept against them in `characters`, and Campaigns with their trackers
+//! in `campaigns`.
+//!
+//! The schema is the one in `migrations/pg`. Queries are checked at run time
+//! rather than by `sqlx::query!`, which only knows the SQLite database.
+
+use sqlx::{postgres::PgPool, Row};
+use uuid::Uuid;
+
+use super::{Catalog, Repository};
+use crate::{
+    dc20::{
+        Ancestry, AncestryTrait, Background, Class, Duration, Language, Maneuver, PointEnhancement,
+        Range, Skill, Source, Spell, SpellList, SpellSchool, Trade,
+    },
+    player::Player,
+};
+
+mod campaigns;
+mod characters;
+
+#[derive(Clone, Debug)]
+pub struct PostgresRepository {
+    pool: PgPool,
+}
+
+impl PostgresRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn connect(url: &str) -> crate::Result<Self> {
+        Ok(Self::new(PgPool::connect(url).await?))
+    }
+
+    /// Brings the database's schema up to date.
+    pub async fn migrate(&self) -> Result<(), sqlx::migrate::MigrateError> {
+        sqlx::migrate!("migrations/pg").run(&self.pool).await
+    }
+
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+
+    async fn delete_from(&self, table: &str, column: &str, id: Uuid) -> crate::Result<bool> {
+        let result = sqlx::query(&format!("DELETE FROM {table} WHERE {column} = $1"))
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+impl Repository<Player> for PostgresRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<Player>> {
+        let row: Option<(Uuid, String)> =
+            sqlx::query_as("SELECT player_id, name FROM players WHERE player_id = $1")
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        row.map(|(id, name)| Ok(Player::builder().id(id).name(name)?.build()?))
+            .transpose()
+    }
+
+    async fn save(&self, value: Player) -> crate::Result<()> {
+        sqlx::query(
+            "
+                INSERT INTO players (player_id, name)
+                VALUES ($1, $2)
+                ON CONFLICT (player_id) DO UPDATE
+                    SET name = $2
+            ",
+        )
+        .bind(value.id())
+        .bind(value.name())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("players", "player_id", id).await
+    }
+}
+
+impl Catalog<Player> for PostgresRepository {
+    async fn list(&self) -> crate::Result<Vec<Player>> {
+        let rows: Vec<(Uuid, String)> =
+            sqlx::query_as("SELECT player_id, name FROM players ORDER BY name")
+                .fetch_all(&self.pool)
+                .await?;
+
+        rows.into_iter()
+            .map(|(id, name)| Ok(Player::builder().id(id).name(name)?.build()?))
+            .collect()
+    }
+}
+
+impl Repository<Ancestry> for PostgresRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<Ancestry>> {
+        let row: Option<(Uuid, String)> =
+            sqlx::query_as("SELECT ancestry_id, name FROM ancestries WHERE ancestry_id = $1")
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(row.map(|(id, name)| Ancestry { id, name }))
+    }
+
+    async fn save(&self, value: Ancestry) -> crate::Result<()> {
+        sqlx::query(
+            "
+                INSERT INTO ancestries (ancestry_id, name)
+                VALUES ($1, $2)
+                ON CONFLICT (ancestry_id) DO UPDATE
+                    SET name = $2
+            ",
+        )
+        .bind(value.id)
+        .bind(value.name)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("ancestries", "ancestry_id", id).await
+    }
+}
+
+impl Catalog<Ancestry> for PostgresRepository {
+    async fn list(&self) -> crate::Result<Vec<Ancestry>> {
+        let rows: Vec<(Uuid, String)> =
+            sqlx::query_as("SELECT ancestry_id, name FROM ancestries ORDER BY name")
+                .fetch_all(&self.pool)
+                .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, name)| Ancestry { id, name })
+            .collect())
+    }
+}
+
+type AncestryTraitRow = (Uuid, String, String, i64, Option<Uuid>);
+
+fn ancestry_trait((id, name, description, cost, source): AncestryTraitRow) -> AncestryTrait {
+    AncestryTrait {
+        id,
+        name,
+        description,
+        cost: cost as i8,
+        source,
+    }
+}
+
+impl Repository<AncestryTrait> for PostgresRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<AncestryTrait>> {
+        let row: Option<AncestryTraitRow> = sqlx::query_as(
+            "
+                SELECT ancestry_trait_id, name, description, cost, source_id
+                FROM ancestry_traits
+                WHERE ancestry_trait_id = $1
+            ",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(ancestry_trait))
+    }
+
+    async fn save(&self, value: AncestryTrait) -> crate::Result<()> {
+        sqlx::query(
+            "
+                INSERT INTO ancestry_traits
+                    (ancestry_trait_id, name, description, cost, source_id)
+                VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (ancestry_trait_id) DO UPDATE
+                    SET name = $2
+                    , description = $3
+                    , cost = $4
+                    , source_id = $5
+            ",
+        )
+        .bind(value.id)
+        .bind(value.name)
+        .bind(value.description)
+        .bind(value.cost as i64)
+        .bind(value.source)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("ancestry_traits", "ancestry_trait_id", id)
+            .await
+    }
+}
+
+impl Catalog<AncestryTrait> for PostgresRepository {
+    async fn list(&self) -> crate::Result<Vec<AncestryTrait>> {
+        let rows: Vec<AncestryTraitRow> = sqlx::query_as(
+            "
+                SELECT ancestry_trait_id, name, description, cost, source_id
+                FROM ancestry_traits
+                ORDER BY name
+            ",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(ancestry_trait).collect())
+    }
+}
+
+impl Repository<Background> for PostgresRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<Background>> {
+        let row: Option<(Uuid, String, Option<Uuid>)> = sqlx::query_as(
+            "
+                SELECT background_id, name, source_id
+                FROM backgrounds
+                WHERE background_id = $1
+            ",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|(id, name, source)| Background { id, name, source }))
+    }
+
+    async fn save(&self, value: Background) -> crate::Result<()> {
+        sqlx::query(
+            "
+                INSERT INTO backgrounds (background_id, name, source_id)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (background_id) DO UPDATE
+                    SET name = $2
+                    , source_id = $3
+            ",
+        )
+        .bind(value.id)
+        .bind(value.name)
+        .bind(value.source)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("backgrounds", "background_id", id).await
+    }
+}
+
+impl Catalog<Background> for PostgresRepository {
+    async fn list(&self) -> crate::Result<Vec<Background>> {
+        let rows: Vec<(Uuid, String, Option<Uuid>)> = sqlx::query_as(
+            "
+                SELECT background_id, name, source_id
+                FROM backgrounds
+                ORDER BY name
+            ",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, name, source)| Background { id, name, source })
+            .collect())
+    }
+}
+
+impl Repository<Class> for PostgresRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<Class>> {
+        let row: Option<(Uuid, String, Option<Uuid>)> =
+            sqlx::query_as("SELECT class_id, name, source_id FROM classes WHERE class_id = $1")
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(row.map(|(id, name, source)| Class { id, name, source }))
+    }
+
+    async fn save(&self, value: Class) -> crate::Result<()> {
+        sqlx::query(
+            "
+                INSERT INTO classes (class_id, name, source_id)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (class_id) DO UPDATE
+                    SET name = $2
+                    , source_id = $3
+            ",
+        )
+        .bind(value.id)
+        .bind(value.name)
+        .bind(value.source)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("classes", "class_id", id).await
+    }
+}
+
+impl Catalog<Class> for PostgresRepository {
+    async fn list(&self) -> crate::Result<Vec<Class>> {
+        let rows: Vec<(Uuid, String, Option<Uuid>)> =
+            sqlx::query_as("SELECT class_id, name, source_id FROM classes ORDER BY name")
+                .fetch_all(&self.pool)
+                .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, name, source)| Class { id, name, source })
+            .collect())
+    }
+}
+
+impl Repository<Language> for PostgresRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<Language>> {
+        let row: Option<(Uuid, String)> =
+            sqlx::query_as("SELECT language_id, name FROM languages WHERE language_id = $1")
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(row.map(|(id, name)| Language { id, name }))
+    }
+
+    async fn save(&self, value: Language) -> crate::Result<()> {
+        sqlx::query(
+            "
+                INSERT INTO languages (language_id, name)
+                VALUES ($1, $2)
+                ON CONFLICT (language_id) DO UPDATE
+                    SET name = $2
+            ",
+        )
+        .bind(value.id)
+        .bind(value.name)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("languages", "language_id", id).await
+    }
+}
+
+impl Catalog<Language> for PostgresRepository {
+    async fn list(&self) -> crate::Result<Vec<Language>> {
+        let rows: Vec<(Uuid, String)> =
+            sqlx::query_as("SELECT language_id, name FROM languages ORDER BY name")
+                .fetch_all(&self.pool)
+                .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, name)| Language { id, name })
+            .collect())
+    }
+}
+
+type ManeuverRow = (Uuid, String, String, i64, i64, String);
+
+fn maneuver(row: ManeuverRow) -> crate::Result<Maneuver> {
+    let (uuid, name, kind, action_point_cost, stamina_point_cost, description) = row;
+
+    Ok(Maneuver {
+        uuid,
+        name,
+        kind: kind.parse().map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+        action_point_cost: action_point_cost as u64,
+        stamina_point_cost: stamina_point_cost as u64,
+        description,
+    })
+}
+
+impl Repository<Maneuver> for PostgresRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<Maneuver>> {
+        let row: Option<ManeuverRow> = sqlx::query_as(
+            "
+                SELECT maneuver_id
+                    , name
+                    , kind
+                    , action_point_cost
+                    , stamina_point_cost
+                    , description
+                FROM maneuvers
+                WHERE maneuver_id = $1
+            ",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(maneuver).transpose()
+    }
+
+    async fn save(&self, value: Maneuver) -> crate::Result<()> {
+        sqlx::query(
+            "
+                INSERT INTO maneuvers
+                    ( maneuver_id
+                    , name
+                    , kind
+                    , action_point_cost
+                    , stamina_point_cost
+                    , description
+                    )
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ON CONFLICT (maneuver_id) DO UPDATE
+                    SET name = $2
+                    , kind = $3
+                    , action_point_cost = $4
+                    , stamina_point_cost = $5
+                    , description = $6
+            ",
+        )
+        .bind(value.uuid)
+        .bind(value.name)
+        .bind(value.kind.to_string())
+        .bind(value.action_point_cost as i64)
+        .bind(value.stamina_point_cost as i64)
+        .bind(value.description)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("maneuvers", "maneuver_id", id).await
+    }
+}
+
+impl Catalog<Maneuver> for PostgresRepository {
+    async fn list(&self) -> crate::Result<Vec<Maneuver>> {
+        let rows: Vec<ManeuverRow> = sqlx::query_as(
+            "
+                SELECT maneuver_id
+                    , name
+                    , kind
+                    , action_point_cost
+                    , stamina_point_cost
+                    , description
+                FROM maneuvers
+                ORDER BY name
+            ",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(maneuver).collect()
+    }
+}
+
+impl Repository<Skill> for PostgresRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<Skill>> {
+        let row: Option<(Uuid, String, Uuid)> =
+            sqlx::query_as("SELECT skill_id, name, attribute_id FROM skills WHERE skill_id = $1")
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(row.map(|(id, name, attribute_id)| Skill {
+            id,
+            name,
+            attribute_id,
+        }))
+    }
+
+    async fn save(&self, value: Skill) -> crate::Result<()> {
+        sqlx::query(
+            "
+                INSERT INTO skills (skill_id, name, attribute_id)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (skill_id) DO UPDATE
+                    SET name = $2
+                    , attribute_id = $3
+            ",
+        )
+        .bind(value.id)
+        .bind(value.name)
+        .bind(value.attribute_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("skills", "skill_id", id).await
+    }
+}
+
+impl Catalog<Skill> for PostgresRepository {
+    async fn list(&self) -> crate::Result<Vec<Skill>> {
+        let rows: Vec<(Uuid, String, Uuid)> =
+            sqlx::query_as("SELECT skill_id, name, attribute_id FROM skills ORDER BY name")
+                .fetch_all(&self.pool)
+                .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, name, attribute_id)| Skill {
+                id,
+                name,
+                attribute_id,
+            })
+            .collect())
+    }
+}
+
+impl Repository<Source> for PostgresRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<Source>> {
+        let row: Option<(Uuid, String)> =
+            sqlx::query_as("SELECT source_id, name FROM sources WHERE source_id = $1")
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(row.map(|(id, name)| Source { id, name }))
+    }
+
+    async fn save(&self, value: Source) -> crate::Result<()> {
+        sqlx::query(
+            "
+                INSERT INTO sources (source_id, name)
+                VALUES ($1, $2)
+                ON CONFLICT (source_id) DO UPDATE
+                    SET name = $2
+            ",
+        )
+        .bind(value.id)
+        .bind(value.name)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("sources", "source_id", id).await
+    }
+}
+
+impl Catalog<Source> for PostgresRepository {
+    async fn list(&self) -> crate::Result<Vec<Source>> {
+        let rows: Vec<(Uuid, String)> =
+            sqlx::query_as("SELECT source_id, name FROM sources ORDER BY name")
+                .fetch_all(&self.pool)
+                .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, name)| Source { id, name })
+            .collect())
+    }
+}
+
+impl Repository<Spell> for PostgresRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<Spell>> {
+        let Some(row) = sqlx::query(
+            "
+                SELECT spell_id
+                    , spell.name
+                    , spell_school_id
+                    , school.name AS school_name
+                    , has_verbal
+                    , has_somatic
+                    , EXISTS (
+                        SELECT 1
+                        FROM spell_material_components
+                        WHERE spell_id = $1
+                    ) AS has_material
+                    , action_point_cost
+                    , mana_point_cost
+                    , range_kind
+                    , range_value
+                    , duration_kind
+                    , duration_value
+                    , sustained
+                    , description
+                    , spell.source_id
+                FROM spells AS spell
+                JOIN spell_schools AS school
+                    USING (spell_school_id)
+                WHERE spell_id = $1
+            ",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let point_enhancements: Vec<(Uuid, String, i64, i64, String)> = sqlx::query_as(
+            "
+                SELECT point_enhancement_id
+                    , name
+                    , action_point_cost
+                    , mana_point_cost
+                    , description
+                FROM point_enhancements
+                JOIN point_enhancements_spells
+                    USING (point_enhancement_id)
+                WHERE spell_id = $1
+            ",
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let description = match row.try_get("description")? {
+            Some(description) => description,
+            None => {
+                let effects: Vec<(String, String)> = sqlx::query_as(
+                    "
+                        SELECT name, description
+                        FROM spell_effects
+                        JOIN spells_spell_effects
+                            USING (spell_effect_id)
+                        WHERE spell_id = $1
+                    ",
+                )
+                .bind(id)
+                .fetch_all(&self.pool)
+                .await?;
+
+                let effects: Vec<String> = effects
+                    .into_iter()
+                    .map(|(name, description)| format!("{name}: {description}"))
+                    .collect();
+
+                format!(
+                    "You can produce 1 of the following effects:\n{}",
+                    effects.join("\n")
+                )
+            }
+        };
+
+        Ok(Some(Spell {
+            id: row.try_get("spell_id")?,
+            name: row.try_get("name")?,
+            school: SpellSchool {
+                id: row.try_get("spell_school_id")?,
+                name: row.try_get("school_name")?,
+            },
+            has_verbal: row.try_get("has_verbal")?,
+            has_somatic: row.try_get("has_somatic")?,
+            has_material: row.try_get("has_material")?,
+            action_point_cost: row.try_get::<i64, _>("action_point_cost")? as u64,
+            mana_point_cost: row.try_get::<i64, _>("mana_point_cost")? as u64,
+            range: Range::parse(row.try_get("range_kind")?, row.try_get("range_value")?)?,
+            duration: Duration::parse(
+                row.try_get("duration_kind")?,
+                row.try_get("duration_value")?,
+            )?,
+            sustained: row.try_get("sustained")?,
+            description,
+            point_enhancements: point_enhancements
+                .into_iter()
+                .map(
+                    |(id, name, action_point_cost, mana_point_cost, description)| {
+                        PointEnhancement {
+                            id,
+                            name,
+                            action_point_cost: action_point_cost as u64,
+                            mana_point_cost: mana_point_cost as u64,
+                            description,
+                        }
+                    },
+                )
+                .collect(),
+            source: row.try_get("source_id")?,
+        }))
+    }
+
+    /// Saves the Spell with its School and Point Enhancements, like
+    /// [`Spell::save`] does.
+    async fn save(&self, value: Spell) -> crate::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            "
+                INSERT INTO spell_schools (spell_school_id, name)
+                VALUES ($1, $2)
+                ON CONFLICT (spell_school_id) DO UPDATE
+                    SET name = $2
+            ",
+        )
+        .bind(value.school.id)
+        .bind(&value.school.name)
+        .execute(&mut *tx)
+        .await?;
+
+        let (range_kind, range_value) = value.range.columns();
+        let (duration_kind, duration_value) = value.duration.columns();
+
+        sqlx::query(
+            "
+                INSERT INTO spells
+                    ( spell_id
+                    , name
+                    , spell_school_id
+                    , has_verbal
+                    , has_somatic
+                    , action_point_cost
+                    , mana_point_cost
+                    , range_kind
+                    , range_value
+                    , duration_kind
+                    , duration_value
+                    , sustained
+                    , description
+                    , source_id
+                    )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+                ON CONFLICT (spell_id) DO UPDATE
+                    SET name = $2
+                    , spell_school_id = $3
+                    , has_verbal = $4
+                    , has_somatic = $5
+                    , action_point_cost = $6
+                    , mana_point_cost = $7
+                    , range_kind = $8
+                    , range_value = $9
+                    , duration_kind = $10
+                    , duration_value = $11
+                    , sustained = $12
+                    , description = $13
+                    , source_id = $14
+            ",
+        )
+        .bind(value.id)
+        .bind(&value.name)
+        .bind(value.school.id)
+        .bind(value.has_verbal)
+        .bind(value.has_somatic)
+        .bind(value.action_point_cost as i64)
+        .bind(value.mana_point_cost as i64)
+        .bind(range_kind)
+        .bind(range_value)
+        .bind(duration_kind)
+        .bind(duration_value)
+        .bind(value.sustained)
+        .bind(&value.description)
+        .bind(value.source)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query("DELETE FROM point_enhancements_spells WHERE spell_id = $1")
+            .bind(value.id)
+            .execute(&mut *tx)
+            .await?;
+
+        for enhancement in value.point_enhancements {
+            sqlx::query(
+                "
+                    INSERT INTO point_enhancements
+                        (point_enhancement_id, name, action_point_cost, mana_point_cost, description)
+                    VALUES ($1, $2, $3, $4, $5)
+                    ON CONFLICT (point_enhancement_id) DO UPDATE
+                        SET name = $2
+                        , action_point_cost = $3
+                        , mana_point_cost = $4
+                        , description = $5
+                ",
+            )
+            .bind(enhancement.id)
+            .bind(enhancement.name)
+            .bind(enhancement.action_point_cost as i64)
+            .bind(enhancement.mana_point_cost as i64)
+            .bind(enhancement.description)
+            .execute(&mut *tx)
+            .await?;
+
+            sqlx::query(
+                "
+                    INSERT INTO point_enhancements_spells (point_enhancement_id, spell_id)
+                    VALUES ($1, $2)
+                ",
+            )
+            .bind(enhancement.id)
+            .bind(value.id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("spells", "spell_id", id).await
+    }
+}
+
+impl Catalog<Spell> for PostgresRepository {
+    async fn list(&self) -> crate::Result<Vec<Spell>> {
+        let ids: Vec<Uuid> = sqlx::query_scalar("SELECT spell_id FROM spells ORDER BY name")
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut spells = vec![];
+
+        for id in ids {
+            spells.extend(Repository::<Spell>::load(self, id).await?);
+        }
+
+        Ok(spells)
+    }
+}
+
+impl Repository<SpellList> for PostgresRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<SpellList>> {
+        let Some(name) =
+            sqlx::query_scalar("SELECT name FROM spell_lists WHERE spell_list_id = $1")
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await?
+        else {
+            return Ok(None);
+        };
+
+        let spell_ids: Vec<Uuid> =
+            sqlx::query_scalar("SELECT spell_id FROM spells_spell_lists WHERE spell_list_id = $1")
+                .bind(id)
+                .fetch_all(&self.pool)
+                .await?;
+
+        let mut spells = vec![];
+
+        for spell_id in spell_ids {
+            spells.extend(Repository::<Spell>::load(self, spell_id).await?);
+        }
+
+        Ok(Some(SpellList { id, name, spells }))
+    }
+
+    /// Saves the Spell List and which Spells are on it. The Spells
+    /// themselves must already be saved.
+    async fn save(&self, value: SpellList) -> crate::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            "
+                INSERT INTO spell_lists (spell_list_id, name)
+                VALUES ($1, $2)
+                ON CONFLICT (spell_list_id) DO UPDATE
+                    SET name = $2
+            ",
+        )
+        .bind(value.id)
+        .bind(value.name)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query("DELETE FROM spells_spell_lists WHERE spell_list_id = $1")
+            .bind(value.id)
+            .execute(&mut *tx)
+            .await?;
+
+        for spell in value.spells {
+            sqlx::query("INSERT INTO spells_spell_lists (spell_id, spell_list_id) VALUES ($1, $2)")
+                .bind(spell.id)
+                .bind(value.id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("spell_lists", "spell_list_id", id).await
+    }
+}
+
+impl Catalog<SpellList> for PostgresRepository {
+    async fn list(&self) -> crate::Result<Vec<SpellList>> {
+        let ids: Vec<Uuid> =
+            sqlx::query_scalar("SELECT spell_list_id FROM spell_lists ORDER BY name")
+                .fetch_all(&self.pool)
+                .await?;
+
+        let mut spell_lists = vec![];
+
+        for id in ids {
+            spell_lists.extend(Repository::<SpellList>::load(self, id).await?);
+        }
+
+        Ok(spell_lists)
+    }
+}
+
+impl Repository<Trade> for PostgresRepository {
+    async fn load(&self, id: Uuid) -> crate::Result<Option<Trade>> {
+        let row: Option<(Uuid, String)> =
+            sqlx::query_as("SELECT trade_id, name FROM trades WHERE trade_id = $1")
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(row.map(|(id, name)| Trade { id, name }))
+    }
+
+    async fn save(&self, value: Trade) -> crate::Result<()> {
+        sqlx::query(
+            "
+                INSERT INTO trades (trade_id, name)
+                VALUES ($1, $2)
+                ON CONFLICT (trade_id) DO UPDATE
+                    SET name = $2
+            ",
+        )
+        .bind(value.id)
+        .bind(value.name)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<bool> {
+        self.delete_from("trades", "trade_id", id).await
+    }
+}
+
+impl Catalog<Trade> for PostgresRepository {
+    async fn list(&self) -> crate::Result<Vec<Trade>> {
+        let rows: Vec<(Uuid, String)> =
+            sqlx::query_as("SELECT trade_id, name FROM trades ORDER BY name")
+                .fetch_all(&self.pool)
+                .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, name)| Trade { id, name })
+            .collect())
+    }
+}
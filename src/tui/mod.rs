@@ -0,0 +1,386 @@
+use mellon_book::dc20::{
+    item::consumable::Resource, roll_check, AttributeName, Attributes, Character, Defense,
+    Language, Level, Resources, RollMode, SkillCheck, Spell, Trade,
+};
+use rand::Rng;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+
+mod ui;
+
+/// Everything the sheet shows, loaded once when it opens.
+#[derive(Clone, Debug)]
+pub struct Sheet {
+    pub character: Character,
+    pub level: Level,
+    pub classes: Vec<String>,
+    pub subclasses: Vec<String>,
+    pub attributes: Attributes,
+    pub precision_defense: Defense,
+    pub area_defense: Defense,
+    pub skills: Vec<SkillCheck>,
+    pub languages: Vec<Language>,
+    pub trades: Vec<Trade>,
+    pub spells: Vec<Spell>,
+    pub resources: Resources,
+}
+
+impl Sheet {
+    pub async fn load(pool: &sqlx::SqlitePool, character: Character) -> anyhow::Result<Sheet> {
+        let level = character.load_max_level(pool).await?;
+        let attributes = level.load_base_attributes(pool).await?;
+        let combat_mastery = level.calc_combat_mastery();
+
+        Ok(Sheet {
+            classes: level
+                .load_classes(pool, None)
+                .await?
+                .into_iter()
+                .map(|class| class.name)
+                .collect(),
+            subclasses: level
+                .load_sublasses(pool)
+                .await?
+                .into_iter()
+                .map(|subclass| subclass.name)
+                .collect(),
+            precision_defense: attributes.precision_defense(combat_mastery),
+            area_defense: attributes.area_defense(combat_mastery),
+            languages: character.background().load_languages(pool).await?,
+            trades: character.background().load_trades(pool).await?,
+            resources: Resources::load(pool, *character.id()).await?,
+            skills: level.load_skill_checks(pool).await?,
+            spells: level.load_known_spells(pool).await?,
+            character,
+            level,
+            attributes,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Tab {
+    #[default]
+    Overview,
+    Skills,
+    Classes,
+    Spells,
+}
+
+impl Tab {
+    pub const ALL: [Tab; 4] = [Tab::Overview, Tab::Skills, Tab::Classes, Tab::Spells];
+
+    fn index(self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|tab| *tab == self)
+            .unwrap_or_default()
+    }
+
+    fn next(self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    fn previous(self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// What the event loop should do after a key press.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Continue,
+    /// The resources changed and should be saved.
+    Save,
+    Quit,
+}
+
+const ATTRIBUTES: [AttributeName; 5] = [
+    AttributeName::Prime,
+    AttributeName::Might,
+    AttributeName::Agility,
+    AttributeName::Charisma,
+    AttributeName::Intelligence,
+];
+
+/// The sheet plus whatever the player is doing with it.
+#[derive(Clone, Debug)]
+pub struct App {
+    pub sheet: Sheet,
+    pub tab: Tab,
+    /// The highlighted row of the current tab's list.
+    pub selected: usize,
+    /// The resource `+`, `-`, `[` and `]` change.
+    pub resource: Resource,
+    pub roll_mode: RollMode,
+    /// The most recent roll first.
+    pub rolls: Vec<String>,
+}
+
+impl App {
+    #[must_use]
+    pub fn new(sheet: Sheet) -> Self {
+        Self {
+            sheet,
+            tab: Tab::default(),
+            selected: 0,
+            resource: Resource::HitPoints,
+            roll_mode: RollMode::Normal,
+            rolls: vec![],
+        }
+    }
+
+    fn rows(&self) -> usize {
+        match self.tab {
+            Tab::Overview => ATTRIBUTES.len(),
+            Tab::Skills => self.sheet.skills.len(),
+            Tab::Classes => 0,
+            Tab::Spells => self.sheet.spells.len(),
+        }
+    }
+
+    pub fn handle_key<R: Rng + ?Sized>(&mut self, key: KeyCode, rng: &mut R) -> Action {
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return Action::Quit,
+            KeyCode::Tab | KeyCode::Right => self.switch_tab(self.tab.next()),
+            KeyCode::BackTab | KeyCode::Left => self.switch_tab(self.tab.previous()),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(self.rows().saturating_sub(1));
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Char('h') => self.resource = Resource::HitPoints,
+            KeyCode::Char('m') => self.resource = Resource::ManaPoints,
+            KeyCode::Char('s') => self.resource = Resource::StaminaPoints,
+            KeyCode::Char('+') | KeyCode::Char('=') => return self.adjust(1, 0),
+            KeyCode::Char('-') => return self.adjust(-1, 0),
+            KeyCode::Char(']') => return self.adjust(0, 1),
+            KeyCode::Char('[') => return self.adjust(0, -1),
+            KeyCode::Char('a') => {
+                self.roll_mode = match self.roll_mode {
+                    RollMode::Normal => RollMode::Advantage(1),
+                    RollMode::Advantage(_) => RollMode::Disadvantage(1),
+                    RollMode::Disadvantage(_) => RollMode::Normal,
+                };
+            }
+            KeyCode::Enter | KeyCode::Char('r') => self.roll_selected(rng),
+            _ => {}
+        }
+
+        Action::Continue
+    }
+
+    fn switch_tab(&mut self, tab: Tab) {
+        self.tab = tab;
+        self.selected = 0;
+    }
+
+    fn adjust(&mut self, current: i64, maximum: i64) -> Action {
+        let pool = self.sheet.resources.get_mut(self.resource);
+        pool.adjust_maximum(maximum);
+        pool.adjust(current);
+
+        Action::Save
+    }
+
+    /// Rolls a Check for the highlighted Attribute or Skill.
+    fn roll_selected<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let (name, bonus) = match self.tab {
+            Tab::Overview => {
+                let attribute = ATTRIBUTES[self.selected.min(ATTRIBUTES.len() - 1)];
+                (attribute.to_string(), self.sheet.attributes.get(attribute))
+            }
+            Tab::Skills => match self.sheet.skills.get(self.selected) {
+                Some(check) => (
+                    check.skill.name.clone(),
+                    check.bonus(&self.sheet.attributes),
+                ),
+                None => return,
+            },
+            Tab::Classes | Tab::Spells => return,
+        };
+
+        let roll = roll_check(rng, bonus, self.roll_mode);
+
+        let mut text = format!(
+            "{name} Check: {} (d20 {:?} {:+})",
+            roll.total(),
+            roll.dice,
+            roll.bonus
+        );
+        if roll.is_critical_success() {
+            text.push_str(" Critical Success!");
+        } else if roll.is_critical_failure() {
+            text.push_str(" Critical Failure!");
+        }
+
+        self.rolls.insert(0, text);
+        self.rolls.truncate(5);
+    }
+}
+
+/// Opens the full-screen sheet, saving resource changes as they're made.
+pub async fn run(pool: &sqlx::SqlitePool, sheet: Sheet) -> anyhow::Result<()> {
+    let mut app = App::new(sheet);
+    let mut rng = rand::rng();
+    let mut terminal = ratatui::init();
+
+    let result = loop {
+        if let Err(e) = terminal.draw(|frame| ui::render(frame, &app)) {
+            break Err(e.into());
+        }
+
+        let key = match event::read() {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => key.code,
+            Ok(_) => continue,
+            Err(e) => break Err(e.into()),
+        };
+
+        match app.handle_key(key, &mut rng) {
+            Action::Continue => {}
+            Action::Save => {
+                let character_id = *app.sheet.character.id();
+                if let Err(e) = app.sheet.resources.save(pool, character_id).await {
+                    break Err(e.into());
+                }
+            }
+            Action::Quit => break Ok(()),
+        }
+    };
+
+    ratatui::restore();
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use mellon_book::{
+        dc20::{Background, Mastery, ResourcePool, Skill},
+        player::Player,
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+    use ratatui::{backend::TestBackend, Terminal};
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn sheet() -> Sheet {
+        let player = Player::builder()
+            .name("Spencer Dent")
+            .unwrap()
+            .build()
+            .unwrap();
+        let background = Background::builder()
+            .name("Human Mercenary")
+            .unwrap()
+            .build()
+            .unwrap();
+        let character = Character::builder()
+            .player(player)
+            .character_name("Cygnus")
+            .background(background)
+            .build()
+            .unwrap();
+        let attributes = Attributes {
+            prime: 3,
+            might: 0,
+            agility: 1,
+            charisma: 0,
+            intelligence: 3,
+        };
+
+        Sheet {
+            character,
+            level: Level::default(),
+            classes: vec!["Psion".into()],
+            subclasses: vec![],
+            precision_defense: attributes.precision_defense(1),
+            area_defense: attributes.area_defense(1),
+            attributes,
+            skills: vec![],
+            languages: vec![],
+            trades: vec![],
+            spells: vec![],
+            resources: Resources {
+                hit_points: ResourcePool::full(12),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn _keys_edit_the_selected_resource() {
+        let mut app = App::new(sheet());
+        let mut rng = StdRng::seed_from_u64(20);
+
+        assert_eq!(app.handle_key(KeyCode::Char('-'), &mut rng), Action::Save);
+        assert_eq!(app.sheet.resources.hit_points.current, 11);
+
+        app.handle_key(KeyCode::Char('m'), &mut rng);
+        app.handle_key(KeyCode::Char(']'), &mut rng);
+        app.handle_key(KeyCode::Char('+'), &mut rng);
+        assert_eq!(app.sheet.resources.mana_points, ResourcePool::full(1));
+
+        assert_eq!(app.handle_key(KeyCode::Char('q'), &mut rng), Action::Quit);
+    }
+
+    #[test]
+    fn _rolling_on_the_overview_checks_the_selected_attribute() {
+        let mut app = App::new(sheet());
+        let mut rng = StdRng::seed_from_u64(20);
+
+        app.handle_key(KeyCode::Down, &mut rng);
+        app.handle_key(KeyCode::Char('a'), &mut rng);
+        app.handle_key(KeyCode::Enter, &mut rng);
+
+        assert_eq!(app.roll_mode, RollMode::Advantage(1));
+        assert!(app.rolls[0].starts_with("Might Check: "));
+
+        app.handle_key(KeyCode::Tab, &mut rng);
+        assert_eq!((app.tab, app.selected), (Tab::Skills, 0));
+    }
+
+    #[test]
+    fn _skill_checks_add_the_skill_mastery() {
+        let mut sheet = sheet();
+        sheet.skills.push(SkillCheck {
+            skill: Skill {
+                id: Uuid::nil(),
+                name: "Investigation".into(),
+                attribute_id: Uuid::nil(),
+            },
+            attribute: AttributeName::Intelligence,
+            mastery: Some(Mastery {
+                id: Uuid::nil(),
+                name: "Adept".into(),
+                bonus: 4,
+            }),
+        });
+        let mut app = App::new(sheet);
+        let mut rng = StdRng::seed_from_u64(20);
+
+        app.handle_key(KeyCode::Tab, &mut rng);
+        app.handle_key(KeyCode::Enter, &mut rng);
+
+        assert!(app.rolls[0].starts_with("Investigation Check: "));
+        assert!(app.rolls[0].contains(" +7)"));
+    }
+
+    #[test]
+    fn _overview_shows_defenses_and_resources() {
+        let app = App::new(sheet());
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+
+        terminal.draw(|frame| ui::render(frame, &app)).unwrap();
+
+        let screen = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect::<String>();
+        assert!(screen.contains("Cygnus"));
+        assert!(screen.contains("PD 13"));
+        assert!(screen.contains("HP 12/12"));
+    }
+}
@@ -0,0 +1,279 @@
+use mellon_book::dc20::{item::consumable::Resource, RollMode};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Tabs, Wrap},
+    Frame,
+};
+
+use super::{App, Tab, ATTRIBUTES};
+
+const HELP: &str = "Tab switch | ↑↓ select | Enter roll | a ADV/DisADV | h/m/s resource | +/- current | [/] max | q quit";
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let [header, tabs, body, footer, help] = Layout::vertical([
+        Constraint::Length(2),
+        Constraint::Length(1),
+        Constraint::Min(5),
+        Constraint::Length(8),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let sheet = &app.sheet;
+    frame.render_widget(
+        Paragraph::new(vec![
+            Line::from(format!(
+                "{} - Level {} {}",
+                sheet.character.character_name(),
+                sheet.level.level,
+                sheet.classes.join("/")
+            ))
+            .style(Style::new().add_modifier(Modifier::BOLD)),
+            Line::from(format!(
+                "Player: {} | Background: {}",
+                sheet.character.player_name(),
+                sheet.character.background().name
+            )),
+        ]),
+        header,
+    );
+
+    frame.render_widget(
+        Tabs::new(["Overview", "Skills", "Classes", "Spells"])
+            .select(Tab::ALL.iter().position(|tab| *tab == app.tab))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED)),
+        tabs,
+    );
+
+    match app.tab {
+        Tab::Overview => render_overview(frame, app, body),
+        Tab::Skills => render_skills(frame, app, body),
+        Tab::Classes => render_classes(frame, app, body),
+        Tab::Spells => render_spells(frame, app, body),
+    }
+
+    render_footer(frame, app, footer);
+    frame.render_widget(Paragraph::new(HELP), help);
+}
+
+fn selectable_list<'a>(
+    frame: &mut Frame,
+    title: &'a str,
+    items: Vec<ListItem<'a>>,
+    selected: usize,
+    area: Rect,
+) {
+    let mut state = ListState::default().with_selected(Some(selected));
+
+    frame.render_stateful_widget(
+        List::new(items)
+            .block(Block::new().borders(Borders::ALL).title(title))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED)),
+        area,
+        &mut state,
+    );
+}
+
+fn render_overview(frame: &mut Frame, app: &App, area: Rect) {
+    let sheet = &app.sheet;
+    let [attributes, defenses] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(area);
+
+    let items = ATTRIBUTES
+        .iter()
+        .map(|attribute| {
+            ListItem::new(format!(
+                "{attribute:<12} {:+}",
+                sheet.attributes.get(*attribute)
+            ))
+        })
+        .collect();
+    selectable_list(frame, "Attributes", items, app.selected, attributes);
+
+    let defense_lines = [
+        ("PD", &sheet.precision_defense),
+        ("AD", &sheet.area_defense),
+    ]
+    .into_iter()
+    .map(|(name, defense)| {
+        Line::from(format!(
+            "{name} {} (Heavy {}, Brutal {})",
+            defense.score,
+            defense.heavy(),
+            defense.brutal()
+        ))
+    })
+    .chain([Line::from(format!(
+        "Combat Mastery +{}",
+        sheet.level.calc_combat_mastery()
+    ))])
+    .collect::<Vec<_>>();
+    frame.render_widget(
+        Paragraph::new(defense_lines).block(Block::new().borders(Borders::ALL).title("Defenses")),
+        defenses,
+    );
+}
+
+fn render_skills(frame: &mut Frame, app: &App, area: Rect) {
+    let sheet = &app.sheet;
+    let [skills, other] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(area);
+
+    let items = sheet
+        .skills
+        .iter()
+        .map(|check| {
+            let mut text = format!(
+                "{:<16} {:+} ({})",
+                check.skill.name,
+                check.bonus(&sheet.attributes),
+                check.attribute
+            );
+            if let Some(mastery) = &check.mastery {
+                text.push_str(&format!(" {}", mastery.name));
+            }
+            ListItem::new(text)
+        })
+        .collect();
+    selectable_list(frame, "Skills", items, app.selected, skills);
+
+    let names = |names: Vec<&str>| {
+        if names.is_empty() {
+            "None".to_string()
+        } else {
+            names.join(", ")
+        }
+    };
+    frame.render_widget(
+        Paragraph::new(vec![
+            Line::from(format!(
+                "Trades: {}",
+                names(
+                    sheet
+                        .trades
+                        .iter()
+                        .map(|trade| trade.name.as_str())
+                        .collect()
+                )
+            )),
+            Line::from(format!(
+                "Languages: {}",
+                names(
+                    sheet
+                        .languages
+                        .iter()
+                        .map(|language| language.name.as_str())
+                        .collect()
+                )
+            )),
+        ])
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::new()
+                .borders(Borders::ALL)
+                .title("Trades & Languages"),
+        ),
+        other,
+    );
+}
+
+fn render_classes(frame: &mut Frame, app: &App, area: Rect) {
+    let sheet = &app.sheet;
+
+    let mut lines = vec![Line::from(format!("Classes: {}", sheet.classes.join(", ")))];
+    if !sheet.subclasses.is_empty() {
+        lines.push(Line::from(format!(
+            "Subclasses: {}",
+            sheet.subclasses.join(", ")
+        )));
+    }
+
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::new().borders(Borders::ALL).title("Classes")),
+        area,
+    );
+}
+
+fn render_spells(frame: &mut Frame, app: &App, area: Rect) {
+    let sheet = &app.sheet;
+    let [spells, details] =
+        Layout::horizontal([Constraint::Percentage(35), Constraint::Percentage(65)]).areas(area);
+
+    let items = sheet
+        .spells
+        .iter()
+        .map(|spell| ListItem::new(spell.name.as_str()))
+        .collect();
+    selectable_list(frame, "Spells", items, app.selected, spells);
+
+    let text = match sheet.spells.get(app.selected) {
+        Some(spell) => {
+            format!(
+                "{} ({})\n{} AP, {} MP | Range {} | {}\n\n{}",
+                spell.name,
+                spell.school.name(),
+                spell.action_point_cost,
+                spell.mana_point_cost,
+                spell.range,
+                spell.duration,
+                spell.description
+            )
+        }
+        None => "No Spells known.".to_string(),
+    };
+
+    frame.render_widget(
+        Paragraph::new(text)
+            .wrap(Wrap { trim: false })
+            .block(Block::new().borders(Borders::ALL).title("Spell")),
+        details,
+    );
+}
+
+fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
+    let [resources, rolls] =
+        Layout::horizontal([Constraint::Percentage(35), Constraint::Percentage(65)]).areas(area);
+
+    let resource_lines = [
+        (Resource::HitPoints, "HP"),
+        (Resource::ManaPoints, "MP"),
+        (Resource::StaminaPoints, "SP"),
+    ]
+    .into_iter()
+    .map(|(resource, name)| {
+        let pool = app.sheet.resources.get(resource);
+        let line = Line::from(format!("{name} {}/{}", pool.current, pool.maximum));
+
+        if resource == app.resource {
+            line.style(Style::new().add_modifier(Modifier::BOLD | Modifier::REVERSED))
+        } else {
+            line
+        }
+    })
+    .collect::<Vec<_>>();
+    frame.render_widget(
+        Paragraph::new(resource_lines).block(Block::new().borders(Borders::ALL).title("Resources")),
+        resources,
+    );
+
+    let mode = match app.roll_mode {
+        RollMode::Normal => "Normal".to_string(),
+        RollMode::Advantage(n) => format!("ADV {n}"),
+        RollMode::Disadvantage(n) => format!("DisADV {n}"),
+    };
+    let roll_lines = app
+        .rolls
+        .iter()
+        .map(|roll| Line::from(roll.as_str()))
+        .collect::<Vec<_>>();
+    frame.render_widget(
+        Paragraph::new(roll_lines).block(
+            Block::new()
+                .borders(Borders::ALL)
+                .title(format!("Rolls ({mode})")),
+        ),
+        rolls,
+    );
+}
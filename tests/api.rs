@@ -0,0 +1,845 @@
+use axum::{
+    body::Body,
+    http::{Method, Request, StatusCode},
+    Router,
+};
+use common::rules;
+use http_body_util::BodyExt;
+use mellon_book::{api, dc20::*, player::Player};
+use serde_json::{json, Value};
+use sqlx::sqlite::SqlitePoolOptions;
+use tower::ServiceExt;
+
+mod common;
+
+/// The API over a fresh, migrated, in-memory database.
+async fn app() -> Router {
+    api::router(rules().await)
+}
+
+async fn send(app: &Router, method: Method, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+    let request = Request::builder().method(method).uri(uri);
+    let request = match body {
+        Some(body) => request
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => request.body(Body::empty()),
+    }
+    .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let value = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+
+    (status, value)
+}
+
+#[tokio::test]
+async fn _database_failures_are_not_described_to_clients() {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    let app = api::router(pool.clone());
+    pool.close().await;
+
+    let (status, body) = send(&app, Method::GET, "/players", None).await;
+
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(
+        body,
+        json!({ "error": "Something went wrong with the database" })
+    );
+}
+
+#[tokio::test]
+async fn _refused_constraints_are_not_described_to_clients() {
+    let app = app().await;
+    let source = json!({ "name": "Homebrew" });
+    let (status, _) = send(&app, Method::POST, "/sources", Some(source.clone())).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, body) = send(&app, Method::POST, "/sources", Some(source)).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        body,
+        json!({ "error": "Something the same is already saved" })
+    );
+}
+
+#[tokio::test]
+async fn _players_can_be_created_read_updated_and_deleted() {
+    let app = app().await;
+
+    let (status, player) = send(
+        &app,
+        Method::POST,
+        "/players",
+        Some(json!({ "name": "Spencer Dent" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let uri = format!("/players/{}", player["id"].as_str().unwrap());
+
+    let (status, found) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(
+        (status, &found["name"]),
+        (StatusCode::OK, &json!("Spencer Dent"))
+    );
+
+    let (status, renamed) = send(&app, Method::PUT, &uri, Some(json!({ "name": "Spencer" }))).await;
+    assert_eq!(
+        (status, &renamed["name"]),
+        (StatusCode::OK, &json!("Spencer"))
+    );
+
+    let (status, players) = send(&app, Method::GET, "/players", None).await;
+    assert_eq!(
+        (status, players.as_array().unwrap().len()),
+        (StatusCode::OK, 1)
+    );
+
+    let (status, _) = send(&app, Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, error) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(error["error"], "Not found");
+}
+
+#[tokio::test]
+async fn _invalid_bodies_are_bad_requests() {
+    let app = app().await;
+
+    let (status, error) = send(&app, Method::POST, "/players", Some(json!({ "name": "" }))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(error["error"].is_string());
+
+    let (status, _) = send(
+        &app,
+        Method::PUT,
+        "/players/01991836da1972298430f8ad85a67ee0",
+        Some(json!({ "id": "0199183e-5a1a-7d4d-a3f5-dd8e3e4d6c3b", "name": "Spencer" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+/// Saves a Player, Background and level 1 Character through the API.
+async fn character(app: &Router) -> Character {
+    let player = Player::builder()
+        .name("Spencer Dent")
+        .unwrap()
+        .build()
+        .unwrap();
+    let background = Background::builder()
+        .name("Human Mercenary")
+        .unwrap()
+        .build()
+        .unwrap();
+    send(app, Method::POST, "/players", Some(json!(player))).await;
+    let (status, _) = send(app, Method::POST, "/backgrounds", Some(json!(background))).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let character = Character::builder()
+        .player(player)
+        .character_name("Cygnus")
+        .background(background)
+        .build()
+        .unwrap();
+    let body = json!({
+        "id": character.id(),
+        "character_name": "Cygnus",
+        "player_id": character.player().id(),
+        "background_id": character.background().id,
+    });
+    let (status, created) = send(app, Method::POST, "/characters", Some(body)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(created["character_name"], "Cygnus");
+
+    character
+}
+
+#[tokio::test]
+async fn _characters_are_created_at_level_one_and_can_level_up() {
+    let app = app().await;
+    let character = character(&app).await;
+
+    let uri = format!("/characters/{}", character.id());
+    let (status, summaries) = send(&app, Method::GET, "/characters", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(summaries[0]["level"], 1);
+
+    let (status, level) = send(
+        &app,
+        Method::POST,
+        &format!("{uri}/levels"),
+        Some(json!({})),
+    )
+    .await;
+    assert_eq!((status, &level["level"]), (StatusCode::CREATED, &json!(2)));
+
+    // a Level can't be moved past the maximum or onto another Character
+    let level_uri = format!("/levels/{}", level["id"].as_str().unwrap());
+    let mut too_high = level.clone();
+    too_high["level"] = json!(99);
+    let (status, _) = send(&app, Method::PUT, &level_uri, Some(too_high)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let mut moved = level.clone();
+    moved["character_id"] = json!(uuid::Uuid::now_v7());
+    let (status, _) = send(&app, Method::PUT, &level_uri, Some(moved)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let mut taken = level.clone();
+    taken["level"] = json!(1);
+    let (status, _) = send(&app, Method::PUT, &level_uri, Some(taken)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, levels) = send(&app, Method::GET, &format!("{uri}/levels"), None).await;
+    assert_eq!(
+        (status, levels.as_array().unwrap().len()),
+        (StatusCode::OK, 2)
+    );
+
+    let (status, comparison) = send(
+        &app,
+        Method::GET,
+        &format!("{uri}/levels/compare?from=1&to=2"),
+        None,
+    )
+    .await;
+    assert_eq!((status, &comparison["to"]), (StatusCode::OK, &json!(2)));
+    let (status, first) = send(&app, Method::GET, &format!("{uri}/levels/1"), None).await;
+    assert_eq!(
+        (status, &first["stats"]["combat_mastery"]),
+        (StatusCode::OK, &json!(1))
+    );
+    let (status, chart) = send(&app, Method::GET, &format!("{uri}/progression"), None).await;
+    assert_eq!(
+        (status, chart.as_array().unwrap().len()),
+        (StatusCode::OK, 2)
+    );
+    let (status, _) = send(&app, Method::GET, &format!("{uri}/levels/9"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(&app, Method::GET, &format!("{uri}/sheet.md?level=9"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = send(&app, Method::GET, &format!("{uri}/sheet.html"), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    // only the highest Level can be given up, and never the first
+    let first_uri = format!("/levels/{}", levels[0]["id"].as_str().unwrap());
+    let (status, _) = send(&app, Method::DELETE, &first_uri, None).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _) = send(&app, Method::DELETE, &level_uri, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = send(&app, Method::DELETE, &first_uri, None).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send(&app, Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = send(&app, Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn _characters_are_replaced_from_ids() {
+    let app = app().await;
+    let character = character(&app).await;
+    let uri = format!("/characters/{}", character.id());
+
+    let body = json!({
+        "character_name": "Cygnus the Bold",
+        "player_id": character.player().id(),
+        "background_id": character.background().id,
+    });
+    let (status, replaced) = send(&app, Method::PUT, &uri, Some(body)).await;
+    assert_eq!(
+        (status, &replaced["character_name"]),
+        (StatusCode::OK, &json!("Cygnus the Bold"))
+    );
+
+    let body = json!({
+        "character_name": "Cygnus",
+        "player_id": "01991836-da19-7229-8430-f8ad85a67ee0",
+        "background_id": character.background().id,
+    });
+    let (status, _) = send(&app, Method::PUT, &uri, Some(body)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn _spells_can_be_searched() {
+    let app = app().await;
+
+    let (status, spells) = send(&app, Method::GET, "/spells?name=bolt&max_mana_cost=1", None).await;
+    assert_eq!((status, spells), (StatusCode::OK, json!([])));
+
+    let (status, _) = send(
+        &app,
+        Method::GET,
+        "/spells/01999679b6397fdf9dafaf18ce8ba4ef",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+/// Saves a Campaign run by the Character's Player, returning its id.
+async fn campaign(app: &Router, character: &Character) -> String {
+    let (status, campaign) = send(
+        app,
+        Method::POST,
+        "/campaigns",
+        Some(json!({ "name": "Starfall", "game_master_id": character.player().id() })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    campaign["id"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn _parties_summarize_characters_from_their_campaign() {
+    let app = app().await;
+    let character = character(&app).await;
+    let campaign_id = campaign(&app, &character).await;
+    let uri = format!("/campaigns/{campaign_id}");
+
+    let (status, party) = send(
+        &app,
+        Method::POST,
+        &format!("{uri}/parties"),
+        Some(json!({ "name": "The Swans" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let party_uri = format!("/parties/{}", party["id"].as_str().unwrap());
+
+    // Not in the Campaign yet
+    let member_uri = format!("{party_uri}/characters/{}", character.id());
+    let (status, _) = send(&app, Method::PUT, &member_uri, None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, _) = send(
+        &app,
+        Method::PUT,
+        &format!("{uri}/characters/{}", character.id()),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = send(&app, Method::PUT, &member_uri, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (_, characters) = send(&app, Method::GET, &format!("{uri}/characters"), None).await;
+    assert_eq!(characters[0]["level"]["level"], 1);
+
+    let (status, summary) = send(&app, Method::GET, &format!("{party_uri}/summary"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        (&summary["members"], &summary["average_level"]),
+        (&json!(1), &json!(1.0))
+    );
+
+    // Leaving the Campaign leaves the Party too
+    send(
+        &app,
+        Method::DELETE,
+        &format!("{uri}/characters/{}", character.id()),
+        None,
+    )
+    .await;
+    let (status, _) = send(&app, Method::DELETE, &member_uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn _tracker_changes_bump_the_version_and_can_be_resynced() {
+    let app = app().await;
+    let character = character(&app).await;
+    let campaign_id = campaign(&app, &character).await;
+    let uri = &format!("/campaigns/{campaign_id}/tracker");
+
+    let join = json!({ "type": "Join", "character_id": character.id() });
+    let (status, error) = send(&app, Method::POST, uri, Some(join.clone())).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(error["error"]
+        .as_str()
+        .unwrap()
+        .contains("not in the Campaign"));
+    send(
+        &app,
+        Method::PUT,
+        &format!("/campaigns/{campaign_id}/characters/{}", character.id()),
+        None,
+    )
+    .await;
+
+    let (status, error) = send(
+        &app,
+        Method::POST,
+        uri,
+        Some(json!({ "type": "SetInitiative", "character_id": character.id(), "initiative": 14 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(error["error"]
+        .as_str()
+        .unwrap()
+        .contains("not being tracked"));
+
+    let (status, update) = send(&app, Method::POST, uri, Some(join)).await;
+    assert_eq!((status, &update["version"]), (StatusCode::OK, &json!(1)));
+
+    send(
+        &app,
+        Method::POST,
+        uri,
+        Some(json!({
+            "type": "SetResource",
+            "character_id": character.id(),
+            "resource": "HitPoints",
+            "pool": { "current": 12, "maximum": 12 }
+        })),
+    )
+    .await;
+    let (_, update) = send(
+        &app,
+        Method::POST,
+        uri,
+        Some(
+            json!({ "type": "AddCondition", "character_id": character.id(), "condition": "Dazed" }),
+        ),
+    )
+    .await;
+    assert_eq!(update["version"], 3);
+    assert_eq!(update["character"]["conditions"], json!(["Dazed"]));
+
+    let (_, tracker) = send(&app, Method::GET, &format!("{uri}?since=3"), None).await;
+    assert_eq!(
+        (&tracker["version"], &tracker["characters"]),
+        (&json!(3), &json!([]))
+    );
+
+    let (_, tracker) = send(&app, Method::GET, &format!("{uri}?since=1"), None).await;
+    assert_eq!(
+        tracker["characters"][0]["resources"]["hit_points"]["current"],
+        12
+    );
+
+    let (_, tracker) = send(&app, Method::GET, uri, None).await;
+    assert_eq!(tracker["characters"][0]["conditions"], json!(["Dazed"]));
+}
+
+mod live {
+    use futures_util::{SinkExt, StreamExt};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+    use super::*;
+
+    async fn next(
+        socket: &mut tokio_tungstenite::WebSocketStream<
+            tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+        >,
+    ) -> Value {
+        loop {
+            match socket.next().await.unwrap().unwrap() {
+                Message::Text(text) => return serde_json::from_str(text.as_str()).unwrap(),
+                _ => continue,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn _changes_are_broadcast_to_everyone_at_the_table() {
+        let app = app().await;
+        let character = character(&app).await;
+
+        let campaign_id = campaign(&app, &character).await;
+        send(
+            &app,
+            Method::PUT,
+            &format!("/campaigns/{campaign_id}/characters/{}", character.id()),
+            None,
+        )
+        .await;
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        let url = format!("ws://{address}/campaigns/{campaign_id}/live");
+
+        let (mut gm, _) = connect_async(&url).await.unwrap();
+        let (mut player, _) = connect_async(&url).await.unwrap();
+        assert_eq!(next(&mut gm).await["version"], 0);
+        assert_eq!(next(&mut player).await["type"], "Sync");
+
+        for change in [
+            json!({ "type": "Join", "character_id": character.id() }),
+            json!({ "type": "SetInitiative", "character_id": character.id(), "initiative": 17 }),
+        ] {
+            player
+                .send(Message::Text(change.to_string().into()))
+                .await
+                .unwrap();
+        }
+
+        for socket in [&mut gm, &mut player] {
+            assert_eq!(next(socket).await["version"], 1);
+            let update = next(socket).await;
+            assert_eq!(update["type"], "Update");
+            assert_eq!(update["version"], 2);
+            assert_eq!(update["character"]["initiative"], 17);
+        }
+
+        gm.send(Message::Text("{\"type\":\"Flee\"}".into()))
+            .await
+            .unwrap();
+        assert_eq!(next(&mut gm).await["type"], "Error");
+
+        // Reconnecting after version 1 only needs the initiative change
+        let (mut late, _) = connect_async(format!("{url}?since=1")).await.unwrap();
+        let sync = next(&mut late).await;
+        assert_eq!(sync["version"], 2);
+        assert_eq!(sync["characters"][0]["initiative"], 17);
+    }
+}
+
+#[tokio::test]
+async fn _session_awards_make_characters_eligible_to_level_up() {
+    let app = app().await;
+    let character = character(&app).await;
+    let campaign_id = campaign(&app, &character).await;
+    let member_uri = format!("/campaigns/{campaign_id}/characters/{}", character.id());
+    let progress_uri = format!("{member_uri}/progress");
+
+    // Only Characters in the Campaign make progress there
+    let (status, _) = send(&app, Method::GET, &progress_uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    send(&app, Method::PUT, &member_uri, None).await;
+    let (status, progress) = send(&app, Method::GET, &progress_uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(progress["eligible_for_level_up"], false);
+
+    // DC20 levels by Milestone until the Campaign asks for Experience
+    let experience_uri = format!("/campaigns/{campaign_id}/experience");
+    let (_, thresholds) = send(&app, Method::GET, &experience_uri, None).await;
+    assert_eq!(thresholds, json!([]));
+    let (status, _) = send(&app, Method::PUT, &experience_uri, Some(json!([900, 300]))).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _) = send(&app, Method::PUT, &experience_uri, Some(json!([300, 900]))).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, session) = send(
+        &app,
+        Method::POST,
+        &format!("/campaigns/{campaign_id}/sessions"),
+        Some(json!({
+            "id": "0199f3c2-1a2b-7c3d-8e4f-5a6b7c8d9e0f",
+            "campaign_id": campaign_id,
+            "played_on": "2026-10-17",
+            "title": "The Drowned Vault",
+            "attendees": [character.id()],
+            "loot": [{ "description": "Healing Potion", "quantity": 2 }],
+            "awards": [
+                { "character_id": character.id(), "award": { "Experience": 300 } },
+                { "character_id": character.id(), "award": "Milestone", "note": "Opened the vault" }
+            ]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(session["awards"][1]["note"], "Opened the vault");
+
+    let (_, progress) = send(&app, Method::GET, &progress_uri, None).await;
+    assert_eq!(
+        (&progress["experience"], &progress["earned_level"]),
+        (&json!(300), &json!(3))
+    );
+    assert_eq!(progress["eligible_for_level_up"], true);
+
+    // Awards from another Campaign don't count towards this one
+    let other_campaign_id = campaign(&app, &character).await;
+    send(
+        &app,
+        Method::PUT,
+        &format!(
+            "/campaigns/{other_campaign_id}/characters/{}",
+            character.id()
+        ),
+        None,
+    )
+    .await;
+    let (_, progress) = send(
+        &app,
+        Method::GET,
+        &format!(
+            "/campaigns/{other_campaign_id}/characters/{}/progress",
+            character.id()
+        ),
+        None,
+    )
+    .await;
+    assert_eq!(
+        (&progress["experience"], &progress["earned_level"]),
+        (&json!(0), &json!(1))
+    );
+
+    for _ in 0..2 {
+        send(
+            &app,
+            Method::POST,
+            &format!("/characters/{}/levels", character.id()),
+            Some(json!({})),
+        )
+        .await;
+    }
+    let (_, progress) = send(&app, Method::GET, &progress_uri, None).await;
+    assert_eq!(progress["eligible_for_level_up"], false);
+
+    let request = Request::builder()
+        .uri(format!("/campaigns/{campaign_id}/journal.md"))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let markdown = String::from_utf8(bytes.to_vec()).unwrap();
+    assert!(markdown.starts_with("# Starfall Journal\n"));
+    assert!(markdown.contains("## 2026-10-17: The Drowned Vault"));
+    assert!(markdown.contains("- Cygnus: 300 XP"));
+}
+
+#[tokio::test]
+async fn _exported_characters_can_be_imported_at_another_table() {
+    // The other table has none of the content, so it comes from the export
+    let other = app().await;
+    let app = app().await;
+    let character = character(&app).await;
+    let (status, _) = send(
+        &app,
+        Method::POST,
+        "/classes",
+        Some(json!({ "id": "0199f3c2-2b3c-7d4e-9f50-6b7c8d9e0f1a", "name": "Barbarian" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    send(
+        &app,
+        Method::POST,
+        &format!("/characters/{}/levels", character.id()),
+        Some(json!({ "class_id": "0199f3c2-2b3c-7d4e-9f50-6b7c8d9e0f1a" })),
+    )
+    .await;
+
+    let (status, export) = send(
+        &app,
+        Method::GET,
+        &format!("/characters/{}/export", character.id()),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(export["version"], 2);
+    assert_eq!(export["levels"][1]["classes"][0]["name"], "Barbarian");
+
+    let (status, imported) = send(
+        &other,
+        Method::POST,
+        "/characters/import",
+        Some(export.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(imported["id"], json!(character.id()));
+    assert_eq!(imported["background"]["name"], "Human Mercenary");
+
+    let (_, levels) = send(
+        &other,
+        Method::GET,
+        &format!("/characters/{}/levels", character.id()),
+        None,
+    )
+    .await;
+    assert_eq!(levels.as_array().unwrap().len(), 2);
+
+    let (status, _) = send(
+        &other,
+        Method::POST,
+        "/characters/import",
+        Some(export.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, copy) = send(
+        &other,
+        Method::POST,
+        "/characters/import?ids=Remap",
+        Some(export.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_ne!(copy["id"], json!(character.id()));
+
+    let mut export = export;
+    export["levels"][0]["backgrounds"] =
+        json!([{ "content": "reference", "id": "0199f3c2-3c4d-7e5f-a061-7c8d9e0f1a2b" }]);
+    let (status, _) = send(
+        &other,
+        Method::POST,
+        "/characters/import?ids=Remap",
+        Some(export),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn _content_packs_are_installed_and_toggled_per_campaign() {
+    let app = app().await;
+    let character = character(&app).await;
+    let campaign = campaign(&app, &character).await;
+
+    let pack = json!({
+        "manifest": {
+            "id": "0199f5c2-7a10-7000-8000-000000000001",
+            "name": "Tome of Tides",
+            "version": "1.0.0",
+            "author": "Spencer Dent"
+        },
+        "content": {
+            "classes": [{ "id": "0199f5c2-7a10-7000-8000-000000000020", "name": "Tidecaller" }]
+        }
+    });
+    let (status, installed) = send(&app, Method::POST, "/packs", Some(pack.clone())).await;
+    assert_eq!((status, &installed["added"]), (StatusCode::OK, &json!(1)));
+
+    let uri = format!("/classes?campaign={campaign}");
+    let (_, classes) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(classes, json!([]));
+
+    let toggle = format!("/campaigns/{campaign}/packs/0199f5c2-7a10-7000-8000-000000000001");
+    let (status, _) = send(&app, Method::PUT, &toggle, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, classes) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(classes[0]["name"], "Tidecaller");
+
+    // Another pack with the same Class is refused
+    let mut rival = pack;
+    rival["manifest"]["id"] = json!("0199f5c2-7a10-7000-8000-000000000002");
+    let (status, error) = send(&app, Method::POST, "/packs", Some(rival)).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert!(error["error"].as_str().unwrap().contains("belongs to pack"));
+
+    let (status, _) = send(
+        &app,
+        Method::DELETE,
+        "/packs/0199f5c2-7a10-7000-8000-000000000001",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, classes) = send(&app, Method::GET, "/classes", None).await;
+    assert_eq!(classes, json!([]));
+    let (_, packs) = send(
+        &app,
+        Method::GET,
+        &format!("/campaigns/{campaign}/packs"),
+        None,
+    )
+    .await;
+    assert_eq!(packs, json!([]));
+}
+
+#[tokio::test]
+async fn _campaigns_only_list_classes_from_sources_they_allow() {
+    let app = app().await;
+    let character = character(&app).await;
+    let campaign = campaign(&app, &character).await;
+
+    let core = json!({ "name": "DC20 Core Rules" });
+    let (status, core) = send(&app, Method::POST, "/sources", Some(core)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let core = core["id"].as_str().unwrap().to_owned();
+
+    let wizard = json!({ "name": "Wizard", "source": core });
+    let (status, wizard) = send(&app, Method::POST, "/classes", Some(wizard)).await;
+    assert_eq!(
+        (status, &wizard["source"]),
+        (StatusCode::CREATED, &json!(core))
+    );
+    let homebrew = json!({ "name": "Tidecaller" });
+    send(&app, Method::POST, "/classes", Some(homebrew)).await;
+
+    let uri = format!("/classes?campaign={campaign}");
+    let (_, classes) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(classes.as_array().unwrap().len(), 2);
+
+    let allow = format!("/campaigns/{campaign}/sources/{core}");
+    let (status, _) = send(&app, Method::PUT, &allow, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, sources) = send(
+        &app,
+        Method::GET,
+        &format!("/campaigns/{campaign}/sources"),
+        None,
+    )
+    .await;
+    assert_eq!(sources[0]["name"], "DC20 Core Rules");
+    let (_, classes) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(classes, json!([wizard]));
+
+    let (status, _) = send(&app, Method::DELETE, &format!("/sources/{core}"), None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, classes) = send(&app, Method::GET, &uri, None).await;
+    assert_eq!(classes.as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn _levelling_up_is_recorded_and_can_be_undone() {
+    let app = app().await;
+    let character = character(&app).await;
+    let uri = format!("/characters/{}", character.id());
+
+    send(
+        &app,
+        Method::POST,
+        &format!("{uri}/levels"),
+        Some(json!({})),
+    )
+    .await;
+    let (status, history) = send(&app, Method::GET, &format!("{uri}/history"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(history[0]["note"], "Created");
+    assert_eq!(history[1]["note"], "Reached Level 2");
+
+    let created = history[0]["revision"].as_str().unwrap();
+    let diff = format!("{uri}/history/diff?from={created}");
+    let (_, changes) = send(&app, Method::GET, &diff, None).await;
+    assert_eq!(changes.as_array().unwrap().len(), 1);
+    assert_eq!(changes[0]["before"], Value::Null);
+    assert_eq!(changes[0]["after"]["level"], 2);
+
+    let restore = format!("{uri}/history/{created}/restore");
+    let (status, _) = send(&app, Method::POST, &restore, None).await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, levels) = send(&app, Method::GET, &format!("{uri}/levels"), None).await;
+    assert_eq!(levels.as_array().unwrap().len(), 1);
+
+    let (status, _) = send(
+        &app,
+        Method::GET,
+        &format!("{uri}/history/diff?from=then"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let unknown = format!("{uri}/history/{}/restore", uuid::Uuid::now_v7());
+    let (status, _) = send(&app, Method::POST, &unknown, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -1,6 +1,10 @@
-use std::error::Error;
+use std::{collections::HashSet, error::Error};
 
-use mellon_book::{dc20::*, player::Player};
+use common::core;
+use mellon_book::{dc20::*, export::sheet::CharacterSheet, player::Player};
+use uuid::Uuid;
+
+mod common;
 
 #[test]
 fn _built_character_should_have_name() -> Result<(), Box<dyn Error>> {
@@ -16,3 +20,181 @@ fn _built_character_should_have_name() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn _a_new_character_starts_with_their_class_equipment() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    let player = Player::builder().name("Test Player")?.build()?;
+    player.clone().save(&pool).await?;
+    let character = Character::builder()
+        .player(player)
+        .character_name("Test Name")
+        .background(Background::load_by_name(&pool, "Acolyte", None).await?)
+        .build()?;
+    let barbarian = Class::load_by_name(&pool, "Barbarian", None).await?;
+
+    let first = character
+        .clone()
+        .create(&pool, Some(&barbarian), &Attributes::default())
+        .await?;
+
+    let classes = first.load_classes(&pool, None).await?;
+    assert_eq!(classes.len(), 1);
+    assert_eq!(classes[0].name, "Barbarian");
+    let inventory = character.load_inventory(&pool).await?;
+    let carried: Vec<(&str, u64)> = inventory
+        .entries
+        .iter()
+        .map(|entry| (entry.item.name.as_str(), entry.quantity))
+        .collect();
+    assert!(carried.contains(&("Greataxe", 1)));
+    assert!(carried.contains(&("Backpack", 1)));
+    assert!(carried.contains(&("Torch", 3)));
+
+    let table = barbarian.load_table(&pool).await?;
+    let resources = Resources::load(&pool, *character.id()).await?;
+    assert_eq!(
+        resources.hit_points,
+        ResourcePool::full(table.health_points.get(1) as u64)
+    );
+    assert_eq!(
+        resources.stamina_points,
+        ResourcePool::full(table.stamina_points.get(1) as u64)
+    );
+
+    let skills = first.load_skill_checks(&pool).await?;
+    assert_eq!(skills.len(), Skill::list(&pool).await?.len());
+    assert!(skills.iter().all(|check| check.mastery.is_none()));
+
+    let athletics = skills
+        .iter()
+        .find(|check| check.skill.name == "Athletics")
+        .expect("Athletics to be a Skill");
+    let adept: Uuid =
+        sqlx::query_scalar("SELECT `mastery_id` FROM `masteries` WHERE `name` = 'Adept'")
+            .fetch_one(&pool)
+            .await?;
+    first
+        .save_skill_mastery(&pool, &athletics.skill, &Mastery::load(&pool, adept).await?)
+        .await?;
+    let athletics = first
+        .load_skill_checks(&pool)
+        .await?
+        .into_iter()
+        .find(|check| check.skill.name == "Athletics")
+        .expect("Athletics to be a Skill");
+    assert_eq!(athletics.bonus(&Attributes::default()), 4, "Adept adds 4");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _ancestry_traits_left_out_are_dropped_when_saved() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    let player = Player::builder().name("Test Player")?.build()?;
+    player.clone().save(&pool).await?;
+    let background = Background::load_by_name(&pool, "Acolyte", None).await?;
+    let traits = AncestryTrait::list(&pool).await?;
+    let (kept, dropped) = (traits[0].clone(), traits[1].clone());
+    let character = Character::builder()
+        .player(player.clone())
+        .character_name("Test Name")
+        .background(background.clone())
+        .ancestry_trait(kept.clone())
+        .ancestry_trait(dropped)
+        .build()?;
+    let id = *character.id();
+    character.save(&pool).await?;
+    assert_eq!(Character::load(&pool, id).await?.ancestry_traits().len(), 2);
+
+    Character::builder()
+        .id(id)
+        .player(player)
+        .character_name("Test Name")
+        .background(background)
+        .ancestry_trait(kept.clone())
+        .build()?
+        .save(&pool)
+        .await?;
+
+    assert_eq!(Character::load(&pool, id).await?.ancestry_traits(), [kept]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _the_sheet_is_worked_out_from_what_is_saved() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    let player = Player::builder().name("Test Player")?.build()?;
+    player.clone().save(&pool).await?;
+    let character = Character::builder()
+        .player(player)
+        .character_name("Test Name")
+        .background(Background::load_by_name(&pool, "Acolyte", None).await?)
+        .build()?;
+    let barbarian = Class::load_by_name(&pool, "Barbarian", None).await?;
+    let attributes = Attributes {
+        might: 3,
+        ..Default::default()
+    };
+    let first = character
+        .clone()
+        .create(&pool, Some(&barbarian), &attributes)
+        .await?;
+
+    let adept: Uuid =
+        sqlx::query_scalar("SELECT `mastery_id` FROM `masteries` WHERE `name` = 'Adept'")
+            .fetch_one(&pool)
+            .await?;
+    let athletics = Skill::list(&pool)
+        .await?
+        .into_iter()
+        .find(|skill| skill.name == "Athletics")
+        .expect("Athletics to be a Skill");
+    first
+        .save_skill_mastery(&pool, &athletics, &Mastery::load(&pool, adept).await?)
+        .await?;
+
+    // Only Spells on the Character's Spell Lists can be learned
+    let frost_bolt = Spell::load_by_name(&pool, "Frost Bolt", None).await?;
+    assert!(matches!(
+        first.learn_spell(&pool, &frost_bolt).await,
+        Err(mellon_book::Error::RuleViolation(_))
+    ));
+    let arcane: Uuid =
+        sqlx::query_scalar("SELECT `spell_list_id` FROM `spell_lists` WHERE `name` = 'Arcane'")
+            .fetch_one(&pool)
+            .await?;
+    let arcane = SpellList::load(&pool, arcane).await?;
+    first.save_spell_list(&pool, &arcane).await?;
+    first.learn_spell(&pool, &frost_bolt).await?;
+
+    let sheet = CharacterSheet::load(&pool, &character, &first).await?;
+
+    assert_eq!(sheet.skills.len(), Skill::list(&pool).await?.len());
+    let athletics = sheet
+        .skills
+        .iter()
+        .find(|skill| skill.name == "Athletics")
+        .expect("Athletics on the sheet");
+    assert_eq!(athletics.bonus, 3 + 4, "Might plus Adept");
+    let spells: Vec<&str> = sheet
+        .spells
+        .iter()
+        .map(|spell| spell.name.as_str())
+        .collect();
+    assert_eq!(spells, ["Frost Bolt"]);
+    let masteries: HashSet<AttributeName> = sheet
+        .attributes
+        .iter()
+        .filter(|attribute| attribute.save_mastery)
+        .map(|attribute| attribute.name)
+        .collect();
+    assert_eq!(
+        masteries,
+        HashSet::from_iter(barbarian.load_save_masteries(&pool).await?)
+    );
+    assert!(!masteries.is_empty());
+
+    Ok(())
+}
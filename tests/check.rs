@@ -0,0 +1,194 @@
+use std::error::Error;
+
+use common::core;
+use mellon_book::check::{self, Problem};
+use sqlx::SqlitePool;
+use uuid::{uuid, Uuid};
+
+mod common;
+
+const DESTRUCTION: Uuid = uuid!("01999676-d58d-7840-b029-51505eb57504");
+
+/// Sets the value of each base Attribute, by name, for the Level.
+async fn attributes(pool: &SqlitePool, level_id: Uuid, values: &[(&str, i64)]) {
+    for (name, value) in values {
+        sqlx::query(
+            "
+                INSERT INTO `character_level_base_attribute_values`
+                SELECT ?1, `attribute_id`, ?3
+                FROM `attributes`
+                WHERE `name` = ?2
+            ",
+        )
+        .bind(level_id)
+        .bind(name)
+        .bind(value)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn _the_core_rules_have_no_problems() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+
+    assert_eq!(check::check(&pool).await?, []);
+    assert_eq!(check::repair(&pool).await?, Default::default());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _problems_are_found_and_the_safe_ones_repaired() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    let (player, character) = (Uuid::now_v7(), Uuid::now_v7());
+    let (first, third) = (Uuid::now_v7(), Uuid::now_v7());
+    let (homebrew, spell) = (Uuid::now_v7(), Uuid::now_v7());
+
+    sqlx::query("INSERT INTO `players` VALUES (?1, 'Spencer Dent')")
+        .bind(player)
+        .execute(&pool)
+        .await?;
+    sqlx::query("INSERT INTO `characters` VALUES (?1, 'Cygnus', ?2)")
+        .bind(character)
+        .bind(player)
+        .execute(&pool)
+        .await?;
+    sqlx::query("INSERT INTO `character_levels` VALUES (?1, ?3, 1), (?2, ?3, 3)")
+        .bind(first)
+        .bind(third)
+        .bind(character)
+        .execute(&pool)
+        .await?;
+    attributes(
+        &pool,
+        first,
+        &[
+            ("Prime", 3),
+            ("Might", 3),
+            ("Agility", 1),
+            ("Charisma", 0),
+            ("Intelligence", -1),
+        ],
+    )
+    .await;
+    attributes(&pool, third, &[("Prime", 4), ("Might", 4)]).await;
+
+    // What an older, unenforced database might have let in
+    let mut conn = pool.acquire().await?;
+    sqlx::query("PRAGMA foreign_keys = OFF")
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query("PRAGMA ignore_check_constraints = ON")
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query("INSERT INTO `character_levels_classes` VALUES (?1, ?2)")
+        .bind(first)
+        .bind(Uuid::now_v7())
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query("INSERT INTO `classes` VALUES (?1, 'Homebrew Knight', ?2)")
+        .bind(homebrew)
+        .bind(Uuid::now_v7())
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query(
+        "
+            INSERT INTO `spells`
+                (`spell_id`, `name`, `spell_school_id`, `range_kind`, `duration_kind`)
+            VALUES (?1, 'Everburn', ?2, 'Self', 'Forever')
+        ",
+    )
+    .bind(spell)
+    .bind(DESTRUCTION)
+    .execute(&mut *conn)
+    .await?;
+    sqlx::query("PRAGMA ignore_check_constraints = OFF")
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query("PRAGMA foreign_keys = ON")
+        .execute(&mut *conn)
+        .await?;
+    drop(conn);
+
+    let orphaned_class = Problem::Orphans {
+        table: "character_levels_classes".into(),
+        column: "class_id".into(),
+        parent: "classes".into(),
+        rows: 1,
+        on_delete: "CASCADE".into(),
+    };
+    let orphaned_source = Problem::Orphans {
+        table: "classes".into(),
+        column: "source_id".into(),
+        parent: "sources".into(),
+        rows: 1,
+        on_delete: "SET NULL".into(),
+    };
+    let missing = Problem::MissingAttributes {
+        level_id: third,
+        character_id: character,
+        level: 3,
+        missing: vec!["Agility".into(), "Charisma".into(), "Intelligence".into()],
+    };
+    let unreadable = Problem::UnreadableDuration {
+        spell_id: spell,
+        spell: "Everburn".into(),
+        kind: "Forever".into(),
+        value: None,
+    };
+    let no_subclasses = Problem::NoSubclasses {
+        class_id: homebrew,
+        class: "Homebrew Knight".into(),
+    };
+    let gap = Problem::LevelGap {
+        character_id: character,
+        character: "Cygnus".into(),
+        missing: vec![2],
+    };
+
+    let problems = check::check(&pool).await?;
+    assert_eq!(
+        problems,
+        [
+            orphaned_class.clone(),
+            orphaned_source.clone(),
+            missing.clone(),
+            unreadable.clone(),
+            no_subclasses.clone(),
+            gap.clone(),
+        ]
+    );
+    assert_eq!(
+        problems
+            .iter()
+            .filter(|problem| problem.can_repair())
+            .count(),
+        3
+    );
+    assert_eq!(gap.to_string(), "Character `Cygnus` has no Level 2");
+
+    let repair = check::repair(&pool).await?;
+    assert_eq!(repair.repaired, [orphaned_class, orphaned_source, missing]);
+    assert_eq!(repair.remaining, [unreadable, no_subclasses, gap]);
+    assert_eq!(check::check(&pool).await?, repair.remaining);
+
+    // The third Level has its Attributes back, carried on from the first
+    let (might, agility): (i64, i64) = sqlx::query_as(
+        "SELECT `might`, `agility` FROM `character_level_attributes` WHERE `character_level_id` = ?1",
+    )
+    .bind(third)
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!((might, agility), (4, 1));
+
+    let source: Option<Uuid> =
+        sqlx::query_scalar("SELECT `source_id` FROM `classes` WHERE `class_id` = ?1")
+            .bind(homebrew)
+            .fetch_one(&pool)
+            .await?;
+    assert_eq!(source, None);
+
+    Ok(())
+}
@@ -0,0 +1,186 @@
+//! Fixtures shared by the integration tests. Each test binary only uses
+//! some of them.
+#![allow(dead_code)]
+
+use std::{error::Error, path::Path};
+
+use mellon_book::{
+    db::OpenOptions,
+    dc20::{
+        item::{
+            armor::{Armor, ArmorType},
+            weapon::{WeaponBuilder, WeaponProperty, WeaponStyle},
+        },
+        *,
+    },
+    export::sheet::{CharacterSheet, SkillBonus},
+    player::Player,
+};
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+use uuid::Uuid;
+
+/// A fresh in-memory database with nothing but the core rules in it.
+pub async fn core() -> SqlitePool {
+    let pool = OpenOptions::new()
+        .core_content(true)
+        .open_url(&format!(
+            "sqlite:file:core-{}?mode=memory&cache=shared",
+            Uuid::now_v7()
+        ))
+        .await
+        .unwrap();
+    let broken: Vec<(String,)> = sqlx::query_as("PRAGMA foreign_key_check")
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+    assert!(broken.is_empty(), "{broken:?}");
+
+    pool
+}
+
+/// A fresh, migrated, in-memory database with nothing in it.
+pub async fn empty() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::migrate!().run(&pool).await.unwrap();
+
+    pool
+}
+
+/// A fresh, migrated, in-memory database with just enough of the rules for
+/// Characters to have Attributes, Skills and Weapons.
+pub async fn rules() -> SqlitePool {
+    let pool = empty().await;
+    sqlx::raw_sql(include_str!("../fixtures/rules.sql"))
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    pool
+}
+
+fn item(name: &str, weight: f64) -> Item {
+    Item {
+        uuid: Uuid::now_v7(),
+        name: name.into(),
+        weight,
+        ..Default::default()
+    }
+}
+
+/// Cygnus at Level 3, built in memory so no database is needed.
+pub fn sheet() -> Result<CharacterSheet, Box<dyn Error>> {
+    let character = Character::builder()
+        .player(Player::builder().name("Spencer Dent")?.build()?)
+        .character_name("Cygnus")
+        .background(Background::builder().name("Human Mercenary")?.build()?)
+        .build()?;
+
+    let mut inventory = Inventory::new(*character.id());
+    let sword = inventory.add_entry(
+        InventoryEntry::new(item("Longsword", 3.0), 1).with_weapon(
+            WeaponBuilder::new_melee()
+                .style(WeaponStyle::Sword)?
+                .add_properties(&[WeaponProperty::Guard])?
+                .build()?,
+        ),
+    );
+    let mail = inventory.add_entry(InventoryEntry::new(item("Chain Mail", 20.0), 1).with_armor(
+        Armor {
+            armor_type: ArmorType::HeavyArmor,
+            precision_defense_bonus: 2,
+            area_defense_bonus: 1,
+            precision_damage_reduction: 1,
+            speed_penalty: 1,
+            ..Default::default()
+        },
+    ));
+    inventory.add(item("Torch", 1.0), 3);
+    inventory.equip(sword, EquipmentSlot::MainHand)?;
+    inventory.equip(mail, EquipmentSlot::Armor)?;
+
+    let level = Level {
+        level: 3,
+        ..Default::default()
+    };
+    let attributes = Attributes {
+        prime: 3,
+        might: 3,
+        agility: 1,
+        charisma: 0,
+        intelligence: -1,
+    };
+    let combatant = CharacterCombatant::new("Cygnus", level, attributes, inventory);
+
+    let mut sheet = CharacterSheet::new(
+        &character,
+        &combatant,
+        &[AttributeName::Might, AttributeName::Agility],
+    );
+    sheet.ancestries = vec!["Human".into()];
+    sheet.classes = vec!["Barbarian".into()];
+    sheet.resources = Resources {
+        hit_points: ResourcePool {
+            current: 9,
+            maximum: 12,
+        },
+        stamina_points: ResourcePool::full(2),
+        mana_points: ResourcePool::default(),
+    };
+    sheet.skills = vec![SkillBonus {
+        name: "Athletics".into(),
+        attribute: AttributeName::Might,
+        bonus: 3,
+    }];
+    sheet.languages = vec!["Common".into(), "Dwarvish".into()];
+    sheet.maneuvers = vec![Maneuver {
+        uuid: Uuid::now_v7(),
+        name: "Power Attack".into(),
+        kind: ManeuverKind::Attack,
+        action_point_cost: 1,
+        stamina_point_cost: 1,
+        description: "Deal +1 damage.".into(),
+    }];
+    sheet.spells = vec![serde_json::from_value(serde_json::json!({
+        "id": Uuid::now_v7(),
+        "name": "Fire Bolt",
+        "school": { "id": Uuid::now_v7(), "name": "Destruction" },
+        "has_verbal": true,
+        "has_somatic": true,
+        "has_material": false,
+        "action_point_cost": 1,
+        "mana_point_cost": 0,
+        "range": { "Spaces": 15 },
+        "duration": "Instant",
+        "sustained": false,
+        "description": "Hurl a mote of fire <burning> the target.",
+        "point_enhancements": [{
+            "id": Uuid::now_v7(),
+            "name": "Fire & Fury",
+            "action_point_cost": 0,
+            "mana_point_cost": 1,
+            "description": "+1 damage."
+        }]
+    }))?];
+
+    Ok(sheet)
+}
+
+/// Compares against the golden file, or rewrites it when `UPDATE_GOLDEN` is set.
+pub fn assert_golden(name: &str, actual: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(name);
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::write(&path, actual).unwrap();
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(actual, expected, "{name} no longer matches its golden file");
+}
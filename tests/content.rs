@@ -0,0 +1,195 @@
+use std::error::Error;
+
+use common::core;
+use mellon_book::{db, dc20::*};
+use sqlx::SqlitePool;
+
+mod common;
+
+/// How many rows of `table` have no matching row through `join`.
+async fn unjoined(pool: &SqlitePool, table: &str, key: &str, join: &str) -> i64 {
+    sqlx::query_scalar(&format!(
+        "SELECT count(*) FROM `{table}` WHERE `{key}` NOT IN (SELECT `{key}` FROM `{join}`)"
+    ))
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn _loading_the_core_rules_twice_changes_nothing() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    let counts = || async {
+        let mut counts = Vec::new();
+        for table in [
+            "ancestry_traits",
+            "class_features",
+            "spells",
+            "maneuvers",
+            "weapons",
+        ] {
+            let count: i64 = sqlx::query_scalar(&format!("SELECT count(*) FROM `{table}`"))
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+            counts.push(count);
+        }
+        counts
+    };
+
+    let before = counts().await;
+    db::load_core_content(&pool).await?;
+    assert_eq!(counts().await, before);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _every_ancestry_has_traits_and_every_trait_an_ancestry() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    assert!(Ancestry::list(&pool).await?.len() > 2);
+
+    assert_eq!(
+        unjoined(
+            &pool,
+            "ancestries",
+            "ancestry_id",
+            "ancestries_ancestry_traits"
+        )
+        .await,
+        0
+    );
+    assert_eq!(
+        unjoined(
+            &pool,
+            "ancestry_traits",
+            "ancestry_trait_id",
+            "ancestries_ancestry_traits"
+        )
+        .await,
+        0
+    );
+
+    let expanded: i64 =
+        sqlx::query_scalar("SELECT count(*) FROM `ancestries_ancestry_traits` WHERE `expanded`")
+            .fetch_one(&pool)
+            .await?;
+    assert!(expanded > 0);
+
+    let core = Source::load_by_name(&pool, "DC20 Core Rules").await?;
+    for ancestry_trait in AncestryTrait::list(&pool).await? {
+        assert_eq!(
+            ancestry_trait.source,
+            Some(core.id),
+            "{}",
+            ancestry_trait.name
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _every_background_grants_skills_trades_and_languages() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    let backgrounds = Background::list(&pool, None).await?;
+    assert!(backgrounds.len() > 1);
+
+    for background in backgrounds {
+        assert!(
+            !background.load_skills(&pool).await?.is_empty(),
+            "{}",
+            background.name
+        );
+        assert!(
+            !background.load_trades(&pool).await?.is_empty(),
+            "{}",
+            background.name
+        );
+        assert!(
+            !background.load_languages(&pool).await?.is_empty(),
+            "{}",
+            background.name
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _every_class_has_subclasses_features_and_a_full_table() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+
+    for class in Class::list(&pool, None).await? {
+        assert!(
+            !class.load_sublasses(&pool).await?.is_empty(),
+            "{}",
+            class.name
+        );
+
+        let features = class.load_features(&pool).await?;
+        assert!(
+            features.iter().any(|(level, _)| *level == 1),
+            "{}",
+            class.name
+        );
+        assert!(features.is_sorted_by_key(|(level, _)| *level));
+
+        let table = class.load_table(&pool).await?;
+        assert!(
+            table.health_points.0.iter().all(|&hp| hp > 0),
+            "{}",
+            class.name
+        );
+        assert!(
+            table.stamina_points.total(10) + table.mana_points.total(10) > 0,
+            "{} has neither SP nor MP",
+            class.name
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _every_spell_loads_and_is_on_a_spell_list() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    let spells = Spell::search(&pool, &SpellSearch::default(), None).await?;
+    assert!(spells.len() > 1);
+
+    for spell in &spells {
+        assert_eq!(Spell::load(&pool, spell.id).await?, *spell);
+    }
+    assert_eq!(
+        unjoined(&pool, "spells", "spell_id", "spells_spell_lists").await,
+        0
+    );
+    assert_eq!(
+        unjoined(&pool, "spells", "spell_id", "spells_spell_effects").await,
+        0
+    );
+
+    let lists = SpellList::list(&pool).await?;
+    let listed: usize = lists.iter().map(|list| list.spells.len()).sum();
+    assert!(listed >= spells.len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _every_weapon_has_a_style_and_every_maneuver_loads() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    assert_eq!(
+        unjoined(&pool, "weapons", "weapon_id", "weapons_weapon_styles").await,
+        0
+    );
+
+    let maneuvers = Maneuver::list(&pool).await?;
+    for kind in ["Attack", "Save", "Grapple", "Defense"] {
+        assert!(maneuvers
+            .iter()
+            .any(|maneuver| maneuver.kind.to_string() == kind));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use std::error::Error;
+
+use common::core;
+use mellon_book::dc20::{
+    item::weapon::{DamageType, Weapon},
+    *,
+};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+mod common;
+
+async fn creature_count(pool: &SqlitePool) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM `creatures`")
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn _a_damage_type_can_have_several_resistances() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+
+    let imported = Creature::import(
+        &pool,
+        "
+            Name: Ember Wisp
+            Level: 1
+            Hit Points: 4
+            Attributes: Prime 1, Might -1, Agility 1, Charisma 0, Intelligence 0
+            Resistance: Fire Resistance (Half)
+            Vulnerability: Fire Vulnerability (1)
+            Immunity: Poison Immunity
+        ",
+    )
+    .await?;
+    let loaded = Creature::load(&pool, imported[0].id).await?;
+
+    assert_eq!(loaded.resistances, imported[0].resistances);
+    assert_eq!(loaded.resistances.len(), 3);
+    assert_eq!(mitigate(&loaded.resistances, 6, DamageType::Fire), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _nothing_is_imported_when_one_stat_block_fails() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+
+    let result = Creature::import(
+        &pool,
+        "
+            Name: Goblin Skirmisher
+            Level: 1
+            Hit Points: 6
+            Attributes: Prime 2, Might 0, Agility 2, Charisma -1, Intelligence 0
+            Maneuver: Trip
+            ---
+            Name: Goblin Hexer
+            Level: 1
+            Hit Points: 5
+            Attributes: Prime 2, Might -1, Agility 1, Charisma 0, Intelligence 2
+            Spell: Not A Real Spell
+        ",
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(mellon_book::Error::ContentReference(_))
+    ));
+    assert_eq!(creature_count(&pool).await, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _saving_a_creature_leaves_catalog_weapons_alone() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+
+    let imported = Creature::import(
+        &pool,
+        "
+            Name: Goblin Skirmisher
+            Level: 1
+            Hit Points: 6
+            Attributes: Prime 2, Might 0, Agility 2, Charisma -1, Intelligence 0
+            Attack: Scimitar | Sword | 2
+        ",
+    )
+    .await?;
+    let mut goblin = Creature::load(&pool, imported[0].id).await?;
+    let scimitar = goblin.attacks[0].weapon.uuid;
+
+    let longsword_id = Uuid::from_u128(0x019a44c1_e63c_73c6_b553_eb9f4bb8a002);
+    let longsword = Item::load(&pool, longsword_id).await?;
+    goblin.attacks = vec![Attack::from_weapon(
+        "Stolen Blade",
+        Weapon::load(&pool, longsword_id).await?,
+    )];
+    goblin.clone().save(&pool).await?;
+
+    assert_eq!(Item::load(&pool, longsword_id).await?, longsword);
+    assert!(
+        Item::load(&pool, scimitar).await.is_err(),
+        "the replaced Attack's own Weapon is deleted"
+    );
+    assert_eq!(Creature::load(&pool, goblin.id).await?, goblin);
+
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use std::error::Error;
+
+use common::core;
+use mellon_book::{
+    dc20::{item::consumable::Consumable, *},
+    player::Player,
+};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+mod common;
+
+async fn character(pool: &SqlitePool) -> Result<Character, Box<dyn Error>> {
+    let player = Player::builder().name("Test Player")?.build()?;
+    player.clone().save(pool).await?;
+    let character = Character::builder()
+        .player(player)
+        .character_name("Test Name")
+        .background(Background::load_by_name(pool, "Acolyte", None).await?)
+        .build()?;
+    character
+        .clone()
+        .create(pool, None, &Attributes::default())
+        .await?;
+
+    Ok(character)
+}
+
+async fn item(pool: &SqlitePool, name: &str) -> Item {
+    Item::search(pool, &ItemSearch::default())
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|item| item.name == name)
+        .unwrap()
+}
+
+#[tokio::test]
+async fn _buying_and_selling_moves_coins_and_items_together() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    let character = character(&pool).await?;
+    let id = *character.id();
+    Purse::from_copper(500).save(&pool, id).await?;
+    let torch = item(&pool, "Torch").await;
+
+    purchase(&pool, id, torch.uuid, 4).await?;
+
+    assert_eq!(
+        Purse::load(&pool, id).await?.total_copper(),
+        500 - 4 * torch.price
+    );
+    let inventory = character.load_inventory(&pool).await?;
+    assert_eq!(inventory.entries.len(), 1);
+    assert_eq!(inventory.entries[0].quantity, 4);
+
+    sell(&pool, id, inventory.entries[0].id, 3, 2).await?;
+
+    assert_eq!(
+        Purse::load(&pool, id).await?.total_copper(),
+        500 - 4 * torch.price + 6
+    );
+    assert_eq!(
+        character.load_inventory(&pool).await?.entries[0].quantity,
+        1
+    );
+    let ledger: Vec<i64> = LedgerEntry::load_all(&pool, id)
+        .await?
+        .iter()
+        .map(|entry| entry.amount)
+        .collect();
+    assert_eq!(ledger, [-((4 * torch.price) as i64), 6]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _nothing_changes_when_a_trade_fails() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    let character = character(&pool).await?;
+    let id = *character.id();
+    Purse::from_copper(10).save(&pool, id).await?;
+    let backpack = item(&pool, "Backpack").await;
+
+    assert!(matches!(
+        purchase(&pool, id, backpack.uuid, 1).await,
+        Err(mellon_book::Error::RuleViolation(_))
+    ));
+    assert!(matches!(
+        purchase(&pool, id, backpack.uuid, u64::MAX).await,
+        Err(mellon_book::Error::Validation(_))
+    ));
+    assert!(matches!(
+        purchase(&pool, id, backpack.uuid, 0).await,
+        Err(mellon_book::Error::Validation(_))
+    ));
+
+    assert_eq!(Purse::load(&pool, id).await?.total_copper(), 10);
+    assert!(character.load_inventory(&pool).await?.entries.is_empty());
+    assert!(LedgerEntry::load_all(&pool, id).await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _bought_consumables_keep_all_their_charges() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    let character = character(&pool).await?;
+    let id = *character.id();
+    Purse::from_copper(500).save(&pool, id).await?;
+    let wand = Item {
+        uuid: Uuid::now_v7(),
+        name: "Wand of Sparks".into(),
+        kind: ItemKind::Consumable,
+        price: 10,
+        ..Default::default()
+    };
+    wand.clone().save(&pool).await?;
+    Consumable {
+        uuid: wand.uuid,
+        charges: 3,
+        description: "Shoots a spark at a creature within 5 Spaces.".into(),
+        ..Default::default()
+    }
+    .save(&pool)
+    .await?;
+
+    purchase(&pool, id, wand.uuid, 2).await?;
+    let mut inventory = character.load_inventory(&pool).await?;
+    inventory.consume(inventory.entries[0].id)?;
+    inventory.save(&pool).await?;
+
+    purchase(&pool, id, wand.uuid, 1).await?;
+
+    let inventory = character.load_inventory(&pool).await?;
+    let charges: Vec<(u64, Option<u64>)> = inventory
+        .entries
+        .iter()
+        .map(|entry| {
+            (
+                entry.quantity,
+                entry.consumable.as_ref().map(|wand| wand.charges),
+            )
+        })
+        .collect();
+    assert_eq!(charges.len(), 3);
+    assert!(charges.contains(&(1, Some(2))), "the wand in use");
+    assert_eq!(
+        charges
+            .iter()
+            .filter(|&&(_, charges)| charges == Some(3))
+            .count(),
+        2
+    );
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use std::{error::Error, path::PathBuf};
+
+use mellon_book::{
+    db::{self, OpenOptions},
+    dc20::{Class, Source},
+};
+use uuid::Uuid;
+
+/// Somewhere to put a database that is removed again once the test is done.
+struct Scratch(PathBuf);
+
+impl Scratch {
+    fn new() -> Self {
+        Self(std::env::temp_dir().join(format!("mellon-book-{}.sqlite", Uuid::now_v7())))
+    }
+}
+
+impl Drop for Scratch {
+    fn drop(&mut self) {
+        for suffix in ["", "-shm", "-wal"] {
+            let _ = std::fs::remove_file(format!("{}{suffix}", self.0.display()));
+        }
+    }
+}
+
+#[tokio::test]
+async fn _opening_creates_and_migrates_the_database() -> Result<(), Box<dyn Error>> {
+    let scratch = Scratch::new();
+    assert!(!scratch.0.exists());
+
+    let pool = db::open(&scratch.0).await?;
+    assert!(scratch.0.exists());
+    assert!(Class::list(&pool, None).await?.is_empty());
+
+    let foreign_keys: bool = sqlx::query_scalar("PRAGMA foreign_keys")
+        .fetch_one(&pool)
+        .await?;
+    assert!(foreign_keys);
+    let orphan = sqlx::query("INSERT INTO `characters` VALUES (?1, 'Nobody', ?2)")
+        .bind(Uuid::now_v7())
+        .bind(Uuid::now_v7())
+        .execute(&pool)
+        .await;
+    assert!(orphan.is_err());
+    let orphan = sqlx::query("INSERT INTO `campaign_trackers` VALUES (?1, 0)")
+        .bind(Uuid::now_v7())
+        .execute(&pool)
+        .await;
+    assert!(orphan.is_err());
+
+    // Nothing is pending the second time round
+    pool.close().await;
+    let pool = db::open(&scratch.0).await?;
+    assert!(Class::list(&pool, None).await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _only_new_databases_get_the_core_rules() -> Result<(), Box<dyn Error>> {
+    let scratch = Scratch::new();
+    let mut options = OpenOptions::new();
+    options.core_content(true);
+
+    let pool = options.open(&scratch.0).await?;
+    let core = Source::load_by_name(&pool, "DC20 Core Rules").await?;
+    let classes = Class::list(&pool, None).await?;
+    assert!(classes.iter().any(|class| class.name == "Wizard"));
+    assert!(classes.iter().all(|class| class.source == Some(core.id)));
+
+    let broken: Vec<(String,)> = sqlx::query_as("PRAGMA foreign_key_check")
+        .fetch_all(&pool)
+        .await?;
+    assert!(broken.is_empty());
+
+    // The table's own choices are kept when it is opened again
+    Source::delete(&pool, core.id).await?;
+    let wizard = classes.iter().find(|class| class.name == "Wizard").unwrap();
+    Class::delete(&pool, wizard.id).await?;
+    pool.close().await;
+
+    let pool = options.open(&scratch.0).await?;
+    assert!(Class::load(&pool, wizard.id).await.is_err());
+
+    // but they can still be asked for again
+    db::load_core_content(&pool).await?;
+    assert_eq!(Class::load(&pool, wizard.id).await?.name, "Wizard");
+    db::load_core_content(&pool).await?;
+    assert_eq!(Class::list(&pool, None).await?.len(), classes.len());
+
+    Ok(())
+}
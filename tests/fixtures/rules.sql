@@ -0,0 +1,21 @@
+-- Just enough of the rules for Characters to have Attributes and Weapons.
+
+INSERT INTO `attributes`
+VALUES (X'01993b832d6c7e7882b2063d613880b9', 'Prime')
+, (X'01993b83e9f978d4a5ae97c2011f49c6', 'Might')
+, (X'01993b8460827289a9e9cc105341940e', 'Agility')
+, (X'01993b84fcf17fcbb1fed093bfd9853d', 'Charisma')
+, (X'01993b8556b4774aa4a333bd7f76469e', 'Intelligence')
+;
+
+INSERT INTO `weapon_styles`
+    (`weapon_style_id`, `name`, `description`, `damage_type`)
+VALUES (X'019a0348c1d87a51b1f2e3c4d5a6b709', 'Sword', 'You gain +1 PD against Attacks while wielding the Weapon', 'Slashing')
+, (X'019a0348c1d87a51b1f2e3c4d5a6b70a', 'Whip', 'You can make Opportunity Attacks with the Weapon''s Reach', 'Slashing')
+;
+
+INSERT INTO `weapon_properties`
+    (`weapon_property_id`, `name`, `description`, `required_weapon_property_id`)
+VALUES (X'019a034a0b5e7c2f9d1e8a7b6c5d4e02', 'Guard', 'You gain +1 PD while wielding the Weapon.', NULL)
+, (X'019a034a0b5e7c2f9d1e8a7b6c5d4e08', 'Reach', 'The Weapon''s melee range increases by 1 Space.', NULL)
+;
@@ -0,0 +1,75 @@
+use std::error::Error;
+
+use common::{assert_golden, rules, sheet};
+use mellon_book::{
+    dc20::{item::weapon::WeaponProperty, *},
+    export::foundry::{self, Actor},
+    player::Player,
+};
+
+mod common;
+
+#[test]
+fn _exported_actor_matches_golden_file() -> Result<(), Box<dyn Error>> {
+    let actor = Actor::from(&sheet()?);
+    assert_golden("foundry.json", &actor.to_json()?);
+
+    assert_eq!(Actor::parse(&actor.to_json()?)?, actor);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _foundry_actors_are_imported_where_fields_map_cleanly() -> Result<(), Box<dyn Error>> {
+    let pool = rules().await;
+    let player = Player::builder().name("Spencer Dent")?.build()?;
+    let json = include_str!("fixtures/foundry_actor.json");
+
+    let imported = foundry::import(&pool, json, player.clone()).await?;
+    let character = imported.character;
+    assert_eq!(character.character_name(), "Vess");
+    assert_eq!(character.background().name, "Street Urchin");
+    assert_eq!(imported.skipped.len(), 2);
+    assert!(imported.skipped[0].starts_with("Weapon `Moonblade`"));
+    assert!(imported.skipped[1].starts_with("Spell `Sacred Flame`"));
+
+    let levels = character.load_levels(&pool).await?;
+    assert_eq!(levels.len(), 2);
+    let attributes = levels[1].load_base_attributes(&pool).await?;
+    assert_eq!(
+        (attributes.prime, attributes.might, attributes.agility),
+        (3, 1, 3)
+    );
+    assert_eq!(levels[1].load_classes(&pool, None).await?[0].name, "Rogue");
+    assert_eq!(levels[0].load_ancestries(&pool).await?[0].name, "Elf");
+
+    let maneuvers = levels[0].load_maneuvers(&pool).await?;
+    assert_eq!(
+        (maneuvers[0].name.as_str(), maneuvers[0].kind),
+        ("Trip", ManeuverKind::Save)
+    );
+
+    let inventory = character.load_inventory(&pool).await?;
+    let whip = &inventory.entries[0];
+    assert_eq!(whip.item.name, "Barbed Whip");
+    assert_eq!(
+        whip.weapon.as_ref().map(|weapon| weapon.properties.clone()),
+        Some(vec![WeaponProperty::Reach])
+    );
+
+    let resources = Resources::load(&pool, *character.id()).await?;
+    assert_eq!(
+        resources.hit_points,
+        ResourcePool {
+            current: 7,
+            maximum: 10
+        }
+    );
+
+    // A second copy shares the content the first one created
+    foundry::import(&pool, json, player).await?;
+    assert_eq!(Class::list(&pool, None).await?.len(), 1);
+    assert_eq!(Background::list(&pool, None).await?.len(), 1);
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use std::error::Error;
+
+use common::{core, rules};
+use mellon_book::{
+    dc20::*,
+    export::{foundry, json},
+    history::{self, HistoryError, Point, Revision, UNTRACKED},
+    player::Player,
+};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+mod common;
+
+/// Vess, imported from the Foundry fixture, with their first Revision.
+async fn vess(pool: &SqlitePool) -> Result<(Character, Player), Box<dyn Error>> {
+    let player = Player::builder().name("Spencer Dent")?.build()?;
+    let json = include_str!("fixtures/foundry_actor.json");
+    let character = foundry::import(pool, json, player.clone()).await?.character;
+    history::record(pool, *character.id(), Some(*player.id()), "Imported").await?;
+
+    Ok((character, player))
+}
+
+#[tokio::test]
+async fn _a_wrecked_character_is_restored_from_an_earlier_revision() -> Result<(), Box<dyn Error>> {
+    let pool = rules().await;
+    let (character, player) = vess(&pool).await?;
+    let id = *character.id();
+    let before = json::export(&pool, id).await?;
+
+    // Wrecked mid-session, outside of anything that records history
+    let levels = character.load_levels(&pool).await?;
+    Level::delete(&pool, levels[1].id).await?;
+    Purse::default().save(&pool, id).await?;
+    let mut conditions = Conditions::new();
+    conditions.insert(Condition::Dazed);
+    conditions.save(&pool, id).await?;
+    Inventory::new(id).save(&pool).await?;
+
+    let first = Revision::load_all(&pool, id).await?.remove(0);
+    let restored = history::restore(&pool, id, first.id, Some(*player.id())).await?;
+    assert_eq!(restored.character_name(), "Vess");
+    assert_eq!(json::export(&pool, id).await?, before);
+
+    let entries = history::log(&pool, id).await?;
+    let notes: Vec<&str> = entries.iter().map(|entry| entry.note.as_str()).collect();
+    assert_eq!(
+        notes,
+        [
+            "Imported",
+            UNTRACKED,
+            &format!("Restored revision {}", first.id)
+        ]
+    );
+    assert_eq!(entries[1].author, None);
+    assert_eq!(entries[2].author, Some(*player.id()));
+    assert!(entries[1]
+        .changes
+        .iter()
+        .any(|change| change.path == "conditions"
+            && change.after == Some(serde_json::json!(["Dazed"]))));
+    assert!(
+        entries[1]
+            .changes
+            .iter()
+            .any(|change| change.path == format!("levels[{}]", levels[1].id)
+                && change.after.is_none())
+    );
+    assert_eq!(entries[1].changes.len(), entries[2].changes.len());
+
+    // The wrecked sheet is still there to go back to
+    let wrecked = Point::Revision(entries[1].revision);
+    let undone = history::diff_between(&pool, id, wrecked, None).await?;
+    assert_eq!(undone.len(), entries[2].changes.len());
+    let first = Point::Revision(entries[0].revision);
+    assert!(history::diff_between(&pool, id, first, None)
+        .await?
+        .is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _restoring_a_revision_keeps_its_skill_masteries() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    let (character, player) = vess(&pool).await?;
+    let id = *character.id();
+
+    let level = character.load_levels(&pool).await?.remove(0);
+    let athletics = Skill::list(&pool)
+        .await?
+        .into_iter()
+        .find(|skill| skill.name == "Athletics")
+        .expect("Athletics to be a Skill");
+    let adept: Uuid =
+        sqlx::query_scalar("SELECT `mastery_id` FROM `masteries` WHERE `name` = 'Adept'")
+            .fetch_one(&pool)
+            .await?;
+    let adept = Mastery::load(&pool, adept).await?;
+    level.save_skill_mastery(&pool, &athletics, &adept).await?;
+    let raised = history::record(&pool, id, Some(*player.id()), "Raised Athletics")
+        .await?
+        .expect("raising a Skill to be a change");
+    assert_eq!(raised.snapshot.levels[0].skills.len(), 1);
+
+    let mut conditions = Conditions::new();
+    conditions.insert(Condition::Dazed);
+    conditions.save(&pool, id).await?;
+    history::restore(&pool, id, raised.id, Some(*player.id())).await?;
+
+    assert_eq!(
+        level.load_skill_masteries(&pool).await?,
+        vec![(athletics, adept)]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _nothing_is_recorded_when_nothing_changed() -> Result<(), Box<dyn Error>> {
+    let pool = rules().await;
+    let (character, _) = vess(&pool).await?;
+    let id = *character.id();
+
+    assert_eq!(history::record(&pool, id, None, "Again").await?, None);
+    history::checkpoint(&pool, id).await?;
+    assert_eq!(Revision::load_all(&pool, id).await?.len(), 1);
+
+    let level = character.level_up(&pool, None).await?;
+    let revision = history::record(&pool, id, None, "Reached Level 3")
+        .await?
+        .unwrap();
+    assert_eq!(revision.snapshot.levels.len(), level.level as usize);
+
+    // Revisions are found by time as well as by id
+    let at = revision.recorded_at().unwrap();
+    let found = Revision::load_at(&pool, id, Point::At(at)).await?;
+    assert_eq!(found, Some(revision));
+    let long_ago = Point::At(chrono::DateTime::UNIX_EPOCH);
+    assert_eq!(Revision::load_at(&pool, id, long_ago).await?, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _revisions_belong_to_their_own_character() -> Result<(), Box<dyn Error>> {
+    let pool = rules().await;
+    let (character, _) = vess(&pool).await?;
+    let revision = Revision::load_all(&pool, *character.id()).await?.remove(0);
+
+    let stranger = Uuid::now_v7();
+    let error = history::restore(&pool, stranger, revision.id, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, mellon_book::Error::NotFound(_)));
+    assert_eq!(
+        error
+            .source()
+            .and_then(|e| e.downcast_ref::<HistoryError>()),
+        Some(&HistoryError::UnknownRevision {
+            character_id: stranger,
+            id: revision.id,
+        })
+    );
+
+    // and are never rewritten
+    let rewrite = sqlx::query("UPDATE `character_revisions` SET `note` = 'Nothing to see'")
+        .execute(&pool)
+        .await;
+    assert!(rewrite.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use std::error::Error;
+
+use common::core;
+use mellon_book::{
+    dc20::{
+        item::consumable::{Consumable, ConsumableEffect},
+        *,
+    },
+    export::json::{self, Ids},
+    player::Player,
+};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+mod common;
+
+/// A Character carrying a Wand of Sparks with one of its three charges left.
+async fn character(pool: &SqlitePool) -> Result<(Character, Consumable), Box<dyn Error>> {
+    let player = Player::builder().name("Test Player")?.build()?;
+    player.clone().save(pool).await?;
+    let character = Character::builder()
+        .player(player)
+        .character_name("Test Name")
+        .background(Background::load_by_name(pool, "Acolyte", None).await?)
+        .build()?;
+    character
+        .clone()
+        .create(pool, None, &Attributes::default())
+        .await?;
+
+    let wand = Item {
+        uuid: Uuid::now_v7(),
+        name: "Wand of Sparks".into(),
+        kind: ItemKind::Consumable,
+        ..Default::default()
+    };
+    let sparks = Consumable {
+        uuid: wand.uuid,
+        charges: 3,
+        effect: ConsumableEffect::Narrative,
+        description: "A spark leaps from the tip.".into(),
+    };
+    wand.clone().save(pool).await?;
+    sparks.clone().save(pool).await?;
+
+    let mut inventory = character.load_inventory(pool).await?;
+    inventory.add_entry(InventoryEntry::new(wand, 1).with_consumable(Consumable {
+        charges: 1,
+        ..sparks.clone()
+    }));
+    inventory.save(pool).await?;
+
+    Ok((character, sparks))
+}
+
+#[tokio::test]
+async fn _charges_left_travel_on_the_entry_not_the_consumable() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    let (character, sparks) = character(&pool).await?;
+
+    let export = json::export(&pool, *character.id()).await?;
+    let entry = &export.inventory[0];
+    assert_eq!(entry.entry.consumable.as_ref(), Some(&sparks));
+    assert_eq!(entry.charges, Some(1));
+
+    let other = core().await;
+    json::restore(&other, export, Ids::Preserve).await?;
+
+    assert_eq!(Consumable::load(&other, sparks.uuid).await?, sparks);
+    let inventory = character.load_inventory(&other).await?;
+    assert_eq!(
+        inventory.entries[0]
+            .consumable
+            .as_ref()
+            .map(|wand| wand.charges),
+        Some(1)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _a_failed_import_leaves_nothing_behind() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    let (character, _) = character(&pool).await?;
+    let mut export = json::export(&pool, *character.id()).await?;
+    export.inventory[0].entry.quantity = 0;
+
+    let other = core().await;
+    assert!(json::restore(&other, export, Ids::Preserve).await.is_err());
+
+    assert!(matches!(
+        Character::load(&other, *character.id()).await,
+        Err(mellon_book::Error::NotFound(_))
+    ));
+    assert!(matches!(
+        Player::load(&other, *character.player().id()).await,
+        Err(mellon_book::Error::NotFound(_))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _skill_masteries_travel_with_their_level() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    let (character, _) = character(&pool).await?;
+    let level = character.load_levels(&pool).await?.remove(0);
+    let athletics = Skill::list(&pool)
+        .await?
+        .into_iter()
+        .find(|skill| skill.name == "Athletics")
+        .expect("Athletics to be a Skill");
+    let adept: Uuid =
+        sqlx::query_scalar("SELECT `mastery_id` FROM `masteries` WHERE `name` = 'Adept'")
+            .fetch_one(&pool)
+            .await?;
+    let adept = Mastery::load(&pool, adept).await?;
+    level.save_skill_mastery(&pool, &athletics, &adept).await?;
+
+    let export = json::export(&pool, *character.id()).await?;
+    assert_eq!(export.levels[0].skills[0].skill_id, athletics.id);
+
+    // An overwrite replaces the Levels, so the Masteries must be written back
+    json::restore(&pool, export.clone(), Ids::Overwrite).await?;
+    assert_eq!(
+        level.load_skill_masteries(&pool).await?,
+        vec![(athletics.clone(), adept.clone())]
+    );
+
+    let other = core().await;
+    let mut unknown = export.clone();
+    unknown.levels[0].skills[0].skill_id = Uuid::now_v7();
+    assert!(matches!(
+        json::restore(&other, unknown, Ids::Preserve).await,
+        Err(mellon_book::Error::ContentReference(_))
+    ));
+
+    json::restore(&other, export, Ids::Preserve).await?;
+    assert_eq!(
+        level.load_skill_masteries(&other).await?,
+        vec![(athletics, adept)]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,261 @@
+use std::{error::Error, path::PathBuf};
+
+use common::empty;
+use mellon_book::{
+    campaign::Campaign,
+    dc20::*,
+    pack::{Conflict, ContentKind, ContentPack, Manifest, PackError},
+    player::Player,
+};
+use uuid::Uuid;
+
+mod common;
+
+fn fixture() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/pack")
+}
+
+/// A scratch directory of the test's own, emptied first.
+fn scratch(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("mellon-book-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    dir
+}
+
+const TIDECALLER: Uuid = Uuid::from_u128(0x0199f5c2_7a10_7000_8000_000000000020);
+const RIPTIDE: Uuid = Uuid::from_u128(0x0199f5c2_7a10_7000_8000_000000000030);
+
+#[tokio::test]
+async fn _installing_a_pack_twice_changes_nothing_the_second_time() -> Result<(), Box<dyn Error>> {
+    let pool = empty().await;
+    let pack = ContentPack::read(&fixture())?;
+    assert_eq!(pack.content.ids().len(), 8);
+
+    let installed = pack.install(&pool).await?;
+    assert_eq!(
+        (installed.added, installed.updated, installed.removed),
+        (8, 0, 0)
+    );
+
+    let installed = pack.install(&pool).await?;
+    assert_eq!(
+        (installed.added, installed.updated, installed.removed),
+        (0, 8, 0)
+    );
+
+    assert_eq!(Class::list(&pool, None).await?.len(), 1);
+    let list = SpellList::load(
+        &pool,
+        Uuid::from_u128(0x0199f5c2_7a10_7000_8000_000000000034),
+    )
+    .await?;
+    assert_eq!(list.spells.len(), 2);
+    let waterbreathing = Spell::load_by_name(&pool, "Waterbreathing", None).await?;
+    assert_eq!(waterbreathing.duration, Duration::Hours(1));
+    assert_eq!(waterbreathing.point_enhancements.len(), 1);
+
+    assert_eq!(ContentPack::load(&pool, pack.manifest.id).await?, pack);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _packs_are_read_from_archives_and_written_back_out() -> Result<(), Box<dyn Error>> {
+    let pack = ContentPack::read(&fixture())?;
+    let dir = scratch("pack-archive");
+
+    let archive = dir.join("tides.tar.gz");
+    let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+        std::fs::File::create(&archive)?,
+        flate2::Compression::default(),
+    ));
+    builder.append_dir_all("tome-of-tides", fixture())?;
+    builder.into_inner()?.finish()?;
+    assert_eq!(ContentPack::read(&archive)?, pack);
+
+    let exported = dir.join("exported");
+    pack.write(&exported)?;
+    assert_eq!(ContentPack::read(&exported)?, pack);
+
+    std::fs::remove_dir_all(dir)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _content_the_pack_does_not_own_is_never_overwritten() -> Result<(), Box<dyn Error>> {
+    let pool = empty().await;
+    let mut class = Class::builder().name("Tidecaller")?.build()?;
+    class.id = TIDECALLER;
+    class.save(&pool).await?;
+
+    let pack = ContentPack::read(&fixture())?;
+    let error = pack.install(&pool).await.unwrap_err();
+    assert!(matches!(error, mellon_book::Error::ContentReference(_)));
+    assert_eq!(
+        error.source().and_then(|e| e.downcast_ref::<PackError>()),
+        Some(&PackError::Conflicts(vec![Conflict {
+            kind: ContentKind::Class,
+            id: TIDECALLER,
+            owner: None,
+        }]))
+    );
+    assert!(Manifest::list(&pool).await?.is_empty());
+    assert!(Spell::search(&pool, &SpellSearch::default(), None)
+        .await?
+        .is_empty());
+
+    // Another pack claiming the same content conflicts with the first
+    Class::delete(&pool, TIDECALLER).await?;
+    pack.install(&pool).await?;
+    let mut rival = pack.clone();
+    rival.manifest.id = Uuid::now_v7();
+    let error = rival.install(&pool).await.unwrap_err();
+    let Some(PackError::Conflicts(conflicts)) =
+        error.source().and_then(|e| e.downcast_ref::<PackError>())
+    else {
+        panic!("expected conflicts, got {error}");
+    };
+    assert_eq!(conflicts.len(), 8);
+    assert!(conflicts
+        .iter()
+        .all(|conflict| conflict.owner == Some(pack.manifest.id)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _newer_versions_drop_content_and_uninstalling_removes_the_rest(
+) -> Result<(), Box<dyn Error>> {
+    let pool = empty().await;
+    let pack = ContentPack::read(&fixture())?;
+    pack.install(&pool).await?;
+
+    let mut newer = pack.clone();
+    newer.manifest.version = "1.1.0".into();
+    newer.content.classes.clear();
+    let installed = newer.install(&pool).await?;
+    assert_eq!(
+        (installed.added, installed.updated, installed.removed),
+        (0, 7, 1)
+    );
+    assert!(Class::list(&pool, None).await?.is_empty());
+    assert_eq!(
+        Manifest::load(&pool, pack.manifest.id).await?.version,
+        "1.1.0"
+    );
+
+    assert!(ContentPack::uninstall(&pool, pack.manifest.id).await?);
+    assert!(Manifest::list(&pool).await?.is_empty());
+    assert!(Ancestry::list(&pool).await?.is_empty());
+    assert!(SpellList::list(&pool).await?.is_empty());
+    assert!(Spell::search(&pool, &SpellSearch::default(), None)
+        .await?
+        .is_empty());
+    assert!(!ContentPack::uninstall(&pool, pack.manifest.id).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _campaigns_only_see_content_from_packs_they_enable() -> Result<(), Box<dyn Error>> {
+    let pool = empty().await;
+    let game_master = Player::builder().name("Spencer Dent")?.build()?;
+    game_master.clone().save(&pool).await?;
+    let campaign = Campaign::builder()
+        .name("Starfall")?
+        .game_master(game_master)
+        .build()?;
+    campaign.clone().save(&pool).await?;
+
+    let pack = ContentPack::read(&fixture())?;
+    pack.install(&pool).await?;
+
+    let search = SpellSearch {
+        campaign: Some(*campaign.id()),
+        ..SpellSearch::default()
+    };
+    assert!(Spell::search(&pool, &search, None).await?.is_empty());
+    assert_eq!(
+        Spell::search(&pool, &SpellSearch::default(), None)
+            .await?
+            .len(),
+        2
+    );
+
+    campaign
+        .enable_content_pack(&pool, pack.manifest.id)
+        .await?;
+    assert_eq!(
+        campaign.load_content_packs(&pool).await?,
+        vec![pack.manifest.clone()]
+    );
+    let spells = Spell::search(&pool, &search, None).await?;
+    assert_eq!(spells.len(), 2);
+    assert_eq!(spells[0].id, RIPTIDE);
+
+    assert!(
+        campaign
+            .disable_content_pack(&pool, pack.manifest.id)
+            .await?
+    );
+    assert!(Spell::search(&pool, &search, None).await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _core_rules_campaigns_cannot_pick_homebrew_content() -> Result<(), Box<dyn Error>> {
+    let pool = empty().await;
+    let game_master = Player::builder().name("Spencer Dent")?.build()?;
+    game_master.clone().save(&pool).await?;
+    let campaign = Campaign::builder()
+        .name("Starfall")?
+        .game_master(game_master)
+        .build()?;
+    campaign.clone().save(&pool).await?;
+
+    let core = Source::builder().name("DC20 Core Rules")?.build()?;
+    core.clone().save(&pool).await?;
+    let mut wizard = Class::builder().name("Wizard")?.build()?;
+    wizard.source = Some(core.id);
+    wizard.clone().save(&pool).await?;
+
+    let pack = ContentPack::read(&fixture())?;
+    pack.install(&pool).await?;
+    campaign
+        .enable_content_pack(&pool, pack.manifest.id)
+        .await?;
+    assert_eq!(Source::list(&pool).await?.len(), 2);
+
+    let search = SpellSearch {
+        campaign: Some(*campaign.id()),
+        ..SpellSearch::default()
+    };
+    assert_eq!(Spell::search(&pool, &search, None).await?.len(), 2);
+    assert!(campaign.load_source_filter(&pool).await?.is_none());
+
+    campaign.allow_source(&pool, core.id).await?;
+    assert_eq!(campaign.load_sources(&pool).await?, vec![core.clone()]);
+    assert!(Spell::search(&pool, &search, None).await?.is_empty());
+
+    let core_only = campaign.load_source_filter(&pool).await?;
+    assert_eq!(core_only, Some(SourceFilter::new([core.id])));
+    assert_eq!(Class::list(&pool, core_only.as_ref()).await?, vec![wizard]);
+    assert!(Spell::load_by_name(&pool, "Riptide", core_only.as_ref())
+        .await
+        .is_err());
+    assert_eq!(
+        Spell::search(&pool, &SpellSearch::default(), core_only.as_ref())
+            .await?
+            .len(),
+        0
+    );
+
+    assert!(campaign.disallow_source(&pool, core.id).await?);
+    assert_eq!(Spell::search(&pool, &search, None).await?.len(), 2);
+
+    Ok(())
+}
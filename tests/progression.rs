@@ -0,0 +1,159 @@
+use std::error::Error;
+
+use common::core;
+use mellon_book::{
+    dc20::*,
+    player::Player,
+    progression::{self, LevelStats},
+};
+use sqlx::SqlitePool;
+
+mod common;
+
+/// Cygnus: a Barbarian at Level 1 who picks up Trip and more Might at
+/// Level 2, then takes a Level in Wizard.
+async fn cygnus(pool: &SqlitePool) -> Result<Character, Box<dyn Error>> {
+    let player = Player::builder().name("Spencer Dent")?.build()?;
+    player.clone().save(pool).await?;
+    let character = Character::builder()
+        .player(player)
+        .character_name("Cygnus")
+        .background(Background::load_by_name(pool, "Acolyte", None).await?)
+        .build()?;
+    character.clone().save(pool).await?;
+
+    let first = character.load_level(pool, 1).await?;
+    let mut attributes = Attributes {
+        prime: 3,
+        might: 3,
+        agility: 1,
+        charisma: 0,
+        intelligence: -1,
+    };
+    first.save_base_attributes(pool, &attributes).await?;
+    first
+        .save_class(pool, &Class::load_by_name(pool, "Barbarian", None).await?)
+        .await?;
+
+    let second = character.level_up(pool, None).await?;
+    attributes.might = 4;
+    second.save_base_attributes(pool, &attributes).await?;
+    second
+        .save_maneuver(pool, &Maneuver::load_by_name(pool, "Trip").await?)
+        .await?;
+
+    let wizard = Class::load_by_name(pool, "Wizard", None).await?;
+    character.level_up(pool, Some(&wizard)).await?;
+
+    Ok(character)
+}
+
+#[tokio::test]
+async fn _a_past_level_is_everything_taken_up_to_it() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    let id = *cygnus(&pool).await?.id();
+
+    let second = progression::at_level(&pool, id, 2).await?;
+    assert_eq!(second.level.level, 2);
+    assert_eq!(second.attributes.as_ref().map(|a| a.might), Some(4));
+    let classes: Vec<(&str, u32)> = second
+        .classes
+        .iter()
+        .map(|taken| (taken.class.name.as_str(), taken.levels))
+        .collect();
+    assert_eq!(classes, [("Barbarian", 2)]);
+    assert_eq!(second.backgrounds[0].name, "Acolyte");
+    assert_eq!(second.maneuvers.len(), 1);
+    assert_eq!(second.maneuvers[0].name, "Trip");
+    assert_eq!(second.stats.combat_mastery, 1);
+
+    assert!(matches!(
+        progression::at_level(&pool, id, 4).await,
+        Err(mellon_book::Error::NotFound(_))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _comparing_levels_shows_only_what_changed() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    let id = *cygnus(&pool).await?.id();
+
+    let comparison = progression::compare(&pool, id, 1, 3).await?;
+    assert_eq!((comparison.from, comparison.to), (1, 3));
+    let attributes: Vec<(&str, Option<i64>, Option<i64>)> = comparison
+        .attributes
+        .iter()
+        .map(|change| (change.name.as_str(), change.from, change.to))
+        .collect();
+    assert_eq!(attributes, [("Might", Some(3), Some(4))]);
+    let classes: Vec<(&str, u32, u32)> = comparison
+        .classes
+        .iter()
+        .map(|change| (change.class.name.as_str(), change.from, change.to))
+        .collect();
+    assert_eq!(classes, [("Barbarian", 1, 2), ("Wizard", 0, 1)]);
+    assert_eq!(comparison.maneuvers.added[0].name, "Trip");
+    assert!(comparison.maneuvers.removed.is_empty());
+    assert!(comparison.backgrounds.added.is_empty());
+    assert!(comparison
+        .stats
+        .iter()
+        .any(|change| change.name == "Combat Mastery"
+            && change.from == Some(1)
+            && change.to == Some(2)));
+
+    // Going back gives things up
+    let back = progression::compare(&pool, id, 3, 1).await?;
+    assert_eq!(back.maneuvers.removed[0].name, "Trip");
+    assert!(back.maneuvers.added.is_empty());
+
+    assert!(progression::compare(&pool, id, 2, 2)
+        .await?
+        .stats
+        .is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn _the_chart_adds_each_level_from_its_own_class_table() -> Result<(), Box<dyn Error>> {
+    let pool = core().await;
+    let id = *cygnus(&pool).await?.id();
+    let barbarian = Class::load_by_name(&pool, "Barbarian", None)
+        .await?
+        .load_table(&pool)
+        .await?;
+    let wizard = Class::load_by_name(&pool, "Wizard", None)
+        .await?
+        .load_table(&pool)
+        .await?;
+
+    let chart = progression::chart(&pool, id).await?;
+    let levels: Vec<u32> = chart.iter().map(|stats| stats.level).collect();
+    assert_eq!(levels, [1, 2, 3]);
+
+    let LevelStats {
+        health_points,
+        mana_points,
+        stamina_points,
+        ..
+    } = chart[2];
+    assert_eq!(
+        health_points,
+        barbarian.health_points.total(2) + wizard.health_points.get(1)
+    );
+    assert_eq!(
+        mana_points,
+        barbarian.mana_points.total(2) + wizard.mana_points.get(1)
+    );
+    assert_eq!(
+        stamina_points,
+        barbarian.stamina_points.total(2) + wizard.stamina_points.get(1)
+    );
+    assert_eq!(chart[1], progression::at_level(&pool, id, 2).await?.stats);
+    assert!(chart.iter().all(|stats| stats.precision_defense.is_some()));
+
+    Ok(())
+}
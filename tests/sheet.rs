@@ -0,0 +1,44 @@
+use std::error::Error;
+
+use common::{assert_golden, sheet};
+
+mod common;
+
+#[test]
+fn _markdown_sheet_matches_golden_file() -> Result<(), Box<dyn Error>> {
+    assert_golden("sheet.md", &sheet()?.to_markdown());
+
+    Ok(())
+}
+
+#[test]
+fn _html_sheet_matches_golden_file() -> Result<(), Box<dyn Error>> {
+    assert_golden("sheet.html", &sheet()?.to_html());
+
+    Ok(())
+}
+
+#[test]
+fn _pdf_sheet_has_a_page_for_every_four_spell_cards() -> Result<(), Box<dyn Error>> {
+    let mut sheet = sheet()?;
+    let pdf = sheet.to_pdf()?;
+    assert!(pdf.starts_with(b"%PDF-"));
+    assert_eq!(pages(&pdf), 2);
+
+    let spell = sheet.spells[0].clone();
+    sheet.spells = vec![spell; 5];
+    assert_eq!(pages(&sheet.to_pdf()?), 3);
+
+    sheet.spells.clear();
+    assert_eq!(pages(&sheet.to_pdf()?), 1);
+
+    Ok(())
+}
+
+/// Counts the page objects, leaving out the one that lists them.
+fn pages(pdf: &[u8]) -> usize {
+    let page = b"/Type/Page";
+    pdf.windows(page.len() + 1)
+        .filter(|window| window.starts_with(page) && window[page.len()] != b's')
+        .count()
+}